@@ -0,0 +1,236 @@
+//! Compares layouts of keys of the hottest columns of the RocksDB database:
+//!
+//! - The owned coins index with the legacy `Address ++ UtxoId` keys and
+//!   with the `OwnerId ++ UtxoId` keys.
+//! - Transaction statuses with the legacy transaction id keys and with
+//!   the `BlockHeight ++ TransactionIndex` keys together with the index of pointers.
+//!
+//! Each iteration writes keys into the empty database in batches, like the block
+//! importer does, and compacts it, so the result includes the write amplification.
+
+use criterion::{
+    criterion_group,
+    criterion_main,
+    measurement::WallTime,
+    BatchSize,
+    BenchmarkGroup,
+    BenchmarkId,
+    Criterion,
+};
+use fuel_core::{
+    database::database_description::{
+        off_chain::OffChain,
+        on_chain::OnChain,
+        DatabaseDescription,
+    },
+    fuel_core_graphql_api::storage::{
+        transactions::tx_status_key,
+        Column as OffChainColumn,
+    },
+    state::rocks_db::{
+        RocksDb,
+        ShallowTempDir,
+    },
+};
+use fuel_core_storage::{
+    codec::{
+        postcard::Postcard,
+        primitive::{
+            owner_id_utxo_id_to_bytes,
+            utxo_id_to_bytes,
+        },
+        Encode,
+    },
+    column::Column,
+    kv_store::{
+        BatchOperations,
+        Value,
+        WriteOperation,
+    },
+};
+use fuel_core_types::{
+    fuel_tx::{
+        Address,
+        Bytes32,
+        TxPointer,
+        UtxoId,
+    },
+    services::txpool::TransactionStatus,
+    tai64::Tai64,
+};
+use rand::{
+    rngs::StdRng,
+    Rng,
+    SeedableRng,
+};
+use std::sync::Arc;
+
+// Use Jemalloc during benchmarks
+#[global_allocator]
+static GLOBAL: tikv_jemallocator::Jemalloc = tikv_jemallocator::Jemalloc;
+
+const OWNERS: usize = 100;
+const ENTRIES_PER_BATCH: usize = 100;
+
+type Batch<Description> =
+    Vec<(Vec<u8>, <Description as DatabaseDescription>::Column, Value)>;
+
+struct TempDatabase<Description> {
+    db: RocksDb<Description>,
+    _tmp_dir: ShallowTempDir,
+}
+
+impl<Description> TempDatabase<Description>
+where
+    Description: DatabaseDescription,
+{
+    fn new() -> Self {
+        let tmp_dir = ShallowTempDir::new();
+        let db = RocksDb::default_open(tmp_dir.path(), None).unwrap();
+        Self {
+            db,
+            _tmp_dir: tmp_dir,
+        }
+    }
+}
+
+fn bench_writes<Description>(
+    group: &mut BenchmarkGroup<WallTime>,
+    id: BenchmarkId,
+    batches: &[Batch<Description>],
+) where
+    Description: DatabaseDescription,
+{
+    group.bench_with_input(id, batches, |b, batches| {
+        b.iter_batched(
+            TempDatabase::<Description>::new,
+            |database| {
+                for batch in batches {
+                    database
+                        .db
+                        .batch_write(&mut batch.iter().map(|(key, column, value)| {
+                            (key.clone(), *column, WriteOperation::Insert(value.clone()))
+                        }))
+                        .unwrap();
+                }
+                database.db.compact();
+                database
+            },
+            BatchSize::PerIteration,
+        )
+    });
+}
+
+fn owned_coin_keys(c: &mut Criterion) {
+    let mut group = c.benchmark_group("owned_coin_keys");
+    group.sample_size(10);
+
+    for n in [10_000, 100_000] {
+        let mut rng = StdRng::seed_from_u64(0xF00DF00D);
+        let owners: Vec<Address> = (0..OWNERS).map(|_| rng.gen()).collect();
+        let coins: Vec<(u64, Address, UtxoId)> = (0..n)
+            .map(|_| {
+                let owner_id = rng.gen_range(0..OWNERS);
+                let utxo_id = UtxoId::new(rng.gen(), rng.gen());
+                (owner_id as u64, owners[owner_id], utxo_id)
+            })
+            .collect();
+        let empty = Arc::new(vec![]);
+
+        let legacy: Vec<Batch<OnChain>> = coins
+            .chunks(ENTRIES_PER_BATCH)
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .map(|(_, owner, utxo_id)| {
+                        let mut key = owner.to_vec();
+                        key.extend(utxo_id_to_bytes(utxo_id));
+                        (key, Column::OwnedCoins, empty.clone())
+                    })
+                    .collect()
+            })
+            .collect();
+        bench_writes(&mut group, BenchmarkId::new("legacy", n), &legacy);
+
+        let compact: Vec<Batch<OnChain>> = coins
+            .chunks(ENTRIES_PER_BATCH)
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .map(|(owner_id, _, utxo_id)| {
+                        let key = owner_id_utxo_id_to_bytes(*owner_id, utxo_id);
+                        (key.to_vec(), Column::OwnedCoins, empty.clone())
+                    })
+                    .collect()
+            })
+            .collect();
+        bench_writes(&mut group, BenchmarkId::new("compact", n), &compact);
+    }
+
+    group.finish();
+}
+
+fn tx_status_keys(c: &mut Criterion) {
+    let mut group = c.benchmark_group("tx_status_keys");
+    group.sample_size(10);
+
+    for n in [10_000, 100_000] {
+        let mut rng = StdRng::seed_from_u64(0xF00DF00D);
+        let tx_ids: Vec<Bytes32> = (0..n).map(|_| rng.gen()).collect();
+        let status = Postcard::encode_as_value(&TransactionStatus::Success {
+            block_id: Default::default(),
+            time: Tai64::UNIX_EPOCH,
+            result: None,
+        });
+
+        let legacy: Vec<Batch<OffChain>> = tx_ids
+            .chunks(ENTRIES_PER_BATCH)
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .map(|tx_id| {
+                        (
+                            tx_id.to_vec(),
+                            OffChainColumn::TransactionStatus,
+                            status.clone(),
+                        )
+                    })
+                    .collect()
+            })
+            .collect();
+        bench_writes(&mut group, BenchmarkId::new("legacy", n), &legacy);
+
+        let compact: Vec<Batch<OffChain>> = tx_ids
+            .chunks(ENTRIES_PER_BATCH)
+            .enumerate()
+            .map(|(height, chunk)| {
+                chunk
+                    .iter()
+                    .enumerate()
+                    .flat_map(|(tx_idx, tx_id)| {
+                        let tx_pointer =
+                            TxPointer::new((height as u32).into(), tx_idx as u16);
+                        [
+                            (
+                                tx_status_key(&tx_pointer).to_vec(),
+                                OffChainColumn::TransactionStatus,
+                                status.clone(),
+                            ),
+                            (
+                                tx_id.to_vec(),
+                                OffChainColumn::TransactionPointers,
+                                Postcard::encode_as_value(&tx_pointer),
+                            ),
+                        ]
+                    })
+                    .collect()
+            })
+            .collect();
+        bench_writes(&mut group, BenchmarkId::new("compact", n), &compact);
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, owned_coin_keys, tx_status_keys);
+criterion_main!(benches);
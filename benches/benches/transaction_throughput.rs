@@ -10,6 +10,10 @@ use criterion::{
 };
 use fuel_core::service::config::Trigger;
 use fuel_core_benches::*;
+use fuel_core_test_harness::builder::{
+    TestContext,
+    TestSetupBuilder,
+};
 use fuel_core_types::{
     fuel_asm::{
         op,
@@ -35,6 +39,7 @@ use fuel_core_types::{
         CheckPredicateParams,
         EstimatePredicates,
     },
+    services::txpool::TxOrigin,
 };
 use rand::{
     rngs::StdRng,
@@ -44,10 +49,6 @@ use std::{
     sync::Arc,
     time::Duration,
 };
-use test_helpers::builder::{
-    TestContext,
-    TestSetupBuilder,
-};
 
 // Use Jemalloc during benchmarks
 #[global_allocator]
@@ -105,7 +106,10 @@ where
                                 test_builder.finalize().await;
 
                             // insert all transactions
-                            srv.shared.txpool.insert(transactions).await;
+                            srv.shared
+                                .txpool
+                                .insert(transactions, TxOrigin::Injected)
+                                .await;
                             let _ = client.produce_blocks(1, None).await;
 
                             // sanity check block to ensure the transactions were actually processed
@@ -50,6 +50,7 @@ pub fn provision_import_test(
     let params = Config {
         header_batch_size: header_batch_size as usize,
         block_stream_buffer_size,
+        max_in_flight_bytes: usize::MAX,
     };
     let p2p = Arc::new(PressurePeerToPeer::new(
         shared_count.clone(),
@@ -16,11 +16,20 @@ use dotenvy::dotenv;
 
 lazy_static::lazy_static! {
     pub static ref DEFAULT_DB_PATH: PathBuf = dirs::home_dir().unwrap().join(".fuel").join("db");
+    pub static ref DEFAULT_KEYSTORE_PATH: PathBuf = dirs::home_dir().unwrap().join(".fuel").join("keystore");
 }
 
+pub mod check_upgrade;
+pub mod db;
+pub mod export_blocks;
 pub mod fee_contract;
+pub mod import_blocks;
+pub mod key;
+pub mod keystore;
 pub mod run;
+pub mod secrets;
 pub mod snapshot;
+pub mod stress;
 
 #[derive(Parser, Debug)]
 #[clap(
@@ -39,7 +48,13 @@ pub struct Opt {
 pub enum Fuel {
     Run(run::Command),
     Snapshot(snapshot::Command),
+    Db(db::Command),
+    CheckUpgrade(check_upgrade::Command),
+    ExportBlocks(export_blocks::Command),
+    ImportBlocks(import_blocks::Command),
     GenerateFeeContract(fee_contract::Command),
+    Key(key::Command),
+    Stress(stress::Command),
 }
 
 pub const LOG_FILTER: &str = "RUST_LOG";
@@ -100,30 +115,48 @@ pub async fn init_logging() -> anyhow::Result<()> {
     Ok(())
 }
 
-pub async fn run_cli() -> anyhow::Result<()> {
-    init_logging().await?;
-    if let Some(path) = init_environment() {
-        let path = path.display();
-        tracing::info!("Loading environment variables from {path}");
-    }
+pub fn run_cli() -> anyhow::Result<()> {
+    let environment = init_environment();
     let opt = Opt::try_parse();
-    if opt.is_err() {
-        let command = run::Command::try_parse();
-        if let Ok(command) = command {
+    let mut deprecated_cli = false;
+    let command = match opt {
+        Ok(opt) => opt.command,
+        Err(e) => {
+            if let Ok(command) = run::Command::try_parse() {
+                deprecated_cli = true;
+                Fuel::Run(command)
+            } else {
+                // Prints the error and exits.
+                e.exit()
+            }
+        }
+    };
+
+    let runtime = match &command {
+        Fuel::Run(command) => command.runtime.build()?,
+        _ => run::runtime::default_runtime()?,
+    };
+
+    runtime.block_on(async move {
+        init_logging().await?;
+        if let Some(path) = environment {
+            let path = path.display();
+            tracing::info!("Loading environment variables from {path}");
+        }
+        if deprecated_cli {
             tracing::warn!("This cli format for running `fuel-core` is deprecated and will be removed. Please use `fuel-core run` or use `--help` for more information");
-            return run::exec(command).await
         }
-    }
 
-    match opt {
-        Ok(opt) => match opt.command {
+        match command {
             Fuel::Run(command) => run::exec(command).await,
             Fuel::Snapshot(command) => snapshot::exec(command).await,
+            Fuel::Db(command) => db::exec(command).await,
+            Fuel::CheckUpgrade(command) => check_upgrade::exec(command).await,
+            Fuel::ExportBlocks(command) => export_blocks::exec(command).await,
+            Fuel::ImportBlocks(command) => import_blocks::exec(command).await,
             Fuel::GenerateFeeContract(command) => fee_contract::exec(command).await,
-        },
-        Err(e) => {
-            // Prints the error and exits.
-            e.exit()
+            Fuel::Key(command) => key::exec(command).await,
+            Fuel::Stress(command) => stress::exec(command).await,
         }
-    }
+    })
 }
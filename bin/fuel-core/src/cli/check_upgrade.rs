@@ -0,0 +1,84 @@
+use crate::cli::DEFAULT_DB_PATH;
+use clap::Parser;
+use std::path::PathBuf;
+
+/// Inspects the data directory of the node and reports whether the current binary
+/// can open it directly, needs to migrate it first, or can't open it at all.
+/// The databases are opened in the read-only mode, so the running node isn't affected.
+/// Fails if any database is incompatible.
+#[derive(Debug, Clone, Parser)]
+pub struct Command {
+    /// The path to the data directory of the node.
+    #[clap(
+        name = "FROM_DATADIR",
+        long = "from-datadir",
+        value_parser,
+        default_value = (*DEFAULT_DB_PATH).to_str().unwrap()
+    )]
+    from_datadir: PathBuf,
+
+    /// The file to write the JSON report to. By default, the report is written to stdout.
+    #[clap(long = "output")]
+    output: Option<PathBuf>,
+}
+
+#[cfg(not(any(feature = "rocksdb", feature = "rocksdb-production")))]
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    Err(anyhow::anyhow!(
+        "Rocksdb must be enabled to use the database at {}",
+        command.from_datadir.display()
+    ))
+}
+
+#[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    use anyhow::Context;
+    use fuel_core::database::upgrade::{
+        check_upgrade,
+        UpgradeStatus,
+    };
+
+    let path = command.from_datadir;
+    if !path.exists() {
+        return Err(anyhow::anyhow!(
+            "the data directory doesn't exist at path {}",
+            path.display()
+        ))
+    }
+
+    let report = check_upgrade(&path).context(format!(
+        "failed to inspect the data directory at path {}",
+        path.display()
+    ))?;
+    match &command.output {
+        Some(output) => {
+            let file = std::fs::File::create(output).context(format!(
+                "failed to create the report file {}",
+                output.display()
+            ))?;
+            serde_json::to_writer_pretty(file, &report)
+        }
+        None => serde_json::to_writer_pretty(std::io::stdout().lock(), &report),
+    }
+    .context("failed to write the report")?;
+
+    match report.status {
+        UpgradeStatus::Compatible => {
+            tracing::info!("The data directory can be opened directly");
+        }
+        UpgradeStatus::NeedsMigrations => {
+            tracing::info!(
+                "The data directory is migrated during the start, \
+                it takes about {}s",
+                report.estimated_duration_secs
+            );
+        }
+        UpgradeStatus::Incompatible => {
+            return Err(anyhow::anyhow!(
+                "The data directory at path {} is incompatible with this version",
+                path.display()
+            ))
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,274 @@
+use crate::cli::DEFAULT_DB_PATH;
+use clap::{
+    Parser,
+    Subcommand,
+};
+use fuel_core::database::reindex::Index;
+use std::path::PathBuf;
+
+/// Maintenance of the database: statistics, compaction, integrity checks
+/// and the rebuild of indexes.
+#[derive(Debug, Clone, Parser)]
+pub struct Command {
+    /// The path to the database.
+    #[clap(
+        name = "DB_PATH",
+        long = "db-path",
+        value_parser,
+        default_value = (*DEFAULT_DB_PATH).to_str().unwrap()
+    )]
+    database_path: PathBuf,
+
+    /// The file to write the JSON report to. By default, the report is written to stdout.
+    #[clap(long = "output")]
+    output: Option<PathBuf>,
+
+    /// The sub-command of the database operation.
+    #[command(subcommand)]
+    subcommand: SubCommands,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum SubCommands {
+    /// Reports the number of keys and the size of each column family.
+    Stat,
+    /// Triggers the manual compaction of all column families and
+    /// reports statistics after the compaction.
+    Compact,
+    /// Validates the referential integrity of the on-chain database: every transaction
+    /// referenced by a block exists, and every coin points at an existing transaction.
+    /// Fails if any violation is found.
+    Check {
+        /// Instead of the full check, verifies the number of random blocks and
+        /// the number of random contracts against their merkle roots and reports
+        /// the estimated corruption rate of the database.
+        #[clap(long = "sample")]
+        sample: Option<usize>,
+    },
+    /// Rebuilds derived indexes from the on-chain data and reports the number
+    /// of removed and written entries of each index.
+    Reindex {
+        /// The index to rebuild: `owned-transactions`, `owned-coins`, `owned-messages`
+        /// or `coin-balances`. By default, all indexes are rebuilt. Receipts and
+        /// statuses of messages are not derived indexes, so they can't be rebuilt.
+        #[clap(long = "index")]
+        index: Option<Index>,
+
+        /// The number of index entries committed by one database transaction.
+        #[clap(long = "batch-size", default_value = "10000")]
+        batch_size: usize,
+
+        /// The pause after each committed batch, limiting the load on the database.
+        #[clap(long = "throttle", default_value = "0s")]
+        throttle: humantime::Duration,
+    },
+}
+
+#[cfg(not(any(feature = "rocksdb", feature = "rocksdb-production")))]
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    Err(anyhow::anyhow!(
+        "Rocksdb must be enabled to use the database at {}",
+        command.database_path.display()
+    ))
+}
+
+#[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    use anyhow::Context;
+    use fuel_core::{
+        combined_database::CombinedDatabase,
+        database::{
+            database_description::{
+                off_chain::OffChain,
+                on_chain::OnChain,
+                relayer::Relayer,
+                DatabaseDescription,
+            },
+            integrity::VerificationScope,
+            reindex::ReindexConfig,
+            Database,
+        },
+        state::rocks_db::{
+            ColumnStatistic,
+            RocksDb,
+        },
+    };
+    use std::{
+        collections::BTreeMap,
+        path::Path,
+        sync::Arc,
+    };
+
+    fn open<Description>(path: &Path) -> anyhow::Result<RocksDb<Description>>
+    where
+        Description: DatabaseDescription,
+    {
+        RocksDb::<Description>::default_open(path, None)
+            .map_err(Into::<anyhow::Error>::into)
+            .context(format!(
+                "failed to open the {} database at path {}",
+                Description::name(),
+                path.display()
+            ))
+    }
+
+    /// Opens the database without modifying it, so it can be inspected
+    /// while the node is running.
+    fn open_read_only<Description>(path: &Path) -> anyhow::Result<RocksDb<Description>>
+    where
+        Description: DatabaseDescription,
+    {
+        RocksDb::<Description>::default_open_read_only(path, None)
+            .map_err(Into::<anyhow::Error>::into)
+            .context(format!(
+                "failed to open the {} database at path {} in the read-only mode",
+                Description::name(),
+                path.display()
+            ))
+    }
+
+    fn statistics(
+        path: &Path,
+        compact: bool,
+    ) -> anyhow::Result<BTreeMap<String, Vec<ColumnStatistic>>> {
+        fn collect<Description>(
+            path: &Path,
+            compact: bool,
+            report: &mut BTreeMap<String, Vec<ColumnStatistic>>,
+        ) -> anyhow::Result<()>
+        where
+            Description: DatabaseDescription,
+        {
+            let db = if compact {
+                let db = open::<Description>(path)?;
+                db.compact();
+                db
+            } else {
+                open_read_only::<Description>(path)?
+            };
+            report.insert(Description::name().to_string(), db.column_statistics()?);
+            Ok(())
+        }
+
+        let mut report = BTreeMap::new();
+        collect::<OnChain>(path, compact, &mut report)?;
+        collect::<OffChain>(path, compact, &mut report)?;
+        collect::<Relayer>(path, compact, &mut report)?;
+        Ok(report)
+    }
+
+    let path = command.database_path;
+    if !path.exists() {
+        return Err(anyhow::anyhow!(
+            "the database doesn't exist at path {}",
+            path.display()
+        ))
+    }
+
+    let write_report = |report: serde_json::Value| -> anyhow::Result<()> {
+        match &command.output {
+            Some(output) => {
+                let file = std::fs::File::create(output).context(format!(
+                    "failed to create the report file {}",
+                    output.display()
+                ))?;
+                serde_json::to_writer_pretty(file, &report)
+            }
+            None => serde_json::to_writer_pretty(std::io::stdout().lock(), &report),
+        }
+        .context("failed to write the report")
+    };
+
+    match command.subcommand {
+        SubCommands::Stat => {
+            let report = statistics(&path, false)?;
+            write_report(serde_json::to_value(&report)?)?;
+        }
+        SubCommands::Compact => {
+            let report = statistics(&path, true)?;
+            write_report(serde_json::to_value(&report)?)?;
+        }
+        SubCommands::Check {
+            sample: Some(sample),
+        } => {
+            let db =
+                Database::<OnChain>::new(Arc::new(open_read_only::<OnChain>(&path)?));
+            let report = db.verify_roots(
+                VerificationScope::Sample(sample),
+                &mut rand::thread_rng(),
+            )?;
+            write_report(serde_json::to_value(&report)?)?;
+            if !report.is_ok() {
+                return Err(anyhow::anyhow!(
+                    "the database has {} corrupted among {} sampled blocks and \
+                    {} corrupted among {} sampled contracts, the estimated corruption \
+                    rate of blocks is {:.4}% and of contracts is {:.4}%",
+                    report.blocks.corrupted,
+                    report.blocks.verified,
+                    report.contracts.corrupted,
+                    report.contracts.verified,
+                    report.blocks.estimated_rate * 100.0,
+                    report.contracts.estimated_rate * 100.0
+                ))
+            }
+        }
+        SubCommands::Check { sample: None } => {
+            let db =
+                Database::<OnChain>::new(Arc::new(open_read_only::<OnChain>(&path)?));
+            let report = db.check_integrity()?;
+            write_report(serde_json::to_value(&report)?)?;
+            if !report.is_ok() {
+                return Err(anyhow::anyhow!(
+                    "the database has {} missing transactions and {} orphan coins",
+                    report.missing_transactions.len(),
+                    report.orphan_coins.len()
+                ))
+            }
+        }
+        SubCommands::Reindex {
+            index,
+            batch_size,
+            throttle,
+        } => {
+            let database = CombinedDatabase::new(
+                Database::new(Arc::new(open::<OnChain>(&path)?)),
+                Database::new(Arc::new(open::<OffChain>(&path)?)),
+                Database::new(Arc::new(open::<Relayer>(&path)?)),
+            );
+            let config = ReindexConfig {
+                batch_size,
+                throttle: throttle.into(),
+            };
+            let indexes = match index {
+                Some(index) => vec![index],
+                None => Index::all().collect(),
+            };
+
+            let mut reports = vec![];
+            for index in indexes {
+                tracing::info!("Rebuilding the `{index}` index");
+                let report =
+                    database.reindex(index, &config, |progress| {
+                        match progress.total {
+                            Some(total) => tracing::info!(
+                                "Rebuilding the `{index}` index: processed {} of {total}",
+                                progress.processed
+                            ),
+                            None => tracing::info!(
+                                "Rebuilding the `{index}` index: processed {}",
+                                progress.processed
+                            ),
+                        }
+                    })?;
+                tracing::info!(
+                    "Rebuilt the `{index}` index: removed {} and wrote {} entries",
+                    report.removed,
+                    report.written
+                );
+                reports.push(report);
+            }
+            write_report(serde_json::to_value(&reports)?)?;
+        }
+    }
+    Ok(())
+}
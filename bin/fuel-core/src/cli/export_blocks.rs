@@ -0,0 +1,73 @@
+use crate::cli::DEFAULT_DB_PATH;
+use clap::Parser;
+use std::path::PathBuf;
+
+/// Exports sealed blocks from the database to the file in the stable binary format.
+#[derive(Debug, Clone, Parser)]
+pub struct Command {
+    /// The path to the database.
+    #[clap(
+        name = "DB_PATH",
+        long = "db-path",
+        value_parser,
+        default_value = (*DEFAULT_DB_PATH).to_str().unwrap()
+    )]
+    database_path: PathBuf,
+
+    /// The height of the first exported block.
+    #[clap(long = "from", default_value = "0")]
+    from: u32,
+
+    /// The height of the last exported block. By default, it is the latest block.
+    #[clap(long = "to")]
+    to: Option<u32>,
+
+    /// The file to write blocks to.
+    #[clap(long = "out")]
+    out: PathBuf,
+}
+
+#[cfg(not(any(feature = "rocksdb", feature = "rocksdb-production")))]
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    Err(anyhow::anyhow!(
+        "Rocksdb must be enabled to use the database at {}",
+        command.database_path.display()
+    ))
+}
+
+#[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    use anyhow::Context;
+    use fuel_core::{
+        block_archive::export_blocks,
+        database::Database,
+        state::rocks_db::RocksDb,
+    };
+    use std::{
+        fs::File,
+        io::BufWriter,
+        sync::Arc,
+    };
+
+    let path = command.database_path;
+    let data_source = RocksDb::default_open(&path, None)
+        .map_err(Into::<anyhow::Error>::into)
+        .context(format!(
+            "failed to open database at path {}",
+            path.display()
+        ))?;
+    let db = Database::new(Arc::new(data_source));
+
+    let to = match command.to {
+        Some(to) => to.into(),
+        None => db.latest_height()?,
+    };
+    let file = File::create(&command.out).context(format!(
+        "failed to create the file {}",
+        command.out.display()
+    ))?;
+
+    let exported = export_blocks(&db, command.from.into(), to, BufWriter::new(file))?;
+    tracing::info!("Exported {exported} blocks to {}", command.out.display());
+    Ok(())
+}
@@ -0,0 +1,106 @@
+use crate::cli::DEFAULT_DB_PATH;
+use clap::Parser;
+use std::path::PathBuf;
+
+/// Imports sealed blocks exported by the `export-blocks` command into the database.
+/// The seal of each block is verified against the PoA authority of the chain, and
+/// the block is validated and executed before the commit, as if it was received
+/// from the network. The import stops at the first block that fails the verification.
+#[derive(Debug, Clone, Parser)]
+pub struct Command {
+    /// The path to the database.
+    #[clap(
+        name = "DB_PATH",
+        long = "db-path",
+        value_parser,
+        default_value = (*DEFAULT_DB_PATH).to_str().unwrap()
+    )]
+    database_path: PathBuf,
+
+    /// Specify either an alias to a built-in configuration or filepath to a JSON file.
+    /// It should be the chain config of the node that exported blocks.
+    #[clap(name = "CHAIN_CONFIG", long = "chain", default_value = "local_testnet")]
+    chain_config: String,
+
+    /// The file with exported blocks.
+    #[clap(long = "in")]
+    input: PathBuf,
+
+    /// The number of blocks read from the file at once. The progress is reported
+    /// after each batch.
+    #[clap(long = "batch-size", default_value = "1000")]
+    batch_size: usize,
+}
+
+#[cfg(not(any(feature = "rocksdb", feature = "rocksdb-production")))]
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    Err(anyhow::anyhow!(
+        "Rocksdb must be enabled to use the database at {}",
+        command.database_path.display()
+    ))
+}
+
+#[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    use anyhow::Context;
+    use fuel_core::{
+        block_archive::import_blocks,
+        chain_config::ChainConfig,
+        service::{
+            config::Trigger,
+            Config,
+            DbType,
+            FuelService,
+            ServiceTrait,
+        },
+    };
+    use std::{
+        fs::File,
+        io::BufReader,
+    };
+
+    let chain_conf: ChainConfig = command.chain_config.as_str().parse()?;
+    let consensus = chain_conf.consensus.clone();
+    let file = File::open(&command.input).context(format!(
+        "failed to open the file {}",
+        command.input.display()
+    ))?;
+
+    // The node only imports blocks: it doesn't produce blocks and doesn't
+    // connect to the network.
+    let mut config = Config::local_node();
+    config.database_type = DbType::RocksDb;
+    config.database_path = command.database_path;
+    config.block_production = Trigger::Never;
+    config.debug = false;
+    config.utxo_validation = true;
+    config.consensus_key = None;
+    config.block_importer =
+        fuel_core::service::config::fuel_core_importer::Config::new(&chain_conf);
+    config.txpool.chain_config = chain_conf.clone();
+    config.txpool.utxo_validation = true;
+    config.chain_conf = chain_conf;
+    #[cfg(feature = "p2p")]
+    {
+        config.p2p = None;
+    }
+
+    let service = FuelService::new_node(config).await?;
+    let result = import_blocks(
+        &service.shared.block_importer,
+        service.shared.database.on_chain(),
+        &consensus,
+        BufReader::new(file),
+        command.batch_size,
+    )
+    .await;
+    service.stop_and_await().await?;
+
+    let report = result?;
+    tracing::info!(
+        "Imported {} blocks, skipped {} existing blocks",
+        report.imported,
+        report.skipped
+    );
+    Ok(())
+}
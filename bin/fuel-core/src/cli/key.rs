@@ -0,0 +1,95 @@
+use crate::cli::{
+    keystore::Keystore,
+    secrets,
+    DEFAULT_KEYSTORE_PATH,
+};
+use anyhow::Context;
+use clap::{
+    Parser,
+    Subcommand,
+};
+use fuel_core::types::fuel_crypto::SecretKey;
+use std::{
+    path::PathBuf,
+    str::FromStr,
+};
+
+/// Management of secret keys in the encrypted keystore.
+///
+/// The keys of the keystore can be used by the node via the `key:<NAME>` secret reference,
+/// e.g., `--consensus-key key:<NAME>` or `--keypair key:<NAME>`.
+/// The passphrase is taken from the `SECRETS_PASSPHRASE` environment variable,
+/// or requested interactively if it is not set.
+#[derive(Debug, Clone, Parser)]
+pub struct Command {
+    /// The directory of the keystore.
+    #[clap(
+        long = "keystore",
+        env = secrets::KEYSTORE_ENV,
+        value_parser,
+        default_value = (*DEFAULT_KEYSTORE_PATH).to_str().unwrap()
+    )]
+    keystore: PathBuf,
+
+    /// The sub-command of the key operation.
+    #[command(subcommand)]
+    subcommand: SubCommands,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum SubCommands {
+    /// Generates a new random key and stores it in the keystore.
+    Generate {
+        /// The name of the key.
+        name: String,
+    },
+    /// Imports the existing secret key into the keystore.
+    Import {
+        /// The name of the key.
+        name: String,
+        /// The hex encoded secret key, or the secret reference (`env:<VAR>` or `cmd:<COMMAND>`).
+        /// If not set, the secret key is requested interactively.
+        #[clap(long = "secret")]
+        secret: Option<String>,
+    },
+    /// Decrypts the key and prints its secret to stdout.
+    Export {
+        /// The name of the key.
+        name: String,
+    },
+    /// Lists names and addresses of keys in the keystore.
+    List,
+}
+
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    let keystore = Keystore::new(&command.keystore);
+    match command.subcommand {
+        SubCommands::Generate { name } => {
+            let passphrase = secrets::new_passphrase(&format!("the key `{name}`"))?;
+            let key = keystore.generate(&name, &passphrase)?;
+            println!("{}", serde_json::to_string_pretty(&key)?);
+        }
+        SubCommands::Import { name, secret } => {
+            let secret = match secret {
+                Some(secret) => secrets::resolve(&secret)?,
+                None => rpassword::prompt_password("Enter the secret key: ")
+                    .context("failed to read the secret key")?,
+            };
+            let secret = SecretKey::from_str(secret.trim())
+                .context("failed to parse the secret key")?;
+            let passphrase = secrets::new_passphrase(&format!("the key `{name}`"))?;
+            let key = keystore.insert(&name, &secret, &passphrase)?;
+            println!("{}", serde_json::to_string_pretty(&key)?);
+        }
+        SubCommands::Export { name } => {
+            let passphrase = secrets::passphrase(&format!("the key `{name}`"))?;
+            let secret = keystore.load(&name, &passphrase)?;
+            tracing::warn!("The secret of the key `{name}` is printed in plaintext");
+            println!("0x{}", hex::encode(secret.as_ref()));
+        }
+        SubCommands::List => {
+            println!("{}", serde_json::to_string_pretty(&keystore.list()?)?);
+        }
+    }
+    Ok(())
+}
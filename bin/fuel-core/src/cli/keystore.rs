@@ -0,0 +1,343 @@
+//! The encrypted on-disk keystore of secret keys used by the node.
+//!
+//! Each key is stored in the `<NAME>.json` file of the keystore directory.
+//! The secret key is encrypted with AES-256-GCM, and the encryption key is derived
+//! from the passphrase with scrypt. The address of the key is stored unencrypted,
+//! so keys can be listed without the passphrase.
+
+use aes_gcm::{
+    aead::{
+        Aead,
+        KeyInit,
+    },
+    Aes256Gcm,
+    Key,
+    Nonce,
+};
+use anyhow::{
+    anyhow,
+    Context,
+};
+use fuel_core::types::{
+    fuel_crypto::{
+        rand::{
+            rngs::StdRng,
+            RngCore,
+            SeedableRng,
+        },
+        SecretKey,
+    },
+    fuel_tx::{
+        Address,
+        Input,
+    },
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use std::{
+    fs,
+    io::Write,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+const VERSION: u32 = 1;
+const KDF: &str = "scrypt";
+const CIPHER: &str = "aes-256-gcm";
+const SALT_SIZE: usize = 32;
+const NONCE_SIZE: usize = 12;
+
+/// The cost parameters of the scrypt key derivation function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScryptParams {
+    pub log_n: u8,
+    pub r: u32,
+    pub p: u32,
+}
+
+impl Default for ScryptParams {
+    fn default() -> Self {
+        // Takes ~100ms and 32MB of memory on the modern hardware.
+        Self {
+            log_n: 15,
+            r: 8,
+            p: 1,
+        }
+    }
+}
+
+/// The key of the keystore without its secret.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct StoredKey {
+    pub name: String,
+    pub address: Address,
+}
+
+#[derive(Serialize, Deserialize)]
+struct KeyFile {
+    version: u32,
+    address: Address,
+    crypto: Crypto,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Crypto {
+    kdf: String,
+    kdfparams: ScryptParams,
+    /// The hex encoded salt of the key derivation.
+    salt: String,
+    cipher: String,
+    /// The hex encoded nonce of the cipher.
+    nonce: String,
+    /// The hex encoded encrypted secret key with the authentication tag.
+    ciphertext: String,
+}
+
+/// The directory of encrypted keys.
+#[derive(Debug, Clone)]
+pub struct Keystore {
+    dir: PathBuf,
+    params: ScryptParams,
+}
+
+impl Keystore {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self::with_params(dir, ScryptParams::default())
+    }
+
+    /// Creates the keystore that encrypts new keys using the `params`.
+    pub fn with_params(dir: impl Into<PathBuf>, params: ScryptParams) -> Self {
+        Self {
+            dir: dir.into(),
+            params,
+        }
+    }
+
+    /// Generates a new random key and stores it under the `name`.
+    pub fn generate(&self, name: &str, passphrase: &str) -> anyhow::Result<StoredKey> {
+        let secret = SecretKey::random(&mut StdRng::from_entropy());
+        self.insert(name, &secret, passphrase)
+    }
+
+    /// Encrypts the `secret` with the `passphrase` and stores it under the `name`.
+    /// Existing keys are never overwritten.
+    pub fn insert(
+        &self,
+        name: &str,
+        secret: &SecretKey,
+        passphrase: &str,
+    ) -> anyhow::Result<StoredKey> {
+        let path = self.path(name)?;
+        let mut rng = StdRng::from_entropy();
+        let mut salt = [0u8; SALT_SIZE];
+        rng.fill_bytes(&mut salt);
+        let mut nonce = [0u8; NONCE_SIZE];
+        rng.fill_bytes(&mut nonce);
+
+        let cipher = cipher(passphrase, &salt, self.params)?;
+        let ciphertext = cipher
+            .encrypt(Nonce::from_slice(&nonce), secret.as_ref())
+            .map_err(|_| anyhow!("failed to encrypt the key"))?;
+        let address = Input::owner(&secret.public_key());
+        let file = KeyFile {
+            version: VERSION,
+            address,
+            crypto: Crypto {
+                kdf: KDF.to_string(),
+                kdfparams: self.params,
+                salt: hex::encode(salt),
+                cipher: CIPHER.to_string(),
+                nonce: hex::encode(nonce),
+                ciphertext: hex::encode(ciphertext),
+            },
+        };
+
+        fs::create_dir_all(&self.dir).with_context(|| {
+            format!("failed to create the keystore {}", self.dir.display())
+        })?;
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        let mut writer = options.open(&path).with_context(|| {
+            format!("failed to create the key file {}", path.display())
+        })?;
+        writer.write_all(&serde_json::to_vec_pretty(&file)?)?;
+
+        Ok(StoredKey {
+            name: name.to_string(),
+            address,
+        })
+    }
+
+    /// Decrypts the key stored under the `name`.
+    pub fn load(&self, name: &str, passphrase: &str) -> anyhow::Result<SecretKey> {
+        let path = self.path(name)?;
+        let file = read_key_file(&path)?;
+        let crypto = &file.crypto;
+        if crypto.kdf != KDF || crypto.cipher != CIPHER {
+            return Err(anyhow!(
+                "the key file {} uses the unsupported `{}` key derivation or `{}` cipher",
+                path.display(),
+                crypto.kdf,
+                crypto.cipher
+            ))
+        }
+        let salt = hex::decode(&crypto.salt).context("invalid salt")?;
+        let nonce = hex::decode(&crypto.nonce).context("invalid nonce")?;
+        if nonce.len() != NONCE_SIZE {
+            return Err(anyhow!("invalid nonce size {}", nonce.len()))
+        }
+        let ciphertext = hex::decode(&crypto.ciphertext).context("invalid ciphertext")?;
+
+        let cipher = cipher(passphrase, &salt, crypto.kdfparams)?;
+        let plaintext = cipher
+            .decrypt(Nonce::from_slice(&nonce), ciphertext.as_slice())
+            .map_err(|_| {
+                anyhow!(
+                    "failed to decrypt the key `{name}`, the passphrase is wrong \
+                     or the key file is corrupted"
+                )
+            })?;
+        let secret = SecretKey::try_from(plaintext.as_slice())
+            .map_err(|e| anyhow!("the key `{name}` is invalid: {e}"))?;
+        if Input::owner(&secret.public_key()) != file.address {
+            return Err(anyhow!(
+                "the key `{name}` doesn't match the address of its key file"
+            ))
+        }
+        Ok(secret)
+    }
+
+    /// Returns keys of the keystore sorted by their names.
+    pub fn list(&self) -> anyhow::Result<Vec<StoredKey>> {
+        if !self.dir.exists() {
+            return Ok(vec![])
+        }
+        let mut keys = vec![];
+        for entry in fs::read_dir(&self.dir).with_context(|| {
+            format!("failed to read the keystore {}", self.dir.display())
+        })? {
+            let path = entry?.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+                continue
+            }
+            let Some(name) = path.file_stem().and_then(|name| name.to_str()) else {
+                continue
+            };
+            let file = read_key_file(&path)?;
+            keys.push(StoredKey {
+                name: name.to_string(),
+                address: file.address,
+            });
+        }
+        keys.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(keys)
+    }
+
+    fn path(&self, name: &str) -> anyhow::Result<PathBuf> {
+        let valid = !name.is_empty()
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+        if !valid {
+            return Err(anyhow!(
+                "invalid key name `{name}`, only ASCII letters, digits, `-` and `_` are allowed"
+            ))
+        }
+        Ok(self.dir.join(format!("{name}.json")))
+    }
+}
+
+fn read_key_file(path: &Path) -> anyhow::Result<KeyFile> {
+    let content = fs::read(path)
+        .with_context(|| format!("failed to read the key file {}", path.display()))?;
+    let file: KeyFile = serde_json::from_slice(&content)
+        .with_context(|| format!("failed to parse the key file {}", path.display()))?;
+    if file.version != VERSION {
+        return Err(anyhow!(
+            "the key file {} has the unsupported version {}",
+            path.display(),
+            file.version
+        ))
+    }
+    Ok(file)
+}
+
+fn cipher(
+    passphrase: &str,
+    salt: &[u8],
+    params: ScryptParams,
+) -> anyhow::Result<Aes256Gcm> {
+    let params = scrypt::Params::new(params.log_n, params.r, params.p)
+        .map_err(|e| anyhow!("invalid scrypt parameters: {e}"))?;
+    let mut key = [0u8; 32];
+    scrypt::scrypt(passphrase.as_bytes(), salt, &params, &mut key)
+        .map_err(|e| anyhow!("failed to derive the encryption key: {e}"))?;
+    Ok(Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keystore(dir: &Path) -> Keystore {
+        Keystore::with_params(
+            dir,
+            ScryptParams {
+                log_n: 4,
+                r: 8,
+                p: 1,
+            },
+        )
+    }
+
+    #[test]
+    fn inserted_key_is_loaded_with_the_same_passphrase() {
+        let dir = tempfile::tempdir().unwrap();
+        let keystore = keystore(dir.path());
+        let secret = SecretKey::random(&mut StdRng::seed_from_u64(1));
+
+        let stored = keystore.insert("consensus", &secret, "passphrase").unwrap();
+
+        assert_eq!(stored.address, Input::owner(&secret.public_key()));
+        assert_eq!(keystore.load("consensus", "passphrase").unwrap(), secret);
+        assert!(keystore.load("consensus", "wrong").is_err());
+    }
+
+    #[test]
+    fn existing_key_is_not_overwritten() {
+        let dir = tempfile::tempdir().unwrap();
+        let keystore = keystore(dir.path());
+        let secret = keystore.generate("p2p", "passphrase").unwrap();
+
+        assert!(keystore.generate("p2p", "passphrase").is_err());
+        assert_eq!(keystore.list().unwrap(), vec![secret]);
+    }
+
+    #[test]
+    fn keys_are_listed_by_name() {
+        let dir = tempfile::tempdir().unwrap();
+        let keystore = keystore(dir.path());
+        let second = keystore.generate("second", "passphrase").unwrap();
+        let first = keystore.generate("first", "passphrase").unwrap();
+
+        assert_eq!(keystore.list().unwrap(), vec![first, second]);
+    }
+
+    #[test]
+    fn key_name_is_not_a_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let keystore = keystore(dir.path());
+
+        assert!(keystore.generate("../key", "passphrase").is_err());
+        assert!(keystore.generate("", "passphrase").is_err());
+    }
+}
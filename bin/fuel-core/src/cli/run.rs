@@ -1,7 +1,11 @@
 #![allow(unused_variables)]
 use crate::{
     cli::{
-        run::consensus::PoATriggerArgs,
+        run::consensus::{
+            PoATriggerArgs,
+            SignerArgs,
+        },
+        secrets,
         DEFAULT_DB_PATH,
     },
     FuelService,
@@ -10,7 +14,10 @@ use anyhow::{
     anyhow,
     Context,
 };
-use clap::Parser;
+use clap::{
+    Parser,
+    ValueEnum,
+};
 use fuel_core::{
     chain_config::{
         default_consensus_dev_key,
@@ -18,14 +25,21 @@ use fuel_core::{
     },
     producer::Config as ProducerConfig,
     service::{
-        config::Trigger,
+        config::{
+            MaintenanceEntry,
+            Trigger,
+            VerificationScope,
+        },
         Config,
         DbType,
         RelayerConsensusConfig,
         ServiceTrait,
         VMConfig,
     },
-    txpool::Config as TxPoolConfig,
+    txpool::{
+        config::SubscriptionLimits,
+        Config as TxPoolConfig,
+    },
     types::{
         blockchain::primitives::SecretKeyWrapper,
         fuel_tx::ContractId,
@@ -61,9 +75,15 @@ const DEFAULT_DATABASE_CACHE_SIZE: usize = 1024 * 1024 * 1024;
 mod p2p;
 
 mod consensus;
+mod gas_price;
+mod graphql;
+mod node_identity;
 mod profiling;
 #[cfg(feature = "relayer")]
 mod relayer;
+mod replica;
+mod replication;
+pub mod runtime;
 
 /// Run the Fuel client node locally.
 #[derive(Debug, Clone, Parser)]
@@ -104,6 +124,40 @@ pub struct Command {
     )]
     pub database_type: DbType,
 
+    /// Opens the database at the `--db-path` without the write capability and only serves
+    /// the GraphQL API. The txpool, the block production, the synchronization and
+    /// the relayer are disabled, so the data directory can be inspected without
+    /// the risk of modifying it.
+    #[clap(
+        long = "db-read-only",
+        conflicts_with_all = &["replica", "standby_of", "replication_listen_addr"],
+        env
+    )]
+    pub db_read_only: bool,
+
+    #[clap(flatten)]
+    pub replica: replica::ReplicaArgs,
+
+    #[clap(flatten)]
+    pub replication: replication::ReplicationArgs,
+
+    /// Runs the node as the only block producer of the network without the P2P network
+    /// and the synchronization. The node fails to start if the P2P is enabled or
+    /// the block production is disabled.
+    #[clap(
+        long = "single-node",
+        conflicts_with_all = &["replica", "standby_of", "db_read_only"],
+        env
+    )]
+    pub single_node: bool,
+
+    /// The number of blocks on top of the block with the transaction after which
+    /// the transaction status reports the transaction as finalized. The `0` means
+    /// the instant finality of the block production. If not set, transactions
+    /// are reported as included or confirmed only.
+    #[clap(long = "tx-finality-depth", env)]
+    pub tx_finality_depth: Option<u32>,
+
     /// Specify either an alias to a built-in configuration or filepath to a JSON file.
     #[arg(
         name = "CHAIN_CONFIG",
@@ -113,6 +167,15 @@ pub struct Command {
     )]
     pub chain_config: String,
 
+    /// Enables the canary mode of the consensus parameters. Each imported block is also
+    /// executed with the consensus parameters of the specified chain configuration
+    /// (an alias or filepath to a JSON file) by the same executor. The canary runs in
+    /// the background on the state history. Divergences from the canonical execution
+    /// are reported and don't affect the canonical result.
+    #[cfg(feature = "consensus-parameters-canary")]
+    #[arg(long = "consensus-parameters-canary-chain", env)]
+    pub consensus_parameters_canary_chain: Option<String>,
+
     /// Should be used for local development only. Enabling debug mode:
     /// - Allows GraphQL Endpoints to arbitrarily advance blocks.
     /// - Enables debugger GraphQL Endpoints.
@@ -120,6 +183,17 @@ pub struct Command {
     #[arg(long = "debug", env)]
     pub debug: bool,
 
+    /// The number of the latest blocks, the transactions of which can be traced
+    /// by the debugger, or which can be checked by the consensus parameters canary.
+    /// The node keeps the state before each of them in memory.
+    #[arg(long = "state-history-blocks", default_value = "64", env)]
+    pub state_history_blocks: usize,
+
+    /// The directory where the debugger writes the dumps of the TxPool.
+    /// The dumps are disabled if it is not specified.
+    #[arg(long = "tx-pool-dump-dir", env)]
+    pub tx_pool_dump_dir: Option<PathBuf>,
+
     /// Enable logging of backtraces from vm errors
     #[arg(long = "vm-backtrace", env)]
     pub vm_backtrace: bool,
@@ -133,8 +207,12 @@ pub struct Command {
     #[arg(long = "min-gas-price", default_value = "0", env)]
     pub min_gas_price: u64,
 
+    #[clap(flatten)]
+    pub gas_price: gas_price::GasPriceArgs,
+
     /// The signing key used when producing blocks.
-    /// Setting via the `CONSENSUS_KEY_SECRET` ENV var is preferred.
+    /// Setting via the `CONSENSUS_KEY_SECRET` ENV var or a secret reference
+    /// (`env:<VAR>`, `keystore:<PATH>`, `key:<NAME>` or `cmd:<COMMAND>`) is preferred.
     #[arg(long = "consensus-key", env)]
     pub consensus_key: Option<String>,
 
@@ -142,6 +220,9 @@ pub struct Command {
     #[clap(flatten)]
     pub poa_trigger: PoATriggerArgs,
 
+    #[clap(flatten)]
+    pub consensus_signer: SignerArgs,
+
     /// The block's fee recipient public key.
     ///
     /// If not set, `consensus_key` is used as the provider of the `Address`.
@@ -185,6 +266,35 @@ pub struct Command {
     #[clap(long = "tx-number-active-subscriptions", default_value = "4064", env)]
     pub tx_number_active_subscriptions: usize,
 
+    /// The maximum number of active subscriptions of a single client of the API.
+    /// Clients are identified by the API key or by the IP address. When the client
+    /// reaches the limit, its least recently updated subscription is dropped.
+    /// The subscriptions shared with other clients, like the status or the activity
+    /// subscriptions, are limited separately, and the new ones are rejected.
+    #[clap(long = "tx-max-subscriptions-per-client", default_value = "64", env)]
+    pub tx_max_subscriptions_per_client: usize,
+
+    /// Subscriptions that haven't received any status update during this period are dropped.
+    #[clap(long = "tx-subscription-idle-timeout", default_value = "5m", env)]
+    pub tx_subscription_idle_timeout: humantime::Duration,
+
+    /// The maximum total size in bytes of transactions whose verified predicates
+    /// are cached by the `TxPool`. The cache skips the repeated verification of predicates
+    /// of the same transaction.
+    #[clap(long = "tx-predicate-cache-size", default_value = "67108864", env)]
+    pub tx_predicate_cache_size: usize,
+
+    /// The maximum total size in bytes of recently received transactions whose verified
+    /// signatures are cached by the `TxPool`. The cache skips the repeated verification of
+    /// signatures of duplicates of the transaction, like retries via the API or gossip
+    /// from several peers.
+    #[clap(long = "tx-signature-cache-size", default_value = "16777216", env)]
+    pub tx_signature_cache_size: usize,
+
+    /// For how long transactions with verified signatures are cached.
+    #[clap(long = "tx-signature-cache-ttl", default_value = "30s", env)]
+    pub tx_signature_cache_ttl: humantime::Duration,
+
     /// The number of reserved peers to connect to before starting to sync.
     #[clap(long = "min-connected-reserved-peers", default_value = "0", env)]
     pub min_connected_reserved_peers: usize,
@@ -201,8 +311,46 @@ pub struct Command {
     #[clap(long = "api-request-timeout", default_value = "30m", env)]
     pub api_request_timeout: humantime::Duration,
 
+    #[clap(flatten)]
+    pub graphql: graphql::GraphQLArgs,
+
+    #[clap(flatten)]
+    pub node_identity: node_identity::NodeIdentityArgs,
+
+    /// The maximum time to wait for the commit of the block in progress during the shutdown.
+    #[clap(long = "shutdown-timeout", default_value = "30s", env)]
+    pub shutdown_timeout: humantime::Duration,
+
+    /// The node is reported as not ready by the `/v1/readiness` endpoint
+    /// if it doesn't import a block within this time.
+    #[clap(long = "health-stall-timeout", env)]
+    pub health_stall_timeout: Option<humantime::Duration>,
+
+    /// The maintenance tasks executed by the node on the schedule, separated by `;`.
+    /// Each entry is the cron schedule `minute hour day-of-month month day-of-week` in UTC
+    /// followed by the task: `compact`, `backup <DIR>`, `snapshot <DIR>` or `verify-integrity`.
+    /// For example, `0 3 * * * compact;0 4 * * 0 backup /var/backups/fuel`.
+    /// The status of tasks is served by the `/v1/maintenance` endpoint to the clients
+    /// with one of the `--graphql-api-keys` in the `x-api-key` header.
+    #[clap(long = "maintenance", value_delimiter = ';', env)]
+    pub maintenance: Vec<MaintenanceEntry>,
+
+    /// Verifies blocks and contracts against their merkle roots on the start of the node:
+    /// `sample` verifies random entries, `full` verifies all of them. The result with
+    /// the estimated corruption rate of the database is logged.
+    #[clap(long = "startup-verification", default_value = "off", value_enum, env)]
+    pub startup_verification: StartupVerification,
+
+    /// The number of random blocks and the number of random contracts verified
+    /// by the `sample` startup verification.
+    #[clap(long = "startup-verification-sample-size", default_value = "1000", env)]
+    pub startup_verification_sample_size: usize,
+
     #[clap(flatten)]
     pub profiling: profiling::ProfilingArgs,
+
+    #[clap(flatten)]
+    pub runtime: runtime::RuntimeArgs,
 }
 
 impl Command {
@@ -214,13 +362,24 @@ impl Command {
             max_database_cache_size,
             database_path,
             database_type,
+            db_read_only,
+            replica,
+            replication,
+            single_node,
+            tx_finality_depth,
             chain_config,
+            #[cfg(feature = "consensus-parameters-canary")]
+            consensus_parameters_canary_chain,
             vm_backtrace,
             debug,
+            state_history_blocks,
+            tx_pool_dump_dir,
             utxo_validation,
             min_gas_price,
+            gas_price,
             consensus_key,
             poa_trigger,
+            consensus_signer,
             coinbase_recipient,
             #[cfg(feature = "relayer")]
             relayer_args,
@@ -235,16 +394,54 @@ impl Command {
             tx_max_number,
             tx_max_depth,
             tx_number_active_subscriptions,
+            tx_max_subscriptions_per_client,
+            tx_subscription_idle_timeout,
+            tx_predicate_cache_size,
+            tx_signature_cache_size,
+            tx_signature_cache_ttl,
             min_connected_reserved_peers,
             time_until_synced,
             query_log_threshold_time,
             api_request_timeout,
+            graphql,
+            node_identity,
+            shutdown_timeout,
+            health_stall_timeout,
+            maintenance,
+            startup_verification,
+            startup_verification_sample_size,
             profiling: _,
+            runtime: _,
         } = self;
 
         let addr = net::SocketAddr::new(ip, port);
 
+        let replica = replica.into_config(&database_path);
+        if replica.is_some() && database_type != DbType::RocksDb {
+            return Err(anyhow!(
+                "The replica mode requires the `rocks-db` database type"
+            ))
+        }
+
+        if db_read_only && database_type != DbType::RocksDb {
+            return Err(anyhow!(
+                "The read-only mode requires the `rocks-db` database type"
+            ))
+        }
+
+        let replication = replication.into_config();
+
         let chain_conf: ChainConfig = chain_config.as_str().parse()?;
+        let gas_price_oracle =
+            gas_price.into_oracle(min_gas_price, chain_conf.block_gas_limit);
+
+        #[cfg(feature = "consensus-parameters-canary")]
+        let consensus_parameters_canary = consensus_parameters_canary_chain
+            .map(|chain| -> anyhow::Result<_> {
+                let chain: ChainConfig = chain.as_str().parse()?;
+                Ok(chain.consensus_parameters)
+            })
+            .transpose()?;
 
         #[cfg(feature = "relayer")]
         let relayer_cfg = relayer_args.into_config();
@@ -252,6 +449,7 @@ impl Command {
         #[cfg(feature = "p2p")]
         let p2p_cfg = p2p_args.into_config(chain_conf.chain_name.clone(), metrics)?;
 
+        poa_trigger.validate()?;
         let trigger: Trigger = poa_trigger.into();
 
         if trigger != Trigger::Never {
@@ -278,6 +476,9 @@ impl Command {
                 None
             }
         });
+        let consensus_signer =
+            consensus_signer.into_signer(consensus_key.clone(), &chain_conf.consensus)?;
+        let node_identity = node_identity.into_config(consensus_key.as_ref())?;
 
         let coinbase_recipient = if let Some(coinbase_recipient) = coinbase_recipient {
             Some(
@@ -300,11 +501,23 @@ impl Command {
         let config = Config {
             addr,
             api_request_timeout: api_request_timeout.into(),
+            graphql_max_depth: graphql.graphql_max_depth,
+            graphql_max_complexity: graphql.graphql_max_complexity,
+            graphql_rate_limit: graphql.rate_limit(),
+            graphql_max_batch_size: graphql.graphql_max_batch_size,
+            graphql_api_keys: graphql.graphql_api_keys,
             max_database_cache_size,
             database_path,
             database_type,
+            replica,
+            replication,
+            db_read_only,
+            single_node,
+            tx_finality_depth,
             chain_conf: chain_conf.clone(),
             debug,
+            state_history_blocks,
+            tx_pool_dump_dir,
             utxo_validation,
             block_production: trigger,
             vm: VMConfig {
@@ -315,10 +528,18 @@ impl Command {
                 tx_max_depth,
                 chain_conf,
                 min_gas_price,
+                gas_price_oracle,
                 utxo_validation,
                 metrics,
                 tx_pool_ttl.into(),
                 tx_number_active_subscriptions,
+                SubscriptionLimits {
+                    max_per_client: tx_max_subscriptions_per_client,
+                    idle_timeout: tx_subscription_idle_timeout.into(),
+                },
+                tx_predicate_cache_size,
+                tx_signature_cache_size,
+                tx_signature_cache_ttl.into(),
             ),
             block_producer: ProducerConfig {
                 utxo_validation,
@@ -326,6 +547,8 @@ impl Command {
                 metrics,
             },
             block_importer,
+            #[cfg(feature = "consensus-parameters-canary")]
+            consensus_parameters_canary,
             #[cfg(feature = "relayer")]
             relayer: relayer_cfg,
             #[cfg(feature = "p2p")]
@@ -333,16 +556,41 @@ impl Command {
             #[cfg(feature = "p2p")]
             sync: sync_args.into(),
             consensus_key,
+            consensus_signer,
+            node_identity,
             name,
             relayer_consensus_config: verifier,
             min_connected_reserved_peers,
             time_until_synced: time_until_synced.into(),
             query_log_threshold_time: query_log_threshold_time.into(),
+            shutdown_timeout: shutdown_timeout.into(),
+            health_stall_timeout: health_stall_timeout.map(Into::into),
+            hooks: Default::default(),
+            maintenance,
+            startup_verification: startup_verification
+                .into_scope(startup_verification_sample_size),
         };
         Ok(config)
     }
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum StartupVerification {
+    Off,
+    Sample,
+    Full,
+}
+
+impl StartupVerification {
+    fn into_scope(self, sample_size: usize) -> Option<VerificationScope> {
+        match self {
+            StartupVerification::Off => None,
+            StartupVerification::Sample => Some(VerificationScope::Sample(sample_size)),
+            StartupVerification::Full => Some(VerificationScope::Full),
+        }
+    }
+}
+
 pub async fn exec(command: Command) -> anyhow::Result<()> {
     let profiling = command.profiling.clone();
     let config = command.get_config()?;
@@ -373,11 +621,16 @@ fn load_consensus_key(
     cli_arg: Option<String>,
 ) -> anyhow::Result<Option<Secret<SecretKeyWrapper>>> {
     let secret_string = if let Some(cli_arg) = cli_arg {
-        warn!("Consensus key configured insecurely using cli args. Consider setting the {} env var instead.", CONSENSUS_KEY_ENV);
+        if !secrets::is_reference(&cli_arg) {
+            warn!("Consensus key configured insecurely using cli args. Consider setting the {} env var or using a secret reference instead.", CONSENSUS_KEY_ENV);
+        }
         Some(cli_arg)
     } else {
         env::var(CONSENSUS_KEY_ENV).ok()
     };
+    let secret_string = secret_string
+        .map(|secret| secrets::resolve(&secret))
+        .transpose()?;
 
     if let Some(key) = secret_string {
         let key =
@@ -1,11 +1,37 @@
 //! Clap configuration related to consensus parameters
 
+use crate::cli::secrets;
+use anyhow::{
+    anyhow,
+    Context,
+};
 use clap::{
     ArgGroup,
     ValueEnum,
 };
-use fuel_core::service::config::Trigger as PoATrigger;
+use fuel_core::{
+    chain_config::ConsensusConfig,
+    poa::signer::{
+        BlockSigner,
+        KeyRotation,
+        LocalSigner,
+    },
+    service::{
+        adapters::consensus_module::remote_signer::RemoteSigner,
+        config::Trigger as PoATrigger,
+    },
+    types::{
+        blockchain::primitives::SecretKeyWrapper,
+        fuel_crypto::SecretKey,
+        fuel_types::BlockHeight,
+        secrecy::Secret,
+    },
+};
 use humantime::Duration;
+use std::{
+    str::FromStr,
+    sync::Arc,
+};
 
 #[derive(Debug, Clone, clap::Args)]
 pub struct PoATriggerArgs {
@@ -13,6 +39,8 @@ pub struct PoATriggerArgs {
     instant: Instant,
     #[clap(flatten)]
     interval: Interval,
+    #[clap(flatten)]
+    hybrid: Hybrid,
 }
 
 // Convert from arg struct to PoATrigger enum
@@ -25,6 +53,17 @@ impl From<PoATriggerArgs> for PoATrigger {
             } => PoATrigger::Interval {
                 block_time: p.into(),
             },
+            PoATriggerArgs {
+                hybrid:
+                    Hybrid {
+                        min_block_time: Some(min_block_time),
+                        max_block_time: Some(max_block_time),
+                    },
+                ..
+            } => PoATrigger::Hybrid {
+                min_block_time: min_block_time.into(),
+                max_block_time: max_block_time.into(),
+            },
             PoATriggerArgs { instant, .. } if instant.instant == Boolean::True => {
                 PoATrigger::Instant
             }
@@ -35,7 +74,7 @@ impl From<PoATriggerArgs> for PoATrigger {
 
 #[derive(Debug, Clone, clap::Args)]
 #[clap(
-    group = ArgGroup::new("instant-mode").args(&["instant"]).conflicts_with_all(&["interval-mode"]),
+    group = ArgGroup::new("instant-mode").args(&["instant"]).conflicts_with_all(&["interval-mode", "hybrid-mode"]),
 )]
 struct Instant {
     /// Use instant block production mode.
@@ -53,7 +92,7 @@ enum Boolean {
 
 #[derive(Debug, Clone, clap::Args)]
 #[clap(
-    group = ArgGroup::new("interval-mode").args(&["period"]).conflicts_with_all(&["instant-mode"]),
+    group = ArgGroup::new("interval-mode").args(&["period"]).conflicts_with_all(&["instant-mode", "hybrid-mode"]),
 )]
 struct Interval {
     /// Interval trigger option.
@@ -63,6 +102,135 @@ struct Interval {
     pub period: Option<Duration>,
 }
 
+#[derive(Debug, Clone, clap::Args)]
+#[clap(
+    group = ArgGroup::new("hybrid-mode").args(&["min_block_time", "max_block_time"]).multiple(true).conflicts_with_all(&["instant-mode", "interval-mode"]),
+)]
+struct Hybrid {
+    /// Hybrid trigger option.
+    /// Newly submitted txs trigger the production of the next block, but not earlier
+    /// than the minimal block time after the previous block.
+    /// Requires `--poa-hybrid-max-block-time`. Cannot be combined with other poa flags.
+    #[clap(long = "poa-hybrid-min-block-time", requires = "max_block_time", env)]
+    pub min_block_time: Option<Duration>,
+
+    /// Hybrid trigger option.
+    /// An empty block is produced if no blocks were produced during the maximal block time.
+    /// Requires `--poa-hybrid-min-block-time`. Cannot be combined with other poa flags.
+    #[clap(long = "poa-hybrid-max-block-time", requires = "min_block_time", env)]
+    pub max_block_time: Option<Duration>,
+}
+
+impl Hybrid {
+    fn validate(&self) -> anyhow::Result<()> {
+        if let (Some(min), Some(max)) = (self.min_block_time, self.max_block_time) {
+            if *min > *max {
+                return Err(anyhow!(
+                    "`--poa-hybrid-min-block-time` should not be greater than `--poa-hybrid-max-block-time`"
+                ))
+            }
+        }
+        Ok(())
+    }
+}
+
+impl PoATriggerArgs {
+    /// Checks the consistency of the trigger options that can't be expressed by `clap`.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        self.hybrid.validate()
+    }
+}
+
+#[derive(Debug, Clone, clap::Args)]
+pub struct SignerArgs {
+    /// The URL of the external signing service used to sign produced blocks.
+    /// If set, the node doesn't use the consensus key for signing.
+    /// The `http` scheme is allowed only for the loopback host.
+    #[arg(long = "consensus-signer-url", env)]
+    pub signer_url: Option<String>,
+
+    /// The bearer token used to authenticate requests to the external signing services.
+    /// The token can be a secret reference(`env:<VAR>`, `keystore:<PATH>`,
+    /// `key:<NAME>` or `cmd:<COMMAND>`).
+    #[arg(long = "consensus-signer-token", env)]
+    pub signer_token: Option<String>,
+
+    /// The timeout of requests to the external signing service.
+    #[arg(long = "consensus-signer-timeout", default_value = "10s", env)]
+    pub signer_timeout: Duration,
+
+    /// The schedule of the consensus key rotation. Each entry has the format
+    /// `<HEIGHT>=<SIGNER>`, where `<SIGNER>` is either a secret key or a URL of the
+    /// external signing service used to sign blocks starting from the `<HEIGHT>`.
+    /// The `<SIGNER>` can be a secret reference(`env:<VAR>`, `keystore:<PATH>`,
+    /// `key:<NAME>` or `cmd:<COMMAND>`). Setting via the `CONSENSUS_KEY_ROTATION` ENV var is preferred.
+    /// The schedule should match the `PoAKeyRotation` consensus of the chain config,
+    /// otherwise other nodes reject produced blocks.
+    #[arg(long = "consensus-key-rotation", value_delimiter = ',', env)]
+    pub key_rotation: Vec<String>,
+}
+
+impl SignerArgs {
+    /// Builds the signer of blocks. The signer from the `consensus_key` or
+    /// the signing service is used until the first rotation. Signatures of
+    /// the signing services are verified against the authority keys of the `consensus`.
+    pub fn into_signer(
+        self,
+        consensus_key: Option<Secret<SecretKeyWrapper>>,
+        consensus: &ConsensusConfig,
+    ) -> anyhow::Result<Option<Arc<dyn BlockSigner>>> {
+        let timeout = self.signer_timeout.into();
+        let token = self
+            .signer_token
+            .map(|token| secrets::resolve(&token))
+            .transpose()?;
+        let remote_signer = |url: &str| -> anyhow::Result<Arc<dyn BlockSigner>> {
+            let signer = RemoteSigner::new(url, timeout, consensus.clone())?;
+            let signer = match &token {
+                Some(token) => signer.with_bearer_token(Secret::new(token.clone())),
+                None => signer,
+            };
+            Ok(Arc::new(signer))
+        };
+        let signer: Option<Arc<dyn BlockSigner>> = match self.signer_url {
+            Some(url) => {
+                let url = secrets::resolve(&url)?;
+                Some(remote_signer(&url)?)
+            }
+            None => consensus_key
+                .map(|key| Arc::new(LocalSigner::new(key)) as Arc<dyn BlockSigner>),
+        };
+
+        if self.key_rotation.is_empty() {
+            return Ok(signer)
+        }
+
+        let mut rotation = KeyRotation::new();
+        if let Some(signer) = signer {
+            rotation = rotation.rotate_at(BlockHeight::from(0u32), signer);
+        }
+        for entry in self.key_rotation {
+            let (height, signer) = entry.split_once('=').ok_or_else(|| {
+                anyhow!("the rotation entry should be `<HEIGHT>=<SIGNER>`")
+            })?;
+            let height: u32 = height
+                .parse()
+                .context("failed to parse the height of the key rotation")?;
+            let signer = secrets::resolve(signer)?;
+            let signer: Arc<dyn BlockSigner> =
+                if signer.starts_with("http://") || signer.starts_with("https://") {
+                    remote_signer(&signer)?
+                } else {
+                    let key = SecretKey::from_str(&signer)
+                        .context("failed to parse the key of the key rotation")?;
+                    Arc::new(LocalSigner::new(Secret::new(key.into())))
+                };
+            rotation = rotation.rotate_at(height.into(), signer);
+        }
+        Ok(Some(Arc::new(rotation)))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -81,9 +249,52 @@ mod tests {
     #[test_case(&["", "--poa-instant=false"] => Ok(Trigger::Never); "never trigger if instant is explicitly disabled")]
     #[test_case(&["", "--poa-interval-period=1s"] => Ok(Trigger::Interval { block_time: StdDuration::from_secs(1)}); "uses interval mode if set")]
     #[test_case(&["", "--poa-instant=true", "--poa-interval-period=1s"] => Err(()); "can't set interval and instant at the same time")]
+    #[test_case(&["", "--poa-hybrid-min-block-time=100ms", "--poa-hybrid-max-block-time=2s"] => Ok(Trigger::Hybrid { min_block_time: StdDuration::from_millis(100), max_block_time: StdDuration::from_secs(2)}); "uses hybrid mode if set")]
+    #[test_case(&["", "--poa-hybrid-min-block-time=100ms"] => Err(()); "hybrid mode requires max block time")]
+    #[test_case(&["", "--poa-hybrid-min-block-time=3s", "--poa-hybrid-max-block-time=2s"] => Err(()); "hybrid min block time can't exceed max block time")]
+    #[test_case(&["", "--poa-interval-period=1s", "--poa-hybrid-min-block-time=100ms", "--poa-hybrid-max-block-time=2s"] => Err(()); "can't set interval and hybrid at the same time")]
     fn parse(args: &[&str]) -> Result<Trigger, ()> {
-        Command::try_parse_from(args)
+        let command = Command::try_parse_from(args).map_err(|_| ())?;
+        command.trigger.validate().map_err(|_| ())?;
+        Ok(command.trigger.into())
+    }
+
+    #[derive(Debug, Clone, Parser)]
+    pub struct SignerCommand {
+        #[clap(flatten)]
+        signer: SignerArgs,
+    }
+
+    const KEY: &str =
+        "0xa449b1ffee0e2205fa924c6740cc48b3b473aa28587df6dab12abc245d1f5298";
+
+    #[test_case(&[""] => Ok(false); "no signer without key")]
+    #[test_case(&["", "--consensus-signer-url=http://127.0.0.1:4001"] => Ok(true); "external signer")]
+    #[test_case(&["", "--consensus-signer-url=http://127.0.0.1:4001", "--consensus-signer-token=token"] => Ok(true); "external signer with token")]
+    #[test_case(&["", "--consensus-signer-url=http://10.0.0.1:4001"] => Err(()); "external signer over http to remote host")]
+    #[test_case(&["", "--consensus-key-rotation=10=0xa449b1ffee0e2205fa924c6740cc48b3b473aa28587df6dab12abc245d1f5298"] => Ok(true); "rotation with key")]
+    #[test_case(&["", "--consensus-key-rotation=10,http://127.0.0.1:4001"] => Err(()); "rotation without height")]
+    #[test_case(&["", "--consensus-key-rotation=ten=http://127.0.0.1:4001"] => Err(()); "rotation with invalid height")]
+    fn parse_signer(args: &[&str]) -> Result<bool, ()> {
+        SignerCommand::try_parse_from(args)
+            .map_err(|_| ())?
+            .signer
+            .into_signer(None, &ConsensusConfig::default_poa())
             .map_err(|_| ())
-            .map(|c| c.trigger.into())
+            .map(|signer| signer.is_some())
+    }
+
+    #[test]
+    fn rotation_is_unavailable_before_first_height_without_consensus_key() {
+        let args = ["".to_string(), format!("--consensus-key-rotation=10={KEY}")];
+        let signer = SignerCommand::try_parse_from(args)
+            .unwrap()
+            .signer
+            .into_signer(None, &ConsensusConfig::default_poa())
+            .unwrap()
+            .unwrap();
+
+        assert!(!signer.is_available(&5u32.into()));
+        assert!(signer.is_available(&10u32.into()));
     }
 }
@@ -0,0 +1,109 @@
+use clap::{
+    Args,
+    ValueEnum,
+};
+use fuel_core::txpool::{
+    gas_price::{
+        BlockFullnessConfig,
+        BlockFullnessGasPrice,
+    },
+    ports::GasPriceOracle,
+};
+use std::sync::Arc;
+
+#[derive(Debug, Clone, Args)]
+pub struct GasPriceArgs {
+    /// The source of the minimum gas price of transactions accepted into the pool
+    /// and included into produced blocks.
+    #[clap(long = "gas-price-oracle", default_value = "static", value_enum, env)]
+    pub gas_price_oracle: GasPriceOracleKind,
+
+    /// The gas used by the block, in percents of the block gas limit, at which
+    /// the `block-fullness` oracle keeps the price.
+    #[clap(
+        long = "gas-price-target-fullness",
+        default_value = "50",
+        value_parser = clap::value_parser!(u8).range(1..=100),
+        env
+    )]
+    pub gas_price_target_fullness: u8,
+
+    /// The maximum change of the price by the `block-fullness` oracle
+    /// after one block, in percents.
+    #[clap(
+        long = "gas-price-max-change",
+        default_value = "12",
+        value_parser = clap::value_parser!(u8).range(0..=100),
+        env
+    )]
+    pub gas_price_max_change: u8,
+
+    /// The number of the latest blocks the `block-fullness` oracle derives
+    /// the price from.
+    #[clap(
+        long = "gas-price-history-blocks",
+        default_value = "64",
+        value_parser = clap::value_parser!(u32).range(1..),
+        env
+    )]
+    pub gas_price_history_blocks: u32,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum GasPriceOracleKind {
+    /// The minimum gas price is always the `--min-gas-price`.
+    Static,
+    /// The minimum gas price follows the fullness of blocks, similar to EIP-1559.
+    /// It never falls below the `--min-gas-price`.
+    BlockFullness,
+}
+
+impl GasPriceArgs {
+    pub fn into_oracle(
+        self,
+        min_gas_price: u64,
+        block_gas_limit: u64,
+    ) -> Option<Arc<dyn GasPriceOracle>> {
+        match self.gas_price_oracle {
+            GasPriceOracleKind::Static => None,
+            GasPriceOracleKind::BlockFullness => {
+                let config = BlockFullnessConfig {
+                    floor: min_gas_price,
+                    target_fullness_percent: self.gas_price_target_fullness,
+                    max_change_percent: self.gas_price_max_change,
+                    history_blocks: self.gas_price_history_blocks,
+                };
+                Some(Arc::new(BlockFullnessGasPrice::new(
+                    config,
+                    block_gas_limit,
+                )))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+    use test_case::test_case;
+
+    #[derive(Debug, Clone, Parser)]
+    pub struct Command {
+        #[clap(flatten)]
+        gas_price: GasPriceArgs,
+    }
+
+    #[test_case(&[""] => Ok(None); "static by default")]
+    #[test_case(&["", "--gas-price-oracle=block-fullness"] => Ok(Some(7)); "block fullness starts from the min gas price")]
+    #[test_case(&["", "--gas-price-oracle=block-fullness", "--gas-price-target-fullness=0"] => Err(()); "zero target fullness")]
+    #[test_case(&["", "--gas-price-oracle=block-fullness", "--gas-price-max-change=101"] => Err(()); "too large max change")]
+    #[test_case(&["", "--gas-price-oracle=block-fullness", "--gas-price-history-blocks=0"] => Err(()); "zero history blocks")]
+    fn parse(args: &[&str]) -> Result<Option<u64>, ()> {
+        Command::try_parse_from(args).map_err(|_| ()).map(|c| {
+            c.gas_price
+                .into_oracle(7, 1_000_000)
+                .map(|oracle| oracle.min_gas_price(Default::default()))
+        })
+    }
+}
@@ -0,0 +1,81 @@
+use clap::Args;
+use fuel_core::service::config::RateLimitConfig;
+use std::num::NonZeroU32;
+
+#[derive(Debug, Clone, Args)]
+pub struct GraphQLArgs {
+    /// The maximum depth of the GraphQL query. Deeper queries are rejected before execution.
+    #[clap(long = "graphql-max-depth", default_value = "16", env)]
+    pub graphql_max_depth: usize,
+
+    /// The maximum complexity of the GraphQL query. Each requested field adds to
+    /// the complexity, and more complex queries are rejected before execution.
+    #[clap(long = "graphql-max-complexity", default_value = "20000", env)]
+    pub graphql_max_complexity: usize,
+
+    /// The number of GraphQL requests per second allowed for each client.
+    /// The client is identified by the `x-api-key` header with one of the
+    /// `--graphql-api-keys`, or by the IP address otherwise.
+    /// Requests above the limit are rejected with the "THROTTLED" error. Each operation
+    /// is counted as one more request per 1000 of its complexity.
+    /// Rate limiting is disabled by default.
+    #[clap(long = "graphql-rate-limit", env)]
+    pub graphql_rate_limit: Option<NonZeroU32>,
+
+    /// The number of GraphQL requests that the idle client can send at once.
+    /// By default, it is equal to the `--graphql-rate-limit`.
+    #[clap(
+        long = "graphql-rate-limit-burst",
+        requires = "graphql_rate_limit",
+        env
+    )]
+    pub graphql_rate_limit_burst: Option<NonZeroU32>,
+
+    /// The comma-separated API keys of clients. The client sending one of these keys
+    /// in the `x-api-key` header is identified by the key instead of the IP address.
+    /// Unknown keys are ignored.
+    #[clap(long = "graphql-api-keys", value_delimiter = ',', env)]
+    pub graphql_api_keys: Vec<String>,
+
+    /// The maximum number of operations in the batched GraphQL request.
+    /// Each operation of the batch is counted as a separate request by the rate limit,
+    /// and is checked against the depth and complexity limits on its own.
+    #[clap(long = "graphql-max-batch-size", default_value = "32", env)]
+    pub graphql_max_batch_size: usize,
+}
+
+impl GraphQLArgs {
+    pub fn rate_limit(&self) -> Option<RateLimitConfig> {
+        self.graphql_rate_limit
+            .map(|requests_per_second| RateLimitConfig {
+                requests_per_second,
+                burst: self.graphql_rate_limit_burst.unwrap_or(requests_per_second),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+    use test_case::test_case;
+
+    #[derive(Debug, Clone, Parser)]
+    pub struct Command {
+        #[clap(flatten)]
+        graphql: GraphQLArgs,
+    }
+
+    #[test_case(&[""] => Ok(None); "disabled by default")]
+    #[test_case(&["", "--graphql-rate-limit=10"] => Ok(Some((10, 10))); "burst equals rate by default")]
+    #[test_case(&["", "--graphql-rate-limit=10", "--graphql-rate-limit-burst=50"] => Ok(Some((10, 50))); "custom burst")]
+    #[test_case(&["", "--graphql-rate-limit-burst=50"] => Err(()); "burst requires rate")]
+    #[test_case(&["", "--graphql-rate-limit=0"] => Err(()); "zero rate")]
+    fn parse(args: &[&str]) -> Result<Option<(u32, u32)>, ()> {
+        Command::try_parse_from(args).map_err(|_| ()).map(|c| {
+            c.graphql
+                .rate_limit()
+                .map(|config| (config.requests_per_second.get(), config.burst.get()))
+        })
+    }
+}
@@ -0,0 +1,114 @@
+use crate::cli::secrets;
+use clap::Args;
+use fuel_core::{
+    service::NodeIdentityConfig,
+    types::{
+        blockchain::primitives::SecretKeyWrapper,
+        fuel_vm::SecretKey,
+        secrecy::Secret,
+    },
+};
+
+#[derive(Debug, Clone, Args)]
+pub struct NodeIdentityArgs {
+    /// The name of the operator of the node. When set, the node serves its identity,
+    /// signed by the node key, at the `/.well-known/fuel-node-identity` path of the API.
+    #[clap(long = "node-identity-operator", env)]
+    pub node_identity_operator: Option<String>,
+
+    /// The contact of the operator of the node, like an email or a website.
+    #[clap(
+        long = "node-identity-contact",
+        requires = "node_identity_operator",
+        default_value = "",
+        env
+    )]
+    pub node_identity_contact: String,
+
+    /// The public URL of the API, like `https://node.example.com`, signed with the
+    /// identity. By default, the URL is derived from the `Host` header of the request.
+    #[clap(long = "node-identity-url", requires = "node_identity_operator", env)]
+    pub node_identity_url: Option<String>,
+
+    /// The key signing the identity of the node, or a secret reference
+    /// (`env:<VAR>`, `keystore:<PATH>`, `key:<NAME>` or `cmd:<COMMAND>`).
+    /// By default, the consensus key is used.
+    #[clap(
+        long = "node-identity-key",
+        requires = "node_identity_operator",
+        value_parser = secrets::parse::<SecretKey>,
+        env
+    )]
+    pub node_identity_key: Option<SecretKey>,
+}
+
+impl NodeIdentityArgs {
+    pub fn into_config(
+        self,
+        consensus_key: Option<&Secret<SecretKeyWrapper>>,
+    ) -> anyhow::Result<Option<NodeIdentityConfig>> {
+        let Some(operator) = self.node_identity_operator else {
+            return Ok(None)
+        };
+
+        let key = match self.node_identity_key {
+            Some(key) => Secret::new(key.into()),
+            None => consensus_key.cloned().ok_or_else(|| {
+                anyhow::anyhow!(
+                    "The `--node-identity-key` or the consensus key is required to sign the identity"
+                )
+            })?,
+        };
+        Ok(Some(NodeIdentityConfig {
+            operator,
+            contact: self.node_identity_contact,
+            public_url: self.node_identity_url,
+            key,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+    use fuel_core::{
+        chain_config::default_consensus_dev_key,
+        types::secrecy::ExposeSecret,
+    };
+    use test_case::test_case;
+
+    #[derive(Debug, Clone, Parser)]
+    pub struct Command {
+        #[clap(flatten)]
+        node_identity: NodeIdentityArgs,
+    }
+
+    const KEY: &str =
+        "0xa449b1ffee0e2205fa924c6740cc48b3b473aa28587df6dab12abc245d1f5298";
+
+    #[test_case(&[""], false => Ok(None); "disabled by default")]
+    #[test_case(&["", "--node-identity-operator=Fuel", "--node-identity-contact=ops@fuel.network"], true => Ok(Some(("Fuel".into(), "ops@fuel.network".into(), default_consensus_dev_key()))); "consensus key by default")]
+    #[test_case(&["", "--node-identity-operator=Fuel", &format!("--node-identity-key={KEY}")], false => Ok(Some(("Fuel".into(), "".into(), KEY.parse().unwrap()))); "custom key")]
+    #[test_case(&["", "--node-identity-operator=Fuel"], false => Err(()); "no key")]
+    #[test_case(&["", "--node-identity-contact=ops@fuel.network"], true => Err(()); "contact requires operator")]
+    fn parse(
+        args: &[&str],
+        with_consensus_key: bool,
+    ) -> Result<Option<(String, String, SecretKey)>, ()> {
+        let consensus_key =
+            with_consensus_key.then(|| Secret::new(default_consensus_dev_key().into()));
+        let command = Command::try_parse_from(args).map_err(|_| ())?;
+        let config = command
+            .node_identity
+            .into_config(consensus_key.as_ref())
+            .map_err(|_| ())?;
+        Ok(config.map(|config| {
+            (
+                config.operator,
+                config.contact,
+                **config.key.expose_secret(),
+            )
+        }))
+    }
+}
@@ -1,3 +1,4 @@
+use crate::cli::secrets;
 use anyhow::anyhow;
 use clap::{
     builder::ArgPredicate::IsPresent,
@@ -9,10 +10,12 @@ use fuel_core::{
             convert_to_libp2p_keypair,
             Config,
             NotInitialized,
+            Proxy,
             MAX_RESPONSE_SIZE,
         },
         gossipsub_config::default_gossipsub_builder,
         heartbeat,
+        request_accounting::PeerRequestLimits,
         Multiaddr,
     },
     types::{
@@ -41,6 +44,7 @@ pub struct P2PArgs {
     pub enable_p2p: bool,
 
     /// Peering secret key. Supports either a hex encoded secret key inline or a path to bip32 mnemonic encoded secret file.
+    /// The value can be a secret reference(`env:<VAR>`, `keystore:<PATH>`, `key:<NAME>` or `cmd:<COMMAND>`).
     #[clap(long = "keypair", env, value_parser = KeypairArg::try_from_string)]
     #[arg(required_if_eq("enable_p2p", "true"))]
     #[arg(requires_if(IsPresent, "enable_p2p"))]
@@ -84,6 +88,12 @@ pub struct P2PArgs {
     #[clap(long = "enable-mdns", env)]
     pub enable_mdns: bool,
 
+    /// The proxy used to dial peers, in the format `<PROTOCOL>://[<USERNAME>:<PASSWORD>@]<HOST>:<PORT>`,
+    /// where `<PROTOCOL>` is `socks5`, `socks5h` or `http`. Inbound connections aren't affected.
+    /// The value can be a secret reference(`env:<VAR>`, `keystore:<PATH>`, `key:<NAME>` or `cmd:<COMMAND>`).
+    #[clap(long = "p2p-proxy", env, value_parser = parse_proxy)]
+    pub p2p_proxy: Option<Proxy>,
+
     /// Max number of unique peers connected
     /// This number should be at least number of `mesh_n` from `Gossipsub` configuration.
     /// The total number of connections will be `(max_peers_connected + reserved_nodes.len()) * max_connections_per_peer`
@@ -181,6 +191,20 @@ pub struct P2PArgs {
     /// For peer reputations, the maximum time since last heartbeat before penalty
     #[clap(long = "heartbeat-max-time-since-last", default_value = "40", env)]
     pub heartbeat_max_time_since_last: u64,
+
+    /// The window within which the blocks and bytes served to each peer are accounted
+    #[clap(long = "peer-request-window", default_value = "60s", env)]
+    pub peer_request_window: humantime::Duration,
+
+    /// The maximum number of blocks served to a single peer within the window.
+    /// Requests above the limit are answered with empty responses.
+    #[clap(long = "max-blocks-served-per-peer", env)]
+    pub max_blocks_served_per_peer: Option<u64>,
+
+    /// The maximum number of bytes served to a single peer within the window.
+    /// Requests above the limit are answered with empty responses.
+    #[clap(long = "max-bytes-served-per-peer", env)]
+    pub max_bytes_served_per_peer: Option<u64>,
 }
 
 #[derive(Debug, Clone, Args)]
@@ -191,6 +215,15 @@ pub struct SyncArgs {
     /// The maximum number of headers to request in a single batch.
     #[clap(long = "sync-header-batch-size", default_value = "10", env)]
     pub header_batch_size: u32,
+    /// The maximum number of bytes of blocks downloaded or being downloaded and
+    /// waiting for the execution. New blocks are not requested from peers while
+    /// the limit is reached.
+    #[clap(long = "sync-max-in-flight-bytes", default_value = "67108864", env)]
+    pub max_in_flight_bytes: usize,
+}
+
+fn parse_proxy(s: &str) -> anyhow::Result<Proxy> {
+    Proxy::from_str(&secrets::resolve(s)?)
 }
 
 #[derive(Clone, Debug)]
@@ -201,6 +234,8 @@ pub enum KeypairArg {
 
 impl KeypairArg {
     pub fn try_from_string(s: &str) -> anyhow::Result<KeypairArg> {
+        let s = secrets::resolve(s)?;
+        let s = s.as_str();
         // first try to parse as inline secret
         // then try to parse as a pathbuf
 
@@ -223,6 +258,7 @@ impl From<SyncArgs> for fuel_core::sync::Config {
         Self {
             block_stream_buffer_size: value.block_stream_buffer_size,
             header_batch_size: value.header_batch_size as usize,
+            max_in_flight_bytes: value.max_in_flight_bytes,
         }
     }
 }
@@ -283,6 +319,18 @@ impl P2PArgs {
             )
         };
 
+        let peer_request_limits = if self.max_blocks_served_per_peer.is_some()
+            || self.max_bytes_served_per_peer.is_some()
+        {
+            Some(PeerRequestLimits {
+                window: self.peer_request_window.into(),
+                max_blocks: self.max_blocks_served_per_peer.unwrap_or(u64::MAX),
+                max_bytes: self.max_bytes_served_per_peer.unwrap_or(u64::MAX),
+            })
+        } else {
+            None
+        };
+
         let config = Config {
             keypair: local_keypair,
             network_name,
@@ -318,7 +366,9 @@ impl P2PArgs {
             ),
             info_interval: Some(Duration::from_secs(self.info_interval)),
             identify_interval: Some(Duration::from_secs(self.identify_interval)),
+            peer_request_limits,
             metrics,
+            proxy: self.p2p_proxy,
             state: NotInitialized,
         };
         Ok(Some(config))
@@ -0,0 +1,66 @@
+use clap::Args;
+use fuel_core::service::ReplicaConfig;
+use std::path::{
+    Path,
+    PathBuf,
+};
+
+#[derive(Debug, Clone, Args)]
+pub struct ReplicaArgs {
+    /// Runs the node as the read replica of the node that owns the database at the `--db-path`.
+    /// The replica opens the database as the RocksDB secondary instance, serves
+    /// the GraphQL API, and catches up with the primary node periodically.
+    #[clap(long = "replica", env)]
+    pub replica: bool,
+
+    /// The directory for the files of the secondary database instance.
+    /// It should be unique for each replica. By default, it is `<DB_PATH>/replica`.
+    #[clap(long = "replica-path", env)]
+    pub replica_path: Option<PathBuf>,
+
+    /// The interval between catching up with the primary database.
+    #[clap(long = "replica-catch-up-interval", default_value = "1s", env)]
+    pub replica_catch_up_interval: humantime::Duration,
+}
+
+impl ReplicaArgs {
+    pub fn into_config(self, database_path: &Path) -> Option<ReplicaConfig> {
+        if !self.replica {
+            return None
+        }
+
+        let secondary_path = self
+            .replica_path
+            .unwrap_or_else(|| database_path.join("replica"));
+        Some(ReplicaConfig {
+            secondary_path,
+            catch_up_interval: self.replica_catch_up_interval.into(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+    use std::time::Duration;
+    use test_case::test_case;
+
+    #[derive(Debug, Clone, Parser)]
+    pub struct Command {
+        #[clap(flatten)]
+        replica: ReplicaArgs,
+    }
+
+    #[test_case(&[""] => Ok(None); "disabled by default")]
+    #[test_case(&["", "--replica"] => Ok(Some(("/db/replica".into(), Duration::from_secs(1)))); "default path and interval")]
+    #[test_case(&["", "--replica", "--replica-path=/replica", "--replica-catch-up-interval=500ms"] => Ok(Some(("/replica".into(), Duration::from_millis(500)))); "custom path and interval")]
+    #[test_case(&["", "--replica", "--replica-catch-up-interval=often"] => Err(()); "invalid interval")]
+    fn parse(args: &[&str]) -> Result<Option<(PathBuf, Duration)>, ()> {
+        Command::try_parse_from(args).map_err(|_| ()).map(|c| {
+            c.replica
+                .into_config(Path::new("/db"))
+                .map(|config| (config.secondary_path, config.catch_up_interval))
+        })
+    }
+}
@@ -0,0 +1,113 @@
+use crate::cli::secrets;
+use clap::Args;
+use fuel_core::{
+    service::{
+        PrimaryReplicationConfig,
+        ReplicationConfig,
+        StandbyReplicationConfig,
+    },
+    types::secrecy::Secret,
+};
+use std::net::SocketAddr;
+
+#[derive(Debug, Clone, Args)]
+pub struct ReplicationArgs {
+    /// The address accepting connections of warm standby nodes. When set, the node
+    /// streams the changes of its database to standby nodes that know the `--replication-secret`.
+    #[clap(
+        long = "replication-listen-addr",
+        requires = "replication_secret",
+        conflicts_with_all = &["standby_of", "replica"],
+        env
+    )]
+    pub replication_listen_addr: Option<SocketAddr>,
+
+    /// The number of the latest batches of changes kept for reconnecting standby nodes.
+    /// The standby node that fell behind the log should be initialized with a fresh
+    /// copy of the database of the primary node.
+    #[clap(long = "replication-log-capacity", default_value = "10000", env)]
+    pub replication_log_capacity: usize,
+
+    /// The replication address of the primary node. When set, the node runs as
+    /// the warm standby: it applies the changes of the primary node instead of executing
+    /// blocks and only serves the GraphQL API.
+    #[clap(
+        long = "standby-of",
+        requires = "replication_secret",
+        conflicts_with = "replica",
+        env
+    )]
+    pub standby_of: Option<String>,
+
+    /// The secret shared by the primary and standby nodes, or a secret reference
+    /// (`env:<VAR>`, `keystore:<PATH>`, `key:<NAME>` or `cmd:<COMMAND>`).
+    #[clap(
+        long = "replication-secret",
+        value_parser = secrets::parse::<String>,
+        env
+    )]
+    pub replication_secret: Option<String>,
+
+    /// The interval between attempts of the standby node to reconnect to the primary node.
+    #[clap(long = "standby-reconnect-interval", default_value = "5s", env)]
+    pub standby_reconnect_interval: humantime::Duration,
+}
+
+impl ReplicationArgs {
+    pub fn into_config(self) -> Option<ReplicationConfig> {
+        let secret = Secret::new(self.replication_secret?);
+        if let Some(addr) = self.replication_listen_addr {
+            return Some(ReplicationConfig::Primary(PrimaryReplicationConfig {
+                addr,
+                secret,
+                log_capacity: self.replication_log_capacity,
+            }))
+        }
+        self.standby_of.map(|primary| {
+            ReplicationConfig::Standby(StandbyReplicationConfig {
+                primary,
+                secret,
+                reconnect_interval: self.standby_reconnect_interval.into(),
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+    use test_case::test_case;
+
+    #[derive(Debug, Clone, Parser)]
+    pub struct Command {
+        /// Mirrors the flag of the read replica that conflicts with the replication.
+        #[clap(long = "replica")]
+        replica: bool,
+
+        #[clap(flatten)]
+        replication: ReplicationArgs,
+    }
+
+    #[test_case(&[""] => Ok("none".to_string()); "disabled by default")]
+    #[test_case(&["", "--replication-listen-addr=127.0.0.1:4100", "--replication-secret=secret"] => Ok("primary 127.0.0.1:4100 10000".to_string()); "primary")]
+    #[test_case(&["", "--standby-of=primary:4100", "--replication-secret=secret", "--standby-reconnect-interval=1s"] => Ok("standby primary:4100 1s".to_string()); "standby")]
+    #[test_case(&["", "--standby-of=primary:4100"] => Err(()); "standby requires secret")]
+    #[test_case(&["", "--replication-listen-addr=127.0.0.1:4100", "--standby-of=primary:4100", "--replication-secret=secret"] => Err(()); "primary conflicts with standby")]
+    #[test_case(&["", "--replica", "--standby-of=primary:4100", "--replication-secret=secret"] => Err(()); "standby conflicts with replica")]
+    fn parse(args: &[&str]) -> Result<String, ()> {
+        Command::try_parse_from(args).map_err(|_| ()).map(|c| {
+            match c.replication.into_config() {
+                None => "none".to_string(),
+                Some(ReplicationConfig::Primary(config)) => {
+                    format!("primary {} {}", config.addr, config.log_capacity)
+                }
+                Some(ReplicationConfig::Standby(config)) => format!(
+                    "standby {} {}",
+                    config.primary,
+                    humantime::format_duration(config.reconnect_interval)
+                ),
+            }
+        })
+    }
+}
@@ -0,0 +1,80 @@
+use clap::Args;
+use tokio::runtime::{
+    Builder,
+    Runtime,
+};
+
+#[derive(Debug, Clone, Args)]
+pub struct RuntimeArgs {
+    /// The number of worker threads of the tokio runtime. The runtime drives
+    /// latency-sensitive work: the GraphQL API, the gossip and the services' main loops.
+    /// By default, it is equal to the number of CPU cores.
+    #[clap(long = "runtime-worker-threads", env)]
+    pub runtime_worker_threads: Option<usize>,
+
+    /// The maximum number of threads spawned by the tokio runtime for blocking operations.
+    #[clap(long = "runtime-max-blocking-threads", default_value = "512", env)]
+    pub runtime_max_blocking_threads: usize,
+
+    /// The number of threads of the pool used for throughput work: block execution,
+    /// block verification, dry runs and verification of predicates.
+    /// By default, it is equal to the number of CPU cores.
+    #[clap(long = "execution-threads", env)]
+    pub execution_threads: Option<usize>,
+}
+
+impl RuntimeArgs {
+    /// Builds the tokio runtime for the latency-sensitive work and initializes
+    /// the global pool for the heavy work, so the heavy work doesn't occupy
+    /// the worker threads of the runtime.
+    pub fn build(&self) -> anyhow::Result<Runtime> {
+        let mut pool = rayon::ThreadPoolBuilder::new()
+            .thread_name(|index| format!("fuel-execution-{index}"));
+        if let Some(threads) = self.execution_threads {
+            pool = pool.num_threads(threads);
+        }
+        pool.build_global()?;
+
+        let mut runtime = Builder::new_multi_thread();
+        runtime
+            .enable_all()
+            .thread_name("fuel-runtime")
+            .max_blocking_threads(self.runtime_max_blocking_threads);
+        if let Some(threads) = self.runtime_worker_threads {
+            runtime.worker_threads(threads);
+        }
+        Ok(runtime.build()?)
+    }
+}
+
+/// The runtime used by the commands without runtime configuration.
+pub fn default_runtime() -> anyhow::Result<Runtime> {
+    Ok(Builder::new_multi_thread().enable_all().build()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+    use test_case::test_case;
+
+    #[derive(Debug, Clone, Parser)]
+    pub struct Command {
+        #[clap(flatten)]
+        runtime: RuntimeArgs,
+    }
+
+    #[test_case(&[""] => Ok((None, 512, None)); "defaults")]
+    #[test_case(&["", "--runtime-worker-threads=2", "--execution-threads=6"] => Ok((Some(2), 512, Some(6))); "separate thread counts")]
+    #[test_case(&["", "--runtime-max-blocking-threads=16"] => Ok((None, 16, None)); "custom blocking threads")]
+    #[test_case(&["", "--execution-threads=many"] => Err(()); "invalid thread count")]
+    fn parse(args: &[&str]) -> Result<(Option<usize>, usize, Option<usize>), ()> {
+        Command::try_parse_from(args).map_err(|_| ()).map(|c| {
+            (
+                c.runtime.runtime_worker_threads,
+                c.runtime.runtime_max_blocking_threads,
+                c.runtime.execution_threads,
+            )
+        })
+    }
+}
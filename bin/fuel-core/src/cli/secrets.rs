@@ -0,0 +1,215 @@
+//! Resolution of secrets referenced in the configuration.
+//!
+//! Each secret value can be specified as:
+//! - `env:<VAR>` - the value of the environment variable `<VAR>`.
+//! - `keystore:<PATH>` - the content of the encrypted keystore file at `<PATH>`.
+//!   The passphrase is taken from the `SECRETS_PASSPHRASE` environment variable,
+//!   or requested interactively if it is not set.
+//! - `key:<NAME>` - the key `<NAME>` of the keystore managed by `fuel-core key`.
+//!   The keystore directory is taken from the `KEYSTORE` environment variable,
+//!   or `~/.fuel/keystore` is used. The passphrase is requested as for `keystore:<PATH>`.
+//! - `cmd:<COMMAND>` - the output of the external command, e.g., a secrets manager CLI.
+//! - Any other value is used as is.
+
+use crate::cli::{
+    keystore::Keystore,
+    DEFAULT_KEYSTORE_PATH,
+};
+use anyhow::{
+    anyhow,
+    Context,
+};
+use std::{
+    env,
+    path::{
+        Path,
+        PathBuf,
+    },
+    process,
+    str::FromStr,
+};
+
+pub const SECRETS_PASSPHRASE_ENV: &str = "SECRETS_PASSPHRASE";
+pub const KEYSTORE_ENV: &str = "KEYSTORE";
+
+const ENV_PREFIX: &str = "env:";
+const KEYSTORE_PREFIX: &str = "keystore:";
+const KEY_PREFIX: &str = "key:";
+const COMMAND_PREFIX: &str = "cmd:";
+
+/// Returns `true` if the value is a reference to the secret, instead of the secret itself.
+pub fn is_reference(value: &str) -> bool {
+    [ENV_PREFIX, KEYSTORE_PREFIX, KEY_PREFIX, COMMAND_PREFIX]
+        .iter()
+        .any(|prefix| value.starts_with(prefix))
+}
+
+/// Resolves the secret reference into the value of the secret.
+pub fn resolve(value: &str) -> anyhow::Result<String> {
+    if let Some(var) = value.strip_prefix(ENV_PREFIX) {
+        env::var(var).with_context(|| {
+            format!("failed to read the secret from the environment variable `{var}`")
+        })
+    } else if let Some(path) = value.strip_prefix(KEYSTORE_PREFIX) {
+        from_keystore(Path::new(path))
+    } else if let Some(name) = value.strip_prefix(KEY_PREFIX) {
+        from_named_key(name)
+    } else if let Some(command) = value.strip_prefix(COMMAND_PREFIX) {
+        from_command(command)
+    } else {
+        Ok(value.to_string())
+    }
+}
+
+/// Resolves the secret reference and parses the value of the secret.
+/// It can be used as a `value_parser` of the argument.
+pub fn parse<T>(value: &str) -> anyhow::Result<T>
+where
+    T: FromStr,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    let secret = resolve(value)?;
+    Ok(T::from_str(&secret)?)
+}
+
+/// Returns the passphrase from the `SECRETS_PASSPHRASE` environment variable,
+/// or requests it interactively for the `subject`.
+pub fn passphrase(subject: &str) -> anyhow::Result<String> {
+    match env::var(SECRETS_PASSPHRASE_ENV) {
+        Ok(passphrase) => Ok(passphrase),
+        Err(_) => {
+            rpassword::prompt_password(format!("Enter the passphrase for {subject}: "))
+                .context("failed to read the passphrase")
+        }
+    }
+}
+
+/// Same as [`passphrase`], but the interactively entered passphrase must be repeated.
+/// It is used to encrypt new secrets.
+pub fn new_passphrase(subject: &str) -> anyhow::Result<String> {
+    if let Ok(passphrase) = env::var(SECRETS_PASSPHRASE_ENV) {
+        return Ok(passphrase)
+    }
+    let passphrase =
+        rpassword::prompt_password(format!("Enter the new passphrase for {subject}: "))
+            .context("failed to read the passphrase")?;
+    let repeated = rpassword::prompt_password("Repeat the passphrase: ")
+        .context("failed to read the passphrase")?;
+    if passphrase != repeated {
+        return Err(anyhow!("the passphrases don't match"))
+    }
+    Ok(passphrase)
+}
+
+/// Returns the directory of the keystore used by `key:<NAME>` references.
+pub fn keystore_dir() -> PathBuf {
+    env::var_os(KEYSTORE_ENV)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| DEFAULT_KEYSTORE_PATH.clone())
+}
+
+fn from_named_key(name: &str) -> anyhow::Result<String> {
+    let passphrase = passphrase(&format!("the key `{name}`"))?;
+    let secret = Keystore::new(keystore_dir()).load(name, &passphrase)?;
+    Ok(format!("0x{}", hex::encode(secret.as_ref())))
+}
+
+fn from_keystore(path: &Path) -> anyhow::Result<String> {
+    let passphrase = passphrase(&path.display().to_string())?;
+    let secret = eth_keystore::decrypt_key(path, passphrase)
+        .with_context(|| format!("failed to decrypt the keystore {}", path.display()))?;
+
+    // Binary secrets, like raw private keys, are hex encoded.
+    let secret = String::from_utf8(secret)
+        .unwrap_or_else(|e| format!("0x{}", hex::encode(e.into_bytes())));
+    Ok(secret)
+}
+
+fn from_command(command: &str) -> anyhow::Result<String> {
+    #[cfg(unix)]
+    let output = process::Command::new("sh").arg("-c").arg(command).output();
+    #[cfg(not(unix))]
+    let output = process::Command::new("cmd").arg("/C").arg(command).output();
+
+    let output = output
+        .with_context(|| format!("failed to run the secrets command `{command}`"))?;
+    if !output.status.success() {
+        return Err(anyhow!(
+            "the secrets command `{command}` failed with {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr)
+        ))
+    }
+    let secret = String::from_utf8(output.stdout)
+        .context("the secrets command returned the non UTF-8 output")?;
+    Ok(secret.trim_end_matches(['\r', '\n']).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("plain" => Ok("plain".to_string()); "plain value is used as is")]
+    #[test_case("env:SECRETS_TEST_VALUE" => Ok("from env".to_string()); "value from env")]
+    #[test_case("env:SECRETS_TEST_MISSING" => Err(()); "missing env")]
+    #[test_case("cmd:echo from command" => Ok("from command".to_string()); "value from command")]
+    #[test_case("cmd:exit 1" => Err(()); "failed command")]
+    fn resolve_secret(value: &str) -> Result<String, ()> {
+        env::set_var("SECRETS_TEST_VALUE", "from env");
+        resolve(value).map_err(|_| ())
+    }
+
+    #[test]
+    fn resolve_secret_from_keystore() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut rng = rand::thread_rng();
+        let passphrase = "passphrase";
+        eth_keystore::encrypt_key(
+            dir.path(),
+            &mut rng,
+            "secret value",
+            passphrase,
+            Some("secret"),
+        )
+        .unwrap();
+        env::set_var(SECRETS_PASSPHRASE_ENV, passphrase);
+
+        let path = dir.path().join("secret");
+        let secret = resolve(&format!("keystore:{}", path.display())).unwrap();
+
+        assert_eq!(secret, "secret value");
+    }
+
+    #[test]
+    fn resolve_secret_from_named_key() {
+        let dir = tempfile::tempdir().unwrap();
+        let passphrase = "passphrase";
+        let keystore = Keystore::with_params(
+            dir.path(),
+            crate::cli::keystore::ScryptParams {
+                log_n: 4,
+                r: 8,
+                p: 1,
+            },
+        );
+        keystore.generate("consensus", passphrase).unwrap();
+        let expected = keystore.load("consensus", passphrase).unwrap();
+        env::set_var(KEYSTORE_ENV, dir.path());
+        env::set_var(SECRETS_PASSPHRASE_ENV, passphrase);
+
+        let secret: fuel_core::types::fuel_crypto::SecretKey =
+            parse("key:consensus").unwrap();
+
+        assert_eq!(secret, expected);
+    }
+
+    #[test]
+    fn parse_resolves_the_secret_before_parsing() {
+        env::set_var("SECRETS_TEST_NUMBER", "42");
+
+        let value: u64 = parse("env:SECRETS_TEST_NUMBER").unwrap();
+
+        assert_eq!(value, 42);
+    }
+}
@@ -1,10 +1,25 @@
 use crate::cli::DEFAULT_DB_PATH;
+use anyhow::Context;
 use clap::{
     Parser,
     Subcommand,
 };
-use fuel_core::types::fuel_types::ContractId;
-use std::path::PathBuf;
+use fuel_core::{
+    chain_config::{
+        ChainConfig,
+        StateConfig,
+        StateDelta,
+    },
+    types::fuel_types::ContractId,
+};
+use std::{
+    fs::File,
+    io::BufReader,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
 
 /// Print a snapshot of blockchain state to stdout.
 #[derive(Debug, Clone, Parser)]
@@ -39,10 +54,105 @@ pub enum SubCommands {
         #[clap(long = "id")]
         contract_id: ContractId,
     },
+    /// Creates a delta with the state changed since the base snapshot.
+    #[command(arg_required_else_help = true)]
+    Delta {
+        /// The path to the base snapshot produced by the `everything` command.
+        #[clap(long = "base")]
+        base: PathBuf,
+        /// The path to the target snapshot. By default, the current state of the database.
+        #[clap(long = "target")]
+        target: Option<PathBuf>,
+    },
+    /// Applies deltas one by one to the base snapshot and produces the resulting snapshot.
+    #[command(arg_required_else_help = true)]
+    ApplyDelta {
+        /// The path to the base snapshot.
+        #[clap(long = "base")]
+        base: PathBuf,
+        /// The paths to deltas in the order of their heights.
+        #[clap(required = true)]
+        deltas: Vec<PathBuf>,
+    },
+    /// Merges deltas of consecutive height ranges into one delta.
+    #[command(arg_required_else_help = true)]
+    MergeDeltas {
+        /// The paths to deltas in the order of their heights.
+        #[clap(required = true)]
+        deltas: Vec<PathBuf>,
+    },
+}
+
+fn read_json<T: serde::de::DeserializeOwned>(path: &Path) -> anyhow::Result<T> {
+    let file = File::open(path)
+        .context(format!("failed to open the file {}", path.display()))?;
+    serde_json::from_reader(BufReader::new(file))
+        .context(format!("failed to parse the file {}", path.display()))
+}
+
+fn read_state(path: &Path) -> anyhow::Result<(ChainConfig, StateConfig)> {
+    let mut config: ChainConfig = read_json(path)?;
+    let state = config
+        .initial_state
+        .take()
+        .ok_or_else(|| anyhow::anyhow!("the snapshot {} has no state", path.display()))?;
+    Ok((config, state))
+}
+
+fn write_json<T: serde::Serialize>(value: &T) -> anyhow::Result<()> {
+    let stdout = std::io::stdout().lock();
+    serde_json::to_writer_pretty(stdout, value).context("failed to dump to JSON")
+}
+
+/// Executes sub-commands that work only with files and don't need the database.
+/// Returns the sub-command back if it requires the database.
+fn exec_offline(subcommand: SubCommands) -> anyhow::Result<Option<SubCommands>> {
+    match subcommand {
+        SubCommands::Delta {
+            base,
+            target: Some(target),
+        } => {
+            let (_, base) = read_state(&base)?;
+            let (_, target) = read_state(&target)?;
+            write_json(&StateDelta::between(&base, &target)?)?;
+        }
+        SubCommands::ApplyDelta { base, deltas } => {
+            let (config, mut state) = read_state(&base)?;
+            for path in deltas {
+                let delta: StateDelta = read_json(&path)?;
+                state = delta
+                    .apply(&state)
+                    .context(format!("failed to apply the delta {}", path.display()))?;
+            }
+            write_json(&ChainConfig {
+                initial_state: Some(state),
+                ..config
+            })?;
+        }
+        SubCommands::MergeDeltas { deltas } => {
+            let mut merged: Option<StateDelta> = None;
+            for path in deltas {
+                let delta: StateDelta = read_json(&path)?;
+                merged = Some(match merged {
+                    Some(merged) => merged.merge(delta).context(format!(
+                        "failed to merge the delta {}",
+                        path.display()
+                    ))?,
+                    None => delta,
+                });
+            }
+            write_json(&merged)?;
+        }
+        subcommand => return Ok(Some(subcommand)),
+    }
+    Ok(None)
 }
 
 #[cfg(not(any(feature = "rocksdb", feature = "rocksdb-production")))]
 pub async fn exec(command: Command) -> anyhow::Result<()> {
+    if exec_offline(command.subcommand)?.is_none() {
+        return Ok(())
+    }
     Err(anyhow::anyhow!(
         "Rocksdb must be enabled to use the database at {}",
         command.database_path.display()
@@ -51,13 +161,9 @@ pub async fn exec(command: Command) -> anyhow::Result<()> {
 
 #[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
 pub async fn exec(command: Command) -> anyhow::Result<()> {
-    use anyhow::Context;
-    use fuel_core::{
-        chain_config::{
-            ChainConfig,
-            StateConfig,
-        },
-        database::Database,
+    use fuel_core::database::Database;
+    let Some(subcommand) = exec_offline(command.subcommand)? else {
+        return Ok(())
     };
     let path = command.database_path;
     let data_source = fuel_core::state::rocks_db::RocksDb::default_open(&path, None)
@@ -68,7 +174,7 @@ pub async fn exec(command: Command) -> anyhow::Result<()> {
         ))?;
     let db = Database::new(std::sync::Arc::new(data_source));
 
-    match command.subcommand {
+    match subcommand {
         SubCommands::Everything { chain_config } => {
             let config: ChainConfig = chain_config.parse()?;
             let state_conf = StateConfig::generate_state_config(db)?;
@@ -90,6 +196,18 @@ pub async fn exec(command: Command) -> anyhow::Result<()> {
             serde_json::to_writer_pretty(stdout, &config)
                 .context("failed to dump contract snapshot to JSON")?;
         }
+        SubCommands::Delta { base, target: None } => {
+            let (_, base) = read_state(&base)?;
+            let target = StateConfig::generate_state_config(db)?;
+            write_json(&StateDelta::between(&base, &target)?)?;
+        }
+        SubCommands::Delta {
+            target: Some(_), ..
+        }
+        | SubCommands::ApplyDelta { .. }
+        | SubCommands::MergeDeltas { .. } => {
+            unreachable!("Executed without the database")
+        }
     }
     Ok(())
 }
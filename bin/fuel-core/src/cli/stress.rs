@@ -0,0 +1,317 @@
+use anyhow::Context;
+use clap::{
+    Parser,
+    Subcommand,
+};
+use fuel_core_client::client::FuelClient;
+use fuel_core_types::{
+    fuel_asm::{
+        op,
+        RegId,
+    },
+    fuel_tx::{
+        Transaction,
+        TransactionBuilder,
+        TxId,
+    },
+};
+use futures::StreamExt;
+use std::{
+    future::Future,
+    net::SocketAddr,
+    sync::Arc,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+use tokio::{
+    sync::Semaphore,
+    task::JoinSet,
+};
+
+/// Runs load scenarios against the local node and prints latency and error summaries.
+///
+/// The scenarios are intended for validating the capacity of the node before
+/// exposing it to the public network. Transactions are built with random fee inputs,
+/// so the `tx-flood` scenario requires the node to run without `--utxo-validation`.
+#[derive(Debug, Clone, Parser)]
+pub struct Command {
+    /// The URL of the GraphQL API of the node.
+    #[clap(long = "url", default_value = "127.0.0.1:4000", env)]
+    url: String,
+
+    /// The maximum number of requests in flight.
+    #[clap(long = "concurrency", default_value = "64", env)]
+    concurrency: usize,
+
+    /// The scenario to run.
+    #[command(subcommand)]
+    scenario: Scenario,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum Scenario {
+    /// Submits transactions at the configured rate.
+    TxFlood {
+        /// The number of submitted transactions per second.
+        #[clap(long = "tps", default_value = "100")]
+        tps: u32,
+        /// For how long to submit transactions.
+        #[clap(long = "duration", default_value = "30s")]
+        duration: humantime::Duration,
+    },
+    /// Sends the burst of dry runs of large transactions at once.
+    DryRunBurst {
+        /// The number of dry runs in the burst.
+        #[clap(long = "count", default_value = "1000")]
+        count: usize,
+        /// The number of instructions in the script of every dry-run transaction.
+        #[clap(long = "script-instructions", default_value = "16384")]
+        script_instructions: usize,
+    },
+    /// Opens transaction status subscriptions and drops them shortly afterward.
+    SubscriptionChurn {
+        /// The number of opened subscriptions.
+        #[clap(long = "count", default_value = "1000")]
+        count: usize,
+        /// For how long every subscription is kept open.
+        #[clap(long = "hold", default_value = "100ms")]
+        hold: humantime::Duration,
+    },
+    /// Opens TCP connections to the p2p port and closes them right away.
+    PeerStorm {
+        /// The address of the p2p listener of the node.
+        #[clap(long = "p2p-address", default_value = "127.0.0.1:30333")]
+        address: SocketAddr,
+        /// The number of opened connections.
+        #[clap(long = "count", default_value = "1000")]
+        count: usize,
+    },
+}
+
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    anyhow::ensure!(
+        command.concurrency > 0,
+        "the concurrency should be positive"
+    );
+    let client = Arc::new(FuelClient::new(&command.url)?);
+    client
+        .health()
+        .await
+        .context(format!("the node at {} is unreachable", command.url))?;
+    let limit = Arc::new(Semaphore::new(command.concurrency));
+
+    let summary = match command.scenario {
+        Scenario::TxFlood { tps, duration } => {
+            tracing::info!("Submitting {tps} transactions per second for {duration}");
+            tx_flood(client, limit, tps, duration.into()).await?
+        }
+        Scenario::DryRunBurst {
+            count,
+            script_instructions,
+        } => {
+            tracing::info!(
+                "Sending {count} dry runs of scripts with {script_instructions} instructions"
+            );
+            let script: Vec<u8> = vec![op::ret(RegId::ONE); script_instructions.max(1)]
+                .into_iter()
+                .collect();
+            run_requests(limit, count, move || {
+                let client = client.clone();
+                let tx = random_transaction(script.clone());
+                async move { client.dry_run_opt(&[tx], Some(false)).await.map(|_| ()) }
+            })
+            .await?
+        }
+        Scenario::SubscriptionChurn { count, hold } => {
+            tracing::info!("Opening {count} subscriptions held for {hold}");
+            let hold: Duration = hold.into();
+            run_requests(limit, count, move || {
+                let client = client.clone();
+                async move {
+                    let id = TxId::new(rand::random());
+                    let mut stream = client.subscribe_transaction_status(&id).await?;
+                    // The unknown transaction has no status updates,
+                    // so the subscription only occupies the server until dropped.
+                    let _ = tokio::time::timeout(hold, stream.next()).await;
+                    Ok(())
+                }
+            })
+            .await?
+        }
+        Scenario::PeerStorm { address, count } => {
+            tracing::info!("Opening {count} connections to {address}");
+            run_requests(limit, count, move || async move {
+                let stream = tokio::net::TcpStream::connect(address).await?;
+                drop(stream);
+                Ok(())
+            })
+            .await?
+        }
+    };
+
+    println!("{summary}");
+    Ok(())
+}
+
+async fn tx_flood(
+    client: Arc<FuelClient>,
+    limit: Arc<Semaphore>,
+    tps: u32,
+    duration: Duration,
+) -> anyhow::Result<Summary> {
+    let period = Duration::from_secs(1)
+        .checked_div(tps)
+        .context("the `--tps` should be positive")?;
+    let mut interval = tokio::time::interval(period);
+    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    let started = Instant::now();
+    let mut tasks = JoinSet::new();
+    while started.elapsed() < duration {
+        interval.tick().await;
+        // Wait for the free slot to not hide the backpressure of the node
+        // behind the growing number of in-flight requests.
+        let permit = limit.clone().acquire_owned().await?;
+        let client = client.clone();
+        let tx = random_transaction(vec![]);
+        tasks.spawn(async move {
+            let start = Instant::now();
+            let result = client.submit(&tx).await;
+            drop(permit);
+            (start.elapsed(), result.map(|_| ()))
+        });
+    }
+    collect(tasks, started).await
+}
+
+async fn run_requests<F, Fut>(
+    limit: Arc<Semaphore>,
+    count: usize,
+    request: F,
+) -> anyhow::Result<Summary>
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = std::io::Result<()>> + Send + 'static,
+{
+    let started = Instant::now();
+    let mut tasks = JoinSet::new();
+    for _ in 0..count {
+        let permit = limit.clone().acquire_owned().await?;
+        let request = request();
+        tasks.spawn(async move {
+            let start = Instant::now();
+            let result = request.await;
+            drop(permit);
+            (start.elapsed(), result)
+        });
+    }
+    collect(tasks, started).await
+}
+
+async fn collect(
+    mut tasks: JoinSet<(Duration, std::io::Result<()>)>,
+    started: Instant,
+) -> anyhow::Result<Summary> {
+    let mut summary = Summary::default();
+    while let Some(result) = tasks.join_next().await {
+        let (latency, result) = result?;
+        if let Err(err) = result {
+            tracing::debug!("The request failed: {err}");
+        }
+        summary.record(latency, result.is_ok());
+    }
+    summary.elapsed = started.elapsed();
+    Ok(summary)
+}
+
+fn random_transaction(script: Vec<u8>) -> Transaction {
+    TransactionBuilder::script(script, vec![])
+        .add_random_fee_input()
+        .finalize_as_transaction()
+}
+
+/// The latency and error statistics of the scenario.
+#[derive(Debug, Default)]
+struct Summary {
+    latencies: Vec<Duration>,
+    errors: usize,
+    elapsed: Duration,
+}
+
+impl Summary {
+    fn record(&mut self, latency: Duration, succeeded: bool) {
+        self.latencies.push(latency);
+        if !succeeded {
+            self.errors = self.errors.saturating_add(1);
+        }
+    }
+
+    /// Returns the latency below which the `percentile` of requests fall.
+    fn percentile(&self, percentile: f64) -> Duration {
+        let mut latencies = self.latencies.clone();
+        latencies.sort_unstable();
+        let Some(last) = latencies.len().checked_sub(1) else {
+            return Duration::ZERO
+        };
+        let index = ((last as f64) * percentile / 100.0).round() as usize;
+        latencies[index.min(last)]
+    }
+
+    fn throughput(&self) -> f64 {
+        let secs = self.elapsed.as_secs_f64();
+        if secs > 0.0 {
+            self.latencies.len() as f64 / secs
+        } else {
+            0.0
+        }
+    }
+}
+
+impl std::fmt::Display for Summary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "requests: {}, errors: {}, elapsed: {:?}, throughput: {:.1}/s",
+            self.latencies.len(),
+            self.errors,
+            self.elapsed,
+            self.throughput()
+        )?;
+        write!(
+            f,
+            "latency: min {:?}, p50 {:?}, p90 {:?}, p99 {:?}, max {:?}",
+            self.percentile(0.0),
+            self.percentile(50.0),
+            self.percentile(90.0),
+            self.percentile(99.0),
+            self.percentile(100.0),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn percentiles_are_taken_from_recorded_latencies() {
+        let mut summary = Summary::default();
+        for ms in (1..=100u64).rev() {
+            summary.record(Duration::from_millis(ms), ms.checked_rem(10) != Some(0));
+        }
+
+        assert_eq!(summary.errors, 10);
+        assert_eq!(summary.percentile(0.0), Duration::from_millis(1));
+        assert_eq!(summary.percentile(50.0), Duration::from_millis(51));
+        assert_eq!(summary.percentile(99.0), Duration::from_millis(99));
+        assert_eq!(summary.percentile(100.0), Duration::from_millis(100));
+    }
+
+    #[test]
+    fn empty_summary_has_zero_latency() {
+        let summary = Summary::default();
+        assert_eq!(summary.percentile(50.0), Duration::ZERO);
+        assert_eq!(summary.throughput(), 0.0);
+    }
+}
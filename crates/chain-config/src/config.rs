@@ -2,15 +2,19 @@ mod chain;
 mod coin;
 mod consensus;
 mod contract;
+mod delta;
 mod message;
 mod state;
+mod upgrade;
 
 pub use chain::*;
 pub use coin::*;
 pub use consensus::*;
 pub use contract::*;
+pub use delta::*;
 pub use message::*;
 pub use state::*;
+pub use upgrade::*;
 
 #[cfg(test)]
 mod tests {
@@ -18,6 +22,8 @@ mod tests {
         blockchain::primitives::DaBlockHeight,
         fuel_asm::op,
         fuel_tx::{
+            ConsensusParameters,
+            TxParameters,
             TxPointer,
             UtxoId,
         },
@@ -46,6 +52,7 @@ mod tests {
         contract::ContractConfig,
         message::MessageConfig,
         state::StateConfig,
+        upgrade::ConsensusParametersUpgrade,
     };
 
     #[cfg(feature = "std")]
@@ -93,6 +100,86 @@ mod tests {
         insta::assert_snapshot!(json);
     }
 
+    fn upgrade(height: u32, max_inputs: u8) -> ConsensusParametersUpgrade {
+        ConsensusParametersUpgrade {
+            height: height.into(),
+            consensus_parameters: ConsensusParameters {
+                tx_params: TxParameters {
+                    max_inputs,
+                    ..TxParameters::DEFAULT
+                },
+                ..ConsensusParameters::default()
+            },
+        }
+    }
+
+    #[test]
+    fn can_roundtrip_serialize_consensus_parameters_upgrades() {
+        let config = ChainConfig {
+            consensus_parameters_upgrades: vec![upgrade(10, 1), upgrade(20, 2)],
+            ..ChainConfig::local_testnet()
+        };
+        let json = serde_json::to_string(&config).unwrap();
+        let deserialized_config: ChainConfig =
+            serde_json::from_str(json.as_str()).unwrap();
+        assert_eq!(config, deserialized_config);
+    }
+
+    #[test]
+    fn consensus_parameters_at_selects_latest_activated_upgrade() {
+        // The order of upgrades in the config doesn't matter.
+        let config = ChainConfig {
+            consensus_parameters_upgrades: vec![upgrade(20, 2), upgrade(10, 1)],
+            ..ChainConfig::local_testnet()
+        };
+        let max_inputs = |height: u32| {
+            config
+                .consensus_parameters_at(height.into())
+                .tx_params
+                .max_inputs
+        };
+
+        assert_eq!(
+            max_inputs(0),
+            config.consensus_parameters.tx_params.max_inputs
+        );
+        assert_eq!(
+            max_inputs(9),
+            config.consensus_parameters.tx_params.max_inputs
+        );
+        assert_eq!(max_inputs(10), 1);
+        assert_eq!(max_inputs(19), 1);
+        assert_eq!(max_inputs(20), 2);
+        assert_eq!(max_inputs(u32::MAX), 2);
+    }
+
+    #[test]
+    fn consensus_parameters_upgrades_are_validated() {
+        let valid = ChainConfig {
+            consensus_parameters_upgrades: vec![upgrade(10, 1), upgrade(20, 2)],
+            ..ChainConfig::local_testnet()
+        };
+        assert!(valid.validate_consensus_parameters_upgrades().is_ok());
+
+        let same_height = ChainConfig {
+            consensus_parameters_upgrades: vec![upgrade(10, 1), upgrade(10, 2)],
+            ..ChainConfig::local_testnet()
+        };
+        assert!(same_height
+            .validate_consensus_parameters_upgrades()
+            .is_err());
+
+        let mut new_chain_id = upgrade(10, 1);
+        new_chain_id.consensus_parameters.chain_id = 1.into();
+        let new_chain_id = ChainConfig {
+            consensus_parameters_upgrades: vec![new_chain_id],
+            ..ChainConfig::local_testnet()
+        };
+        assert!(new_chain_id
+            .validate_consensus_parameters_upgrades()
+            .is_err());
+    }
+
     #[test]
     fn can_roundtrip_serialize_block_height_config() {
         let mut rng = StdRng::seed_from_u64(2);
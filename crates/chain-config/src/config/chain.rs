@@ -15,6 +15,7 @@ use fuel_core_types::{
     fuel_types::{
         Address,
         AssetId,
+        BlockHeight,
         Bytes32,
     },
     fuel_vm::SecretKey,
@@ -38,6 +39,12 @@ use crate::{
     config::{
         coin::CoinConfig,
         state::StateConfig,
+        upgrade::{
+            consensus_parameters_at,
+            upgrade_at,
+            validate_upgrades,
+            ConsensusParametersUpgrade,
+        },
     },
     genesis::GenesisCommitment,
     ConsensusConfig,
@@ -59,6 +66,11 @@ pub struct ChainConfig {
     #[serde(default)]
     pub initial_state: Option<StateConfig>,
     pub consensus_parameters: ConsensusParameters,
+    /// The upgrades of the consensus parameters. Each upgrade replaces `consensus_parameters`
+    /// starting from its height: blocks are executed, transactions are accepted by the pool,
+    /// and the API reports the parameters in effect at the height of the block.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub consensus_parameters_upgrades: Vec<ConsensusParametersUpgrade>,
     pub consensus: ConsensusConfig,
 }
 
@@ -68,6 +80,7 @@ impl Default for ChainConfig {
             chain_name: "local".into(),
             block_gas_limit: TxParameters::DEFAULT.max_gas_per_tx * 10, /* TODO: Pick a sensible default */
             consensus_parameters: ConsensusParameters::default(),
+            consensus_parameters_upgrades: vec![],
             initial_state: None,
             consensus: ConsensusConfig::default_poa(),
         }
@@ -77,6 +90,34 @@ impl Default for ChainConfig {
 impl ChainConfig {
     pub const BASE_ASSET: AssetId = AssetId::zeroed();
 
+    /// Returns the consensus parameters in effect at the `height`.
+    pub fn consensus_parameters_at(&self, height: BlockHeight) -> &ConsensusParameters {
+        consensus_parameters_at(
+            &self.consensus_parameters,
+            &self.consensus_parameters_upgrades,
+            height,
+        )
+    }
+
+    /// Returns the version of the consensus parameters in effect at the `height`:
+    /// the activation height of the upgrade, or `None` for the genesis parameters.
+    pub fn consensus_parameters_version_at(
+        &self,
+        height: BlockHeight,
+    ) -> Option<BlockHeight> {
+        upgrade_at(&self.consensus_parameters_upgrades, height)
+            .map(|upgrade| upgrade.height)
+    }
+
+    /// Checks that the `consensus_parameters_upgrades` are compatible with
+    /// the genesis `consensus_parameters`.
+    pub fn validate_consensus_parameters_upgrades(&self) -> anyhow::Result<()> {
+        validate_upgrades(
+            &self.consensus_parameters,
+            &self.consensus_parameters_upgrades,
+        )
+    }
+
     pub fn local_testnet() -> Self {
         // endow some preset accounts with an initial balance
         tracing::info!("Initial Accounts");
@@ -179,14 +220,23 @@ impl FromStr for ChainConfig {
                 // Attempt to load chain config from path
                 let path = PathBuf::from(s.to_string());
                 let contents = std::fs::read(path)?;
-                serde_json::from_slice(&contents).map_err(|e| {
+                let config: Self = serde_json::from_slice(&contents).map_err(|e| {
                     std::io::Error::new(
                         ErrorKind::InvalidData,
                         anyhow::Error::new(e).context(format!(
                             "an error occurred while loading the chain config file {s}"
                         )),
                     )
-                })
+                })?;
+                config
+                    .validate_consensus_parameters_upgrades()
+                    .map_err(|e| {
+                        std::io::Error::new(
+                            ErrorKind::InvalidData,
+                            e.context(format!("the chain config file {s} is invalid")),
+                        )
+                    })?;
+                Ok(config)
             }
         }
     }
@@ -203,16 +253,23 @@ impl GenesisCommitment for ChainConfig {
             // Skip the `initial_state` bec
             initial_state: _,
             consensus_parameters,
+            consensus_parameters_upgrades,
             consensus,
         } = self;
 
         // TODO: Hash settlement configuration when it will be available.
-        let config_hash = *Hasher::default()
+        let mut hasher = Hasher::default()
             .chain(chain_name.as_bytes())
             .chain(block_gas_limit.to_be_bytes())
             .chain(consensus_parameters.root()?)
-            .chain(consensus.root()?)
-            .finalize();
+            .chain(consensus.root()?);
+        // Upgrades are hashed only if they exist to keep the hash of old configs the same.
+        for upgrade in consensus_parameters_upgrades {
+            hasher = hasher
+                .chain(u32::from(upgrade.height).to_be_bytes())
+                .chain(upgrade.consensus_parameters.root()?);
+        }
+        let config_hash = *hasher.finalize();
 
         Ok(config_hash)
     }
@@ -1,6 +1,9 @@
 use fuel_core_types::{
     fuel_tx::Input,
-    fuel_types::Address,
+    fuel_types::{
+        Address,
+        BlockHeight,
+    },
 };
 use serde::{
     Deserialize,
@@ -11,7 +14,21 @@ use crate::default_consensus_dev_key;
 
 #[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 pub enum ConsensusConfig {
-    PoA { signing_key: Address },
+    PoA {
+        signing_key: Address,
+    },
+    /// The PoA consensus with the rotation of the authority. Each key seals blocks
+    /// starting from its height until the height of the next key.
+    PoAKeyRotation {
+        signing_keys: Vec<SigningKeyRotation>,
+    },
+}
+
+/// The key sealing blocks starting from the `height`.
+#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+pub struct SigningKeyRotation {
+    pub height: BlockHeight,
+    pub signing_key: Address,
 }
 
 impl ConsensusConfig {
@@ -20,4 +37,45 @@ impl ConsensusConfig {
             signing_key: Input::owner(&default_consensus_dev_key().public_key()),
         }
     }
+
+    /// Returns the address of the authority key that seals the block at the `height`,
+    /// or `None` if no key is scheduled for the `height`.
+    pub fn signing_key_at(&self, height: BlockHeight) -> Option<Address> {
+        match self {
+            ConsensusConfig::PoA { signing_key } => Some(*signing_key),
+            ConsensusConfig::PoAKeyRotation { signing_keys } => signing_keys
+                .iter()
+                .filter(|rotation| rotation.height <= height)
+                .max_by_key(|rotation| rotation.height)
+                .map(|rotation| rotation.signing_key),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn signing_key_at_follows_the_rotation_schedule() {
+        let first = Address::new([1; 32]);
+        let second = Address::new([2; 32]);
+        let consensus = ConsensusConfig::PoAKeyRotation {
+            signing_keys: vec![
+                SigningKeyRotation {
+                    height: 10u32.into(),
+                    signing_key: second,
+                },
+                SigningKeyRotation {
+                    height: 1u32.into(),
+                    signing_key: first,
+                },
+            ],
+        };
+
+        assert_eq!(consensus.signing_key_at(0u32.into()), None);
+        assert_eq!(consensus.signing_key_at(9u32.into()), Some(first));
+        assert_eq!(consensus.signing_key_at(10u32.into()), Some(second));
+        assert_eq!(consensus.signing_key_at(100u32.into()), Some(second));
+    }
 }
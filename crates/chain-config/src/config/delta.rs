@@ -0,0 +1,715 @@
+use crate::serialization::{
+    HexNumber,
+    HexType,
+};
+use fuel_core_types::{
+    fuel_tx::ContractId,
+    fuel_types::{
+        AssetId,
+        BlockHeight,
+        Bytes32,
+        Nonce,
+        Salt,
+    },
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use serde_with::{
+    serde_as,
+    skip_serializing_none,
+};
+use std::collections::{
+    BTreeMap,
+    BTreeSet,
+};
+
+use super::{
+    coin::CoinConfig,
+    contract::ContractConfig,
+    message::MessageConfig,
+    state::StateConfig,
+};
+
+/// The id of the coin: the id of the transaction and the index of the output.
+type CoinKey = (Bytes32, u8);
+
+/// The changes of the state between two snapshots.
+///
+/// Applying the delta to the snapshot at `from_height` produces the snapshot
+/// at `to_height`. Deltas of consecutive ranges can be merged into one delta.
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+pub struct StateDelta {
+    /// The height of the snapshot to which the delta is applied.
+    #[serde_as(as = "HexNumber")]
+    pub from_height: BlockHeight,
+    /// The height of the snapshot produced by the delta.
+    #[serde_as(as = "HexNumber")]
+    pub to_height: BlockHeight,
+    /// Coins created after the `from_height`.
+    #[serde(default)]
+    pub coins: Vec<CoinConfig>,
+    /// Ids of coins spent after the `from_height`.
+    #[serde_as(as = "Vec<(HexType, HexNumber)>")]
+    #[serde(default)]
+    pub spent_coins: Vec<CoinKey>,
+    /// Contracts created or modified after the `from_height`.
+    #[serde(default)]
+    pub contracts: Vec<ContractDelta>,
+    /// Ids of contracts removed after the `from_height`.
+    #[serde_as(as = "Vec<HexType>")]
+    #[serde(default)]
+    pub removed_contracts: Vec<ContractId>,
+    /// Messages received after the `from_height`.
+    #[serde(default)]
+    pub messages: Vec<MessageConfig>,
+    /// Nonces of messages spent after the `from_height`.
+    #[serde_as(as = "Vec<HexType>")]
+    #[serde(default)]
+    pub spent_messages: Vec<Nonce>,
+}
+
+/// The changes of the contract between two snapshots.
+#[skip_serializing_none]
+#[serde_as]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Eq, PartialEq)]
+pub struct ContractDelta {
+    #[serde_as(as = "HexType")]
+    pub contract_id: ContractId,
+    /// The code of the contract. Set only for new contracts.
+    #[serde_as(as = "Option<HexType>")]
+    #[serde(default)]
+    pub code: Option<Vec<u8>>,
+    /// The salt of the contract. Set only for new contracts.
+    #[serde_as(as = "Option<HexType>")]
+    #[serde(default)]
+    pub salt: Option<Salt>,
+    /// Written storage slots.
+    #[serde_as(as = "Vec<(HexType, HexType)>")]
+    #[serde(default)]
+    pub state: Vec<(Bytes32, Bytes32)>,
+    /// Keys of removed storage slots.
+    #[serde_as(as = "Vec<HexType>")]
+    #[serde(default)]
+    pub removed_state: Vec<Bytes32>,
+    /// Changed balances.
+    #[serde_as(as = "Vec<(HexType, HexNumber)>")]
+    #[serde(default)]
+    pub balances: Vec<(AssetId, u64)>,
+    /// Assets of removed balances.
+    #[serde_as(as = "Vec<HexType>")]
+    #[serde(default)]
+    pub removed_balances: Vec<AssetId>,
+    /// The latest UTXO of the contract.
+    #[serde_as(as = "Option<HexType>")]
+    #[serde(default)]
+    pub tx_id: Option<Bytes32>,
+    #[serde_as(as = "Option<HexNumber>")]
+    #[serde(default)]
+    pub output_index: Option<u8>,
+    /// The latest tx pointer of the contract.
+    #[serde_as(as = "Option<HexNumber>")]
+    #[serde(default)]
+    pub tx_pointer_block_height: Option<BlockHeight>,
+    #[serde_as(as = "Option<HexNumber>")]
+    #[serde(default)]
+    pub tx_pointer_tx_idx: Option<u16>,
+}
+
+impl StateDelta {
+    /// Returns the delta between the `base` and the `target` snapshots.
+    ///
+    /// Both snapshots should have heights, and all coins should have UTXO ids,
+    /// which is always the case for snapshots of the database.
+    pub fn between(base: &StateConfig, target: &StateConfig) -> anyhow::Result<Self> {
+        let from_height = snapshot_height(base)?;
+        let to_height = snapshot_height(target)?;
+        anyhow::ensure!(
+            from_height <= to_height,
+            "The base snapshot at {from_height} is above the target snapshot at {to_height}"
+        );
+
+        let base_coins = coins_by_key(base)?;
+        let target_coins = coins_by_key(target)?;
+        let coins = target_coins
+            .iter()
+            .filter(|(key, coin)| base_coins.get(key) != Some(coin))
+            .map(|(_, coin)| (*coin).clone())
+            .collect();
+        let spent_coins = base_coins
+            .keys()
+            .filter(|key| !target_coins.contains_key(key))
+            .copied()
+            .collect();
+
+        let base_contracts = contracts_by_id(base);
+        let target_contracts = contracts_by_id(target);
+        let contracts = target_contracts
+            .iter()
+            .filter_map(|(id, contract)| {
+                ContractDelta::between(base_contracts.get(id).copied(), contract)
+            })
+            .collect();
+        let removed_contracts = base_contracts
+            .keys()
+            .filter(|id| !target_contracts.contains_key(id))
+            .copied()
+            .collect();
+
+        let base_messages = messages_by_nonce(base);
+        let target_messages = messages_by_nonce(target);
+        let messages = target_messages
+            .iter()
+            .filter(|(nonce, message)| base_messages.get(nonce) != Some(message))
+            .map(|(_, message)| (*message).clone())
+            .collect();
+        let spent_messages = base_messages
+            .keys()
+            .filter(|nonce| !target_messages.contains_key(nonce))
+            .copied()
+            .collect();
+
+        Ok(Self {
+            from_height,
+            to_height,
+            coins,
+            spent_coins,
+            contracts,
+            removed_contracts,
+            messages,
+            spent_messages,
+        })
+    }
+
+    /// Applies the delta to the `base` snapshot at the `from_height`.
+    pub fn apply(&self, base: &StateConfig) -> anyhow::Result<StateConfig> {
+        let height = snapshot_height(base)?;
+        anyhow::ensure!(
+            height == self.from_height,
+            "The delta from {} can't be applied to the snapshot at {height}",
+            self.from_height
+        );
+
+        let mut coins: BTreeMap<_, _> = coins_by_key(base)?
+            .into_iter()
+            .map(|(key, coin)| (key, coin.clone()))
+            .collect();
+        for key in &self.spent_coins {
+            coins.remove(key);
+        }
+        for coin in &self.coins {
+            coins.insert(coin_key(coin)?, coin.clone());
+        }
+
+        let mut contracts: BTreeMap<_, _> = contracts_by_id(base)
+            .into_iter()
+            .map(|(id, contract)| (id, contract.clone()))
+            .collect();
+        for id in &self.removed_contracts {
+            contracts.remove(id);
+        }
+        for delta in &self.contracts {
+            let contract = delta.apply(contracts.remove(&delta.contract_id))?;
+            contracts.insert(delta.contract_id, contract);
+        }
+
+        let mut messages: BTreeMap<_, _> = messages_by_nonce(base)
+            .into_iter()
+            .map(|(nonce, message)| (nonce, message.clone()))
+            .collect();
+        for nonce in &self.spent_messages {
+            messages.remove(nonce);
+        }
+        for message in &self.messages {
+            messages.insert(message.nonce, message.clone());
+        }
+
+        Ok(StateConfig {
+            coins: non_empty(base.coins.is_some(), coins.into_values().collect()),
+            contracts: non_empty(
+                base.contracts.is_some(),
+                contracts.into_values().collect(),
+            ),
+            messages: non_empty(
+                base.messages.is_some(),
+                messages.into_values().collect(),
+            ),
+            height: Some(self.to_height),
+        })
+    }
+
+    /// Merges the delta with the `next` delta that starts at the `to_height` of this one.
+    /// Applying the merged delta is equivalent to applying both deltas one by one.
+    pub fn merge(self, next: StateDelta) -> anyhow::Result<StateDelta> {
+        anyhow::ensure!(
+            self.to_height == next.from_height,
+            "The delta to {} can't be merged with the delta from {}",
+            self.to_height,
+            next.from_height
+        );
+
+        let mut coins = BTreeMap::new();
+        for coin in self.coins {
+            coins.insert(coin_key(&coin)?, coin);
+        }
+        let mut spent_coins: BTreeSet<_> = self.spent_coins.into_iter().collect();
+        for key in next.spent_coins {
+            // Coins created and spent between two snapshots don't affect the result.
+            if coins.remove(&key).is_none() {
+                spent_coins.insert(key);
+            }
+        }
+        for coin in next.coins {
+            coins.insert(coin_key(&coin)?, coin);
+        }
+
+        let mut contracts: BTreeMap<_, _> = self
+            .contracts
+            .into_iter()
+            .map(|delta| (delta.contract_id, delta))
+            .collect();
+        let mut removed_contracts: BTreeSet<_> =
+            self.removed_contracts.into_iter().collect();
+        for id in next.removed_contracts {
+            contracts.remove(&id);
+            removed_contracts.insert(id);
+        }
+        for delta in next.contracts {
+            let merged = match contracts.remove(&delta.contract_id) {
+                Some(previous) => previous.merge(delta),
+                None => delta,
+            };
+            contracts.insert(merged.contract_id, merged);
+        }
+
+        let mut messages: BTreeMap<_, _> = self
+            .messages
+            .into_iter()
+            .map(|message| (message.nonce, message))
+            .collect();
+        let mut spent_messages: BTreeSet<_> = self.spent_messages.into_iter().collect();
+        for nonce in next.spent_messages {
+            if messages.remove(&nonce).is_none() {
+                spent_messages.insert(nonce);
+            }
+        }
+        for message in next.messages {
+            messages.insert(message.nonce, message);
+        }
+
+        Ok(StateDelta {
+            from_height: self.from_height,
+            to_height: next.to_height,
+            coins: coins.into_values().collect(),
+            spent_coins: spent_coins.into_iter().collect(),
+            contracts: contracts.into_values().collect(),
+            removed_contracts: removed_contracts.into_iter().collect(),
+            messages: messages.into_values().collect(),
+            spent_messages: spent_messages.into_iter().collect(),
+        })
+    }
+}
+
+impl ContractDelta {
+    /// Returns the delta of the contract, or `None` if the contract is unchanged.
+    fn between(base: Option<&ContractConfig>, target: &ContractConfig) -> Option<Self> {
+        if base == Some(target) {
+            return None
+        }
+        let (code, salt) = match base {
+            Some(base) if base.code == target.code && base.salt == target.salt => {
+                (None, None)
+            }
+            _ => (Some(target.code.clone()), Some(target.salt)),
+        };
+
+        let base_state = base.map(|base| pairs(&base.state)).unwrap_or_default();
+        let target_state = pairs(&target.state);
+        let (state, removed_state) = diff(&base_state, &target_state);
+
+        let base_balances = base.map(|base| pairs(&base.balances)).unwrap_or_default();
+        let target_balances = pairs(&target.balances);
+        let (balances, removed_balances) = diff(&base_balances, &target_balances);
+
+        Some(Self {
+            contract_id: target.contract_id,
+            code,
+            salt,
+            state,
+            removed_state,
+            balances,
+            removed_balances,
+            tx_id: target.tx_id,
+            output_index: target.output_index,
+            tx_pointer_block_height: target.tx_pointer_block_height,
+            tx_pointer_tx_idx: target.tx_pointer_tx_idx,
+        })
+    }
+
+    fn apply(&self, base: Option<ContractConfig>) -> anyhow::Result<ContractConfig> {
+        let mut contract = match base {
+            Some(contract) => contract,
+            None => ContractConfig {
+                contract_id: self.contract_id,
+                code: self.code.clone().ok_or_else(|| {
+                    anyhow::anyhow!("The new contract {} has no code", self.contract_id)
+                })?,
+                salt: self.salt.ok_or_else(|| {
+                    anyhow::anyhow!("The new contract {} has no salt", self.contract_id)
+                })?,
+                state: None,
+                balances: None,
+                tx_id: None,
+                output_index: None,
+                tx_pointer_block_height: None,
+                tx_pointer_tx_idx: None,
+            },
+        };
+        if let Some(code) = &self.code {
+            contract.code = code.clone();
+        }
+        if let Some(salt) = self.salt {
+            contract.salt = salt;
+        }
+        contract.state = patch(contract.state.take(), &self.state, &self.removed_state);
+        contract.balances = patch(
+            contract.balances.take(),
+            &self.balances,
+            &self.removed_balances,
+        );
+        contract.tx_id = self.tx_id;
+        contract.output_index = self.output_index;
+        contract.tx_pointer_block_height = self.tx_pointer_block_height;
+        contract.tx_pointer_tx_idx = self.tx_pointer_tx_idx;
+        Ok(contract)
+    }
+
+    fn merge(self, next: ContractDelta) -> ContractDelta {
+        let (state, removed_state) = merge_changes(
+            self.state,
+            self.removed_state,
+            next.state,
+            next.removed_state,
+        );
+        let (balances, removed_balances) = merge_changes(
+            self.balances,
+            self.removed_balances,
+            next.balances,
+            next.removed_balances,
+        );
+        ContractDelta {
+            contract_id: self.contract_id,
+            code: next.code.or(self.code),
+            salt: next.salt.or(self.salt),
+            state,
+            removed_state,
+            balances,
+            removed_balances,
+            tx_id: next.tx_id,
+            output_index: next.output_index,
+            tx_pointer_block_height: next.tx_pointer_block_height,
+            tx_pointer_tx_idx: next.tx_pointer_tx_idx,
+        }
+    }
+}
+
+fn snapshot_height(state: &StateConfig) -> anyhow::Result<BlockHeight> {
+    state
+        .height
+        .ok_or_else(|| anyhow::anyhow!("The snapshot doesn't have the height"))
+}
+
+fn coin_key(coin: &CoinConfig) -> anyhow::Result<CoinKey> {
+    match (coin.tx_id, coin.output_index) {
+        (Some(tx_id), Some(output_index)) => Ok((tx_id, output_index)),
+        _ => Err(anyhow::anyhow!(
+            "The coin {coin:?} doesn't have the UTXO id"
+        )),
+    }
+}
+
+fn coins_by_key(state: &StateConfig) -> anyhow::Result<BTreeMap<CoinKey, &CoinConfig>> {
+    state
+        .coins
+        .iter()
+        .flatten()
+        .map(|coin| coin_key(coin).map(|key| (key, coin)))
+        .collect()
+}
+
+fn contracts_by_id(state: &StateConfig) -> BTreeMap<ContractId, &ContractConfig> {
+    state
+        .contracts
+        .iter()
+        .flatten()
+        .map(|contract| (contract.contract_id, contract))
+        .collect()
+}
+
+fn messages_by_nonce(state: &StateConfig) -> BTreeMap<Nonce, &MessageConfig> {
+    state
+        .messages
+        .iter()
+        .flatten()
+        .map(|message| (message.nonce, message))
+        .collect()
+}
+
+fn non_empty<T>(was_set: bool, items: Vec<T>) -> Option<Vec<T>> {
+    if items.is_empty() && !was_set {
+        None
+    } else {
+        Some(items)
+    }
+}
+
+fn pairs<K: Ord + Copy, V: Copy>(pairs: &Option<Vec<(K, V)>>) -> BTreeMap<K, V> {
+    pairs.iter().flatten().copied().collect()
+}
+
+/// Returns the written and the removed entries between the `base` and the `target`.
+fn diff<K: Ord + Copy, V: PartialEq + Copy>(
+    base: &BTreeMap<K, V>,
+    target: &BTreeMap<K, V>,
+) -> (Vec<(K, V)>, Vec<K>) {
+    let written = target
+        .iter()
+        .filter(|(key, value)| base.get(key) != Some(value))
+        .map(|(key, value)| (*key, *value))
+        .collect();
+    let removed = base
+        .keys()
+        .filter(|key| !target.contains_key(key))
+        .copied()
+        .collect();
+    (written, removed)
+}
+
+fn patch<K: Ord + Copy, V: Copy>(
+    base: Option<Vec<(K, V)>>,
+    written: &[(K, V)],
+    removed: &[K],
+) -> Option<Vec<(K, V)>> {
+    let was_set = base.is_some();
+    let mut entries = pairs(&base);
+    for key in removed {
+        entries.remove(key);
+    }
+    entries.extend(written.iter().copied());
+    non_empty(was_set, entries.into_iter().collect())
+}
+
+fn merge_changes<K: Ord + Copy, V: Copy>(
+    written: Vec<(K, V)>,
+    removed: Vec<K>,
+    next_written: Vec<(K, V)>,
+    next_removed: Vec<K>,
+) -> (Vec<(K, V)>, Vec<K>) {
+    let mut written: BTreeMap<_, _> = written.into_iter().collect();
+    let mut removed: BTreeSet<_> = removed.into_iter().collect();
+    for key in next_removed {
+        written.remove(&key);
+        removed.insert(key);
+    }
+    for (key, value) in next_written {
+        removed.remove(&key);
+        written.insert(key, value);
+    }
+    (written.into_iter().collect(), removed.into_iter().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{
+        prelude::StdRng,
+        Rng,
+        SeedableRng,
+    };
+
+    fn coin(rng: &mut StdRng) -> CoinConfig {
+        CoinConfig {
+            tx_id: Some(rng.gen()),
+            output_index: Some(rng.gen()),
+            tx_pointer_block_height: None,
+            tx_pointer_tx_idx: None,
+            maturity: None,
+            owner: rng.gen(),
+            amount: rng.gen(),
+            asset_id: rng.gen(),
+        }
+    }
+
+    fn message(rng: &mut StdRng) -> MessageConfig {
+        MessageConfig {
+            sender: rng.gen(),
+            recipient: rng.gen(),
+            nonce: rng.gen(),
+            amount: rng.gen(),
+            data: vec![rng.gen()],
+            da_height: Default::default(),
+        }
+    }
+
+    fn contract(rng: &mut StdRng) -> ContractConfig {
+        ContractConfig {
+            contract_id: rng.gen(),
+            code: vec![rng.gen()],
+            salt: rng.gen(),
+            state: Some(vec![(rng.gen(), rng.gen()), (rng.gen(), rng.gen())]),
+            balances: Some(vec![(rng.gen(), rng.gen())]),
+            tx_id: Some(rng.gen()),
+            output_index: Some(rng.gen()),
+            tx_pointer_block_height: None,
+            tx_pointer_tx_idx: None,
+        }
+    }
+
+    /// Sorts the entries of the snapshot in the order of the database.
+    fn sorted(mut state: StateConfig) -> StateConfig {
+        if let Some(coins) = &mut state.coins {
+            coins.sort_by_key(|coin| coin_key(coin).unwrap());
+        }
+        if let Some(contracts) = &mut state.contracts {
+            contracts.sort_by_key(|contract| contract.contract_id);
+            for contract in contracts {
+                contract.state.iter_mut().for_each(|state| state.sort());
+                contract.balances.iter_mut().for_each(|state| state.sort());
+            }
+        }
+        if let Some(messages) = &mut state.messages {
+            messages.sort_by_key(|message| message.nonce);
+        }
+        state
+    }
+
+    fn snapshot(
+        height: u32,
+        coins: Vec<CoinConfig>,
+        contracts: Vec<ContractConfig>,
+        messages: Vec<MessageConfig>,
+    ) -> StateConfig {
+        sorted(StateConfig {
+            coins: Some(coins),
+            contracts: Some(contracts),
+            messages: Some(messages),
+            height: Some(height.into()),
+        })
+    }
+
+    /// Returns three consecutive snapshots of the changing state.
+    fn snapshots() -> [StateConfig; 3] {
+        let mut rng = StdRng::seed_from_u64(1);
+        let coins: Vec<_> = (0..4).map(|_| coin(&mut rng)).collect();
+        let messages: Vec<_> = (0..3).map(|_| message(&mut rng)).collect();
+        let first_contract = contract(&mut rng);
+        let second_contract = contract(&mut rng);
+
+        let mut updated_contract = first_contract.clone();
+        updated_contract.state.as_mut().unwrap()[0].1 = rng.gen();
+        updated_contract.state.as_mut().unwrap().remove(1);
+        updated_contract.balances = Some(vec![(rng.gen(), rng.gen())]);
+        updated_contract.tx_id = Some(rng.gen());
+
+        let first = snapshot(
+            10,
+            coins[0..2].to_vec(),
+            vec![first_contract],
+            messages[0..1].to_vec(),
+        );
+        let second = snapshot(
+            20,
+            coins[1..3].to_vec(),
+            vec![updated_contract.clone(), second_contract.clone()],
+            messages[1..3].to_vec(),
+        );
+        let third = snapshot(
+            30,
+            vec![coins[1].clone(), coins[3].clone()],
+            vec![updated_contract],
+            messages[2..3].to_vec(),
+        );
+        [first, second, third]
+    }
+
+    #[test]
+    fn applied_delta_produces_target_snapshot() {
+        let [base, target, _] = snapshots();
+
+        let delta = StateDelta::between(&base, &target).unwrap();
+
+        assert_eq!(sorted(delta.apply(&base).unwrap()), target);
+    }
+
+    #[test]
+    fn delta_contains_only_changes() {
+        let [base, target, _] = snapshots();
+
+        let delta = StateDelta::between(&base, &target).unwrap();
+
+        assert_eq!(delta.coins.len(), 1);
+        assert_eq!(delta.spent_coins.len(), 1);
+        assert_eq!(delta.messages.len(), 2);
+        assert_eq!(delta.spent_messages.len(), 1);
+        assert_eq!(delta.contracts.len(), 2);
+        // The existing contract doesn't repeat its code.
+        let updated = delta
+            .contracts
+            .iter()
+            .find(|contract| contract.code.is_none())
+            .expect("The existing contract is updated");
+        assert_eq!(updated.state.len(), 1);
+        assert_eq!(updated.removed_state.len(), 1);
+    }
+
+    #[test]
+    fn delta_of_same_snapshot_is_empty() {
+        let [base, _, _] = snapshots();
+
+        let delta = StateDelta::between(&base, &base).unwrap();
+
+        assert!(delta.coins.is_empty());
+        assert!(delta.spent_coins.is_empty());
+        assert!(delta.contracts.is_empty());
+        assert!(delta.messages.is_empty());
+        assert!(delta.spent_messages.is_empty());
+    }
+
+    #[test]
+    fn merged_delta_is_equal_to_applying_deltas_one_by_one() {
+        let [first, second, third] = snapshots();
+        let first_delta = StateDelta::between(&first, &second).unwrap();
+        let second_delta = StateDelta::between(&second, &third).unwrap();
+
+        let merged = first_delta.merge(second_delta).unwrap();
+
+        assert_eq!(merged.from_height, 10u32.into());
+        assert_eq!(merged.to_height, 30u32.into());
+        assert_eq!(sorted(merged.apply(&first).unwrap()), third);
+    }
+
+    #[test]
+    fn delta_is_not_applied_to_snapshot_at_other_height() {
+        let [first, second, third] = snapshots();
+        let delta = StateDelta::between(&second, &third).unwrap();
+
+        assert!(delta.apply(&first).is_err());
+        assert!(StateDelta::between(&first, &second)
+            .unwrap()
+            .merge(StateDelta::between(&first, &second).unwrap())
+            .is_err());
+    }
+
+    #[test]
+    fn can_roundtrip_serialize_delta() {
+        let [base, target, _] = snapshots();
+        let delta = StateDelta::between(&base, &target).unwrap();
+
+        let json = serde_json::to_string(&delta).unwrap();
+        let deserialized: StateDelta = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(delta, deserialized);
+    }
+}
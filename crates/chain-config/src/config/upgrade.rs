@@ -0,0 +1,64 @@
+use fuel_core_types::{
+    fuel_tx::ConsensusParameters,
+    fuel_types::BlockHeight,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+/// The consensus parameters in effect starting from the `height`.
+#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+pub struct ConsensusParametersUpgrade {
+    pub height: BlockHeight,
+    pub consensus_parameters: ConsensusParameters,
+}
+
+/// Returns the consensus parameters in effect at the `height`: the parameters of
+/// the latest upgrade activated at or before the `height`, or the `genesis` parameters.
+pub fn consensus_parameters_at<'a>(
+    genesis: &'a ConsensusParameters,
+    upgrades: &'a [ConsensusParametersUpgrade],
+    height: BlockHeight,
+) -> &'a ConsensusParameters {
+    upgrade_at(upgrades, height)
+        .map(|upgrade| &upgrade.consensus_parameters)
+        .unwrap_or(genesis)
+}
+
+/// Returns the latest upgrade activated at or before the `height`, if any.
+pub fn upgrade_at(
+    upgrades: &[ConsensusParametersUpgrade],
+    height: BlockHeight,
+) -> Option<&ConsensusParametersUpgrade> {
+    upgrades
+        .iter()
+        .filter(|upgrade| upgrade.height <= height)
+        .max_by_key(|upgrade| upgrade.height)
+}
+
+/// Checks that the `upgrades` can be applied on top of the `genesis` parameters.
+/// Upgrades can't be scheduled at the same height, and they can't change
+/// the chain id or the base asset, because the existing transactions and coins depend on them.
+pub fn validate_upgrades(
+    genesis: &ConsensusParameters,
+    upgrades: &[ConsensusParametersUpgrade],
+) -> anyhow::Result<()> {
+    for (i, upgrade) in upgrades.iter().enumerate() {
+        let height = upgrade.height;
+        if upgrades[..i].iter().any(|other| other.height == height) {
+            anyhow::bail!("More than one upgrade of consensus parameters at {height}");
+        }
+        if upgrade.consensus_parameters.chain_id != genesis.chain_id {
+            anyhow::bail!(
+                "The upgrade of consensus parameters at {height} changes the chain id"
+            );
+        }
+        if upgrade.consensus_parameters.base_asset_id != genesis.base_asset_id {
+            anyhow::bail!(
+                "The upgrade of consensus parameters at {height} changes the base asset id"
+            );
+        }
+    }
+    Ok(())
+}
@@ -1,11 +1,15 @@
 use crate::client::{
     schema::{
         block::BlockByHeightArgs,
+        chain::ConsensusParametersByHeightArgs,
         coins::{
             ExcludeInput,
             SpendQueryElementInput,
         },
-        contract::ContractBalanceQueryArgs,
+        contract::{
+            ContractBalanceQueryArgs,
+            ContractStateDiffArgs,
+        },
         message::MessageStatusArgs,
         tx::DryRunArg,
         Tai64Timestamp,
@@ -38,7 +42,9 @@ use fuel_core_types::{
         Instruction,
         Word,
     },
+    fuel_crypto::PublicKey,
     fuel_tx::{
+        ConsensusParameters,
         Receipt,
         Transaction,
         TxId,
@@ -51,7 +57,15 @@ use fuel_core_types::{
     },
     services::{
         executor::TransactionExecutionStatus,
-        p2p::PeerInfo,
+        node_identity::{
+            NodeIdentity,
+            SignedNodeIdentity,
+            NODE_IDENTITY_PATH,
+        },
+        p2p::{
+            NetworkInfo,
+            PeerInfo,
+        },
     },
 };
 #[cfg(feature = "subscriptions")]
@@ -68,6 +82,8 @@ use schema::{
     coins::CoinByIdArgs,
     contract::ContractByIdArgs,
     tx::{
+        DumpTxPoolArgs,
+        FlushTxPoolArgs,
         TxArg,
         TxIdArgs,
     },
@@ -79,6 +95,7 @@ use schema::{
     IdArg,
     MemoryArgs,
     RegisterArgs,
+    RemoveBreakpoint,
     RunResult,
     SetBreakpoint,
     SetBreakpointArgs,
@@ -86,6 +103,10 @@ use schema::{
     SetSingleSteppingArgs,
     StartTx,
     StartTxArgs,
+    StepTx,
+    TraceTransaction,
+    TraceTransactionArgs,
+    TransactionTrace,
     U32,
     U64,
 };
@@ -102,16 +123,21 @@ use std::{
         self,
         FromStr,
     },
+    time::UNIX_EPOCH,
 };
 use tai64::Tai64;
 use tracing as _;
 use types::{
+    TransactionConfirmation,
     TransactionResponse,
     TransactionStatus,
 };
 
 use self::schema::{
-    block::ProduceBlockArgs,
+    block::{
+        ProduceBlockArgs,
+        SetBlockProductionArgs,
+    },
     message::{
         MessageProofArgs,
         NonceArgs,
@@ -343,11 +369,62 @@ impl FuelClient {
         self.query(query).await.map(|r| r.health)
     }
 
+    /// Returns the identity of the node signed by its node key, or `None` if
+    /// the node doesn't serve the identity. The signature isn't verified.
+    pub async fn node_identity(&self) -> io::Result<Option<SignedNodeIdentity>> {
+        let mut url = self.url.clone();
+        url.set_path(NODE_IDENTITY_PATH);
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None)
+        }
+        let identity = response
+            .error_for_status()
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?
+            .json()
+            .await
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        Ok(Some(identity))
+    }
+
+    /// Returns the identity of the node if it is signed by the expected `node_key`,
+    /// issued for the `network` and the URL of the node, and issued recently.
+    /// Otherwise, returns an error.
+    pub async fn verified_node_identity(
+        &self,
+        node_key: &PublicKey,
+        network: &str,
+    ) -> io::Result<NodeIdentity> {
+        let identity = self.node_identity().await?.ok_or_else(|| {
+            io::Error::new(ErrorKind::NotFound, "The node doesn't serve its identity")
+        })?;
+        let mut url = self.url.clone();
+        url.set_path(NODE_IDENTITY_PATH);
+        let now = UNIX_EPOCH.elapsed().unwrap_or_default().as_secs();
+        identity
+            .verify_expected(node_key, network, url.as_str(), now)
+            .map_err(|e| io::Error::new(ErrorKind::InvalidData, e))?;
+        Ok(identity.identity)
+    }
+
     pub async fn node_info(&self) -> io::Result<types::NodeInfo> {
         let query = schema::node_info::QueryNodeInfo::build(());
         self.query(query).await.map(|r| r.node_info.into())
     }
 
+    /// Returns the telemetry of the P2P network, or `None` if the P2P network is disabled.
+    pub async fn network_info(&self) -> io::Result<Option<NetworkInfo>> {
+        let query = schema::network_info::QueryNetworkInfo::build(());
+        self.query(query)
+            .await
+            .map(|r| r.network_info.map(Into::into))
+    }
+
     pub async fn connected_peers_info(&self) -> io::Result<Vec<PeerInfo>> {
         let query = schema::node_info::QueryPeersInfo::build(());
         self.query(query)
@@ -360,6 +437,21 @@ impl FuelClient {
         self.query(query).await.map(|r| r.chain.into())
     }
 
+    /// Returns the consensus parameters in effect at the `height`.
+    pub async fn consensus_parameters(
+        &self,
+        height: BlockHeight,
+    ) -> io::Result<ConsensusParameters> {
+        let query = schema::chain::ConsensusParametersByHeightQuery::build(
+            ConsensusParametersByHeightArgs {
+                height: U32(*height),
+            },
+        );
+        self.query(query)
+            .await
+            .map(|r| r.consensus_parameters.into())
+    }
+
     /// Default dry run, matching the exact configuration as the node
     pub async fn dry_run(
         &self,
@@ -391,6 +483,29 @@ impl FuelClient {
             .collect()
     }
 
+    /// Dry run of the bundle of transactions executed one after another on the same
+    /// fork of the state. Returns the result of each transaction in the order of the bundle.
+    pub async fn dry_run_bundle(
+        &self,
+        txs: &[Transaction],
+        // Disable utxo input checks (exists, unspent, and valid signature)
+        utxo_validation: Option<bool>,
+    ) -> io::Result<Vec<types::DryRunBundleStep>> {
+        let txs = txs
+            .iter()
+            .map(|tx| HexString(Bytes(tx.to_bytes())))
+            .collect::<Vec<HexString>>();
+        let query = schema::tx::DryRunBundle::build(DryRunArg {
+            txs,
+            utxo_validation,
+        });
+        let steps = self.query(query).await.map(|r| r.dry_run_bundle)?;
+        steps
+            .into_iter()
+            .map(|step| step.try_into().map_err(Into::into))
+            .collect()
+    }
+
     /// Estimate predicates for the transaction
     pub async fn estimate_predicates(&self, tx: &mut Transaction) -> io::Result<()> {
         let serialized_tx = tx.to_bytes();
@@ -416,6 +531,49 @@ impl FuelClient {
         Ok(id)
     }
 
+    /// Pauses the insertion of new transactions into the `TxPool` of the node.
+    /// Requires the `debug` mode of the node.
+    pub async fn pause_tx_pool(&self) -> io::Result<()> {
+        let query = schema::tx::PauseTxPool::build(());
+        self.query(query).await?;
+        Ok(())
+    }
+
+    /// Resumes the insertion of new transactions into the `TxPool` of the node.
+    /// Requires the `debug` mode of the node.
+    pub async fn resume_tx_pool(&self) -> io::Result<()> {
+        let query = schema::tx::ResumeTxPool::build(());
+        self.query(query).await?;
+        Ok(())
+    }
+
+    /// Removes transactions of the `owner` or the `contract`, or all transactions if
+    /// neither is set, from the `TxPool` of the node. Returns the number of removed
+    /// transactions. Requires the `debug` mode of the node.
+    pub async fn flush_tx_pool(
+        &self,
+        owner: Option<&Address>,
+        contract: Option<&ContractId>,
+    ) -> io::Result<u64> {
+        let query = schema::tx::FlushTxPool::build(FlushTxPoolArgs {
+            owner: owner.map(|owner| (*owner).into()),
+            contract: contract.map(|contract| (*contract).into()),
+        });
+        let flushed = self.query(query).await?.flush_tx_pool;
+        Ok(flushed.into())
+    }
+
+    /// Writes the state of the `TxPool` as JSON to the new file at `path` relative
+    /// to the dump directory of the node. Returns the number of dumped transactions.
+    /// Requires the `debug` mode of the node with the configured dump directory.
+    pub async fn dump_tx_pool(&self, path: &str) -> io::Result<u64> {
+        let query = schema::tx::DumpTxPool::build(DumpTxPoolArgs {
+            path: path.to_string(),
+        });
+        let dumped = self.query(query).await?.dump_tx_pool;
+        Ok(dumped.into())
+    }
+
     /// Submit the transaction and wait for it either to be included in
     /// a block or removed from `TxPool`.
     ///
@@ -483,6 +641,18 @@ impl FuelClient {
         Ok(self.query(query).await?.register.0 as Word)
     }
 
+    pub async fn registers(&self, id: &str) -> io::Result<Vec<Word>> {
+        let query = schema::Registers::build(IdArg { id: id.into() });
+
+        Ok(self
+            .query(query)
+            .await?
+            .registers
+            .into_iter()
+            .map(|register| register.0 as Word)
+            .collect())
+    }
+
     pub async fn memory(&self, id: &str, start: u32, size: u32) -> io::Result<Vec<u8>> {
         let query = schema::Memory::build(MemoryArgs {
             id: id.into(),
@@ -517,6 +687,28 @@ impl FuelClient {
         Ok(())
     }
 
+    pub async fn remove_breakpoint(
+        &self,
+        session_id: &str,
+        contract: fuel_types::ContractId,
+        pc: u64,
+    ) -> io::Result<()> {
+        let operation = RemoveBreakpoint::build(SetBreakpointArgs {
+            id: Id::new(session_id),
+            bp: schema::Breakpoint {
+                contract: contract.into(),
+                pc: U64(pc),
+            },
+        });
+
+        let response = self.query(operation).await?;
+        assert!(
+            response.remove_breakpoint,
+            "Removing breakpoint returned invalid reply"
+        );
+        Ok(())
+    }
+
     pub async fn set_single_stepping(
         &self,
         session_id: &str,
@@ -551,6 +743,29 @@ impl FuelClient {
         Ok(response)
     }
 
+    pub async fn step_tx(&self, session_id: &str) -> io::Result<RunResult> {
+        let operation = StepTx::build(ContinueTxArgs {
+            id: Id::new(session_id),
+        });
+        let response = self.query(operation).await?.step_tx;
+        Ok(response)
+    }
+
+    /// Re-executes the committed transaction and returns the trace of executed
+    /// instructions. Requires the debug mode of the node.
+    pub async fn trace_transaction(
+        &self,
+        id: &TxId,
+        max_steps: Option<u32>,
+    ) -> io::Result<TransactionTrace> {
+        let query = TraceTransaction::build(TraceTransactionArgs {
+            id: (*id).into(),
+            max_steps: max_steps.map(Into::into),
+        });
+        let trace = self.query(query).await?.trace_transaction;
+        Ok(trace)
+    }
+
     pub async fn transaction(
         &self,
         id: &TxId,
@@ -585,6 +800,27 @@ impl FuelClient {
         Ok(status)
     }
 
+    /// Get the confirmation of the transaction included in the block. Returns `None`
+    /// if the transaction is unknown or isn't included in the block yet.
+    pub async fn transaction_confirmation(
+        &self,
+        id: &TxId,
+    ) -> io::Result<Option<TransactionConfirmation>> {
+        let query = schema::tx::TransactionConfirmationQuery::build(TxIdArgs {
+            id: (*id).into(),
+        });
+
+        let status = self
+            .query(query)
+            .await?
+            .transaction
+            .and_then(|tx| tx.status);
+        match status {
+            Some(schema::tx::ConfirmationTransactionStatus::Unknown) | None => Ok(None),
+            Some(status) => Ok(Some(status.try_into()?)),
+        }
+    }
+
     #[tracing::instrument(skip(self), level = "debug")]
     #[cfg(feature = "subscriptions")]
     /// Subscribe to the status of a transaction
@@ -720,6 +956,22 @@ impl FuelClient {
         Ok(new_height.into())
     }
 
+    /// Changes the trigger of the block production of the node.
+    /// Requires the `debug` flag of the node.
+    pub async fn set_block_production(
+        &self,
+        trigger: types::BlockProductionTrigger,
+    ) -> io::Result<()> {
+        let query =
+            schema::block::SetBlockProductionMutation::build(SetBlockProductionArgs {
+                trigger: trigger.into(),
+            });
+
+        self.query(query).await?;
+
+        Ok(())
+    }
+
     pub async fn block(&self, id: &BlockId) -> io::Result<Option<types::Block>> {
         let query = schema::block::BlockByIdQuery::build(BlockByIdArgs {
             id: Some((*id).into()),
@@ -850,6 +1102,31 @@ impl FuelClient {
         Ok(balance.amount)
     }
 
+    /// Returns the values of the contract storage slots written by blocks
+    /// from the `from` height to the `to` height inclusively.
+    pub async fn contract_state_diff(
+        &self,
+        contract_id: &ContractId,
+        from: BlockHeight,
+        to: BlockHeight,
+    ) -> io::Result<Vec<types::ContractStateChange>> {
+        let query =
+            schema::contract::ContractStateDiffQuery::build(ContractStateDiffArgs {
+                contract_id: (*contract_id).into(),
+                from_height: from.into(),
+                to_height: to.into(),
+            });
+
+        let changes = self
+            .query(query)
+            .await?
+            .contract_state_diff
+            .into_iter()
+            .map(Into::into)
+            .collect();
+        Ok(changes)
+    }
+
     pub async fn balance(
         &self,
         owner: &Address,
@@ -32,6 +32,7 @@ pub mod chain;
 pub mod coins;
 pub mod contract;
 pub mod message;
+pub mod network_info;
 pub mod node_info;
 pub mod primitives;
 pub mod tx;
@@ -109,6 +110,17 @@ pub struct Register {
     pub register: U64,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "IdArg"
+)]
+pub struct Registers {
+    #[arguments(id: $id)]
+    pub registers: Vec<U64>,
+}
+
 #[derive(cynic::QueryVariables)]
 pub struct MemoryArgs {
     pub id: cynic::Id,
@@ -144,6 +156,17 @@ pub struct SetBreakpoint {
     pub set_breakpoint: bool,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "SetBreakpointArgs"
+)]
+pub struct RemoveBreakpoint {
+    #[arguments(id: $id, breakpoint: $bp)]
+    pub remove_breakpoint: bool,
+}
+
 #[derive(cynic::InputObject, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct Breakpoint {
@@ -201,6 +224,17 @@ pub struct ContinueTx {
     pub continue_tx: RunResult,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "ContinueTxArgs"
+)]
+pub struct StepTx {
+    #[arguments(id: $id)]
+    pub step_tx: RunResult,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct RunResult {
@@ -234,6 +268,50 @@ pub struct OutputBreakpoint {
     pub pc: U64,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct TraceTransactionArgs {
+    pub id: TransactionId,
+    pub max_steps: Option<U32>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TraceTransactionArgs"
+)]
+pub struct TraceTransaction {
+    #[arguments(id: $id, maxSteps: $max_steps)]
+    pub trace_transaction: TransactionTrace,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TransactionTrace {
+    pub steps: Vec<TraceStep>,
+    pub truncated: bool,
+    pub json_receipts: Vec<String>,
+}
+
+impl TransactionTrace {
+    pub fn receipts(&self) -> impl Iterator<Item = fuel_tx::Receipt> + '_ {
+        self.json_receipts.iter().map(|r| {
+            serde_json::from_str::<fuel_tx::Receipt>(r)
+                .expect("Receipt deserialization failed, server/client version mismatch")
+        })
+    }
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TraceStep {
+    pub contract: Option<ContractId>,
+    pub pc: U64,
+    pub instruction: Option<U32>,
+    pub opcode: Option<String>,
+    pub registers: Vec<U64>,
+}
+
 /// Generic graphql pagination query args
 #[derive(cynic::QueryVariables, Debug, Default)]
 pub struct ConnectionArgs {
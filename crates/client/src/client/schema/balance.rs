@@ -2,9 +2,9 @@ use crate::client::{
     schema::{
         schema,
         Address,
+        Amount,
         AssetId,
         PageInfo,
-        U64,
     },
     PageDirection,
     PaginationRequest,
@@ -99,7 +99,7 @@ pub struct BalanceEdge {
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct Balance {
     pub owner: Address,
-    pub amount: U64,
+    pub amount: Amount,
     pub asset_id: AssetId,
 }
 
@@ -104,6 +104,40 @@ pub struct BlockMutation {
     pub produce_blocks: U32,
 }
 
+#[derive(cynic::Enum, Clone, Copy, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum BlockProductionMode {
+    Never,
+    Instant,
+    Interval,
+    Hybrid,
+}
+
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct BlockProductionTriggerInput {
+    pub mode: BlockProductionMode,
+    pub block_time_ms: Option<U64>,
+    pub min_block_time_ms: Option<U64>,
+    pub max_block_time_ms: Option<U64>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct SetBlockProductionArgs {
+    pub trigger: BlockProductionTriggerInput,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    variables = "SetBlockProductionArgs",
+    graphql_type = "Mutation"
+)]
+pub struct SetBlockProductionMutation {
+    #[arguments(trigger: $trigger)]
+    pub set_block_production: bool,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct Header {
@@ -191,6 +225,20 @@ mod tests {
         insta::assert_snapshot!(operation.query)
     }
 
+    #[test]
+    fn set_block_production_mutation_gql_output() {
+        use cynic::MutationBuilder;
+        let operation = SetBlockProductionMutation::build(SetBlockProductionArgs {
+            trigger: BlockProductionTriggerInput {
+                mode: BlockProductionMode::Hybrid,
+                block_time_ms: None,
+                min_block_time_ms: Some(U64(100)),
+                max_block_time_ms: Some(U64(1000)),
+            },
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
     #[test]
     fn blocks_connection_query_gql_output() {
         use cynic::QueryBuilder;
@@ -326,6 +326,22 @@ pub struct ChainInfo {
     pub consensus_parameters: ConsensusParameters,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ConsensusParametersByHeightArgs {
+    pub height: U32,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ConsensusParametersByHeightArgs"
+)]
+pub struct ConsensusParametersByHeightQuery {
+    #[arguments(height: $height)]
+    pub consensus_parameters: ConsensusParameters,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -336,4 +352,14 @@ mod tests {
         let operation = ChainQuery::build(());
         insta::assert_snapshot!(operation.query)
     }
+
+    #[test]
+    fn consensus_parameters_by_height_gql_query_output() {
+        use cynic::QueryBuilder;
+        let operation =
+            ConsensusParametersByHeightQuery::build(ConsensusParametersByHeightArgs {
+                height: U32(0),
+            });
+        insta::assert_snapshot!(operation.query)
+    }
 }
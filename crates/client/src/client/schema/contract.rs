@@ -2,10 +2,12 @@ use crate::client::{
     schema::{
         schema,
         AssetId,
+        Bytes32,
         ContractId,
         HexString,
         PageInfo,
         Salt,
+        U32,
         U64,
     },
     PageDirection,
@@ -53,6 +55,32 @@ pub struct ContractBalanceQuery {
     pub contract_balance: ContractBalance,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ContractStateDiffArgs {
+    pub contract_id: ContractId,
+    pub from_height: U32,
+    pub to_height: U32,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ContractStateDiffArgs"
+)]
+pub struct ContractStateDiffQuery {
+    #[arguments(contractId: $contract_id, fromHeight: $from_height, toHeight: $to_height)]
+    pub contract_state_diff: Vec<ContractStateChange>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractStateChange {
+    pub block_height: U32,
+    pub key: Bytes32,
+    pub value: Option<Bytes32>,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct Contract {
@@ -147,4 +175,15 @@ mod tests {
         });
         insta::assert_snapshot!(operation.query)
     }
+
+    #[test]
+    fn contract_state_diff_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = ContractStateDiffQuery::build(ContractStateDiffArgs {
+            contract_id: ContractId::default(),
+            from_height: 1u32.into(),
+            to_height: 10u32.into(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
 }
@@ -0,0 +1,132 @@
+use crate::client::schema::{
+    schema,
+    U32,
+    U64,
+};
+use fuel_core_types::services::p2p;
+use std::{
+    str::FromStr,
+    time::Duration,
+};
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct QueryNetworkInfo {
+    pub network_info: Option<NetworkInfo>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct NetworkInfo {
+    pub inbound_peers: U32,
+    pub outbound_peers: U32,
+    pub bandwidth: Vec<ProtocolBandwidth>,
+    pub mesh: Vec<TopicMesh>,
+    pub block_propagation: LatencyHistogram,
+    pub tx_propagation: LatencyHistogram,
+}
+
+#[derive(cynic::Enum, Copy, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum NetworkProtocol {
+    Gossipsub,
+    RequestResponse,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ProtocolBandwidth {
+    pub protocol: NetworkProtocol,
+    pub inbound_bytes: U64,
+    pub outbound_bytes: U64,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TopicMesh {
+    pub topic: String,
+    pub peers: Vec<String>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct LatencyHistogram {
+    pub count: U64,
+    pub sum_ms: U64,
+    pub buckets: Vec<LatencyBucket>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct LatencyBucket {
+    pub upper_bound_ms: U64,
+    pub count: U64,
+}
+
+impl From<NetworkInfo> for p2p::NetworkInfo {
+    fn from(info: NetworkInfo) -> Self {
+        Self {
+            inbound_peers: info.inbound_peers.into(),
+            outbound_peers: info.outbound_peers.into(),
+            bandwidth: info.bandwidth.into_iter().map(Into::into).collect(),
+            mesh: info.mesh.into_iter().map(Into::into).collect(),
+            block_propagation: info.block_propagation.into(),
+            tx_propagation: info.tx_propagation.into(),
+        }
+    }
+}
+
+impl From<ProtocolBandwidth> for p2p::ProtocolBandwidth {
+    fn from(bandwidth: ProtocolBandwidth) -> Self {
+        Self {
+            protocol: match bandwidth.protocol {
+                NetworkProtocol::Gossipsub => p2p::NetworkProtocol::Gossipsub,
+                NetworkProtocol::RequestResponse => p2p::NetworkProtocol::RequestResponse,
+            },
+            inbound_bytes: bandwidth.inbound_bytes.into(),
+            outbound_bytes: bandwidth.outbound_bytes.into(),
+        }
+    }
+}
+
+impl From<TopicMesh> for p2p::TopicMesh {
+    fn from(mesh: TopicMesh) -> Self {
+        Self {
+            topic: mesh.topic,
+            peers: mesh
+                .peers
+                .iter()
+                .map(|peer| p2p::PeerId::from_str(peer).unwrap_or_default())
+                .collect(),
+        }
+    }
+}
+
+impl From<LatencyHistogram> for p2p::LatencyHistogram {
+    fn from(histogram: LatencyHistogram) -> Self {
+        Self {
+            count: histogram.count.into(),
+            sum: Duration::from_millis(histogram.sum_ms.into()),
+            buckets: histogram
+                .buckets
+                .into_iter()
+                .map(|bucket| p2p::LatencyBucket {
+                    upper_bound: Duration::from_millis(bucket.upper_bound_ms.into()),
+                    count: bucket.count.into(),
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn network_info_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = QueryNetworkInfo::build(());
+        insta::assert_snapshot!(operation.query)
+    }
+}
@@ -24,6 +24,18 @@ pub struct NodeInfo {
     pub max_tx: U64,
     pub max_depth: U64,
     pub node_version: String,
+    pub node_mode: NodeMode,
+}
+
+#[derive(cynic::Enum, Copy, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum NodeMode {
+    Network,
+    SingleNode,
+    Standalone,
+    Replica,
+    Standby,
+    ReadOnly,
 }
 
 #[derive(cynic::QueryFragment, Debug)]
@@ -273,6 +273,7 @@ macro_rules! number_scalar {
 number_scalar!(U64, u64);
 number_scalar!(U32, u32);
 number_scalar!(U8, u8);
+number_scalar!(Amount, u64);
 
 impl TryFrom<U64> for PanicInstruction {
     type Error = ConversionError;
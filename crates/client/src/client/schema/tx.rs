@@ -5,11 +5,14 @@ use crate::client::{
         tx::transparent_receipt::Receipt,
         Address,
         ConnectionArgs,
+        ContractId,
         ConversionError,
         HexString,
         PageInfo,
         Tai64Timestamp,
         TransactionId,
+        U32,
+        U64,
     },
     types::TransactionResponse,
     PageDirection,
@@ -195,6 +198,62 @@ pub struct FailureStatus {
     pub receipts: Vec<Receipt>,
 }
 
+#[derive(cynic::Enum, Copy, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum ConfirmationStatus {
+    Included,
+    Confirmed,
+    Finalized,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TransactionConfirmation {
+    pub status: ConfirmationStatus,
+    pub depth: U32,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TxIdArgs"
+)]
+pub struct TransactionConfirmationQuery {
+    #[arguments(id: $id)]
+    pub transaction: Option<TransactionConfirmationFragment>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Transaction")]
+pub struct TransactionConfirmationFragment {
+    pub status: Option<ConfirmationTransactionStatus>,
+}
+
+#[derive(cynic::InlineFragments, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "TransactionStatus"
+)]
+pub enum ConfirmationTransactionStatus {
+    SuccessStatus(SuccessConfirmation),
+    FailureStatus(FailureConfirmation),
+    #[cynic(fallback)]
+    Unknown,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "SuccessStatus")]
+pub struct SuccessConfirmation {
+    pub confirmation: TransactionConfirmation,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "FailureStatus")]
+pub struct FailureConfirmation {
+    pub confirmation: TransactionConfirmation,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct SqueezedOutStatus {
@@ -275,6 +334,23 @@ impl TryFrom<DryRunTransactionExecutionStatus> for TransactionExecutionStatus {
     }
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct DryRunSkippedTransaction {
+    pub id: TransactionId,
+    pub reason: String,
+}
+
+#[allow(clippy::enum_variant_names)]
+#[derive(cynic::InlineFragments, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum DryRunBundleStep {
+    DryRunTransactionExecutionStatus(DryRunTransactionExecutionStatus),
+    DryRunSkippedTransaction(DryRunSkippedTransaction),
+    #[cynic(fallback)]
+    Unknown,
+}
+
 #[derive(cynic::QueryVariables, Debug)]
 pub struct TransactionsByOwnerConnectionArgs {
     /// Select transactions based on related `owner`s
@@ -368,6 +444,17 @@ pub struct DryRun {
     pub dry_run: Vec<DryRunTransactionExecutionStatus>,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "DryRunArg"
+)]
+pub struct DryRunBundle {
+    #[arguments(txs: $txs, utxoValidation: $utxo_validation)]
+    pub dry_run_bundle: Vec<DryRunBundleStep>,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
@@ -390,6 +477,51 @@ pub struct SubmitAndAwaitSubscription {
     pub submit_and_await: TransactionStatus,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Mutation")]
+pub struct PauseTxPool {
+    pub pause_tx_pool: bool,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Mutation")]
+pub struct ResumeTxPool {
+    pub resume_tx_pool: bool,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct FlushTxPoolArgs {
+    pub owner: Option<Address>,
+    pub contract: Option<ContractId>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "FlushTxPoolArgs"
+)]
+pub struct FlushTxPool {
+    #[arguments(owner: $owner, contract: $contract)]
+    pub flush_tx_pool: U64,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct DumpTxPoolArgs {
+    pub path: String,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "DumpTxPoolArgs"
+)]
+pub struct DumpTxPool {
+    #[arguments(path: $path)]
+    pub dump_tx_pool: U64,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
 pub struct AllReceipts {
@@ -420,6 +552,15 @@ pub mod tests {
         insta::assert_snapshot!(operation.query)
     }
 
+    #[test]
+    fn transaction_confirmation_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = TransactionConfirmationQuery::build(TxIdArgs {
+            id: TransactionId::default(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
     #[test]
     fn transactions_connection_query_gql_output() {
         use cynic::QueryBuilder;
@@ -457,6 +598,17 @@ pub mod tests {
         insta::assert_snapshot!(query.query)
     }
 
+    #[test]
+    fn dry_run_bundle_gql_output() {
+        use cynic::MutationBuilder;
+        let tx = fuel_tx::Transaction::default_test_tx();
+        let query = DryRunBundle::build(DryRunArg {
+            txs: vec![HexString(Bytes(tx.to_bytes()))],
+            utxo_validation: None,
+        });
+        insta::assert_snapshot!(query.query)
+    }
+
     #[test]
     fn submit_tx_gql_output() {
         use cynic::MutationBuilder;
@@ -7,6 +7,7 @@ use crate::client::schema::{
         TxIdArgs,
     },
     Address,
+    Amount,
     AssetId,
     Bytes32,
     ConnectionArgs,
@@ -121,7 +122,7 @@ pub struct Transaction {
     /// The field of the `Transaction::Mint`.
     pub output_contract: Option<ContractOutput>,
     /// The field of the `Transaction::Mint`.
-    pub mint_amount: Option<U64>,
+    pub mint_amount: Option<Amount>,
     /// The field of the `Transaction::Mint`.
     pub mint_asset_id: Option<AssetId>,
     /// The field of the `Transaction::Script`.
@@ -532,7 +533,7 @@ pub struct Policies {
     pub gas_price: Option<U64>,
     pub maturity: Option<U32>,
     pub witness_limit: Option<U64>,
-    pub max_fee: Option<U64>,
+    pub max_fee: Option<Amount>,
 }
 
 impl From<Policies> for fuel_tx::policies::Policies {
@@ -11,6 +11,7 @@ pub mod node_info;
 pub use balance::Balance;
 pub use block::{
     Block,
+    BlockProductionTrigger,
     Consensus,
 };
 pub use chain_info::ChainInfo;
@@ -22,6 +23,7 @@ pub use coins::{
 pub use contract::{
     Contract,
     ContractBalance,
+    ContractStateChange,
 };
 pub use gas_costs::{
     DependentCost,
@@ -32,10 +34,16 @@ pub use message::{
     Message,
     MessageProof,
 };
-pub use node_info::NodeInfo;
+pub use node_info::{
+    NodeInfo,
+    NodeMode,
+};
 
 use crate::client::schema::{
     tx::{
+        ConfirmationStatus as SchemaConfirmationStatus,
+        ConfirmationTransactionStatus as SchemaConfirmationTxStatus,
+        DryRunBundleStep as SchemaDryRunBundleStep,
         OpaqueTransaction,
         TransactionStatus as SchemaTxStatus,
     },
@@ -45,9 +53,11 @@ use fuel_core_types::{
     fuel_tx::{
         Receipt,
         Transaction,
+        TxId,
     },
     fuel_types::canonical::Deserialize,
     fuel_vm::ProgramState,
+    services::executor::TransactionExecutionStatus,
 };
 use tai64::Tai64;
 
@@ -146,6 +156,82 @@ impl TryFrom<SchemaTxStatus> for TransactionStatus {
     }
 }
 
+/// The confirmation of the transaction included in the block.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TransactionConfirmation {
+    pub status: ConfirmationStatus,
+    /// The number of blocks produced on top of the block with the transaction.
+    pub depth: u32,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ConfirmationStatus {
+    /// The block with the transaction is the latest block of the chain.
+    Included,
+    /// Blocks are produced on top of the block with the transaction.
+    Confirmed,
+    /// The block with the transaction reached the finality depth of the node.
+    Finalized,
+}
+
+impl From<SchemaConfirmationStatus> for ConfirmationStatus {
+    fn from(status: SchemaConfirmationStatus) -> Self {
+        match status {
+            SchemaConfirmationStatus::Included => Self::Included,
+            SchemaConfirmationStatus::Confirmed => Self::Confirmed,
+            SchemaConfirmationStatus::Finalized => Self::Finalized,
+        }
+    }
+}
+
+impl TryFrom<SchemaConfirmationTxStatus> for TransactionConfirmation {
+    type Error = ConversionError;
+
+    fn try_from(status: SchemaConfirmationTxStatus) -> Result<Self, Self::Error> {
+        let confirmation = match status {
+            SchemaConfirmationTxStatus::SuccessStatus(s) => s.confirmation,
+            SchemaConfirmationTxStatus::FailureStatus(s) => s.confirmation,
+            SchemaConfirmationTxStatus::Unknown => {
+                return Err(Self::Error::UnknownVariant("SchemaConfirmationTxStatus"))
+            }
+        };
+        Ok(Self {
+            status: confirmation.status.into(),
+            depth: confirmation.depth.into(),
+        })
+    }
+}
+
+/// The result of one transaction of the dry run bundle.
+#[derive(Debug, Clone)]
+pub enum DryRunBundleStep {
+    /// The transaction is executed, and its changes are visible to the next transactions.
+    Executed(TransactionExecutionStatus),
+    /// The transaction is invalid and skipped without affecting the state.
+    Skipped { id: TxId, reason: String },
+}
+
+impl TryFrom<SchemaDryRunBundleStep> for DryRunBundleStep {
+    type Error = ConversionError;
+
+    fn try_from(step: SchemaDryRunBundleStep) -> Result<Self, Self::Error> {
+        Ok(match step {
+            SchemaDryRunBundleStep::DryRunTransactionExecutionStatus(status) => {
+                DryRunBundleStep::Executed(status.try_into()?)
+            }
+            SchemaDryRunBundleStep::DryRunSkippedTransaction(skipped) => {
+                DryRunBundleStep::Skipped {
+                    id: skipped.id.into(),
+                    reason: skipped.reason,
+                }
+            }
+            SchemaDryRunBundleStep::Unknown => {
+                return Err(Self::Error::UnknownVariant("DryRunBundleStep"))
+            }
+        })
+    }
+}
+
 impl TryFrom<OpaqueTransaction> for TransactionResponse {
     type Error = ConversionError;
 
@@ -10,6 +10,7 @@ use crate::client::{
     },
     PaginatedResult,
 };
+use std::time::Duration;
 use tai64::Tai64;
 
 #[derive(Debug)]
@@ -80,6 +81,22 @@ impl From<schema::block::Header> for Header {
     }
 }
 
+impl From<&Header> for fuel_core_light::BlockHeader {
+    fn from(value: &Header) -> Self {
+        Self {
+            da_height: value.da_height,
+            transactions_count: value.transactions_count,
+            message_receipt_count: value.message_receipt_count,
+            transactions_root: value.transactions_root,
+            message_receipt_root: value.message_receipt_root,
+            prev_root: value.prev_root,
+            height: value.height.into(),
+            time: value.time,
+            application_hash: value.application_hash,
+        }
+    }
+}
+
 impl From<schema::block::Consensus> for Consensus {
     fn from(value: schema::block::Consensus) -> Self {
         match value {
@@ -143,3 +160,61 @@ impl From<schema::block::BlockConnection> for PaginatedResult<Block, String> {
         }
     }
 }
+
+/// The trigger of the block production of the node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlockProductionTrigger {
+    /// Blocks are never produced automatically.
+    Never,
+    /// A block is produced instantly when transactions are available.
+    Instant,
+    /// Blocks are produced periodically regardless of transactions.
+    Interval { block_time: Duration },
+    /// A block is produced when transactions are available, but not earlier than
+    /// `min_block_time` after the previous block. An empty block is produced if
+    /// no blocks were produced during `max_block_time`.
+    Hybrid {
+        min_block_time: Duration,
+        max_block_time: Duration,
+    },
+}
+
+impl From<BlockProductionTrigger> for schema::block::BlockProductionTriggerInput {
+    fn from(trigger: BlockProductionTrigger) -> Self {
+        use schema::block::BlockProductionMode;
+        let millis = |duration: Duration| {
+            Some(
+                u64::try_from(duration.as_millis())
+                    .unwrap_or(u64::MAX)
+                    .into(),
+            )
+        };
+        let input = schema::block::BlockProductionTriggerInput {
+            mode: BlockProductionMode::Never,
+            block_time_ms: None,
+            min_block_time_ms: None,
+            max_block_time_ms: None,
+        };
+        match trigger {
+            BlockProductionTrigger::Never => input,
+            BlockProductionTrigger::Instant => Self {
+                mode: BlockProductionMode::Instant,
+                ..input
+            },
+            BlockProductionTrigger::Interval { block_time } => Self {
+                mode: BlockProductionMode::Interval,
+                block_time_ms: millis(block_time),
+                ..input
+            },
+            BlockProductionTrigger::Hybrid {
+                min_block_time,
+                max_block_time,
+            } => Self {
+                mode: BlockProductionMode::Hybrid,
+                min_block_time_ms: millis(min_block_time),
+                max_block_time_ms: millis(max_block_time),
+                ..input
+            },
+        }
+    }
+}
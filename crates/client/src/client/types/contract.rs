@@ -3,6 +3,7 @@ use crate::client::{
     types::primitives::{
         AssetId,
         Bytes,
+        Bytes32,
         ContractId,
         Salt,
     },
@@ -22,6 +23,14 @@ pub struct ContractBalance {
     pub asset_id: AssetId,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractStateChange {
+    pub block_height: u32,
+    pub key: Bytes32,
+    /// The value of the slot after the block. `None` if the block removed the slot.
+    pub value: Option<Bytes32>,
+}
+
 // GraphQL Translation
 
 impl From<schema::contract::Contract> for Contract {
@@ -44,6 +53,16 @@ impl From<schema::contract::ContractBalance> for ContractBalance {
     }
 }
 
+impl From<schema::contract::ContractStateChange> for ContractStateChange {
+    fn from(value: schema::contract::ContractStateChange) -> Self {
+        Self {
+            block_height: value.block_height.into(),
+            key: value.key.into(),
+            value: value.value.map(Into::into),
+        }
+    }
+}
+
 impl From<schema::contract::ContractBalanceConnection>
     for PaginatedResult<ContractBalance, String>
 {
@@ -28,3 +28,12 @@ impl From<schema::message::MerkleProof> for MerkleProof {
         }
     }
 }
+
+impl From<&MerkleProof> for fuel_core_light::MerkleProof {
+    fn from(value: &MerkleProof) -> Self {
+        Self {
+            proof_set: value.proof_set.iter().map(|root| **root).collect(),
+            proof_index: value.proof_index,
+        }
+    }
+}
@@ -7,6 +7,23 @@ pub struct NodeInfo {
     pub max_tx: u64,
     pub max_depth: u64,
     pub node_version: String,
+    pub node_mode: NodeMode,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum NodeMode {
+    /// The node participates in the P2P network.
+    Network,
+    /// The node is the only block producer without the P2P network.
+    SingleNode,
+    /// The node runs without the P2P network, but the single-node mode isn't requested.
+    Standalone,
+    /// The node is the read replica of another node.
+    Replica,
+    /// The node is the warm standby of the primary node.
+    Standby,
+    /// The node only reads its database and serves the GraphQL API.
+    ReadOnly,
 }
 
 // GraphQL Translation
@@ -20,6 +37,20 @@ impl From<schema::node_info::NodeInfo> for NodeInfo {
             max_tx: value.max_tx.into(),
             max_depth: value.max_depth.into(),
             node_version: value.node_version,
+            node_mode: value.node_mode.into(),
+        }
+    }
+}
+
+impl From<schema::node_info::NodeMode> for NodeMode {
+    fn from(value: schema::node_info::NodeMode) -> Self {
+        match value {
+            schema::node_info::NodeMode::Network => Self::Network,
+            schema::node_info::NodeMode::SingleNode => Self::SingleNode,
+            schema::node_info::NodeMode::Standalone => Self::Standalone,
+            schema::node_info::NodeMode::Replica => Self::Replica,
+            schema::node_info::NodeMode::Standby => Self::Standby,
+            schema::node_info::NodeMode::ReadOnly => Self::ReadOnly,
         }
     }
 }
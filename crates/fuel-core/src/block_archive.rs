@@ -0,0 +1,439 @@
+//! The stable binary format of the stream of sealed blocks. It is used to export blocks
+//! from one node and import them into another one, for example, to backfill analytics
+//! pipelines or to migrate between storage backends.
+//!
+//! The stream starts with the [`MAGIC`] bytes and the big-endian `u16` [`VERSION`]
+//! of the format, followed by records. Each record is the big-endian `u32` length of
+//! the encoded block and the block encoded with `postcard`. The stream ends at
+//! the boundary of the record.
+
+use crate::{
+    chain_config::ConsensusConfig,
+    database::Database,
+    service::adapters::BlockImporterAdapter,
+};
+use anyhow::{
+    anyhow,
+    Context,
+};
+use fuel_core_poa::verifier::verify_consensus;
+use fuel_core_storage::{
+    tables::FuelBlocks,
+    StorageAsRef,
+};
+use fuel_core_types::{
+    blockchain::{
+        consensus::Consensus,
+        SealedBlock,
+    },
+    fuel_types::BlockHeight,
+};
+use std::io::{
+    ErrorKind,
+    Read,
+    Write,
+};
+
+/// The bytes at the beginning of the stream.
+pub const MAGIC: [u8; 8] = *b"FUELBLKS";
+
+/// The version of the format.
+pub const VERSION: u16 = 1;
+
+/// The maximum size of the encoded block. It protects the reader from
+/// allocating huge buffers because of the corrupted length of the record.
+pub const MAX_BLOCK_SIZE: u32 = 64 * 1024 * 1024;
+
+/// Writes sealed blocks to the stream.
+pub struct BlockWriter<W> {
+    writer: W,
+}
+
+impl<W: Write> BlockWriter<W> {
+    /// Creates the writer and writes the header of the stream.
+    pub fn new(mut writer: W) -> anyhow::Result<Self> {
+        writer.write_all(&MAGIC)?;
+        writer.write_all(&VERSION.to_be_bytes())?;
+        Ok(Self { writer })
+    }
+
+    /// Writes the block as the next record of the stream.
+    pub fn write(&mut self, block: &SealedBlock) -> anyhow::Result<()> {
+        let bytes = postcard::to_allocvec(block)?;
+        let len = u32::try_from(bytes.len())
+            .ok()
+            .filter(|len| *len <= MAX_BLOCK_SIZE)
+            .ok_or_else(|| anyhow!("The block is too big: {} bytes", bytes.len()))?;
+        self.writer.write_all(&len.to_be_bytes())?;
+        self.writer.write_all(&bytes)?;
+        Ok(())
+    }
+
+    /// Flushes the stream and returns the underlying writer.
+    pub fn finish(mut self) -> anyhow::Result<W> {
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+/// Reads sealed blocks from the stream.
+pub struct BlockReader<R> {
+    reader: R,
+}
+
+impl<R: Read> BlockReader<R> {
+    /// Creates the reader and validates the header of the stream.
+    pub fn new(mut reader: R) -> anyhow::Result<Self> {
+        let mut magic = [0u8; MAGIC.len()];
+        reader
+            .read_exact(&mut magic)
+            .context("Failed to read the header of the stream")?;
+        if magic != MAGIC {
+            return Err(anyhow!("The stream is not an export of blocks"))
+        }
+
+        let mut version = [0u8; 2];
+        reader
+            .read_exact(&mut version)
+            .context("Failed to read the version of the stream")?;
+        let version = u16::from_be_bytes(version);
+        if version != VERSION {
+            return Err(anyhow!(
+                "The version {version} of the stream is not supported, expected {VERSION}"
+            ))
+        }
+
+        Ok(Self { reader })
+    }
+
+    /// Reads the next block. Returns `None` at the end of the stream.
+    pub fn read(&mut self) -> anyhow::Result<Option<SealedBlock>> {
+        let mut len = [0u8; 4];
+        if !self.read_record_start(&mut len)? {
+            return Ok(None)
+        }
+        let len = u32::from_be_bytes(len);
+        if len > MAX_BLOCK_SIZE {
+            return Err(anyhow!("The record is too big: {len} bytes"))
+        }
+
+        let mut bytes = vec![0u8; usize::try_from(len)?];
+        self.reader
+            .read_exact(&mut bytes)
+            .context("The stream ends in the middle of the record")?;
+        let block = postcard::from_bytes(&bytes).context("Failed to decode the block")?;
+        Ok(Some(block))
+    }
+
+    /// Reads the length of the record. Returns `false` if the stream ends before it.
+    fn read_record_start(&mut self, buf: &mut [u8; 4]) -> anyhow::Result<bool> {
+        let mut read = 0;
+        while read < buf.len() {
+            match self.reader.read(&mut buf[read..]) {
+                Ok(0) if read == 0 => return Ok(false),
+                Ok(0) => {
+                    return Err(anyhow!("The stream ends in the middle of the record"))
+                }
+                Ok(n) => read = read.saturating_add(n),
+                Err(err) if err.kind() == ErrorKind::Interrupted => continue,
+                Err(err) => return Err(err.into()),
+            }
+        }
+        Ok(true)
+    }
+}
+
+impl<R: Read> Iterator for BlockReader<R> {
+    type Item = anyhow::Result<SealedBlock>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read().transpose()
+    }
+}
+
+/// Writes blocks from the `from` height to the `to` height inclusively into the `writer`.
+/// Returns the number of exported blocks.
+pub fn export_blocks<W: Write>(
+    database: &Database,
+    from: BlockHeight,
+    to: BlockHeight,
+    writer: W,
+) -> anyhow::Result<u32> {
+    let mut writer = BlockWriter::new(writer)?;
+    let mut exported = 0u32;
+    for height in *from..=*to {
+        let height = BlockHeight::from(height);
+        let block = database
+            .get_sealed_block_by_height(&height)?
+            .ok_or_else(|| anyhow!("The block at height {height} is not found"))?;
+        writer.write(&block)?;
+        exported = exported.saturating_add(1);
+    }
+    writer.finish()?;
+    Ok(exported)
+}
+
+/// The result of the import of blocks.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ImportReport {
+    /// The number of blocks executed and committed by the importer.
+    pub imported: u32,
+    /// The number of blocks that are already in the database.
+    pub skipped: u32,
+}
+
+/// Imports blocks from the `reader`. The seal of each block is verified against
+/// the `consensus` of the chain, and the block is validated and executed
+/// by the `importer` before the commit. Blocks that are already in the database
+/// are skipped if they are the same. The blocks are read in batches
+/// of the `batch_size`, and the progress is reported after each batch.
+pub async fn import_blocks<R: Read>(
+    importer: &BlockImporterAdapter,
+    database: &Database,
+    consensus: &ConsensusConfig,
+    reader: R,
+    batch_size: usize,
+) -> anyhow::Result<ImportReport> {
+    let mut blocks = BlockReader::new(reader)?;
+    let mut report = ImportReport::default();
+    loop {
+        let batch = blocks
+            .by_ref()
+            .take(batch_size.max(1))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        if batch.is_empty() {
+            break
+        }
+
+        for block in batch {
+            let height = *block.entity.header().height();
+            let existing = database.storage::<FuelBlocks>().get(&height)?;
+            if let Some(existing) = existing {
+                if existing.header().id() != block.entity.header().id() {
+                    return Err(anyhow!(
+                        "The block at height {height} differs from the block in the database"
+                    ))
+                }
+                report.skipped = report.skipped.saturating_add(1);
+                continue
+            }
+
+            verify_seal(consensus, &block)?;
+            importer.execute_and_commit(block).await.with_context(|| {
+                format!("Failed to import the block at height {height}")
+            })?;
+            report.imported = report.imported.saturating_add(1);
+        }
+        tracing::info!(
+            "Imported {} blocks, skipped {} existing blocks",
+            report.imported,
+            report.skipped
+        );
+    }
+    Ok(report)
+}
+
+/// Only the genesis block has the `Genesis` consensus, and it is created by the node
+/// itself. Other blocks should be sealed by the PoA authority of the chain.
+fn verify_seal(consensus: &ConsensusConfig, block: &SealedBlock) -> anyhow::Result<()> {
+    let header = block.entity.header();
+    let height = header.height();
+    match &block.consensus {
+        Consensus::PoA(seal) => {
+            if verify_consensus(consensus, header, seal) {
+                Ok(())
+            } else {
+                Err(anyhow!(
+                    "The block at height {height} is not sealed by the PoA authority"
+                ))
+            }
+        }
+        consensus => Err(anyhow!(
+            "The block at height {height} has the unsupported consensus {consensus:?}"
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::{
+        Config,
+        FuelService,
+    };
+    use fuel_core_poa::{
+        service::Mode,
+        Trigger,
+    };
+    use fuel_core_types::{
+        blockchain::consensus::poa::PoAConsensus,
+        fuel_crypto::{
+            SecretKey,
+            Signature,
+        },
+    };
+    use rand::{
+        rngs::StdRng,
+        SeedableRng,
+    };
+
+    fn block(height: u32) -> SealedBlock {
+        let mut block = SealedBlock::default();
+        block.entity.header_mut().set_block_height(height.into());
+        block
+    }
+
+    #[test]
+    fn reader_reads_blocks_written_by_writer() {
+        let blocks = vec![block(1), block(2), block(3)];
+        let mut writer = BlockWriter::new(vec![]).unwrap();
+        for block in &blocks {
+            writer.write(block).unwrap();
+        }
+        let bytes = writer.finish().unwrap();
+
+        let read = BlockReader::new(bytes.as_slice())
+            .unwrap()
+            .collect::<anyhow::Result<Vec<_>>>()
+            .unwrap();
+
+        assert_eq!(read, blocks);
+    }
+
+    #[test]
+    fn reader_rejects_unknown_header() {
+        let mut bytes = BlockWriter::new(vec![]).unwrap().finish().unwrap();
+        bytes[0] = b'X';
+        assert!(BlockReader::new(bytes.as_slice()).is_err());
+
+        let mut bytes = BlockWriter::new(vec![]).unwrap().finish().unwrap();
+        bytes[MAGIC.len()] = 0xff;
+        assert!(BlockReader::new(bytes.as_slice()).is_err());
+    }
+
+    #[test]
+    fn reader_rejects_truncated_record() {
+        let mut writer = BlockWriter::new(vec![]).unwrap();
+        writer.write(&block(1)).unwrap();
+        let mut bytes = writer.finish().unwrap();
+        bytes.pop();
+
+        let mut reader = BlockReader::new(bytes.as_slice()).unwrap();
+
+        assert!(reader.read().is_err());
+    }
+
+    #[test]
+    fn reader_rejects_too_big_record() {
+        let mut bytes = BlockWriter::new(vec![]).unwrap().finish().unwrap();
+        bytes.extend(MAX_BLOCK_SIZE.saturating_add(1).to_be_bytes());
+
+        let mut reader = BlockReader::new(bytes.as_slice()).unwrap();
+
+        assert!(reader.read().is_err());
+    }
+
+    #[tokio::test]
+    async fn import_blocks_commits_blocks_exported_from_another_node() {
+        let mut config = Config::local_node();
+        config.block_production = Trigger::Never;
+        let source = FuelService::new_node(config.clone()).await.unwrap();
+        source
+            .shared
+            .poa_adapter
+            .manually_produce_blocks(
+                None,
+                Mode::Blocks {
+                    number_of_blocks: 5,
+                },
+            )
+            .await
+            .unwrap();
+        let mut exported = vec![];
+        let source_database = source.shared.database.on_chain();
+        let count =
+            export_blocks(source_database, 0u32.into(), 5u32.into(), &mut exported)
+                .unwrap();
+        assert_eq!(count, 6);
+
+        let target = FuelService::new_node(config.clone()).await.unwrap();
+        let report = import_blocks(
+            &target.shared.block_importer,
+            target.shared.database.on_chain(),
+            &config.chain_conf.consensus,
+            exported.as_slice(),
+            2,
+        )
+        .await
+        .unwrap();
+
+        // The genesis block is created by the target node itself.
+        assert_eq!(
+            report,
+            ImportReport {
+                imported: 5,
+                skipped: 1
+            }
+        );
+        let target_database = target.shared.database.on_chain();
+        assert_eq!(target_database.latest_height().unwrap(), 5u32.into());
+        assert_eq!(
+            target_database
+                .get_sealed_block_by_height(&5u32.into())
+                .unwrap(),
+            source_database
+                .get_sealed_block_by_height(&5u32.into())
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn import_blocks_rejects_block_with_tampered_seal() {
+        let mut config = Config::local_node();
+        config.block_production = Trigger::Never;
+        let source = FuelService::new_node(config.clone()).await.unwrap();
+        source
+            .shared
+            .poa_adapter
+            .manually_produce_blocks(
+                None,
+                Mode::Blocks {
+                    number_of_blocks: 2,
+                },
+            )
+            .await
+            .unwrap();
+        let source_database = source.shared.database.on_chain();
+        let mut writer = BlockWriter::new(vec![]).unwrap();
+        for height in 1u32..=2 {
+            let mut block = source_database
+                .get_sealed_block_by_height(&height.into())
+                .unwrap()
+                .unwrap();
+            if height == 2 {
+                // The seal is valid, but not by the authority of the chain.
+                let forger = SecretKey::random(&mut StdRng::seed_from_u64(1));
+                let message = block.entity.id().into_message();
+                block.consensus =
+                    Consensus::PoA(PoAConsensus::new(Signature::sign(&forger, &message)));
+            }
+            writer.write(&block).unwrap();
+        }
+        let exported = writer.finish().unwrap();
+
+        let target = FuelService::new_node(config.clone()).await.unwrap();
+        let result = import_blocks(
+            &target.shared.block_importer,
+            target.shared.database.on_chain(),
+            &config.chain_conf.consensus,
+            exported.as_slice(),
+            10,
+        )
+        .await;
+
+        assert!(result.is_err());
+        // The block before the forged one is imported.
+        let target_database = target.shared.database.on_chain();
+        assert_eq!(target_database.latest_height().unwrap(), 1u32.into());
+    }
+}
@@ -1,9 +1,13 @@
 use crate::{
     fuel_core_graphql_api::database::ReadView,
-    query::asset_query::{
-        AssetQuery,
-        AssetSpendTarget,
-        Exclude,
+    query::{
+        asset_query::{
+            AssetQuery,
+            AssetSpendTarget,
+            Exclude,
+        },
+        Amount,
+        AmountOverflow,
     },
 };
 use core::mem::swap;
@@ -40,6 +44,8 @@ pub enum CoinsQueryError {
     MaxCoinsReached,
     #[error("the query contains duplicate assets")]
     DuplicateAssets(AssetId),
+    #[error("the amount of selected coins doesn't fit into `u64`: {0}")]
+    AmountOverflow(AmountOverflow),
 }
 
 #[cfg(test)]
@@ -128,12 +134,13 @@ pub fn largest_first(query: &AssetQuery) -> Result<Vec<CoinType>, CoinsQueryErro
     let mut inputs: Vec<_> = query.coins().try_collect()?;
     inputs.sort_by_key(|coin| Reverse(coin.amount()));
 
-    let mut collected_amount = 0u64;
+    let target = Amount::new(query.asset.target);
+    let mut collected_amount = Amount::ZERO;
     let mut coins = vec![];
 
     for coin in inputs {
         // Break if we don't need any more coins
-        if collected_amount >= query.asset.target {
+        if collected_amount >= target {
             break
         }
 
@@ -143,14 +150,14 @@ pub fn largest_first(query: &AssetQuery) -> Result<Vec<CoinType>, CoinsQueryErro
         }
 
         // Add to list
-        collected_amount = collected_amount.saturating_add(coin.amount());
+        collected_amount = collected_amount.checked_add(coin.amount())?;
         coins.push(coin);
     }
 
-    if collected_amount < query.asset.target {
+    if collected_amount < target {
         return Err(CoinsQueryError::InsufficientCoins {
             asset_id: query.asset.id,
-            collected_amount,
+            collected_amount: collected_amount.into(),
         })
     }
 
@@ -169,18 +176,23 @@ pub fn random_improve(
         inputs.shuffle(&mut thread_rng());
         inputs.truncate(query.asset.max);
 
-        let mut collected_amount = 0;
+        let mut collected_amount = Amount::ZERO;
         let mut coins = vec![];
 
         // Set parameters according to spec
-        let target = query.asset.target;
-        let upper_target = query.asset.target.saturating_mul(2);
+        let target = Amount::new(query.asset.target);
+        // The upper limit is only a bound for the coin, so it is capped by `u64::MAX`.
+        let upper_target = target.checked_mul(2u64).unwrap_or(Amount::MAX);
 
         for coin in inputs {
+            let coin_amount = Amount::new(coin.amount());
             // Try to improve the result by adding dust to the result.
             if collected_amount >= target {
                 // Break if found coin exceeds max `u64` or the upper limit
-                if collected_amount == u64::MAX || coin.amount() > upper_target {
+                let Ok(next_amount) = collected_amount.checked_add(coin_amount) else {
+                    break
+                };
+                if coin_amount > upper_target {
                     break
                 }
 
@@ -188,21 +200,26 @@ pub fn random_improve(
                 let change_amount = collected_amount
                     .checked_sub(target)
                     .expect("We checked it above");
-                let distance = target.abs_diff(change_amount);
-                let next_distance =
-                    target.abs_diff(change_amount.saturating_add(coin.amount()));
+                let next_change_amount = next_amount
+                    .checked_sub(target)
+                    .expect("We checked it above");
+                let distance = target.get().abs_diff(change_amount.get());
+                let next_distance = target.get().abs_diff(next_change_amount.get());
                 if next_distance >= distance {
                     break
                 }
             }
 
-            // Add to list
-            collected_amount = collected_amount.saturating_add(coin.amount());
+            // Add to list, or fallback to largest_first below if the amount overflows
+            let Ok(next_amount) = collected_amount.checked_add(coin_amount) else {
+                break
+            };
+            collected_amount = next_amount;
             coins.push(coin);
         }
 
         // Fallback to largest_first if we can't fit more coins
-        if collected_amount < query.asset.target {
+        if collected_amount < target {
             swap(&mut coins, &mut largest_first(&query)?);
         }
 
@@ -218,6 +235,12 @@ impl From<StorageError> for CoinsQueryError {
     }
 }
 
+impl From<AmountOverflow> for CoinsQueryError {
+    fn from(e: AmountOverflow) -> Self {
+        CoinsQueryError::AmountOverflow(e)
+    }
+}
+
 #[allow(clippy::arithmetic_side_effects)]
 #[cfg(test)]
 mod tests {
@@ -230,9 +253,14 @@ mod tests {
         },
         combined_database::CombinedDatabase,
         fuel_core_graphql_api::api_service::ReadDatabase as ServiceDatabase,
-        query::asset_query::{
-            AssetQuery,
-            AssetSpendTarget,
+        query::{
+            asset_query::{
+                AssetQuery,
+                AssetSpendTarget,
+            },
+            Amount,
+            AmountOperation,
+            AmountOverflow,
         },
     };
     use assert_matches::assert_matches;
@@ -896,13 +924,26 @@ mod tests {
     )]
     #[test_case::test_case(
         TestCase {
-            db_amount: vec![2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, u64::MAX - 1],
+            db_amount: vec![2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, u64::MAX - 2],
             target_amount: u64::MAX,
             max_coins: 2,
         }
         => Ok(2)
         ; "Enough coins in the DB to reach target(u64::MAX) by 2 coins"
     )]
+    #[test_case::test_case(
+        TestCase {
+            db_amount: vec![2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, u64::MAX - 1],
+            target_amount: u64::MAX,
+            max_coins: 2,
+        }
+        => Err(CoinsQueryError::AmountOverflow(AmountOverflow {
+            operation: AmountOperation::Add,
+            lhs: Amount::new(u64::MAX - 1),
+            rhs: Amount::new(2),
+        }))
+        ; "The amount of coins required to reach target(u64::MAX) overflows `u64`"
+    )]
     #[test_case::test_case(
         TestCase {
             db_amount: vec![u64::MAX, u64::MAX],
@@ -1,11 +1,17 @@
-use crate::database::{
-    database_description::{
-        off_chain::OffChain,
-        on_chain::OnChain,
-        relayer::Relayer,
+use crate::{
+    database::{
+        database_description::{
+            off_chain::OffChain,
+            on_chain::OnChain,
+            relayer::Relayer,
+        },
+        Database,
+        Result as DatabaseResult,
+    },
+    state::replicated::{
+        ReplicatedDatabase,
+        ReplicationLog,
     },
-    Database,
-    Result as DatabaseResult,
 };
 use fuel_core_storage::Result as StorageResult;
 use fuel_core_types::{
@@ -47,6 +53,39 @@ impl CombinedDatabase {
         })
     }
 
+    #[cfg(feature = "rocksdb")]
+    pub fn open_secondary(
+        path: &std::path::Path,
+        secondary_path: &std::path::Path,
+        capacity: usize,
+    ) -> DatabaseResult<Self> {
+        let on_chain = Database::open_secondary(path, secondary_path, capacity)?;
+        let off_chain = Database::open_secondary(path, secondary_path, capacity)?;
+        let relayer = Database::open_secondary(path, secondary_path, capacity)?;
+        Ok(Self {
+            on_chain,
+            off_chain,
+            relayer,
+        })
+    }
+
+    /// Opens all databases without the write capability, so the data directory
+    /// can't be modified by the node.
+    #[cfg(feature = "rocksdb")]
+    pub fn open_read_only(
+        path: &std::path::Path,
+        capacity: usize,
+    ) -> DatabaseResult<Self> {
+        let on_chain = Database::open_read_only(path, capacity)?;
+        let off_chain = Database::open_read_only(path, capacity)?;
+        let relayer = Database::open_read_only(path, capacity)?;
+        Ok(Self {
+            on_chain,
+            off_chain,
+            relayer,
+        })
+    }
+
     pub fn in_memory() -> Self {
         Self::new(
             Database::in_memory(),
@@ -60,12 +99,39 @@ impl CombinedDatabase {
         block_height: &BlockHeight,
         da_block_height: &DaBlockHeight,
     ) -> StorageResult<()> {
+        self.on_chain.migrate()?;
+        self.off_chain.migrate(&self.on_chain)?;
+        self.on_chain.rebuild_coin_balances_if_dirty()?;
         self.on_chain.init(block_height)?;
         self.off_chain.init(block_height)?;
         self.relayer.init(da_block_height)?;
         Ok(())
     }
 
+    /// Records all changes of databases into the replication `log`.
+    pub fn with_replication(self, log: &ReplicationLog) -> Self {
+        Self {
+            on_chain: self
+                .on_chain
+                .with_replication(ReplicatedDatabase::OnChain, log.clone()),
+            off_chain: self
+                .off_chain
+                .with_replication(ReplicatedDatabase::OffChain, log.clone()),
+            relayer: self
+                .relayer
+                .with_replication(ReplicatedDatabase::Relayer, log.clone()),
+        }
+    }
+
+    /// Keeps the state of the on-chain database at the heights of the latest
+    /// `capacity` blocks.
+    pub fn with_state_history(self, capacity: usize) -> Self {
+        Self {
+            on_chain: self.on_chain.with_state_history(capacity),
+            ..self
+        }
+    }
+
     pub fn on_chain(&self) -> &Database<OnChain> {
         &self.on_chain
     }
@@ -89,4 +155,33 @@ impl CombinedDatabase {
         self.relayer.flush()?;
         Ok(())
     }
+
+    /// Catches up all databases with their primary instances.
+    pub fn catch_up_with_primary(&self) -> DatabaseResult<()> {
+        self.on_chain.catch_up_with_primary()?;
+        self.off_chain.catch_up_with_primary()?;
+        self.relayer.catch_up_with_primary()?;
+        Ok(())
+    }
+
+    /// Compacts all databases.
+    pub fn compact(&self) -> DatabaseResult<()> {
+        self.on_chain.compact()?;
+        self.off_chain.compact()?;
+        self.relayer.compact()?;
+        Ok(())
+    }
+
+    /// Creates the copy of all databases in the `path` directory.
+    /// The copy can be opened as the regular database.
+    ///
+    /// Each database is copied consistently, but the databases are copied one after
+    /// another without pausing commits. So the copy of the off-chain or relayer
+    /// database may include changes committed after the copy of the on-chain database.
+    pub fn checkpoint(&self, path: &std::path::Path) -> DatabaseResult<()> {
+        self.on_chain.checkpoint(path)?;
+        self.off_chain.checkpoint(path)?;
+        self.relayer.checkpoint(path)?;
+        Ok(())
+    }
 }
@@ -9,7 +9,17 @@ use crate::{
         transaction::DatabaseTransaction,
     },
     state::{
+        historical::{
+            HistoricalStorage,
+            StateHistory,
+        },
         in_memory::memory_store::MemoryStore,
+        replicated::{
+            ReplicatedChange,
+            ReplicatedDatabase,
+            ReplicatedStorage,
+            ReplicationLog,
+        },
         DataSource,
     },
 };
@@ -30,6 +40,7 @@ use fuel_core_storage::{
     kv_store::{
         BatchOperations,
         KeyValueStore,
+        StorageColumn,
         Value,
         WriteOperation,
     },
@@ -79,14 +90,18 @@ pub mod block;
 pub mod coin;
 pub mod contracts;
 pub mod database_description;
+pub mod integrity;
 pub mod message;
 pub mod metadata;
+pub mod migration;
+pub mod reindex;
 pub mod sealed_block;
 pub mod state;
 pub mod statistic;
 pub mod storage;
 pub mod transaction;
 pub mod transactions;
+pub mod upgrade;
 
 #[derive(Clone, Debug)]
 pub struct Database<Description = OnChain>
@@ -94,6 +109,8 @@ where
     Description: DatabaseDescription,
 {
     data: StructuredStorage<DataSource<Description>>,
+    /// The storage recording the history of the latest blocks, if enabled.
+    history: Option<Arc<HistoricalStorage<Description>>>,
     // used for RAII
     _drop: Arc<DropResources>,
 }
@@ -137,6 +154,7 @@ where
     {
         Self {
             data: StructuredStorage::new(data_source.into()),
+            history: None,
             _drop: Default::default(),
         }
     }
@@ -146,6 +164,63 @@ where
         self
     }
 
+    /// Records all changes of the database into the replication `log`.
+    pub fn with_replication(
+        self,
+        database: ReplicatedDatabase,
+        log: ReplicationLog,
+    ) -> Self {
+        let data_source: &DataSource<Description> = self.data.as_ref();
+        let replicated = ReplicatedStorage::new(data_source.clone(), database, log);
+        Self {
+            data: StructuredStorage::new(Arc::new(replicated).into()),
+            history: self.history,
+            _drop: self._drop,
+        }
+    }
+
+    /// Records the previous values of keys modified by the latest `capacity` blocks,
+    /// so the state at their heights is available via the [`AtomicView::view_at`].
+    pub fn with_state_history(self, capacity: usize) -> Self {
+        let data_source: &DataSource<Description> = self.data.as_ref();
+        let historical = Arc::new(HistoricalStorage::new(
+            data_source.clone(),
+            StateHistory::new(capacity),
+        ));
+        Self {
+            data: StructuredStorage::new(historical.clone().into()),
+            history: Some(historical),
+            _drop: self._drop,
+        }
+    }
+
+    /// Writes the `changes` replicated from the primary node at once.
+    pub fn write_replicated_changes(
+        &self,
+        changes: &[ReplicatedChange],
+    ) -> StorageResult<()> {
+        let entries = changes
+            .iter()
+            .map(|(id, key, value)| {
+                let column = enum_iterator::all::<Description::Column>()
+                    .find(|column| column.id() == *id)
+                    .ok_or_else(|| {
+                        StorageError::Other(anyhow::anyhow!(
+                            "The column {id} is unknown to the {} database",
+                            Description::name()
+                        ))
+                    })?;
+                let operation = match value {
+                    Some(value) => WriteOperation::Insert(Arc::new(value.clone())),
+                    None => WriteOperation::Remove,
+                };
+                Ok((key.clone(), column, operation))
+            })
+            .collect::<StorageResult<Vec<_>>>()?;
+        let data_source: &DataSource<Description> = self.data.as_ref();
+        data_source.batch_write(&mut entries.into_iter())
+    }
+
     #[cfg(feature = "rocksdb")]
     pub fn open(path: &Path, capacity: impl Into<Option<usize>>) -> DatabaseResult<Self> {
         use anyhow::Context;
@@ -153,6 +228,49 @@ where
 
         Ok(Database {
             data: StructuredStorage::new(Arc::new(db).into()),
+            history: None,
+            _drop: Default::default(),
+        })
+    }
+
+    /// Opens the database as the secondary instance of the primary database at the `path`.
+    #[cfg(feature = "rocksdb")]
+    pub fn open_secondary(
+        path: &Path,
+        secondary_path: &Path,
+        capacity: impl Into<Option<usize>>,
+    ) -> DatabaseResult<Self> {
+        use anyhow::Context;
+        let db = RocksDb::<Description>::default_open_secondary(
+            path,
+            secondary_path,
+            capacity.into(),
+        )
+        .map_err(Into::<anyhow::Error>::into)
+        .context("Failed to open rocksdb as the secondary instance")?;
+
+        Ok(Database {
+            data: StructuredStorage::new(Arc::new(db).into()),
+            history: None,
+            _drop: Default::default(),
+        })
+    }
+
+    /// Opens the existing database at the `path` without the write capability.
+    /// Any attempt to modify the database fails.
+    #[cfg(feature = "rocksdb")]
+    pub fn open_read_only(
+        path: &Path,
+        capacity: impl Into<Option<usize>>,
+    ) -> DatabaseResult<Self> {
+        use anyhow::Context;
+        let db = RocksDb::<Description>::default_open_read_only(path, capacity.into())
+            .map_err(Into::<anyhow::Error>::into)
+            .context("Failed to open rocksdb in the read-only mode")?;
+
+        Ok(Database {
+            data: StructuredStorage::new(Arc::new(db).into()),
+            history: None,
             _drop: Default::default(),
         })
     }
@@ -160,6 +278,7 @@ where
     pub fn in_memory() -> Self {
         Self {
             data: StructuredStorage::new(Arc::new(MemoryStore::default()).into()),
+            history: None,
             _drop: Default::default(),
         }
     }
@@ -170,6 +289,7 @@ where
         let db = RocksDb::<Description>::default_open(tmp_dir.path(), None).unwrap();
         Self {
             data: StructuredStorage::new(Arc::new(db).into()),
+            history: None,
             _drop: Arc::new(
                 {
                     move || {
@@ -189,6 +309,20 @@ where
     pub fn flush(self) -> DatabaseResult<()> {
         self.data.as_ref().flush()
     }
+
+    pub fn catch_up_with_primary(&self) -> DatabaseResult<()> {
+        self.data.as_ref().catch_up_with_primary()
+    }
+
+    /// Compacts the whole key range of the database.
+    pub fn compact(&self) -> DatabaseResult<()> {
+        self.data.as_ref().compact()
+    }
+
+    /// Creates the consistent copy of the database in the `path` directory.
+    pub fn checkpoint(&self, path: &std::path::Path) -> DatabaseResult<()> {
+        self.data.as_ref().checkpoint(path)
+    }
 }
 
 impl<Description> KeyValueStore for DataSource<Description>
@@ -428,9 +562,22 @@ impl AtomicView for Database<OnChain> {
         self.latest_height().unwrap_or_default()
     }
 
-    fn view_at(&self, _: &BlockHeight) -> StorageResult<Self::View> {
-        // TODO: Unimplemented until of the https://github.com/FuelLabs/fuel-core/issues/451
-        Ok(self.latest_view())
+    fn view_at(&self, height: &BlockHeight) -> StorageResult<Self::View> {
+        let latest_height = self.latest_height()?;
+        if *height == latest_height {
+            return Ok(self.latest_view())
+        }
+        if *height > latest_height {
+            return Err(StorageError::Other(anyhow::anyhow!(
+                "The height {height} is above the latest height {latest_height}"
+            )))
+        }
+        let Some(history) = self.history.as_ref() else {
+            return Err(StorageError::Other(anyhow::anyhow!(
+                "The database doesn't keep the state history to view it at height {height}"
+            )))
+        };
+        Ok(Database::new(Arc::new(history.view_at(*height)?)))
     }
 
     fn latest_view(&self) -> Self::View {
@@ -1,19 +1,28 @@
-use crate::database::{
-    database_description::{
-        on_chain::OnChain,
-        DatabaseDescription,
-        DatabaseMetadata,
+use crate::{
+    database::{
+        database_description::{
+            on_chain::OnChain,
+            DatabaseDescription,
+            DatabaseMetadata,
+        },
+        metadata::MetadataTable,
+        Database,
     },
-    metadata::MetadataTable,
-    Database,
+    state::DataSource,
 };
 use fuel_core_storage::{
-    blueprint::plain::Plain,
+    blueprint::{
+        plain::Plain,
+        Blueprint,
+    },
     codec::{
         primitive::Primitive,
         raw::Raw,
+        Encode,
+        Encoder,
     },
     iter::IterDirection,
+    kv_store::KeyValueStore,
     not_found,
     structured_storage::TableWithBlueprint,
     tables::{
@@ -40,6 +49,7 @@ use fuel_core_types::{
             Block,
             CompressedBlock,
         },
+        header::BlockHeader,
         primitives::BlockId,
     },
     entities::message::MerkleProof,
@@ -193,6 +203,38 @@ impl Database {
             .map(|v| v.map(|v| v.into_owned()))
     }
 
+    /// Get the header of the block at the `height` without decoding the transactions of the block.
+    pub fn get_block_header(
+        &self,
+        height: &BlockHeight,
+    ) -> StorageResult<Option<BlockHeader>> {
+        let key = FuelBlocksKeyCodec::encode(height);
+        self.data
+            .as_ref()
+            .get(key.as_bytes().as_ref(), FuelBlocks::column())?
+            .map(|value| decode_block_header(&value))
+            .transpose()
+    }
+
+    /// Iterates over the headers of blocks starting from the `height` without decoding
+    /// the transactions of blocks.
+    pub fn block_headers(
+        &self,
+        height: Option<&BlockHeight>,
+        direction: IterDirection,
+    ) -> impl Iterator<Item = StorageResult<BlockHeader>> + '_ {
+        let start = height.map(FuelBlocksKeyCodec::encode);
+        self.data
+            .as_ref()
+            .iter_all(
+                FuelBlocks::column(),
+                None,
+                start.as_ref().map(|start| start.as_bytes()).as_deref(),
+                direction,
+            )
+            .map(|result| result.and_then(|(_, value)| decode_block_header(&value)))
+    }
+
     /// Retrieve the full block and all associated transactions
     pub(crate) fn get_full_block(
         &self,
@@ -219,6 +261,26 @@ impl Database {
     }
 }
 
+type FuelBlocksKeyCodec = <<FuelBlocks as TableWithBlueprint>::Blueprint as Blueprint<
+    FuelBlocks,
+    DataSource,
+>>::KeyCodec;
+
+/// The leading part of the serialized [`CompressedBlock`]. The header is encoded before
+/// the transactions of the block, so it can be decoded without the rest of the block.
+#[derive(serde::Deserialize)]
+enum CompressedBlockHeader {
+    V1(BlockHeader),
+}
+
+fn decode_block_header(bytes: &[u8]) -> StorageResult<BlockHeader> {
+    let (header, _) = postcard::take_from_bytes::<CompressedBlockHeader>(bytes)
+        .map_err(|e| StorageError::Codec(anyhow::anyhow!(e)))?;
+    let CompressedBlockHeader::V1(mut header) = header;
+    header.recalculate_metadata();
+    Ok(header)
+}
+
 impl MerkleRootStorage<BlockHeight, FuelBlocks> for Database {
     fn root(
         &self,
@@ -282,13 +344,16 @@ mod tests {
         blockchain::{
             block::PartialFuelBlock,
             header::{
+                ApplicationHeader,
                 ConsensusHeader,
                 PartialBlockHeader,
             },
             primitives::Empty,
         },
+        fuel_tx::Transaction,
         fuel_types::ChainId,
         fuel_vm::crypto::ephemeral_merkle_root,
+        tai64::Tai64,
     };
     use test_case::test_case;
 
@@ -440,4 +505,88 @@ mod tests {
         );
         assert!(result.is_err());
     }
+
+    #[test]
+    fn block_headers_are_read_without_transactions() {
+        let mut database = Database::default();
+        let blocks = (0..3u32)
+            .map(|height| {
+                let header = PartialBlockHeader {
+                    application: Default::default(),
+                    consensus: ConsensusHeader::<Empty> {
+                        height: height.into(),
+                        ..Default::default()
+                    },
+                };
+                let transactions = vec![Transaction::default_test_tx(); height as usize];
+                PartialFuelBlock::new(header, transactions).generate(&[])
+            })
+            .collect::<Vec<_>>();
+        for block in &blocks {
+            StorageMutate::<FuelBlocks>::insert(
+                &mut database,
+                block.header().height(),
+                &block.compress(&ChainId::default()),
+            )
+            .unwrap();
+        }
+
+        for block in &blocks {
+            let header = database
+                .get_block_header(block.header().height())
+                .unwrap()
+                .expect("The header should exist");
+            assert_eq!(&header, block.header());
+            assert_eq!(header.id(), block.id());
+        }
+        let headers = database
+            .block_headers(Some(&1u32.into()), IterDirection::Reverse)
+            .collect::<StorageResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(
+            headers,
+            vec![blocks[1].header().clone(), blocks[0].header().clone()]
+        );
+        assert_eq!(database.get_block_header(&3u32.into()).unwrap(), None);
+    }
+
+    #[test]
+    fn block_header_decoding_follows_the_encoding_of_compressed_block() {
+        type FuelBlocksValueCodec =
+            <<FuelBlocks as TableWithBlueprint>::Blueprint as Blueprint<
+                FuelBlocks,
+                DataSource,
+            >>::ValueCodec;
+
+        let header = PartialBlockHeader {
+            application: ApplicationHeader {
+                da_height: 17u64.into(),
+                ..Default::default()
+            },
+            consensus: ConsensusHeader::<Empty> {
+                prev_root: [3; 32].into(),
+                height: 42u32.into(),
+                time: Tai64(1337),
+                ..Default::default()
+            },
+        };
+        let transactions = vec![Transaction::default_test_tx(); 3];
+        let block = PartialFuelBlock::new(header, transactions)
+            .generate(&[])
+            .compress(&ChainId::default());
+        // `CompressedBlockHeader` mirrors the versions of `CompressedBlock` and
+        // `BlockHeader`, so new versions must be added to it too.
+        match &block {
+            CompressedBlock::V1(_) => {}
+        }
+        match block.header() {
+            BlockHeader::V1(_) => {}
+        }
+
+        let encoded = FuelBlocksValueCodec::encode_as_value(&block);
+        let header = decode_block_header(&encoded).unwrap();
+
+        assert_eq!(&header, block.header());
+        assert_eq!(header.id(), block.header().id());
+    }
 }
@@ -1,5 +1,6 @@
 use crate::database::{
     database_description::on_chain::OnChain,
+    migration::migrate_in_batches,
     Database,
 };
 use fuel_core_chain_config::CoinConfig;
@@ -7,10 +8,20 @@ use fuel_core_storage::{
     blueprint::plain::Plain,
     codec::{
         postcard::Postcard,
-        primitive::utxo_id_to_bytes,
+        primitive::{
+            owner_id_utxo_id_to_bytes,
+            utxo_id_to_bytes,
+            Primitive,
+        },
         raw::Raw,
+        Decode,
     },
-    iter::IterDirection,
+    iter::{
+        BoxedIter,
+        IntoBoxedIter,
+        IterDirection,
+    },
+    kv_store::KeyValueStore,
     not_found,
     structured_storage::TableWithBlueprint,
     tables::Coins,
@@ -27,23 +38,26 @@ use fuel_core_types::{
     entities::coins::coin::CompressedCoin,
     fuel_tx::{
         Address,
+        AssetId,
         UtxoId,
     },
 };
-use std::borrow::Cow;
+use std::{
+    borrow::Cow,
+    mem::size_of,
+};
 
-// TODO: Reuse `fuel_vm::storage::double_key` macro.
-pub fn owner_coin_id_key(owner: &Address, coin_id: &UtxoId) -> OwnedCoinKey {
-    let mut default = [0u8; Address::LEN + TxId::LEN + 1];
-    default[0..Address::LEN].copy_from_slice(owner.as_ref());
-    default[Address::LEN..].copy_from_slice(utxo_id_to_bytes(coin_id).as_ref());
-    default
+/// The compact id of the owner assigned by the [`OwnerIds`] table.
+pub type OwnerId = u64;
+
+pub fn owner_coin_id_key(owner_id: OwnerId, coin_id: &UtxoId) -> OwnedCoinKey {
+    owner_id_utxo_id_to_bytes(owner_id, coin_id)
 }
 
 /// The storage table of owned coin ids. Maps addresses to owned coins.
 pub struct OwnedCoins;
-/// The storage key for owned coins: `Address ++ UtxoId`
-pub type OwnedCoinKey = [u8; Address::LEN + TxId::LEN + 1];
+/// The storage key for owned coins: `OwnerId ++ UtxoId`
+pub type OwnedCoinKey = [u8; size_of::<OwnerId>() + TxId::LEN + 1];
 
 impl Mappable for OwnedCoins {
     type Key = Self::OwnedKey;
@@ -61,6 +75,245 @@ impl TableWithBlueprint for OwnedCoins {
     }
 }
 
+/// The storage table of compact ids of owners. The id replaces the address
+/// in keys of the [`OwnedCoins`] table, making keys shorter and cheaper to compare.
+/// Ids are assigned sequentially and never reused.
+pub struct OwnerIds;
+
+impl Mappable for OwnerIds {
+    type Key = Self::OwnedKey;
+    type OwnedKey = Address;
+    type Value = Self::OwnedValue;
+    type OwnedValue = OwnerId;
+}
+
+impl TableWithBlueprint for OwnerIds {
+    type Blueprint = Plain<Raw, Primitive<8>>;
+    type Column = fuel_core_storage::column::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::OwnerIds
+    }
+}
+
+/// The storage table of the last id assigned by the [`OwnerIds`] table.
+pub struct LastOwnerId;
+
+impl Mappable for LastOwnerId {
+    type Key = Self::OwnedKey;
+    type OwnedKey = ();
+    type Value = Self::OwnedValue;
+    type OwnedValue = OwnerId;
+}
+
+impl TableWithBlueprint for LastOwnerId {
+    type Blueprint = Plain<Postcard, Primitive<8>>;
+    type Column = fuel_core_storage::column::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::LastOwnerId
+    }
+}
+
+/// The storage table of the total amount of coins per owner and asset.
+/// It is updated together with the [`Coins`] table, so the balance of the owner
+/// doesn't require iteration over all its coins.
+pub struct CoinBalances;
+/// The storage key for coin balances: `Address ++ AssetId`
+pub type CoinBalanceKey = [u8; Address::LEN + AssetId::LEN];
+
+pub fn coin_balance_key(owner: &Address, asset_id: &AssetId) -> CoinBalanceKey {
+    let mut key = [0u8; Address::LEN + AssetId::LEN];
+    key[..Address::LEN].copy_from_slice(owner.as_ref());
+    key[Address::LEN..].copy_from_slice(asset_id.as_ref());
+    key
+}
+
+impl Mappable for CoinBalances {
+    type Key = Self::OwnedKey;
+    type OwnedKey = CoinBalanceKey;
+    type Value = Self::OwnedValue;
+    type OwnedValue = u64;
+}
+
+impl TableWithBlueprint for CoinBalances {
+    type Blueprint = Plain<Raw, Primitive<8>>;
+    type Column = fuel_core_storage::column::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::CoinBalances
+    }
+}
+
+/// The storage table marking the [`CoinBalances`] table as inconsistent with the [`Coins`] table.
+/// The balances are computed from coins until the table is rebuilt.
+pub struct CoinBalancesDirty;
+
+impl Mappable for CoinBalancesDirty {
+    type Key = Self::OwnedKey;
+    type OwnedKey = ();
+    type Value = Self::OwnedValue;
+    type OwnedValue = ();
+}
+
+impl TableWithBlueprint for CoinBalancesDirty {
+    type Blueprint = Plain<Postcard, Postcard>;
+    type Column = fuel_core_storage::column::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::CoinBalancesDirty
+    }
+}
+
+impl Database {
+    /// Returns the compact id of the `owner`, if any coin of the `owner` was indexed.
+    pub fn owner_id(&self, owner: &Address) -> StorageResult<Option<OwnerId>> {
+        Ok(self
+            .storage::<OwnerIds>()
+            .get(owner)?
+            .map(|owner_id| *owner_id))
+    }
+
+    pub(crate) fn owner_id_or_assign(
+        &mut self,
+        owner: &Address,
+    ) -> StorageResult<OwnerId> {
+        if let Some(owner_id) = self.owner_id(owner)? {
+            return Ok(owner_id)
+        }
+
+        let owner_id = match self.storage::<LastOwnerId>().get(&())? {
+            Some(last) => last
+                .checked_add(1)
+                .ok_or(anyhow::anyhow!("The owner ids are exhausted"))?,
+            None => 0,
+        };
+        self.storage_as_mut::<LastOwnerId>()
+            .insert(&(), &owner_id)?;
+        self.storage_as_mut::<OwnerIds>().insert(owner, &owner_id)?;
+        Ok(owner_id)
+    }
+
+    /// Re-encodes keys of the [`OwnedCoins`] table from the legacy `Address ++ UtxoId`
+    /// layout into the `OwnerId ++ UtxoId` layout. Keys of both layouts are derived
+    /// from the [`Coins`] table, so the migration passes over coins once.
+    pub(crate) fn reencode_owned_coins(&mut self) -> StorageResult<()> {
+        let column = OwnedCoins::column();
+        migrate_in_batches(self, self.iter_all::<Coins>(None), |database, entry| {
+            let (utxo_id, coin) = entry;
+            let mut legacy_key = coin.owner().to_vec();
+            legacy_key.extend(utxo_id_to_bytes(&utxo_id));
+            database.data.as_ref().delete(&legacy_key, column)?;
+
+            let owner_id = database.owner_id_or_assign(coin.owner())?;
+            database
+                .storage_as_mut::<OwnedCoins>()
+                .insert(&owner_coin_id_key(owner_id, &utxo_id), &())?;
+            Ok(())
+        })
+    }
+
+    /// Returns `true` if the [`CoinBalances`] table is inconsistent with
+    /// the [`Coins`] table and requires a rebuild.
+    pub fn coin_balances_dirty(&self) -> StorageResult<bool> {
+        self.storage::<CoinBalancesDirty>().contains_key(&())
+    }
+
+    /// Rebuilds the [`CoinBalances`] table from the [`Coins`] table.
+    /// Balances are accumulated in the database and committed in batches,
+    /// so the rebuild doesn't keep all coins in memory.
+    ///
+    /// Returns an error if the balance overflows `u64`. The index stays dirty then.
+    pub(crate) fn rebuild_coin_balances(&mut self) -> StorageResult<()> {
+        let column = CoinBalances::column();
+        let keys = self
+            .data
+            .as_ref()
+            .iter_all(column, None, None, IterDirection::Forward)
+            .map(|result| result.map(|(key, _)| key));
+        migrate_in_batches(self, keys, |database, key| {
+            database.data.as_ref().delete(&key, column)
+        })?;
+
+        migrate_in_batches(self, self.iter_all::<Coins>(None), |database, entry| {
+            let (_, coin) = entry;
+            let key = coin_balance_key(coin.owner(), coin.asset_id());
+            let balance = database
+                .storage::<CoinBalances>()
+                .get(&key)?
+                .map(|balance| *balance)
+                .unwrap_or_default()
+                .checked_add(*coin.amount())
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "The balance of the asset {} of the owner {} overflows `u64`",
+                        coin.asset_id(),
+                        coin.owner()
+                    )
+                })?;
+            database
+                .storage_as_mut::<CoinBalances>()
+                .insert(&key, &balance)?;
+            Ok(())
+        })?;
+
+        // The rebuilt balances are committed, so the index is consistent again.
+        self.storage_as_mut::<CoinBalancesDirty>().remove(&())?;
+        Ok(())
+    }
+
+    /// Rebuilds the [`CoinBalances`] table if it is marked as dirty.
+    pub fn rebuild_coin_balances_if_dirty(&mut self) -> StorageResult<()> {
+        if self.coin_balances_dirty()? {
+            tracing::info!("Rebuilding the dirty coin balances index");
+            self.rebuild_coin_balances()?;
+        }
+        Ok(())
+    }
+
+    fn increase_coin_balance(&mut self, coin: &CompressedCoin) -> StorageResult<()> {
+        let key = coin_balance_key(coin.owner(), coin.asset_id());
+        let balance = self
+            .storage::<CoinBalances>()
+            .get(&key)?
+            .map(|balance| *balance)
+            .unwrap_or_default();
+        let Some(balance) = balance.checked_add(*coin.amount()) else {
+            return self.mark_coin_balances_dirty()
+        };
+        self.storage_as_mut::<CoinBalances>()
+            .insert(&key, &balance)?;
+        Ok(())
+    }
+
+    fn decrease_coin_balance(&mut self, coin: &CompressedCoin) -> StorageResult<()> {
+        let key = coin_balance_key(coin.owner(), coin.asset_id());
+        let balance = self
+            .storage::<CoinBalances>()
+            .get(&key)?
+            .map(|balance| *balance)
+            .unwrap_or_default();
+        match balance.checked_sub(*coin.amount()) {
+            Some(0) => {
+                self.storage_as_mut::<CoinBalances>().remove(&key)?;
+            }
+            Some(balance) => {
+                self.storage_as_mut::<CoinBalances>()
+                    .insert(&key, &balance)?;
+            }
+            None => return self.mark_coin_balances_dirty(),
+        }
+        Ok(())
+    }
+
+    fn mark_coin_balances_dirty(&mut self) -> StorageResult<()> {
+        tracing::warn!("The coin balances index is inconsistent with coins");
+        self.storage_as_mut::<CoinBalancesDirty>()
+            .insert(&(), &())?;
+        Ok(())
+    }
+}
+
 impl StorageInspect<Coins> for Database {
     type Error = StorageError;
 
@@ -79,12 +332,18 @@ impl StorageMutate<Coins> for Database {
         key: &UtxoId,
         value: &CompressedCoin,
     ) -> Result<Option<CompressedCoin>, Self::Error> {
-        let coin_by_owner = owner_coin_id_key(value.owner(), key);
+        let owner_id = self.owner_id_or_assign(value.owner())?;
+        let coin_by_owner = owner_coin_id_key(owner_id, key);
         // insert primary record
         let insert = self.data.storage_as_mut::<Coins>().insert(key, value)?;
         // insert secondary index by owner
         self.storage_as_mut::<OwnedCoins>()
             .insert(&coin_by_owner, &())?;
+        // update the balance of the owner
+        if let Some(replaced) = &insert {
+            self.decrease_coin_balance(replaced)?;
+        }
+        self.increase_coin_balance(value)?;
         Ok(insert)
     }
 
@@ -93,8 +352,11 @@ impl StorageMutate<Coins> for Database {
 
         // cleanup secondary index
         if let Some(coin) = &coin {
-            let key = owner_coin_id_key(coin.owner(), key);
-            self.storage_as_mut::<OwnedCoins>().remove(&key)?;
+            if let Some(owner_id) = self.owner_id(coin.owner())? {
+                let key = owner_coin_id_key(owner_id, key);
+                self.storage_as_mut::<OwnedCoins>().remove(&key)?;
+            }
+            self.decrease_coin_balance(coin)?;
         }
 
         Ok(coin)
@@ -107,21 +369,59 @@ impl Database<OnChain> {
         owner: &Address,
         start_coin: Option<UtxoId>,
         direction: Option<IterDirection>,
-    ) -> impl Iterator<Item = StorageResult<UtxoId>> + '_ {
-        let start_coin = start_coin.map(|b| owner_coin_id_key(owner, &b));
+    ) -> BoxedIter<'_, StorageResult<UtxoId>> {
+        let owner_id = match self.owner_id(owner) {
+            Ok(Some(owner_id)) => owner_id,
+            Ok(None) => return core::iter::empty().into_boxed(),
+            Err(err) => return core::iter::once(Err(err)).into_boxed(),
+        };
+        let start_coin = start_coin.map(|b| owner_coin_id_key(owner_id, &b));
         self.iter_all_filtered::<OwnedCoins, _>(
-            Some(*owner), start_coin.as_ref(),
+            Some(owner_id.to_be_bytes()),
+            start_coin.as_ref(),
             direction,
         )
-        // Safety: key is always 64 bytes
         .map(|res| {
-            res.map(|(key, _)| {
-                UtxoId::new(
-                    TxId::try_from(&key[32..64]).expect("The slice has size 32"),
-                    key[64],
-                )
+            res.and_then(|(key, _)| {
+                let utxo_id = &key[size_of::<OwnerId>()..];
+                Primitive::<{ TxId::LEN + 1 }>::decode(utxo_id)
+                    .map_err(StorageError::Codec)
             })
         })
+        .into_boxed()
+    }
+
+    /// Returns the total amount of coins of the `asset_id` owned by the `owner`
+    /// from the [`CoinBalances`] table.
+    pub fn coin_balance(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+    ) -> StorageResult<u64> {
+        Ok(self
+            .storage::<CoinBalances>()
+            .get(&coin_balance_key(owner, asset_id))?
+            .map(|balance| *balance)
+            .unwrap_or_default())
+    }
+
+    /// Iterates over the total amounts of coins per asset owned by the `owner`
+    /// from the [`CoinBalances`] table. The balances are ordered by the asset id.
+    pub fn coin_balances(
+        &self,
+        owner: &Address,
+        direction: Option<IterDirection>,
+    ) -> BoxedIter<'_, StorageResult<(AssetId, u64)>> {
+        self.iter_all_filtered::<CoinBalances, _>(Some(*owner), None, direction)
+            .map(|res| {
+                res.map(|(key, balance)| {
+                    let asset_id: [u8; AssetId::LEN] = key[Address::LEN..]
+                        .try_into()
+                        .expect("It's an array of 32 bytes");
+                    (asset_id.into(), balance)
+                })
+            })
+            .into_boxed()
     }
 }
 
@@ -162,16 +462,151 @@ impl Database {
 #[cfg(test)]
 mod test {
     use super::*;
+    use fuel_core_types::entities::coins::coin::CompressedCoinV1;
+
+    fn coin(owner: Address, asset_id: AssetId, amount: u64) -> CompressedCoin {
+        CompressedCoinV1 {
+            owner,
+            amount,
+            asset_id,
+            ..Default::default()
+        }
+        .into()
+    }
+
+    #[test]
+    fn coin_balances_follow_inserted_and_removed_coins() {
+        let owner = Address::new([1; 32]);
+        let other_owner = Address::new([2; 32]);
+        let asset_id = AssetId::new([1; 32]);
+        let other_asset_id = AssetId::new([2; 32]);
+        let database = &mut Database::default();
+        let coins = [
+            (UtxoId::new([1; 32].into(), 0), coin(owner, asset_id, 10)),
+            (UtxoId::new([2; 32].into(), 0), coin(owner, asset_id, 20)),
+            (
+                UtxoId::new([3; 32].into(), 0),
+                coin(owner, other_asset_id, 30),
+            ),
+            (
+                UtxoId::new([4; 32].into(), 0),
+                coin(other_owner, asset_id, 40),
+            ),
+        ];
+        for (utxo_id, coin) in coins.iter() {
+            database
+                .storage_as_mut::<Coins>()
+                .insert(utxo_id, coin)
+                .unwrap();
+        }
+
+        database
+            .storage_as_mut::<Coins>()
+            .remove(&coins[1].0)
+            .unwrap();
+
+        assert_eq!(database.coin_balance(&owner, &asset_id).unwrap(), 10);
+        let balances: Vec<_> = database
+            .coin_balances(&owner, None)
+            .collect::<StorageResult<_>>()
+            .unwrap();
+        assert_eq!(balances, vec![(asset_id, 10), (other_asset_id, 30)]);
+        database
+            .storage_as_mut::<Coins>()
+            .remove(&coins[0].0)
+            .unwrap();
+        let balances: Vec<_> = database
+            .coin_balances(&owner, Some(IterDirection::Reverse))
+            .collect::<StorageResult<_>>()
+            .unwrap();
+        assert_eq!(balances, vec![(other_asset_id, 30)]);
+        assert!(!database.coin_balances_dirty().unwrap());
+    }
+
+    #[test]
+    fn rebuild_coin_balances_if_dirty_restores_balances_from_coins() {
+        let owner = Address::new([1; 32]);
+        let asset_id = AssetId::new([1; 32]);
+        let utxo_id = UtxoId::new([1; 32].into(), 0);
+        let database = &mut Database::default();
+        // The coin is written bypassing the balances index.
+        database
+            .data
+            .storage_as_mut::<Coins>()
+            .insert(&utxo_id, &coin(owner, asset_id, 10))
+            .unwrap();
+        database
+            .storage_as_mut::<Coins>()
+            .insert(&UtxoId::new([2; 32].into(), 0), &coin(owner, asset_id, 5))
+            .unwrap();
+        database.storage_as_mut::<Coins>().remove(&utxo_id).unwrap();
+        assert!(database.coin_balances_dirty().unwrap());
+
+        database.rebuild_coin_balances_if_dirty().unwrap();
+
+        assert!(!database.coin_balances_dirty().unwrap());
+        assert_eq!(database.coin_balance(&owner, &asset_id).unwrap(), 5);
+    }
+
+    #[test]
+    fn rebuild_coin_balances_removes_stale_balances() {
+        let owner = Address::new([1; 32]);
+        let other_owner = Address::new([2; 32]);
+        let asset_id = AssetId::new([1; 32]);
+        let database = &mut Database::default();
+        database
+            .storage_as_mut::<Coins>()
+            .insert(&UtxoId::new([1; 32].into(), 0), &coin(owner, asset_id, 10))
+            .unwrap();
+        database
+            .storage_as_mut::<Coins>()
+            .insert(&UtxoId::new([2; 32].into(), 0), &coin(owner, asset_id, 20))
+            .unwrap();
+        // The balance without coins is written bypassing the coins.
+        database
+            .storage_as_mut::<CoinBalances>()
+            .insert(&coin_balance_key(&other_owner, &asset_id), &100)
+            .unwrap();
+        database.mark_coin_balances_dirty().unwrap();
+
+        database.rebuild_coin_balances().unwrap();
+
+        assert!(!database.coin_balances_dirty().unwrap());
+        assert_eq!(database.coin_balance(&owner, &asset_id).unwrap(), 30);
+        assert_eq!(database.coin_balance(&other_owner, &asset_id).unwrap(), 0);
+    }
+
+    #[test]
+    fn rebuild_coin_balances_fails_on_overflow_and_stays_dirty() {
+        let owner = Address::new([1; 32]);
+        let asset_id = AssetId::new([1; 32]);
+        let database = &mut Database::default();
+        for output_index in 0..2 {
+            database
+                .storage_as_mut::<Coins>()
+                .insert(
+                    &UtxoId::new([1; 32].into(), output_index),
+                    &coin(owner, asset_id, u64::MAX),
+                )
+                .unwrap();
+        }
+        assert!(database.coin_balances_dirty().unwrap());
+
+        let result = database.rebuild_coin_balances();
+
+        assert!(result.is_err());
+        assert!(database.coin_balances_dirty().unwrap());
+    }
 
     fn generate_key(rng: &mut impl rand::Rng) -> <OwnedCoins as Mappable>::Key {
-        let mut bytes = [0u8; 65];
+        let mut bytes = [0u8; 41];
         rng.fill(bytes.as_mut());
         bytes
     }
 
     fuel_core_storage::basic_storage_tests!(
         OwnedCoins,
-        [0u8; 65],
+        [0u8; 41],
         <OwnedCoins as Mappable>::Value::default(),
         <OwnedCoins as Mappable>::Value::default(),
         generate_key
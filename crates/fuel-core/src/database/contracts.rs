@@ -1,4 +1,13 @@
-use crate::database::Database;
+use crate::{
+    database::{
+        database_description::off_chain::OffChain,
+        Database,
+    },
+    fuel_core_graphql_api::storage::contracts::{
+        ContractStateChangeKey,
+        ContractStateChanges,
+    },
+};
 use fuel_core_chain_config::ContractConfig;
 use fuel_core_storage::{
     iter::IterDirection,
@@ -17,10 +26,12 @@ use fuel_core_types::{
     entities::contract::ContractUtxoInfo,
     fuel_types::{
         AssetId,
+        BlockHeight,
         Bytes32,
         ContractId,
         Word,
     },
+    services::executor::ContractStateChange,
 };
 
 impl Database {
@@ -116,6 +127,52 @@ impl Database {
     }
 }
 
+impl Database<OffChain> {
+    /// Iterates over the writes to the storage slots of the `contract_id` made by blocks
+    /// from the `from` height to the `to` height inclusively. The writes are ordered
+    /// by the block height and then by the key of the slot.
+    pub fn contract_state_changes(
+        &self,
+        contract_id: ContractId,
+        from: BlockHeight,
+        to: BlockHeight,
+    ) -> impl Iterator<Item = StorageResult<(BlockHeight, ContractStateChange)>> + '_
+    {
+        let start = ContractStateChangeKey::new(&contract_id, from, &Bytes32::zeroed());
+        self.iter_all_filtered::<ContractStateChanges, _>(
+            Some(contract_id),
+            Some(&start),
+            Some(IterDirection::Forward),
+        )
+        .map(|res| {
+            res.map(|(key, value)| {
+                let change = ContractStateChange {
+                    contract_id: key.contract_id,
+                    key: key.key,
+                    value,
+                };
+                (key.block_height, change)
+            })
+        })
+        .take_while(move |res| {
+            res.as_ref()
+                .map_or(true, |(block_height, _)| *block_height <= to)
+        })
+    }
+
+    pub fn record_contract_state_change(
+        &mut self,
+        block_height: BlockHeight,
+        change: &ContractStateChange,
+    ) -> StorageResult<Option<Option<Bytes32>>> {
+        use fuel_core_storage::StorageAsMut;
+        self.storage::<ContractStateChanges>().insert(
+            &ContractStateChangeKey::new(&change.contract_id, block_height, &change.key),
+            &change.value,
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -149,4 +206,44 @@ mod tests {
             .into_owned();
         assert_eq!(returned, contract);
     }
+
+    #[test]
+    fn contract_state_changes_returns_changes_in_range_of_blocks() {
+        let contract_id = ContractId::from([1u8; 32]);
+        let other_contract_id = ContractId::from([2u8; 32]);
+        let change = |contract_id, key: u8, value: Option<u8>| ContractStateChange {
+            contract_id,
+            key: Bytes32::from([key; 32]),
+            value: value.map(|value| Bytes32::from([value; 32])),
+        };
+        let database = &mut Database::<OffChain>::default();
+        for (height, change) in [
+            (1u32, change(contract_id, 1, Some(1))),
+            (2, change(contract_id, 2, Some(2))),
+            (2, change(contract_id, 1, None)),
+            (2, change(other_contract_id, 1, Some(3))),
+            (3, change(contract_id, 3, Some(3))),
+        ] {
+            database
+                .record_contract_state_change(height.into(), &change)
+                .unwrap();
+        }
+
+        let changes = database
+            .contract_state_changes(contract_id, 2u32.into(), 2u32.into())
+            .collect::<StorageResult<Vec<_>>>()
+            .unwrap();
+
+        assert_eq!(
+            changes,
+            vec![
+                (2u32.into(), change(contract_id, 1, None)),
+                (2u32.into(), change(contract_id, 2, Some(2))),
+            ]
+        );
+        let changes = database
+            .contract_state_changes(contract_id, 0u32.into(), 10u32.into())
+            .count();
+        assert_eq!(changes, 4);
+    }
 }
@@ -12,7 +12,7 @@ impl DatabaseDescription for OffChain {
     type Height = BlockHeight;
 
     fn version() -> u32 {
-        0
+        1
     }
 
     fn name() -> &'static str {
@@ -31,6 +31,10 @@ impl DatabaseDescription for OffChain {
                 // prefix is address length
                 Some(32)
             }
+            Self::Column::ContractStateChanges => {
+                // prefix is contract id length
+                Some(32)
+            }
             _ => None,
         }
     }
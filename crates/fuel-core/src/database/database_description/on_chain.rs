@@ -9,7 +9,7 @@ impl DatabaseDescription for OnChain {
     type Height = BlockHeight;
 
     fn version() -> u32 {
-        0
+        2
     }
 
     fn name() -> &'static str {
@@ -22,8 +22,12 @@ impl DatabaseDescription for OnChain {
 
     fn prefix(column: &Self::Column) -> Option<usize> {
         match column {
-            Self::Column::OwnedCoins
-            | Self::Column::OwnedMessageIds
+            Self::Column::OwnedCoins => {
+                // prefix is owner id length
+                Some(8)
+            }
+            Self::Column::OwnedMessageIds
+            | Self::Column::CoinBalances
             | Self::Column::ContractsAssets
             | Self::Column::ContractsState => {
                 // prefix is address length
@@ -0,0 +1,661 @@
+use crate::database::{
+    database_description::on_chain::OnChain,
+    Database,
+};
+use fuel_core_storage::{
+    iter::IterDirection,
+    tables::{
+        Coins,
+        ContractsAssets,
+        ContractsInfo,
+        ContractsState,
+        FuelBlocks,
+        Transactions,
+    },
+    IsNotFound,
+    MerkleRoot,
+    MerkleRootStorage,
+    Result as StorageResult,
+    StorageAsRef,
+};
+use fuel_core_types::{
+    blockchain::block::CompressedBlock,
+    fuel_merkle::sparse::{
+        in_memory,
+        MerkleTreeKey,
+    },
+    fuel_tx::ContractId,
+    fuel_types::{
+        BlockHeight,
+        Bytes32,
+    },
+};
+use rand::Rng;
+use std::collections::BTreeSet;
+
+/// The z-score of the 95% confidence level of the corruption rate estimation.
+const CONFIDENCE_Z_SCORE: f64 = 1.96;
+
+/// The transaction referenced by the block but missing in the database.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct MissingTransaction {
+    pub block_height: u32,
+    pub tx_id: String,
+}
+
+/// The coin created by the transaction that doesn't exist in the database.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct OrphanCoin {
+    pub utxo_id: String,
+    pub block_height: u32,
+}
+
+/// The report of the referential integrity check of the on-chain database.
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct IntegrityReport {
+    /// The number of checked blocks.
+    pub blocks: u64,
+    /// The number of checked transactions referenced by blocks.
+    pub transactions: u64,
+    /// The number of checked coins.
+    pub coins: u64,
+    pub missing_transactions: Vec<MissingTransaction>,
+    pub orphan_coins: Vec<OrphanCoin>,
+}
+
+impl IntegrityReport {
+    /// Returns `true` if no violations were found.
+    pub fn is_ok(&self) -> bool {
+        self.missing_transactions.is_empty() && self.orphan_coins.is_empty()
+    }
+}
+
+impl Database<OnChain> {
+    /// Checks that every transaction referenced by a block exists, and every coin
+    /// points at the existing transaction. Coins created by the genesis block
+    /// don't have transactions and are skipped.
+    pub fn check_integrity(&self) -> StorageResult<IntegrityReport> {
+        let mut report = IntegrityReport::default();
+        let mut genesis_height: Option<BlockHeight> = None;
+
+        for result in self.iter_all::<FuelBlocks>(Some(IterDirection::Forward)) {
+            let (height, block) = result?;
+            genesis_height.get_or_insert(height);
+            report.blocks = report.blocks.saturating_add(1);
+
+            for tx_id in block.transactions() {
+                report.transactions = report.transactions.saturating_add(1);
+                if !self.storage::<Transactions>().contains_key(tx_id)? {
+                    report.missing_transactions.push(MissingTransaction {
+                        block_height: *height,
+                        tx_id: tx_id.to_string(),
+                    });
+                }
+            }
+        }
+
+        let genesis_height = genesis_height.unwrap_or_default();
+        for result in self.iter_all::<Coins>(None) {
+            let (utxo_id, coin) = result?;
+            report.coins = report.coins.saturating_add(1);
+
+            let block_height = coin.tx_pointer().block_height();
+            if block_height <= genesis_height {
+                continue
+            }
+            if !self
+                .storage::<Transactions>()
+                .contains_key(utxo_id.tx_id())?
+            {
+                report.orphan_coins.push(OrphanCoin {
+                    utxo_id: utxo_id.to_string(),
+                    block_height: *block_height,
+                });
+            }
+        }
+
+        Ok(report)
+    }
+}
+
+/// The entries verified against their merkle roots.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VerificationScope {
+    /// Verifies up to the number of random blocks and up to the number of random contracts.
+    Sample(usize),
+    /// Verifies all blocks and contracts.
+    Full,
+}
+
+/// The entry that doesn't match the merkle root committed to it.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum RootViolation {
+    /// The block is missing between the genesis and the latest blocks.
+    MissingBlock { block_height: u32 },
+    /// The transaction of the block is missing, so the transactions root can't be verified.
+    MissingTransaction { block_height: u32, tx_id: String },
+    /// The transactions of the block don't match the transactions root of its header.
+    TransactionsRootMismatch { block_height: u32 },
+    /// The merkle root of blocks up to this block doesn't match
+    /// the `prev_root` of the next block.
+    BlockRootMismatch { block_height: u32 },
+    /// The state of the contract doesn't match its state root.
+    ContractStateRootMismatch { contract_id: String },
+    /// The balances of the contract don't match its balances root.
+    ContractBalancesRootMismatch { contract_id: String },
+}
+
+/// The estimation of the corruption of one kind of verified entries.
+#[derive(Debug, Default, Clone, PartialEq, serde::Serialize)]
+pub struct CorruptionEstimate {
+    /// The number of verified entries.
+    pub verified: u64,
+    /// The number of verified entries with at least one violation.
+    pub corrupted: u64,
+    /// The share of corrupted entries among verified ones.
+    pub estimated_rate: f64,
+    /// The upper bound of the share of corrupted entries of this kind in the whole
+    /// database with the 95% confidence. For the full verification,
+    /// it is the exact share.
+    pub rate_upper_bound: f64,
+}
+
+impl CorruptionEstimate {
+    fn record(&mut self, violations: &[RootViolation]) {
+        self.verified = self.verified.saturating_add(1);
+        if !violations.is_empty() {
+            self.corrupted = self.corrupted.saturating_add(1);
+        }
+    }
+
+    fn estimate(&mut self, scope: VerificationScope) {
+        if self.verified == 0 {
+            return
+        }
+        let n = self.verified as f64;
+        let rate = self.corrupted as f64 / n;
+        self.estimated_rate = rate;
+        self.rate_upper_bound = match scope {
+            VerificationScope::Full => rate,
+            // The upper bound of the Wilson score interval. Unlike the normal
+            // approximation, it is meaningful when no corruption is found.
+            VerificationScope::Sample(_) => {
+                let z2 = CONFIDENCE_Z_SCORE * CONFIDENCE_Z_SCORE;
+                let center = rate + z2 / (2.0 * n);
+                let margin = CONFIDENCE_Z_SCORE
+                    * (rate * (1.0 - rate) / n + z2 / (4.0 * n * n)).sqrt();
+                ((center + margin) / (1.0 + z2 / n)).min(1.0)
+            }
+        };
+    }
+}
+
+/// The report of the verification of entries against their merkle roots.
+/// Blocks and contracts are different populations, so their corruption
+/// is estimated separately.
+#[derive(Debug, Default, Clone, PartialEq, serde::Serialize)]
+pub struct RootVerificationReport {
+    pub blocks: CorruptionEstimate,
+    pub contracts: CorruptionEstimate,
+    pub violations: Vec<RootViolation>,
+}
+
+impl RootVerificationReport {
+    /// Returns `true` if no violations were found.
+    pub fn is_ok(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+impl Database<OnChain> {
+    /// Verifies blocks and contracts against merkle roots committed to them.
+    /// The transactions of the block are verified against the transactions root
+    /// of its header, and the merkle root of blocks up to the block against
+    /// the `prev_root` of the next block. The state and balances of the contract
+    /// are verified against its state and balances roots.
+    ///
+    /// The sampled blocks and contracts are picked uniformly, so the estimated
+    /// corruption rates apply to all blocks and all contracts.
+    pub fn verify_roots<R: Rng>(
+        &self,
+        scope: VerificationScope,
+        rng: &mut R,
+    ) -> StorageResult<RootVerificationReport> {
+        let mut report = RootVerificationReport::default();
+
+        for height in self.blocks_to_verify(scope, rng)? {
+            let violations = self.verify_block_roots(height)?;
+            report.blocks.record(&violations);
+            report.violations.extend(violations);
+        }
+
+        for contract_id in self.contracts_to_verify(scope, rng)? {
+            let violations = self.verify_contract_roots(&contract_id)?;
+            report.contracts.record(&violations);
+            report.violations.extend(violations);
+        }
+
+        report.blocks.estimate(scope);
+        report.contracts.estimate(scope);
+        Ok(report)
+    }
+
+    fn blocks_to_verify<R: Rng>(
+        &self,
+        scope: VerificationScope,
+        rng: &mut R,
+    ) -> StorageResult<Vec<BlockHeight>> {
+        let first = self
+            .iter_all::<FuelBlocks>(Some(IterDirection::Forward))
+            .next()
+            .transpose()?;
+        let last = self
+            .iter_all::<FuelBlocks>(Some(IterDirection::Reverse))
+            .next()
+            .transpose()?;
+        let (Some((first, _)), Some((last, _))) = (first, last) else {
+            return Ok(vec![])
+        };
+        let (first, last) = (u32::from(first), u32::from(last));
+        let blocks = usize::try_from(last.saturating_sub(first))
+            .unwrap_or(usize::MAX)
+            .saturating_add(1);
+
+        let offsets: Vec<usize> = match scope {
+            VerificationScope::Sample(size) if size < blocks => {
+                let mut offsets = rand::seq::index::sample(rng, blocks, size).into_vec();
+                offsets.sort_unstable();
+                offsets
+            }
+            _ => (0..blocks).collect(),
+        };
+        Ok(offsets
+            .into_iter()
+            .filter_map(|offset| u32::try_from(offset).ok())
+            .filter_map(|offset| first.checked_add(offset))
+            .map(BlockHeight::from)
+            .collect())
+    }
+
+    fn contracts_to_verify<R: Rng>(
+        &self,
+        scope: VerificationScope,
+        rng: &mut R,
+    ) -> StorageResult<BTreeSet<ContractId>> {
+        let size = match scope {
+            VerificationScope::Sample(size) => size,
+            VerificationScope::Full => {
+                return self
+                    .iter_all::<ContractsInfo>(None)
+                    .map(|result| result.map(|(contract_id, _)| contract_id))
+                    .collect()
+            }
+        };
+
+        // The reservoir sampling picks every contract with the same probability
+        // in one pass over contracts.
+        let mut contracts = Vec::with_capacity(size);
+        for (seen, result) in self.iter_all::<ContractsInfo>(None).enumerate() {
+            let (contract_id, _) = result?;
+            if contracts.len() < size {
+                contracts.push(contract_id);
+            } else {
+                let index = rng.gen_range(0..=seen);
+                if let Some(contract) = contracts.get_mut(index) {
+                    *contract = contract_id;
+                }
+            }
+        }
+        Ok(contracts.into_iter().collect())
+    }
+
+    fn verify_block_roots(
+        &self,
+        height: BlockHeight,
+    ) -> StorageResult<Vec<RootViolation>> {
+        let block_height = *height;
+        let Some(block) = self.storage::<FuelBlocks>().get(&height)? else {
+            return Ok(vec![RootViolation::MissingBlock { block_height }])
+        };
+        let block: CompressedBlock = block.into_owned();
+
+        let mut violations = vec![];
+        let mut transactions = Vec::with_capacity(block.transactions().len());
+        for tx_id in block.transactions() {
+            match self.storage::<Transactions>().get(tx_id)? {
+                Some(tx) => transactions.push(tx.into_owned()),
+                None => violations.push(RootViolation::MissingTransaction {
+                    block_height,
+                    tx_id: tx_id.to_string(),
+                }),
+            }
+        }
+        if violations.is_empty() && !block.header().validate_transactions(&transactions) {
+            violations.push(RootViolation::TransactionsRootMismatch { block_height });
+        }
+
+        let next_header = match height.succ() {
+            Some(next) => self.get_block_header(&next)?,
+            None => None,
+        };
+        if let Some(next_header) = next_header {
+            let root = match self.storage::<FuelBlocks>().root(&height) {
+                Ok(root) => Some(Bytes32::from(root)),
+                Err(err) if err.is_not_found() => None,
+                Err(err) => return Err(err),
+            };
+            if root.as_ref() != Some(next_header.prev_root()) {
+                violations.push(RootViolation::BlockRootMismatch { block_height });
+            }
+        }
+
+        Ok(violations)
+    }
+
+    fn verify_contract_roots(
+        &self,
+        contract_id: &ContractId,
+    ) -> StorageResult<Vec<RootViolation>> {
+        let mut violations = vec![];
+
+        // The keys and values are encoded in the same way as by the `Sparse` blueprint.
+        let state = self
+            .iter_all_by_prefix::<ContractsState, _>(Some(contract_id.as_ref()))
+            .map(|result| {
+                result
+                    .map(|(key, value)| (key.as_ref().to_vec(), value.as_ref().to_vec()))
+            })
+            .collect::<StorageResult<Vec<_>>>()?;
+        if sparse_root(state) != self.storage::<ContractsState>().root(contract_id)? {
+            violations.push(RootViolation::ContractStateRootMismatch {
+                contract_id: contract_id.to_string(),
+            });
+        }
+
+        let balances = self
+            .iter_all_by_prefix::<ContractsAssets, _>(Some(contract_id.as_ref()))
+            .map(|result| {
+                result.map(|(key, value)| {
+                    (key.as_ref().to_vec(), value.to_be_bytes().to_vec())
+                })
+            })
+            .collect::<StorageResult<Vec<_>>>()?;
+        if sparse_root(balances) != self.storage::<ContractsAssets>().root(contract_id)? {
+            violations.push(RootViolation::ContractBalancesRootMismatch {
+                contract_id: contract_id.to_string(),
+            });
+        }
+
+        Ok(violations)
+    }
+}
+
+fn sparse_root(set: Vec<(Vec<u8>, Vec<u8>)>) -> MerkleRoot {
+    let (root, _) = in_memory::MerkleTree::nodes_from_set(
+        set.iter()
+            .map(|(key, value)| (MerkleTreeKey::new(key), value)),
+    );
+    root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_storage::{
+        tables::merkle::{
+            ContractsStateMerkleMetadata,
+            SparseMerkleMetadata,
+        },
+        ContractsAssetKey,
+        ContractsStateKey,
+        StorageAsMut,
+    };
+    use fuel_core_types::{
+        blockchain::{
+            block::Block,
+            header::PartialBlockHeader,
+        },
+        entities::coins::coin::{
+            CompressedCoin,
+            CompressedCoinV1,
+        },
+        fuel_tx::{
+            Transaction,
+            TxPointer,
+            UniqueIdentifier,
+            UtxoId,
+        },
+        fuel_types::ChainId,
+    };
+    use rand::{
+        rngs::StdRng,
+        SeedableRng,
+    };
+
+    fn database_with_block(height: u32, txs: &[Transaction]) -> Database<OnChain> {
+        let mut database = Database::default();
+        let chain_id = ChainId::default();
+        let mut header = PartialBlockHeader::default();
+        header.consensus.height = height.into();
+        let block = Block::new(header, txs.to_vec(), &[]).compress(&chain_id);
+        database
+            .storage_as_mut::<FuelBlocks>()
+            .insert(&height.into(), &block)
+            .unwrap();
+        database
+    }
+
+    fn insert_coin(database: &mut Database<OnChain>, utxo_id: UtxoId, height: u32) {
+        let coin: CompressedCoin = CompressedCoinV1 {
+            tx_pointer: TxPointer::new(height.into(), 0),
+            ..Default::default()
+        }
+        .into();
+        database
+            .storage_as_mut::<Coins>()
+            .insert(&utxo_id, &coin)
+            .unwrap();
+    }
+
+    #[test]
+    fn check_integrity_detects_missing_transactions() {
+        let tx = Transaction::default_test_tx();
+        let database = database_with_block(1, &[tx.clone()]);
+
+        let report = database.check_integrity().unwrap();
+
+        assert!(!report.is_ok());
+        assert_eq!(
+            report.missing_transactions,
+            vec![MissingTransaction {
+                block_height: 1,
+                tx_id: tx.id(&ChainId::default()).to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn check_integrity_passes_when_references_are_valid() {
+        let tx = Transaction::default_test_tx();
+        let tx_id = tx.id(&ChainId::default());
+        let mut database = database_with_block(0, &[tx.clone()]);
+        database
+            .storage_as_mut::<Transactions>()
+            .insert(&tx_id, &tx)
+            .unwrap();
+        insert_coin(&mut database, UtxoId::new(tx_id, 0), 1);
+        // The genesis coin doesn't have a transaction.
+        insert_coin(&mut database, UtxoId::new([1; 32].into(), 0), 0);
+
+        let report = database.check_integrity().unwrap();
+
+        assert!(report.is_ok(), "{report:?}");
+        assert_eq!(report.blocks, 1);
+        assert_eq!(report.transactions, 1);
+        assert_eq!(report.coins, 2);
+    }
+
+    fn database_with_chain(blocks: u32) -> Database<OnChain> {
+        let mut database = Database::default();
+        let chain_id = ChainId::default();
+        let tx = Transaction::default_test_tx();
+        database
+            .storage_as_mut::<Transactions>()
+            .insert(&tx.id(&chain_id), &tx)
+            .unwrap();
+
+        let mut prev_root = Bytes32::zeroed();
+        for height in 0..blocks {
+            insert_block(&mut database, height, prev_root, &[tx.clone()]);
+            prev_root = database
+                .storage::<FuelBlocks>()
+                .root(&height.into())
+                .unwrap()
+                .into();
+        }
+        database
+    }
+
+    fn insert_block(
+        database: &mut Database<OnChain>,
+        height: u32,
+        prev_root: Bytes32,
+        txs: &[Transaction],
+    ) {
+        let mut header = PartialBlockHeader::default();
+        header.consensus.height = height.into();
+        header.consensus.prev_root = prev_root;
+        let block = Block::new(header, txs.to_vec(), &[]).compress(&ChainId::default());
+        database
+            .storage_as_mut::<FuelBlocks>()
+            .insert(&height.into(), &block)
+            .unwrap();
+    }
+
+    fn insert_contract(database: &mut Database<OnChain>, contract_id: ContractId) {
+        database
+            .storage_as_mut::<ContractsInfo>()
+            .insert(&contract_id, &(Default::default(), Default::default()))
+            .unwrap();
+        database
+            .storage_as_mut::<ContractsState>()
+            .insert(
+                &ContractsStateKey::new(&contract_id, &[2; 32].into()),
+                &[3; 32].into(),
+            )
+            .unwrap();
+        database
+            .storage_as_mut::<ContractsAssets>()
+            .insert(&ContractsAssetKey::new(&contract_id, &[4; 32].into()), &100)
+            .unwrap();
+    }
+
+    #[test]
+    fn verify_roots_passes_for_consistent_blocks_and_contracts() {
+        let mut database = database_with_chain(3);
+        insert_contract(&mut database, [1; 32].into());
+
+        let report = database
+            .verify_roots(VerificationScope::Full, &mut StdRng::seed_from_u64(0))
+            .unwrap();
+
+        assert!(report.is_ok(), "{report:?}");
+        assert_eq!(report.blocks.verified, 3);
+        assert_eq!(report.contracts.verified, 1);
+        assert_eq!(report.blocks.rate_upper_bound, 0.0);
+        assert_eq!(report.contracts.rate_upper_bound, 0.0);
+    }
+
+    #[test]
+    fn verify_roots_detects_mismatched_roots() {
+        let mut database = database_with_chain(3);
+        insert_block(&mut database, 3, [1; 32].into(), &[]);
+        let contract_id = ContractId::from([1; 32]);
+        insert_contract(&mut database, contract_id);
+        database
+            .storage_as_mut::<ContractsStateMerkleMetadata>()
+            .insert(&contract_id, &SparseMerkleMetadata::new([5; 32]))
+            .unwrap();
+
+        let report = database
+            .verify_roots(VerificationScope::Full, &mut StdRng::seed_from_u64(0))
+            .unwrap();
+
+        assert_eq!(
+            report.violations,
+            vec![
+                RootViolation::BlockRootMismatch { block_height: 2 },
+                RootViolation::ContractStateRootMismatch {
+                    contract_id: contract_id.to_string(),
+                },
+            ]
+        );
+        assert_eq!(report.blocks.corrupted, 1);
+        assert_eq!(report.blocks.estimated_rate, 0.25);
+        assert_eq!(report.contracts.corrupted, 1);
+        assert_eq!(report.contracts.estimated_rate, 1.0);
+    }
+
+    #[test]
+    fn sampled_verification_estimates_corruption_upper_bound() {
+        let database = database_with_chain(10);
+
+        let report = database
+            .verify_roots(VerificationScope::Sample(4), &mut StdRng::seed_from_u64(0))
+            .unwrap();
+
+        assert!(report.is_ok(), "{report:?}");
+        assert_eq!(report.blocks.verified, 4);
+        assert_eq!(report.blocks.estimated_rate, 0.0);
+        // The Wilson upper bound for 4 samples without corruption is z^2 / (4 + z^2).
+        assert!((report.blocks.rate_upper_bound - 0.49).abs() < 0.01);
+        // No contracts were verified, so they don't affect the estimation of blocks.
+        assert_eq!(report.contracts, CorruptionEstimate::default());
+    }
+
+    #[test]
+    fn sampled_verification_picks_distinct_contracts() {
+        // Given
+        let mut database = database_with_chain(1);
+        // Most contracts are clustered, so seeking random ids would mostly pick the last.
+        for byte in [1, 2, 3, 4, 0xF0] {
+            insert_contract(&mut database, [byte; 32].into());
+        }
+
+        // When
+        let sample = database
+            .contracts_to_verify(
+                VerificationScope::Sample(3),
+                &mut StdRng::seed_from_u64(0),
+            )
+            .unwrap();
+        let all = database
+            .contracts_to_verify(
+                VerificationScope::Sample(10),
+                &mut StdRng::seed_from_u64(0),
+            )
+            .unwrap();
+
+        // Then
+        assert_eq!(sample.len(), 3);
+        assert_eq!(all.len(), 5);
+    }
+
+    #[test]
+    fn check_integrity_detects_orphan_coins() {
+        let mut database = database_with_block(0, &[]);
+        let utxo_id = UtxoId::new([1; 32].into(), 0);
+        insert_coin(&mut database, utxo_id, 1);
+
+        let report = database.check_integrity().unwrap();
+
+        assert_eq!(
+            report.orphan_coins,
+            vec![OrphanCoin {
+                utxo_id: utxo_id.to_string(),
+                block_height: 1,
+            }]
+        );
+    }
+}
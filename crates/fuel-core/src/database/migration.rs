@@ -0,0 +1,397 @@
+use crate::{
+    database::{
+        database_description::{
+            off_chain::OffChain,
+            on_chain::OnChain,
+            DatabaseDescription,
+            DatabaseMetadata,
+        },
+        metadata::MetadataTable,
+        Database,
+    },
+    fuel_core_graphql_api::storage::Column as OffChainColumn,
+};
+use fuel_core_storage::{
+    column::Column,
+    transactional::Transaction,
+    Error as StorageError,
+    Result as StorageResult,
+    StorageAsMut,
+    StorageMutate,
+};
+
+/// The number of scanned entries whose changes are committed together.
+/// The migration commits changes in batches instead of keeping them all in memory.
+/// Each migration is idempotent, so the node resumes the interrupted migration
+/// from the start, because the version is updated only after all migrations.
+pub const BATCH_SIZE: usize = 10_000;
+
+/// The migration of the database from the `version` to the next version.
+pub struct Migration<Description>
+where
+    Description: DatabaseDescription,
+{
+    /// The version of the database before the migration.
+    pub version: u32,
+    /// The description of the changes done by the migration.
+    pub description: &'static str,
+    /// The column whose entries are processed by the migration.
+    /// The number of its entries defines the duration of the migration.
+    pub scanned_column: Description::Column,
+    /// Migrates the database. The on-chain database is the source of
+    /// the data for migrations of other databases.
+    migrate: fn(&mut Database<Description>, &Database<OnChain>) -> StorageResult<()>,
+}
+
+/// Migrations of the on-chain database, ordered by the version.
+pub const MIGRATIONS: &[Migration<OnChain>] = &[
+    Migration {
+        version: 0,
+        description: "Re-encodes keys of the owned coins index with compact owner ids",
+        scanned_column: Column::Coins,
+        migrate: |database, _| database.reencode_owned_coins(),
+    },
+    Migration {
+        version: 1,
+        description: "Rebuilds the coin balances index from coins",
+        scanned_column: Column::Coins,
+        migrate: |database, _| database.rebuild_coin_balances(),
+    },
+];
+
+/// Migrations of the off-chain database, ordered by the version.
+pub const OFF_CHAIN_MIGRATIONS: &[Migration<OffChain>] = &[Migration {
+    version: 0,
+    description: "Re-encodes keys of transaction statuses with positions of transactions",
+    scanned_column: OffChainColumn::TransactionStatus,
+    migrate: Database::<OffChain>::reencode_tx_statuses,
+}];
+
+/// Returns the migration of the on-chain database from the `version` to the next version.
+pub fn migration_from(version: u32) -> Option<&'static Migration<OnChain>> {
+    MIGRATIONS
+        .iter()
+        .find(|migration| migration.version == version)
+}
+
+/// Returns the migration of the off-chain database from the `version` to the next version.
+pub fn off_chain_migration_from(version: u32) -> Option<&'static Migration<OffChain>> {
+    OFF_CHAIN_MIGRATIONS
+        .iter()
+        .find(|migration| migration.version == version)
+}
+
+/// Applies `migrate` to each of `entries` and commits changes every [`BATCH_SIZE`] entries.
+pub(crate) fn migrate_in_batches<Description, Entry>(
+    database: &Database<Description>,
+    entries: impl Iterator<Item = StorageResult<Entry>>,
+    mut migrate: impl FnMut(&mut Database<Description>, Entry) -> StorageResult<()>,
+) -> StorageResult<()>
+where
+    Description: DatabaseDescription,
+{
+    let mut transaction = database.transaction();
+    let mut pending = 0usize;
+    for entry in entries {
+        migrate(transaction.as_mut(), entry?)?;
+        pending = pending.saturating_add(1);
+        if pending >= BATCH_SIZE {
+            transaction.commit()?;
+            pending = 0;
+        }
+    }
+    transaction.commit()
+}
+
+impl<Description> Database<Description>
+where
+    Description: DatabaseDescription,
+    Self: StorageMutate<MetadataTable<Description>, Error = StorageError>,
+{
+    /// Applies `migrations` to the database created by the previous version of the node.
+    /// Does nothing if the database is not initialized yet.
+    /// The database with the version from the future is rejected later by the `init`.
+    fn apply_migrations(
+        &mut self,
+        migrations: impl Fn(u32) -> Option<&'static Migration<Description>>,
+        on_chain: &Database<OnChain>,
+    ) -> StorageResult<()> {
+        let Some(metadata) = self
+            .storage::<MetadataTable<Description>>()
+            .get(&())?
+            .map(|metadata| metadata.into_owned())
+        else {
+            return Ok(())
+        };
+
+        let found = metadata.version();
+        let expected = Description::version();
+        if found >= expected {
+            return Ok(())
+        }
+
+        tracing::info!(
+            "Migrating the {} database from version {found} to version {expected}",
+            Description::name()
+        );
+        for version in found..expected {
+            let migration = migrations(version).ok_or_else(|| {
+                anyhow::anyhow!("The migration from version {version} is unknown")
+            })?;
+            tracing::info!("Applying the migration: {}", migration.description);
+            (migration.migrate)(self, on_chain)?;
+        }
+        self.storage_as_mut::<MetadataTable<Description>>().insert(
+            &(),
+            &DatabaseMetadata::V1 {
+                version: expected,
+                height: *metadata.height(),
+            },
+        )?;
+
+        Ok(())
+    }
+}
+
+impl Database<OnChain> {
+    /// Migrates the layout of the database created by the previous version of the node
+    /// to the current version.
+    pub fn migrate(&mut self) -> StorageResult<()> {
+        let on_chain = self.clone();
+        self.apply_migrations(migration_from, &on_chain)
+    }
+}
+
+impl Database<OffChain> {
+    /// Migrates the layout of the database created by the previous version of the node
+    /// to the current version. The `on_chain` database must be migrated first.
+    pub fn migrate(&mut self, on_chain: &Database<OnChain>) -> StorageResult<()> {
+        self.apply_migrations(off_chain_migration_from, on_chain)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        database::coin::{
+            OwnedCoins,
+            OwnerIds,
+        },
+        fuel_core_graphql_api::storage::transactions::{
+            TransactionPointers,
+            TransactionStatuses,
+        },
+    };
+    use fuel_core_storage::{
+        codec::{
+            postcard::Postcard,
+            primitive::utxo_id_to_bytes,
+            Encode,
+        },
+        kv_store::KeyValueStore,
+        structured_storage::TableWithBlueprint,
+        tables::{
+            Coins,
+            FuelBlocks,
+        },
+    };
+    use fuel_core_types::{
+        blockchain::{
+            block::CompressedBlock,
+            header::BlockHeader,
+        },
+        entities::coins::coin::{
+            CompressedCoin,
+            CompressedCoinV1,
+        },
+        fuel_tx::{
+            Address,
+            Bytes32,
+            TxPointer,
+            UtxoId,
+        },
+        fuel_types::BlockHeight,
+        services::txpool::TransactionStatus,
+    };
+    use std::sync::Arc;
+
+    fn legacy_database(coins: &[(UtxoId, Address)]) -> Database<OnChain> {
+        let mut database = Database::<OnChain>::default();
+        for (utxo_id, owner) in coins {
+            let coin: CompressedCoin = CompressedCoinV1 {
+                owner: *owner,
+                ..Default::default()
+            }
+            .into();
+            database
+                .data
+                .storage_as_mut::<Coins>()
+                .insert(utxo_id, &coin)
+                .unwrap();
+
+            let mut legacy_key = owner.to_vec();
+            legacy_key.extend(utxo_id_to_bytes(utxo_id));
+            database
+                .data
+                .as_ref()
+                .put(&legacy_key, OwnedCoins::column(), Arc::new(vec![]))
+                .unwrap();
+        }
+        database
+            .storage_as_mut::<MetadataTable<OnChain>>()
+            .insert(
+                &(),
+                &DatabaseMetadata::V1 {
+                    version: 0,
+                    height: 10u32.into(),
+                },
+            )
+            .unwrap();
+        database
+    }
+
+    #[test]
+    fn migrate_reencodes_owned_coins() {
+        let owner = Address::new([1; 32]);
+        let other_owner = Address::new([2; 32]);
+        let coins = [
+            (UtxoId::new([1; 32].into(), 0), owner),
+            (UtxoId::new([2; 32].into(), 1), owner),
+            (UtxoId::new([3; 32].into(), 0), other_owner),
+        ];
+        let mut database = legacy_database(&coins);
+
+        database.migrate().unwrap();
+
+        let owned: Vec<_> = database
+            .owned_coins_ids(&owner, None, None)
+            .collect::<StorageResult<_>>()
+            .unwrap();
+        assert_eq!(owned, vec![coins[0].0, coins[1].0]);
+        let other_owned: Vec<_> = database
+            .owned_coins_ids(&other_owner, None, None)
+            .collect::<StorageResult<_>>()
+            .unwrap();
+        assert_eq!(other_owned, vec![coins[2].0]);
+        assert!(database
+            .iter_all::<OwnedCoins>(None)
+            .all(|result| result.is_ok()));
+        assert!(database.storage::<OwnerIds>().contains_key(&owner).unwrap());
+
+        let metadata = database
+            .storage::<MetadataTable<OnChain>>()
+            .get(&())
+            .unwrap()
+            .unwrap();
+        assert_eq!(metadata.version(), OnChain::version());
+        assert_eq!(*metadata.height(), 10u32.into());
+        database.init(&10u32.into()).unwrap();
+    }
+
+    #[test]
+    fn migrate_rebuilds_coin_balances() {
+        let owner = Address::new([1; 32]);
+        let coins = [
+            (UtxoId::new([1; 32].into(), 0), owner),
+            (UtxoId::new([2; 32].into(), 1), owner),
+        ];
+        let mut database = legacy_database(&coins);
+        for (utxo_id, _) in coins.iter() {
+            let coin: CompressedCoin = CompressedCoinV1 {
+                owner,
+                amount: 10,
+                ..Default::default()
+            }
+            .into();
+            database
+                .data
+                .storage_as_mut::<Coins>()
+                .insert(utxo_id, &coin)
+                .unwrap();
+        }
+
+        database.migrate().unwrap();
+
+        assert_eq!(
+            database.coin_balance(&owner, &Default::default()).unwrap(),
+            20
+        );
+    }
+
+    #[test]
+    fn migrate_reencodes_tx_statuses() {
+        let height: BlockHeight = 10u32.into();
+        let tx_ids = vec![Bytes32::new([1; 32]), Bytes32::new([2; 32])];
+        let mut on_chain = Database::<OnChain>::default();
+        let mut header = BlockHeader::default();
+        header.set_block_height(height);
+        on_chain
+            .data
+            .storage_as_mut::<FuelBlocks>()
+            .insert(&height, &CompressedBlock::test(header, tx_ids.clone()))
+            .unwrap();
+
+        let mut off_chain = Database::<OffChain>::default();
+        let status = TransactionStatus::SqueezedOut {
+            reason: "reason".to_string(),
+        };
+        off_chain
+            .data
+            .as_ref()
+            .put(
+                tx_ids[1].as_ref(),
+                TransactionStatuses::column(),
+                Postcard::encode_as_value(&status),
+            )
+            .unwrap();
+        off_chain
+            .storage_as_mut::<MetadataTable<OffChain>>()
+            .insert(&(), &DatabaseMetadata::V1 { version: 0, height })
+            .unwrap();
+
+        off_chain.migrate(&on_chain).unwrap();
+
+        assert_eq!(off_chain.get_tx_status(&tx_ids[0]).unwrap(), None);
+        assert_eq!(off_chain.get_tx_status(&tx_ids[1]).unwrap(), Some(status));
+        assert_eq!(
+            off_chain
+                .storage::<TransactionPointers>()
+                .get(&tx_ids[1])
+                .unwrap()
+                .unwrap()
+                .into_owned(),
+            TxPointer::new(height, 1)
+        );
+        assert!(!off_chain
+            .data
+            .as_ref()
+            .exists(tx_ids[1].as_ref(), TransactionStatuses::column())
+            .unwrap());
+        off_chain.init(&height).unwrap();
+    }
+
+    #[test]
+    fn every_version_before_the_current_has_a_migration() {
+        for version in 0..OnChain::version() {
+            assert!(migration_from(version).is_some(), "{version}");
+        }
+        assert!(migration_from(OnChain::version()).is_none());
+        for version in 0..OffChain::version() {
+            assert!(off_chain_migration_from(version).is_some(), "{version}");
+        }
+        assert!(off_chain_migration_from(OffChain::version()).is_none());
+    }
+
+    #[test]
+    fn migrate_skips_uninitialized_database() {
+        let mut database = Database::<OnChain>::default();
+
+        database.migrate().unwrap();
+
+        assert!(!database
+            .storage::<MetadataTable<OnChain>>()
+            .contains_key(&())
+            .unwrap());
+    }
+}
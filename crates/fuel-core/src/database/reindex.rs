@@ -0,0 +1,631 @@
+//! Rebuilds derived indexes from the canonical data, so a corrupted or a newly
+//! added index doesn't require the resynchronization of the blockchain.
+
+use crate::{
+    combined_database::CombinedDatabase,
+    database::{
+        coin::{
+            coin_balance_key,
+            owner_coin_id_key,
+            CoinBalanceKey,
+            CoinBalances,
+            CoinBalancesDirty,
+            OwnedCoins,
+        },
+        database_description::{
+            off_chain::OffChain,
+            on_chain::OnChain,
+            DatabaseDescription,
+        },
+        message::{
+            OwnedMessageIds,
+            OwnedMessageKey,
+        },
+        transaction::DatabaseTransaction,
+        Database,
+    },
+    fuel_core_graphql_api::{
+        storage::transactions::OwnedTransactions,
+        worker_service::transaction_owners,
+    },
+};
+use fuel_core_storage::{
+    iter::IterDirection,
+    kv_store::KeyValueStore,
+    not_found,
+    structured_storage::TableWithBlueprint,
+    tables::{
+        Coins,
+        FuelBlocks,
+        Messages,
+        Transactions,
+    },
+    transactional::Transaction,
+    Result as StorageResult,
+    StorageAsMut,
+    StorageAsRef,
+};
+use std::{
+    collections::HashMap,
+    time::Duration,
+};
+
+/// The derived index rebuilt by [`CombinedDatabase::reindex`].
+///
+/// The node has no index of receipts by contract and no index of message statuses:
+/// receipts are stored by the transaction id from the execution results, which are
+/// not part of blocks, and the status of the message is read from the canonical
+/// `Messages` and `SpentMessages` tables. So neither of them can be rebuilt.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    enum_iterator::Sequence,
+    strum_macros::EnumString,
+    strum_macros::Display,
+    serde::Serialize,
+)]
+#[strum(serialize_all = "kebab-case")]
+#[serde(rename_all = "kebab-case")]
+pub enum Index {
+    /// The transactions of owners in the off-chain database, rebuilt from blocks.
+    OwnedTransactions,
+    /// The coins of owners, rebuilt from coins.
+    OwnedCoins,
+    /// The messages of recipients, rebuilt from messages.
+    OwnedMessages,
+    /// The total amount of coins per owner and asset, rebuilt from coins.
+    CoinBalances,
+}
+
+impl Index {
+    /// Returns all indexes in the order of their rebuild.
+    pub fn all() -> impl Iterator<Item = Self> {
+        enum_iterator::all::<Self>()
+    }
+}
+
+/// The configuration of the index rebuild.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReindexConfig {
+    /// The number of index entries written or removed by one database transaction.
+    pub batch_size: usize,
+    /// The pause after each committed batch, limiting the load on the database.
+    pub throttle: Duration,
+}
+
+impl Default for ReindexConfig {
+    fn default() -> Self {
+        Self {
+            batch_size: 10_000,
+            throttle: Duration::ZERO,
+        }
+    }
+}
+
+/// The progress of the index rebuild, reported after each committed batch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReindexProgress {
+    pub index: Index,
+    /// The number of processed source records: blocks, coins or messages.
+    pub processed: u64,
+    /// The total number of source records, if it is known in advance.
+    pub total: Option<u64>,
+}
+
+/// The report of the index rebuild.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct ReindexReport {
+    pub index: Index,
+    /// The number of processed source records: blocks, coins or messages.
+    pub processed: u64,
+    /// The number of entries removed from the index before the rebuild.
+    pub removed: u64,
+    /// The number of entries written to the index.
+    pub written: u64,
+}
+
+impl CombinedDatabase {
+    /// Removes all entries of the `index` and rebuilds it from the on-chain data.
+    /// Changes are committed in batches, so an interrupted rebuild leaves
+    /// the index incomplete until the rebuild is repeated. The coin balances
+    /// are marked dirty for the duration of the rebuild and are recomputed
+    /// on the start of the node if the rebuild is interrupted.
+    pub fn reindex<F>(
+        &self,
+        index: Index,
+        config: &ReindexConfig,
+        progress: F,
+    ) -> StorageResult<ReindexReport>
+    where
+        F: FnMut(&ReindexProgress),
+    {
+        match index {
+            Index::OwnedTransactions => {
+                let mut rebuild = Rebuild::new(index, self.off_chain(), config, progress);
+                rebuild.total = block_count(self.on_chain())?;
+                reindex_owned_transactions(self.on_chain(), rebuild)
+            }
+            Index::OwnedCoins => reindex_owned_coins(Rebuild::new(
+                index,
+                self.on_chain(),
+                config,
+                progress,
+            )),
+            Index::OwnedMessages => reindex_owned_messages(Rebuild::new(
+                index,
+                self.on_chain(),
+                config,
+                progress,
+            )),
+            Index::CoinBalances => reindex_coin_balances(Rebuild::new(
+                index,
+                self.on_chain(),
+                config,
+                progress,
+            )),
+        }
+    }
+}
+
+fn reindex_owned_transactions<F>(
+    on_chain: &Database<OnChain>,
+    mut rebuild: Rebuild<OffChain, F>,
+) -> StorageResult<ReindexReport>
+where
+    F: FnMut(&ReindexProgress),
+{
+    rebuild.clear(OwnedTransactions::column())?;
+    for result in on_chain.iter_all::<FuelBlocks>(Some(IterDirection::Forward)) {
+        let (height, block) = result?;
+        for (tx_idx, tx_id) in block.transactions().iter().enumerate() {
+            let tx_idx = u16::try_from(tx_idx).map_err(|_| {
+                anyhow::anyhow!(
+                    "The block {height} has more than `u16::MAX` transactions"
+                )
+            })?;
+            let tx = on_chain
+                .storage::<Transactions>()
+                .get(tx_id)?
+                .ok_or(not_found!(Transactions))?;
+            for owner in transaction_owners(&tx) {
+                rebuild.write(|db| {
+                    db.record_tx_id_owner(&owner, height, tx_idx, tx_id)?;
+                    Ok(())
+                })?;
+            }
+        }
+        rebuild.processed();
+    }
+    rebuild.finish()
+}
+
+fn reindex_owned_coins<F>(
+    mut rebuild: Rebuild<OnChain, F>,
+) -> StorageResult<ReindexReport>
+where
+    F: FnMut(&ReindexProgress),
+{
+    rebuild.clear(OwnedCoins::column())?;
+    let on_chain = rebuild.source;
+    for result in on_chain.iter_all::<Coins>(None) {
+        let (utxo_id, coin) = result?;
+        rebuild.processed();
+        rebuild.write(|db| {
+            let owner_id = db.owner_id_or_assign(coin.owner())?;
+            db.storage_as_mut::<OwnedCoins>()
+                .insert(&owner_coin_id_key(owner_id, &utxo_id), &())?;
+            Ok(())
+        })?;
+    }
+    rebuild.finish()
+}
+
+fn reindex_owned_messages<F>(
+    mut rebuild: Rebuild<OnChain, F>,
+) -> StorageResult<ReindexReport>
+where
+    F: FnMut(&ReindexProgress),
+{
+    rebuild.clear(OwnedMessageIds::column())?;
+    let on_chain = rebuild.source;
+    for result in on_chain.iter_all::<Messages>(None) {
+        let (nonce, message) = result?;
+        rebuild.processed();
+        rebuild.write(|db| {
+            db.storage_as_mut::<OwnedMessageIds>()
+                .insert(&OwnedMessageKey::new(message.recipient(), &nonce), &())?;
+            Ok(())
+        })?;
+    }
+    rebuild.finish()
+}
+
+fn reindex_coin_balances<F>(
+    mut rebuild: Rebuild<OnChain, F>,
+) -> StorageResult<ReindexReport>
+where
+    F: FnMut(&ReindexProgress),
+{
+    // The balances are computed from coins while the table is incomplete.
+    rebuild
+        .database()
+        .storage_as_mut::<CoinBalancesDirty>()
+        .insert(&(), &())?;
+    rebuild.commit()?;
+    rebuild.clear(CoinBalances::column())?;
+
+    let on_chain = rebuild.source;
+    let mut balances = HashMap::<CoinBalanceKey, u64>::new();
+    for result in on_chain.iter_all::<Coins>(None) {
+        let (_, coin) = result?;
+        let balance = balances
+            .entry(coin_balance_key(coin.owner(), coin.asset_id()))
+            .or_default();
+        // The balances stay dirty, so they are computed from coins.
+        *balance = balance.checked_add(*coin.amount()).ok_or_else(|| {
+            anyhow::anyhow!(
+                "The balance of the asset {} of the owner {} overflows `u64`",
+                coin.asset_id(),
+                coin.owner()
+            )
+        })?;
+        rebuild.processed();
+    }
+    for (key, balance) in balances {
+        rebuild.write(|db| {
+            db.storage_as_mut::<CoinBalances>().insert(&key, &balance)?;
+            Ok(())
+        })?;
+    }
+
+    rebuild
+        .database()
+        .storage_as_mut::<CoinBalancesDirty>()
+        .remove(&())?;
+    rebuild.finish()
+}
+
+/// Returns the number of blocks between the first and the last stored blocks.
+fn block_count(on_chain: &Database<OnChain>) -> StorageResult<Option<u64>> {
+    let first = on_chain
+        .iter_all::<FuelBlocks>(Some(IterDirection::Forward))
+        .next()
+        .transpose()?;
+    let last = on_chain
+        .iter_all::<FuelBlocks>(Some(IterDirection::Reverse))
+        .next()
+        .transpose()?;
+    Ok(first.zip(last).map(|((first, _), (last, _))| {
+        u64::from(last.saturating_sub(*first)).saturating_add(1)
+    }))
+}
+
+/// Writes the rebuilt index in batches of database transactions.
+struct Rebuild<'a, Description, F>
+where
+    Description: DatabaseDescription,
+{
+    source: &'a Database<Description>,
+    transaction: DatabaseTransaction<Description>,
+    config: &'a ReindexConfig,
+    progress: F,
+    pending: usize,
+    total: Option<u64>,
+    report: ReindexReport,
+}
+
+impl<'a, Description, F> Rebuild<'a, Description, F>
+where
+    Description: DatabaseDescription,
+    F: FnMut(&ReindexProgress),
+{
+    fn new(
+        index: Index,
+        source: &'a Database<Description>,
+        config: &'a ReindexConfig,
+        progress: F,
+    ) -> Self {
+        Self {
+            source,
+            transaction: source.transaction(),
+            config,
+            progress,
+            pending: 0,
+            total: None,
+            report: ReindexReport {
+                index,
+                processed: 0,
+                removed: 0,
+                written: 0,
+            },
+        }
+    }
+
+    /// Returns the database of the current batch.
+    fn database(&mut self) -> &mut Database<Description> {
+        self.transaction.as_mut()
+    }
+
+    /// Removes all entries of the `column` in batches.
+    fn clear(&mut self, column: Description::Column) -> StorageResult<()> {
+        let keys = self.source.data.as_ref().iter_all(
+            column,
+            None,
+            None,
+            IterDirection::Forward,
+        );
+        for result in keys {
+            let (key, _) = result?;
+            self.transaction
+                .as_mut()
+                .data
+                .as_ref()
+                .delete(&key, column)?;
+            self.report.removed = self.report.removed.saturating_add(1);
+            self.entry()?;
+        }
+        self.commit()
+    }
+
+    /// Writes one entry of the index.
+    fn write<W>(&mut self, write: W) -> StorageResult<()>
+    where
+        W: FnOnce(&mut Database<Description>) -> StorageResult<()>,
+    {
+        write(self.database())?;
+        self.report.written = self.report.written.saturating_add(1);
+        self.entry()
+    }
+
+    fn processed(&mut self) {
+        self.report.processed = self.report.processed.saturating_add(1);
+    }
+
+    /// Commits the batch once it is full.
+    fn entry(&mut self) -> StorageResult<()> {
+        self.pending = self.pending.saturating_add(1);
+        if self.pending >= self.config.batch_size {
+            self.commit()?;
+        }
+        Ok(())
+    }
+
+    fn commit(&mut self) -> StorageResult<()> {
+        self.transaction.commit()?;
+        self.transaction = self.source.transaction();
+        self.pending = 0;
+        (self.progress)(&ReindexProgress {
+            index: self.report.index,
+            processed: self.report.processed,
+            total: self.total,
+        });
+        if !self.config.throttle.is_zero() {
+            std::thread::sleep(self.config.throttle);
+        }
+        Ok(())
+    }
+
+    fn finish(mut self) -> StorageResult<ReindexReport> {
+        self.commit()?;
+        Ok(self.report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fuel_core_graphql_api::storage::transactions::OwnedTransactionIndexCursor;
+    use fuel_core_types::{
+        blockchain::{
+            block::Block,
+            header::PartialBlockHeader,
+        },
+        entities::{
+            coins::coin::{
+                CompressedCoin,
+                CompressedCoinV1,
+            },
+            message::{
+                Message,
+                MessageV1,
+            },
+        },
+        fuel_tx::{
+            Address,
+            AssetId,
+            Output,
+            TransactionBuilder,
+            UniqueIdentifier,
+            UtxoId,
+        },
+        fuel_types::{
+            ChainId,
+            Nonce,
+        },
+    };
+
+    fn coin(owner: Address, amount: u64) -> CompressedCoin {
+        CompressedCoinV1 {
+            owner,
+            amount,
+            asset_id: AssetId::BASE,
+            ..Default::default()
+        }
+        .into()
+    }
+
+    #[test]
+    fn reindex_rebuilds_owned_transactions_from_blocks() {
+        let mut database = CombinedDatabase::in_memory();
+        let owner = Address::from([1; 32]);
+        let stale_owner = Address::from([2; 32]);
+        let chain_id = ChainId::default();
+        let tx = TransactionBuilder::script(vec![], vec![])
+            .add_output(Output::coin(owner, 10, AssetId::BASE))
+            .finalize_as_transaction();
+        let tx_id = tx.id(&chain_id);
+        let mut header = PartialBlockHeader::default();
+        header.consensus.height = 1.into();
+        let block = Block::new(header, vec![tx.clone()], &[]).compress(&chain_id);
+        let on_chain = database.on_chain_mut();
+        on_chain
+            .storage_as_mut::<FuelBlocks>()
+            .insert(&1.into(), &block)
+            .unwrap();
+        on_chain
+            .storage_as_mut::<Transactions>()
+            .insert(&tx_id, &tx)
+            .unwrap();
+        let mut off_chain = database.off_chain().clone();
+        off_chain
+            .record_tx_id_owner(&stale_owner, 1.into(), 0, &tx_id)
+            .unwrap();
+
+        let report = database
+            .reindex(Index::OwnedTransactions, &Default::default(), |_| {})
+            .unwrap();
+
+        assert_eq!(
+            report,
+            ReindexReport {
+                index: Index::OwnedTransactions,
+                processed: 1,
+                removed: 1,
+                written: 1,
+            }
+        );
+        let owned = |owner| {
+            database
+                .off_chain()
+                .owned_transactions(owner, None::<OwnedTransactionIndexCursor>, None)
+                .map(|result| result.unwrap().1)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(owned(owner), vec![tx_id]);
+        assert_eq!(owned(stale_owner), vec![]);
+    }
+
+    #[test]
+    fn reindex_rebuilds_coin_balances_from_coins() {
+        let mut database = CombinedDatabase::in_memory();
+        let owner = Address::from([1; 32]);
+        let on_chain = database.on_chain_mut();
+        for (output_index, amount) in [(0, 10), (1, 20)] {
+            on_chain
+                .storage_as_mut::<Coins>()
+                .insert(
+                    &UtxoId::new([1; 32].into(), output_index),
+                    &coin(owner, amount),
+                )
+                .unwrap();
+        }
+        let key = coin_balance_key(&owner, &AssetId::BASE);
+        on_chain
+            .storage_as_mut::<CoinBalances>()
+            .insert(&key, &1)
+            .unwrap();
+
+        let report = database
+            .reindex(Index::CoinBalances, &Default::default(), |_| {})
+            .unwrap();
+
+        assert_eq!(report.processed, 2);
+        assert_eq!(report.written, 1);
+        let balance = database
+            .on_chain()
+            .storage::<CoinBalances>()
+            .get(&key)
+            .unwrap()
+            .map(|balance| *balance);
+        assert_eq!(balance, Some(30));
+        assert!(!database.on_chain().coin_balances_dirty().unwrap());
+    }
+
+    #[test]
+    fn reindex_fails_on_overflow_of_coin_balance() {
+        let mut database = CombinedDatabase::in_memory();
+        let owner = Address::from([1; 32]);
+        let on_chain = database.on_chain_mut();
+        for output_index in 0..2 {
+            on_chain
+                .storage_as_mut::<Coins>()
+                .insert(
+                    &UtxoId::new([1; 32].into(), output_index),
+                    &coin(owner, u64::MAX),
+                )
+                .unwrap();
+        }
+
+        let result = database.reindex(Index::CoinBalances, &Default::default(), |_| {});
+
+        assert!(result.is_err());
+        assert!(database.on_chain().coin_balances_dirty().unwrap());
+    }
+
+    #[test]
+    fn reindex_rebuilds_owned_messages_from_messages() {
+        let mut database = CombinedDatabase::in_memory();
+        let recipient = Address::from([1; 32]);
+        let nonce = Nonce::from([1; 32]);
+        let message: Message = MessageV1 {
+            recipient,
+            nonce,
+            ..Default::default()
+        }
+        .into();
+        let on_chain = database.on_chain_mut();
+        on_chain
+            .storage_as_mut::<Messages>()
+            .insert(&nonce, &message)
+            .unwrap();
+        on_chain
+            .storage_as_mut::<OwnedMessageIds>()
+            .remove(&OwnedMessageKey::new(&recipient, &nonce))
+            .unwrap();
+
+        let report = database
+            .reindex(Index::OwnedMessages, &Default::default(), |_| {})
+            .unwrap();
+
+        assert_eq!(report.written, 1);
+        assert!(database
+            .on_chain()
+            .storage::<OwnedMessageIds>()
+            .contains_key(&OwnedMessageKey::new(&recipient, &nonce))
+            .unwrap());
+    }
+
+    #[test]
+    fn reindex_reports_progress_after_each_batch() {
+        let mut database = CombinedDatabase::in_memory();
+        let on_chain = database.on_chain_mut();
+        for i in 0..3u8 {
+            on_chain
+                .storage_as_mut::<Coins>()
+                .insert(&UtxoId::new([1; 32].into(), i), &coin([i; 32].into(), 1))
+                .unwrap();
+        }
+        let config = ReindexConfig {
+            batch_size: 2,
+            throttle: Duration::ZERO,
+        };
+
+        let mut progress = vec![];
+        database
+            .reindex(Index::OwnedCoins, &config, |p| progress.push(p.processed))
+            .unwrap();
+
+        // The removal of 3 stale entries is committed in 2 batches,
+        // the rebuild of 3 entries is committed in 2 batches.
+        assert_eq!(progress, vec![0, 0, 2, 3]);
+        let owned_coins = database
+            .on_chain()
+            .owned_coins_ids(&[2; 32].into(), None, None)
+            .count();
+        assert_eq!(owned_coins, 1);
+    }
+}
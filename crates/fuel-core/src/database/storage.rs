@@ -1,15 +1,23 @@
 use crate::{
     database::{
         block::FuelBlockSecondaryKeyBlockHeights,
-        coin::OwnedCoins,
+        coin::{
+            CoinBalances,
+            CoinBalancesDirty,
+            LastOwnerId,
+            OwnedCoins,
+            OwnerIds,
+        },
         database_description::DatabaseDescription,
         message::OwnedMessageIds,
         Database,
     },
     fuel_core_graphql_api::storage::{
+        contracts::ContractStateChanges,
         receipts::Receipts,
         transactions::{
             OwnedTransactions,
+            TransactionPointers,
             TransactionStatuses,
         },
     },
@@ -90,9 +98,15 @@ use_structured_implementation!(
     ContractsAssetsMerkleMetadata,
     ContractsAssetsMerkleData,
     OwnedCoins,
+    OwnerIds,
+    LastOwnerId,
+    CoinBalances,
+    CoinBalancesDirty,
     OwnedMessageIds,
     OwnedTransactions,
     TransactionStatuses,
+    TransactionPointers,
+    ContractStateChanges,
     FuelBlockSecondaryKeyBlockHeights,
     FuelBlockMerkleData,
     FuelBlockMerkleMetadata
@@ -100,7 +114,8 @@ use_structured_implementation!(
 #[cfg(feature = "relayer")]
 use_structured_implementation!(
     fuel_core_relayer::storage::DaHeightTable,
-    fuel_core_relayer::storage::EventsHistory
+    fuel_core_relayer::storage::EventsHistory,
+    fuel_core_relayer::storage::SyncCheckpoints
 );
 
 impl<Description, M> StorageInspect<M> for Database<Description>
@@ -1,19 +1,30 @@
 use crate::{
     database::{
-        database_description::off_chain::OffChain,
+        database_description::{
+            off_chain::OffChain,
+            on_chain::OnChain,
+        },
+        migration::migrate_in_batches,
         Database,
     },
     fuel_core_graphql_api::storage::transactions::{
+        tx_status_key,
         OwnedTransactionIndexCursor,
         OwnedTransactionIndexKey,
         OwnedTransactions,
         TransactionIndex,
+        TransactionPointers,
         TransactionStatuses,
     },
 };
 use fuel_core_storage::{
     iter::IterDirection,
-    tables::Transactions,
+    kv_store::KeyValueStore,
+    structured_storage::TableWithBlueprint,
+    tables::{
+        FuelBlocks,
+        Transactions,
+    },
     Result as StorageResult,
 };
 use fuel_core_types::{
@@ -29,6 +40,7 @@ use fuel_core_types::{
     },
     services::txpool::TransactionStatus,
 };
+use itertools::Itertools;
 
 impl Database {
     pub fn all_transactions(
@@ -79,13 +91,18 @@ impl Database<OffChain> {
         )
     }
 
+    /// Stores the `status` of the transaction with the `id` at the `tx_pointer`.
     pub fn update_tx_status(
         &mut self,
         id: &Bytes32,
+        tx_pointer: TxPointer,
         status: TransactionStatus,
     ) -> StorageResult<Option<TransactionStatus>> {
         use fuel_core_storage::StorageAsMut;
-        self.storage::<TransactionStatuses>().insert(id, &status)
+        self.storage::<TransactionPointers>()
+            .insert(id, &tx_pointer)?;
+        self.storage::<TransactionStatuses>()
+            .insert(&tx_status_key(&tx_pointer), &status)
     }
 
     pub fn get_tx_status(
@@ -93,8 +110,52 @@ impl Database<OffChain> {
         id: &Bytes32,
     ) -> StorageResult<Option<TransactionStatus>> {
         use fuel_core_storage::StorageAsRef;
+        let Some(tx_pointer) = self.storage::<TransactionPointers>().get(id)? else {
+            return Ok(None)
+        };
         self.storage::<TransactionStatuses>()
-            .get(id)
+            .get(&tx_status_key(&tx_pointer))
             .map(|v| v.map(|v| v.into_owned()))
     }
+
+    /// Moves statuses of transactions from the legacy transaction id keys to
+    /// the [`TransactionStatuses`] keys and indexes [`TransactionPointers`].
+    /// Positions of transactions are taken from blocks of the `on_chain` database.
+    pub(crate) fn reencode_tx_statuses(
+        &mut self,
+        on_chain: &Database<OnChain>,
+    ) -> StorageResult<()> {
+        use fuel_core_storage::StorageAsMut;
+
+        let column = TransactionStatuses::column();
+        let transactions = on_chain
+            .iter_all::<FuelBlocks>(None)
+            .map_ok(|(block_height, block)| {
+                let (_, tx_ids) = block.into_inner();
+                tx_ids
+                    .into_iter()
+                    .enumerate()
+                    .map(move |(tx_idx, tx_id)| (block_height, tx_idx, tx_id))
+            })
+            .flatten_ok();
+        migrate_in_batches(self, transactions, |database, entry| {
+            let (block_height, tx_idx, tx_id) = entry;
+            let Some(status) = database.data.as_ref().get(tx_id.as_ref(), column)? else {
+                return Ok(())
+            };
+            let tx_idx = u16::try_from(tx_idx).map_err(|e| {
+                anyhow::anyhow!("The block has more than `u16::MAX` transactions, {}", e)
+            })?;
+            let tx_pointer = TxPointer::new(block_height, tx_idx);
+            database
+                .data
+                .as_ref()
+                .put(&tx_status_key(&tx_pointer), column, status)?;
+            database.data.as_ref().delete(tx_id.as_ref(), column)?;
+            database
+                .storage_as_mut::<TransactionPointers>()
+                .insert(&tx_id, &tx_pointer)?;
+            Ok(())
+        })
+    }
 }
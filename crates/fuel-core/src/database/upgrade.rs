@@ -0,0 +1,413 @@
+//! Checks whether databases of the data directory can be opened by the current version
+//! of the node, so operators can find out before an in-place upgrade whether it requires
+//! migrations or is impossible.
+
+use crate::database::{
+    database_description::DatabaseDescription,
+    metadata::MetadataTable,
+    migration::{
+        migration_from,
+        off_chain_migration_from,
+        Migration,
+    },
+    Database,
+};
+use fuel_core_storage::{
+    Error as StorageError,
+    Result as StorageResult,
+    StorageAsRef,
+    StorageInspect,
+};
+use std::collections::BTreeMap;
+
+#[cfg(feature = "rocksdb")]
+use crate::state::rocks_db::RocksDb;
+
+/// The rough number of entries processed by a migration per second.
+/// It is used only to estimate the duration of migrations.
+pub const MIGRATED_ENTRIES_PER_SECOND: u64 = 50_000;
+
+/// The result of the check, ordered from the best to the worst.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum UpgradeStatus {
+    /// The node opens the database directly.
+    Compatible,
+    /// The node migrates the database during the start.
+    NeedsMigrations,
+    /// The node can't open the database.
+    Incompatible,
+}
+
+/// The migration that the node applies to the database during the start.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct PlannedMigration {
+    pub from_version: u32,
+    pub description: String,
+    /// The estimated number of entries processed by the migration.
+    pub estimated_entries: u64,
+    pub estimated_duration_secs: u64,
+}
+
+/// The compatibility of one database with the current version of the node.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct DatabaseCompatibility {
+    pub status: UpgradeStatus,
+    /// The version of the database. It is `None` if the database is not initialized.
+    pub found_version: Option<u32>,
+    pub expected_version: u32,
+    pub migrations: Vec<PlannedMigration>,
+    /// Explains why the database is incompatible.
+    pub reason: Option<String>,
+}
+
+impl DatabaseCompatibility {
+    fn compatible(found_version: Option<u32>, expected_version: u32) -> Self {
+        Self {
+            status: UpgradeStatus::Compatible,
+            found_version,
+            expected_version,
+            migrations: vec![],
+            reason: None,
+        }
+    }
+
+    fn incompatible(
+        found_version: Option<u32>,
+        expected_version: u32,
+        reason: String,
+    ) -> Self {
+        Self {
+            status: UpgradeStatus::Incompatible,
+            found_version,
+            expected_version,
+            migrations: vec![],
+            reason: Some(reason),
+        }
+    }
+
+    /// Returns the estimated duration of all migrations of the database.
+    pub fn estimated_duration_secs(&self) -> u64 {
+        self.migrations.iter().fold(0u64, |sum, migration| {
+            sum.saturating_add(migration.estimated_duration_secs)
+        })
+    }
+}
+
+/// The compatibility of all databases of the data directory.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct UpgradeReport {
+    /// The worst status among databases.
+    pub status: UpgradeStatus,
+    pub estimated_duration_secs: u64,
+    pub databases: BTreeMap<String, DatabaseCompatibility>,
+}
+
+impl UpgradeReport {
+    pub fn new(databases: BTreeMap<String, DatabaseCompatibility>) -> Self {
+        let status = databases
+            .values()
+            .map(|database| database.status)
+            .max()
+            .unwrap_or(UpgradeStatus::Compatible);
+        let estimated_duration_secs = databases.values().fold(0u64, |sum, database| {
+            sum.saturating_add(database.estimated_duration_secs())
+        });
+        Self {
+            status,
+            estimated_duration_secs,
+            databases,
+        }
+    }
+}
+
+/// Checks the version of the `database` against the version expected by the node.
+/// The `migrations` are available to upgrade the database, and the number of entries
+/// processed by each of them is estimated by the `estimated_entries`.
+pub fn check_database<Description>(
+    database: &Database<Description>,
+    migrations: impl Fn(u32) -> Option<&'static Migration<Description>>,
+    estimated_entries: impl Fn(&Migration<Description>) -> StorageResult<u64>,
+) -> StorageResult<DatabaseCompatibility>
+where
+    Description: DatabaseDescription,
+    Database<Description>:
+        StorageInspect<MetadataTable<Description>, Error = StorageError>,
+{
+    let expected = Description::version();
+    let Some(metadata) = database.storage::<MetadataTable<Description>>().get(&())?
+    else {
+        // The node initializes the empty database during the start.
+        return Ok(DatabaseCompatibility::compatible(None, expected))
+    };
+    let found = metadata.version();
+
+    if found == expected {
+        return Ok(DatabaseCompatibility::compatible(Some(found), expected))
+    }
+    if found > expected {
+        return Ok(DatabaseCompatibility::incompatible(
+            Some(found),
+            expected,
+            format!(
+                "The {} database of version {found} is created by a newer version of the node",
+                Description::name()
+            ),
+        ))
+    }
+
+    let mut planned = vec![];
+    for version in found..expected {
+        let Some(migration) = migrations(version) else {
+            return Ok(DatabaseCompatibility::incompatible(
+                Some(found),
+                expected,
+                format!(
+                    "The {} database has no migration from version {version}",
+                    Description::name()
+                ),
+            ))
+        };
+        let estimated_entries = estimated_entries(migration)?;
+        planned.push(PlannedMigration {
+            from_version: version,
+            description: migration.description.to_string(),
+            estimated_entries,
+            estimated_duration_secs: estimated_entries
+                .checked_div(MIGRATED_ENTRIES_PER_SECOND)
+                .unwrap_or_default(),
+        });
+    }
+
+    Ok(DatabaseCompatibility {
+        status: UpgradeStatus::NeedsMigrations,
+        found_version: Some(found),
+        expected_version: expected,
+        migrations: planned,
+        reason: None,
+    })
+}
+
+/// Checks all databases of the data directory at the `path` without modifying them.
+/// The databases are opened in the read-only mode, so the check is possible
+/// while the node of the previous version is running.
+#[cfg(feature = "rocksdb")]
+pub fn check_upgrade(path: &std::path::Path) -> StorageResult<UpgradeReport> {
+    use crate::database::database_description::{
+        off_chain::OffChain,
+        on_chain::OnChain,
+        relayer::Relayer,
+    };
+
+    let mut databases = BTreeMap::new();
+    databases.insert(
+        OnChain::name().to_string(),
+        check_rocksdb::<OnChain>(path, |database, rocks_db, column_families| {
+            check_database(database, migration_from, |migration| {
+                estimated_keys(rocks_db, column_families, migration.scanned_column)
+            })
+        })?,
+    );
+    databases.insert(
+        OffChain::name().to_string(),
+        check_rocksdb::<OffChain>(path, |database, rocks_db, column_families| {
+            check_database(database, off_chain_migration_from, |migration| {
+                estimated_keys(rocks_db, column_families, migration.scanned_column)
+            })
+        })?,
+    );
+    databases.insert(
+        Relayer::name().to_string(),
+        check_rocksdb::<Relayer>(path, |database, _, _| {
+            check_database(database, |_| None, |_| Ok(0))
+        })?,
+    );
+    Ok(UpgradeReport::new(databases))
+}
+
+/// Returns the number of keys in the `column`, or zero if the database doesn't have it yet.
+#[cfg(feature = "rocksdb")]
+fn estimated_keys<Description>(
+    rocks_db: &RocksDb<Description>,
+    column_families: &[String],
+    column: Description::Column,
+) -> StorageResult<u64>
+where
+    Description: DatabaseDescription,
+{
+    let name = RocksDb::<Description>::column_family_name(column);
+    if !column_families.contains(&name) {
+        return Ok(0)
+    }
+    Ok(rocks_db.estimated_keys(column)?)
+}
+
+/// Opens the RocksDB database at the `path` in the read-only mode and checks it
+/// by the `check`. The database with column families unknown to the node
+/// is created by a newer version of the node and is incompatible.
+#[cfg(feature = "rocksdb")]
+fn check_rocksdb<Description>(
+    path: &std::path::Path,
+    check: impl FnOnce(
+        &Database<Description>,
+        &RocksDb<Description>,
+        &[String],
+    ) -> StorageResult<DatabaseCompatibility>,
+) -> StorageResult<DatabaseCompatibility>
+where
+    Description: DatabaseDescription,
+{
+    use std::sync::Arc;
+
+    let expected = Description::version();
+    if !path.join(Description::name()).exists() {
+        return Ok(DatabaseCompatibility::compatible(None, expected))
+    }
+
+    let column_families = RocksDb::<Description>::column_families(path)?;
+    let known = enum_iterator::all::<Description::Column>()
+        .map(|column| (RocksDb::<Description>::column_family_name(column), column))
+        .collect::<BTreeMap<_, _>>();
+    let unknown = column_families
+        .iter()
+        .filter(|name| *name != "default" && !known.contains_key(*name))
+        .cloned()
+        .collect::<Vec<_>>();
+    if !unknown.is_empty() {
+        return Ok(DatabaseCompatibility::incompatible(
+            None,
+            expected,
+            format!(
+                "The {} database has column families unknown to the node: {}",
+                Description::name(),
+                unknown.join(", ")
+            ),
+        ))
+    }
+
+    let columns = column_families
+        .iter()
+        .filter_map(|name| known.get(name).copied())
+        .collect();
+    let rocks_db = Arc::new(RocksDb::<Description>::open_read_only(path, columns, None)?);
+    let database = Database::new(rocks_db.clone());
+    check(&database, &rocks_db, &column_families)
+}
+
+#[allow(clippy::arithmetic_side_effects)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::database_description::{
+        on_chain::OnChain,
+        DatabaseMetadata,
+    };
+    use fuel_core_storage::{
+        column::Column,
+        StorageAsMut,
+    };
+
+    fn database_of_version(version: u32) -> Database<OnChain> {
+        let mut database = Database::<OnChain>::default();
+        database
+            .storage_as_mut::<MetadataTable<OnChain>>()
+            .insert(
+                &(),
+                &DatabaseMetadata::V1 {
+                    version,
+                    height: 10u32.into(),
+                },
+            )
+            .unwrap();
+        database
+    }
+
+    fn check(database: &Database<OnChain>) -> DatabaseCompatibility {
+        check_database(database, migration_from, |migration| {
+            assert_eq!(migration.scanned_column, Column::Coins);
+            Ok(MIGRATED_ENTRIES_PER_SECOND * 3)
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn uninitialized_database_is_compatible() {
+        let compatibility = check(&Database::<OnChain>::default());
+
+        assert_eq!(compatibility.status, UpgradeStatus::Compatible);
+        assert_eq!(compatibility.found_version, None);
+    }
+
+    #[test]
+    fn database_of_current_version_is_compatible() {
+        let compatibility = check(&database_of_version(OnChain::version()));
+
+        assert_eq!(compatibility.status, UpgradeStatus::Compatible);
+        assert!(compatibility.migrations.is_empty());
+    }
+
+    #[test]
+    fn database_of_old_version_needs_all_following_migrations() {
+        let compatibility = check(&database_of_version(0));
+
+        assert_eq!(compatibility.status, UpgradeStatus::NeedsMigrations);
+        let versions: Vec<_> = compatibility
+            .migrations
+            .iter()
+            .map(|migration| migration.from_version)
+            .collect();
+        assert_eq!(versions, (0..OnChain::version()).collect::<Vec<_>>());
+        assert_eq!(
+            compatibility.estimated_duration_secs(),
+            3 * u64::from(OnChain::version())
+        );
+    }
+
+    #[test]
+    fn database_of_newer_version_is_incompatible() {
+        let compatibility = check(&database_of_version(OnChain::version() + 1));
+
+        assert_eq!(compatibility.status, UpgradeStatus::Incompatible);
+        assert!(compatibility.reason.is_some());
+    }
+
+    #[test]
+    fn database_without_migrations_is_incompatible() {
+        let database = database_of_version(0);
+
+        let compatibility = check_database(&database, |_| None, |_| Ok(0)).unwrap();
+
+        assert_eq!(compatibility.status, UpgradeStatus::Incompatible);
+    }
+
+    #[test]
+    fn report_has_the_worst_status_of_databases() {
+        let databases = [
+            ("on_chain".to_string(), check(&database_of_version(0))),
+            (
+                "off_chain".to_string(),
+                check(&database_of_version(OnChain::version())),
+            ),
+        ]
+        .into_iter()
+        .collect();
+
+        let report = UpgradeReport::new(databases);
+
+        assert_eq!(report.status, UpgradeStatus::NeedsMigrations);
+        assert_eq!(
+            report.estimated_duration_secs,
+            3 * u64::from(OnChain::version())
+        );
+    }
+}
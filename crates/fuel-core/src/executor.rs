@@ -11,7 +11,10 @@ mod tests {
             Executor,
             OnceTransactionsSource,
         },
-        ports::RelayerPort,
+        ports::{
+            PredicateVerificationCache,
+            RelayerPort,
+        },
         refs::ContractRef,
         Config,
     };
@@ -19,6 +22,7 @@ mod tests {
         tables::{
             Coins,
             ContractsRawCode,
+            ContractsState,
             Messages,
         },
         transactional::AtomicView,
@@ -80,6 +84,7 @@ mod tests {
             Transaction,
             TransactionBuilder,
             TransactionFee,
+            TxId,
             TxParameters,
             TxPointer,
             UniqueIdentifier,
@@ -97,7 +102,12 @@ mod tests {
             Word,
         },
         fuel_vm::{
-            checked_transaction::CheckError,
+            checked_transaction::{
+                CheckError,
+                CheckPredicateParams,
+                Checked,
+                EstimatePredicates,
+            },
             interpreter::ExecutableTransaction,
             script_with_data_offset,
             util::test_helpers::TestBuilder as TxBuilder,
@@ -108,6 +118,7 @@ mod tests {
         services::{
             block_producer::Components,
             executor::{
+                DryRunBundleStep,
                 Error as ExecutorError,
                 ExecutionBlock,
                 ExecutionResult,
@@ -116,6 +127,7 @@ mod tests {
                 TransactionExecutionResult,
                 TransactionValidityError,
             },
+            port::PortResult,
             relayer::Event,
         },
         tai64::Tai64,
@@ -139,7 +151,7 @@ mod tests {
             false
         }
 
-        fn get_events(&self, _: &DaBlockHeight) -> anyhow::Result<Vec<Event>> {
+        fn get_events(&self, _: &DaBlockHeight) -> PortResult<Vec<Event>> {
             unimplemented!()
         }
     }
@@ -315,6 +327,41 @@ mod tests {
         assert!(skipped_transactions.is_empty());
     }
 
+    #[test]
+    fn executor_uses_consensus_parameters_of_block_height() {
+        // The upgrade at the height `2` makes the gas limit of test transactions too high.
+        let config = Config {
+            consensus_parameters_upgrades: vec![
+                fuel_core_chain_config::ConsensusParametersUpgrade {
+                    height: 2u32.into(),
+                    consensus_parameters: ConsensusParameters {
+                        tx_params: TxParameters {
+                            max_gas_per_tx: 1,
+                            ..TxParameters::DEFAULT
+                        },
+                        ..Default::default()
+                    },
+                },
+            ],
+            ..Default::default()
+        };
+        let execute = |height: u32| {
+            let producer = create_executor(Default::default(), config.clone());
+            let block = test_block(height.into(), 0u64.into(), 1);
+            producer
+                .execute_and_commit(
+                    ExecutionTypes::Production(block.into()),
+                    Default::default(),
+                )
+                .unwrap()
+                .skipped_transactions
+        };
+
+        assert!(execute(1).is_empty());
+        assert_eq!(execute(2).len(), 1);
+        assert_eq!(execute(3).len(), 1);
+    }
+
     // Ensure transaction commitment != default after execution
     #[test]
     fn executor_commits_transactions_to_block() {
@@ -1253,6 +1300,108 @@ mod tests {
         ));
     }
 
+    /// Verifies predicates on its own and records transactions looked up by the executor.
+    #[derive(Debug, Default)]
+    struct RecordingPredicateCache {
+        consensus_parameters: ConsensusParameters,
+        lookups: std::sync::Mutex<Vec<TxId>>,
+    }
+
+    impl PredicateVerificationCache for RecordingPredicateCache {
+        fn verified(
+            &self,
+            _: BlockHeight,
+            tx: &Checked<Transaction>,
+        ) -> Option<Checked<Transaction>> {
+            self.lookups.lock().unwrap().push(tx.id());
+            tx.clone()
+                .check_predicates(&CheckPredicateParams::from(&self.consensus_parameters))
+                .ok()
+        }
+    }
+
+    #[test]
+    fn executor_uses_predicates_verified_by_cache() {
+        let predicate: Vec<u8> = vec![op::ret(RegId::ONE)].into_iter().collect();
+        let owner = Input::predicate_owner(&predicate);
+        let utxo_id = UtxoId::new([1; 32].into(), 0);
+        let mut tx = TransactionBuilder::script(
+            vec![op::ret(RegId::ONE)].into_iter().collect(),
+            vec![],
+        )
+        .script_gas_limit(10000)
+        .add_input(Input::coin_predicate(
+            utxo_id,
+            owner,
+            100,
+            AssetId::BASE,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            predicate,
+            vec![],
+        ))
+        .add_output(Output::change(owner, 0, AssetId::BASE))
+        .finalize_as_transaction();
+        let cache = Arc::new(RecordingPredicateCache::default());
+        tx.estimate_predicates(&CheckPredicateParams::from(&cache.consensus_parameters))
+            .unwrap();
+
+        let mut coin = CompressedCoin::default();
+        coin.set_owner(owner);
+        coin.set_amount(100);
+        let mut db = Database::default();
+        db.storage::<Coins>().insert(&utxo_id, &coin).unwrap();
+        let executor = create_executor(
+            db,
+            Config {
+                utxo_validation_default: true,
+                predicate_cache: Some(cache.clone()),
+                ..Default::default()
+            },
+        );
+
+        // Predicates are verified by the cache when the utxo validation is enabled.
+        let mut block = PartialFuelBlock {
+            header: Default::default(),
+            transactions: vec![tx.clone()],
+        };
+        let ExecutionData {
+            skipped_transactions,
+            ..
+        } = executor
+            .execute_block(
+                ExecutionType::Production(PartialBlockComponent::from_partial_block(
+                    &mut block,
+                )),
+                ExecutionOptions {
+                    utxo_validation: true,
+                },
+            )
+            .unwrap();
+        assert!(skipped_transactions.is_empty());
+        let tx_id = tx.id(&ChainId::default());
+        assert!(cache.lookups.lock().unwrap().contains(&tx_id));
+
+        // The cache is not used when the utxo validation is disabled.
+        cache.lookups.lock().unwrap().clear();
+        let mut block = PartialFuelBlock {
+            header: Default::default(),
+            transactions: vec![tx],
+        };
+        executor
+            .execute_block(
+                ExecutionType::Production(PartialBlockComponent::from_partial_block(
+                    &mut block,
+                )),
+                ExecutionOptions {
+                    utxo_validation: false,
+                },
+            )
+            .unwrap();
+        assert!(cache.lookups.lock().unwrap().is_empty());
+    }
+
     #[test]
     fn skipped_tx_not_changed_spent_status() {
         // `tx2` has two inputs: one used by `tx1` and on random. So after the execution of `tx1`,
@@ -1774,6 +1923,88 @@ mod tests {
         );
     }
 
+    #[test]
+    fn state_changes_contain_final_values_of_written_slots() {
+        let mut rng = StdRng::seed_from_u64(2322u64);
+
+        // Create a contract that writes the same slot twice.
+        let (create, contract_id) = create_contract(
+            vec![
+                // Sets the state STATE[0x1; 32] = value of `RegId::ZERO`;
+                op::sww(0x1, 0x29, RegId::ZERO),
+                // Sets the state STATE[0x1; 32] = value of `RegId::PC`;
+                op::sww(0x1, 0x29, RegId::PC),
+                op::ret(1),
+            ]
+            .into_iter()
+            .collect::<Vec<u8>>(),
+            &mut rng,
+        );
+
+        let (script, _) = script_with_data_offset!(
+            data_offset,
+            vec![
+                op::movi(0x10, data_offset),
+                op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+                op::ret(RegId::ONE),
+            ],
+            TxParameters::DEFAULT.tx_offset()
+        );
+        let script_data = Call::new(contract_id, 0, 0).to_bytes();
+        let modify_state_tx = TxBuilder::new(2322)
+            .script_gas_limit(10000)
+            .start_script(script, script_data)
+            .contract_input(contract_id)
+            .fee_input()
+            .contract_output(&contract_id)
+            .build()
+            .transaction()
+            .clone();
+        let db = &mut Database::default();
+
+        let executor = create_executor(
+            db.clone(),
+            Config {
+                utxo_validation_default: false,
+                ..Default::default()
+            },
+        );
+
+        let block = PartialFuelBlock {
+            header: PartialBlockHeader {
+                consensus: ConsensusHeader {
+                    height: 1.into(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            transactions: vec![create.into(), modify_state_tx.into()],
+        };
+
+        let ExecutionResult {
+            tx_status,
+            state_changes,
+            ..
+        } = executor
+            .execute_and_commit(ExecutionBlock::Production(block), Default::default())
+            .unwrap();
+
+        assert!(matches!(
+            tx_status[1].result,
+            TransactionExecutionResult::Success { .. }
+        ));
+        assert_eq!(state_changes.len(), 1);
+        let change = state_changes[0];
+        assert_eq!(change.contract_id, contract_id);
+        let value = db
+            .storage::<ContractsState>()
+            .get(&(&contract_id, &change.key).into())
+            .unwrap()
+            .map(|value| *value);
+        assert_eq!(change.value, value);
+        assert_ne!(change.value, Some(Bytes32::zeroed()));
+    }
+
     #[test]
     fn contracts_balance_and_state_roots_in_inputs_updated() {
         // Values in inputs and outputs are random. If the execution of the transaction that
@@ -1949,6 +2180,67 @@ mod tests {
         );
     }
 
+    #[test]
+    fn dry_run_bundle_executes_transactions_on_the_same_fork() {
+        let mut rng = StdRng::seed_from_u64(2322u64);
+
+        let (create, contract_id) = create_contract(vec![], &mut rng);
+        let (_, unknown_contract_id) = create_contract(vec![1], &mut rng);
+        let call_contract = |contract_id: &ContractId| -> Transaction {
+            TxBuilder::new(2322)
+                .start_script(vec![op::ret(1)], vec![])
+                .contract_input(*contract_id)
+                .fee_input()
+                .contract_output(contract_id)
+                .build()
+                .transaction()
+                .clone()
+                .into()
+        };
+        // The first call uses the contract that doesn't exist,
+        // the second call uses the contract created by the first transaction.
+        let invalid = call_contract(&unknown_contract_id);
+        let dependent = call_contract(&contract_id);
+        let transactions: Vec<Transaction> =
+            vec![create.clone().into(), invalid.clone(), dependent.clone()];
+
+        let mut db = Database::default();
+        let executor = create_executor(db.clone(), Default::default());
+
+        let steps = executor
+            .dry_run_bundle(
+                Components {
+                    header_to_produce: Default::default(),
+                    transactions_source: transactions,
+                    gas_limit: u64::MAX,
+                },
+                None,
+            )
+            .unwrap();
+
+        let chain_id = ChainId::default();
+        assert_eq!(steps.len(), 3);
+        assert!(matches!(
+            &steps[0],
+            DryRunBundleStep::Executed(status) if status.id == create.id(&chain_id)
+        ));
+        assert!(matches!(
+            &steps[1],
+            DryRunBundleStep::Skipped { id, .. } if *id == invalid.id(&chain_id)
+        ));
+        assert!(matches!(
+            &steps[2],
+            DryRunBundleStep::Executed(status) if status.id == dependent.id(&chain_id)
+                && matches!(status.result, TransactionExecutionResult::Success { .. })
+        ));
+
+        // The dry run doesn't affect the state.
+        assert!(!db
+            .storage::<ContractsRawCode>()
+            .contains_key(&contract_id)
+            .unwrap());
+    }
+
     #[test]
     fn input_coins_are_marked_as_spent_with_utxo_validation_enabled() {
         // ensure coins are marked as spent after tx is processed
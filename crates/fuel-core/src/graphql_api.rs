@@ -1,3 +1,11 @@
+use crate::service::{
+    NodeIdentityConfig,
+    NodeMode,
+};
+use fuel_core_chain_config::{
+    consensus_parameters_at,
+    ConsensusParametersUpgrade,
+};
 use fuel_core_storage::{
     Error as StorageError,
     IsNotFound,
@@ -5,30 +13,72 @@ use fuel_core_storage::{
 use fuel_core_types::{
     blockchain::primitives::SecretKeyWrapper,
     fuel_tx::ConsensusParameters,
+    fuel_types::BlockHeight,
     secrecy::Secret,
 };
-use std::net::SocketAddr;
+use rate_limiter::RateLimitConfig;
+use std::{
+    collections::HashSet,
+    net::SocketAddr,
+    path::PathBuf,
+};
 
+pub mod activity;
 pub mod api_service;
 pub mod database;
 pub(crate) mod metrics_extension;
 pub mod ports;
+pub(crate) mod rate_limit_extension;
+pub mod rate_limiter;
 pub mod storage;
+pub(crate) mod subscription_fan_out;
 pub(crate) mod view_extension;
 pub mod worker_service;
 
 #[derive(Clone, Debug)]
 pub struct Config {
     pub addr: SocketAddr,
+    /// The mode of the node reported by the API.
+    pub node_mode: NodeMode,
+    /// The confirmation depth after which transactions are reported as finalized.
+    pub tx_finality_depth: Option<u32>,
     pub utxo_validation: bool,
     pub debug: bool,
+    /// The directory for dumps of the `TxPool`. Dumps are disabled if `None`.
+    pub tx_pool_dump_dir: Option<PathBuf>,
     pub vm_backtrace: bool,
-    pub min_gas_price: u64,
     pub max_tx: usize,
     pub max_depth: usize,
     pub chain_name: String,
     pub consensus_parameters: ConsensusParameters,
+    pub consensus_parameters_upgrades: Vec<ConsensusParametersUpgrade>,
     pub consensus_key: Option<Secret<SecretKeyWrapper>>,
+    /// The maximum depth of the GraphQL query.
+    pub max_queries_depth: usize,
+    /// The maximum complexity of the GraphQL query.
+    pub max_queries_complexity: usize,
+    /// The per-client rate limit of requests. `None` disables rate limiting.
+    pub rate_limit: Option<RateLimitConfig>,
+    /// The API keys identifying clients instead of their IP addresses.
+    pub api_keys: HashSet<String>,
+    /// The maximum number of operations in the batched request.
+    pub max_batch_size: usize,
+    /// The maximum number of active shared subscriptions of a single client.
+    pub max_subscriptions_per_client: usize,
+    /// The identity of the node, signed for the `chain_name` on each request.
+    /// The identity isn't served if `None`.
+    pub node_identity: Option<NodeIdentityConfig>,
+}
+
+impl Config {
+    /// Returns the consensus parameters in effect at the `height`.
+    pub fn consensus_parameters_at(&self, height: BlockHeight) -> &ConsensusParameters {
+        consensus_parameters_at(
+            &self.consensus_parameters,
+            &self.consensus_parameters_upgrades,
+            height,
+        )
+    }
 }
 
 pub trait IntoApiResult<T> {
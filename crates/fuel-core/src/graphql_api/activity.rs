@@ -0,0 +1,524 @@
+//! The activity of assets and contracts in imported blocks.
+//!
+//! The off-chain worker extracts the activity from each imported block and publishes it
+//! to subscribers of the GraphQL API. Subscribers filter it by the asset or the contract,
+//! so clients can monitor them without processing every block.
+
+use fuel_core_types::{
+    fuel_crypto::Hasher,
+    fuel_tx::{
+        field::Outputs,
+        AssetId,
+        Bytes32,
+        ContractId,
+        Output,
+        Receipt,
+        Transaction,
+        TxId,
+    },
+    fuel_types::{
+        Address,
+        BlockHeight,
+    },
+    services::{
+        block_importer::ImportResult,
+        executor::TransactionExecutionResult,
+    },
+};
+use futures::{
+    Stream,
+    StreamExt,
+};
+use std::{
+    collections::HashSet,
+    sync::Arc,
+};
+use tokio::sync::broadcast;
+use tokio_stream::wrappers::BroadcastStream;
+
+/// The number of blocks buffered for the slow subscriber. The subscriber that lags
+/// more than that is disconnected.
+const ACTIVITY_BUFFER: usize = 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AssetActivityKind {
+    /// The contract minted the asset.
+    Mint,
+    /// The contract burned the asset.
+    Burn,
+    /// The asset is transferred to the address or the contract.
+    Transfer,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Recipient {
+    Address(Address),
+    Contract(ContractId),
+}
+
+/// The change of the asset made by the transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssetActivity {
+    pub block_height: BlockHeight,
+    pub tx_id: TxId,
+    pub asset_id: AssetId,
+    pub kind: AssetActivityKind,
+    pub amount: u64,
+    /// The contract that minted, burned or transferred the asset.
+    /// It is `None` for coins created by outputs of the transaction.
+    pub contract_id: Option<ContractId>,
+    /// The recipient of the transfer.
+    pub recipient: Option<Recipient>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContractActivityKind {
+    /// The contract is called by the transaction.
+    Call {
+        tx_id: TxId,
+        /// The calling contract. It is `None` if the contract is called by the script.
+        caller: Option<ContractId>,
+    },
+    /// The block wrote the storage slot of the contract.
+    StateChange {
+        key: Bytes32,
+        /// The value after the block. `None` if the slot is removed.
+        value: Option<Bytes32>,
+    },
+}
+
+/// The call or the state change of the contract.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractActivity {
+    pub block_height: BlockHeight,
+    pub contract_id: ContractId,
+    pub kind: ContractActivityKind,
+}
+
+/// The activity of assets and contracts in one block.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BlockActivity {
+    pub assets: Vec<AssetActivity>,
+    pub contracts: Vec<ContractActivity>,
+}
+
+impl BlockActivity {
+    /// Extracts the activity from receipts, outputs and state changes of the imported block.
+    /// The receipts of failed transactions are reverted, and only their change outputs
+    /// are created, so the rest of their activity is skipped.
+    pub fn from_import_result(result: &ImportResult) -> Self {
+        let block = &result.sealed_block.entity;
+        let block_height = *block.header().height();
+        let mut activity = Self::default();
+
+        let mut failed = HashSet::new();
+        for status in result.tx_status.iter() {
+            if matches!(status.result, TransactionExecutionResult::Failed { .. }) {
+                failed.insert(status.id);
+                continue
+            }
+            for receipt in status.receipts.iter() {
+                activity.record_receipt(block_height, status.id, receipt);
+            }
+        }
+
+        for tx in block.transactions() {
+            let Some(tx_id) = tx.cached_id() else {
+                continue
+            };
+            let outputs = match tx {
+                Transaction::Script(tx) => tx.outputs().as_slice(),
+                Transaction::Create(tx) => tx.outputs().as_slice(),
+                Transaction::Mint(_) => continue,
+            };
+            let failed = failed.contains(&tx_id);
+            for output in outputs {
+                let transfer = match output {
+                    Output::Coin {
+                        to,
+                        amount,
+                        asset_id,
+                    }
+                    | Output::Variable {
+                        to,
+                        amount,
+                        asset_id,
+                    } if !failed => Some((to, amount, asset_id)),
+                    Output::Change {
+                        to,
+                        amount,
+                        asset_id,
+                    } => Some((to, amount, asset_id)),
+                    _ => None,
+                };
+                if let Some((to, amount, asset_id)) = transfer {
+                    if *amount > 0 {
+                        activity.assets.push(AssetActivity {
+                            block_height,
+                            tx_id,
+                            asset_id: *asset_id,
+                            kind: AssetActivityKind::Transfer,
+                            amount: *amount,
+                            contract_id: None,
+                            recipient: Some(Recipient::Address(*to)),
+                        });
+                    }
+                }
+            }
+        }
+
+        for change in result.state_changes.iter() {
+            activity.contracts.push(ContractActivity {
+                block_height,
+                contract_id: change.contract_id,
+                kind: ContractActivityKind::StateChange {
+                    key: change.key,
+                    value: change.value,
+                },
+            });
+        }
+
+        activity
+    }
+
+    fn record_receipt(
+        &mut self,
+        block_height: BlockHeight,
+        tx_id: TxId,
+        receipt: &Receipt,
+    ) {
+        let asset = |asset_id, kind, amount, contract_id, recipient| AssetActivity {
+            block_height,
+            tx_id,
+            asset_id,
+            kind,
+            amount,
+            contract_id: Some(contract_id),
+            recipient,
+        };
+        match receipt {
+            Receipt::Call { id, to, .. } => {
+                self.contracts.push(ContractActivity {
+                    block_height,
+                    contract_id: *to,
+                    kind: ContractActivityKind::Call {
+                        tx_id,
+                        caller: (*id != ContractId::zeroed()).then_some(*id),
+                    },
+                });
+            }
+            Receipt::Mint {
+                sub_id,
+                contract_id,
+                val,
+                ..
+            } => self.assets.push(asset(
+                minted_asset_id(contract_id, sub_id),
+                AssetActivityKind::Mint,
+                *val,
+                *contract_id,
+                None,
+            )),
+            Receipt::Burn {
+                sub_id,
+                contract_id,
+                val,
+                ..
+            } => self.assets.push(asset(
+                minted_asset_id(contract_id, sub_id),
+                AssetActivityKind::Burn,
+                *val,
+                *contract_id,
+                None,
+            )),
+            Receipt::Transfer {
+                id,
+                to,
+                amount,
+                asset_id,
+                ..
+            } => self.assets.push(asset(
+                *asset_id,
+                AssetActivityKind::Transfer,
+                *amount,
+                *id,
+                Some(Recipient::Contract(*to)),
+            )),
+            Receipt::TransferOut {
+                id,
+                to,
+                amount,
+                asset_id,
+                ..
+            } => self.assets.push(asset(
+                *asset_id,
+                AssetActivityKind::Transfer,
+                *amount,
+                *id,
+                Some(Recipient::Address(*to)),
+            )),
+            _ => {}
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.assets.is_empty() && self.contracts.is_empty()
+    }
+}
+
+/// The id of the asset minted by the contract with the sub id, as derived by the VM.
+fn minted_asset_id(contract_id: &ContractId, sub_id: &Bytes32) -> AssetId {
+    AssetId::new(
+        *Hasher::default()
+            .chain(contract_id.as_slice())
+            .chain(sub_id.as_slice())
+            .finalize(),
+    )
+}
+
+/// Publishes the activity of imported blocks to subscribers.
+#[derive(Clone)]
+pub struct ActivityBroadcast {
+    sender: broadcast::Sender<Arc<BlockActivity>>,
+}
+
+impl Default for ActivityBroadcast {
+    fn default() -> Self {
+        let (sender, _) = broadcast::channel(ACTIVITY_BUFFER);
+        Self { sender }
+    }
+}
+
+impl ActivityBroadcast {
+    /// Returns `true` if anyone is subscribed, so the activity is worth extracting.
+    pub fn has_subscribers(&self) -> bool {
+        self.sender.receiver_count() > 0
+    }
+
+    pub fn publish(&self, activity: BlockActivity) {
+        if !activity.is_empty() {
+            let _ = self.sender.send(Arc::new(activity));
+        }
+    }
+
+    /// Returns the stream of the activity of the asset in blocks imported
+    /// after the subscription. The stream ends if the subscriber lags behind.
+    pub fn subscribe_asset(
+        &self,
+        asset_id: AssetId,
+    ) -> impl Stream<Item = AssetActivity> + Send + 'static {
+        self.subscribe().flat_map(move |activity| {
+            let assets = activity
+                .assets
+                .iter()
+                .filter(|activity| activity.asset_id == asset_id)
+                .cloned()
+                .collect::<Vec<_>>();
+            futures::stream::iter(assets)
+        })
+    }
+
+    /// Returns the stream of calls and state changes of the contract in blocks imported
+    /// after the subscription. The stream ends if the subscriber lags behind.
+    pub fn subscribe_contract(
+        &self,
+        contract_id: ContractId,
+    ) -> impl Stream<Item = ContractActivity> + Send + 'static {
+        self.subscribe().flat_map(move |activity| {
+            let contracts = activity
+                .contracts
+                .iter()
+                .filter(|activity| activity.contract_id == contract_id)
+                .cloned()
+                .collect::<Vec<_>>();
+            futures::stream::iter(contracts)
+        })
+    }
+
+    fn subscribe(&self) -> impl Stream<Item = Arc<BlockActivity>> + Send + 'static {
+        BroadcastStream::new(self.sender.subscribe())
+            .take_while(|activity| futures::future::ready(activity.is_ok()))
+            .filter_map(|activity| futures::future::ready(activity.ok()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::{
+        blockchain::{
+            block::Block,
+            SealedBlock,
+        },
+        fuel_tx::{
+            Cacheable,
+            TransactionBuilder,
+            UniqueIdentifier,
+        },
+        fuel_types::ChainId,
+        services::executor::{
+            ContractStateChange,
+            TransactionExecutionStatus,
+        },
+    };
+
+    fn import_result(receipts: Vec<Receipt>) -> ImportResult {
+        let block = SealedBlock {
+            entity: Block::default(),
+            consensus: Default::default(),
+        };
+        ImportResult::new_from_local(
+            block,
+            vec![TransactionExecutionStatus {
+                id: [1; 32].into(),
+                result: TransactionExecutionResult::Success { result: None },
+                receipts,
+            }],
+        )
+    }
+
+    #[test]
+    fn failed_transaction_has_only_change_activity() {
+        let contract_id = ContractId::new([2; 32]);
+        let owner = Address::new([4; 32]);
+        let mut tx = TransactionBuilder::script(vec![], vec![])
+            .add_output(Output::coin(owner, 5, AssetId::BASE))
+            .add_output(Output::change(owner, 3, AssetId::BASE))
+            .finalize_as_transaction();
+        tx.precompute(&ChainId::default()).unwrap();
+        let tx_id = tx.id(&ChainId::default());
+        let mut block = Block::default();
+        block.transactions_mut().push(tx);
+        let result = ImportResult::new_from_local(
+            SealedBlock {
+                entity: block,
+                consensus: Default::default(),
+            },
+            vec![TransactionExecutionStatus {
+                id: tx_id,
+                result: TransactionExecutionResult::Failed {
+                    result: None,
+                    reason: "Revert".to_string(),
+                },
+                receipts: vec![
+                    Receipt::mint(Bytes32::zeroed(), contract_id, 10, 0, 0),
+                    Receipt::revert(contract_id, 0, 0, 0),
+                ],
+            }],
+        );
+
+        let activity = BlockActivity::from_import_result(&result);
+
+        assert_eq!(
+            activity.assets,
+            vec![AssetActivity {
+                block_height: Default::default(),
+                tx_id,
+                asset_id: AssetId::BASE,
+                kind: AssetActivityKind::Transfer,
+                amount: 3,
+                contract_id: None,
+                recipient: Some(Recipient::Address(owner)),
+            }]
+        );
+        assert!(activity.contracts.is_empty());
+    }
+
+    #[test]
+    fn mint_and_transfer_receipts_are_asset_activity() {
+        let contract_id = ContractId::new([2; 32]);
+        let sub_id = Bytes32::new([3; 32]);
+        let asset_id = minted_asset_id(&contract_id, &sub_id);
+        let receiver = Address::new([4; 32]);
+        let result = import_result(vec![
+            Receipt::mint(sub_id, contract_id, 10, 0, 0),
+            Receipt::transfer_out(contract_id, receiver, 7, asset_id, 0, 0),
+        ]);
+
+        let activity = BlockActivity::from_import_result(&result);
+
+        let kinds: Vec<_> = activity
+            .assets
+            .iter()
+            .map(|activity| (activity.asset_id, activity.kind, activity.amount))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                (asset_id, AssetActivityKind::Mint, 10),
+                (asset_id, AssetActivityKind::Transfer, 7),
+            ]
+        );
+        assert_eq!(
+            activity.assets[1].recipient,
+            Some(Recipient::Address(receiver))
+        );
+    }
+
+    #[test]
+    fn calls_and_state_changes_are_contract_activity() {
+        let contract_id = ContractId::new([2; 32]);
+        let mut result = import_result(vec![Receipt::call(
+            ContractId::zeroed(),
+            contract_id,
+            0,
+            AssetId::BASE,
+            0,
+            0,
+            0,
+            0,
+            0,
+        )]);
+        result.state_changes.push(ContractStateChange {
+            contract_id,
+            key: Bytes32::new([5; 32]),
+            value: None,
+        });
+
+        let activity = BlockActivity::from_import_result(&result);
+
+        assert_eq!(
+            activity
+                .contracts
+                .iter()
+                .map(|activity| activity.kind)
+                .collect::<Vec<_>>(),
+            vec![
+                ContractActivityKind::Call {
+                    tx_id: [1; 32].into(),
+                    caller: None,
+                },
+                ContractActivityKind::StateChange {
+                    key: Bytes32::new([5; 32]),
+                    value: None,
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn subscribers_receive_only_activity_of_their_asset() {
+        let broadcast = ActivityBroadcast::default();
+        let asset_id = AssetId::new([1; 32]);
+        let mut subscription = Box::pin(broadcast.subscribe_asset(asset_id));
+        let activity = |asset_id| AssetActivity {
+            block_height: 1u32.into(),
+            tx_id: Default::default(),
+            asset_id,
+            kind: AssetActivityKind::Transfer,
+            amount: 1,
+            contract_id: None,
+            recipient: None,
+        };
+        assert!(broadcast.has_subscribers());
+
+        broadcast.publish(BlockActivity {
+            assets: vec![activity(AssetId::new([2; 32])), activity(asset_id)],
+            contracts: vec![],
+        });
+        drop(broadcast);
+
+        assert_eq!(subscription.next().await, Some(activity(asset_id)));
+        assert_eq!(subscription.next().await, None);
+    }
+}
@@ -1,5 +1,6 @@
 use crate::{
     fuel_core_graphql_api::{
+        activity::ActivityBroadcast,
         metrics_extension::MetricsExtension,
         ports::{
             BlockProducerPort,
@@ -9,25 +10,51 @@ use crate::{
             P2pPort,
             TxPoolPort,
         },
+        rate_limit_extension::RateLimitExtension,
+        rate_limiter::{
+            ApiKeys,
+            RateLimiter,
+        },
+        subscription_fan_out::{
+            shareable_key,
+            SubscriptionFanOut,
+        },
         view_extension::ViewExtension,
         Config,
     },
     schema::{
         CoreSchema,
-        CoreSchemaBuilder,
+        Mutation,
+        NodeSchemaBuilder,
+        Query,
+        Subscription,
+    },
+    service::{
+        maintenance::MaintenanceStatus,
+        metrics::metrics,
+        NodeIdentityConfig,
     },
-    service::metrics::metrics,
 };
 use async_graphql::{
     http::{
         playground_source,
         GraphQLPlaygroundConfig,
     },
+    BatchRequest,
+    BatchResponse,
+    EmptyMutation,
+    EmptySubscription,
+    ObjectType,
     Request,
     Response,
+    Schema,
+    SchemaBuilder,
+    ServerError,
+    SubscriptionType,
 };
 use axum::{
     extract::{
+        ConnectInfo,
         DefaultBodyLimit,
         Extension,
     },
@@ -37,7 +64,9 @@ use axum::{
             ACCESS_CONTROL_ALLOW_METHODS,
             ACCESS_CONTROL_ALLOW_ORIGIN,
         },
+        HeaderMap,
         HeaderValue,
+        StatusCode,
     },
     response::{
         sse::Event,
@@ -53,13 +82,26 @@ use axum::{
     Router,
 };
 use fuel_core_services::{
+    HealthRegistry,
+    HealthReport,
     RunnableService,
     RunnableTask,
     StateWatcher,
 };
 use fuel_core_storage::transactional::AtomicView;
-use fuel_core_types::fuel_types::BlockHeight;
-use futures::Stream;
+use fuel_core_types::{
+    fuel_types::BlockHeight,
+    secrecy::ExposeSecret,
+    services::node_identity::{
+        NodeIdentity,
+        SignedNodeIdentity,
+        NODE_IDENTITY_PATH,
+    },
+};
+use futures::{
+    Stream,
+    StreamExt,
+};
 use serde_json::json;
 use std::{
     future::Future,
@@ -68,9 +110,11 @@ use std::{
         TcpListener,
     },
     pin::Pin,
-    time::Duration,
+    time::{
+        Duration,
+        UNIX_EPOCH,
+    },
 };
-use tokio_stream::StreamExt;
 use tower_http::{
     set_header::SetResponseHeaderLayer,
     timeout::TimeoutLayer,
@@ -131,7 +175,7 @@ impl RunnableService for GraphqlService {
 
         let server = axum::Server::from_tcp(listener)
             .unwrap()
-            .serve(router.into_make_service())
+            .serve(router.into_make_service_with_connect_info::<SocketAddr>())
             .with_graceful_shutdown(async move {
                 state
                     .while_started()
@@ -162,11 +206,51 @@ impl RunnableTask for Task {
     }
 }
 
+/// The data and the extensions shared by all kinds of the schema.
+struct SchemaData {
+    config: Config,
+    database: ReadDatabase,
+    txpool: TxPool,
+    producer: BlockProducer,
+    consensus_module: ConsensusModule,
+    p2p_service: P2pService,
+    activity: ActivityBroadcast,
+    log_threshold_ms: Duration,
+    rate_limiter: RateLimiter,
+    max_queries_depth: usize,
+    max_queries_complexity: usize,
+}
+
+impl SchemaData {
+    fn finish<Q, M, S>(self, schema: SchemaBuilder<Q, M, S>) -> Schema<Q, M, S>
+    where
+        Q: ObjectType + 'static,
+        M: ObjectType + 'static,
+        S: SubscriptionType + 'static,
+    {
+        schema
+            .data(self.config)
+            .data(self.database)
+            .data(self.txpool)
+            .data(self.producer)
+            .data(self.consensus_module)
+            .data(self.p2p_service)
+            .data(self.activity)
+            .extension(async_graphql::extensions::Tracing)
+            .extension(MetricsExtension::new(self.log_threshold_ms))
+            .extension(ViewExtension::new())
+            .extension(RateLimitExtension::new(self.rate_limiter))
+            .limit_depth(self.max_queries_depth)
+            .limit_complexity(self.max_queries_complexity)
+            .finish()
+    }
+}
+
 // Need a seperate Data Object for each Query endpoint, cannot be avoided
 #[allow(clippy::too_many_arguments)]
 pub fn new_service<OnChain, OffChain>(
     config: Config,
-    schema: CoreSchemaBuilder,
+    schema: NodeSchemaBuilder,
     on_database: OnChain,
     off_database: OffChain,
     txpool: TxPool,
@@ -175,6 +259,9 @@ pub fn new_service<OnChain, OffChain>(
     p2p_service: P2pService,
     log_threshold_ms: Duration,
     request_timeout: Duration,
+    health_registry: HealthRegistry,
+    maintenance: MaintenanceStatus,
+    activity: ActivityBroadcast,
 ) -> anyhow::Result<Service>
 where
     OnChain: AtomicView<Height = BlockHeight> + 'static,
@@ -183,30 +270,79 @@ where
     OffChain::View: OffChainDatabase,
 {
     let network_addr = config.addr;
+    let node_identity = config
+        .node_identity
+        .clone()
+        .map(|identity| NodeIdentitySigner {
+            identity,
+            network: config.chain_name.clone(),
+        });
+    let rate_limiter = RateLimiter::new(config.rate_limit);
+    let api_keys = ApiKeys::new(config.api_keys.clone());
+    let max_queries_depth = config.max_queries_depth;
+    let max_queries_complexity = config.max_queries_complexity;
+    let max_batch_size = MaxBatchSize(config.max_batch_size);
+    let max_subscriptions_per_client = config.max_subscriptions_per_client;
     let combined_read_database = ReadDatabase::new(on_database, off_database);
 
-    let schema = schema
-        .data(config)
-        .data(combined_read_database)
-        .data(txpool)
-        .data(producer)
-        .data(consensus_module)
-        .data(p2p_service)
-        .extension(async_graphql::extensions::Tracing)
-        .extension(MetricsExtension::new(log_threshold_ms))
-        .extension(ViewExtension::new())
-        .finish();
-
-    let router = Router::new()
+    let schema_data = SchemaData {
+        config,
+        database: combined_read_database,
+        txpool,
+        producer,
+        consensus_module,
+        p2p_service,
+        activity,
+        log_threshold_ms,
+        rate_limiter: rate_limiter.clone(),
+        max_queries_depth,
+        max_queries_complexity,
+    };
+
+    let router = match schema {
+        NodeSchemaBuilder::Full(schema) => {
+            let schema = schema_data.finish(schema);
+            Router::new()
+                .route(
+                    "/graphql",
+                    post(graphql_handler::<Query, Mutation, Subscription>).options(ok),
+                )
+                .route(
+                    "/graphql-sub",
+                    post(graphql_subscription_handler).options(ok),
+                )
+                .layer(Extension(schema))
+        }
+        NodeSchemaBuilder::ReadOnly(schema) => {
+            // The read-only schema has no subscriptions, so the endpoint is not served.
+            let schema = schema_data.finish(schema);
+            Router::new()
+                .route(
+                    "/graphql",
+                    post(graphql_handler::<Query, EmptyMutation, EmptySubscription>)
+                        .options(ok),
+                )
+                .layer(Extension(schema))
+        }
+    };
+
+    let router = router
         .route("/playground", get(graphql_playground))
-        .route("/graphql", post(graphql_handler).options(ok))
-        .route(
-            "/graphql-sub",
-            post(graphql_subscription_handler).options(ok),
-        )
         .route("/metrics", get(metrics))
         .route("/health", get(health))
-        .layer(Extension(schema))
+        .route("/v1/health", get(liveness))
+        .route("/v1/readiness", get(readiness))
+        .route("/v1/maintenance", get(maintenance_status))
+        .route(NODE_IDENTITY_PATH, get(identity))
+        .layer(Extension(health_registry))
+        .layer(Extension(maintenance))
+        .layer(Extension(node_identity))
+        .layer(Extension(SubscriptionFanOut::new(
+            max_subscriptions_per_client,
+        )))
+        .layer(Extension(rate_limiter))
+        .layer(Extension(api_keys))
+        .layer(Extension(max_batch_size))
         .layer(TraceLayer::new_for_http())
         .layer(TimeoutLayer::new(request_timeout))
         .layer(SetResponseHeaderLayer::<_>::overriding(
@@ -242,22 +378,195 @@ async fn health() -> Json<serde_json::Value> {
     Json(json!({ "up": true }))
 }
 
-async fn graphql_handler(
-    schema: Extension<CoreSchema>,
-    req: Json<Request>,
-) -> Json<Response> {
-    schema.execute(req.0).await.into()
+/// Responds with `200` while all services are alive.
+async fn liveness(
+    health: Extension<HealthRegistry>,
+) -> (StatusCode, Json<serde_json::Value>) {
+    let report = health.report();
+    health_response(report.is_live(), &report)
+}
+
+/// Responds with `200` if all services are alive, don't have errors, and are not stalled.
+async fn readiness(
+    health: Extension<HealthRegistry>,
+) -> (StatusCode, Json<serde_json::Value>) {
+    let report = health.report();
+    health_response(report.is_ready(), &report)
+}
+
+fn health_response(
+    up: bool,
+    report: &HealthReport,
+) -> (StatusCode, Json<serde_json::Value>) {
+    let services = report
+        .components
+        .iter()
+        .map(|(name, health)| {
+            let last_progress = health
+                .last_progress
+                .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+                .map(|duration| duration.as_secs());
+            let status = json!({
+                "alive": health.alive,
+                "ready": health.is_ready(),
+                "stalled": health.stalled,
+                "last_progress": last_progress,
+                "error": health.error,
+            });
+            (name.to_string(), status)
+        })
+        .collect::<serde_json::Map<_, _>>();
+    let status = if up {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    (status, Json(json!({ "up": up, "services": services })))
+}
+
+/// Responds with the status of each scheduled maintenance task.
+/// Serves the status of maintenance tasks only to the clients with one of the API keys,
+/// because it reveals the schedule of the operator.
+async fn maintenance_status(
+    maintenance: Extension<MaintenanceStatus>,
+    api_keys: Extension<ApiKeys>,
+    headers: HeaderMap,
+) -> Result<Json<serde_json::Value>, StatusCode> {
+    if !api_keys.is_known(&headers) {
+        return Err(StatusCode::UNAUTHORIZED)
+    }
+    Ok(Json(json!({ "tasks": maintenance.report() })))
+}
+
+/// Signs the identity of the node for the network on each request,
+/// so the identity can't be replayed later or at another URL.
+#[derive(Clone)]
+struct NodeIdentitySigner {
+    identity: NodeIdentityConfig,
+    network: String,
+}
+
+impl NodeIdentitySigner {
+    fn sign(&self, host: Option<&str>, issued_at: u64) -> Option<SignedNodeIdentity> {
+        let base = match &self.identity.public_url {
+            Some(url) => url.trim_end_matches('/').to_string(),
+            None => format!("http://{}", host?),
+        };
+        let identity = NodeIdentity {
+            operator: self.identity.operator.clone(),
+            contact: self.identity.contact.clone(),
+            network: self.network.clone(),
+            node_key: Default::default(),
+            url: format!("{base}{NODE_IDENTITY_PATH}"),
+            issued_at,
+        };
+        Some(identity.sign(self.identity.key.expose_secret()))
+    }
+}
+
+async fn identity(
+    node_identity: Extension<Option<NodeIdentitySigner>>,
+    headers: HeaderMap,
+) -> Result<Json<SignedNodeIdentity>, StatusCode> {
+    let signer = node_identity.0.ok_or(StatusCode::NOT_FOUND)?;
+    let host = headers
+        .get(axum::http::header::HOST)
+        .and_then(|host| host.to_str().ok());
+    let now = UNIX_EPOCH.elapsed().unwrap_or_default().as_secs();
+    signer
+        .sign(host, now)
+        .map(Json)
+        .ok_or(StatusCode::BAD_REQUEST)
+}
+
+/// The maximum number of operations in the batched request.
+#[derive(Clone, Copy)]
+struct MaxBatchSize(usize);
+
+async fn graphql_handler<Q, M, S>(
+    schema: Extension<Schema<Q, M, S>>,
+    rate_limiter: Extension<RateLimiter>,
+    api_keys: Extension<ApiKeys>,
+    Extension(MaxBatchSize(max_batch_size)): Extension<MaxBatchSize>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    req: Json<BatchRequest>,
+) -> Result<Json<BatchResponse>, axum::response::Response>
+where
+    Q: ObjectType + 'static,
+    M: ObjectType + 'static,
+    S: SubscriptionType + 'static,
+{
+    let operations = match &req.0 {
+        BatchRequest::Single(_) => 1,
+        BatchRequest::Batch(requests) if requests.len() > max_batch_size => {
+            let error = ServerError::new(
+                format!(
+                    "The batch has {} operations, but at most {max_batch_size} are allowed",
+                    requests.len()
+                ),
+                None,
+            );
+            return Err((
+                StatusCode::BAD_REQUEST,
+                Json(Response::from_errors(vec![error])),
+            )
+                .into_response())
+        }
+        BatchRequest::Batch(requests) => requests.len(),
+    };
+
+    let client = api_keys.client_key(&headers, address.ip());
+    // Each operation of the batch is executed as a separate request.
+    let cost = u32::try_from(operations).unwrap_or(u32::MAX).max(1);
+    rate_limiter
+        .check_cost(client.clone(), cost)
+        .map_err(IntoResponse::into_response)?;
+    Ok(schema.execute_batch(req.0.data(client)).await.into())
 }
 
 async fn graphql_subscription_handler(
     schema: Extension<CoreSchema>,
+    fan_out: Extension<SubscriptionFanOut>,
+    rate_limiter: Extension<RateLimiter>,
+    api_keys: Extension<ApiKeys>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
     req: Json<Request>,
-) -> Sse<impl Stream<Item = anyhow::Result<Event, serde_json::Error>>> {
-    let stream = schema
-        .execute_stream(req.0)
-        .map(|r| Ok(Event::default().json_data(r).unwrap()));
-    Sse::new(stream)
-        .keep_alive(axum::response::sse::KeepAlive::new().text("keep-alive-text"))
+) -> Result<
+    Sse<impl Stream<Item = anyhow::Result<Event, serde_json::Error>>>,
+    axum::response::Response,
+> {
+    let client = api_keys.client_key(&headers, address.ip());
+    rate_limiter
+        .check(client.clone())
+        .map_err(IntoResponse::into_response)?;
+    let request = req.0.data(client.clone());
+    let stream = if let Some(key) = shareable_key(&request) {
+        // Identical subscriptions are executed once, and the serialized events
+        // are shared between all subscribers.
+        let Some(stream) =
+            fan_out.subscribe(key, client, move || schema.0.execute_stream(request))
+        else {
+            let error =
+                ServerError::new("The client has too many active subscriptions", None);
+            return Err((
+                StatusCode::TOO_MANY_REQUESTS,
+                Json(Response::from_errors(vec![error])),
+            )
+                .into_response())
+        };
+        stream
+            .map(|payload| Ok(Event::default().data(&*payload)))
+            .left_stream()
+    } else {
+        schema
+            .execute_stream(request)
+            .map(|r| Ok(Event::default().json_data(r).unwrap()))
+            .right_stream()
+    };
+    Ok(Sse::new(stream)
+        .keep_alive(axum::response::sse::KeepAlive::new().text("keep-alive-text")))
 }
 
 async fn ok() -> anyhow::Result<(), ()> {
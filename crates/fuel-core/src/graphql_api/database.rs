@@ -29,6 +29,7 @@ use fuel_core_txpool::types::{
 use fuel_core_types::{
     blockchain::{
         block::CompressedBlock,
+        header::BlockHeader,
         primitives::{
             BlockId,
             DaBlockHeight,
@@ -49,6 +50,7 @@ use fuel_core_types::{
         Nonce,
     },
     services::{
+        executor::ContractStateChange,
         graphql_api::ContractBalance,
         txpool::TransactionStatus,
     },
@@ -119,6 +121,18 @@ impl DatabaseBlocks for ReadView {
         self.on_chain.blocks(height, direction)
     }
 
+    fn block_header(&self, height: &BlockHeight) -> StorageResult<BlockHeader> {
+        self.on_chain.block_header(height)
+    }
+
+    fn block_headers(
+        &self,
+        height: Option<BlockHeight>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<BlockHeader>> {
+        self.on_chain.block_headers(height, direction)
+    }
+
     fn latest_height(&self) -> StorageResult<BlockHeight> {
         self.on_chain.latest_height()
     }
@@ -206,6 +220,22 @@ impl OnChainDatabase for ReadView {
     ) -> BoxedIter<'_, StorageResult<UtxoId>> {
         self.on_chain.owned_coins_ids(owner, start_coin, direction)
     }
+
+    fn coin_balances_dirty(&self) -> StorageResult<bool> {
+        self.on_chain.coin_balances_dirty()
+    }
+
+    fn coin_balance(&self, owner: &Address, asset_id: &AssetId) -> StorageResult<u64> {
+        self.on_chain.coin_balance(owner, asset_id)
+    }
+
+    fn coin_balances(
+        &self,
+        owner: &Address,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(AssetId, u64)>> {
+        self.on_chain.coin_balances(owner, direction)
+    }
 }
 
 impl StorageInspect<Receipts> for ReadView {
@@ -237,4 +267,13 @@ impl OffChainDatabase for ReadView {
         self.off_chain
             .owned_transactions_ids(owner, start, direction)
     }
+
+    fn contract_state_changes(
+        &self,
+        contract_id: ContractId,
+        from: BlockHeight,
+        to: BlockHeight,
+    ) -> BoxedIter<StorageResult<(BlockHeight, ContractStateChange)>> {
+        self.off_chain.contract_state_changes(contract_id, from, to)
+    }
 }
@@ -1,5 +1,6 @@
 use crate::fuel_core_graphql_api::storage::receipts::Receipts;
 use async_trait::async_trait;
+use fuel_core_poa::Trigger;
 use fuel_core_services::stream::BoxStream;
 use fuel_core_storage::{
     iter::{
@@ -20,10 +21,17 @@ use fuel_core_storage::{
     Result as StorageResult,
     StorageInspect,
 };
-use fuel_core_txpool::service::TxStatusMessage;
+use fuel_core_txpool::{
+    admin::FlushFilter,
+    service::{
+        Subscriber,
+        TxStatusMessage,
+    },
+};
 use fuel_core_types::{
     blockchain::{
         block::CompressedBlock,
+        header::BlockHeader,
         primitives::{
             BlockId,
             DaBlockHeight,
@@ -47,17 +55,28 @@ use fuel_core_types::{
         Nonce,
     },
     services::{
-        executor::TransactionExecutionStatus,
+        executor::{
+            ContractStateChange,
+            DryRunBundleStep,
+            TransactionExecutionStatus,
+        },
         graphql_api::ContractBalance,
-        p2p::PeerInfo,
+        p2p::{
+            NetworkInfo,
+            PeerInfo,
+        },
         txpool::{
             InsertionResult,
             TransactionStatus,
+            TxOrigin,
         },
     },
     tai64::Tai64,
 };
-use std::sync::Arc;
+use std::{
+    path::Path,
+    sync::Arc,
+};
 
 pub trait OffChainDatabase:
     Send + Sync + StorageInspect<Receipts, Error = StorageError>
@@ -70,6 +89,13 @@ pub trait OffChainDatabase:
         start: Option<TxPointer>,
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<(TxPointer, TxId)>>;
+
+    fn contract_state_changes(
+        &self,
+        contract_id: ContractId,
+        from: BlockHeight,
+        to: BlockHeight,
+    ) -> BoxedIter<StorageResult<(BlockHeight, ContractStateChange)>>;
 }
 
 /// The on chain database port expected by GraphQL API service.
@@ -99,6 +125,21 @@ pub trait OnChainDatabase:
         start_coin: Option<UtxoId>,
         direction: IterDirection,
     ) -> BoxedIter<'_, StorageResult<UtxoId>>;
+
+    /// Returns `true` if the balances index is inconsistent with coins.
+    fn coin_balances_dirty(&self) -> StorageResult<bool>;
+
+    /// Returns the total amount of coins of the `asset_id` owned by the `owner`
+    /// from the balances index.
+    fn coin_balance(&self, owner: &Address, asset_id: &AssetId) -> StorageResult<u64>;
+
+    /// Returns the total amounts of coins per asset owned by the `owner`
+    /// from the balances index ordered by the asset id.
+    fn coin_balances(
+        &self,
+        owner: &Address,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(AssetId, u64)>>;
 }
 
 /// Trait that specifies all the getters required for blocks.
@@ -114,6 +155,16 @@ pub trait DatabaseBlocks:
         direction: IterDirection,
     ) -> BoxedIter<'_, StorageResult<CompressedBlock>>;
 
+    /// Returns the header of the block without reading the transactions of the block.
+    fn block_header(&self, height: &BlockHeight) -> StorageResult<BlockHeader>;
+
+    /// Returns the headers of blocks without reading the transactions of blocks.
+    fn block_headers(
+        &self,
+        height: Option<BlockHeight>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<BlockHeader>>;
+
     fn latest_height(&self) -> StorageResult<BlockHeight>;
 }
 
@@ -153,17 +204,37 @@ pub trait DatabaseChain {
 pub trait TxPoolPort: Send + Sync {
     fn transaction(&self, id: TxId) -> Option<Transaction>;
 
-    fn submission_time(&self, id: TxId) -> Option<Tai64>;
+    /// Returns the minimum gas price of transactions accepted for the next block.
+    fn min_gas_price(&self) -> u64;
+
+    fn submitted_status(&self, id: TxId) -> Option<TransactionStatus>;
 
     async fn insert(
         &self,
         txs: Vec<Arc<Transaction>>,
+        origin: TxOrigin,
     ) -> Vec<anyhow::Result<InsertionResult>>;
 
+    /// Subscribes to the status updates of the transaction on behalf of the `subscriber`.
     fn tx_update_subscribe(
         &self,
         tx_id: TxId,
+        subscriber: Option<Subscriber>,
     ) -> anyhow::Result<BoxStream<TxStatusMessage>>;
+
+    /// Pauses the insertion of new transactions from the API and the gossip.
+    fn pause(&self);
+
+    /// Resumes the insertion of new transactions.
+    fn resume(&self);
+
+    /// Removes transactions selected by the `filter` with their dependent transactions.
+    /// Returns the number of removed transactions.
+    fn flush(&self, filter: FlushFilter) -> usize;
+
+    /// Writes the current state of the pool as JSON to the file at `path`.
+    /// Returns the number of dumped transactions.
+    fn dump(&self, path: &Path) -> anyhow::Result<usize>;
 }
 
 #[async_trait]
@@ -174,6 +245,13 @@ pub trait BlockProducerPort: Send + Sync {
         height: Option<BlockHeight>,
         utxo_validation: Option<bool>,
     ) -> anyhow::Result<Vec<TransactionExecutionStatus>>;
+
+    async fn dry_run_bundle(
+        &self,
+        transactions: Vec<Transaction>,
+        height: Option<BlockHeight>,
+        utxo_validation: Option<bool>,
+    ) -> anyhow::Result<Vec<DryRunBundleStep>>;
 }
 
 #[async_trait::async_trait]
@@ -183,6 +261,9 @@ pub trait ConsensusModulePort: Send + Sync {
         start_time: Option<Tai64>,
         number_of_blocks: u32,
     ) -> anyhow::Result<()>;
+
+    /// Changes the trigger of the block production.
+    async fn set_block_production(&self, trigger: Trigger) -> anyhow::Result<()>;
 }
 
 /// Trait that specifies queries supported by the database.
@@ -199,6 +280,9 @@ pub trait DatabaseMessageProof: Send + Sync {
 #[async_trait::async_trait]
 pub trait P2pPort: Send + Sync {
     async fn all_peer_info(&self) -> anyhow::Result<Vec<PeerInfo>>;
+
+    /// Returns the telemetry of the P2P network, or `None` if the P2P network is disabled.
+    fn network_info(&self) -> Option<NetworkInfo>;
 }
 
 pub mod worker {
@@ -220,10 +304,12 @@ pub mod worker {
         fuel_tx::{
             Address,
             Bytes32,
+            TxPointer,
         },
         fuel_types::BlockHeight,
         services::{
             block_importer::SharedImportResult,
+            executor::ContractStateChange,
             txpool::TransactionStatus,
         },
     };
@@ -246,9 +332,16 @@ pub mod worker {
         fn update_tx_status(
             &mut self,
             id: &Bytes32,
+            tx_pointer: TxPointer,
             status: TransactionStatus,
         ) -> StorageResult<Option<TransactionStatus>>;
 
+        fn record_contract_state_change(
+            &mut self,
+            block_height: BlockHeight,
+            change: &ContractStateChange,
+        ) -> StorageResult<()>;
+
         /// Update metadata about the total number of transactions on the chain.
         /// Returns the total count after the update.
         fn increase_tx_count(&mut self, new_txs_count: u64) -> StorageResult<u64>;
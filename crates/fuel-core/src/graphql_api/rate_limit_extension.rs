@@ -0,0 +1,56 @@
+use crate::graphql_api::rate_limiter::{
+    ClientKey,
+    RateLimiter,
+    COMPLEXITY_PER_TOKEN,
+};
+use async_graphql::{
+    extensions::{
+        Extension,
+        ExtensionContext,
+        ExtensionFactory,
+        NextValidation,
+    },
+    ServerError,
+    ValidationResult,
+};
+use std::sync::Arc;
+
+/// The extension that takes the tokens of the client for the complexity of
+/// the operation after its validation. The operation is rejected with the throttled
+/// error if the client doesn't have enough tokens.
+pub(crate) struct RateLimitExtension {
+    rate_limiter: RateLimiter,
+}
+
+impl RateLimitExtension {
+    pub fn new(rate_limiter: RateLimiter) -> Self {
+        Self { rate_limiter }
+    }
+}
+
+impl ExtensionFactory for RateLimitExtension {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(RateLimitExtension::new(self.rate_limiter.clone()))
+    }
+}
+
+#[async_trait::async_trait]
+impl Extension for RateLimitExtension {
+    async fn validation(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        next: NextValidation<'_>,
+    ) -> Result<ValidationResult, Vec<ServerError>> {
+        let result = next.run(ctx).await?;
+        let cost = result.complexity / COMPLEXITY_PER_TOKEN;
+        if cost > 0 {
+            if let Some(client) = ctx.data_opt::<ClientKey>() {
+                let cost = u32::try_from(cost).unwrap_or(u32::MAX);
+                self.rate_limiter
+                    .check_cost(client.clone(), cost)
+                    .map_err(|throttled| vec![throttled.server_error()])?;
+            }
+        }
+        Ok(result)
+    }
+}
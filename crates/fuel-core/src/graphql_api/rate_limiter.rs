@@ -0,0 +1,403 @@
+//! The per-client rate limiting of the GraphQL API.
+//!
+//! Each client has a token bucket with the capacity of `burst` requests, refilled
+//! with `requests_per_second` tokens per second. Each request takes one token, and
+//! the request is throttled when the bucket is empty. The batched request takes one
+//! token per operation. Each operation also takes one token per
+//! [`COMPLEXITY_PER_TOKEN`] of its complexity after the validation. The client
+//! is identified by the API key from the [`API_KEY_HEADER`] if the key is one of
+//! the configured [`ApiKeys`], or by the IP address otherwise.
+
+use async_graphql::{
+    ErrorExtensionValues,
+    Response,
+    ServerError,
+};
+use axum::{
+    http::{
+        header::RETRY_AFTER,
+        HeaderMap,
+        StatusCode,
+    },
+    response::IntoResponse,
+    Json,
+};
+use std::{
+    collections::{
+        HashMap,
+        HashSet,
+    },
+    net::IpAddr,
+    num::NonZeroU32,
+    sync::{
+        Arc,
+        Mutex,
+    },
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+/// The header with the API key of the client.
+pub const API_KEY_HEADER: &str = "x-api-key";
+
+/// The error code of the throttled request in the extensions of the GraphQL error.
+pub const THROTTLED_CODE: &str = "THROTTLED";
+
+/// The complexity of the operation for which it takes one more token.
+pub const COMPLEXITY_PER_TOKEN: usize = 1_000;
+
+/// The number of tracked clients after which the buckets of idle clients are dropped.
+/// If all clients are active, the bucket of the least recently seen client is dropped.
+const MAX_TRACKED_CLIENTS: usize = 65_536;
+
+/// The configuration of the per-client rate limiting.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RateLimitConfig {
+    /// The number of requests per second that is allowed for each client.
+    pub requests_per_second: NonZeroU32,
+    /// The number of requests that the client can send at once after being idle.
+    pub burst: NonZeroU32,
+}
+
+/// The API keys of known clients. Only these keys identify clients,
+/// the requests with other keys are identified by the IP address.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ApiKeys(Arc<HashSet<String>>);
+
+impl ApiKeys {
+    pub(crate) fn new(keys: HashSet<String>) -> Self {
+        Self(Arc::new(keys))
+    }
+
+    /// Returns `true` if the request with the `headers` has one of the known keys.
+    pub(crate) fn is_known(&self, headers: &HeaderMap) -> bool {
+        headers
+            .get(API_KEY_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .map(|api_key| self.0.contains(api_key))
+            .unwrap_or(false)
+    }
+
+    /// Returns the identity of the client sending the request with the `headers`.
+    pub(crate) fn client_key(&self, headers: &HeaderMap, ip: IpAddr) -> ClientKey {
+        ClientKey::new(headers, ip, &self.0)
+    }
+}
+
+/// The identity of the client used for rate limiting.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) enum ClientKey {
+    ApiKey(String),
+    Ip(IpAddr),
+}
+
+impl ClientKey {
+    pub(crate) fn new(
+        headers: &HeaderMap,
+        ip: IpAddr,
+        api_keys: &HashSet<String>,
+    ) -> Self {
+        match headers
+            .get(API_KEY_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .filter(|api_key| api_keys.contains(*api_key))
+        {
+            Some(api_key) => ClientKey::ApiKey(api_key.to_string()),
+            None => ClientKey::Ip(ip),
+        }
+    }
+
+    /// The identity of the client in the limits of transaction status subscriptions.
+    pub(crate) fn subscriber(&self) -> String {
+        match self {
+            ClientKey::ApiKey(api_key) => format!("key:{api_key}"),
+            ClientKey::Ip(ip) => format!("ip:{ip}"),
+        }
+    }
+}
+
+struct Bucket {
+    tokens: f64,
+    updated_at: Instant,
+}
+
+impl Bucket {
+    fn tokens_at(&self, now: Instant, config: &RateLimitConfig) -> f64 {
+        let elapsed = now.saturating_duration_since(self.updated_at).as_secs_f64();
+        let refilled =
+            self.tokens + elapsed * f64::from(config.requests_per_second.get());
+        refilled.min(f64::from(config.burst.get()))
+    }
+}
+
+/// The request was rejected because the client exceeded its rate limit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Throttled {
+    /// The time after which the client gets the token for the next request.
+    pub retry_after: Duration,
+}
+
+impl Throttled {
+    fn retry_after_secs(&self) -> u64 {
+        self.retry_after
+            .as_secs()
+            .saturating_add(u64::from(self.retry_after.subsec_nanos() > 0))
+    }
+
+    /// Returns the GraphQL error with the [`THROTTLED_CODE`].
+    pub(crate) fn server_error(&self) -> ServerError {
+        let mut extensions = ErrorExtensionValues::default();
+        extensions.set("code", THROTTLED_CODE);
+        extensions.set("retryAfter", self.retry_after_secs());
+        let mut error = ServerError::new("Too many requests", None);
+        error.extensions = Some(extensions);
+        error
+    }
+}
+
+impl IntoResponse for Throttled {
+    fn into_response(self) -> axum::response::Response {
+        (
+            StatusCode::TOO_MANY_REQUESTS,
+            [(RETRY_AFTER, self.retry_after_secs().to_string())],
+            Json(Response::from_errors(vec![self.server_error()])),
+        )
+            .into_response()
+    }
+}
+
+/// The token buckets of all clients. Does nothing without the config.
+#[derive(Clone, Default)]
+pub(crate) struct RateLimiter {
+    config: Option<RateLimitConfig>,
+    buckets: Arc<Mutex<HashMap<ClientKey, Bucket>>>,
+}
+
+impl RateLimiter {
+    pub(crate) fn new(config: Option<RateLimitConfig>) -> Self {
+        Self {
+            config,
+            buckets: Default::default(),
+        }
+    }
+
+    /// Takes the token for the next request of the client.
+    pub(crate) fn check(&self, key: ClientKey) -> Result<(), Throttled> {
+        self.check_cost(key, 1)
+    }
+
+    /// Takes `cost` tokens for the next request of the client. The request with
+    /// the cost above the `burst` is allowed when the bucket is full, and the tokens
+    /// above the `burst` are borrowed from the future refills.
+    pub(crate) fn check_cost(&self, key: ClientKey, cost: u32) -> Result<(), Throttled> {
+        self.check_cost_at(key, cost, Instant::now())
+    }
+
+    #[cfg(test)]
+    fn check_at(&self, key: ClientKey, now: Instant) -> Result<(), Throttled> {
+        self.check_cost_at(key, 1, now)
+    }
+
+    fn check_cost_at(
+        &self,
+        key: ClientKey,
+        cost: u32,
+        now: Instant,
+    ) -> Result<(), Throttled> {
+        let Some(config) = &self.config else {
+            return Ok(())
+        };
+        let mut buckets = self.buckets.lock().expect("The lock is poisoned");
+
+        if buckets.len() >= MAX_TRACKED_CLIENTS && !buckets.contains_key(&key) {
+            let burst = f64::from(config.burst.get());
+            buckets.retain(|_, bucket| bucket.tokens_at(now, config) < burst);
+            if buckets.len() >= MAX_TRACKED_CLIENTS {
+                let least_recent = buckets
+                    .iter()
+                    .min_by_key(|(_, bucket)| bucket.updated_at)
+                    .map(|(key, _)| key.clone());
+                if let Some(least_recent) = least_recent {
+                    buckets.remove(&least_recent);
+                }
+            }
+        }
+
+        let bucket = buckets.entry(key).or_insert_with(|| Bucket {
+            tokens: f64::from(config.burst.get()),
+            updated_at: now,
+        });
+        let tokens = bucket.tokens_at(now, config);
+        bucket.updated_at = now;
+        let cost = f64::from(cost);
+        let required = cost.min(f64::from(config.burst.get()));
+
+        if tokens >= required {
+            bucket.tokens = tokens - cost;
+            Ok(())
+        } else {
+            bucket.tokens = tokens;
+            let missing = required - tokens;
+            Err(Throttled {
+                retry_after: Duration::from_secs_f64(
+                    missing / f64::from(config.requests_per_second.get()),
+                ),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::Ipv4Addr;
+
+    fn limiter(requests_per_second: u32, burst: u32) -> RateLimiter {
+        RateLimiter::new(Some(RateLimitConfig {
+            requests_per_second: requests_per_second.try_into().unwrap(),
+            burst: burst.try_into().unwrap(),
+        }))
+    }
+
+    fn ip(last: u8) -> ClientKey {
+        ClientKey::Ip(Ipv4Addr::new(127, 0, 0, last).into())
+    }
+
+    #[test]
+    fn check_throttles_client_after_burst() {
+        let limiter = limiter(1, 3);
+        let now = Instant::now();
+
+        for _ in 0..3 {
+            assert_eq!(limiter.check_at(ip(1), now), Ok(()));
+        }
+        let throttled = limiter.check_at(ip(1), now).unwrap_err();
+
+        assert_eq!(throttled.retry_after, Duration::from_secs(1));
+    }
+
+    #[test]
+    fn check_refills_tokens_over_time() {
+        let limiter = limiter(2, 1);
+        let now = Instant::now();
+        limiter.check_at(ip(1), now).unwrap();
+        assert!(limiter.check_at(ip(1), now).is_err());
+
+        let later = now + Duration::from_millis(500);
+
+        assert_eq!(limiter.check_at(ip(1), later), Ok(()));
+    }
+
+    #[test]
+    fn check_limits_clients_independently() {
+        let limiter = limiter(1, 1);
+        let now = Instant::now();
+        limiter.check_at(ip(1), now).unwrap();
+
+        assert!(limiter.check_at(ip(1), now).is_err());
+        assert_eq!(limiter.check_at(ip(2), now), Ok(()));
+        assert_eq!(
+            limiter.check_at(ClientKey::ApiKey("key".to_string()), now),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn check_cost_takes_token_per_operation() {
+        let limiter = limiter(1, 5);
+        let now = Instant::now();
+
+        assert_eq!(limiter.check_cost_at(ip(1), 3, now), Ok(()));
+        let throttled = limiter.check_cost_at(ip(1), 3, now).unwrap_err();
+
+        assert_eq!(throttled.retry_after, Duration::from_secs(1));
+        assert_eq!(limiter.check_cost_at(ip(1), 2, now), Ok(()));
+    }
+
+    #[test]
+    fn check_cost_above_burst_is_charged_in_full() {
+        let limiter = limiter(1, 2);
+        let now = Instant::now();
+
+        assert_eq!(limiter.check_cost_at(ip(1), 10, now), Ok(()));
+        let throttled = limiter.check_at(ip(1), now).unwrap_err();
+        assert_eq!(throttled.retry_after, Duration::from_secs(9));
+
+        assert!(limiter
+            .check_at(ip(1), now + Duration::from_secs(8))
+            .is_err());
+        assert_eq!(
+            limiter.check_at(ip(1), now + Duration::from_secs(9)),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn check_allows_everything_without_config() {
+        let limiter = RateLimiter::new(None);
+        let now = Instant::now();
+
+        for _ in 0..100 {
+            assert_eq!(limiter.check_at(ip(1), now), Ok(()));
+        }
+    }
+
+    #[test]
+    fn check_drops_least_recent_client_when_all_clients_are_active() {
+        let limiter = limiter(1, 2);
+        let now = Instant::now();
+        let client = |index: usize| {
+            ClientKey::Ip(Ipv4Addr::from(u32::try_from(index).unwrap()).into())
+        };
+        limiter.check_at(client(0), now).unwrap();
+        let later = now + Duration::from_millis(1);
+        for index in 1..MAX_TRACKED_CLIENTS {
+            limiter.check_at(client(index), later).unwrap();
+        }
+
+        limiter
+            .check_at(client(MAX_TRACKED_CLIENTS), later)
+            .unwrap();
+
+        let buckets = limiter.buckets.lock().unwrap();
+        assert_eq!(buckets.len(), MAX_TRACKED_CLIENTS);
+        assert!(!buckets.contains_key(&client(0)));
+        assert!(buckets.contains_key(&client(MAX_TRACKED_CLIENTS)));
+    }
+
+    #[test]
+    fn client_key_uses_only_known_api_keys() {
+        let mut headers = HeaderMap::new();
+        let address: IpAddr = Ipv4Addr::LOCALHOST.into();
+        let api_keys = HashSet::from(["secret".to_string()]);
+        assert_eq!(
+            ClientKey::new(&headers, address, &api_keys),
+            ClientKey::Ip(address)
+        );
+
+        headers.insert(API_KEY_HEADER, "unknown".parse().unwrap());
+        assert_eq!(
+            ClientKey::new(&headers, address, &api_keys),
+            ClientKey::Ip(address)
+        );
+
+        headers.insert(API_KEY_HEADER, "secret".parse().unwrap());
+        assert_eq!(
+            ClientKey::new(&headers, address, &api_keys),
+            ClientKey::ApiKey("secret".to_string())
+        );
+    }
+
+    #[test]
+    fn throttled_response_has_retry_after() {
+        let response = Throttled {
+            retry_after: Duration::from_millis(1500),
+        }
+        .into_response();
+
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(response.headers()[RETRY_AFTER], "2");
+    }
+}
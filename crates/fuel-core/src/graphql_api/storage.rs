@@ -1,5 +1,6 @@
 use fuel_core_storage::kv_store::StorageColumn;
 
+pub mod contracts;
 pub mod receipts;
 pub mod transactions;
 
@@ -23,7 +24,7 @@ pub enum Column {
     Receipts = 1,
     /// The column of the table that stores `true` if `owner` owns `Coin` with `coin_id`
     OwnedCoins = 2,
-    /// Transaction id to current status
+    /// See [`TransactionStatuses`](transactions::TransactionStatuses)
     TransactionStatus = 3,
     /// The column of the table of all `owner`'s transactions
     TransactionsByOwnerBlockIdx = 4,
@@ -31,6 +32,10 @@ pub enum Column {
     OwnedMessageIds = 5,
     /// The column of the table that stores statistic about the blockchain.
     Statistic = 6,
+    /// See [`ContractStateChanges`](contracts::ContractStateChanges)
+    ContractStateChanges = 7,
+    /// See [`TransactionPointers`](transactions::TransactionPointers)
+    TransactionPointers = 8,
 }
 
 impl Column {
@@ -0,0 +1,126 @@
+use fuel_core_storage::{
+    blueprint::plain::Plain,
+    codec::{
+        manual::Manual,
+        postcard::Postcard,
+        Decode,
+        Encode,
+    },
+    structured_storage::TableWithBlueprint,
+    Mappable,
+};
+use fuel_core_types::{
+    fuel_tx::{
+        Bytes32,
+        ContractId,
+    },
+    fuel_types::BlockHeight,
+};
+use std::mem::size_of;
+
+/// The table stores the values of the contract storage slots written by each block.
+/// The value is `None` if the block removed the slot. The key is ordered by the contract id
+/// and then by the block height, so the changes of the contract can be iterated
+/// over the range of blocks.
+pub struct ContractStateChanges;
+
+impl Mappable for ContractStateChanges {
+    type Key = ContractStateChangeKey;
+    type OwnedKey = Self::Key;
+    type Value = Option<Bytes32>;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for ContractStateChanges {
+    type Blueprint = Plain<Manual<ContractStateChangeKey>, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::ContractStateChanges
+    }
+}
+
+const BLOCK_HEIGHT: usize = size_of::<BlockHeight>();
+const KEY_SIZE: usize = ContractId::LEN + BLOCK_HEIGHT + Bytes32::LEN;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContractStateChangeKey {
+    pub contract_id: ContractId,
+    pub block_height: BlockHeight,
+    pub key: Bytes32,
+}
+
+impl ContractStateChangeKey {
+    pub fn new(
+        contract_id: &ContractId,
+        block_height: BlockHeight,
+        key: &Bytes32,
+    ) -> Self {
+        Self {
+            contract_id: *contract_id,
+            block_height,
+            key: *key,
+        }
+    }
+}
+
+impl From<[u8; KEY_SIZE]> for ContractStateChangeKey {
+    fn from(bytes: [u8; KEY_SIZE]) -> Self {
+        let contract_id: [u8; 32] = bytes[..ContractId::LEN]
+            .try_into()
+            .expect("It's an array of 32 bytes");
+        let mut block_height_bytes: [u8; 4] = Default::default();
+        block_height_bytes
+            .copy_from_slice(&bytes[ContractId::LEN..ContractId::LEN + BLOCK_HEIGHT]);
+        let key: [u8; 32] = bytes[ContractId::LEN + BLOCK_HEIGHT..]
+            .try_into()
+            .expect("It's an array of 32 bytes");
+
+        Self {
+            contract_id: contract_id.into(),
+            block_height: u32::from_be_bytes(block_height_bytes).into(),
+            key: key.into(),
+        }
+    }
+}
+
+impl Encode<ContractStateChangeKey> for Manual<ContractStateChangeKey> {
+    type Encoder<'a> = [u8; KEY_SIZE];
+
+    fn encode(t: &ContractStateChangeKey) -> Self::Encoder<'_> {
+        let mut bytes = [0u8; KEY_SIZE];
+        bytes[..ContractId::LEN].copy_from_slice(t.contract_id.as_ref());
+        bytes[ContractId::LEN..ContractId::LEN + BLOCK_HEIGHT]
+            .copy_from_slice(t.block_height.to_bytes().as_ref());
+        bytes[ContractId::LEN + BLOCK_HEIGHT..].copy_from_slice(t.key.as_ref());
+        bytes
+    }
+}
+
+impl Decode<ContractStateChangeKey> for Manual<ContractStateChangeKey> {
+    fn decode(bytes: &[u8]) -> anyhow::Result<ContractStateChangeKey> {
+        let bytes: [u8; KEY_SIZE] = bytes
+            .try_into()
+            .map_err(|_| anyhow::anyhow!("Unable to decode bytes"))?;
+        Ok(bytes.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn generate_key(rng: &mut impl rand::Rng) -> <ContractStateChanges as Mappable>::Key {
+        let mut bytes = [0u8; KEY_SIZE];
+        rng.fill(bytes.as_mut());
+        bytes.into()
+    }
+
+    fuel_core_storage::basic_storage_tests!(
+        ContractStateChanges,
+        [1u8; KEY_SIZE].into(),
+        Some(Bytes32::new([2u8; 32])),
+        Some(Bytes32::new([2u8; 32])),
+        generate_key
+    );
+}
@@ -14,6 +14,7 @@ use fuel_core_types::{
     fuel_tx::{
         Address,
         Bytes32,
+        TxPointer,
     },
     fuel_types::BlockHeight,
     services::txpool::TransactionStatus,
@@ -42,12 +43,24 @@ impl TableWithBlueprint for OwnedTransactions {
     }
 }
 
-/// The table stores the status of each transaction.
+/// The table stores the status of each transaction included in the block.
+/// The key is `block height + tx idx` of the transaction, so new keys are short
+/// and always appended to the end of the column.
 pub struct TransactionStatuses;
+/// The storage key for transaction statuses: `BlockHeight ++ TransactionIndex`
+pub type TransactionStatusKey = [u8; BLOCK_HEIGHT + TX_INDEX_SIZE];
+
+pub fn tx_status_key(tx_pointer: &TxPointer) -> TransactionStatusKey {
+    let mut default = [0u8; BLOCK_HEIGHT + TX_INDEX_SIZE];
+    default[..BLOCK_HEIGHT]
+        .copy_from_slice(tx_pointer.block_height().to_bytes().as_ref());
+    default[BLOCK_HEIGHT..].copy_from_slice(tx_pointer.tx_index().to_be_bytes().as_ref());
+    default
+}
 
 impl Mappable for TransactionStatuses {
-    type Key = Bytes32;
-    type OwnedKey = Self::Key;
+    type Key = Self::OwnedKey;
+    type OwnedKey = TransactionStatusKey;
     type Value = TransactionStatus;
     type OwnedValue = Self::Value;
 }
@@ -61,6 +74,26 @@ impl TableWithBlueprint for TransactionStatuses {
     }
 }
 
+/// The table maps the id of the transaction to its position in the block.
+/// The position is the key of the [`TransactionStatuses`] table.
+pub struct TransactionPointers;
+
+impl Mappable for TransactionPointers {
+    type Key = Bytes32;
+    type OwnedKey = Self::Key;
+    type Value = TxPointer;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for TransactionPointers {
+    type Blueprint = Plain<Raw, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::TransactionPointers
+    }
+}
+
 const TX_INDEX_SIZE: usize = size_of::<TransactionIndex>();
 const BLOCK_HEIGHT: usize = size_of::<BlockHeight>();
 const INDEX_SIZE: usize = Address::LEN + BLOCK_HEIGHT + TX_INDEX_SIZE;
@@ -207,6 +240,28 @@ mod test {
         <TransactionStatuses as Mappable>::Key::default(),
         TransactionStatus::Submitted {
             time: fuel_core_types::tai64::Tai64::UNIX_EPOCH,
+            origin: fuel_core_types::services::txpool::TxOrigin::Injected,
         }
     );
+
+    fuel_core_storage::basic_storage_tests!(
+        TransactionPointers,
+        <TransactionPointers as Mappable>::Key::default(),
+        TxPointer::new(1u32.into(), 2)
+    );
+
+    #[test]
+    fn tx_status_keys_are_ordered_by_tx_pointer() {
+        let pointers = [
+            TxPointer::new(1u32.into(), 2),
+            TxPointer::new(1u32.into(), 256),
+            TxPointer::new(256u32.into(), 0),
+        ];
+
+        let keys: Vec<_> = pointers.iter().map(tx_status_key).collect();
+
+        let mut sorted = keys.clone();
+        sorted.sort();
+        assert_eq!(keys, sorted);
+    }
 }
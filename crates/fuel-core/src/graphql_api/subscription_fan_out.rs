@@ -0,0 +1,546 @@
+//! The fan-out of identical subscriptions.
+//!
+//! Many clients subscribe to the same events, like the status of the same transaction.
+//! Instead of executing and serializing the subscription for each subscriber, the fan-out
+//! executes the subscription once per unique request(the query, the operation and variables)
+//! and shares the serialized payload of each event between all subscribers of the request.
+//!
+//! The shared subscription is executed in the context of its first subscriber, so the limits
+//! of the resolvers don't apply to other subscribers. Instead, the fan-out limits
+//! the number of shared subscriptions of each client.
+
+use crate::graphql_api::rate_limiter::ClientKey;
+use async_graphql::{
+    parser::{
+        parse_query,
+        types::{
+            DocumentOperations,
+            OperationType,
+            Selection,
+        },
+    },
+    Request,
+    Response,
+    ServerError,
+};
+use futures::{
+    Stream,
+    StreamExt,
+};
+use std::{
+    collections::HashMap,
+    sync::{
+        Arc,
+        Mutex,
+    },
+};
+use tokio::sync::{
+    broadcast,
+    oneshot,
+};
+use tokio_stream::wrappers::{
+    errors::BroadcastStreamRecvError,
+    BroadcastStream,
+};
+
+/// The serialized response of the subscription.
+pub(crate) type Payload = Arc<str>;
+
+/// The root fields of subscriptions without side effects. The events of these subscriptions
+/// don't depend on the subscriber, so they can be shared. The flag is `true` if the last
+/// event is the current state, so it is replayed to new subscribers. The activity
+/// subscriptions report only new events, so their events are not replayed.
+const SHAREABLE_SUBSCRIPTIONS: &[(&str, bool)] = &[
+    ("statusChange", true),
+    ("assetActivity", false),
+    ("contractActivity", false),
+];
+
+/// The number of events buffered for the slow subscriber. The subscriber that lags
+/// more than that receives the error and is disconnected.
+const EVENTS_BUFFER: usize = 128;
+
+/// The key of the subscription shared between subscribers with the same key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ShareableKey {
+    /// The query, the operation and variables of the request.
+    key: String,
+    /// Whether the last event is replayed to new subscribers.
+    replay_last: bool,
+}
+
+/// Returns the key of the request if the subscription can be shared
+/// between subscribers with the same key.
+pub(crate) fn shareable_key(request: &Request) -> Option<ShareableKey> {
+    let document = parse_query(&request.query).ok()?;
+    let operation = match (&document.operations, &request.operation_name) {
+        (DocumentOperations::Single(operation), _) => operation,
+        (DocumentOperations::Multiple(operations), Some(name)) => {
+            operations.get(name.as_str())?
+        }
+        (DocumentOperations::Multiple(_), None) => return None,
+    };
+
+    if operation.node.ty != OperationType::Subscription {
+        return None
+    }
+
+    let mut replay_last = true;
+    for selection in &operation.node.selection_set.node.items {
+        let Selection::Field(field) = &selection.node else {
+            return None
+        };
+        let (_, replay) = SHAREABLE_SUBSCRIPTIONS
+            .iter()
+            .find(|(name, _)| *name == field.node.name.node.as_str())?;
+        replay_last &= *replay;
+    }
+
+    let key = serde_json::to_string(&(
+        &request.query,
+        &request.operation_name,
+        &request.variables,
+    ))
+    .ok()?;
+    Some(ShareableKey { key, replay_last })
+}
+
+/// The terminal event of the subscriber that lagged behind by `skipped` events.
+fn lagged_payload(skipped: u64) -> Payload {
+    let error = ServerError::new(
+        format!("The subscriber lagged behind by {skipped} events and was disconnected"),
+        None,
+    );
+    serde_json::to_string(&Response::from_errors(vec![error]))
+        .expect("The error response is serializable")
+        .into()
+}
+
+struct SharedSubscription {
+    id: u64,
+    sender: broadcast::Sender<Payload>,
+    /// The last event of the subscription. It is replayed to new subscribers,
+    /// so they receive the current state, like the first subscriber.
+    /// It is `None` for subscriptions without the replay.
+    last: Option<Payload>,
+    replay_last: bool,
+    subscribers: usize,
+    /// Stops the execution of the subscription when dropped.
+    _stop: oneshot::Sender<()>,
+}
+
+#[derive(Default)]
+struct Subscriptions {
+    next_id: u64,
+    active: HashMap<String, SharedSubscription>,
+    /// The number of active subscriptions of each client.
+    clients: HashMap<ClientKey, usize>,
+}
+
+/// The registry of active shared subscriptions.
+#[derive(Clone)]
+pub(crate) struct SubscriptionFanOut {
+    subscriptions: Arc<Mutex<Subscriptions>>,
+    max_per_client: usize,
+}
+
+impl SubscriptionFanOut {
+    /// Creates the fan-out allowing up to `max_per_client` active subscriptions
+    /// of each client.
+    pub fn new(max_per_client: usize) -> Self {
+        Self {
+            subscriptions: Default::default(),
+            max_per_client,
+        }
+    }
+
+    /// Subscribes the `client` to the events of the subscription with the `key`.
+    /// If there is no active subscription with the `key`, it is created from
+    /// the `upstream`. Returns `None` if the client has too many active subscriptions.
+    pub fn subscribe<F, S>(
+        &self,
+        key: ShareableKey,
+        client: ClientKey,
+        upstream: F,
+    ) -> Option<impl Stream<Item = Payload> + Send + 'static>
+    where
+        F: FnOnce() -> S,
+        S: Stream<Item = Response> + Send + 'static,
+    {
+        let ShareableKey { key, replay_last } = key;
+        let mut subscriptions = self.subscriptions.lock().expect("poisoned");
+        let subscriptions = &mut *subscriptions;
+
+        let client_subscriptions =
+            subscriptions.clients.get(&client).copied().unwrap_or(0);
+        if client_subscriptions >= self.max_per_client {
+            return None
+        }
+        subscriptions
+            .clients
+            .insert(client.clone(), client_subscriptions.saturating_add(1));
+
+        let subscription = subscriptions.active.entry(key.clone()).or_insert_with(|| {
+            let id = subscriptions.next_id;
+            subscriptions.next_id = subscriptions.next_id.wrapping_add(1);
+            let (sender, _) = broadcast::channel(EVENTS_BUFFER);
+            let (stop_sender, stop_receiver) = oneshot::channel();
+            tokio::spawn(self.clone().publish(
+                key.clone(),
+                id,
+                upstream(),
+                stop_receiver,
+            ));
+
+            SharedSubscription {
+                id,
+                sender,
+                last: None,
+                replay_last,
+                subscribers: 0,
+                _stop: stop_sender,
+            }
+        });
+        subscription.subscribers = subscription.subscribers.saturating_add(1);
+
+        let guard = Subscriber {
+            fan_out: self.clone(),
+            key,
+            id: subscription.id,
+            client,
+        };
+        let live = BroadcastStream::new(subscription.sender.subscribe()).scan(
+            false,
+            |lagged, event| {
+                if *lagged {
+                    return futures::future::ready(None)
+                }
+                let payload = match event {
+                    Ok(payload) => payload,
+                    Err(BroadcastStreamRecvError::Lagged(skipped)) => {
+                        // The subscriber missed events, so it can't follow the subscription
+                        // anymore. It receives the error as the last event.
+                        *lagged = true;
+                        lagged_payload(skipped)
+                    }
+                };
+                futures::future::ready(Some(payload))
+            },
+        );
+
+        let stream = futures::stream::iter(subscription.last.clone())
+            .chain(live)
+            .map(move |payload| {
+                // The subscriber is active while the stream is alive.
+                let _ = &guard;
+                payload
+            });
+        Some(stream)
+    }
+
+    async fn publish<S>(
+        self,
+        key: String,
+        id: u64,
+        upstream: S,
+        mut stop: oneshot::Receiver<()>,
+    ) where
+        S: Stream<Item = Response> + Send + 'static,
+    {
+        let mut upstream = Box::pin(upstream);
+        loop {
+            let response = tokio::select! {
+                _ = &mut stop => return,
+                response = upstream.next() => response,
+            };
+
+            let Some(response) = response else { break };
+            let payload: Payload = match serde_json::to_string(&response) {
+                Ok(payload) => payload.into(),
+                Err(err) => {
+                    tracing::error!("Failed to serialize the subscription event: {err}");
+                    break
+                }
+            };
+
+            let mut subscriptions = self.subscriptions.lock().expect("poisoned");
+            match subscriptions.active.get_mut(&key) {
+                Some(subscription) if subscription.id == id => {
+                    if subscription.replay_last {
+                        subscription.last = Some(payload.clone());
+                    }
+                    let _ = subscription.sender.send(payload);
+                }
+                _ => return,
+            }
+        }
+
+        // The subscription is finished, so new subscribers should start a new one.
+        // Current subscribers receive the remaining events and the end of the stream.
+        self.remove(&key, id);
+    }
+
+    fn remove(&self, key: &str, id: u64) {
+        let mut subscriptions = self.subscriptions.lock().expect("poisoned");
+        if matches!(subscriptions.active.get(key), Some(subscription) if subscription.id == id)
+        {
+            subscriptions.active.remove(key);
+        }
+    }
+
+    #[cfg(test)]
+    fn active(&self) -> usize {
+        self.subscriptions.lock().expect("poisoned").active.len()
+    }
+}
+
+/// Unsubscribes from the shared subscription on drop. The subscription is stopped
+/// when the last subscriber unsubscribes.
+struct Subscriber {
+    fan_out: SubscriptionFanOut,
+    key: String,
+    id: u64,
+    client: ClientKey,
+}
+
+impl Drop for Subscriber {
+    fn drop(&mut self) {
+        let mut subscriptions = self.fan_out.subscriptions.lock().expect("poisoned");
+        if let Some(count) = subscriptions.clients.get_mut(&self.client) {
+            *count = count.saturating_sub(1);
+            if *count == 0 {
+                subscriptions.clients.remove(&self.client);
+            }
+        }
+        if let Some(subscription) = subscriptions.active.get_mut(&self.key) {
+            if subscription.id == self.id {
+                subscription.subscribers = subscription.subscribers.saturating_sub(1);
+                if subscription.subscribers == 0 {
+                    subscriptions.active.remove(&self.key);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_graphql::{
+        value,
+        Variables,
+    };
+    use std::{
+        net::Ipv4Addr,
+        sync::atomic::{
+            AtomicUsize,
+            Ordering,
+        },
+    };
+    use tokio::sync::mpsc;
+    use tokio_stream::wrappers::UnboundedReceiverStream;
+
+    fn response(status: &str) -> Response {
+        Response::new(value!({ "statusChange": { "type": status } }))
+    }
+
+    fn payload(status: &str) -> Payload {
+        serde_json::to_string(&response(status)).unwrap().into()
+    }
+
+    fn key(key: &str, replay_last: bool) -> ShareableKey {
+        ShareableKey {
+            key: key.to_string(),
+            replay_last,
+        }
+    }
+
+    fn client(last: u8) -> ClientKey {
+        ClientKey::Ip(Ipv4Addr::new(127, 0, 0, last).into())
+    }
+
+    #[test]
+    fn status_change_is_shareable() {
+        let request = Request::new(
+            "subscription($id: TransactionId!) { statusChange(id: $id) { type } }",
+        )
+        .variables(Variables::from_json(serde_json::json!({ "id": "0x01" })));
+
+        assert!(shareable_key(&request).is_some());
+    }
+
+    #[test]
+    fn activity_is_shareable_without_replay() {
+        let request = Request::new(
+            "subscription($id: AssetId!) { assetActivity(assetId: $id) { amount } }",
+        )
+        .variables(Variables::from_json(serde_json::json!({ "id": "0x01" })));
+
+        let key = shareable_key(&request).expect("Should be shareable");
+
+        assert!(!key.replay_last);
+    }
+
+    #[test]
+    fn submit_and_await_is_not_shareable() {
+        let request =
+            Request::new("subscription { submitAndAwait(tx: \"0x00\") { type } }");
+
+        assert_eq!(shareable_key(&request), None);
+    }
+
+    #[test]
+    fn keys_of_different_variables_differ() {
+        let query =
+            "subscription($id: TransactionId!) { statusChange(id: $id) { type } }";
+        let first = Request::new(query)
+            .variables(Variables::from_json(serde_json::json!({ "id": "0x01" })));
+        let second = Request::new(query)
+            .variables(Variables::from_json(serde_json::json!({ "id": "0x02" })));
+
+        assert_ne!(shareable_key(&first), shareable_key(&second));
+    }
+
+    #[tokio::test]
+    async fn subscribers_share_one_upstream() {
+        let fan_out = SubscriptionFanOut::new(usize::MAX);
+        let executions = Arc::new(AtomicUsize::new(0));
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let mut receiver = Some(receiver);
+        let mut subscribe = || {
+            let executions = executions.clone();
+            let receiver = receiver.take();
+            Box::pin(
+                fan_out
+                    .subscribe(key("key", true), client(1), move || {
+                        executions.fetch_add(1, Ordering::SeqCst);
+                        UnboundedReceiverStream::new(
+                            receiver.expect("Executed only once"),
+                        )
+                    })
+                    .unwrap(),
+            )
+        };
+        let mut first = subscribe();
+        let mut second = subscribe();
+
+        sender.send(response("Submitted")).unwrap();
+        assert_eq!(first.next().await, Some(payload("Submitted")));
+        assert_eq!(second.next().await, Some(payload("Submitted")));
+
+        // The late subscriber receives the last event first.
+        let mut late = subscribe();
+        assert_eq!(late.next().await, Some(payload("Submitted")));
+
+        sender.send(response("Success")).unwrap();
+        drop(sender);
+        for subscriber in [&mut first, &mut second, &mut late] {
+            assert_eq!(subscriber.next().await, Some(payload("Success")));
+            assert_eq!(subscriber.next().await, None);
+        }
+        assert_eq!(executions.load(Ordering::SeqCst), 1);
+        assert_eq!(fan_out.active(), 0);
+    }
+
+    #[tokio::test]
+    async fn late_subscriber_does_not_receive_past_events_without_replay() {
+        let fan_out = SubscriptionFanOut::new(usize::MAX);
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let mut first = Box::pin(
+            fan_out
+                .subscribe(key("key", false), client(1), || {
+                    UnboundedReceiverStream::new(receiver)
+                })
+                .unwrap(),
+        );
+
+        // Given
+        sender.send(response("Submitted")).unwrap();
+        assert_eq!(first.next().await, Some(payload("Submitted")));
+
+        // When
+        let mut late = Box::pin(
+            fan_out
+                .subscribe(key("key", false), client(2), futures::stream::pending)
+                .unwrap(),
+        );
+        sender.send(response("Success")).unwrap();
+
+        // Then
+        assert_eq!(late.next().await, Some(payload("Success")));
+        assert_eq!(first.next().await, Some(payload("Success")));
+    }
+
+    #[tokio::test]
+    async fn lagged_subscriber_receives_error_before_end_of_stream() {
+        let fan_out = SubscriptionFanOut::new(usize::MAX);
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let mut subscriber = Box::pin(
+            fan_out
+                .subscribe(key("key", true), client(1), || {
+                    UnboundedReceiverStream::new(receiver)
+                })
+                .unwrap(),
+        );
+
+        // Given
+        for _ in 0..=EVENTS_BUFFER {
+            sender.send(response("Submitted")).unwrap();
+        }
+        drop(sender);
+        while fan_out.active() != 0 {
+            tokio::task::yield_now().await;
+        }
+
+        // When
+        let event = subscriber.next().await;
+
+        // Then
+        assert_eq!(event, Some(lagged_payload(1)));
+        assert_eq!(subscriber.next().await, None);
+    }
+
+    #[tokio::test]
+    async fn upstream_is_stopped_after_last_subscriber() {
+        let fan_out = SubscriptionFanOut::new(usize::MAX);
+        let (sender, receiver) = mpsc::unbounded_channel::<Response>();
+        let first = fan_out
+            .subscribe(key("key", true), client(1), || {
+                UnboundedReceiverStream::new(receiver)
+            })
+            .unwrap();
+        let second = fan_out
+            .subscribe(key("key", true), client(2), futures::stream::empty)
+            .unwrap();
+
+        drop(first);
+        assert_eq!(fan_out.active(), 1);
+        drop(second);
+        assert_eq!(fan_out.active(), 0);
+
+        tokio::time::timeout(std::time::Duration::from_secs(10), sender.closed())
+            .await
+            .expect("The upstream should be dropped");
+    }
+
+    #[tokio::test]
+    async fn subscriptions_are_limited_per_client() {
+        let fan_out = SubscriptionFanOut::new(1);
+        let first = fan_out
+            .subscribe(key("first", true), client(1), futures::stream::pending)
+            .unwrap();
+
+        // The shared subscription counts for each of its subscribers.
+        assert!(fan_out
+            .subscribe(key("first", true), client(1), futures::stream::pending)
+            .is_none());
+        assert!(fan_out
+            .subscribe(key("second", true), client(2), futures::stream::pending)
+            .is_some());
+
+        drop(first);
+        assert!(fan_out
+            .subscribe(key("second", true), client(1), futures::stream::pending)
+            .is_some());
+    }
+}
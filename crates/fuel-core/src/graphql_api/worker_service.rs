@@ -8,6 +8,10 @@ use crate::{
         metadata::MetadataTable,
     },
     fuel_core_graphql_api::{
+        activity::{
+            ActivityBroadcast,
+            BlockActivity,
+        },
         ports,
         storage::receipts::Receipts,
     },
@@ -15,7 +19,6 @@ use crate::{
 use fuel_core_metrics::graphql_metrics::graphql_metrics;
 use fuel_core_services::{
     stream::BoxStream,
-    EmptyShared,
     RunnableService,
     RunnableTask,
     ServiceRunner,
@@ -41,12 +44,10 @@ use fuel_core_types::{
         Receipt,
         Transaction,
         TxId,
+        TxPointer,
         UniqueIdentifier,
     },
-    fuel_types::{
-        BlockHeight,
-        Bytes32,
-    },
+    fuel_types::Address,
     services::{
         block_importer::{
             ImportResult,
@@ -66,6 +67,8 @@ use futures::{
 pub struct Task<D> {
     block_importer: BoxStream<SharedImportResult>,
     database: D,
+    /// Publishes the activity of assets and contracts of committed blocks.
+    activity: ActivityBroadcast,
 }
 
 impl<D> Task<D>
@@ -83,6 +86,9 @@ where
 
         // save the associated owner for each transaction in the block
         self.index_tx_owners_for_block(block, transaction.as_mut())?;
+
+        // save the contract storage slots written by the block
+        self.persist_state_changes(&result, transaction.as_mut())?;
         let total_tx_count = transaction
             .as_mut()
             .increase_tx_count(block.transactions().len() as u64)
@@ -107,6 +113,11 @@ where
         // update the importer metrics after the block is successfully committed
         graphql_metrics().total_txs_count.set(total_tx_count as i64);
 
+        if self.activity.has_subscribers() {
+            self.activity
+                .publish(BlockActivity::from_import_result(&result));
+        }
+
         Ok(())
     }
 
@@ -118,75 +129,21 @@ where
     ) -> anyhow::Result<()> {
         for (tx_idx, tx) in block.transactions().iter().enumerate() {
             let block_height = *block.header().height();
-            let inputs;
-            let outputs;
             let tx_idx = u16::try_from(tx_idx).map_err(|e| {
                 anyhow::anyhow!("The block has more than `u16::MAX` transactions, {}", e)
             })?;
             let tx_id = tx.cached_id().expect(
                 "The imported block should contains only transactions with cached id",
             );
-            match tx {
-                Transaction::Script(tx) => {
-                    inputs = tx.inputs().as_slice();
-                    outputs = tx.outputs().as_slice();
-                }
-                Transaction::Create(tx) => {
-                    inputs = tx.inputs().as_slice();
-                    outputs = tx.outputs().as_slice();
-                }
-                Transaction::Mint(_) => continue,
-            }
-            self.persist_owners_index(
-                block_height,
-                inputs,
-                outputs,
-                &tx_id,
-                tx_idx,
-                block_st_transaction,
-            )?;
-        }
-        Ok(())
-    }
-
-    /// Index the tx id by owner for all of the inputs and outputs
-    fn persist_owners_index(
-        &self,
-        block_height: BlockHeight,
-        inputs: &[Input],
-        outputs: &[Output],
-        tx_id: &Bytes32,
-        tx_idx: u16,
-        db: &mut D,
-    ) -> StorageResult<()> {
-        let mut owners = vec![];
-        for input in inputs {
-            if let Input::CoinSigned(CoinSigned { owner, .. })
-            | Input::CoinPredicate(CoinPredicate { owner, .. }) = input
-            {
-                owners.push(owner);
-            }
-        }
-
-        for output in outputs {
-            match output {
-                Output::Coin { to, .. }
-                | Output::Change { to, .. }
-                | Output::Variable { to, .. } => {
-                    owners.push(to);
-                }
-                Output::Contract(_) | Output::ContractCreated { .. } => {}
+            for owner in transaction_owners(tx) {
+                block_st_transaction.record_tx_id_owner(
+                    &owner,
+                    block_height,
+                    tx_idx,
+                    &tx_id,
+                )?;
             }
         }
-
-        // dedupe owners from inputs and outputs prior to indexing
-        owners.sort();
-        owners.dedup();
-
-        for owner in owners {
-            db.record_tx_id_owner(owner, block_height, tx_idx, tx_id)?;
-        }
-
         Ok(())
     }
 
@@ -195,18 +152,29 @@ where
         import_result: &ImportResult,
         db: &mut D,
     ) -> StorageResult<()> {
-        for TransactionExecutionStatus {
-            id,
-            result,
-            receipts,
-        } in import_result.tx_status.iter()
+        let block_height = *import_result.sealed_block.entity.header().height();
+        // Statuses follow the order of transactions in the block.
+        for (
+            tx_idx,
+            TransactionExecutionStatus {
+                id,
+                result,
+                receipts,
+            },
+        ) in import_result.tx_status.iter().enumerate()
         {
+            let tx_idx = u16::try_from(tx_idx).map_err(|e| {
+                anyhow::anyhow!("The block has more than `u16::MAX` transactions, {}", e)
+            })?;
             let status = from_executor_to_status(
                 &import_result.sealed_block.entity,
                 result.clone(),
             );
 
-            if db.update_tx_status(id, status)?.is_some() {
+            if db
+                .update_tx_status(id, TxPointer::new(block_height, tx_idx), status)?
+                .is_some()
+            {
                 return Err(anyhow::anyhow!(
                     "Transaction status already exists for tx {}",
                     id
@@ -219,6 +187,18 @@ where
         Ok(())
     }
 
+    fn persist_state_changes(
+        &self,
+        import_result: &ImportResult,
+        db: &mut D,
+    ) -> StorageResult<()> {
+        let block_height = *import_result.sealed_block.entity.header().height();
+        for change in import_result.state_changes.iter() {
+            db.record_contract_state_change(block_height, change)?;
+        }
+        Ok(())
+    }
+
     fn persist_receipts(
         &self,
         tx_id: &TxId,
@@ -232,18 +212,53 @@ where
     }
 }
 
+/// Returns the owners of coins spent and created by the transaction, without duplicates.
+/// The owners index of the transaction is built from them.
+pub(crate) fn transaction_owners(tx: &Transaction) -> Vec<Address> {
+    let (inputs, outputs) = match tx {
+        Transaction::Script(tx) => (tx.inputs().as_slice(), tx.outputs().as_slice()),
+        Transaction::Create(tx) => (tx.inputs().as_slice(), tx.outputs().as_slice()),
+        Transaction::Mint(_) => return vec![],
+    };
+
+    let mut owners = vec![];
+    for input in inputs {
+        if let Input::CoinSigned(CoinSigned { owner, .. })
+        | Input::CoinPredicate(CoinPredicate { owner, .. }) = input
+        {
+            owners.push(*owner);
+        }
+    }
+
+    for output in outputs {
+        match output {
+            Output::Coin { to, .. }
+            | Output::Change { to, .. }
+            | Output::Variable { to, .. } => {
+                owners.push(*to);
+            }
+            Output::Contract(_) | Output::ContractCreated { .. } => {}
+        }
+    }
+
+    // dedupe owners from inputs and outputs prior to indexing
+    owners.sort();
+    owners.dedup();
+    owners
+}
+
 #[async_trait::async_trait]
 impl<D> RunnableService for Task<D>
 where
     D: ports::worker::OffChainDatabase,
 {
     const NAME: &'static str = "GraphQL_Off_Chain_Worker";
-    type SharedData = EmptyShared;
+    type SharedData = ActivityBroadcast;
     type Task = Self;
     type TaskParams = ();
 
     fn shared_data(&self) -> Self::SharedData {
-        EmptyShared
+        self.activity.clone()
     }
 
     async fn into_task(
@@ -316,5 +331,6 @@ where
     ServiceRunner::new(Task {
         block_importer,
         database,
+        activity: ActivityBroadcast::default(),
     })
 }
@@ -11,6 +11,8 @@ pub use fuel_core_chain_config as chain_config;
 #[doc(no_inline)]
 pub use fuel_core_p2p as p2p;
 #[doc(no_inline)]
+pub use fuel_core_poa as poa;
+#[doc(no_inline)]
 pub use fuel_core_producer as producer;
 #[cfg(feature = "relayer")]
 #[doc(no_inline)]
@@ -23,6 +25,7 @@ pub use fuel_core_txpool as txpool;
 #[doc(no_inline)]
 pub use fuel_core_types as types;
 
+pub mod block_archive;
 pub mod coins_query;
 pub mod combined_database;
 pub mod database;
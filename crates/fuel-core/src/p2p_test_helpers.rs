@@ -45,7 +45,10 @@ use fuel_core_types::{
         ChainId,
     },
     secrecy::Secret,
-    services::p2p::GossipsubMessageAcceptance,
+    services::{
+        p2p::GossipsubMessageAcceptance,
+        txpool::TxOrigin,
+    },
 };
 use futures::StreamExt;
 use itertools::Itertools;
@@ -261,11 +264,10 @@ pub async fn make_nodes(
                         config.clone(),
                     );
                     if let Some(BootstrapSetup { pub_key, .. }) = boot {
-                        match &mut node_config.chain_conf.consensus {
-                            crate::chain_config::ConsensusConfig::PoA { signing_key } => {
-                                *signing_key = pub_key;
-                            }
-                        }
+                        node_config.chain_conf.consensus =
+                            crate::chain_config::ConsensusConfig::PoA {
+                                signing_key: pub_key,
+                            };
                     }
                     Bootstrap::new(&node_config).await
                 }
@@ -310,11 +312,10 @@ pub async fn make_nodes(
 
             node_config.utxo_validation = utxo_validation;
             let pub_key = secret.public_key();
-            match &mut node_config.chain_conf.consensus {
-                crate::chain_config::ConsensusConfig::PoA { signing_key } => {
-                    *signing_key = Input::owner(&pub_key);
-                }
-            }
+            node_config.chain_conf.consensus =
+                crate::chain_config::ConsensusConfig::PoA {
+                    signing_key: Input::owner(&pub_key),
+                };
 
             node_config.consensus_key = Some(Secret::new(secret.into()));
 
@@ -354,11 +355,10 @@ pub async fn make_nodes(
                     node_config.p2p.as_mut().unwrap().reserved_nodes = boots.clone();
                 }
             }
-            match &mut node_config.chain_conf.consensus {
-                crate::chain_config::ConsensusConfig::PoA { signing_key } => {
-                    *signing_key = pub_key;
-                }
-            }
+            node_config.chain_conf.consensus =
+                crate::chain_config::ConsensusConfig::PoA {
+                    signing_key: pub_key,
+                };
         }
         validators.push(make_node(node_config, Vec::with_capacity(0)).await)
     }
@@ -475,7 +475,7 @@ impl Node {
                 .node
                 .shared
                 .txpool
-                .insert(vec![Arc::new(tx.clone())])
+                .insert(vec![Arc::new(tx.clone())], TxOrigin::Injected)
                 .await
                 .pop()
                 .unwrap()
@@ -0,0 +1,160 @@
+use fuel_core_storage::Error as StorageError;
+use fuel_core_types::fuel_types::Word;
+use std::fmt::{
+    Display,
+    Formatter,
+};
+
+/// The amount of an asset. The arithmetic on amounts is checked and returns
+/// [`AmountOverflow`] instead of wrapping or saturating on extreme values.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Amount(Word);
+
+impl Amount {
+    pub const ZERO: Self = Self(0);
+    pub const MAX: Self = Self(Word::MAX);
+
+    pub const fn new(amount: Word) -> Self {
+        Self(amount)
+    }
+
+    pub const fn get(self) -> Word {
+        self.0
+    }
+
+    pub fn checked_add(self, rhs: impl Into<Amount>) -> Result<Self, AmountOverflow> {
+        let rhs = rhs.into();
+        self.0
+            .checked_add(rhs.0)
+            .map(Self)
+            .ok_or(AmountOverflow::new(AmountOperation::Add, self, rhs))
+    }
+
+    pub fn checked_sub(self, rhs: impl Into<Amount>) -> Result<Self, AmountOverflow> {
+        let rhs = rhs.into();
+        self.0
+            .checked_sub(rhs.0)
+            .map(Self)
+            .ok_or(AmountOverflow::new(AmountOperation::Sub, self, rhs))
+    }
+
+    pub fn checked_mul(self, rhs: impl Into<Amount>) -> Result<Self, AmountOverflow> {
+        let rhs = rhs.into();
+        self.0
+            .checked_mul(rhs.0)
+            .map(Self)
+            .ok_or(AmountOverflow::new(AmountOperation::Mul, self, rhs))
+    }
+
+    /// Sums the `amounts`, failing on the first overflow.
+    pub fn checked_sum<I, A>(amounts: I) -> Result<Self, AmountOverflow>
+    where
+        I: IntoIterator<Item = A>,
+        A: Into<Amount>,
+    {
+        amounts
+            .into_iter()
+            .try_fold(Self::ZERO, |sum, amount| sum.checked_add(amount))
+    }
+}
+
+impl From<Word> for Amount {
+    fn from(amount: Word) -> Self {
+        Self(amount)
+    }
+}
+
+impl From<Amount> for Word {
+    fn from(amount: Amount) -> Self {
+        amount.0
+    }
+}
+
+impl Display for Amount {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// The arithmetic operation on amounts.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AmountOperation {
+    Add,
+    Sub,
+    Mul,
+}
+
+impl AmountOperation {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AmountOperation::Add => "add",
+            AmountOperation::Sub => "sub",
+            AmountOperation::Mul => "mul",
+        }
+    }
+}
+
+/// The result of the arithmetic operation on amounts doesn't fit into `u64`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, thiserror::Error)]
+#[error("the amount overflowed during `{lhs} {} {rhs}`", operation.as_str())]
+pub struct AmountOverflow {
+    pub operation: AmountOperation,
+    pub lhs: Amount,
+    pub rhs: Amount,
+}
+
+impl AmountOverflow {
+    const fn new(operation: AmountOperation, lhs: Amount, rhs: Amount) -> Self {
+        Self {
+            operation,
+            lhs,
+            rhs,
+        }
+    }
+
+    /// Returns the overflow that caused the storage `error`, if any.
+    pub fn from_storage_error(error: &StorageError) -> Option<&Self> {
+        match error {
+            StorageError::Other(error) => error.downcast_ref(),
+            _ => None,
+        }
+    }
+}
+
+impl From<AmountOverflow> for StorageError {
+    fn from(overflow: AmountOverflow) -> Self {
+        StorageError::Other(overflow.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checked_sum_fails_on_overflow() {
+        assert_eq!(Amount::checked_sum([1u64, 2, 3]), Ok(Amount::new(6)));
+        assert_eq!(
+            Amount::checked_sum([Word::MAX, 1, 2]),
+            Err(AmountOverflow {
+                operation: AmountOperation::Add,
+                lhs: Amount::MAX,
+                rhs: Amount::new(1),
+            })
+        );
+    }
+
+    #[test]
+    fn checked_sub_fails_on_underflow() {
+        assert_eq!(Amount::new(3).checked_sub(2u64), Ok(Amount::new(1)));
+        assert!(Amount::new(2).checked_sub(3u64).is_err());
+    }
+
+    #[test]
+    fn overflow_is_found_in_storage_error() {
+        let overflow = Amount::MAX.checked_mul(2u64).unwrap_err();
+        let error = StorageError::from(overflow);
+
+        assert_eq!(AmountOverflow::from_storage_error(&error), Some(&overflow));
+    }
+}
@@ -1,4 +1,10 @@
-use crate::fuel_core_graphql_api::database::ReadView;
+use crate::{
+    fuel_core_graphql_api::{
+        database::ReadView,
+        ports::OnChainDatabase,
+    },
+    query::Amount,
+};
 use asset_query::{
     AssetQuery,
     AssetSpendTarget,
@@ -50,27 +56,36 @@ impl BalanceQueryData for ReadView {
         asset_id: AssetId,
         base_asset_id: AssetId,
     ) -> StorageResult<AddressBalance> {
-        let amount = AssetQuery::new(
-            &owner,
-            &AssetSpendTarget::new(asset_id, u64::MAX, usize::MAX),
-            &base_asset_id,
-            None,
-            self,
-        )
-        .coins()
-        .map(|res| res.map(|coins| coins.amount()))
-        .try_fold(0u64, |mut balance, res| -> StorageResult<_> {
-            let amount = res?;
-
-            // Increase the balance
-            balance = balance.saturating_add(amount);
+        let amount = if !self.coin_balances_dirty()? {
+            let coins_amount = Amount::new(self.coin_balance(&owner, &asset_id)?);
+            let messages_amount = if asset_id == base_asset_id {
+                self.messages_amount(&owner, &base_asset_id)?
+            } else {
+                Amount::ZERO
+            };
+            coins_amount.checked_add(messages_amount)?
+        } else {
+            // The index is dirty, so the balance is computed from coins.
+            AssetQuery::new(
+                &owner,
+                &AssetSpendTarget::new(asset_id, u64::MAX, usize::MAX),
+                &base_asset_id,
+                None,
+                self,
+            )
+            .coins()
+            .map(|res| res.map(|coins| coins.amount()))
+            .try_fold(Amount::ZERO, |balance, res| -> StorageResult<_> {
+                let amount = res?;
 
-            Ok(balance)
-        })?;
+                // Increase the balance
+                Ok(balance.checked_add(amount)?)
+            })?
+        };
 
         Ok(AddressBalance {
             owner,
-            amount,
+            amount: amount.into(),
             asset_id,
         })
     }
@@ -80,21 +95,58 @@ impl BalanceQueryData for ReadView {
         owner: Address,
         direction: IterDirection,
         base_asset_id: AssetId,
+    ) -> BoxedIter<StorageResult<AddressBalance>> {
+        match self.coin_balances_dirty() {
+            Ok(false) => {
+                let messages_amount = self.messages_amount(&owner, &base_asset_id);
+                indexed_balances(
+                    owner,
+                    self.coin_balances(&owner, direction),
+                    messages_amount,
+                    direction,
+                    base_asset_id,
+                )
+                .into_boxed()
+            }
+            // The index is dirty, so the balances are computed from coins.
+            Ok(true) => self.balances_from_coins(owner, direction, base_asset_id),
+            Err(err) => core::iter::once(Err(err)).into_boxed(),
+        }
+    }
+}
+
+impl ReadView {
+    fn messages_amount(
+        &self,
+        owner: &Address,
+        base_asset_id: &AssetId,
+    ) -> StorageResult<Amount> {
+        AssetsQuery::new(owner, None, None, self, base_asset_id)
+            .messages()
+            .try_fold(Amount::ZERO, |balance, res| -> StorageResult<_> {
+                Ok(balance.checked_add(res?.amount())?)
+            })
+    }
+
+    fn balances_from_coins(
+        &self,
+        owner: Address,
+        direction: IterDirection,
+        base_asset_id: AssetId,
     ) -> BoxedIter<StorageResult<AddressBalance>> {
         let mut amounts_per_asset = HashMap::new();
         let mut errors = vec![];
 
         for coin in AssetsQuery::new(&owner, None, None, self, &base_asset_id).coins() {
-            match coin {
-                Ok(coin) => {
-                    let amount: &mut u64 = amounts_per_asset
-                        .entry(*coin.asset_id(&base_asset_id))
-                        .or_default();
-                    *amount = amount.saturating_add(coin.amount());
-                }
-                Err(err) => {
-                    errors.push(err);
-                }
+            let amount = coin.and_then(|coin| {
+                let amount: &mut Amount = amounts_per_asset
+                    .entry(*coin.asset_id(&base_asset_id))
+                    .or_default();
+                *amount = amount.checked_add(coin.amount())?;
+                Ok(())
+            });
+            if let Err(err) = amount {
+                errors.push(err);
             }
         }
 
@@ -102,7 +154,7 @@ impl BalanceQueryData for ReadView {
             .into_iter()
             .map(|(asset_id, amount)| AddressBalance {
                 owner,
-                amount,
+                amount: amount.into(),
                 asset_id,
             })
             .collect_vec();
@@ -126,3 +178,178 @@ impl BalanceQueryData for ReadView {
             .into_boxed()
     }
 }
+
+/// Merges the amount of message coins into the balance of the base asset
+/// from the balances index, keeping the order of the index.
+fn indexed_balances(
+    owner: Address,
+    coin_balances: BoxedIter<StorageResult<(AssetId, u64)>>,
+    messages_amount: StorageResult<Amount>,
+    direction: IterDirection,
+    base_asset_id: AssetId,
+) -> impl Iterator<Item = StorageResult<AddressBalance>> {
+    let (mut messages_amount, messages_error) = match messages_amount {
+        Ok(amount) => (amount, None),
+        Err(err) => (Amount::ZERO, Some(err)),
+    };
+    let mut balances = vec![];
+    for result in coin_balances {
+        let balance = result.and_then(|(asset_id, amount)| {
+            let mut amount = Amount::new(amount);
+            if asset_id == base_asset_id {
+                amount = amount.checked_add(core::mem::take(&mut messages_amount))?;
+            }
+            Ok(AddressBalance {
+                owner,
+                amount: amount.into(),
+                asset_id,
+            })
+        });
+        balances.push(balance);
+    }
+
+    if messages_amount > Amount::ZERO {
+        // The owner doesn't have coins of the base asset, only message coins.
+        let position = balances
+            .iter()
+            .position(|balance| {
+                balance.as_ref().map_or(false, |balance| match direction {
+                    IterDirection::Forward => balance.asset_id > base_asset_id,
+                    IterDirection::Reverse => balance.asset_id < base_asset_id,
+                })
+            })
+            .unwrap_or(balances.len());
+        balances.insert(
+            position,
+            Ok(AddressBalance {
+                owner,
+                amount: messages_amount.into(),
+                asset_id: base_asset_id,
+            }),
+        );
+    }
+
+    balances.into_iter().chain(messages_error.map(Err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_storage::Error as StorageError;
+
+    const OWNER: Address = Address::new([1; 32]);
+    const BASE_ASSET_ID: AssetId = AssetId::new([2; 32]);
+
+    fn balances(
+        coin_balances: Vec<(AssetId, u64)>,
+        messages_amount: StorageResult<Amount>,
+        direction: IterDirection,
+    ) -> Vec<StorageResult<(AssetId, u64)>> {
+        let coin_balances = coin_balances.into_iter().map(Ok).into_boxed();
+        indexed_balances(
+            OWNER,
+            coin_balances,
+            messages_amount,
+            direction,
+            BASE_ASSET_ID,
+        )
+        .map(|balance| balance.map(|balance| (balance.asset_id, balance.amount)))
+        .collect()
+    }
+
+    fn unwrap_all(balances: Vec<StorageResult<(AssetId, u64)>>) -> Vec<(AssetId, u64)> {
+        balances.into_iter().map(Result::unwrap).collect()
+    }
+
+    #[test]
+    fn indexed_balances__adds_messages_to_the_base_asset() {
+        // Given
+        let coin_balances = vec![
+            (AssetId::new([1; 32]), 10),
+            (BASE_ASSET_ID, 20),
+            (AssetId::new([3; 32]), 30),
+        ];
+
+        // When
+        let balances =
+            balances(coin_balances, Ok(Amount::new(5)), IterDirection::Forward);
+
+        // Then
+        assert_eq!(
+            unwrap_all(balances),
+            vec![
+                (AssetId::new([1; 32]), 10),
+                (BASE_ASSET_ID, 25),
+                (AssetId::new([3; 32]), 30),
+            ]
+        );
+    }
+
+    #[test]
+    fn indexed_balances__inserts_messages_in_the_order_of_assets() {
+        // Given
+        let coin_balances =
+            vec![(AssetId::new([1; 32]), 10), (AssetId::new([3; 32]), 30)];
+
+        // When
+        let forward = balances(
+            coin_balances.clone(),
+            Ok(Amount::new(5)),
+            IterDirection::Forward,
+        );
+        let reverse = balances(
+            coin_balances.into_iter().rev().collect(),
+            Ok(Amount::new(5)),
+            IterDirection::Reverse,
+        );
+
+        // Then
+        assert_eq!(
+            unwrap_all(forward),
+            vec![
+                (AssetId::new([1; 32]), 10),
+                (BASE_ASSET_ID, 5),
+                (AssetId::new([3; 32]), 30),
+            ]
+        );
+        assert_eq!(
+            unwrap_all(reverse),
+            vec![
+                (AssetId::new([3; 32]), 30),
+                (BASE_ASSET_ID, 5),
+                (AssetId::new([1; 32]), 10),
+            ]
+        );
+    }
+
+    #[test]
+    fn indexed_balances__appends_messages_after_the_last_asset() {
+        // Given
+        let coin_balances = vec![(AssetId::new([1; 32]), 10)];
+
+        // When
+        let balances =
+            balances(coin_balances, Ok(Amount::new(5)), IterDirection::Forward);
+
+        // Then
+        assert_eq!(
+            unwrap_all(balances),
+            vec![(AssetId::new([1; 32]), 10), (BASE_ASSET_ID, 5)]
+        );
+    }
+
+    #[test]
+    fn indexed_balances__returns_the_error_of_messages_last() {
+        // Given
+        let coin_balances = vec![(BASE_ASSET_ID, 20)];
+        let error = StorageError::Other(anyhow::anyhow!("Messages are unavailable"));
+
+        // When
+        let balances = balances(coin_balances, Err(error), IterDirection::Forward);
+
+        // Then
+        assert_eq!(balances.len(), 2);
+        assert_eq!(balances[0].as_ref().unwrap(), &(BASE_ASSET_ID, 20));
+        assert!(balances[1].is_err());
+    }
+}
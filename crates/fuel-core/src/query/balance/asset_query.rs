@@ -158,12 +158,17 @@ impl<'a> AssetsQuery<'a> {
     // TODO: Optimize this by creating an index
     //  https://github.com/FuelLabs/fuel-core/issues/588
     pub fn coins(&self) -> impl Iterator<Item = StorageResult<CoinType>> + '_ {
+        self.coins_iter().chain(self.messages())
+    }
+
+    /// Returns the iterator over all valid(spendable, allowed by `exclude`) message coins
+    /// of the `owner`. It is empty if the base asset is not allowed.
+    pub fn messages(&self) -> impl Iterator<Item = StorageResult<CoinType>> + '_ {
         let has_base_asset = self.has_asset(self.base_asset_id);
-        let messages_iter = has_base_asset
+        has_base_asset
             .then(|| self.messages_iter())
             .into_iter()
-            .flatten();
-        self.coins_iter().chain(messages_iter)
+            .flatten()
     }
 }
 
@@ -22,7 +22,10 @@
 use fuel_core_txpool::service::TxStatusMessage;
 use fuel_core_types::{
     fuel_types::Bytes32,
-    services::txpool::TransactionStatus,
+    services::txpool::{
+        TransactionStatus,
+        TxOrigin,
+    },
     tai64::Tai64,
 };
 use futures::StreamExt;
@@ -46,7 +49,10 @@ fn txn_id(i: u8) -> Bytes32 {
 
 /// Returns a TransactionStatus with Submitted status and time set to 0
 fn submitted() -> TransactionStatus {
-    TransactionStatus::Submitted { time: Tai64(0) }
+    TransactionStatus::Submitted {
+        time: Tai64(0),
+        origin: TxOrigin::Injected,
+    }
 }
 
 /// Returns a TransactionStatus with Success status, time set to 0, and result set to None
@@ -7,6 +7,8 @@ use async_graphql::{
         Edge,
         EmptyFields,
     },
+    EmptyMutation,
+    EmptySubscription,
     MergedObject,
     MergedSubscription,
     OutputType,
@@ -19,6 +21,7 @@ use fuel_core_storage::{
 };
 use itertools::Itertools;
 
+pub mod activity;
 pub mod balance;
 pub mod block;
 pub mod chain;
@@ -27,6 +30,7 @@ pub mod contract;
 pub mod dap;
 pub mod health;
 pub mod message;
+pub mod network_info;
 pub mod node_info;
 pub mod scalars;
 pub mod tx;
@@ -43,6 +47,7 @@ pub struct Query(
     contract::ContractQuery,
     contract::ContractBalanceQuery,
     node_info::NodeQuery,
+    network_info::NetworkInfoQuery,
     message::MessageQuery,
 );
 
@@ -50,7 +55,7 @@ pub struct Query(
 pub struct Mutation(dap::DapMutation, tx::TxMutation, block::BlockMutation);
 
 #[derive(MergedSubscription, Default)]
-pub struct Subscription(tx::TxStatusSubscription);
+pub struct Subscription(tx::TxStatusSubscription, activity::ActivitySubscription);
 
 pub type CoreSchema = Schema<Query, Mutation, Subscription>;
 pub type CoreSchemaBuilder = SchemaBuilder<Query, Mutation, Subscription>;
@@ -64,6 +69,29 @@ pub fn build_schema() -> CoreSchemaBuilder {
     )
 }
 
+pub type ReadOnlySchema = Schema<Query, EmptyMutation, EmptySubscription>;
+pub type ReadOnlySchemaBuilder = SchemaBuilder<Query, EmptyMutation, EmptySubscription>;
+
+/// Builds the schema of the node that doesn't modify its database, like the read replica.
+/// It has no mutations, and no subscriptions because the node neither imports blocks
+/// nor accepts transactions.
+pub fn build_read_only_schema() -> ReadOnlySchemaBuilder {
+    Schema::build_with_ignore_name_conflicts(
+        Query::default(),
+        EmptyMutation,
+        EmptySubscription,
+        ["TransactionConnection", "MessageConnection"],
+    )
+}
+
+/// The schema served by the GraphQL API of the node.
+pub enum NodeSchemaBuilder {
+    /// The schema of the node with queries, mutations and subscriptions.
+    Full(CoreSchemaBuilder),
+    /// The schema of the node that doesn't modify its database.
+    ReadOnly(ReadOnlySchemaBuilder),
+}
+
 async fn query_pagination<F, Entries, SchemaKey, SchemaValue>(
     after: Option<String>,
     before: Option<String>,
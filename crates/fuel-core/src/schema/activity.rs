@@ -0,0 +1,195 @@
+use crate::{
+    fuel_core_graphql_api::activity::{
+        self,
+        ActivityBroadcast,
+        Recipient,
+    },
+    schema::scalars::{
+        Address,
+        AssetId,
+        Bytes32,
+        ContractId,
+        TransactionId,
+        U32,
+        U64,
+    },
+};
+use async_graphql::{
+    Context,
+    Enum,
+    Object,
+    Subscription,
+};
+use futures::{
+    Stream,
+    StreamExt,
+};
+
+#[derive(Default)]
+pub struct ActivitySubscription;
+
+#[Subscription]
+impl ActivitySubscription {
+    /// Returns a stream of mints, burns and transfers of the asset in blocks
+    /// imported after the subscription.
+    ///
+    /// The stream is closed if it is polled slower than blocks are imported.
+    /// In such a case, the stream can be restarted, and the missed activity
+    /// can be found in receipts of transactions.
+    ///
+    /// The subscription is rejected if the client has too many active subscriptions.
+    async fn asset_activity<'a>(
+        &self,
+        ctx: &Context<'a>,
+        #[graphql(desc = "The ID of the asset")] asset_id: AssetId,
+    ) -> impl Stream<Item = AssetActivity> + 'a {
+        let broadcast = ctx.data_unchecked::<ActivityBroadcast>();
+        broadcast
+            .subscribe_asset(asset_id.into())
+            .map(AssetActivity)
+    }
+
+    /// Returns a stream of calls and storage writes of the contract in blocks
+    /// imported after the subscription.
+    ///
+    /// The stream is closed if it is polled slower than blocks are imported.
+    ///
+    /// The subscription is rejected if the client has too many active subscriptions.
+    async fn contract_activity<'a>(
+        &self,
+        ctx: &Context<'a>,
+        #[graphql(desc = "The ID of the contract")] contract_id: ContractId,
+    ) -> impl Stream<Item = ContractActivity> + 'a {
+        let broadcast = ctx.data_unchecked::<ActivityBroadcast>();
+        broadcast
+            .subscribe_contract(contract_id.into())
+            .map(ContractActivity)
+    }
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum AssetActivityKind {
+    Mint,
+    Burn,
+    Transfer,
+}
+
+impl From<activity::AssetActivityKind> for AssetActivityKind {
+    fn from(kind: activity::AssetActivityKind) -> Self {
+        match kind {
+            activity::AssetActivityKind::Mint => Self::Mint,
+            activity::AssetActivityKind::Burn => Self::Burn,
+            activity::AssetActivityKind::Transfer => Self::Transfer,
+        }
+    }
+}
+
+pub struct AssetActivity(activity::AssetActivity);
+
+#[Object]
+impl AssetActivity {
+    async fn block_height(&self) -> U32 {
+        self.0.block_height.into()
+    }
+
+    async fn transaction_id(&self) -> TransactionId {
+        self.0.tx_id.into()
+    }
+
+    async fn asset_id(&self) -> AssetId {
+        self.0.asset_id.into()
+    }
+
+    async fn kind(&self) -> AssetActivityKind {
+        self.0.kind.into()
+    }
+
+    async fn amount(&self) -> U64 {
+        self.0.amount.into()
+    }
+
+    /// The contract that minted, burned or transferred the asset.
+    /// `null` for coins created by outputs of the transaction.
+    async fn contract_id(&self) -> Option<ContractId> {
+        self.0.contract_id.map(Into::into)
+    }
+
+    /// The recipient address of the transfer.
+    async fn to_address(&self) -> Option<Address> {
+        match self.0.recipient {
+            Some(Recipient::Address(address)) => Some(address.into()),
+            _ => None,
+        }
+    }
+
+    /// The recipient contract of the transfer.
+    async fn to_contract(&self) -> Option<ContractId> {
+        match self.0.recipient {
+            Some(Recipient::Contract(contract_id)) => Some(contract_id.into()),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum ContractActivityKind {
+    Call,
+    StateChange,
+}
+
+pub struct ContractActivity(activity::ContractActivity);
+
+#[Object]
+impl ContractActivity {
+    async fn block_height(&self) -> U32 {
+        self.0.block_height.into()
+    }
+
+    async fn contract_id(&self) -> ContractId {
+        self.0.contract_id.into()
+    }
+
+    async fn kind(&self) -> ContractActivityKind {
+        match self.0.kind {
+            activity::ContractActivityKind::Call { .. } => ContractActivityKind::Call,
+            activity::ContractActivityKind::StateChange { .. } => {
+                ContractActivityKind::StateChange
+            }
+        }
+    }
+
+    /// The transaction that called the contract. `null` for storage writes,
+    /// which are reported for the whole block.
+    async fn transaction_id(&self) -> Option<TransactionId> {
+        match self.0.kind {
+            activity::ContractActivityKind::Call { tx_id, .. } => Some(tx_id.into()),
+            activity::ContractActivityKind::StateChange { .. } => None,
+        }
+    }
+
+    /// The contract that called the contract. `null` if it is called by the script.
+    async fn caller(&self) -> Option<ContractId> {
+        match self.0.kind {
+            activity::ContractActivityKind::Call { caller, .. } => caller.map(Into::into),
+            activity::ContractActivityKind::StateChange { .. } => None,
+        }
+    }
+
+    /// The key of the written storage slot.
+    async fn key(&self) -> Option<Bytes32> {
+        match self.0.kind {
+            activity::ContractActivityKind::StateChange { key, .. } => Some(key.into()),
+            activity::ContractActivityKind::Call { .. } => None,
+        }
+    }
+
+    /// The value of the slot after the block. `null` if the slot is removed.
+    async fn value(&self) -> Option<Bytes32> {
+        match self.0.kind {
+            activity::ContractActivityKind::StateChange { value, .. } => {
+                value.map(Into::into)
+            }
+            activity::ContractActivityKind::Call { .. } => None,
+        }
+    }
+}
@@ -3,11 +3,14 @@ use crate::{
         database::ReadView,
         Config,
     },
-    query::BalanceQueryData,
+    query::{
+        AmountOverflow,
+        BalanceQueryData,
+    },
     schema::scalars::{
         Address,
+        Amount,
         AssetId,
-        U64,
     },
 };
 use anyhow::anyhow;
@@ -17,10 +20,13 @@ use async_graphql::{
         EmptyFields,
     },
     Context,
+    ErrorExtensions,
     InputObject,
     Object,
 };
+use fuel_core_storage::Error as StorageError;
 use fuel_core_types::services::graphql_api;
+use std::sync::OnceLock;
 
 pub struct Balance(graphql_api::AddressBalance);
 
@@ -30,7 +36,7 @@ impl Balance {
         self.0.owner.into()
     }
 
-    async fn amount(&self) -> U64 {
+    async fn amount(&self) -> Amount {
         self.0.amount.into()
     }
 
@@ -61,7 +67,10 @@ impl BalanceQuery {
             .data_unchecked::<Config>()
             .consensus_parameters
             .base_asset_id();
-        let balance = query.balance(owner.0, asset_id.0, base_asset_id)?.into();
+        let balance = query
+            .balance(owner.0, asset_id.0, base_asset_id)
+            .map_err(balance_error)?
+            .into();
         Ok(balance)
     }
 
@@ -83,19 +92,49 @@ impl BalanceQuery {
             return Err(anyhow!("pagination is not yet supported").into())
         }
         let query: &ReadView = ctx.data_unchecked();
-        crate::schema::query_pagination(after, before, first, last, |_, direction| {
-            let owner = filter.owner.into();
-            let base_asset_id = *ctx
-                .data_unchecked::<Config>()
-                .consensus_parameters
-                .base_asset_id();
-            Ok(query
-                .balances(owner, direction, base_asset_id)
-                .map(|result| {
-                    result.map(|balance| (balance.asset_id.into(), balance.into()))
-                }))
-        })
-        .await
+        // The pagination hides the type of the error, so the overflow is kept aside
+        // to be reported as the structured error, like in `balance_error`.
+        let overflow = OnceLock::new();
+        let balances = crate::schema::query_pagination(
+            after,
+            before,
+            first,
+            last,
+            |_, direction| {
+                let owner = filter.owner.into();
+                let base_asset_id = *ctx
+                    .data_unchecked::<Config>()
+                    .consensus_parameters
+                    .base_asset_id();
+                Ok(query
+                    .balances(owner, direction, base_asset_id)
+                    .map(|result| {
+                        result
+                            .map(|balance| (balance.asset_id.into(), balance.into()))
+                            .map_err(|error| {
+                                if let Some(found) =
+                                    AmountOverflow::from_storage_error(&error)
+                                {
+                                    let _ = overflow.set(*found);
+                                }
+                                error
+                            })
+                    }))
+            },
+        )
+        .await;
+        match overflow.into_inner() {
+            Some(overflow) => Err(overflow.extend()),
+            None => balances,
+        }
+    }
+}
+
+/// Keeps the details of the amount overflow in the extensions of the GraphQL error.
+fn balance_error(error: StorageError) -> async_graphql::Error {
+    match AmountOverflow::from_storage_error(&error) {
+        Some(overflow) => overflow.extend(),
+        None => error.into(),
     }
 }
 
@@ -33,10 +33,13 @@ use async_graphql::{
         EmptyFields,
     },
     Context,
+    Enum,
+    InputObject,
     Object,
     SimpleObject,
     Union,
 };
+use fuel_core_poa::Trigger;
 use fuel_core_storage::{
     iter::{
         BoxedIter,
@@ -50,11 +53,18 @@ use fuel_core_types::{
         block::CompressedBlock,
         header::BlockHeader,
     },
+    fuel_tx,
     fuel_types,
     fuel_types::BlockHeight,
 };
+use std::time::Duration;
 
-pub struct Block(pub(crate) CompressedBlock);
+pub struct Block {
+    header: BlockHeader,
+    /// The ids of transactions of the block. If `None`, they are read on demand,
+    /// so queries selecting only the header don't read the body of the block.
+    transactions: Option<Vec<fuel_tx::TxId>>,
+}
 
 pub struct Header(pub(crate) BlockHeader);
 
@@ -88,17 +98,17 @@ pub struct PoAConsensus {
 #[Object]
 impl Block {
     async fn id(&self) -> BlockId {
-        let bytes: fuel_types::Bytes32 = self.0.header().id().into();
+        let bytes: fuel_types::Bytes32 = self.header.id().into();
         bytes.into()
     }
 
     async fn header(&self) -> Header {
-        self.0.header().clone().into()
+        self.header.clone().into()
     }
 
     async fn consensus(&self, ctx: &Context<'_>) -> async_graphql::Result<Consensus> {
         let query: &Database = ctx.data_unchecked();
-        let height = self.0.header().height();
+        let height = self.header.height();
         let core_consensus = query.consensus(height)?;
 
         let my_consensus = core_consensus.try_into()?;
@@ -110,8 +120,16 @@ impl Block {
         ctx: &Context<'_>,
     ) -> async_graphql::Result<Vec<Transaction>> {
         let query: &ReadView = ctx.data_unchecked();
-        self.0
-            .transactions()
+        let loaded;
+        let transactions = match &self.transactions {
+            Some(transactions) => transactions,
+            None => {
+                let block = query.block(self.header.height())?;
+                loaded = block.into_inner().1;
+                &loaded
+            }
+        };
+        transactions
             .iter()
             .map(|tx_id| {
                 let tx = query.transaction(tx_id)?;
@@ -212,7 +230,7 @@ impl BlockQuery {
         };
 
         height
-            .and_then(|height| query.block(&height))
+            .and_then(|height| query.block_header(&height))
             .into_api_result()
     }
 
@@ -246,7 +264,7 @@ impl HeaderQuery {
         Ok(BlockQuery {}
             .block(ctx, id, height)
             .await?
-            .map(|b| b.0.header().clone().into()))
+            .map(|b| b.header.into()))
     }
 
     async fn headers(
@@ -272,11 +290,11 @@ fn blocks_query<T>(
 ) -> BoxedIter<StorageResult<(U32, T)>>
 where
     T: async_graphql::OutputType,
-    T: From<CompressedBlock>,
+    T: From<BlockHeader>,
 {
-    let blocks = query.compressed_blocks(height, direction).map(|result| {
-        result.map(|block| ((*block.header().height()).into(), block.into()))
-    });
+    let blocks = query
+        .block_headers(height, direction)
+        .map(|result| result.map(|header| ((*header.height()).into(), header.into())));
 
     blocks.into_boxed()
 }
@@ -315,11 +333,106 @@ impl BlockMutation {
             .map(Into::into)
             .map_err(Into::into)
     }
+
+    /// Changes the trigger of the block production without the restart of the node.
+    /// The next block is scheduled according to the new trigger relatively
+    /// to the previous block.
+    async fn set_block_production(
+        &self,
+        ctx: &Context<'_>,
+        trigger: BlockProductionTriggerInput,
+    ) -> async_graphql::Result<bool> {
+        let consensus_module = ctx.data_unchecked::<ConsensusModule>();
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+
+        if !config.debug {
+            return Err(anyhow!("`debug` must be enabled to use this endpoint").into())
+        }
+
+        consensus_module
+            .set_block_production(trigger.try_into()?)
+            .await?;
+        Ok(true)
+    }
+}
+
+#[derive(Enum, Copy, Clone, Debug, Eq, PartialEq)]
+enum BlockProductionMode {
+    /// Blocks are never produced automatically.
+    Never,
+    /// A block is produced instantly when transactions are available.
+    Instant,
+    /// Blocks are produced periodically regardless of transactions.
+    Interval,
+    /// A block is produced when transactions are available, but not more often than
+    /// `minBlockTimeMs`, and an empty block is produced after `maxBlockTimeMs`
+    /// without blocks.
+    Hybrid,
+}
+
+#[derive(InputObject)]
+struct BlockProductionTriggerInput {
+    /// The mode of the block production.
+    mode: BlockProductionMode,
+    /// The interval between blocks in milliseconds. Required by the `INTERVAL` mode.
+    block_time_ms: Option<U64>,
+    /// The minimal interval between blocks in milliseconds. Required by the `HYBRID` mode.
+    min_block_time_ms: Option<U64>,
+    /// The maximal interval between blocks in milliseconds. Required by the `HYBRID` mode.
+    max_block_time_ms: Option<U64>,
+}
+
+impl TryFrom<BlockProductionTriggerInput> for Trigger {
+    type Error = anyhow::Error;
+
+    fn try_from(input: BlockProductionTriggerInput) -> Result<Self, Self::Error> {
+        let millis = |value: Option<U64>, name: &str| {
+            value
+                .map(|value| Duration::from_millis(value.into()))
+                .ok_or_else(|| {
+                    anyhow!("`{name}` is required in the `{:?}` mode", input.mode)
+                })
+        };
+        let trigger = match input.mode {
+            BlockProductionMode::Never => Trigger::Never,
+            BlockProductionMode::Instant => Trigger::Instant,
+            BlockProductionMode::Interval => Trigger::Interval {
+                block_time: millis(input.block_time_ms, "blockTimeMs")?,
+            },
+            BlockProductionMode::Hybrid => {
+                let min_block_time = millis(input.min_block_time_ms, "minBlockTimeMs")?;
+                let max_block_time = millis(input.max_block_time_ms, "maxBlockTimeMs")?;
+                if min_block_time > max_block_time {
+                    return Err(anyhow!(
+                        "`minBlockTimeMs` should not be greater than `maxBlockTimeMs`"
+                    ))
+                }
+                Trigger::Hybrid {
+                    min_block_time,
+                    max_block_time,
+                }
+            }
+        };
+        Ok(trigger)
+    }
 }
 
 impl From<CompressedBlock> for Block {
     fn from(block: CompressedBlock) -> Self {
-        Block(block)
+        let (header, transactions) = block.into_inner();
+        Block {
+            header,
+            transactions: Some(transactions),
+        }
+    }
+}
+
+impl From<BlockHeader> for Block {
+    fn from(header: BlockHeader) -> Self {
+        Block {
+            header,
+            transactions: None,
+        }
     }
 }
 
@@ -1,6 +1,7 @@
 use crate::{
     fuel_core_graphql_api::{
         database::ReadView,
+        ports::DatabaseBlocks,
         Config as GraphQLConfig,
     },
     graphql_api::Config,
@@ -70,72 +71,36 @@ impl From<fuel_tx::DependentCost> for DependentCost {
 
 #[Object]
 impl ConsensusParameters {
-    async fn tx_params(&self, ctx: &Context<'_>) -> async_graphql::Result<TxParameters> {
-        let config = ctx.data_unchecked::<GraphQLConfig>();
-
-        Ok(TxParameters(
-            config.consensus_parameters.tx_params().to_owned(),
-        ))
+    async fn tx_params(&self) -> TxParameters {
+        TxParameters(self.0.tx_params().to_owned())
     }
 
-    async fn predicate_params(
-        &self,
-        ctx: &Context<'_>,
-    ) -> async_graphql::Result<PredicateParameters> {
-        let config = ctx.data_unchecked::<GraphQLConfig>();
-
-        Ok(PredicateParameters(
-            config.consensus_parameters.predicate_params().to_owned(),
-        ))
+    async fn predicate_params(&self) -> PredicateParameters {
+        PredicateParameters(self.0.predicate_params().to_owned())
     }
 
-    async fn script_params(
-        &self,
-        ctx: &Context<'_>,
-    ) -> async_graphql::Result<ScriptParameters> {
-        let config = ctx.data_unchecked::<GraphQLConfig>();
-
-        Ok(ScriptParameters(
-            config.consensus_parameters.script_params().to_owned(),
-        ))
+    async fn script_params(&self) -> ScriptParameters {
+        ScriptParameters(self.0.script_params().to_owned())
     }
 
-    async fn contract_params(
-        &self,
-        ctx: &Context<'_>,
-    ) -> async_graphql::Result<ContractParameters> {
-        let config = ctx.data_unchecked::<GraphQLConfig>();
-
-        Ok(ContractParameters(
-            config.consensus_parameters.contract_params().to_owned(),
-        ))
+    async fn contract_params(&self) -> ContractParameters {
+        ContractParameters(self.0.contract_params().to_owned())
     }
 
-    async fn fee_params(
-        &self,
-        ctx: &Context<'_>,
-    ) -> async_graphql::Result<FeeParameters> {
-        let config = ctx.data_unchecked::<GraphQLConfig>();
-
-        Ok(FeeParameters(
-            config.consensus_parameters.fee_params().to_owned(),
-        ))
+    async fn fee_params(&self) -> FeeParameters {
+        FeeParameters(self.0.fee_params().to_owned())
     }
 
-    async fn base_asset_id(&self, ctx: &Context<'_>) -> async_graphql::Result<AssetId> {
-        let config = ctx.data_unchecked::<GraphQLConfig>();
-
-        Ok(AssetId(*config.consensus_parameters.base_asset_id()))
+    async fn base_asset_id(&self) -> AssetId {
+        AssetId(*self.0.base_asset_id())
     }
 
     async fn chain_id(&self) -> U64 {
         (*self.0.chain_id).into()
     }
 
-    async fn gas_costs(&self, ctx: &Context<'_>) -> async_graphql::Result<GasCosts> {
-        let config = ctx.data_unchecked::<GraphQLConfig>();
-
-        Ok(GasCosts(config.consensus_parameters.gas_costs.clone()))
+    async fn gas_costs(&self) -> GasCosts {
+        GasCosts(self.0.gas_costs.clone())
     }
 }
 
@@ -691,7 +656,7 @@ impl ChainInfo {
     async fn latest_block(&self, ctx: &Context<'_>) -> async_graphql::Result<Block> {
         let query: &ReadView = ctx.data_unchecked();
 
-        let latest_block = query.latest_block()?.into();
+        let latest_block = query.block_header(&query.latest_block_height()?)?.into();
         Ok(latest_block)
     }
 
@@ -709,15 +674,23 @@ impl ChainInfo {
         &self,
         ctx: &Context<'_>,
     ) -> async_graphql::Result<ConsensusParameters> {
+        let query: &ReadView = ctx.data_unchecked();
         let config = ctx.data_unchecked::<GraphQLConfig>();
+        let height = query.latest_block_height()?;
 
-        Ok(ConsensusParameters(config.consensus_parameters.clone()))
+        Ok(ConsensusParameters(
+            config.consensus_parameters_at(height).clone(),
+        ))
     }
 
     async fn gas_costs(&self, ctx: &Context<'_>) -> async_graphql::Result<GasCosts> {
+        let query: &ReadView = ctx.data_unchecked();
         let config = ctx.data_unchecked::<GraphQLConfig>();
+        let height = query.latest_block_height()?;
 
-        Ok(GasCosts(config.consensus_parameters.gas_costs.clone()))
+        Ok(GasCosts(
+            config.consensus_parameters_at(height).gas_costs.clone(),
+        ))
     }
 }
 
@@ -729,4 +702,16 @@ impl ChainQuery {
     async fn chain(&self) -> ChainInfo {
         ChainInfo
     }
+
+    /// Returns the consensus parameters in effect at the `height`, taking into account
+    /// upgrades of parameters scheduled by the chain configuration.
+    async fn consensus_parameters(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Height of the block")] height: U32,
+    ) -> ConsensusParameters {
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+
+        ConsensusParameters(config.consensus_parameters_at(height.0.into()).clone())
+    }
 }
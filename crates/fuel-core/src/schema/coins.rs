@@ -1,6 +1,7 @@
 use crate::{
     coins_query::{
         random_improve,
+        CoinsQueryError,
         SpendQuery,
     },
     fuel_core_graphql_api::{
@@ -27,6 +28,7 @@ use async_graphql::{
         EmptyFields,
     },
     Context,
+    ErrorExtensions,
 };
 use fuel_core_types::{
     entities::{
@@ -242,7 +244,11 @@ impl CoinQuery {
 
         let query: &ReadView = ctx.data_unchecked();
 
-        let coins = random_improve(query, &spend_query)?
+        let coins = random_improve(query, &spend_query)
+            .map_err(|error| match error {
+                CoinsQueryError::AmountOverflow(overflow) => overflow.extend(),
+                error => error.into(),
+            })?
             .into_iter()
             .map(|coins| {
                 coins
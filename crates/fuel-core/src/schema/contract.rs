@@ -1,14 +1,17 @@
 use crate::{
     fuel_core_graphql_api::{
         database::ReadView,
+        ports::OffChainDatabase,
         IntoApiResult,
     },
     query::ContractQueryData,
     schema::scalars::{
         AssetId,
+        Bytes32,
         ContractId,
         HexString,
         Salt,
+        U32,
         U64,
     },
 };
@@ -21,11 +24,21 @@ use async_graphql::{
     InputObject,
     Object,
 };
+use fuel_core_storage::Result as StorageResult;
 use fuel_core_types::{
     fuel_types,
-    services::graphql_api,
+    services::{
+        executor,
+        graphql_api,
+    },
 };
 
+/// The maximum number of blocks in the range of the `contractStateDiff` query.
+const MAX_STATE_DIFF_BLOCKS: u32 = 10_000;
+
+/// The maximum number of storage writes returned by the `contractStateDiff` query.
+const MAX_STATE_DIFF_CHANGES: usize = 10_000;
+
 pub struct Contract(pub(crate) fuel_types::ContractId);
 
 impl From<fuel_types::ContractId> for Contract {
@@ -70,6 +83,72 @@ impl ContractQuery {
         let query: &ReadView = ctx.data_unchecked();
         query.contract_id(id.0).into_api_result()
     }
+
+    /// Returns the values of the contract storage slots written by blocks in the range.
+    /// Fails if blocks of the range wrote more than 10000 slots, so the range should be narrowed.
+    async fn contract_state_diff(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "ID of the Contract")] contract_id: ContractId,
+        #[graphql(desc = "Height of the first block in the range")] from_height: U32,
+        #[graphql(desc = "Height of the last block in the range, inclusive")]
+        to_height: U32,
+    ) -> async_graphql::Result<Vec<ContractStateChange>> {
+        let (from, to) = (from_height.0, to_height.0);
+        if from > to {
+            return Err(anyhow::anyhow!(
+                "`fromHeight` {from} is greater than `toHeight` {to}"
+            )
+            .into())
+        }
+        if to.saturating_sub(from) >= MAX_STATE_DIFF_BLOCKS {
+            return Err(anyhow::anyhow!(
+                "The range can't contain more than {MAX_STATE_DIFF_BLOCKS} blocks"
+            )
+            .into())
+        }
+
+        let query: &ReadView = ctx.data_unchecked();
+        let changes = query
+            .contract_state_changes(contract_id.0, from.into(), to.into())
+            .map(|result| {
+                result.map(|(block_height, change)| ContractStateChange {
+                    block_height: block_height.into(),
+                    change,
+                })
+            })
+            .take(MAX_STATE_DIFF_CHANGES.saturating_add(1))
+            .collect::<StorageResult<Vec<_>>>()?;
+        if changes.len() > MAX_STATE_DIFF_CHANGES {
+            return Err(anyhow::anyhow!(
+                "The range contains more than {MAX_STATE_DIFF_CHANGES} changes, narrow the range"
+            )
+            .into())
+        }
+        Ok(changes)
+    }
+}
+
+/// The write to the contract storage slot made by the block.
+pub struct ContractStateChange {
+    block_height: U32,
+    change: executor::ContractStateChange,
+}
+
+#[Object]
+impl ContractStateChange {
+    async fn block_height(&self) -> U32 {
+        self.block_height
+    }
+
+    async fn key(&self) -> Bytes32 {
+        self.change.key.into()
+    }
+
+    /// The value of the slot after the block. `null` if the block removed the slot.
+    async fn value(&self) -> Option<Bytes32> {
+        self.change.value.map(Into::into)
+    }
 }
 
 pub struct ContractBalance(graphql_api::ContractBalance);
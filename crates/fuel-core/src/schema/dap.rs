@@ -4,7 +4,20 @@ use crate::{
         transaction::DatabaseTransaction,
         Database,
     },
+    fuel_core_graphql_api::{
+        database::ReadView,
+        ports::{
+            DatabaseBlocks,
+            OffChainDatabase,
+        },
+        Config as GraphQLConfig,
+    },
+    query::{
+        SimpleBlockData,
+        SimpleTransactionData,
+    },
     schema::scalars::{
+        TransactionId,
         U32,
         U64,
     },
@@ -15,14 +28,22 @@ use async_graphql::{
     SchemaBuilder,
     ID,
 };
+use fuel_core_chain_config::{
+    consensus_parameters_at,
+    ConsensusParametersUpgrade,
+};
 use fuel_core_storage::{
     not_found,
+    transactional::AtomicView,
     vm_storage::VmStorage,
     InterpreterStorage,
+    Result as StorageResult,
 };
 use fuel_core_types::{
+    blockchain::header::BlockHeader,
     fuel_asm::{
         Instruction,
+        RegId,
         RegisterId,
         Word,
     },
@@ -32,7 +53,9 @@ use fuel_core_types::{
         Executable,
         Script,
         Transaction,
+        TxId,
     },
+    fuel_types::BlockHeight,
     fuel_vm::{
         checked_transaction::{
             CheckedTransaction,
@@ -40,9 +63,11 @@ use fuel_core_types::{
         },
         consts,
         state::DebugEval,
+        Breakpoint,
         Interpreter,
         InterpreterError,
     },
+    services::txpool::TransactionStatus,
 };
 use futures::lock::Mutex;
 use std::{
@@ -56,6 +81,12 @@ use tracing::{
 };
 use uuid::Uuid;
 
+/// The default maximum number of instructions in the trace of the transaction.
+const DEFAULT_TRACE_STEPS: u32 = 1_000;
+
+/// The maximum number of instructions in the trace of the transaction.
+const MAX_TRACE_STEPS: u32 = 10_000;
+
 pub struct Config {
     /// `true` means that debugger functionality is enabled.
     debug_enabled: bool,
@@ -67,22 +98,36 @@ pub struct ConcreteStorage {
     tx: HashMap<ID, Vec<Script>>,
     db: HashMap<ID, DatabaseTransaction<OnChain>>,
     params: ConsensusParameters,
+    params_upgrades: Vec<ConsensusParametersUpgrade>,
 }
 
 impl ConcreteStorage {
-    pub fn new(params: ConsensusParameters) -> Self {
+    pub fn new(
+        params: ConsensusParameters,
+        params_upgrades: Vec<ConsensusParametersUpgrade>,
+    ) -> Self {
         Self {
             params,
+            params_upgrades,
             ..Default::default()
         }
     }
 
+    /// Returns the consensus parameters in effect at the `height`.
+    fn params_at(&self, height: BlockHeight) -> ConsensusParameters {
+        consensus_parameters_at(&self.params, &self.params_upgrades, height).clone()
+    }
+
     pub fn register(&self, id: &ID, register: RegisterId) -> Option<Word> {
         self.vm
             .get(id)
             .and_then(|vm| vm.registers().get(register).copied())
     }
 
+    pub fn registers(&self, id: &ID) -> Option<&[Word]> {
+        self.vm.get(id).map(|vm| vm.registers())
+    }
+
     pub fn memory(&self, id: &ID, start: usize, size: usize) -> Option<&[u8]> {
         let (end, overflow) = start.overflowing_add(size);
         if overflow || end as u64 > consts::VM_MAX_RAM {
@@ -101,9 +146,11 @@ impl ConcreteStorage {
         let id = ID::from(id);
 
         let vm_database = Self::vm_database(&storage)?;
+        let height = vm_database.block_height()?;
+        let params = self.params_at(height);
         let tx = Self::dummy_tx();
         let checked_tx = tx
-            .into_checked_basic(vm_database.block_height()?, &self.params)
+            .into_checked_basic(height, &params)
             .map_err(|e| anyhow::anyhow!("{:?}", e))?;
         self.tx
             .get_mut(&id)
@@ -112,7 +159,7 @@ impl ConcreteStorage {
                 self.tx.insert(id.clone(), txs.to_owned());
             });
 
-        let mut vm = Interpreter::with_storage(vm_database, (&self.params).into());
+        let mut vm = Interpreter::with_storage(vm_database, (&params).into());
         vm.transact(checked_tx).map_err(|e| anyhow::anyhow!(e))?;
         self.vm.insert(id.clone(), vm);
         self.db.insert(id.clone(), storage);
@@ -139,11 +186,13 @@ impl ConcreteStorage {
             .cloned()
             .unwrap_or(Self::dummy_tx());
 
+        let height = vm_database.block_height()?;
+        let params = self.params_at(height);
         let checked_tx = tx
-            .into_checked_basic(vm_database.block_height()?, &self.params)
+            .into_checked_basic(height, &params)
             .map_err(|e| anyhow::anyhow!("{:?}", e))?;
 
-        let mut vm = Interpreter::with_storage(vm_database, (&self.params).into());
+        let mut vm = Interpreter::with_storage(vm_database, (&params).into());
         vm.transact(checked_tx).map_err(|e| anyhow::anyhow!(e))?;
         self.vm.insert(id.clone(), vm).ok_or_else(|| {
             io::Error::new(io::ErrorKind::NotFound, "The VM instance was not found")
@@ -206,10 +255,14 @@ pub struct DapMutation;
 pub fn init<Q, M, S>(
     schema: SchemaBuilder<Q, M, S>,
     params: ConsensusParameters,
+    params_upgrades: Vec<ConsensusParametersUpgrade>,
     debug_enabled: bool,
 ) -> SchemaBuilder<Q, M, S> {
     schema
-        .data(GraphStorage::new(Mutex::new(ConcreteStorage::new(params))))
+        .data(GraphStorage::new(Mutex::new(ConcreteStorage::new(
+            params,
+            params_upgrades,
+        ))))
         .data(Config { debug_enabled })
 }
 
@@ -241,6 +294,21 @@ impl DapQuery {
             .map(|val| val.into())
     }
 
+    /// Read values of all registers.
+    async fn registers(
+        &self,
+        ctx: &Context<'_>,
+        id: ID,
+    ) -> async_graphql::Result<Vec<U64>> {
+        require_debug(ctx)?;
+        ctx.data_unchecked::<GraphStorage>()
+            .lock()
+            .await
+            .registers(&id)
+            .ok_or_else(|| async_graphql::Error::new("VM not found"))
+            .map(|registers| registers.iter().copied().map(Into::into).collect())
+    }
+
     /// Read read a range of memory bytes.
     async fn memory(
         &self,
@@ -257,6 +325,179 @@ impl DapQuery {
             .ok_or_else(|| async_graphql::Error::new("Invalid memory range"))
             .and_then(|mem| Ok(serde_json::to_string(mem)?))
     }
+
+    /// Re-executes the committed transaction instruction by instruction and returns
+    /// the executed instructions with the values of registers before each of them.
+    ///
+    /// The transaction is re-executed on top of the state before its block after
+    /// the transactions preceding it in the block. The node keeps that state only for
+    /// the latest `state_history_blocks` blocks, older transactions can't be traced.
+    async fn trace_transaction(
+        &self,
+        ctx: &Context<'_>,
+        id: TransactionId,
+        #[graphql(desc = "The maximum number of traced instructions.")] max_steps: Option<
+            U32,
+        >,
+    ) -> async_graphql::Result<gql_types::TransactionTrace> {
+        require_debug(ctx)?;
+        let max_steps = max_steps
+            .map(|steps| steps.0)
+            .unwrap_or(DEFAULT_TRACE_STEPS);
+        if max_steps > MAX_TRACE_STEPS {
+            return Err(async_graphql::Error::new(format!(
+                "The maximum number of traced instructions is {MAX_TRACE_STEPS}"
+            )))
+        }
+
+        let tx_id = id.0;
+        let query: &ReadView = ctx.data_unchecked();
+        let block_id = match query.tx_status(&tx_id)? {
+            TransactionStatus::Success { block_id, .. }
+            | TransactionStatus::Failed { block_id, .. } => block_id,
+            _ => {
+                return Err(async_graphql::Error::new(
+                    "The transaction is not included into the block",
+                ))
+            }
+        };
+        let height = query.block_height(&block_id)?;
+        let block = query.block(&height)?;
+        let params = ctx
+            .data_unchecked::<GraphQLConfig>()
+            .consensus_parameters_at(height)
+            .clone();
+
+        let Some(previous_height) = height.pred() else {
+            return Err(async_graphql::Error::new(
+                "The transactions of the genesis block can't be traced",
+            ))
+        };
+        let view = ctx.data_unchecked::<Database>().view_at(&previous_height)?;
+
+        let preceding = block
+            .transactions()
+            .iter()
+            .take_while(|id| **id != tx_id)
+            .map(|id| Ok((*id, query.transaction(id)?)))
+            .collect::<StorageResult<Vec<_>>>()?;
+        let tx = query.transaction(&tx_id)?;
+        let header = block.header().clone();
+
+        // The execution is CPU-bound, so it shouldn't block the runtime.
+        tokio::task::spawn_blocking(move || {
+            trace_execution(view, &header, &params, preceding, tx, max_steps)
+        })
+        .await
+        .map_err(|err| async_graphql::Error::new(format!("The trace failed: {err}")))?
+    }
+}
+
+/// Re-executes the `preceding` transactions of the block with the `header` on top of
+/// the `view` and traces the execution of the `tx` after them.
+fn trace_execution(
+    view: Database,
+    header: &BlockHeader,
+    params: &ConsensusParameters,
+    preceding: Vec<(TxId, Transaction)>,
+    tx: Transaction,
+    max_steps: u32,
+) -> async_graphql::Result<gql_types::TransactionTrace> {
+    let height = *header.height();
+    let storage = view.transaction();
+    let vm_database = VmStorage::new(
+        storage.as_ref().clone(),
+        header.consensus(),
+        // TODO: Use a real coinbase address
+        Default::default(),
+    );
+    let mut vm = Interpreter::with_storage(vm_database, params.into());
+
+    for (preceding_id, tx) in preceding {
+        let checked_tx = tx
+            .into_checked_basic(height, params)
+            .map_err(|err| anyhow::anyhow!("{:?}", err))?
+            .into();
+        match checked_tx {
+            CheckedTransaction::Script(script) => {
+                vm.transact(script).map_err(|err| {
+                    async_graphql::Error::new(format!(
+                        "The preceding transaction {preceding_id} failed: {err:?}"
+                    ))
+                })?;
+            }
+            CheckedTransaction::Create(create) => {
+                vm.deploy(create).map_err(|err| {
+                    async_graphql::Error::new(format!(
+                        "The preceding transaction {preceding_id} failed: {err:?}"
+                    ))
+                })?;
+            }
+            CheckedTransaction::Mint(_) => {}
+        }
+    }
+
+    let checked_tx = tx
+        .into_checked_basic(height, params)
+        .map_err(|err| anyhow::anyhow!("{:?}", err))?
+        .into();
+    let CheckedTransaction::Script(script) = checked_tx else {
+        return Err(async_graphql::Error::new(
+            "Only `Script` transactions can be traced",
+        ))
+    };
+
+    vm.set_single_stepping(true);
+    let mut steps = vec![];
+    let mut state = *vm
+        .transact(script)
+        .map_err(|err| async_graphql::Error::new(format!("Transaction failed: {err:?}")))?
+        .state();
+    while let Some(DebugEval::Breakpoint(breakpoint)) = state.debug_ref() {
+        if steps.len() >= max_steps as usize {
+            break
+        }
+        steps.push(trace_step(&vm, breakpoint));
+        state = vm
+            .resume()
+            .map_err(|err| async_graphql::Error::new(format!("VM error: {err:?}")))?;
+    }
+
+    let json_receipts = vm
+        .receipts()
+        .iter()
+        .map(|r| serde_json::to_string(&r).expect("JSON serialization failed"))
+        .collect();
+
+    Ok(gql_types::TransactionTrace {
+        truncated: state.is_debug(),
+        steps,
+        json_receipts,
+    })
+}
+
+/// Returns the step of the trace for the instruction the VM instance is stopped at.
+fn trace_step(
+    vm: &Interpreter<VmStorage<Database>, Script>,
+    breakpoint: &Breakpoint,
+) -> gql_types::TraceStep {
+    let registers = vm.registers();
+    let instruction = usize::try_from(registers[RegId::PC.to_u8() as usize])
+        .ok()
+        .and_then(|pc| vm.memory().get(pc..pc.saturating_add(Instruction::SIZE)))
+        .and_then(|bytes| <[u8; Instruction::SIZE]>::try_from(bytes).ok());
+    let opcode = instruction
+        .and_then(|bytes| Instruction::try_from(bytes).ok())
+        .map(|instruction| format!("{:?}", instruction.opcode()));
+    let contract = *breakpoint.contract();
+
+    gql_types::TraceStep {
+        contract: (contract != Default::default()).then(|| contract.into()),
+        pc: U64(breakpoint.pc()),
+        instruction: instruction.map(|bytes| U32(u32::from_be_bytes(bytes))),
+        opcode,
+        registers: registers.iter().copied().map(Into::into).collect(),
+    }
 }
 
 #[Object]
@@ -373,6 +614,26 @@ impl DapMutation {
         Ok(true)
     }
 
+    /// Remove a breakpoint from a VM instance.
+    async fn remove_breakpoint(
+        &self,
+        ctx: &Context<'_>,
+        id: ID,
+        breakpoint: gql_types::Breakpoint,
+    ) -> async_graphql::Result<bool> {
+        require_debug(ctx)?;
+        trace!("Remove breakpoint for VM {:?}", id);
+
+        let mut locked = ctx.data_unchecked::<GraphStorage>().lock().await;
+        let vm = locked
+            .vm
+            .get_mut(&id)
+            .ok_or_else(|| async_graphql::Error::new("VM not found"))?;
+
+        vm.remove_breakpoint(&breakpoint.into());
+        Ok(true)
+    }
+
     /// Run a single transaction in given session until it
     /// hits a breakpoint or completes.
     async fn start_tx(
@@ -391,8 +652,9 @@ impl DapMutation {
 
         let db = locked.db.get(&id).ok_or("Invalid debugging session ID")?;
 
+        let height = db.latest_height()?;
         let checked_tx = tx
-            .into_checked_basic(db.latest_height()?, &locked.params)
+            .into_checked_basic(height, &locked.params_at(height))
             .map_err(|err| anyhow::anyhow!("{:?}", err))?
             .into();
 
@@ -463,53 +725,82 @@ impl DapMutation {
             .get_mut(&id)
             .ok_or_else(|| async_graphql::Error::new("VM not found"))?;
 
-        let receipt_count_before = vm.receipts().len();
+        resume(vm)
+    }
 
-        let state = match vm.resume() {
-            Ok(state) => state,
-            // The transaction was already completed earlier, so it cannot be resumed
-            Err(InterpreterError::DebugStateNotInitialized) => {
-                return Ok(gql_types::RunResult {
-                    state: gql_types::RunState::Completed,
-                    breakpoint: None,
-                    json_receipts: Vec::new(),
-                })
-            }
-            // The transaction was already completed earlier, so it cannot be resumed
-            Err(err) => {
-                return Err(async_graphql::Error::new(format!("VM error: {err:?}")))
-            }
-        };
+    /// Execute a single instruction of the VM instance stopped at a breakpoint,
+    /// regardless of the single-stepping mode of the session.
+    async fn step_tx(
+        &self,
+        ctx: &Context<'_>,
+        id: ID,
+    ) -> async_graphql::Result<gql_types::RunResult> {
+        require_debug(ctx)?;
+        trace!("Step execution of VM {:?}", id);
 
-        let json_receipts = vm
-            .receipts()
-            .iter()
-            .skip(receipt_count_before)
-            .map(|r| serde_json::to_string(&r).expect("JSON serialization failed"))
-            .collect();
-
-        let dbgref = state.debug_ref();
-
-        Ok(gql_types::RunResult {
-            state: match dbgref {
-                Some(_) => gql_types::RunState::Breakpoint,
-                None => gql_types::RunState::Completed,
-            },
-            breakpoint: dbgref.and_then(|d| match d {
-                DebugEval::Continue => None,
-                DebugEval::Breakpoint(bp) => Some(bp.into()),
-            }),
-            json_receipts,
-        })
+        let mut locked = ctx.data_unchecked::<GraphStorage>().lock().await;
+        let vm = locked
+            .vm
+            .get_mut(&id)
+            .ok_or_else(|| async_graphql::Error::new("VM not found"))?;
+
+        let single_stepping = vm.single_stepping();
+        vm.set_single_stepping(true);
+        let result = resume(vm);
+        vm.set_single_stepping(single_stepping);
+        result
     }
 }
 
+/// Resumes the VM instance until the next breakpoint or the end of the transaction.
+fn resume(
+    vm: &mut Interpreter<VmStorage<Database>, Script>,
+) -> async_graphql::Result<gql_types::RunResult> {
+    let receipt_count_before = vm.receipts().len();
+
+    let state = match vm.resume() {
+        Ok(state) => state,
+        // The transaction was already completed earlier, so it cannot be resumed
+        Err(InterpreterError::DebugStateNotInitialized) => {
+            return Ok(gql_types::RunResult {
+                state: gql_types::RunState::Completed,
+                breakpoint: None,
+                json_receipts: Vec::new(),
+            })
+        }
+        // The transaction was already completed earlier, so it cannot be resumed
+        Err(err) => return Err(async_graphql::Error::new(format!("VM error: {err:?}"))),
+    };
+
+    let json_receipts = vm
+        .receipts()
+        .iter()
+        .skip(receipt_count_before)
+        .map(|r| serde_json::to_string(&r).expect("JSON serialization failed"))
+        .collect();
+
+    let dbgref = state.debug_ref();
+
+    Ok(gql_types::RunResult {
+        state: match dbgref {
+            Some(_) => gql_types::RunState::Breakpoint,
+            None => gql_types::RunState::Completed,
+        },
+        breakpoint: dbgref.and_then(|d| match d {
+            DebugEval::Continue => None,
+            DebugEval::Breakpoint(bp) => Some(bp.into()),
+        }),
+        json_receipts,
+    })
+}
+
 mod gql_types {
     //! GraphQL type wrappers
     use async_graphql::*;
 
     use crate::schema::scalars::{
         ContractId,
+        U32,
         U64,
     };
 
@@ -568,4 +859,30 @@ mod gql_types {
         pub breakpoint: Option<OutputBreakpoint>,
         pub json_receipts: Vec<String>,
     }
+
+    /// The instruction executed by the traced transaction.
+    #[derive(Debug, Clone, SimpleObject)]
+    pub struct TraceStep {
+        /// The contract executing the instruction, or `null` for the script.
+        pub contract: Option<ContractId>,
+        /// The offset of the instruction relative to the start of the contract or the script.
+        pub pc: U64,
+        /// The raw instruction, or `null` if the memory at `$pc` is out of bounds.
+        pub instruction: Option<U32>,
+        /// The name of the opcode, or `null` if the instruction is invalid.
+        pub opcode: Option<String>,
+        /// The values of registers before the execution of the instruction.
+        pub registers: Vec<U64>,
+    }
+
+    /// The trace of the re-executed transaction.
+    #[derive(Debug, Clone, SimpleObject)]
+    pub struct TransactionTrace {
+        /// The executed instructions in the order of execution.
+        pub steps: Vec<TraceStep>,
+        /// `true` if the transaction executed more instructions than the maximum number of steps.
+        pub truncated: bool,
+        /// Receipts produced by the re-executed transaction.
+        pub json_receipts: Vec<String>,
+    }
 }
@@ -0,0 +1,157 @@
+use super::scalars::{
+    U32,
+    U64,
+};
+use crate::fuel_core_graphql_api::api_service::P2pService;
+use async_graphql::{
+    Context,
+    Enum,
+    Object,
+};
+use fuel_core_types::services::p2p;
+use std::time::Duration;
+
+pub struct NetworkInfo(p2p::NetworkInfo);
+
+#[Object]
+impl NetworkInfo {
+    /// The number of connected peers that dialed the node.
+    async fn inbound_peers(&self) -> U32 {
+        self.0.inbound_peers.into()
+    }
+
+    /// The number of connected peers dialed by the node.
+    async fn outbound_peers(&self) -> U32 {
+        self.0.outbound_peers.into()
+    }
+
+    /// The number of payload bytes exchanged with peers by each protocol
+    /// since the start of the node.
+    async fn bandwidth(&self) -> Vec<ProtocolBandwidth> {
+        self.0
+            .bandwidth
+            .iter()
+            .copied()
+            .map(ProtocolBandwidth)
+            .collect()
+    }
+
+    /// The peers in the gossipsub mesh of each subscribed topic.
+    async fn mesh(&self) -> Vec<TopicMesh> {
+        self.0.mesh.iter().cloned().map(TopicMesh).collect()
+    }
+
+    /// The delays between the first announcement of the block height by peers
+    /// and the import of the block by the node. Blocks not announced by peers
+    /// before the import aren't accounted.
+    async fn block_propagation(&self) -> LatencyHistogram {
+        LatencyHistogram(self.0.block_propagation.clone())
+    }
+
+    /// The delays between the receipt of the gossiped transaction and its
+    /// forwarding to peers. Rejected and ignored transactions aren't accounted.
+    async fn tx_propagation(&self) -> LatencyHistogram {
+        LatencyHistogram(self.0.tx_propagation.clone())
+    }
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+enum NetworkProtocol {
+    Gossipsub,
+    RequestResponse,
+}
+
+impl From<p2p::NetworkProtocol> for NetworkProtocol {
+    fn from(protocol: p2p::NetworkProtocol) -> Self {
+        match protocol {
+            p2p::NetworkProtocol::Gossipsub => NetworkProtocol::Gossipsub,
+            p2p::NetworkProtocol::RequestResponse => NetworkProtocol::RequestResponse,
+        }
+    }
+}
+
+struct ProtocolBandwidth(p2p::ProtocolBandwidth);
+
+#[Object]
+impl ProtocolBandwidth {
+    async fn protocol(&self) -> NetworkProtocol {
+        self.0.protocol.into()
+    }
+
+    /// The number of bytes received from peers.
+    async fn inbound_bytes(&self) -> U64 {
+        self.0.inbound_bytes.into()
+    }
+
+    /// The number of bytes sent to peers.
+    async fn outbound_bytes(&self) -> U64 {
+        self.0.outbound_bytes.into()
+    }
+}
+
+struct TopicMesh(p2p::TopicMesh);
+
+#[Object]
+impl TopicMesh {
+    /// The name of the gossipsub topic.
+    async fn topic(&self) -> String {
+        self.0.topic.clone()
+    }
+
+    /// The libp2p peer ids of peers in the mesh of the topic.
+    async fn peers(&self) -> Vec<String> {
+        self.0.peers.iter().map(ToString::to_string).collect()
+    }
+}
+
+struct LatencyHistogram(p2p::LatencyHistogram);
+
+#[Object]
+impl LatencyHistogram {
+    /// The number of observed latencies.
+    async fn count(&self) -> U64 {
+        self.0.count.into()
+    }
+
+    /// The sum of observed latencies in milliseconds.
+    async fn sum_ms(&self) -> U64 {
+        millis(self.0.sum)
+    }
+
+    /// The cumulative buckets of the histogram in ascending order of their upper bounds.
+    async fn buckets(&self) -> Vec<LatencyBucket> {
+        self.0.buckets.iter().copied().map(LatencyBucket).collect()
+    }
+}
+
+struct LatencyBucket(p2p::LatencyBucket);
+
+#[Object]
+impl LatencyBucket {
+    /// The inclusive upper bound of latencies in the bucket in milliseconds.
+    async fn upper_bound_ms(&self) -> U64 {
+        millis(self.0.upper_bound)
+    }
+
+    /// The number of observed latencies less than or equal to the upper bound.
+    async fn count(&self) -> U64 {
+        self.0.count.into()
+    }
+}
+
+fn millis(duration: Duration) -> U64 {
+    U64(duration.as_millis().try_into().unwrap_or(u64::MAX))
+}
+
+#[derive(Default)]
+pub struct NetworkInfoQuery;
+
+#[Object]
+impl NetworkInfoQuery {
+    /// The telemetry of the P2P network collected since the start of the node.
+    /// Returns `null` if the P2P network is disabled.
+    async fn network_info(&self, ctx: &Context<'_>) -> Option<NetworkInfo> {
+        let p2p: &P2pService = ctx.data_unchecked();
+        p2p.network_info().map(NetworkInfo)
+    }
+}
@@ -2,9 +2,16 @@ use super::scalars::{
     U32,
     U64,
 };
-use crate::fuel_core_graphql_api::Config as GraphQLConfig;
+use crate::{
+    fuel_core_graphql_api::{
+        api_service::TxPool,
+        Config as GraphQLConfig,
+    },
+    service::NodeMode as ServiceNodeMode,
+};
 use async_graphql::{
     Context,
+    Enum,
     Object,
 };
 use std::time::UNIX_EPOCH;
@@ -16,6 +23,30 @@ pub struct NodeInfo {
     max_tx: U64,
     max_depth: U64,
     node_version: String,
+    node_mode: NodeMode,
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+enum NodeMode {
+    Network,
+    SingleNode,
+    Standalone,
+    Replica,
+    Standby,
+    ReadOnly,
+}
+
+impl From<ServiceNodeMode> for NodeMode {
+    fn from(mode: ServiceNodeMode) -> Self {
+        match mode {
+            ServiceNodeMode::Network => NodeMode::Network,
+            ServiceNodeMode::SingleNode => NodeMode::SingleNode,
+            ServiceNodeMode::Standalone => NodeMode::Standalone,
+            ServiceNodeMode::Replica => NodeMode::Replica,
+            ServiceNodeMode::Standby => NodeMode::Standby,
+            ServiceNodeMode::ReadOnly => NodeMode::ReadOnly,
+        }
+    }
 }
 
 #[Object]
@@ -44,6 +75,11 @@ impl NodeInfo {
         self.node_version.to_owned()
     }
 
+    /// The mode in which the node runs.
+    async fn node_mode(&self) -> NodeMode {
+        self.node_mode
+    }
+
     async fn peers(&self, _ctx: &Context<'_>) -> async_graphql::Result<Vec<PeerInfo>> {
         #[cfg(feature = "p2p")]
         {
@@ -69,16 +105,18 @@ pub struct NodeQuery {}
 impl NodeQuery {
     async fn node_info(&self, ctx: &Context<'_>) -> async_graphql::Result<NodeInfo> {
         let config = ctx.data_unchecked::<GraphQLConfig>();
+        let txpool = ctx.data_unchecked::<TxPool>();
 
         const VERSION: &str = env!("CARGO_PKG_VERSION");
 
         Ok(NodeInfo {
             utxo_validation: config.utxo_validation,
             vm_backtrace: config.vm_backtrace,
-            min_gas_price: config.min_gas_price.into(),
+            min_gas_price: txpool.min_gas_price().into(),
             max_tx: (config.max_tx as u64).into(),
             max_depth: (config.max_depth as u64).into(),
             node_version: VERSION.to_owned(),
+            node_mode: config.node_mode.into(),
         })
     }
 }
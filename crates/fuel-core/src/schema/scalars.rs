@@ -1,5 +1,7 @@
+use crate::query;
 use async_graphql::{
     connection::CursorType,
+    ErrorExtensions,
     InputValueError,
     InputValueResult,
     Scalar,
@@ -82,6 +84,34 @@ macro_rules! number_scalar {
 number_scalar!(U64, u64, "U64");
 number_scalar!(U32, u32, "U32");
 number_scalar!(U8, u8, "U8");
+// The amount of an asset or of the fee. It is encoded like `U64`,
+// but resolvers produce it with the overflow-checked `query::Amount`.
+number_scalar!(Amount, u64, "Amount");
+
+impl From<query::Amount> for Amount {
+    fn from(amount: query::Amount) -> Self {
+        Amount(amount.get())
+    }
+}
+
+impl From<Amount> for query::Amount {
+    fn from(amount: Amount) -> Self {
+        query::Amount::new(amount.0)
+    }
+}
+
+impl ErrorExtensions for query::AmountOverflow {
+    /// The overflow is reported with the `AMOUNT_OVERFLOW` code and the operands
+    /// of the failed operation as `Amount` strings.
+    fn extend(&self) -> async_graphql::Error {
+        async_graphql::Error::new(self.to_string()).extend_with(|_, extensions| {
+            extensions.set("code", "AMOUNT_OVERFLOW");
+            extensions.set("operation", self.operation.as_str());
+            extensions.set("lhs", self.lhs.to_string());
+            extensions.set("rhs", self.rhs.to_string());
+        })
+    }
+}
 
 impl From<BlockHeight> for U32 {
     fn from(h: BlockHeight) -> Self {
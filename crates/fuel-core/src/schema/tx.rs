@@ -6,6 +6,7 @@ use crate::{
         },
         database::ReadView,
         ports::OffChainDatabase,
+        rate_limiter::ClientKey,
         Config,
         IntoApiResult,
     },
@@ -18,14 +19,17 @@ use crate::{
     schema::{
         scalars::{
             Address,
+            ContractId,
             HexString,
             SortedTxCursor,
             TransactionId,
             TxPointer,
+            U64,
         },
         tx::types::TransactionStatus,
     },
 };
+use anyhow::anyhow;
 use async_graphql::{
     connection::{
         Connection,
@@ -41,7 +45,11 @@ use fuel_core_storage::{
     Result as StorageResult,
 };
 use fuel_core_txpool::{
-    service::TxStatusMessage,
+    admin::FlushFilter,
+    service::{
+        Subscriber,
+        TxStatusMessage,
+    },
     txpool::TokioWithRayon,
 };
 use fuel_core_types::{
@@ -56,7 +64,10 @@ use fuel_core_types::{
         CheckPredicateParams,
         EstimatePredicates,
     },
-    services::txpool,
+    services::{
+        txpool,
+        txpool::TxOrigin,
+    },
 };
 use futures::{
     Stream,
@@ -65,10 +76,15 @@ use futures::{
 use itertools::Itertools;
 use std::{
     iter,
+    path::{
+        Component,
+        Path,
+    },
     sync::Arc,
 };
 use tokio_stream::StreamExt;
 use types::{
+    DryRunBundleStep,
     DryRunTransactionExecutionStatus,
     Transaction,
 };
@@ -207,10 +223,15 @@ impl TxQuery {
     ) -> async_graphql::Result<Transaction> {
         let mut tx = FuelTx::from_bytes(&tx.0)?;
 
+        let query: &ReadView = ctx.data_unchecked();
         let config = ctx.data_unchecked::<Config>();
+        // The transaction is estimated for the next block.
+        let height = query.latest_block_height()?;
+        let consensus_parameters =
+            config.consensus_parameters_at(height.succ().unwrap_or(height));
 
         tx.estimate_predicates_async::<TokioWithRayon>(&CheckPredicateParams::from(
-            &config.consensus_parameters,
+            consensus_parameters,
         ))
         .await
         .map_err(|err| anyhow::anyhow!("{:?}", err))?;
@@ -268,6 +289,38 @@ impl TxMutation {
         Ok(tx_statuses)
     }
 
+    /// Execute a dry-run of the bundle of transactions using a fork of current state,
+    /// no changes are committed. Transactions are executed one after another, and each
+    /// transaction sees the changes of the previous ones. The invalid transaction is skipped
+    /// without affecting the state, and the execution of the bundle continues.
+    /// Returns the result of each transaction in the order of the bundle.
+    async fn dry_run_bundle(
+        &self,
+        ctx: &Context<'_>,
+        txs: Vec<HexString>,
+        // If set to false, disable input utxo validation, overriding the configuration of the node.
+        // This allows for non-existent inputs to be used without signature validation
+        // for read-only calls.
+        utxo_validation: Option<bool>,
+    ) -> async_graphql::Result<Vec<DryRunBundleStep>> {
+        let block_producer = ctx.data_unchecked::<BlockProducer>();
+        let config = ctx.data_unchecked::<Config>();
+
+        let mut transactions = txs
+            .iter()
+            .map(|tx| FuelTx::from_bytes(&tx.0))
+            .collect::<Result<Vec<FuelTx>, _>>()?;
+        for transaction in &mut transactions {
+            transaction.precompute(&config.consensus_parameters.chain_id)?;
+        }
+
+        let steps = block_producer
+            .dry_run_bundle(transactions, None, utxo_validation)
+            .await?;
+
+        Ok(steps.into_iter().map(Into::into).collect())
+    }
+
     /// Submits transaction to the `TxPool`.
     ///
     /// Returns submitted transaction if the transaction is included in the `TxPool` without problems.
@@ -281,7 +334,7 @@ impl TxMutation {
         let tx = FuelTx::from_bytes(&tx.0)?;
 
         let _: Vec<_> = txpool
-            .insert(vec![Arc::new(tx.clone())])
+            .insert(vec![Arc::new(tx.clone())], api_origin(ctx))
             .await
             .into_iter()
             .try_collect()?;
@@ -290,6 +343,81 @@ impl TxMutation {
         let tx = Transaction(tx, id);
         Ok(tx)
     }
+
+    /// Pauses the insertion of new transactions into the `TxPool` from the API and the gossip.
+    /// Transactions in the `TxPool` are still included into blocks.
+    async fn pause_tx_pool(&self, ctx: &Context<'_>) -> async_graphql::Result<bool> {
+        require_debug(ctx)?;
+        ctx.data_unchecked::<TxPool>().pause();
+        Ok(true)
+    }
+
+    /// Resumes the insertion of new transactions into the `TxPool`.
+    async fn resume_tx_pool(&self, ctx: &Context<'_>) -> async_graphql::Result<bool> {
+        require_debug(ctx)?;
+        ctx.data_unchecked::<TxPool>().resume();
+        Ok(true)
+    }
+
+    /// Removes transactions that spend coins or messages of the `owner`, or that use
+    /// the `contract`, from the `TxPool` with their dependent transactions. Removes all
+    /// transactions if neither is set. Returns the number of removed transactions.
+    async fn flush_tx_pool(
+        &self,
+        ctx: &Context<'_>,
+        owner: Option<Address>,
+        contract: Option<ContractId>,
+    ) -> async_graphql::Result<U64> {
+        require_debug(ctx)?;
+        let filter = match (owner, contract) {
+            (None, None) => FlushFilter::All,
+            (Some(owner), None) => FlushFilter::Owner(owner.0),
+            (None, Some(contract)) => FlushFilter::Contract(contract.0),
+            (Some(_), Some(_)) => {
+                return Err(anyhow!("Either `owner` or `contract`, not both").into())
+            }
+        };
+        let flushed = ctx.data_unchecked::<TxPool>().flush(filter);
+        Ok((flushed as u64).into())
+    }
+
+    /// Writes the state of the `TxPool` as JSON to the new file at `path` relative
+    /// to the dump directory of the node for post-mortem analysis. Existing files
+    /// are never overwritten. Returns the number of dumped transactions.
+    async fn dump_tx_pool(
+        &self,
+        ctx: &Context<'_>,
+        path: String,
+    ) -> async_graphql::Result<U64> {
+        require_debug(ctx)?;
+        let Some(dump_dir) = &ctx.data_unchecked::<Config>().tx_pool_dump_dir else {
+            return Err(
+                anyhow!("The dump directory of the `TxPool` is not configured").into(),
+            )
+        };
+        let path = Path::new(&path);
+        let is_relative = path.components().next().is_some()
+            && path
+                .components()
+                .all(|component| matches!(component, Component::Normal(_)));
+        if !is_relative {
+            return Err(anyhow!(
+                "The dump path must be relative to the dump directory without `..`"
+            )
+            .into())
+        }
+        std::fs::create_dir_all(dump_dir)?;
+        let dumped = ctx.data_unchecked::<TxPool>().dump(&dump_dir.join(path))?;
+        Ok((dumped as u64).into())
+    }
+}
+
+fn require_debug(ctx: &Context<'_>) -> async_graphql::Result<()> {
+    if ctx.data_unchecked::<Config>().debug {
+        Ok(())
+    } else {
+        Err(anyhow!("`debug` must be enabled to use this endpoint").into())
+    }
 }
 
 #[derive(Default)]
@@ -317,14 +445,12 @@ impl TxStatusSubscription {
     {
         let txpool = ctx.data_unchecked::<TxPool>();
         let query: &ReadView = ctx.data_unchecked();
-        let rx = txpool.tx_update_subscribe(id.into())?;
+        let rx = txpool.tx_update_subscribe(id.into(), api_subscriber(ctx))?;
 
         Ok(transaction_status_change(
             move |id| match query.tx_status(&id) {
                 Ok(status) => Ok(Some(status)),
-                Err(StorageError::NotFound(_, _)) => Ok(txpool
-                    .submission_time(id)
-                    .map(|time| txpool::TransactionStatus::Submitted { time })),
+                Err(StorageError::NotFound(_, _)) => Ok(txpool.submitted_status(id)),
                 Err(err) => Err(err),
             },
             rx,
@@ -345,10 +471,10 @@ impl TxStatusSubscription {
         let config = ctx.data_unchecked::<Config>();
         let tx = FuelTx::from_bytes(&tx.0)?;
         let tx_id = tx.id(&config.consensus_parameters.chain_id);
-        let subscription = txpool.tx_update_subscribe(tx_id)?;
+        let subscription = txpool.tx_update_subscribe(tx_id, api_subscriber(ctx))?;
 
         let _: Vec<_> = txpool
-            .insert(vec![Arc::new(tx)])
+            .insert(vec![Arc::new(tx)], api_origin(ctx))
             .await
             .into_iter()
             .try_collect()?;
@@ -372,3 +498,18 @@ impl TxStatusSubscription {
             .take(1))
     }
 }
+
+/// The origin of transactions submitted by the client of the request. Clients without
+/// the API key are submitted anonymously, so their IP addresses aren't tracked.
+fn api_origin(ctx: &Context<'_>) -> TxOrigin {
+    let api_key = match ctx.data_opt::<ClientKey>() {
+        Some(ClientKey::ApiKey(api_key)) => Some(api_key.clone()),
+        _ => None,
+    };
+    TxOrigin::Api { api_key }
+}
+
+/// The client of the request on behalf of which transaction status subscriptions are opened.
+fn api_subscriber(ctx: &Context<'_>) -> Option<Subscriber> {
+    ctx.data_opt::<ClientKey>().map(ClientKey::subscriber)
+}
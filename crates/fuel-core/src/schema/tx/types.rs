@@ -12,7 +12,6 @@ use crate::{
         IntoApiResult,
     },
     query::{
-        SimpleBlockData,
         SimpleTransactionData,
         TransactionQueryData,
     },
@@ -20,6 +19,7 @@ use crate::{
         block::Block,
         contract::Contract,
         scalars::{
+            Amount,
             AssetId,
             Bytes32,
             HexString,
@@ -40,6 +40,7 @@ use async_graphql::{
     Context,
     Enum,
     Object,
+    SimpleObject,
     Union,
 };
 use fuel_core_storage::Error as StorageError;
@@ -76,11 +77,15 @@ use fuel_core_types::{
     fuel_vm::ProgramState as VmProgramState,
     services::{
         executor::{
+            DryRunBundleStep as CoreDryRunBundleStep,
             TransactionExecutionResult,
             TransactionExecutionStatus,
         },
         txpool,
-        txpool::TransactionStatus as TxStatus,
+        txpool::{
+            TransactionStatus as TxStatus,
+            TxOrigin,
+        },
     },
     tai64::Tai64,
 };
@@ -130,6 +135,53 @@ impl From<VmProgramState> for ProgramState {
     }
 }
 
+/// The confirmation status of the transaction included in the block.
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum ConfirmationStatus {
+    /// The block with the transaction is the latest block of the chain.
+    Included,
+    /// Blocks are produced on top of the block with the transaction.
+    Confirmed,
+    /// The block with the transaction is deep enough to be considered final.
+    Finalized,
+}
+
+#[derive(SimpleObject)]
+pub struct TransactionConfirmation {
+    /// The confirmation status defined by the `depth` and the finality depth of the node.
+    status: ConfirmationStatus,
+    /// The number of blocks produced on top of the block with the transaction.
+    depth: U32,
+}
+
+impl TransactionConfirmation {
+    fn new(depth: u32, finality_depth: Option<u32>) -> Self {
+        let status = match finality_depth {
+            Some(finality_depth) if depth >= finality_depth => {
+                ConfirmationStatus::Finalized
+            }
+            _ if depth == 0 => ConfirmationStatus::Included,
+            _ => ConfirmationStatus::Confirmed,
+        };
+        Self {
+            status,
+            depth: depth.into(),
+        }
+    }
+
+    fn of_block(
+        ctx: &Context<'_>,
+        block_id: &primitives::BlockId,
+    ) -> async_graphql::Result<Self> {
+        let query: &ReadView = ctx.data_unchecked();
+        let config = ctx.data_unchecked::<Config>();
+        let height = query.block_height(block_id)?;
+        let tip = query.latest_height()?;
+        let depth = u32::from(tip).saturating_sub(u32::from(height));
+        Ok(Self::new(depth, config.tx_finality_depth))
+    }
+}
+
 #[derive(Union, Debug)]
 pub enum TransactionStatus {
     Submitted(SubmittedStatus),
@@ -138,8 +190,10 @@ pub enum TransactionStatus {
     Failed(FailureStatus),
 }
 
+/// The origin is kept for the conversion back into the status of the pool,
+/// but isn't exposed, because it reveals where the transaction entered the network.
 #[derive(Debug)]
-pub struct SubmittedStatus(pub Tai64);
+pub struct SubmittedStatus(pub Tai64, pub TxOrigin);
 
 #[Object]
 impl SubmittedStatus {
@@ -165,8 +219,16 @@ impl SuccessStatus {
     async fn block(&self, ctx: &Context<'_>) -> async_graphql::Result<Block> {
         let query: &ReadView = ctx.data_unchecked();
         let height = query.block_height(&self.block_id)?;
-        let block = query.block(&height)?;
-        Ok(block.into())
+        let header = query.block_header(&height)?;
+        Ok(header.into())
+    }
+
+    /// The confirmation of the transaction relative to the latest block of the chain.
+    async fn confirmation(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<TransactionConfirmation> {
+        TransactionConfirmation::of_block(ctx, &self.block_id)
     }
 
     async fn time(&self) -> Tai64Timestamp {
@@ -207,8 +269,16 @@ impl FailureStatus {
     async fn block(&self, ctx: &Context<'_>) -> async_graphql::Result<Block> {
         let query: &ReadView = ctx.data_unchecked();
         let height = query.block_height(&self.block_id)?;
-        let block = query.block(&height)?;
-        Ok(block.into())
+        let header = query.block_header(&height)?;
+        Ok(header.into())
+    }
+
+    /// The confirmation of the transaction relative to the latest block of the chain.
+    async fn confirmation(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<TransactionConfirmation> {
+        TransactionConfirmation::of_block(ctx, &self.block_id)
     }
 
     async fn time(&self) -> Tai64Timestamp {
@@ -250,8 +320,8 @@ impl SqueezedOutStatus {
 impl TransactionStatus {
     pub fn new(tx_id: TxId, tx_status: TxStatus) -> Self {
         match tx_status {
-            TxStatus::Submitted { time } => {
-                TransactionStatus::Submitted(SubmittedStatus(time))
+            TxStatus::Submitted { time, origin } => {
+                TransactionStatus::Submitted(SubmittedStatus(time, origin))
             }
             TxStatus::Success {
                 block_id,
@@ -285,8 +355,8 @@ impl TransactionStatus {
 impl From<TransactionStatus> for TxStatus {
     fn from(s: TransactionStatus) -> Self {
         match s {
-            TransactionStatus::Submitted(SubmittedStatus(time)) => {
-                TxStatus::Submitted { time }
+            TransactionStatus::Submitted(SubmittedStatus(time, origin)) => {
+                TxStatus::Submitted { time, origin }
             }
             TransactionStatus::Success(SuccessStatus {
                 block_id,
@@ -336,7 +406,7 @@ impl Policies {
             .map(Into::into)
     }
 
-    async fn max_fee(&self) -> Option<U64> {
+    async fn max_fee(&self) -> Option<Amount> {
         self.0.get(PolicyType::MaxFee).map(Into::into)
     }
 }
@@ -430,7 +500,7 @@ impl Transaction {
         }
     }
 
-    async fn mint_amount(&self) -> Option<U64> {
+    async fn mint_amount(&self) -> Option<Amount> {
         match &self.0 {
             fuel_tx::Transaction::Script(_) | fuel_tx::Transaction::Create(_) => None,
             fuel_tx::Transaction::Mint(mint) => Some((*mint.mint_amount()).into()),
@@ -689,6 +759,47 @@ impl DryRunTransactionExecutionStatus {
     }
 }
 
+/// The result of one transaction of the dry run bundle.
+#[derive(Union)]
+pub enum DryRunBundleStep {
+    Executed(DryRunTransactionExecutionStatus),
+    Skipped(DryRunSkippedTransaction),
+}
+
+impl From<CoreDryRunBundleStep> for DryRunBundleStep {
+    fn from(step: CoreDryRunBundleStep) -> Self {
+        match step {
+            CoreDryRunBundleStep::Executed(status) => {
+                DryRunBundleStep::Executed(DryRunTransactionExecutionStatus(status))
+            }
+            CoreDryRunBundleStep::Skipped { id, reason } => {
+                DryRunBundleStep::Skipped(DryRunSkippedTransaction {
+                    id,
+                    reason: reason.to_string(),
+                })
+            }
+        }
+    }
+}
+
+/// The invalid transaction of the dry run bundle. It doesn't affect the state
+/// used by the next transactions of the bundle.
+pub struct DryRunSkippedTransaction {
+    id: TxId,
+    reason: String,
+}
+
+#[Object]
+impl DryRunSkippedTransaction {
+    async fn id(&self) -> TransactionId {
+        TransactionId(self.id)
+    }
+
+    async fn reason(&self) -> String {
+        self.reason.clone()
+    }
+}
+
 #[tracing::instrument(level = "debug", skip(query, txpool), ret, err)]
 pub(crate) fn get_tx_status(
     id: fuel_core_types::fuel_types::Bytes32,
@@ -703,12 +814,9 @@ pub(crate) fn get_tx_status(
             let status = TransactionStatus::new(id, status);
             Ok(Some(status))
         }
-        None => match txpool.submission_time(id) {
-            Some(submitted_time) => Ok(Some(TransactionStatus::Submitted(
-                SubmittedStatus(submitted_time),
-            ))),
-            _ => Ok(None),
-        },
+        None => Ok(txpool
+            .submitted_status(id)
+            .map(|status| TransactionStatus::new(id, status))),
     }
 }
 
@@ -15,6 +15,7 @@ use fuel_core_services::{
     RunnableService,
     RunnableTask,
     ServiceRunner,
+    ShutdownManager,
     State,
     StateWatcher,
 };
@@ -28,16 +29,27 @@ use tracing::warn;
 pub use config::{
     Config,
     DbType,
+    NodeIdentityConfig,
+    NodeMode,
+    PrimaryReplicationConfig,
     RelayerConsensusConfig,
+    ReplicaConfig,
+    ReplicationConfig,
+    StandbyReplicationConfig,
     VMConfig,
+    VerificationScope,
 };
 pub use fuel_core_services::Service as ServiceTrait;
 
 pub mod adapters;
 pub mod config;
 pub mod genesis;
+pub mod hooks;
+pub mod maintenance;
 pub mod metrics;
 mod query;
+pub mod replica;
+pub mod replication;
 pub mod sub_services;
 
 #[derive(Clone)]
@@ -62,6 +74,8 @@ pub struct SharedState {
     pub database: CombinedDatabase,
     /// Subscribe to new block production.
     pub block_importer: BlockImporterAdapter,
+    /// The address accepting connections of standby nodes, if the node is the primary one.
+    pub replication_address: Option<SocketAddr>,
     /// The config of the service.
     pub config: Config,
 }
@@ -84,6 +98,7 @@ impl FuelService {
     #[tracing::instrument(skip_all, fields(name = %config.name))]
     pub fn new(database: CombinedDatabase, config: Config) -> anyhow::Result<Self> {
         let config = config.make_config_consistent();
+        config.validate()?;
         let task = Task::new(database, config)?;
         let runner = ServiceRunner::new(task);
         let shared = runner.shared.clone();
@@ -107,6 +122,26 @@ impl FuelService {
                         "No RocksDB path configured, initializing database with a tmp directory"
                     );
                     CombinedDatabase::default()
+                } else if config.db_read_only {
+                    tracing::info!(
+                        "Opening database {:?} in the read-only mode",
+                        config.database_path,
+                    );
+                    CombinedDatabase::open_read_only(
+                        &config.database_path,
+                        config.max_database_cache_size,
+                    )?
+                } else if let Some(replica) = &config.replica {
+                    tracing::info!(
+                        "Opening database {:?} as the replica at {:?}",
+                        config.database_path,
+                        replica.secondary_path,
+                    );
+                    CombinedDatabase::open_secondary(
+                        &config.database_path,
+                        &replica.secondary_path,
+                        config.max_database_cache_size,
+                    )?
                 } else {
                     tracing::info!(
                         "Opening database {:?} with cache size \"{}\"",
@@ -209,6 +244,8 @@ pub struct Task {
     services: SubServices,
     /// The address bound by the system for serving the API
     pub shared: SharedState,
+    /// The manager of the graceful shutdown of the block importer.
+    shutdown: ShutdownManager,
 }
 
 impl Task {
@@ -223,12 +260,22 @@ impl Task {
             .and_then(|state| state.height)
             .unwrap_or_default();
         let da_block_height = 0u64.into();
-        database.init(&block_height, &da_block_height)?;
+        // The replica can't modify the database of the primary node,
+        // and the read-only node can't modify its own database.
+        if config.modifies_database() {
+            database.init(&block_height, &da_block_height)?;
+        }
 
         // initialize sub services
         tracing::info!("Initializing sub services");
-        let (services, shared) = sub_services::init_sub_services(&config, database)?;
-        Ok(Task { services, shared })
+        let shutdown = ShutdownManager::new();
+        let (services, shared) =
+            sub_services::init_sub_services(&config, database, shutdown.token())?;
+        Ok(Task {
+            services,
+            shared,
+            shutdown,
+        })
     }
 
     #[cfg(test)]
@@ -256,13 +303,29 @@ impl RunnableService for Task {
         let view = self.shared.database.on_chain().latest_view();
         // check if chain is initialized
         if let Err(err) = view.get_genesis() {
-            if err.is_not_found() {
+            // The replica and the standby receive the genesis block from the primary node.
+            // The read-only node serves the database as it is.
+            if err.is_not_found()
+                && self.shared.config.modifies_database()
+                && !self.shared.config.is_standby()
+            {
                 let result = execute_genesis_block(&self.shared.config, &view)?;
 
                 self.shared.block_importer.commit_result(result).await?;
             }
         }
 
+        if let Some(scope) = self.shared.config.startup_verification {
+            let database = self.shared.database.on_chain().clone();
+            tokio::task::spawn_blocking(move || verify_state(&database, scope)).await??;
+        }
+
+        self.shared
+            .config
+            .hooks
+            .run_before_api_start(&self.shared)
+            .await?;
+
         for service in &self.services {
             service.start_and_await().await?;
         }
@@ -270,6 +333,44 @@ impl RunnableService for Task {
     }
 }
 
+/// Verifies blocks and contracts against their merkle roots and reports the result.
+/// The found corruption doesn't prevent the start of the node,
+/// the operator decides whether to restore the database.
+fn verify_state(database: &Database, scope: VerificationScope) -> anyhow::Result<()> {
+    tracing::info!("Verifying the state of the database: {scope:?}");
+    let report = database.verify_roots(scope, &mut rand::thread_rng())?;
+    let (blocks, contracts) = (&report.blocks, &report.contracts);
+    if report.is_ok() {
+        tracing::info!(
+            "Verified {} blocks and {} contracts without corruption, \
+            the corruption rate of blocks is below {:.4}% and of contracts \
+            is below {:.4}% with the 95% confidence",
+            blocks.verified,
+            contracts.verified,
+            blocks.rate_upper_bound * 100.0,
+            contracts.rate_upper_bound * 100.0
+        );
+    } else {
+        for violation in &report.violations {
+            tracing::error!("The state of the database is corrupted: {violation:?}");
+        }
+        tracing::error!(
+            "Found {} corrupted among {} blocks and {} corrupted among {} contracts, \
+            the estimated corruption rate of blocks is {:.4}% and below {:.4}%, \
+            of contracts is {:.4}% and below {:.4}% with the 95% confidence",
+            blocks.corrupted,
+            blocks.verified,
+            contracts.corrupted,
+            contracts.verified,
+            blocks.estimated_rate * 100.0,
+            blocks.rate_upper_bound * 100.0,
+            contracts.estimated_rate * 100.0,
+            contracts.rate_upper_bound * 100.0
+        );
+    }
+    Ok(())
+}
+
 #[async_trait::async_trait]
 impl RunnableTask for Task {
     #[tracing::instrument(skip_all)]
@@ -293,6 +394,19 @@ impl RunnableTask for Task {
     }
 
     async fn shutdown(self) -> anyhow::Result<()> {
+        self.shared.config.hooks.run_on_shutdown(&self.shared).await;
+
+        // Stop importing new blocks and wait for the commit in progress before stopping
+        // sub-services, so the database is not left with a half-imported block.
+        // Only the block importer uses the shutdown token, other sub-services
+        // are stopped as usual below.
+        let timeout = self.shared.config.shutdown_timeout;
+        if !self.shutdown.shutdown(timeout).await {
+            tracing::error!(
+                "The commit of the block was not finished during {timeout:?} of the shutdown"
+            );
+        }
+
         for service in self.services {
             let result = service.stop_and_await().await;
 
@@ -303,7 +417,9 @@ impl RunnableTask for Task {
                 );
             }
         }
-        self.shared.database.flush()?;
+        if self.shared.config.modifies_database() {
+            self.shared.database.flush()?;
+        }
         Ok(())
     }
 }
@@ -312,6 +428,9 @@ impl RunnableTask for Task {
 mod tests {
     use crate::service::{
         Config,
+        DbType,
+        FuelService,
+        NodeMode,
         Task,
     };
     use fuel_core_services::{
@@ -387,4 +506,108 @@ mod tests {
             assert_eq!(service.borrow_and_update().clone(), State::Stopped);
         }
     }
+
+    #[tokio::test]
+    async fn shutdown_rejects_new_blocks() {
+        let task = Task::new(Default::default(), Config::local_node()).unwrap();
+        let task = task.into_task(&Default::default(), ()).await.unwrap();
+        let block_importer = task.shared.block_importer.clone();
+
+        task.shutdown().await.unwrap();
+
+        let result = block_importer
+            .execute_and_commit(Default::default())
+            .await
+            .expect_err("Importer should reject blocks after shutdown");
+        assert!(result.to_string().contains("shutting down"), "{result}");
+    }
+
+    fn single_node_config() -> Config {
+        let mut config = Config::local_node();
+        config.single_node = true;
+        #[cfg(feature = "p2p")]
+        {
+            config.p2p = None;
+        }
+        config
+    }
+
+    #[tokio::test]
+    async fn single_node_mode_runs_without_network_services() {
+        let config = single_node_config();
+        assert_eq!(config.node_mode(), NodeMode::SingleNode);
+        let mut task = Task::new(Default::default(), config).unwrap();
+
+        // graphql, graphql worker, txpool, PoA
+        assert_eq!(task.sub_services().len(), 4);
+        #[cfg(feature = "p2p")]
+        assert!(task.shared.network.is_none());
+    }
+
+    #[cfg(feature = "p2p")]
+    #[test]
+    fn single_node_mode_rejects_p2p() {
+        let mut config = single_node_config();
+        config.p2p = Config::local_node().p2p;
+
+        let result = FuelService::new(Default::default(), config);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn single_node_mode_rejects_waiting_for_peers() {
+        let mut config = single_node_config();
+        config.min_connected_reserved_peers = 1;
+
+        let result = FuelService::new(Default::default(), config);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn single_node_mode_rejects_disabled_block_production() {
+        let mut config = single_node_config();
+        config.debug = false;
+        config.block_production = crate::service::config::Trigger::Never;
+
+        let result = FuelService::new(Default::default(), config);
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn read_only_mode_runs_only_graphql() {
+        let mut config = Config::local_node();
+        config.db_read_only = true;
+        assert_eq!(config.node_mode(), NodeMode::ReadOnly);
+        let task = Task::new(Default::default(), config).unwrap();
+        let mut task = task.into_task(&Default::default(), ()).await.unwrap();
+
+        assert_eq!(task.sub_services().len(), 1);
+        // The genesis block is not committed into the database.
+        assert!(task.shared.database.on_chain().get_genesis().is_err());
+        task.shutdown().await.unwrap();
+    }
+
+    #[test]
+    fn read_only_mode_rejects_single_node_mode() {
+        let mut config = single_node_config();
+        config.db_read_only = true;
+
+        let result = FuelService::new(Default::default(), config);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn read_only_mode_requires_rocksdb_database_path() {
+        let mut config = Config::local_node();
+        config.db_read_only = true;
+        config.database_type = DbType::InMemory;
+        assert!(config.validate().is_err());
+
+        config.database_type = DbType::RocksDb;
+        config.database_path = Default::default();
+        assert!(config.validate().is_err());
+
+        config.database_path = "/tmp/fuel-core-db".into();
+        assert!(config.validate().is_ok());
+    }
 }
@@ -5,13 +5,17 @@ use crate::{
     },
     service::sub_services::BlockProducerService,
 };
+use fuel_core_chain_config::ConsensusParametersUpgrade;
 use fuel_core_consensus_module::{
     block_verifier::Verifier,
     RelayerConsensusConfig,
 };
 use fuel_core_executor::executor::Executor;
 use fuel_core_services::stream::BoxStream;
-use fuel_core_txpool::service::SharedState as TxPoolSharedState;
+use fuel_core_txpool::{
+    predicate_cache::SharedPredicateCache,
+    service::SharedState as TxPoolSharedState,
+};
 #[cfg(feature = "p2p")]
 use fuel_core_types::services::p2p::peer_reputation::AppScore;
 use fuel_core_types::{
@@ -21,6 +25,8 @@ use fuel_core_types::{
 use std::sync::Arc;
 
 pub mod block_importer;
+#[cfg(feature = "consensus-parameters-canary")]
+pub mod canary;
 pub mod consensus_module;
 pub mod executor;
 pub mod graphql_api;
@@ -50,7 +56,7 @@ impl TxPoolAdapter {
 #[derive(Clone)]
 pub struct TransactionsSource {
     txpool: TxPoolSharedState<P2PAdapter, Database>,
-    _block_height: BlockHeight,
+    block_height: BlockHeight,
 }
 
 impl TransactionsSource {
@@ -60,7 +66,26 @@ impl TransactionsSource {
     ) -> Self {
         Self {
             txpool,
-            _block_height: block_height,
+            block_height,
+        }
+    }
+}
+
+/// Provides transactions with predicates verified by the `TxPool` to the executor.
+#[derive(Debug)]
+pub struct PredicateCacheAdapter {
+    cache: SharedPredicateCache,
+    consensus_parameters_upgrades: Vec<ConsensusParametersUpgrade>,
+}
+
+impl PredicateCacheAdapter {
+    pub fn new(
+        cache: SharedPredicateCache,
+        consensus_parameters_upgrades: Vec<ConsensusParametersUpgrade>,
+    ) -> Self {
+        Self {
+            cache,
+            consensus_parameters_upgrades,
         }
     }
 }
@@ -68,6 +93,10 @@ impl TransactionsSource {
 #[derive(Clone)]
 pub struct ExecutorAdapter {
     pub executor: Arc<Executor<Database, Database<Relayer>>>,
+    /// The canary that checks imported blocks with the candidate consensus parameters
+    /// in the background.
+    #[cfg(feature = "consensus-parameters-canary")]
+    pub canary: Option<Arc<canary::Canary>>,
 }
 
 impl ExecutorAdapter {
@@ -83,8 +112,26 @@ impl ExecutorAdapter {
         };
         Self {
             executor: Arc::new(executor),
+            #[cfg(feature = "consensus-parameters-canary")]
+            canary: None,
         }
     }
+
+    /// Enables the canary mode that executes blocks with the candidate `config`.
+    /// The database should keep the state history for the canary.
+    #[cfg(feature = "consensus-parameters-canary")]
+    pub fn with_canary(
+        mut self,
+        config: fuel_core_executor::Config,
+    ) -> anyhow::Result<Self> {
+        let canary = canary::Canary::spawn(
+            self.executor.database_view_provider.clone(),
+            self.executor.relayer_view_provider.clone(),
+            config,
+        )?;
+        self.canary = Some(Arc::new(canary));
+        Ok(self)
+    }
 }
 
 #[derive(Clone)]
@@ -121,6 +168,19 @@ pub struct MaybeRelayerAdapter {
     pub da_deploy_height: fuel_core_types::blockchain::primitives::DaBlockHeight,
 }
 
+#[cfg(feature = "relayer")]
+impl fuel_core_relayer::ports::FuelBlockDb for Database {
+    fn latest_block_da_height(
+        &self,
+    ) -> fuel_core_storage::Result<
+        Option<fuel_core_types::blockchain::primitives::DaBlockHeight>,
+    > {
+        Ok(self
+            .get_current_block()?
+            .map(|block| block.header().da_height))
+    }
+}
+
 #[derive(Clone)]
 pub struct BlockProducerAdapter {
     pub block_producer: Arc<BlockProducerService>,
@@ -17,6 +17,10 @@ use fuel_core_importer::{
     Config,
     Importer,
 };
+use fuel_core_services::{
+    HealthReporter,
+    ShutdownToken,
+};
 use fuel_core_storage::{
     iter::IterDirection,
     tables::{
@@ -39,10 +43,16 @@ use fuel_core_types::{
         BlockHeight,
         ChainId,
     },
-    services::executor::{
-        ExecutionTypes,
-        Result as ExecutorResult,
-        UncommittedResult as UncommittedExecutionResult,
+    services::{
+        executor::{
+            ExecutionTypes,
+            Result as ExecutorResult,
+            UncommittedResult as UncommittedExecutionResult,
+        },
+        port::{
+            PortError,
+            PortResult,
+        },
     },
 };
 use std::sync::Arc;
@@ -53,8 +63,12 @@ impl BlockImporterAdapter {
         database: Database,
         executor: ExecutorAdapter,
         verifier: VerifierAdapter,
+        shutdown: ShutdownToken,
+        health: HealthReporter,
     ) -> Self {
-        let importer = Importer::new(config, database, executor, verifier);
+        let importer = Importer::new(config, database, executor, verifier)
+            .with_shutdown(shutdown)
+            .with_health(health);
         importer.init_metrics();
         Self {
             block_importer: Arc::new(importer),
@@ -75,8 +89,10 @@ impl BlockVerifier for VerifierAdapter {
         &self,
         consensus: &Consensus,
         block: &Block,
-    ) -> anyhow::Result<()> {
-        self.block_verifier.verify_block_fields(consensus, block)
+    ) -> PortResult<()> {
+        self.block_verifier
+            .verify_block_fields(consensus, block)
+            .map_err(PortError::invalid_input)
     }
 }
 
@@ -125,6 +141,16 @@ impl Executor for ExecutorAdapter {
         block: Block,
     ) -> ExecutorResult<UncommittedExecutionResult<StorageTransaction<Self::Database>>>
     {
+        #[cfg(feature = "consensus-parameters-canary")]
+        if let Some(canary) = &self.canary {
+            let candidate_block = block.clone();
+            let canonical = self._execute_without_commit::<TransactionsSource>(
+                ExecutionTypes::Validation(block),
+            );
+            canary.send(candidate_block, super::canary::outcome(&canonical));
+            return canonical
+        }
+
         self._execute_without_commit::<TransactionsSource>(ExecutionTypes::Validation(
             block,
         ))
@@ -0,0 +1,370 @@
+//! The canary mode of the consensus parameters executes each imported block again
+//! with the candidate consensus parameters. It is the same executor as the canonical
+//! one, so the canary checks the upgrade of the parameters, not a new version of
+//! the executor. The canary runs in its own thread on the state before the block,
+//! so it doesn't slow down the import. Its result is never committed; it is only
+//! compared with the canonical result, and divergences are reported via logs and metrics.
+
+use super::TransactionsSource;
+use crate::database::{
+    database_description::relayer::Relayer,
+    Database,
+};
+use fuel_core_executor::executor::Executor;
+use fuel_core_metrics::importer::importer_metrics;
+use fuel_core_storage::transactional::AtomicView;
+use fuel_core_types::{
+    blockchain::{
+        block::Block,
+        primitives::BlockId,
+    },
+    fuel_types::BlockHeight,
+    services::{
+        block_importer::SharedImportResult,
+        executor::{
+            ExecutionResult,
+            ExecutionTypes,
+            Result as ExecutorResult,
+            TransactionExecutionStatus,
+            UncommittedResult,
+        },
+    },
+};
+use std::sync::{
+    mpsc,
+    Arc,
+};
+use tokio::sync::{
+    broadcast,
+    watch,
+};
+
+/// The maximum number of imported blocks waiting for the canary.
+/// Blocks above the limit are not checked.
+const MAX_PENDING_BLOCKS: usize = 16;
+
+/// The part of the execution result compared by the canary.
+#[derive(Debug)]
+pub struct ExecutionSummary {
+    block_id: BlockId,
+    skipped_transactions: usize,
+    tx_status: Vec<TransactionExecutionStatus>,
+}
+
+impl From<&ExecutionResult> for ExecutionSummary {
+    fn from(result: &ExecutionResult) -> Self {
+        Self {
+            block_id: result.block.id(),
+            skipped_transactions: result.skipped_transactions.len(),
+            tx_status: result.tx_status.clone(),
+        }
+    }
+}
+
+/// The summary of the successful execution, or the description of the error.
+pub type Outcome = Result<ExecutionSummary, String>;
+
+/// Returns the outcome of the execution compared by the canary.
+pub fn outcome<T>(result: &ExecutorResult<UncommittedResult<T>>) -> Outcome {
+    result
+        .as_ref()
+        .map(|result| ExecutionSummary::from(result.result()))
+        .map_err(|e| e.to_string())
+}
+
+/// The canary checking imported blocks in the background.
+pub struct Canary {
+    sender: mpsc::SyncSender<(Block, Outcome)>,
+    database: Database,
+    /// The height of the latest committed block.
+    committed: Arc<watch::Sender<BlockHeight>>,
+}
+
+impl Canary {
+    /// Spawns the thread of the canary that executes blocks with the `config`.
+    /// The executor executes each block on the view of the `database` at the height
+    /// before the block, so the `database` should keep the state history.
+    pub fn spawn(
+        database: Database,
+        relayer: Database<Relayer>,
+        config: fuel_core_executor::Config,
+    ) -> anyhow::Result<Self> {
+        let (sender, receiver) = mpsc::sync_channel(MAX_PENDING_BLOCKS);
+        let (committed, committed_receiver) =
+            watch::channel(AtomicView::latest_height(&database));
+        let config = Arc::new(config);
+        let canary_database = database.clone();
+        std::thread::Builder::new()
+            .name("consensus-parameters-canary".to_string())
+            .spawn(move || {
+                let mut committed = committed_receiver;
+                // The loop ends when the importer drops the sender.
+                for (block, canonical) in receiver {
+                    check(
+                        &canary_database,
+                        &relayer,
+                        &config,
+                        &mut committed,
+                        block,
+                        &canonical,
+                    );
+                }
+            })?;
+        Ok(Self {
+            sender,
+            database,
+            committed: Arc::new(committed),
+        })
+    }
+
+    /// Follows the `blocks` committed by the importer, so the canary executes
+    /// each block after its commit. The blocks are released right away,
+    /// so the canary doesn't delay the import of the next block.
+    pub fn follow(
+        &self,
+        mut blocks: broadcast::Receiver<SharedImportResult>,
+    ) -> anyhow::Result<()> {
+        let committed = self.committed.clone();
+        committed.send_replace(AtomicView::latest_height(&self.database));
+        std::thread::Builder::new()
+            .name("consensus-parameters-canary-blocks".to_string())
+            .spawn(move || loop {
+                match blocks.blocking_recv() {
+                    Ok(result) => {
+                        let height = *result.sealed_block.entity.header().height();
+                        drop(result);
+                        committed.send_if_modified(|committed| {
+                            let modified = height > *committed;
+                            *committed = height.max(*committed);
+                            modified
+                        });
+                    }
+                    // The next block has a greater height anyway.
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            })?;
+        Ok(())
+    }
+
+    /// Sends the imported `block` with the `canonical` result to the canary.
+    pub fn send(&self, block: Block, canonical: Outcome) {
+        if let Err(mpsc::TrySendError::Full((block, _))) =
+            self.sender.try_send((block, canonical))
+        {
+            tracing::warn!(
+                "The consensus parameters canary is behind, the block {} isn't checked",
+                block.header().height()
+            );
+        }
+    }
+}
+
+fn check(
+    database: &Database,
+    relayer: &Database<Relayer>,
+    config: &Arc<fuel_core_executor::Config>,
+    committed: &mut watch::Receiver<BlockHeight>,
+    block: Block,
+    canonical: &Outcome,
+) {
+    let height = *block.header().height();
+    let Some(previous_height) = height.pred() else {
+        return
+    };
+    // The state before the block is modified by the commit of the block, so the
+    // canary waits for the commit and reads the state from the history.
+    if canonical.is_ok()
+        && futures::executor::block_on(committed.wait_for(|h| *h >= height)).is_err()
+    {
+        // The importer is dropped, and the block will never be committed.
+        return
+    }
+    let view = match database.view_at(&previous_height) {
+        Ok(view) => view,
+        Err(e) => {
+            tracing::warn!(
+                "The consensus parameters canary can't check the block {height}: {e}"
+            );
+            return
+        }
+    };
+    let executor = Executor {
+        database_view_provider: view,
+        relayer_view_provider: relayer.clone(),
+        config: config.clone(),
+    };
+    let candidate = executor
+        .execute_without_commit::<TransactionsSource>(ExecutionTypes::Validation(block));
+    report(&height, canonical, &outcome(&candidate));
+}
+
+/// Compares the result with the candidate consensus parameters with the canonical result.
+/// Returns the description of the first found divergence.
+pub fn divergence(canonical: &Outcome, candidate: &Outcome) -> Option<String> {
+    match (canonical, candidate) {
+        (Ok(canonical), Ok(candidate)) => execution_divergence(canonical, candidate),
+        (Ok(_), Err(candidate)) => Some(format!(
+            "The execution with the candidate parameters failed: {candidate}"
+        )),
+        (Err(canonical), Ok(_)) => Some(format!(
+            "The block rejected by the canonical execution was executed \
+            with the candidate parameters: {canonical}"
+        )),
+        (Err(canonical), Err(candidate)) => (canonical != candidate).then(|| {
+            format!(
+                "The executors rejected the block with different errors. \
+                Canonical: {canonical}, candidate: {candidate}"
+            )
+        }),
+    }
+}
+
+fn execution_divergence(
+    canonical: &ExecutionSummary,
+    candidate: &ExecutionSummary,
+) -> Option<String> {
+    let canonical_id = canonical.block_id;
+    let candidate_id = candidate.block_id;
+    if canonical_id != candidate_id {
+        return Some(format!(
+            "The block header or transactions differ. \
+            Canonical block id: {canonical_id}, candidate block id: {candidate_id}"
+        ))
+    }
+
+    if canonical.skipped_transactions != candidate.skipped_transactions {
+        return Some(format!(
+            "The number of skipped transactions differs. Canonical: {}, candidate: {}",
+            canonical.skipped_transactions, candidate.skipped_transactions
+        ))
+    }
+
+    if canonical.tx_status.len() != candidate.tx_status.len() {
+        return Some(format!(
+            "The number of executed transactions differs. Canonical: {}, candidate: {}",
+            canonical.tx_status.len(),
+            candidate.tx_status.len()
+        ))
+    }
+
+    canonical
+        .tx_status
+        .iter()
+        .zip(candidate.tx_status.iter())
+        .find_map(|(canonical, candidate)| {
+            if canonical.id != candidate.id {
+                Some(format!(
+                    "The order of transactions differs. Canonical: {}, candidate: {}",
+                    canonical.id, candidate.id
+                ))
+            } else if canonical.result != candidate.result {
+                Some(format!(
+                    "The result of the transaction {} differs. \
+                    Canonical: {:?}, candidate: {:?}",
+                    canonical.id, canonical.result, candidate.result
+                ))
+            } else if canonical.receipts != candidate.receipts {
+                Some(format!(
+                    "The receipts of the transaction {} differ. \
+                    Canonical: {:?}, candidate: {:?}",
+                    canonical.id, canonical.receipts, candidate.receipts
+                ))
+            } else {
+                None
+            }
+        })
+}
+
+/// Reports the divergence between the canonical and candidate results, if any.
+pub fn report(height: &BlockHeight, canonical: &Outcome, candidate: &Outcome) {
+    if let Some(divergence) = divergence(canonical, candidate) {
+        importer_metrics()
+            .consensus_parameters_canary_divergences
+            .inc();
+        tracing::error!(
+            "The candidate consensus parameters diverged at the block {height}: {divergence}"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::{
+        fuel_tx::{
+            Bytes32,
+            Receipt,
+            ScriptExecutionResult,
+        },
+        services::executor::{
+            Error as ExecutorError,
+            TransactionExecutionResult,
+        },
+    };
+
+    fn status(id: u8, gas_used: u64) -> TransactionExecutionStatus {
+        TransactionExecutionStatus {
+            id: Bytes32::from([id; 32]),
+            result: TransactionExecutionResult::Success { result: None },
+            receipts: vec![Receipt::script_result(
+                ScriptExecutionResult::Success,
+                gas_used,
+            )],
+        }
+    }
+
+    fn executed(tx_status: Vec<TransactionExecutionStatus>) -> Outcome {
+        Ok(ExecutionSummary::from(&ExecutionResult {
+            block: Block::default(),
+            skipped_transactions: vec![],
+            tx_status,
+            state_changes: vec![],
+        }))
+    }
+
+    fn rejected() -> Outcome {
+        Err(ExecutorError::MintMissing.to_string())
+    }
+
+    #[test]
+    fn same_results_do_not_diverge() {
+        let canonical = executed(vec![status(1, 100), status(2, 200)]);
+        let candidate = executed(vec![status(1, 100), status(2, 200)]);
+
+        assert_eq!(divergence(&canonical, &candidate), None);
+    }
+
+    #[test]
+    fn same_errors_do_not_diverge() {
+        let canonical = rejected();
+        let candidate = rejected();
+
+        assert_eq!(divergence(&canonical, &candidate), None);
+    }
+
+    #[test]
+    fn different_receipts_diverge() {
+        let canonical = executed(vec![status(1, 100), status(2, 200)]);
+        let candidate = executed(vec![status(1, 100), status(2, 201)]);
+
+        let divergence = divergence(&canonical, &candidate).expect("Should diverge");
+        assert!(divergence.contains("receipts"), "{divergence}");
+    }
+
+    #[test]
+    fn different_number_of_transactions_diverge() {
+        let canonical = executed(vec![status(1, 100), status(2, 200)]);
+        let candidate = executed(vec![status(1, 100)]);
+
+        assert!(divergence(&canonical, &candidate).is_some());
+    }
+
+    #[test]
+    fn failure_of_candidate_diverges() {
+        let canonical = executed(vec![status(1, 100)]);
+        let candidate = rejected();
+
+        assert!(divergence(&canonical, &candidate).is_some());
+    }
+}
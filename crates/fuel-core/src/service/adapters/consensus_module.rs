@@ -30,6 +30,7 @@ use fuel_core_types::{
 use std::sync::Arc;
 
 pub mod poa;
+pub mod remote_signer;
 
 impl VerifierAdapter {
     pub fn new(config: &Config, database: Database) -> Self {
@@ -21,6 +21,7 @@ use fuel_core_poa::{
         Mode,
         SharedState,
     },
+    Trigger,
 };
 use fuel_core_services::stream::BoxStream;
 use fuel_core_storage::transactional::StorageTransaction;
@@ -59,6 +60,14 @@ impl PoAAdapter {
             .manually_produce_block(start_time, mode)
             .await
     }
+
+    pub async fn set_trigger(&self, trigger: Trigger) -> anyhow::Result<()> {
+        self.shared_state
+            .as_ref()
+            .ok_or(anyhow!("The block production is disabled"))?
+            .set_trigger(trigger)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -71,6 +80,10 @@ impl ConsensusModulePort for PoAAdapter {
         self.manually_produce_blocks(start_time, Mode::Blocks { number_of_blocks })
             .await
     }
+
+    async fn set_block_production(&self, trigger: Trigger) -> anyhow::Result<()> {
+        self.set_trigger(trigger).await
+    }
 }
 
 impl TransactionPool for TxPoolAdapter {
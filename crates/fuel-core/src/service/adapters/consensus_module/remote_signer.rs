@@ -0,0 +1,315 @@
+use crate::chain_config::ConsensusConfig;
+use anyhow::Context;
+use fuel_core_poa::{
+    signer::BlockSigner,
+    verifier::verify_consensus,
+};
+use fuel_core_types::{
+    blockchain::{
+        block::Block,
+        consensus::{
+            poa::PoAConsensus,
+            Consensus,
+        },
+    },
+    fuel_crypto::Signature,
+    fuel_types::BlockHeight,
+    secrecy::{
+        ExposeSecret,
+        Secret,
+    },
+};
+use std::{
+    net::IpAddr,
+    str::FromStr,
+    time::Duration,
+};
+
+/// The request to the external signing service.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct SignRequest {
+    /// The height of the signed block.
+    pub height: u32,
+    /// The hex encoded message to sign.
+    pub message: String,
+}
+
+/// The response of the external signing service.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct SignResponse {
+    /// The hex encoded signature of the message.
+    pub signature: String,
+}
+
+/// The signer that delegates the signing of blocks to the external signing service,
+/// so the node never holds the consensus key.
+///
+/// The signer sends the [`SignRequest`] as a JSON body of the `POST` request to the
+/// `url` and expects the [`SignResponse`] in reply. The signature is accepted only
+/// if it is made by the authority key scheduled for the block by the `consensus`.
+#[derive(Debug, Clone)]
+pub struct RemoteSigner {
+    url: reqwest::Url,
+    client: reqwest::Client,
+    consensus: ConsensusConfig,
+    bearer_token: Option<Secret<String>>,
+}
+
+impl RemoteSigner {
+    /// Creates the signer for the signing service at the `url`. The `http` scheme
+    /// is allowed only for the loopback host, other hosts require `https`.
+    pub fn new(
+        url: &str,
+        timeout: Duration,
+        consensus: ConsensusConfig,
+    ) -> anyhow::Result<Self> {
+        let url: reqwest::Url = url
+            .parse()
+            .context("failed to parse the URL of the signing service")?;
+        match url.scheme() {
+            "https" => {}
+            "http" if is_loopback(&url) => {}
+            "http" => anyhow::bail!(
+                "the signing service at `{url}` should use `https`, \
+                `http` is allowed only for the loopback host"
+            ),
+            scheme => {
+                anyhow::bail!("the unsupported scheme `{scheme}` of the signing service")
+            }
+        }
+        let client = reqwest::Client::builder()
+            .timeout(timeout)
+            .build()
+            .context("failed to build the client of the signing service")?;
+        Ok(Self {
+            url,
+            client,
+            consensus,
+            bearer_token: None,
+        })
+    }
+
+    /// Authenticates requests to the signing service with the bearer `token`.
+    pub fn with_bearer_token(mut self, token: Secret<String>) -> Self {
+        self.bearer_token = Some(token);
+        self
+    }
+}
+
+fn is_loopback(url: &reqwest::Url) -> bool {
+    match url.host_str() {
+        Some("localhost") => true,
+        // The IPv6 host is enclosed in brackets.
+        Some(host) => host
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .parse::<IpAddr>()
+            .map_or(false, |ip| ip.is_loopback()),
+        None => false,
+    }
+}
+
+#[async_trait::async_trait]
+impl BlockSigner for RemoteSigner {
+    fn is_available(&self, _: &BlockHeight) -> bool {
+        true
+    }
+
+    async fn seal_block(&self, block: &Block) -> anyhow::Result<Consensus> {
+        let message = block.id().into_message();
+        let request = SignRequest {
+            height: **block.header().height(),
+            message: hex::encode(*message),
+        };
+
+        let mut request = self
+            .client
+            .post(self.url.clone())
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(serde_json::to_vec(&request)?);
+        if let Some(token) = &self.bearer_token {
+            request = request.bearer_auth(token.expose_secret());
+        }
+        let response = request
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .context("the signing service failed to sign the block")?
+            .bytes()
+            .await?;
+        let response: SignResponse = serde_json::from_slice(&response)
+            .context("the signing service returned the invalid response")?;
+        let signature = Signature::from_str(&response.signature)
+            .map_err(|e| anyhow::anyhow!("the invalid signature: {e:?}"))?;
+
+        // Other nodes reject the block sealed by a wrong key, so the block is not
+        // produced at all if the service signs it with a different key.
+        let seal = PoAConsensus::new(signature);
+        if !verify_consensus(&self.consensus, block.header(), &seal) {
+            anyhow::bail!(
+                "the signing service signed the block at height {} \
+                with a key other than the scheduled authority key",
+                block.header().height()
+            )
+        }
+
+        Ok(Consensus::PoA(seal))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{
+        http::{
+            header::AUTHORIZATION,
+            HeaderMap,
+            StatusCode,
+        },
+        routing::post,
+        Json,
+        Router,
+    };
+    use fuel_core_types::{
+        blockchain::header::PartialBlockHeader,
+        fuel_crypto::{
+            Message,
+            SecretKey,
+        },
+        fuel_tx::Input,
+    };
+    use rand::{
+        rngs::StdRng,
+        SeedableRng,
+    };
+    use std::net::{
+        SocketAddr,
+        TcpListener,
+    };
+
+    const TOKEN: &str = "secret-token";
+
+    fn key(seed: u64) -> SecretKey {
+        let mut rng = StdRng::seed_from_u64(seed);
+        SecretKey::random(&mut rng)
+    }
+
+    fn consensus(secret_key: &SecretKey) -> ConsensusConfig {
+        ConsensusConfig::PoA {
+            signing_key: Input::owner(&secret_key.public_key()),
+        }
+    }
+
+    /// Spawns the signing service that signs with the `secret_key` the requests
+    /// authorized with the [`TOKEN`].
+    fn spawn_signing_service(secret_key: SecretKey) -> SocketAddr {
+        let sign = move |headers: HeaderMap, Json(request): Json<SignRequest>| async move {
+            let authorization = headers
+                .get(AUTHORIZATION)
+                .and_then(|value| value.to_str().ok());
+            if authorization != Some(format!("Bearer {TOKEN}").as_str()) {
+                return Err(StatusCode::UNAUTHORIZED)
+            }
+            let mut message = [0u8; Message::LEN];
+            hex::decode_to_slice(request.message, &mut message).unwrap();
+            let signature = Signature::sign(&secret_key, &Message::from_bytes(message));
+            Ok(Json(SignResponse {
+                signature: signature.to_string(),
+            }))
+        };
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let server = axum::Server::from_tcp(listener)
+            .unwrap()
+            .serve(Router::new().route("/", post(sign)).into_make_service());
+        tokio::spawn(server);
+        address
+    }
+
+    fn signer(address: SocketAddr, consensus: ConsensusConfig) -> RemoteSigner {
+        let url = format!("http://{address}/");
+        RemoteSigner::new(&url, Duration::from_secs(10), consensus)
+            .unwrap()
+            .with_bearer_token(Secret::new(TOKEN.to_string()))
+    }
+
+    #[tokio::test]
+    async fn remote_signer_seals_the_block_with_the_signature_of_the_service() {
+        let secret_key = key(1);
+        let address = spawn_signing_service(secret_key);
+        let signer = signer(address, consensus(&secret_key));
+        let block = Block::new(PartialBlockHeader::default(), vec![], &[]);
+
+        let consensus = signer.seal_block(&block).await.unwrap();
+
+        let Consensus::PoA(poa) = consensus else {
+            panic!("Expected PoA consensus")
+        };
+        let signer = poa.signature.recover(&block.id().into_message()).unwrap();
+        assert_eq!(
+            Input::owner(&signer),
+            Input::owner(&secret_key.public_key())
+        );
+    }
+
+    #[tokio::test]
+    async fn remote_signer_rejects_the_signature_of_another_key() {
+        // Given
+        let address = spawn_signing_service(key(1));
+        let signer = signer(address, consensus(&key(2)));
+        let block = Block::new(PartialBlockHeader::default(), vec![], &[]);
+
+        // When
+        let result = signer.seal_block(&block).await;
+
+        // Then
+        let err = result.expect_err("Should reject the signature");
+        assert!(err.to_string().contains("scheduled authority key"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn remote_signer_fails_without_the_bearer_token() {
+        // Given
+        let secret_key = key(1);
+        let address = spawn_signing_service(secret_key);
+        let url = format!("http://{address}/");
+        let signer =
+            RemoteSigner::new(&url, Duration::from_secs(10), consensus(&secret_key))
+                .unwrap();
+        let block = Block::new(PartialBlockHeader::default(), vec![], &[]);
+
+        // When
+        let result = signer.seal_block(&block).await;
+
+        // Then
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn remote_signer_fails_if_service_is_unavailable() {
+        let url = "http://127.0.0.1:1/";
+        let signer =
+            RemoteSigner::new(url, Duration::from_secs(10), consensus(&key(1))).unwrap();
+        let block = Block::new(PartialBlockHeader::default(), vec![], &[]);
+
+        let result = signer.seal_block(&block).await;
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn remote_signer_allows_http_only_for_the_loopback_host() {
+        let new = |url: &str| {
+            RemoteSigner::new(url, Duration::from_secs(10), consensus(&key(1)))
+        };
+
+        assert!(new("http://127.0.0.1:4001/").is_ok());
+        assert!(new("http://[::1]:4001/").is_ok());
+        assert!(new("http://localhost:4001/").is_ok());
+        assert!(new("https://signer.example.com/").is_ok());
+        assert!(new("http://signer.example.com/").is_err());
+        assert!(new("http://10.0.0.1:4001/").is_err());
+        assert!(new("ftp://127.0.0.1/").is_err());
+    }
+}
@@ -5,12 +5,17 @@ use crate::{
     },
     service::adapters::{
         ExecutorAdapter,
+        PredicateCacheAdapter,
         TransactionsSource,
     },
 };
+use fuel_core_chain_config::upgrade_at;
 use fuel_core_executor::{
     executor::ExecutionBlockWithSource,
-    ports::MaybeCheckedTransaction,
+    ports::{
+        MaybeCheckedTransaction,
+        PredicateVerificationCache,
+    },
 };
 use fuel_core_storage::{
     transactional::StorageTransaction,
@@ -19,13 +24,17 @@ use fuel_core_storage::{
 use fuel_core_types::{
     blockchain::primitives::DaBlockHeight,
     fuel_tx,
+    fuel_types::BlockHeight,
+    fuel_vm::checked_transaction::Checked,
     services::{
         block_producer::Components,
         executor::{
+            DryRunBundleStep,
             Result as ExecutorResult,
             TransactionExecutionStatus,
             UncommittedResult,
         },
+        port::PortResult,
         relayer::Event,
     },
 };
@@ -33,13 +42,26 @@ use fuel_core_types::{
 impl fuel_core_executor::ports::TransactionsSource for TransactionsSource {
     fn next(&self, gas_limit: u64) -> Vec<MaybeCheckedTransaction> {
         self.txpool
-            .select_transactions(gas_limit)
+            .select_transactions(self.block_height, gas_limit)
             .into_iter()
             .map(|tx| MaybeCheckedTransaction::CheckedTransaction(tx.as_ref().into()))
             .collect()
     }
 }
 
+impl PredicateVerificationCache for PredicateCacheAdapter {
+    fn verified(
+        &self,
+        height: BlockHeight,
+        tx: &Checked<fuel_tx::Transaction>,
+    ) -> Option<Checked<fuel_tx::Transaction>> {
+        // The same version of the consensus parameters as the `TxPool` uses.
+        let version = upgrade_at(&self.consensus_parameters_upgrades, height)
+            .map(|upgrade| upgrade.height);
+        self.cache.lock().verified(version, tx)
+    }
+}
+
 impl ExecutorAdapter {
     pub(crate) fn _execute_without_commit<TxSource>(
         &self,
@@ -58,6 +80,14 @@ impl ExecutorAdapter {
     ) -> ExecutorResult<Vec<TransactionExecutionStatus>> {
         self.executor.dry_run(block, utxo_validation)
     }
+
+    pub(crate) fn _dry_run_bundle(
+        &self,
+        block: Components<Vec<fuel_tx::Transaction>>,
+        utxo_validation: Option<bool>,
+    ) -> ExecutorResult<Vec<DryRunBundleStep>> {
+        self.executor.dry_run_bundle(block, utxo_validation)
+    }
 }
 
 /// Implemented to satisfy: `GenesisCommitment for ContractRef<&'a mut Database>`
@@ -79,13 +109,15 @@ impl fuel_core_executor::ports::RelayerPort for Database<Relayer> {
         }
     }
 
-    fn get_events(&self, _da_height: &DaBlockHeight) -> anyhow::Result<Vec<Event>> {
+    fn get_events(&self, _da_height: &DaBlockHeight) -> PortResult<Vec<Event>> {
         #[cfg(feature = "relayer")]
         {
             use fuel_core_storage::StorageAsRef;
+            use fuel_core_types::services::port::PortError;
             let events = self
                 .storage::<fuel_core_relayer::storage::EventsHistory>()
-                .get(_da_height)?
+                .get(_da_height)
+                .map_err(PortError::fatal)?
                 .map(|cow| cow.into_owned())
                 .unwrap_or_default();
             Ok(events)
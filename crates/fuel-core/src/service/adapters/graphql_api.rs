@@ -20,7 +20,11 @@ use async_trait::async_trait;
 use fuel_core_services::stream::BoxStream;
 use fuel_core_storage::Result as StorageResult;
 use fuel_core_txpool::{
-    service::TxStatusMessage,
+    admin::FlushFilter,
+    service::{
+        Subscriber,
+        TxStatusMessage,
+    },
     types::TxId,
 };
 use fuel_core_types::{
@@ -29,14 +33,24 @@ use fuel_core_types::{
     fuel_types::BlockHeight,
     services::{
         block_importer::SharedImportResult,
-        executor::TransactionExecutionStatus,
-        p2p::PeerInfo,
-        txpool::InsertionResult,
+        executor::{
+            DryRunBundleStep,
+            TransactionExecutionStatus,
+        },
+        p2p::{
+            NetworkInfo,
+            PeerInfo,
+        },
+        txpool::{
+            InsertionResult,
+            TransactionStatus,
+            TxOrigin,
+        },
     },
-    tai64::Tai64,
 };
 use std::{
     ops::Deref,
+    path::Path,
     sync::Arc,
 };
 
@@ -51,24 +65,44 @@ impl TxPoolPort for TxPoolAdapter {
             .map(|info| info.tx().clone().deref().into())
     }
 
-    fn submission_time(&self, id: TxId) -> Option<Tai64> {
-        self.service
-            .find_one(id)
-            .map(|info| Tai64::from_unix(info.submitted_time().as_secs() as i64))
+    fn min_gas_price(&self) -> u64 {
+        self.service.min_gas_price()
+    }
+
+    fn submitted_status(&self, id: TxId) -> Option<TransactionStatus> {
+        self.service.find_one(id).map(Into::into)
     }
 
     async fn insert(
         &self,
         txs: Vec<Arc<Transaction>>,
+        origin: TxOrigin,
     ) -> Vec<anyhow::Result<InsertionResult>> {
-        self.service.insert(txs).await
+        self.service.insert(txs, origin).await
     }
 
     fn tx_update_subscribe(
         &self,
         id: TxId,
+        subscriber: Option<Subscriber>,
     ) -> anyhow::Result<BoxStream<TxStatusMessage>> {
-        self.service.tx_update_subscribe(id)
+        self.service.tx_update_subscribe_as(id, subscriber)
+    }
+
+    fn pause(&self) {
+        self.service.pause()
+    }
+
+    fn resume(&self) {
+        self.service.resume()
+    }
+
+    fn flush(&self, filter: FlushFilter) -> usize {
+        self.service.flush(filter).len()
+    }
+
+    fn dump(&self, path: &Path) -> anyhow::Result<usize> {
+        self.service.dump(path)
     }
 }
 
@@ -94,6 +128,17 @@ impl BlockProducerPort for BlockProducerAdapter {
             .dry_run(transactions, height, utxo_validation)
             .await
     }
+
+    async fn dry_run_bundle(
+        &self,
+        transactions: Vec<Transaction>,
+        height: Option<BlockHeight>,
+        utxo_validation: Option<bool>,
+    ) -> anyhow::Result<Vec<DryRunBundleStep>> {
+        self.block_producer
+            .dry_run_bundle(transactions, height, utxo_validation)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -132,6 +177,17 @@ impl P2pPort for P2PAdapter {
             Ok(vec![])
         }
     }
+
+    fn network_info(&self) -> Option<NetworkInfo> {
+        #[cfg(feature = "p2p")]
+        {
+            self.service.as_ref().map(|service| service.network_info())
+        }
+        #[cfg(not(feature = "p2p"))]
+        {
+            None
+        }
+    }
 }
 
 impl worker::BlockImporter for BlockImporterAdapter {
@@ -28,8 +28,14 @@ use fuel_core_types::{
         Bytes32,
         TxPointer,
     },
-    fuel_types::BlockHeight,
-    services::txpool::TransactionStatus,
+    fuel_types::{
+        BlockHeight,
+        ContractId,
+    },
+    services::{
+        executor::ContractStateChange,
+        txpool::TransactionStatus,
+    },
 };
 
 impl OffChainDatabase for Database<OffChain> {
@@ -53,6 +59,15 @@ impl OffChainDatabase for Database<OffChain> {
             .map(|result| result.map_err(StorageError::from))
             .into_boxed()
     }
+
+    fn contract_state_changes(
+        &self,
+        contract_id: ContractId,
+        from: BlockHeight,
+        to: BlockHeight,
+    ) -> BoxedIter<StorageResult<(BlockHeight, ContractStateChange)>> {
+        Database::contract_state_changes(self, contract_id, from, to).into_boxed()
+    }
 }
 
 impl worker::OffChainDatabase for Database<OffChain> {
@@ -69,9 +84,19 @@ impl worker::OffChainDatabase for Database<OffChain> {
     fn update_tx_status(
         &mut self,
         id: &Bytes32,
+        tx_pointer: TxPointer,
         status: TransactionStatus,
     ) -> StorageResult<Option<TransactionStatus>> {
-        Database::update_tx_status(self, id, status)
+        Database::update_tx_status(self, id, tx_pointer, status)
+    }
+
+    fn record_contract_state_change(
+        &mut self,
+        block_height: BlockHeight,
+        change: &ContractStateChange,
+    ) -> StorageResult<()> {
+        Database::record_contract_state_change(self, block_height, change)?;
+        Ok(())
     }
 
     fn increase_tx_count(&mut self, new_txs_count: u64) -> StorageResult<u64> {
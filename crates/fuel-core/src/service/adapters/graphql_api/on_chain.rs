@@ -24,6 +24,7 @@ use fuel_core_txpool::types::ContractId;
 use fuel_core_types::{
     blockchain::{
         block::CompressedBlock,
+        header::BlockHeader,
         primitives::{
             BlockId,
             DaBlockHeight,
@@ -58,6 +59,19 @@ impl DatabaseBlocks for Database {
             .into_boxed()
     }
 
+    fn block_header(&self, height: &BlockHeight) -> StorageResult<BlockHeader> {
+        self.get_block_header(height)
+            .and_then(|header| header.ok_or(not_found!(FuelBlocks)))
+    }
+
+    fn block_headers(
+        &self,
+        height: Option<BlockHeight>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<BlockHeader>> {
+        Database::block_headers(self, height.as_ref(), direction).into_boxed()
+    }
+
     fn latest_height(&self) -> StorageResult<BlockHeight> {
         self.latest_block_height()
             .transpose()
@@ -136,4 +150,20 @@ impl OnChainDatabase for Database {
             .map(|res| res.map_err(StorageError::from))
             .into_boxed()
     }
+
+    fn coin_balances_dirty(&self) -> StorageResult<bool> {
+        Database::coin_balances_dirty(self)
+    }
+
+    fn coin_balance(&self, owner: &Address, asset_id: &AssetId) -> StorageResult<u64> {
+        Database::coin_balance(self, owner, asset_id)
+    }
+
+    fn coin_balances(
+        &self,
+        owner: &Address,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(AssetId, u64)>> {
+        Database::coin_balances(self, owner, Some(direction))
+    }
 }
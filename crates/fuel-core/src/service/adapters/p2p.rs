@@ -1,8 +1,12 @@
-use super::BlockImporterAdapter;
+use super::{
+    BlockImporterAdapter,
+    TxPoolAdapter,
+};
 use crate::database::Database;
 use fuel_core_p2p::ports::{
     BlockHeightImporter,
     P2pDb,
+    TxPool,
 };
 use fuel_core_services::stream::BoxStream;
 use fuel_core_storage::Result as StorageResult;
@@ -11,6 +15,10 @@ use fuel_core_types::{
         consensus::Genesis,
         SealedBlockHeader,
     },
+    fuel_tx::{
+        Transaction,
+        TxId,
+    },
     fuel_types::BlockHeight,
     services::p2p::Transactions,
 };
@@ -49,3 +57,18 @@ impl BlockHeightImporter for BlockImporterAdapter {
         )
     }
 }
+
+impl TxPool for TxPoolAdapter {
+    fn tx_ids(&self, max_txs: usize) -> Vec<TxId> {
+        self.service.tx_ids(max_txs)
+    }
+
+    fn transactions(&self, tx_ids: &[TxId]) -> Vec<Transaction> {
+        self.service
+            .find(tx_ids.to_vec())
+            .into_iter()
+            .flatten()
+            .map(|info| Transaction::from(info.tx().as_ref()))
+            .collect()
+    }
+}
@@ -34,6 +34,7 @@ use fuel_core_types::{
     services::{
         block_producer::Components,
         executor::{
+            DryRunBundleStep,
             ExecutionTypes,
             Result as ExecutorResult,
             TransactionExecutionStatus,
@@ -102,6 +103,14 @@ impl fuel_core_producer::ports::DryRunner for ExecutorAdapter {
     ) -> ExecutorResult<Vec<TransactionExecutionStatus>> {
         self._dry_run(block, utxo_validation)
     }
+
+    fn dry_run_bundle(
+        &self,
+        block: Components<Vec<fuel_tx::Transaction>>,
+        utxo_validation: Option<bool>,
+    ) -> ExecutorResult<Vec<DryRunBundleStep>> {
+        self._dry_run_bundle(block, utxo_validation)
+    }
 }
 
 #[async_trait::async_trait]
@@ -18,14 +18,20 @@ use fuel_core_types::{
         SealedBlockHeader,
     },
     fuel_types::BlockHeight,
-    services::p2p::{
-        peer_reputation::{
-            AppScore,
-            PeerReport,
+    services::{
+        p2p::{
+            peer_reputation::{
+                AppScore,
+                PeerReport,
+            },
+            PeerId,
+            SourcePeer,
+            Transactions,
+        },
+        port::{
+            PortError,
+            PortResult,
         },
-        PeerId,
-        SourcePeer,
-        Transactions,
     },
 };
 use std::ops::Range;
@@ -49,11 +55,13 @@ impl PeerToPeerPort for P2PAdapter {
     async fn get_sealed_block_headers(
         &self,
         block_height_range: Range<u32>,
-    ) -> anyhow::Result<SourcePeer<Option<Vec<SealedBlockHeader>>>> {
+    ) -> PortResult<SourcePeer<Option<Vec<SealedBlockHeader>>>> {
         let result = if let Some(service) = &self.service {
             service.get_sealed_block_headers(block_height_range).await
         } else {
-            Err(anyhow::anyhow!("No P2P service available"))
+            Err(PortError::fatal(anyhow::anyhow!(
+                "No P2P service available"
+            )))
         };
         match result {
             Ok((peer_id, headers)) => {
@@ -68,7 +76,7 @@ impl PeerToPeerPort for P2PAdapter {
     async fn get_transactions(
         &self,
         range: SourcePeer<Range<u32>>,
-    ) -> anyhow::Result<Option<Vec<Transactions>>> {
+    ) -> PortResult<Option<Vec<Transactions>>> {
         let SourcePeer {
             peer_id,
             data: range,
@@ -78,18 +86,22 @@ impl PeerToPeerPort for P2PAdapter {
                 .get_transactions_from_peer(peer_id.into(), range)
                 .await
         } else {
-            Err(anyhow::anyhow!("No P2P service available"))
+            Err(PortError::fatal(anyhow::anyhow!(
+                "No P2P service available"
+            )))
         }
     }
 
-    fn report_peer(&self, peer: PeerId, report: PeerReportReason) -> anyhow::Result<()> {
+    fn report_peer(&self, peer: PeerId, report: PeerReportReason) -> PortResult<()> {
         if let Some(service) = &self.service {
             let service_name = "Sync";
             let new_report = self.process_report(report);
             service.report_peer(peer, new_report, service_name)?;
             Ok(())
         } else {
-            Err(anyhow::anyhow!("No P2P service available"))
+            Err(PortError::fatal(anyhow::anyhow!(
+                "No P2P service available"
+            )))
         }
     }
 }
@@ -27,6 +27,7 @@ use fuel_core_types::{
         UtxoId,
     },
     fuel_types::{
+        BlockHeight,
         ContractId,
         Nonce,
     },
@@ -35,8 +36,11 @@ use fuel_core_types::{
         p2p::{
             GossipsubMessageAcceptance,
             GossipsubMessageInfo,
+            PeerId,
+            ShortTxId,
             TransactionGossipData,
         },
+        port::PortResult,
     },
 };
 use std::sync::Arc;
@@ -48,10 +52,11 @@ impl BlockImporter for BlockImporterAdapter {
 }
 
 #[cfg(feature = "p2p")]
+#[async_trait::async_trait]
 impl fuel_core_txpool::ports::PeerToPeer for P2PAdapter {
     type GossipedTransaction = TransactionGossipData;
 
-    fn broadcast_transaction(&self, transaction: Arc<Transaction>) -> anyhow::Result<()> {
+    fn broadcast_transaction(&self, transaction: Arc<Transaction>) -> PortResult<()> {
         if let Some(service) = &self.service {
             service.broadcast_transaction(transaction)
         } else {
@@ -78,23 +83,72 @@ impl fuel_core_txpool::ports::PeerToPeer for P2PAdapter {
         &self,
         message_info: GossipsubMessageInfo,
         validity: GossipsubMessageAcceptance,
-    ) -> anyhow::Result<()> {
+    ) -> PortResult<()> {
         if let Some(service) = &self.service {
             service.notify_gossip_transaction_validity(message_info, validity)
         } else {
             Ok(())
         }
     }
+
+    fn new_peers_events(&self) -> BoxStream<PeerId> {
+        use tokio_stream::{
+            wrappers::BroadcastStream,
+            StreamExt,
+        };
+        if let Some(service) = &self.service {
+            Box::pin(
+                BroadcastStream::new(service.subscribe_new_peers())
+                    .filter_map(|result| result.ok()),
+            )
+        } else {
+            fuel_core_services::stream::IntoBoxStream::into_boxed(tokio_stream::pending())
+        }
+    }
+
+    async fn request_tx_pool_summary(
+        &self,
+        peer_id: PeerId,
+    ) -> PortResult<Option<Vec<ShortTxId>>> {
+        if let Some(service) = &self.service {
+            service.get_tx_pool_summary_from_peer(peer_id).await
+        } else {
+            Ok(None)
+        }
+    }
+
+    async fn request_pooled_transactions(
+        &self,
+        peer_id: PeerId,
+        tx_ids: Vec<ShortTxId>,
+    ) -> PortResult<Option<Vec<Transaction>>> {
+        use fuel_core_p2p::request_response::messages::MAX_POOLED_TRANSACTIONS_PER_REQUEST;
+
+        let Some(service) = &self.service else {
+            return Ok(None)
+        };
+
+        // Split the request into chunks accepted by peers.
+        let mut transactions = vec![];
+        for chunk in tx_ids.chunks(MAX_POOLED_TRANSACTIONS_PER_REQUEST) {
+            match service
+                .get_pooled_transactions_from_peer(peer_id.clone(), chunk.to_vec())
+                .await?
+            {
+                Some(txs) => transactions.extend(txs),
+                None => break,
+            }
+        }
+        Ok(Some(transactions))
+    }
 }
 
 #[cfg(not(feature = "p2p"))]
+#[async_trait::async_trait]
 impl fuel_core_txpool::ports::PeerToPeer for P2PAdapter {
     type GossipedTransaction = TransactionGossipData;
 
-    fn broadcast_transaction(
-        &self,
-        _transaction: Arc<Transaction>,
-    ) -> anyhow::Result<()> {
+    fn broadcast_transaction(&self, _transaction: Arc<Transaction>) -> PortResult<()> {
         Ok(())
     }
 
@@ -106,9 +160,28 @@ impl fuel_core_txpool::ports::PeerToPeer for P2PAdapter {
         &self,
         _message_info: GossipsubMessageInfo,
         _validity: GossipsubMessageAcceptance,
-    ) -> anyhow::Result<()> {
+    ) -> PortResult<()> {
         Ok(())
     }
+
+    fn new_peers_events(&self) -> BoxStream<PeerId> {
+        Box::pin(fuel_core_services::stream::pending())
+    }
+
+    async fn request_tx_pool_summary(
+        &self,
+        _peer_id: PeerId,
+    ) -> PortResult<Option<Vec<ShortTxId>>> {
+        Ok(None)
+    }
+
+    async fn request_pooled_transactions(
+        &self,
+        _peer_id: PeerId,
+        _tx_ids: Vec<ShortTxId>,
+    ) -> PortResult<Option<Vec<Transaction>>> {
+        Ok(None)
+    }
 }
 
 impl fuel_core_txpool::ports::TxPoolDb for Database {
@@ -131,4 +204,12 @@ impl fuel_core_txpool::ports::TxPoolDb for Database {
     fn is_message_spent(&self, id: &Nonce) -> StorageResult<bool> {
         self.storage::<SpentMessages>().contains_key(id)
     }
+
+    fn block_transactions(
+        &self,
+        height: &BlockHeight,
+    ) -> StorageResult<Option<Vec<Transaction>>> {
+        let block = self.get_full_block(height)?;
+        Ok(block.map(|block| block.transactions().to_vec()))
+    }
 }
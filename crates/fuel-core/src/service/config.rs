@@ -3,6 +3,10 @@ use fuel_core_chain_config::{
     default_consensus_dev_key,
     ChainConfig,
 };
+use fuel_core_poa::signer::{
+    BlockSigner,
+    LocalSigner,
+};
 use fuel_core_types::{
     blockchain::primitives::SecretKeyWrapper,
     secrecy::Secret,
@@ -13,6 +17,7 @@ use std::{
         SocketAddr,
     },
     path::PathBuf,
+    sync::Arc,
     time::Duration,
 };
 use strum_macros::{
@@ -30,6 +35,14 @@ use fuel_core_p2p::config::{
 #[cfg(feature = "relayer")]
 use fuel_core_relayer::Config as RelayerConfig;
 
+pub use crate::{
+    database::integrity::VerificationScope,
+    fuel_core_graphql_api::rate_limiter::RateLimitConfig,
+    service::{
+        hooks::Hooks,
+        maintenance::MaintenanceEntry,
+    },
+};
 pub use fuel_core_consensus_module::RelayerConsensusConfig;
 pub use fuel_core_importer;
 pub use fuel_core_poa::Trigger;
@@ -38,15 +51,51 @@ pub use fuel_core_poa::Trigger;
 pub struct Config {
     pub addr: SocketAddr,
     pub api_request_timeout: Duration,
+    /// The maximum depth of the GraphQL query.
+    pub graphql_max_depth: usize,
+    /// The maximum complexity of the GraphQL query.
+    pub graphql_max_complexity: usize,
+    /// The per-client rate limit of GraphQL requests. `None` disables rate limiting.
+    pub graphql_rate_limit: Option<RateLimitConfig>,
+    /// The API keys of clients. The client sending the known key in the `x-api-key`
+    /// header is identified by the key, other clients by their IP addresses.
+    pub graphql_api_keys: Vec<String>,
+    /// The maximum number of operations in the batched GraphQL request.
+    pub graphql_max_batch_size: usize,
     pub max_database_cache_size: usize,
     pub database_path: PathBuf,
     pub database_type: DbType,
+    /// When set, the node runs as the read replica of the node
+    /// that owns the database at the `database_path`.
+    pub replica: Option<ReplicaConfig>,
+    /// When set, the node streams the changes of its database to the warm
+    /// standby nodes, or follows the primary node as the warm standby.
+    pub replication: Option<ReplicationConfig>,
+    /// When `true`, the node opens the database at the `database_path` without
+    /// the write capability and only serves the GraphQL API. It is used to inspect
+    /// the data directory without the risk of modifying it.
+    pub db_read_only: bool,
+    /// When `true`, the node runs as the only block producer of the network without
+    /// the P2P and synchronization services. The combination with other settings
+    /// is validated on the start of the node.
+    pub single_node: bool,
+    /// The number of blocks on top of the block with the transaction after which
+    /// the transaction status reports it as finalized. `None` means the transaction
+    /// is never reported as finalized, `Some(0)` means the instant finality.
+    pub tx_finality_depth: Option<u32>,
     pub chain_conf: ChainConfig,
     /// When `true`:
     /// - Enables manual block production.
     /// - Enables debugger endpoint.
     /// - Allows setting `utxo_validation` to `false`.
     pub debug: bool,
+    /// The number of the latest blocks, the state before which is kept
+    /// in the debug mode to trace their transactions, and in the consensus
+    /// parameters canary mode to check them.
+    pub state_history_blocks: usize,
+    /// The directory where the debug mode writes the dumps of the `TxPool`.
+    /// Dumps are disabled if `None`.
+    pub tx_pool_dump_dir: Option<PathBuf>,
     // default to false until downstream consumers stabilize
     pub utxo_validation: bool,
     pub block_production: Trigger,
@@ -54,6 +103,12 @@ pub struct Config {
     pub txpool: fuel_core_txpool::Config,
     pub block_producer: fuel_core_producer::Config,
     pub block_importer: fuel_core_importer::Config,
+    /// The candidate consensus parameters. When set, each imported block is also
+    /// executed by the same executor with these parameters, and divergences from
+    /// the canonical result are reported without affecting it.
+    #[cfg(feature = "consensus-parameters-canary")]
+    pub consensus_parameters_canary:
+        Option<fuel_core_types::fuel_tx::ConsensusParameters>,
     #[cfg(feature = "relayer")]
     pub relayer: Option<RelayerConfig>,
     #[cfg(feature = "p2p")]
@@ -61,6 +116,10 @@ pub struct Config {
     #[cfg(feature = "p2p")]
     pub sync: fuel_core_sync::Config,
     pub consensus_key: Option<Secret<SecretKeyWrapper>>,
+    /// The signer of produced blocks. If set, it is used instead of the `consensus_key`.
+    pub consensus_signer: Option<Arc<dyn BlockSigner>>,
+    /// The identity of the node served by the API. The identity isn't served if `None`.
+    pub node_identity: Option<NodeIdentityConfig>,
     pub name: String,
     pub relayer_consensus_config: fuel_core_consensus_module::RelayerConsensusConfig,
     /// The number of reserved peers to connect to before starting to sync.
@@ -69,6 +128,17 @@ pub struct Config {
     pub time_until_synced: Duration,
     /// Time to wait after submitting a query before debug info will be logged about query.
     pub query_log_threshold_time: Duration,
+    /// The maximum time to wait for the commit of the block in progress during the shutdown.
+    pub shutdown_timeout: Duration,
+    /// The node isn't ready if it doesn't import a block within this time.
+    pub health_stall_timeout: Option<Duration>,
+    /// The lifecycle hooks of the node for applications embedding it as a library.
+    pub hooks: Hooks,
+    /// The maintenance tasks executed by the node on the schedule.
+    pub maintenance: Vec<MaintenanceEntry>,
+    /// The verification of blocks and contracts against their merkle roots on the start
+    /// of the node. `None` disables the verification.
+    pub startup_verification: Option<VerificationScope>,
 }
 
 impl Config {
@@ -81,6 +151,11 @@ impl Config {
         Self {
             addr: SocketAddr::new(Ipv4Addr::new(127, 0, 0, 1).into(), 0),
             api_request_timeout: Duration::from_secs(60),
+            graphql_max_depth: 16,
+            graphql_max_complexity: 20000,
+            graphql_rate_limit: None,
+            graphql_api_keys: vec![],
+            graphql_max_batch_size: 32,
             // Set the cache for tests = 10MB
             max_database_cache_size: 10 * 1024 * 1024,
             database_path: Default::default(),
@@ -88,7 +163,14 @@ impl Config {
             database_type: DbType::RocksDb,
             #[cfg(not(feature = "rocksdb"))]
             database_type: DbType::InMemory,
+            replica: None,
+            replication: None,
+            db_read_only: false,
+            single_node: false,
+            tx_finality_depth: None,
             debug: true,
+            state_history_blocks: 64,
+            tx_pool_dump_dir: None,
             chain_conf: chain_conf.clone(),
             block_production: Trigger::Instant,
             vm: Default::default(),
@@ -102,6 +184,8 @@ impl Config {
             },
             block_producer: Default::default(),
             block_importer,
+            #[cfg(feature = "consensus-parameters-canary")]
+            consensus_parameters_canary: None,
             #[cfg(feature = "relayer")]
             relayer: None,
             #[cfg(feature = "p2p")]
@@ -109,11 +193,18 @@ impl Config {
             #[cfg(feature = "p2p")]
             sync: fuel_core_sync::Config::default(),
             consensus_key: Some(Secret::new(default_consensus_dev_key().into())),
+            consensus_signer: None,
+            node_identity: None,
             name: String::default(),
             relayer_consensus_config: Default::default(),
             min_connected_reserved_peers: 0,
             time_until_synced: Duration::ZERO,
             query_log_threshold_time: Duration::from_secs(2),
+            shutdown_timeout: Duration::from_secs(30),
+            health_stall_timeout: None,
+            hooks: Default::default(),
+            maintenance: vec![],
+            startup_verification: None,
         }
     }
 
@@ -141,6 +232,120 @@ impl Config {
 
         self
     }
+
+    /// The node follows the primary node as the warm standby.
+    pub fn is_standby(&self) -> bool {
+        matches!(self.replication, Some(ReplicationConfig::Standby(_)))
+    }
+
+    /// Returns `true` if the node is allowed to write into its database. The read replica
+    /// and the node in the read-only mode only read the database.
+    pub fn modifies_database(&self) -> bool {
+        self.replica.is_none() && !self.db_read_only
+    }
+
+    /// Returns `true` if the node keeps the state history for the debugger
+    /// or the consensus parameters canary.
+    pub fn keeps_state_history(&self) -> bool {
+        #[cfg(feature = "consensus-parameters-canary")]
+        if self.consensus_parameters_canary.is_some() {
+            return true
+        }
+        self.debug
+    }
+
+    /// The mode of the node defined by the configuration.
+    pub fn node_mode(&self) -> NodeMode {
+        if self.db_read_only {
+            NodeMode::ReadOnly
+        } else if self.replica.is_some() {
+            NodeMode::Replica
+        } else if self.is_standby() {
+            NodeMode::Standby
+        } else if self.single_node {
+            NodeMode::SingleNode
+        } else if self.is_p2p_enabled() {
+            NodeMode::Network
+        } else {
+            NodeMode::Standalone
+        }
+    }
+
+    /// Checks that the settings of the configuration are compatible with each other.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        self.chain_conf.validate_consensus_parameters_upgrades()?;
+        if self.db_read_only {
+            if self.replica.is_some() || self.replication.is_some() {
+                anyhow::bail!(
+                    "The read-only mode can't be used with the replica or the replication"
+                );
+            }
+            if self.single_node {
+                anyhow::bail!(
+                    "The read-only mode can't be used with the single-node mode"
+                );
+            }
+            if self.database_type != DbType::RocksDb
+                || self.database_path.as_os_str().is_empty()
+            {
+                anyhow::bail!(
+                    "The read-only mode requires the existing RocksDB database at the `database_path`"
+                );
+            }
+        }
+        if self.single_node {
+            if self.is_p2p_enabled() {
+                anyhow::bail!("The single-node mode can't be used with the P2P network");
+            }
+            if self.replica.is_some() || self.is_standby() {
+                anyhow::bail!(
+                    "The single-node mode can't be used by the replica or the standby node"
+                );
+            }
+            if matches!(self.block_production, Trigger::Never) && !self.debug {
+                anyhow::bail!(
+                    "The single-node mode requires the block production to be enabled"
+                );
+            }
+            if self.min_connected_reserved_peers != 0
+                || self.time_until_synced != Duration::ZERO
+            {
+                anyhow::bail!(
+                    "The single-node mode has no peers to sync with, \
+                    `min_connected_reserved_peers` and `time_until_synced` should be zero"
+                );
+            }
+        }
+        Ok(())
+    }
+
+    fn is_p2p_enabled(&self) -> bool {
+        #[cfg(feature = "p2p")]
+        {
+            self.p2p.is_some()
+        }
+        #[cfg(not(feature = "p2p"))]
+        {
+            false
+        }
+    }
+}
+
+/// The mode in which the node runs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NodeMode {
+    /// The node participates in the P2P network.
+    Network,
+    /// The node is the only block producer without the P2P network.
+    SingleNode,
+    /// The node runs without the P2P network, but the single-node mode isn't requested.
+    Standalone,
+    /// The node is the read replica of another node.
+    Replica,
+    /// The node is the warm standby of the primary node.
+    Standby,
+    /// The node only reads its database and serves the GraphQL API.
+    ReadOnly,
 }
 
 impl From<&Config> for fuel_core_poa::Config {
@@ -148,7 +353,12 @@ impl From<&Config> for fuel_core_poa::Config {
         fuel_core_poa::Config {
             trigger: config.block_production,
             block_gas_limit: config.chain_conf.block_gas_limit,
-            signing_key: config.consensus_key.clone(),
+            signer: config.consensus_signer.clone().or_else(|| {
+                config
+                    .consensus_key
+                    .clone()
+                    .map(|key| Arc::new(LocalSigner::new(key)) as Arc<dyn BlockSigner>)
+            }),
             metrics: false,
             consensus_params: config.chain_conf.consensus_parameters.clone(),
             min_connected_reserved_peers: config.min_connected_reserved_peers,
@@ -162,6 +372,62 @@ pub struct VMConfig {
     pub backtrace: bool,
 }
 
+/// The configuration of the read replica. The replica opens the RocksDB database
+/// of the primary node as the secondary instance and only serves the GraphQL API.
+#[derive(Clone, Debug)]
+pub struct ReplicaConfig {
+    /// The directory for the files of the secondary instance. It should be unique
+    /// for each replica of the same primary database.
+    pub secondary_path: PathBuf,
+    /// The interval between catching up with the primary database.
+    pub catch_up_interval: Duration,
+}
+
+/// The configuration of the warm standby replication.
+#[derive(Clone, Debug)]
+pub enum ReplicationConfig {
+    /// The node streams the changes of its database to standby nodes.
+    Primary(PrimaryReplicationConfig),
+    /// The node applies the changes streamed by the primary node instead of executing blocks.
+    Standby(StandbyReplicationConfig),
+}
+
+#[derive(Clone, Debug)]
+pub struct PrimaryReplicationConfig {
+    /// The address accepting connections of standby nodes.
+    pub addr: SocketAddr,
+    /// The secret shared with standby nodes.
+    pub secret: Secret<String>,
+    /// The number of the latest batches of changes kept for reconnecting standby nodes.
+    pub log_capacity: usize,
+}
+
+#[derive(Clone, Debug)]
+pub struct StandbyReplicationConfig {
+    /// The replication address of the primary node.
+    pub primary: String,
+    /// The secret shared with the primary node.
+    pub secret: Secret<String>,
+    /// The interval between attempts to reconnect to the primary node.
+    pub reconnect_interval: Duration,
+}
+
+/// The configuration of the identity document of the node. The document is signed
+/// by the `key` on each request and served at the `/.well-known/fuel-node-identity`
+/// path of the API.
+#[derive(Clone, Debug)]
+pub struct NodeIdentityConfig {
+    /// The name of the operator of the node.
+    pub operator: String,
+    /// The contact of the operator, like an email or a website.
+    pub contact: String,
+    /// The public URL of the API, like `https://node.example.com`. If `None`,
+    /// the URL is derived from the `Host` header of the request.
+    pub public_url: Option<String>,
+    /// The key signing the identity document.
+    pub key: Secret<SecretKeyWrapper>,
+}
+
 #[derive(
     Clone, Debug, Display, Eq, PartialEq, EnumString, EnumVariantNames, ValueEnum,
 )]
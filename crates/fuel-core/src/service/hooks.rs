@@ -0,0 +1,410 @@
+//! The lifecycle hooks of the node for applications embedding it as a library.
+//! The hooks are registered in the [`Config::hooks`](crate::service::Config::hooks)
+//! before the creation of the [`FuelService`](crate::service::FuelService):
+//!
+//! - `before_api_start` hooks are called after the genesis, before starting sub-services
+//!   and the GraphQL API. The error from the hook fails the start of the node.
+//! - `on_block_imported` and `on_tx_included` hooks are called after each imported block,
+//!   including the genesis block. The read replica and the read-only node don't import
+//!   blocks, so these hooks are not called for them.
+//! - `on_shutdown` hooks are called before stopping sub-services.
+//!
+//! Hooks of the same kind are called sequentially in the order of registration.
+//! The slow import hook delays the processing of the next blocks by hooks,
+//! but not the import itself. If hooks lag behind the import by more blocks than
+//! the importer buffers, the missed blocks are skipped, logged and counted by
+//! the `importer_hooks_skipped_blocks` metric. Errors from hooks other than
+//! `before_api_start` are logged and ignored.
+
+use crate::service::SharedState;
+use fuel_core_metrics::importer::importer_metrics;
+use fuel_core_services::{
+    EmptyShared,
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+};
+use fuel_core_types::{
+    fuel_types::BlockHeight,
+    services::{
+        block_importer::SharedImportResult,
+        executor::TransactionExecutionStatus,
+    },
+};
+use futures::{
+    future::BoxFuture,
+    FutureExt,
+};
+use std::{
+    fmt::Debug,
+    future::Future,
+    sync::Arc,
+};
+use tokio::sync::broadcast::{
+    error::RecvError,
+    Receiver,
+};
+
+/// The future returned by the hook.
+pub type HookFuture = BoxFuture<'static, anyhow::Result<()>>;
+
+type NodeHook = Arc<dyn Fn(SharedState) -> HookFuture + Send + Sync>;
+type BlockHook = Arc<dyn Fn(SharedImportResult) -> HookFuture + Send + Sync>;
+type TransactionHook = Arc<dyn Fn(IncludedTransaction) -> HookFuture + Send + Sync>;
+
+/// The transaction included into the imported block.
+#[derive(Debug, Clone)]
+pub struct IncludedTransaction {
+    /// The height of the block with the transaction.
+    pub block_height: BlockHeight,
+    /// The status of the transaction execution.
+    pub status: TransactionExecutionStatus,
+}
+
+/// The registered lifecycle hooks.
+#[derive(Clone, Default)]
+pub struct Hooks {
+    before_api_start: Vec<NodeHook>,
+    on_block_imported: Vec<BlockHook>,
+    on_tx_included: Vec<TransactionHook>,
+    on_shutdown: Vec<NodeHook>,
+}
+
+impl Debug for Hooks {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Hooks")
+            .field("before_api_start", &self.before_api_start.len())
+            .field("on_block_imported", &self.on_block_imported.len())
+            .field("on_tx_included", &self.on_tx_included.len())
+            .field("on_shutdown", &self.on_shutdown.len())
+            .finish()
+    }
+}
+
+impl Hooks {
+    /// Registers the hook called before starting the GraphQL API.
+    pub fn before_api_start<F, Fut>(&mut self, hook: F) -> &mut Self
+    where
+        F: Fn(SharedState) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = anyhow::Result<()>> + Send + 'static,
+    {
+        self.before_api_start
+            .push(Arc::new(move |shared| hook(shared).boxed()));
+        self
+    }
+
+    /// Registers the hook called after each imported block.
+    pub fn on_block_imported<F, Fut>(&mut self, hook: F) -> &mut Self
+    where
+        F: Fn(SharedImportResult) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = anyhow::Result<()>> + Send + 'static,
+    {
+        self.on_block_imported
+            .push(Arc::new(move |result| hook(result).boxed()));
+        self
+    }
+
+    /// Registers the hook called for each transaction of the imported block.
+    pub fn on_tx_included<F, Fut>(&mut self, hook: F) -> &mut Self
+    where
+        F: Fn(IncludedTransaction) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = anyhow::Result<()>> + Send + 'static,
+    {
+        self.on_tx_included
+            .push(Arc::new(move |transaction| hook(transaction).boxed()));
+        self
+    }
+
+    /// Registers the hook called on the shutdown of the node.
+    pub fn on_shutdown<F, Fut>(&mut self, hook: F) -> &mut Self
+    where
+        F: Fn(SharedState) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = anyhow::Result<()>> + Send + 'static,
+    {
+        self.on_shutdown
+            .push(Arc::new(move |shared| hook(shared).boxed()));
+        self
+    }
+
+    pub(crate) fn has_import_hooks(&self) -> bool {
+        !self.on_block_imported.is_empty() || !self.on_tx_included.is_empty()
+    }
+
+    pub(crate) async fn run_before_api_start(
+        &self,
+        shared: &SharedState,
+    ) -> anyhow::Result<()> {
+        for hook in &self.before_api_start {
+            hook(shared.clone()).await?;
+        }
+        Ok(())
+    }
+
+    pub(crate) async fn run_on_shutdown(&self, shared: &SharedState) {
+        for hook in &self.on_shutdown {
+            if let Err(err) = hook(shared.clone()).await {
+                tracing::error!("The `on_shutdown` hook failed: {err}");
+            }
+        }
+    }
+
+    async fn run_on_import(&self, result: SharedImportResult) {
+        for hook in &self.on_block_imported {
+            if let Err(err) = hook(Arc::clone(&result)).await {
+                tracing::error!("The `on_block_imported` hook failed: {err}");
+            }
+        }
+
+        if self.on_tx_included.is_empty() {
+            return
+        }
+        let block_height = *result.sealed_block.entity.header().height();
+        for status in &result.tx_status {
+            for hook in &self.on_tx_included {
+                let transaction = IncludedTransaction {
+                    block_height,
+                    status: status.clone(),
+                };
+                if let Err(err) = hook(transaction).await {
+                    tracing::error!("The `on_tx_included` hook failed: {err}");
+                }
+            }
+        }
+    }
+}
+
+pub struct Task {
+    blocks: Receiver<SharedImportResult>,
+    hooks: Hooks,
+}
+
+#[async_trait::async_trait]
+impl RunnableService for Task {
+    const NAME: &'static str = "Hooks";
+    type SharedData = EmptyShared;
+    type Task = Self;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {
+        EmptyShared
+    }
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        Ok(self)
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableTask for Task {
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        let should_continue;
+        tokio::select! {
+            biased;
+
+            _ = watcher.while_started() => {
+                should_continue = false;
+            }
+
+            result = self.blocks.recv() => {
+                match result {
+                    Ok(result) => {
+                        self.hooks.run_on_import(result).await;
+                        should_continue = true;
+                    }
+                    Err(RecvError::Lagged(skipped)) => {
+                        tracing::warn!(
+                            "The import hooks lagged behind the import and skipped {skipped} blocks"
+                        );
+                        importer_metrics().hooks_skipped_blocks.inc_by(skipped);
+                        should_continue = true;
+                    }
+                    Err(RecvError::Closed) => {
+                        should_continue = false;
+                    }
+                }
+            }
+        }
+        Ok(should_continue)
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+pub fn new_service(
+    blocks: Receiver<SharedImportResult>,
+    hooks: Hooks,
+) -> ServiceRunner<Task> {
+    ServiceRunner::new(Task { blocks, hooks })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Hooks;
+    use crate::service::{
+        Config,
+        FuelService,
+        Task,
+    };
+    use fuel_core_metrics::importer::importer_metrics;
+    use fuel_core_services::{
+        RunnableService,
+        RunnableTask,
+        Service,
+        State,
+    };
+    use fuel_core_types::{
+        fuel_tx::{
+            Transaction,
+            TransactionBuilder,
+        },
+        services::{
+            block_importer::{
+                ImportResult,
+                SharedImportResult,
+            },
+            txpool::TxOrigin,
+        },
+    };
+    use std::{
+        sync::Arc,
+        time::Duration,
+    };
+    use tokio::sync::{
+        broadcast,
+        mpsc,
+        watch,
+    };
+
+    #[tokio::test]
+    async fn hooks_are_called_during_lifecycle_of_node() {
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+        let mut config = Config::local_node();
+        let events = sender.clone();
+        config.hooks.before_api_start(move |_| {
+            let events = events.clone();
+            async move {
+                events.send("before_api_start".to_string())?;
+                Ok(())
+            }
+        });
+        let events = sender.clone();
+        config.hooks.on_block_imported(move |result| {
+            let events = events.clone();
+            async move {
+                let height = *result.sealed_block.entity.header().height();
+                events.send(format!("block {}", *height))?;
+                Ok(())
+            }
+        });
+        let events = sender.clone();
+        config.hooks.on_tx_included(move |transaction| {
+            let events = events.clone();
+            async move {
+                events.send(format!("tx {}", *transaction.block_height))?;
+                Ok(())
+            }
+        });
+        let events = sender;
+        config.hooks.on_shutdown(move |_| {
+            let events = events.clone();
+            async move {
+                events.send("on_shutdown".to_string())?;
+                Ok(())
+            }
+        });
+
+        let service = FuelService::new_node(config).await.unwrap();
+        let tx: Transaction = TransactionBuilder::script(vec![], vec![])
+            .add_random_fee_input()
+            .finalize_as_transaction();
+        service
+            .shared
+            .txpool
+            .insert(vec![Arc::new(tx)], TxOrigin::Injected)
+            .await;
+        let mut events = vec![];
+        while events.len() < 5 {
+            let event = tokio::time::timeout(Duration::from_secs(5), receiver.recv())
+                .await
+                .unwrap()
+                .unwrap();
+            events.push(event);
+        }
+        service.stop_and_await().await.unwrap();
+        while let Ok(event) = receiver.try_recv() {
+            events.push(event);
+        }
+
+        assert_eq!(
+            events,
+            vec![
+                "before_api_start",
+                "block 0",
+                "block 1",
+                // The script and the mint transactions.
+                "tx 1",
+                "tx 1",
+                "on_shutdown"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn lagged_import_hooks_skip_missed_blocks() {
+        let (sender, mut events) = mpsc::unbounded_channel();
+        let mut hooks = Hooks::default();
+        hooks.on_block_imported(move |_| {
+            let events = sender.clone();
+            async move {
+                events.send(())?;
+                Ok(())
+            }
+        });
+        let (blocks, receiver) = broadcast::channel(1);
+        let mut task = super::Task {
+            blocks: receiver,
+            hooks,
+        };
+        let (_state, watcher) = watch::channel(State::Started);
+        let mut watcher = watcher.into();
+        let skipped = importer_metrics().hooks_skipped_blocks.get();
+
+        // Given
+        for _ in 0..3 {
+            let result: SharedImportResult = Arc::new(Box::new(ImportResult::default()));
+            blocks.send(result).unwrap();
+        }
+
+        // When
+        assert!(task.run(&mut watcher).await.unwrap());
+        assert!(task.run(&mut watcher).await.unwrap());
+
+        // Then
+        assert!(
+            importer_metrics().hooks_skipped_blocks.get() >= skipped.saturating_add(2)
+        );
+        assert_eq!(events.try_recv(), Ok(()));
+        assert!(events.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn failed_before_api_start_hook_fails_start_of_node() {
+        let mut config = Config::local_node();
+        config
+            .hooks
+            .before_api_start(|_| async { Err(anyhow::anyhow!("Not ready")) });
+
+        let task = Task::new(Default::default(), config).unwrap();
+
+        let result = task.into_task(&Default::default(), ()).await;
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,507 @@
+//! The scheduler of maintenance tasks of the node. Tasks are configured with cron-style
+//! entries and executed by the node itself, so the operator doesn't need the external
+//! cron invoking the CLI against the database of the live node.
+//!
+//! The task runs on the blocking thread. If the previous run of the same entry is still
+//! in progress when the entry is due again, the run is skipped. The status of each entry
+//! is served by the `/v1/maintenance` endpoint of the API to the clients with one of
+//! the configured API keys. The errors of runs are only logged.
+
+use crate::combined_database::CombinedDatabase;
+use anyhow::Context;
+use fuel_core_chain_config::{
+    ChainConfig,
+    StateConfig,
+};
+use fuel_core_services::{
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    SharedMutex,
+    StateWatcher,
+};
+use std::{
+    fmt,
+    path::{
+        Path,
+        PathBuf,
+    },
+    str::FromStr,
+    time::{
+        Instant,
+        SystemTime,
+        UNIX_EPOCH,
+    },
+};
+
+mod schedule;
+
+pub use schedule::Schedule;
+
+/// The maintenance operation of the node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MaintenanceTask {
+    /// Compacts all column families of all databases.
+    Compact,
+    /// Creates the RocksDB checkpoint of all databases in the new `backup-<TIMESTAMP>`
+    /// subdirectory of the `dir`. The checkpoint can be opened as the regular database.
+    /// See [`CombinedDatabase::checkpoint`] for the consistency of the databases.
+    Backup { dir: PathBuf },
+    /// Writes the chain config with the current state into the new
+    /// `snapshot-<TIMESTAMP>.json` file in the `dir`. The state is read from
+    /// the RocksDB checkpoint of the on-chain database, so it is pinned at one height.
+    Snapshot { dir: PathBuf },
+    /// Validates the referential integrity of the on-chain database.
+    /// The run fails if any violation is found.
+    VerifyIntegrity,
+}
+
+impl MaintenanceTask {
+    /// The name of the task without its argument.
+    pub fn name(&self) -> &'static str {
+        match self {
+            MaintenanceTask::Compact => "compact",
+            MaintenanceTask::Backup { .. } => "backup",
+            MaintenanceTask::Snapshot { .. } => "snapshot",
+            MaintenanceTask::VerifyIntegrity => "verify-integrity",
+        }
+    }
+}
+
+impl fmt::Display for MaintenanceTask {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MaintenanceTask::Compact => write!(f, "compact"),
+            MaintenanceTask::Backup { dir } => write!(f, "backup {}", dir.display()),
+            MaintenanceTask::Snapshot { dir } => write!(f, "snapshot {}", dir.display()),
+            MaintenanceTask::VerifyIntegrity => write!(f, "verify-integrity"),
+        }
+    }
+}
+
+/// The maintenance task executed on the schedule. It is parsed from the cron-style
+/// entry: the five fields of the [`Schedule`], the name of the task and its argument,
+/// for example, `0 3 * * 0 backup /var/backups/fuel`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MaintenanceEntry {
+    pub schedule: Schedule,
+    pub task: MaintenanceTask,
+}
+
+impl FromStr for MaintenanceEntry {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<&str> = s.split_whitespace().collect();
+        let (schedule, task) = (fields.len() > 5)
+            .then(|| fields.split_at(5))
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "The maintenance entry `{s}` should be `<SCHEDULE> <TASK>`"
+                )
+            })?;
+        let schedule = schedule.join(" ").parse()?;
+        let dir = |name: &str| match task {
+            [_, dir] => Ok(PathBuf::from(dir)),
+            _ => Err(anyhow::anyhow!("The `{name}` task requires the directory")),
+        };
+        let task = match task {
+            ["compact"] => MaintenanceTask::Compact,
+            ["backup", ..] => MaintenanceTask::Backup {
+                dir: dir("backup")?,
+            },
+            ["snapshot", ..] => MaintenanceTask::Snapshot {
+                dir: dir("snapshot")?,
+            },
+            ["verify-integrity"] => MaintenanceTask::VerifyIntegrity,
+            _ => anyhow::bail!(
+                "Unknown maintenance task `{}`, expected `compact`, `backup <DIR>`, \
+                `snapshot <DIR>` or `verify-integrity`",
+                task.join(" ")
+            ),
+        };
+        Ok(Self { schedule, task })
+    }
+}
+
+impl fmt::Display for MaintenanceEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.schedule, self.task)
+    }
+}
+
+/// The status of the scheduled maintenance entry. Times are in seconds
+/// since the `UNIX_EPOCH`.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct EntryStatus {
+    /// The schedule and the name of the task, without the paths of the node.
+    pub entry: String,
+    /// The previous run is still in progress.
+    pub running: bool,
+    pub next_run: Option<u64>,
+    pub last_started: Option<u64>,
+    pub last_finished: Option<u64>,
+    pub last_duration_ms: Option<u64>,
+    /// The last finished run failed. The error is logged by the node.
+    pub last_failed: bool,
+    pub succeeded_runs: u64,
+    pub failed_runs: u64,
+    /// The number of runs skipped because the previous run was still in progress.
+    pub skipped_runs: u64,
+}
+
+/// The statuses of scheduled maintenance entries in the order of the configuration.
+#[derive(Debug, Clone)]
+pub struct MaintenanceStatus(SharedMutex<Vec<EntryStatus>>);
+
+impl MaintenanceStatus {
+    pub fn report(&self) -> Vec<EntryStatus> {
+        self.0.apply(|statuses| statuses.clone())
+    }
+
+    fn update<F>(&self, index: usize, f: F)
+    where
+        F: FnOnce(&mut EntryStatus),
+    {
+        self.0.apply(|statuses| {
+            if let Some(status) = statuses.get_mut(index) {
+                f(status)
+            }
+        })
+    }
+}
+
+pub struct Task {
+    database: CombinedDatabase,
+    chain_config: ChainConfig,
+    entries: Vec<MaintenanceEntry>,
+    next_runs: Vec<Option<SystemTime>>,
+    status: MaintenanceStatus,
+}
+
+#[async_trait::async_trait]
+impl RunnableService for Task {
+    const NAME: &'static str = "Maintenance";
+    type SharedData = MaintenanceStatus;
+    type Task = Self;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {
+        self.status.clone()
+    }
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        Ok(self)
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableTask for Task {
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        let Some(next_run) = self.next_runs.iter().flatten().min().copied() else {
+            // Nothing is scheduled.
+            watcher.while_started().await?;
+            return Ok(false)
+        };
+        let delay = next_run
+            .duration_since(SystemTime::now())
+            .unwrap_or_default();
+
+        let should_continue;
+        tokio::select! {
+            biased;
+
+            _ = watcher.while_started() => {
+                should_continue = false;
+            }
+
+            _ = tokio::time::sleep(delay) => {
+                self.run_due(SystemTime::now());
+                should_continue = true;
+            }
+        }
+        Ok(should_continue)
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        // Runs in progress are not interrupted, they finish on their blocking threads.
+        Ok(())
+    }
+}
+
+impl Task {
+    fn run_due(&mut self, now: SystemTime) {
+        for (index, entry) in self.entries.iter().enumerate() {
+            if !matches!(self.next_runs[index], Some(next_run) if next_run <= now) {
+                continue
+            }
+            let next_run = entry.schedule.next_after(now);
+            self.next_runs[index] = next_run;
+
+            let mut started = false;
+            self.status.update(index, |status| {
+                status.next_run = next_run.and_then(unix_secs);
+                if status.running {
+                    status.skipped_runs = status.skipped_runs.saturating_add(1);
+                } else {
+                    status.running = true;
+                    status.last_started = unix_secs(now);
+                    started = true;
+                }
+            });
+            if !started {
+                tracing::warn!(
+                    "Skipping the maintenance `{entry}`, the previous run is still in progress"
+                );
+                continue
+            }
+
+            let entry = entry.clone();
+            let database = self.database.clone();
+            let chain_config = self.chain_config.clone();
+            let status = self.status.clone();
+            tokio::task::spawn_blocking(move || {
+                tracing::info!("Running the maintenance `{entry}`");
+                let start = Instant::now();
+                let result = execute(&entry.task, &database, &chain_config);
+                let duration = start.elapsed();
+                match &result {
+                    Ok(()) => tracing::info!(
+                        "The maintenance `{entry}` finished in {duration:?}"
+                    ),
+                    Err(err) => {
+                        tracing::error!("The maintenance `{entry}` failed: {err:?}")
+                    }
+                }
+                status.update(index, |status| {
+                    status.running = false;
+                    status.last_finished = unix_secs(SystemTime::now());
+                    status.last_duration_ms =
+                        Some(u64::try_from(duration.as_millis()).unwrap_or(u64::MAX));
+                    status.last_failed = result.is_err();
+                    if result.is_ok() {
+                        status.succeeded_runs = status.succeeded_runs.saturating_add(1);
+                    } else {
+                        status.failed_runs = status.failed_runs.saturating_add(1);
+                    }
+                });
+            });
+        }
+    }
+}
+
+fn execute(
+    task: &MaintenanceTask,
+    database: &CombinedDatabase,
+    chain_config: &ChainConfig,
+) -> anyhow::Result<()> {
+    let timestamp = unix_secs(SystemTime::now()).unwrap_or_default();
+    match task {
+        MaintenanceTask::Compact => database.compact()?,
+        MaintenanceTask::Backup { dir } => {
+            let path = dir.join(format!("backup-{timestamp}"));
+            std::fs::create_dir_all(&path).context(format!(
+                "Failed to create the backup directory {}",
+                path.display()
+            ))?;
+            database.checkpoint(&path)?;
+        }
+        MaintenanceTask::Snapshot { dir } => {
+            let state = pinned_state_config(database)?;
+            let chain_config = ChainConfig {
+                initial_state: Some(state),
+                ..chain_config.clone()
+            };
+            write_snapshot(&chain_config, dir, timestamp)?;
+        }
+        MaintenanceTask::VerifyIntegrity => {
+            let report = database.on_chain().check_integrity()?;
+            if !report.is_ok() {
+                anyhow::bail!(
+                    "The database has {} missing transactions and {} orphan coins",
+                    report.missing_transactions.len(),
+                    report.orphan_coins.len()
+                )
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Generates the state config from the checkpoint of the on-chain database,
+/// so the blocks committed during the generation don't mix into the state.
+#[cfg(feature = "rocksdb")]
+fn pinned_state_config(database: &CombinedDatabase) -> anyhow::Result<StateConfig> {
+    use crate::{
+        database::Database,
+        state::rocks_db::ShallowTempDir,
+    };
+
+    let checkpoint = ShallowTempDir::new();
+    database.on_chain().checkpoint(checkpoint.path())?;
+    let on_chain: Database = Database::open_read_only(checkpoint.path(), None)?;
+    Ok(StateConfig::generate_state_config(on_chain)?)
+}
+
+#[cfg(not(feature = "rocksdb"))]
+fn pinned_state_config(_: &CombinedDatabase) -> anyhow::Result<StateConfig> {
+    anyhow::bail!("The snapshot requires the RocksDB database")
+}
+
+/// Writes the snapshot into the temporary file first, so the incomplete
+/// snapshot never appears under the final name.
+fn write_snapshot(
+    chain_config: &ChainConfig,
+    dir: &Path,
+    timestamp: u64,
+) -> anyhow::Result<()> {
+    std::fs::create_dir_all(dir).context(format!(
+        "Failed to create the snapshot directory {}",
+        dir.display()
+    ))?;
+    let path = dir.join(format!("snapshot-{timestamp}.json"));
+    let tmp_path = dir.join(format!("snapshot-{timestamp}.json.tmp"));
+    let file = std::fs::File::create(&tmp_path)
+        .context(format!("Failed to create {}", tmp_path.display()))?;
+    serde_json::to_writer_pretty(std::io::BufWriter::new(file), chain_config)
+        .context("Failed to write the snapshot")?;
+    std::fs::rename(&tmp_path, &path)
+        .context(format!("Failed to move the snapshot to {}", path.display()))?;
+    Ok(())
+}
+
+fn unix_secs(time: SystemTime) -> Option<u64> {
+    time.duration_since(UNIX_EPOCH)
+        .ok()
+        .map(|duration| duration.as_secs())
+}
+
+pub fn new_service(
+    database: CombinedDatabase,
+    entries: Vec<MaintenanceEntry>,
+    chain_config: ChainConfig,
+) -> ServiceRunner<Task> {
+    ServiceRunner::new(new_task(database, entries, chain_config))
+}
+
+fn new_task(
+    database: CombinedDatabase,
+    entries: Vec<MaintenanceEntry>,
+    chain_config: ChainConfig,
+) -> Task {
+    let now = SystemTime::now();
+    let next_runs: Vec<_> = entries
+        .iter()
+        .map(|entry| entry.schedule.next_after(now))
+        .collect();
+    let statuses = entries
+        .iter()
+        .zip(next_runs.iter())
+        .map(|(entry, next_run)| EntryStatus {
+            entry: format!("{} {}", entry.schedule, entry.task.name()),
+            running: false,
+            next_run: next_run.and_then(unix_secs),
+            last_started: None,
+            last_finished: None,
+            last_duration_ms: None,
+            last_failed: false,
+            succeeded_runs: 0,
+            failed_runs: 0,
+            skipped_runs: 0,
+        })
+        .collect();
+    Task {
+        database,
+        chain_config,
+        entries,
+        next_runs,
+        status: MaintenanceStatus(SharedMutex::new(statuses)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entries_are_parsed() {
+        let entry: MaintenanceEntry =
+            "0 3 * * 0  backup /var/backups/fuel".parse().unwrap();
+        assert_eq!(
+            entry.task,
+            MaintenanceTask::Backup {
+                dir: "/var/backups/fuel".into()
+            }
+        );
+        assert_eq!(entry.to_string(), "0 3 * * 0 backup /var/backups/fuel");
+
+        let entry: MaintenanceEntry = "*/30 * * * * compact".parse().unwrap();
+        assert_eq!(entry.task, MaintenanceTask::Compact);
+        let entry: MaintenanceEntry = "0 * * * * verify-integrity".parse().unwrap();
+        assert_eq!(entry.task, MaintenanceTask::VerifyIntegrity);
+    }
+
+    #[test]
+    fn invalid_entries_are_rejected() {
+        for entry in [
+            "0 3 * * *",
+            "0 3 * * * backup",
+            "0 3 * * * compact now",
+            "0 3 * * * rotate-logs",
+            "0 3 * * compact",
+        ] {
+            assert!(entry.parse::<MaintenanceEntry>().is_err(), "{entry}");
+        }
+    }
+
+    #[tokio::test]
+    async fn due_entry_runs_and_reports_status() {
+        let entries = vec!["* * * * * verify-integrity".parse().unwrap()];
+        let mut task = new_task(
+            CombinedDatabase::in_memory(),
+            entries,
+            ChainConfig::default(),
+        );
+        let status = task.status.clone();
+        let now = SystemTime::now();
+        task.next_runs[0] = Some(now);
+
+        task.run_due(now);
+        // The entry is scheduled for the next minute.
+        assert!(task.next_runs[0].unwrap() > now);
+
+        for _ in 0..100 {
+            if !status.report()[0].running {
+                break
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        let report = status.report();
+        assert_eq!(report[0].succeeded_runs, 1);
+        assert!(!report[0].last_failed);
+        assert!(report[0].last_finished.is_some());
+    }
+
+    #[test]
+    fn due_entry_is_skipped_while_running() {
+        let entries = vec!["* * * * * compact".parse().unwrap()];
+        let mut task = new_task(
+            CombinedDatabase::in_memory(),
+            entries,
+            ChainConfig::default(),
+        );
+        let now = SystemTime::now();
+        task.status.update(0, |status| status.running = true);
+        task.next_runs[0] = Some(now);
+
+        task.run_due(now);
+
+        let report = task.status.report();
+        assert_eq!(report[0].skipped_runs, 1);
+        assert_eq!(report[0].last_started, None);
+    }
+}
@@ -0,0 +1,307 @@
+//! The cron-style schedule of maintenance tasks.
+
+use std::{
+    fmt,
+    str::FromStr,
+    time::{
+        Duration,
+        SystemTime,
+        UNIX_EPOCH,
+    },
+};
+
+const MINUTES_PER_DAY: u64 = 24 * 60;
+/// The schedule is searched for the next run within this number of days.
+/// It covers the longest period of the cron expression, `29 Feb` on Monday.
+const MAX_SEARCH_DAYS: u64 = 366 * 28;
+
+/// The schedule in the cron format: `minute hour day-of-month month day-of-week`.
+/// Each field is `*`, a value, a range `a-b`, a step `*/n` or `a-b/n`, or a comma-separated
+/// list of them. Sunday is `0` or `7`. As in cron, when both day fields are restricted,
+/// the day matches either of them. The schedule is evaluated in UTC.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Schedule {
+    expression: String,
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    any_day_of_month: bool,
+    any_day_of_week: bool,
+}
+
+impl Schedule {
+    /// Returns the time of the first run strictly after the `time`.
+    pub fn next_after(&self, time: SystemTime) -> Option<SystemTime> {
+        let minute = time
+            .duration_since(UNIX_EPOCH)
+            .ok()?
+            .as_secs()
+            .checked_div(60)?;
+        let start = minute.checked_add(1)?;
+        let first_day = start.checked_div(MINUTES_PER_DAY)?;
+        let first_minute = start.checked_rem(MINUTES_PER_DAY)?;
+
+        for day in first_day..first_day.checked_add(MAX_SEARCH_DAYS)? {
+            let (month, day_of_month) = civil_from_days(day)?;
+            // 1970-01-01 was Thursday.
+            let day_of_week = day.checked_add(4)?.checked_rem(7)?;
+            if !self.matches_day(month, day_of_month, day_of_week) {
+                continue
+            }
+            let from = if day == first_day { first_minute } else { 0 };
+            for minute_of_day in from..MINUTES_PER_DAY {
+                let hour = minute_of_day.checked_div(60)?;
+                let minute = minute_of_day.checked_rem(60)?;
+                if contains(self.hours, hour) && contains(self.minutes, minute) {
+                    let minutes = day
+                        .checked_mul(MINUTES_PER_DAY)?
+                        .checked_add(minute_of_day)?;
+                    let secs = minutes.checked_mul(60)?;
+                    return UNIX_EPOCH.checked_add(Duration::from_secs(secs))
+                }
+            }
+        }
+        None
+    }
+
+    fn matches_day(&self, month: u64, day_of_month: u64, day_of_week: u64) -> bool {
+        if !contains(self.months, month) {
+            return false
+        }
+        let by_month = contains(self.days_of_month, day_of_month);
+        let by_week = contains(self.days_of_week, day_of_week);
+        match (self.any_day_of_month, self.any_day_of_week) {
+            (true, true) => true,
+            (true, false) => by_week,
+            (false, true) => by_month,
+            (false, false) => by_month || by_week,
+        }
+    }
+}
+
+impl FromStr for Schedule {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<&str> = s.split_whitespace().collect();
+        let [minutes, hours, days_of_month, months, days_of_week] = fields[..] else {
+            anyhow::bail!(
+                "The schedule `{s}` should have 5 fields: \
+                minute, hour, day of month, month and day of week"
+            )
+        };
+        let mut days_of_week_mask = parse_field(days_of_week, 0, 7, "day of week")?;
+        // Both `0` and `7` are Sunday.
+        if contains(days_of_week_mask, 7) {
+            days_of_week_mask |= 1;
+        }
+        Ok(Self {
+            expression: fields.join(" "),
+            minutes: parse_field(minutes, 0, 59, "minute")?,
+            hours: parse_field(hours, 0, 23, "hour")?,
+            days_of_month: parse_field(days_of_month, 1, 31, "day of month")?,
+            months: parse_field(months, 1, 12, "month")?,
+            days_of_week: days_of_week_mask,
+            any_day_of_month: days_of_month == "*",
+            any_day_of_week: days_of_week == "*",
+        })
+    }
+}
+
+impl fmt::Display for Schedule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.expression)
+    }
+}
+
+/// Parses the field into the bitmask of allowed values.
+fn parse_field(field: &str, min: u64, max: u64, name: &str) -> anyhow::Result<u64> {
+    let mut mask = 0;
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => {
+                let step = step
+                    .parse::<u64>()
+                    .ok()
+                    .filter(|step| *step > 0)
+                    .ok_or_else(|| {
+                        anyhow::anyhow!("Invalid step `{step}` of the {name} field")
+                    })?;
+                (range, step)
+            }
+            None => (part, 1),
+        };
+        let (from, to) = match range {
+            "*" => (min, max),
+            range => {
+                let (from, to) = match range.split_once('-') {
+                    Some(range) => range,
+                    // As in cron, the value with the step starts the range, like `5/15`.
+                    None if part.contains('/') => (range, ""),
+                    None => (range, range),
+                };
+                let parse = |value: &str| {
+                    value
+                        .parse::<u64>()
+                        .ok()
+                        .filter(|value| (min..=max).contains(value))
+                        .ok_or_else(|| {
+                            anyhow::anyhow!(
+                                "The {name} should be between {min} and {max}, got `{value}`"
+                            )
+                        })
+                };
+                let from = parse(from)?;
+                let to = if to.is_empty() { max } else { parse(to)? };
+                (from, to)
+            }
+        };
+        anyhow::ensure!(from <= to, "Invalid range `{range}` of the {name} field");
+        for value in (from..=to).step_by(usize::try_from(step)?) {
+            mask |= 1u64.checked_shl(u32::try_from(value)?).unwrap_or(0);
+        }
+    }
+    Ok(mask)
+}
+
+fn contains(mask: u64, value: u64) -> bool {
+    u32::try_from(value)
+        .ok()
+        .and_then(|value| mask.checked_shr(value))
+        .map(|bits| bits & 1 == 1)
+        .unwrap_or(false)
+}
+
+/// Returns the month and the day of the month of the day since the `UNIX_EPOCH`.
+/// The algorithm is from <https://howardhinnant.github.io/date_algorithms.html>.
+fn civil_from_days(days: u64) -> Option<(u64, u64)> {
+    let z = days.checked_add(719_468)?;
+    let era = z.checked_div(146_097)?;
+    let day_of_era = z.checked_sub(era.checked_mul(146_097)?)?;
+    let year_of_era = day_of_era
+        .checked_sub(day_of_era.checked_div(1460)?)?
+        .checked_add(day_of_era.checked_div(36_524)?)?
+        .checked_sub(day_of_era.checked_div(146_096)?)?
+        .checked_div(365)?;
+    let day_of_year = day_of_era.checked_sub(
+        year_of_era
+            .checked_mul(365)?
+            .checked_add(year_of_era.checked_div(4)?)?
+            .checked_sub(year_of_era.checked_div(100)?)?,
+    )?;
+    let month_from_march = day_of_year
+        .checked_mul(5)?
+        .checked_add(2)?
+        .checked_div(153)?;
+    let day = day_of_year
+        .checked_sub(
+            month_from_march
+                .checked_mul(153)?
+                .checked_add(2)?
+                .checked_div(5)?,
+        )?
+        .checked_add(1)?;
+    let month = if month_from_march < 10 {
+        month_from_march.checked_add(3)?
+    } else {
+        month_from_march.checked_sub(9)?
+    };
+    Some((month, day))
+}
+
+#[allow(clippy::arithmetic_side_effects)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 2024-01-15 10:30:00 UTC, Monday.
+    const MONDAY: u64 = 1_705_314_600;
+
+    fn at(secs: u64) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(secs)
+    }
+
+    fn next(schedule: &str, after: u64) -> u64 {
+        schedule
+            .parse::<Schedule>()
+            .unwrap()
+            .next_after(at(after))
+            .unwrap()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+    }
+
+    #[test]
+    fn every_minute_runs_at_the_next_minute() {
+        assert_eq!(next("* * * * *", MONDAY), MONDAY + 60);
+        assert_eq!(next("* * * * *", MONDAY + 59), MONDAY + 60);
+    }
+
+    #[test]
+    fn daily_schedule_runs_on_the_next_day_after_its_time() {
+        assert_eq!(
+            next("0 3 * * *", MONDAY),
+            MONDAY - 7 * 3600 - 1800 + 24 * 3600
+        );
+        assert_eq!(next("45 10 * * *", MONDAY), MONDAY + 15 * 60);
+    }
+
+    #[test]
+    fn steps_and_lists_are_supported() {
+        assert_eq!(next("*/20 * * * *", MONDAY), MONDAY + 10 * 60);
+        assert_eq!(next("5,35 * * * *", MONDAY), MONDAY + 5 * 60);
+        assert_eq!(next("0 0-12/6 * * *", MONDAY), MONDAY + 90 * 60);
+        assert_eq!(next("15/20 * * * *", MONDAY), MONDAY + 5 * 60);
+    }
+
+    #[test]
+    fn day_of_week_is_matched() {
+        // Sunday, 2024-01-21 00:00.
+        let sunday = MONDAY - 10 * 3600 - 1800 + 6 * 24 * 3600;
+        assert_eq!(next("0 0 * * 0", MONDAY), sunday);
+        assert_eq!(next("0 0 * * 7", MONDAY), sunday);
+    }
+
+    #[test]
+    fn day_of_month_and_month_are_matched() {
+        // 2024-02-29 00:00.
+        assert_eq!(next("0 0 29 2 *", MONDAY), 1_709_164_800);
+        // 2024-02-01 00:00.
+        assert_eq!(next("0 0 1 * *", MONDAY), 1_706_745_600);
+    }
+
+    #[test]
+    fn restricted_day_fields_match_either_of_them() {
+        // Wednesday 2024-01-17 or the 20th, whichever is earlier.
+        assert_eq!(
+            next("0 0 20 * 3", MONDAY),
+            MONDAY - 10 * 3600 - 1800 + 2 * 24 * 3600
+        );
+    }
+
+    #[test]
+    fn invalid_schedules_are_rejected() {
+        for schedule in [
+            "* * * *",
+            "60 * * * *",
+            "* 24 * * *",
+            "* * 0 * *",
+            "* * * 13 *",
+            "* * * * 8",
+            "*/0 * * * *",
+            "5-1 * * * *",
+            "a * * * *",
+        ] {
+            assert!(schedule.parse::<Schedule>().is_err(), "{schedule}");
+        }
+    }
+
+    #[test]
+    fn impossible_schedule_has_no_next_run() {
+        let schedule: Schedule = "0 0 31 2 *".parse().unwrap();
+        assert_eq!(schedule.next_after(at(MONDAY)), None);
+    }
+}
@@ -7,7 +7,10 @@ use fuel_core_types::{
         UniqueIdentifier,
     },
     fuel_types::Bytes32,
-    services::txpool::InsertionResult,
+    services::txpool::{
+        InsertionResult,
+        TxOrigin,
+    },
 };
 use futures::{
     Stream,
@@ -27,7 +30,7 @@ impl FuelService {
         let results: Vec<_> = self
             .shared
             .txpool
-            .insert(vec![Arc::new(tx)])
+            .insert(vec![Arc::new(tx)], TxOrigin::Injected)
             .await
             .into_iter()
             .collect::<Result<_, _>>()?;
@@ -0,0 +1,79 @@
+//! The read replica follows the database of the primary node. It opens the database
+//! as the secondary instance and periodically catches up with the primary instance,
+//! so the GraphQL API of the replica serves the latest committed state.
+
+use crate::combined_database::CombinedDatabase;
+use fuel_core_services::{
+    EmptyShared,
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+};
+use std::time::Duration;
+use tokio::time::MissedTickBehavior;
+
+pub struct Task {
+    database: CombinedDatabase,
+    catch_up_timer: tokio::time::Interval,
+}
+
+#[async_trait::async_trait]
+impl RunnableService for Task {
+    const NAME: &'static str = "Replica";
+    type SharedData = EmptyShared;
+    type Task = Self;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {
+        EmptyShared
+    }
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        self.database.catch_up_with_primary()?;
+        Ok(self)
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableTask for Task {
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        let should_continue;
+        tokio::select! {
+            biased;
+
+            _ = watcher.while_started() => {
+                should_continue = false;
+            }
+
+            _ = self.catch_up_timer.tick() => {
+                // The failure to catch up is not fatal, the replica retries on the next tick.
+                if let Err(err) = self.database.catch_up_with_primary() {
+                    tracing::error!("Failed to catch up with the primary database: {err}");
+                }
+                should_continue = true;
+            }
+        }
+        Ok(should_continue)
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+pub fn new_service(
+    database: CombinedDatabase,
+    catch_up_interval: Duration,
+) -> ServiceRunner<Task> {
+    let mut catch_up_timer = tokio::time::interval(catch_up_interval);
+    catch_up_timer.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    ServiceRunner::new(Task {
+        database,
+        catch_up_timer,
+    })
+}
@@ -0,0 +1,49 @@
+//! The warm standby replication. The primary node streams changes of its databases,
+//! committed at once, to standby nodes over the authenticated connection. The standby
+//! node applies them to its databases without executing blocks, so it can replace
+//! the primary node after the restart without catching up with the network.
+//!
+//! Both nodes compare the roots of the history of blocks to detect the divergence
+//! of their databases. The standby node stops following the primary node if the roots differ.
+
+use crate::database::Database;
+use fuel_core_storage::{
+    not_found,
+    tables::merkle::FuelBlockMerkleMetadata,
+    Result as StorageResult,
+    StorageAsRef,
+};
+use fuel_core_types::fuel_types::{
+    BlockHeight,
+    Bytes32,
+};
+
+pub mod primary;
+mod protocol;
+pub mod standby;
+
+/// Returns the root of the history of blocks up to the block at the `height`.
+fn history_root(
+    database: &Database,
+    height: &BlockHeight,
+) -> StorageResult<Option<Bytes32>> {
+    let metadata = database.storage::<FuelBlockMerkleMetadata>().get(height)?;
+    Ok(metadata.map(|metadata| Bytes32::from(*metadata.root())))
+}
+
+fn block_roots(
+    database: &Database,
+    height: BlockHeight,
+) -> StorageResult<protocol::BlockRoots> {
+    let root =
+        history_root(database, &height)?.ok_or(not_found!("FuelBlockMerkleMetadata"))?;
+    let prev_root = match height.pred() {
+        Some(prev_height) => history_root(database, &prev_height)?,
+        None => None,
+    };
+    Ok(protocol::BlockRoots {
+        height,
+        prev_root,
+        root,
+    })
+}
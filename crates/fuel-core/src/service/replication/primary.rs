@@ -0,0 +1,227 @@
+//! The primary side of the replication. It accepts connections of standby nodes
+//! and streams the replication log to each of them.
+
+use super::{
+    block_roots,
+    protocol::{
+        primary_proof,
+        random_nonce,
+        read_message,
+        standby_proof,
+        write_message,
+        Message,
+        ReplicatedFrame,
+        Session,
+    },
+};
+use crate::{
+    database::Database,
+    state::replicated::ReplicationLog,
+};
+use fuel_core_services::{
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+};
+use fuel_core_types::secrecy::{
+    ExposeSecret,
+    Secret,
+};
+use std::{
+    net::SocketAddr,
+    time::Duration,
+};
+use tokio::net::{
+    TcpListener,
+    TcpStream,
+};
+
+/// The time for the standby node to prove the knowledge of the secret.
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+pub struct Task {
+    listener: std::net::TcpListener,
+    bound_address: SocketAddr,
+    connection: Connection,
+}
+
+pub struct RunningTask {
+    listener: TcpListener,
+    connection: Connection,
+}
+
+#[derive(Clone)]
+struct Connection {
+    log: ReplicationLog,
+    database: Database,
+    secret: Secret<String>,
+}
+
+#[async_trait::async_trait]
+impl RunnableService for Task {
+    const NAME: &'static str = "ReplicationPrimary";
+    type SharedData = SocketAddr;
+    type Task = RunningTask;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {
+        self.bound_address
+    }
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        tracing::info!("Streaming the replication log at {}", self.bound_address);
+        Ok(RunningTask {
+            listener: TcpListener::from_std(self.listener)?,
+            connection: self.connection,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableTask for RunningTask {
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        let should_continue;
+        tokio::select! {
+            biased;
+
+            _ = watcher.while_started() => {
+                should_continue = false;
+            }
+
+            accepted = self.listener.accept() => {
+                match accepted {
+                    Ok((stream, address)) => {
+                        tracing::info!("The standby node {address} connected");
+                        let connection = self.connection.clone();
+                        let shutdown = watcher.clone();
+                        tokio::spawn(async move {
+                            if let Err(err) = connection.serve(stream, shutdown).await {
+                                tracing::warn!(
+                                    "Stopped the replication to the standby node {address}: {err}"
+                                );
+                            }
+                        });
+                    }
+                    Err(err) => {
+                        tracing::error!("Failed to accept the standby node: {err}");
+                    }
+                }
+                should_continue = true;
+            }
+        }
+        Ok(should_continue)
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        // Connections are closed by their tasks after the stop of the service.
+        Ok(())
+    }
+}
+
+impl Connection {
+    async fn serve(
+        self,
+        mut stream: TcpStream,
+        mut shutdown: StateWatcher,
+    ) -> anyhow::Result<()> {
+        let secret = self.secret.expose_secret();
+        let nonce = random_nonce();
+        write_message(&mut stream, &Message::Challenge { nonce }).await?;
+
+        let hello =
+            tokio::time::timeout(HANDSHAKE_TIMEOUT, read_message(&mut stream)).await??;
+        let Message::Hello {
+            proof,
+            nonce: standby_nonce,
+            height,
+        } = hello
+        else {
+            return Err(anyhow::anyhow!("Unexpected handshake message"))
+        };
+        if proof != standby_proof(secret, &nonce) {
+            return reject(&mut stream, "The authentication failed".to_string()).await
+        }
+        let mut sequence = match self.log.resume_sequence(height) {
+            Ok(sequence) => sequence,
+            Err(reason) => return reject(&mut stream, reason).await,
+        };
+        let proof = primary_proof(secret, &standby_nonce);
+        write_message(&mut stream, &Message::Accepted { proof }).await?;
+
+        let session = Session::new(secret, &nonce, &standby_nonce);
+        let mut next_sequence = self.log.subscribe();
+        loop {
+            let Some(batches) = self.log.batches_from(sequence) else {
+                let reason =
+                    "The standby node fell behind the replication log".to_string();
+                return reject(&mut stream, reason).await
+            };
+            for (batch_sequence, batch) in batches {
+                let roots = batch
+                    .height
+                    .map(|height| block_roots(&self.database, height))
+                    .transpose()?;
+                let frame = ReplicatedFrame {
+                    batch: batch.as_ref().clone(),
+                    roots,
+                };
+                let payload = postcard::to_allocvec(&frame)?;
+                let mac = session.batch_mac(batch_sequence, &payload);
+                let message = Message::Batch {
+                    sequence: batch_sequence,
+                    payload,
+                    mac,
+                };
+                write_message(&mut stream, &message).await?;
+                sequence = batch_sequence.saturating_add(1);
+            }
+
+            tokio::select! {
+                biased;
+                _ = shutdown.while_started() => return Ok(()),
+                changed = async {
+                    while *next_sequence.borrow_and_update() <= sequence {
+                        next_sequence.changed().await?;
+                    }
+                    Ok::<_, anyhow::Error>(())
+                } => changed?,
+            }
+        }
+    }
+}
+
+async fn reject(stream: &mut TcpStream, reason: String) -> anyhow::Result<()> {
+    let _ = write_message(
+        stream,
+        &Message::Rejected {
+            reason: reason.clone(),
+        },
+    )
+    .await;
+    Err(anyhow::anyhow!(reason))
+}
+
+pub fn new_service(
+    address: SocketAddr,
+    secret: Secret<String>,
+    log: ReplicationLog,
+    database: Database,
+) -> anyhow::Result<ServiceRunner<Task>> {
+    let listener = std::net::TcpListener::bind(address)?;
+    listener.set_nonblocking(true)?;
+    let bound_address = listener.local_addr()?;
+    Ok(ServiceRunner::new(Task {
+        listener,
+        bound_address,
+        connection: Connection {
+            log,
+            database,
+            secret,
+        },
+    }))
+}
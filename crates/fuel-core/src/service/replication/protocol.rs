@@ -0,0 +1,197 @@
+//! The protocol of the replication between the primary and the standby nodes.
+//!
+//! Messages are serialized with `postcard` and prefixed by their length. The connection
+//! starts with the mutual proof of the knowledge of the shared secret: each side sends
+//! a random nonce, and the other side answers with the keyed hash of it. After that,
+//! the primary node streams batches of changes. Each batch is authenticated by the keyed
+//! hash of the secret, the nonces of the connection, and the sequence number of the batch.
+
+use crate::state::replicated::ReplicatedBatch;
+use fuel_core_types::{
+    fuel_crypto::Hasher,
+    fuel_types::{
+        BlockHeight,
+        Bytes32,
+    },
+};
+use tokio::io::{
+    AsyncRead,
+    AsyncReadExt,
+    AsyncWrite,
+    AsyncWriteExt,
+};
+
+/// The maximum size of the message. It protects the node from allocating
+/// the memory for the length received from the malicious peer.
+const MAX_MESSAGE_SIZE: u32 = 256 * 1024 * 1024;
+
+const STANDBY_DOMAIN: &[u8] = b"FUEL_REPLICATION_STANDBY";
+const PRIMARY_DOMAIN: &[u8] = b"FUEL_REPLICATION_PRIMARY";
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum Message {
+    /// The nonce of the primary node that the standby node should prove.
+    Challenge { nonce: Bytes32 },
+    /// The proof of the standby node and its nonce that the primary should prove.
+    Hello {
+        proof: Bytes32,
+        nonce: Bytes32,
+        /// The height of the latest block in the database of the standby node.
+        height: Option<BlockHeight>,
+    },
+    /// The primary node accepted the standby node and starts streaming batches.
+    Accepted { proof: Bytes32 },
+    /// The primary node rejected the standby node or stopped streaming to it.
+    Rejected { reason: String },
+    /// The batch of changes of the primary database.
+    Batch {
+        sequence: u64,
+        /// The serialized [`ReplicatedFrame`].
+        payload: Vec<u8>,
+        mac: Bytes32,
+    },
+}
+
+/// The roots of the history of blocks in the database of the primary node.
+/// The standby node compares them with its own roots to detect the divergence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct BlockRoots {
+    pub height: BlockHeight,
+    /// The root of the history before the block.
+    pub prev_root: Option<Bytes32>,
+    /// The root of the history including the block.
+    pub root: Bytes32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ReplicatedFrame {
+    pub batch: ReplicatedBatch,
+    pub roots: Option<BlockRoots>,
+}
+
+/// The shared secret and the nonces of the connection.
+pub struct Session {
+    secret: Vec<u8>,
+    id: Bytes32,
+}
+
+impl Session {
+    pub fn new(secret: &str, primary_nonce: &Bytes32, standby_nonce: &Bytes32) -> Self {
+        Self {
+            secret: secret.as_bytes().to_vec(),
+            id: Hasher::default()
+                .chain(primary_nonce)
+                .chain(standby_nonce)
+                .finalize(),
+        }
+    }
+
+    pub fn batch_mac(&self, sequence: u64, payload: &[u8]) -> Bytes32 {
+        keyed_hash(
+            &self.secret,
+            &[self.id.as_ref(), &sequence.to_be_bytes(), payload],
+        )
+    }
+}
+
+pub fn random_nonce() -> Bytes32 {
+    Bytes32::new(rand::random())
+}
+
+/// The proof of the standby node for the `nonce` of the primary node.
+pub fn standby_proof(secret: &str, nonce: &Bytes32) -> Bytes32 {
+    keyed_hash(secret.as_bytes(), &[STANDBY_DOMAIN, nonce.as_ref()])
+}
+
+/// The proof of the primary node for the `nonce` of the standby node.
+pub fn primary_proof(secret: &str, nonce: &Bytes32) -> Bytes32 {
+    keyed_hash(secret.as_bytes(), &[PRIMARY_DOMAIN, nonce.as_ref()])
+}
+
+/// Hashes the `parts` with the `secret` twice, so the hash can't be extended.
+fn keyed_hash(secret: &[u8], parts: &[&[u8]]) -> Bytes32 {
+    let inner = parts
+        .iter()
+        .fold(Hasher::default().chain(secret), |hasher, part| {
+            hasher.chain(part)
+        })
+        .finalize();
+    Hasher::default().chain(secret).chain(inner).finalize()
+}
+
+pub async fn write_message<W>(writer: &mut W, message: &Message) -> anyhow::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let bytes = postcard::to_allocvec(message)?;
+    let len = u32::try_from(bytes.len())
+        .ok()
+        .filter(|len| *len <= MAX_MESSAGE_SIZE)
+        .ok_or_else(|| anyhow::anyhow!("The message is too large"))?;
+    writer.write_all(&len.to_be_bytes()).await?;
+    writer.write_all(&bytes).await?;
+    writer.flush().await?;
+    Ok(())
+}
+
+pub async fn read_message<R>(reader: &mut R) -> anyhow::Result<Message>
+where
+    R: AsyncRead + Unpin,
+{
+    let len = reader.read_u32().await?;
+    if len > MAX_MESSAGE_SIZE {
+        return Err(anyhow::anyhow!("The message of {len} bytes is too large"))
+    }
+    let mut bytes = vec![0; usize::try_from(len)?];
+    reader.read_exact(&mut bytes).await?;
+    Ok(postcard::from_bytes(&bytes)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn messages_are_framed() {
+        let (mut client, mut server) = tokio::io::duplex(1024);
+        let message = Message::Hello {
+            proof: Bytes32::from([1; 32]),
+            nonce: Bytes32::from([2; 32]),
+            height: Some(10u32.into()),
+        };
+
+        write_message(&mut client, &message).await.unwrap();
+        write_message(
+            &mut client,
+            &Message::Rejected {
+                reason: "reason".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(read_message(&mut server).await.unwrap(), message);
+        assert!(matches!(
+            read_message(&mut server).await.unwrap(),
+            Message::Rejected { .. }
+        ));
+    }
+
+    #[test]
+    fn proofs_depend_on_the_secret_and_the_role() {
+        let nonce = random_nonce();
+
+        assert_eq!(
+            standby_proof("secret", &nonce),
+            standby_proof("secret", &nonce)
+        );
+        assert_ne!(
+            standby_proof("secret", &nonce),
+            standby_proof("other", &nonce)
+        );
+        assert_ne!(
+            standby_proof("secret", &nonce),
+            primary_proof("secret", &nonce)
+        );
+    }
+}
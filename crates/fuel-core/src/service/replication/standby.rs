@@ -0,0 +1,329 @@
+//! The standby side of the replication. It follows the primary node and applies
+//! the streamed changes to the local databases.
+
+use super::{
+    history_root,
+    protocol::{
+        primary_proof,
+        random_nonce,
+        read_message,
+        standby_proof,
+        write_message,
+        BlockRoots,
+        Message,
+        ReplicatedFrame,
+        Session,
+    },
+};
+use crate::{
+    combined_database::CombinedDatabase,
+    state::replicated::{
+        ReplicatedBatch,
+        ReplicatedDatabase,
+    },
+};
+use fuel_core_services::{
+    EmptyShared,
+    HealthReporter,
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+};
+use fuel_core_types::{
+    fuel_types::{
+        BlockHeight,
+        Bytes32,
+    },
+    secrecy::{
+        ExposeSecret,
+        Secret,
+    },
+};
+use std::time::Duration;
+use tokio::net::TcpStream;
+
+/// The time for the primary node to prove the knowledge of the secret.
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+pub struct Task {
+    primary: String,
+    secret: Secret<String>,
+    database: CombinedDatabase,
+    reconnect_interval: Duration,
+    health: HealthReporter,
+    connection: Option<Connection>,
+    diverged: bool,
+}
+
+struct Connection {
+    stream: TcpStream,
+    session: Session,
+    next_sequence: Option<u64>,
+}
+
+enum Failure {
+    /// The connection is broken, the standby node reconnects to the primary node.
+    Connection(anyhow::Error),
+    /// The databases of the primary and the standby nodes diverged,
+    /// the standby node stops applying changes.
+    Diverged(String),
+}
+
+impl From<anyhow::Error> for Failure {
+    fn from(err: anyhow::Error) -> Self {
+        Failure::Connection(err)
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableService for Task {
+    const NAME: &'static str = "ReplicationStandby";
+    type SharedData = EmptyShared;
+    type Task = Self;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {
+        EmptyShared
+    }
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        Ok(self)
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableTask for Task {
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        if self.diverged {
+            // The standby keeps serving the API, but the operator should resolve the divergence.
+            watcher.while_started().await?;
+            return Ok(false)
+        }
+
+        let result = match self.connection.take() {
+            None => {
+                let connection = tokio::select! {
+                    biased;
+                    _ = watcher.while_started() => return Ok(false),
+                    connection = self.connect() => connection,
+                };
+                connection.map(|connection| {
+                    tracing::info!("Connected to the primary node {}", self.primary);
+                    self.health.progress();
+                    self.connection = Some(connection);
+                })
+            }
+            Some(mut connection) => {
+                tokio::select! {
+                    biased;
+                    _ = watcher.while_started() => return Ok(false),
+                    message = read_message(&mut connection.stream) => {
+                        let result = message
+                            .map_err(Failure::from)
+                            .and_then(|message| self.process(&mut connection, message));
+                        if result.is_ok() {
+                            self.connection = Some(connection);
+                        }
+                        result
+                    }
+                }
+            }
+        };
+
+        match result {
+            Ok(()) => {}
+            Err(Failure::Diverged(reason)) => {
+                tracing::error!(
+                    "The database diverged from the primary node, \
+                    stopped the replication: {reason}"
+                );
+                self.health.error(format!("Diverged: {reason}"));
+                self.diverged = true;
+            }
+            Err(Failure::Connection(err)) => {
+                tracing::warn!("The replication from the primary node failed: {err}");
+                self.health.error(&err);
+                tokio::select! {
+                    biased;
+                    _ = watcher.while_started() => return Ok(false),
+                    _ = tokio::time::sleep(self.reconnect_interval) => {}
+                }
+            }
+        }
+        Ok(true)
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+impl Task {
+    async fn connect(&self) -> Result<Connection, Failure> {
+        let secret = self.secret.expose_secret();
+        let mut stream = TcpStream::connect(&self.primary)
+            .await
+            .map_err(anyhow::Error::from)?;
+        let Message::Challenge { nonce } = read_handshake(&mut stream).await? else {
+            return Err(anyhow::anyhow!("Unexpected handshake message").into())
+        };
+
+        let standby_nonce = random_nonce();
+        let height = self
+            .database
+            .on_chain()
+            .get_current_block()
+            .map_err(anyhow::Error::from)?
+            .map(|block| *block.header().height());
+        let hello = Message::Hello {
+            proof: standby_proof(secret, &nonce),
+            nonce: standby_nonce,
+            height,
+        };
+        write_message(&mut stream, &hello).await?;
+
+        match read_handshake(&mut stream).await? {
+            Message::Accepted { proof }
+                if proof == primary_proof(secret, &standby_nonce) =>
+            {
+                Ok(Connection {
+                    stream,
+                    session: Session::new(secret, &nonce, &standby_nonce),
+                    next_sequence: None,
+                })
+            }
+            Message::Accepted { .. } => {
+                Err(anyhow::anyhow!("The primary node failed the authentication").into())
+            }
+            Message::Rejected { reason } => {
+                Err(anyhow::anyhow!("The primary node rejected: {reason}").into())
+            }
+            _ => Err(anyhow::anyhow!("Unexpected handshake message").into()),
+        }
+    }
+
+    fn process(
+        &self,
+        connection: &mut Connection,
+        message: Message,
+    ) -> Result<(), Failure> {
+        match message {
+            Message::Batch {
+                sequence,
+                payload,
+                mac,
+            } => {
+                if mac != connection.session.batch_mac(sequence, &payload) {
+                    return Err(
+                        anyhow::anyhow!("The batch {sequence} is not authentic").into()
+                    )
+                }
+                if let Some(expected) = connection.next_sequence {
+                    if sequence != expected {
+                        return Err(anyhow::anyhow!(
+                            "Expected the batch {expected}, but received {sequence}"
+                        )
+                        .into())
+                    }
+                }
+                let frame: ReplicatedFrame =
+                    postcard::from_bytes(&payload).map_err(anyhow::Error::from)?;
+                self.apply(frame)?;
+                connection.next_sequence = Some(sequence.saturating_add(1));
+                self.health.progress();
+                Ok(())
+            }
+            Message::Rejected { reason } => Err(anyhow::anyhow!(
+                "The primary node stopped the replication: {reason}"
+            )
+            .into()),
+            _ => Err(anyhow::anyhow!("Unexpected message").into()),
+        }
+    }
+
+    fn apply(&self, frame: ReplicatedFrame) -> Result<(), Failure> {
+        let ReplicatedFrame { batch, roots } = frame;
+        if batch.height != roots.map(|roots| roots.height) {
+            return Err(Failure::Diverged(
+                "The batch doesn't match the roots of the block".to_string(),
+            ))
+        }
+
+        if let Some(roots) = &roots {
+            let local = match roots.height.pred() {
+                Some(prev_height) => self.root(&prev_height)?,
+                None => None,
+            };
+            if local != roots.prev_root {
+                return Err(Failure::Diverged(format!(
+                    "The history root before the block {} is {local:?}, \
+                    but the primary node has {:?}",
+                    roots.height, roots.prev_root
+                )))
+            }
+        }
+
+        self.write(&batch)
+            .map_err(|err| Failure::Diverged(err.to_string()))?;
+
+        if let Some(BlockRoots { height, root, .. }) = roots {
+            let local = self.root(&height)?;
+            if local != Some(root) {
+                return Err(Failure::Diverged(format!(
+                    "The history root of the block {height} is {local:?}, \
+                    but the primary node has {root}"
+                )))
+            }
+        }
+        Ok(())
+    }
+
+    fn write(&self, batch: &ReplicatedBatch) -> fuel_core_storage::Result<()> {
+        match batch.database {
+            ReplicatedDatabase::OnChain => self
+                .database
+                .on_chain()
+                .write_replicated_changes(&batch.changes),
+            ReplicatedDatabase::OffChain => self
+                .database
+                .off_chain()
+                .write_replicated_changes(&batch.changes),
+            ReplicatedDatabase::Relayer => self
+                .database
+                .relayer()
+                .write_replicated_changes(&batch.changes),
+        }
+    }
+
+    fn root(&self, height: &BlockHeight) -> Result<Option<Bytes32>, Failure> {
+        history_root(self.database.on_chain(), height)
+            .map_err(|err| Failure::Connection(err.into()))
+    }
+}
+
+async fn read_handshake(stream: &mut TcpStream) -> anyhow::Result<Message> {
+    tokio::time::timeout(HANDSHAKE_TIMEOUT, read_message(stream)).await?
+}
+
+pub fn new_service(
+    primary: String,
+    secret: Secret<String>,
+    database: CombinedDatabase,
+    reconnect_interval: Duration,
+    health: HealthReporter,
+) -> ServiceRunner<Task> {
+    ServiceRunner::new(Task {
+        primary,
+        secret,
+        database,
+        reconnect_interval,
+        health,
+        connection: None,
+        diverged: false,
+    })
+}
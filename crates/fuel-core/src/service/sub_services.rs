@@ -5,7 +5,11 @@ use crate::{
     database::Database,
     fuel_core_graphql_api,
     fuel_core_graphql_api::Config as GraphQLConfig,
-    schema::build_schema,
+    schema::{
+        build_read_only_schema,
+        build_schema,
+        NodeSchemaBuilder,
+    },
     service::{
         adapters::{
             BlockImporterAdapter,
@@ -13,28 +17,40 @@ use crate::{
             ExecutorAdapter,
             MaybeRelayerAdapter,
             PoAAdapter,
+            PredicateCacheAdapter,
             TxPoolAdapter,
             VerifierAdapter,
         },
         genesis::create_genesis_block,
         Config,
+        ReplicationConfig,
         SharedState,
         SubServices,
     },
+    state::replicated::ReplicationLog,
 };
+use async_graphql::SchemaBuilder;
 use fuel_core_poa::Trigger;
+use fuel_core_services::{
+    HealthRegistry,
+    Service as _,
+    ShutdownToken,
+};
+use fuel_core_txpool::predicate_cache::PredicateCache;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
 #[cfg(feature = "relayer")]
 use crate::relayer::Config as RelayerConfig;
+#[cfg(feature = "consensus-parameters-canary")]
+use fuel_core_chain_config::ConsensusParametersUpgrade;
 #[cfg(feature = "relayer")]
 use fuel_core_types::blockchain::primitives::DaBlockHeight;
 
 pub type PoAService =
     fuel_core_poa::Service<TxPoolAdapter, BlockProducerAdapter, BlockImporterAdapter>;
 #[cfg(feature = "p2p")]
-pub type P2PService = fuel_core_p2p::service::Service<Database>;
+pub type P2PService = fuel_core_p2p::service::Service<Database, TxPoolAdapter>;
 pub type TxPoolService = fuel_core_txpool::Service<P2PAdapter, Database>;
 pub type BlockProducerService = fuel_core_producer::block_producer::Producer<
     Database,
@@ -43,9 +59,12 @@ pub type BlockProducerService = fuel_core_producer::block_producer::Producer<
 >;
 pub type GraphQL = fuel_core_graphql_api::api_service::Service;
 
+/// Initializes sub-services. The `shutdown` token is used only by the block importer
+/// to finish the commit of the block in progress before the node stops.
 pub fn init_sub_services(
     config: &Config,
     database: CombinedDatabase,
+    shutdown: ShutdownToken,
 ) -> anyhow::Result<(SubServices, SharedState)> {
     let last_block_header = database
         .on_chain()
@@ -57,34 +76,97 @@ pub fn init_sub_services(
         });
     let last_height = *last_block_header.height();
 
+    // The debugger traces transactions, and the consensus parameters canary executes
+    // blocks, on the state before their blocks.
+    let database = if config.keeps_state_history() {
+        database.with_state_history(config.state_history_blocks)
+    } else {
+        database
+    };
+
+    let (database, replication_log) = match &config.replication {
+        Some(ReplicationConfig::Primary(replication)) => {
+            let current_height = database
+                .on_chain()
+                .get_current_block()?
+                .map(|block| *block.header().height());
+            let log = ReplicationLog::new(replication.log_capacity, current_height);
+            (database.with_replication(&log), Some(log))
+        }
+        _ => (database, None),
+    };
+
+    // The cache is shared by the `TxPool` with the executor, so predicates of
+    // transactions from the `TxPool` are not executed again.
+    let predicate_cache = PredicateCache::shared(config.txpool.predicate_cache_size);
+    let executor_config = fuel_core_executor::Config {
+        consensus_parameters: config.chain_conf.consensus_parameters.clone(),
+        consensus_parameters_upgrades: config
+            .chain_conf
+            .consensus_parameters_upgrades
+            .clone(),
+        coinbase_recipient: config.block_producer.coinbase_recipient.unwrap_or_default(),
+        backtrace: config.vm.backtrace,
+        utxo_validation_default: config.utxo_validation,
+        predicate_cache: Some(Arc::new(PredicateCacheAdapter::new(
+            predicate_cache.clone(),
+            config.chain_conf.consensus_parameters_upgrades.clone(),
+        ))),
+    };
     let executor = ExecutorAdapter::new(
         database.on_chain().clone(),
         database.relayer().clone(),
-        fuel_core_executor::Config {
-            consensus_parameters: config.chain_conf.consensus_parameters.clone(),
-            coinbase_recipient: config
-                .block_producer
-                .coinbase_recipient
-                .unwrap_or_default(),
-            backtrace: config.vm.backtrace,
-            utxo_validation_default: config.utxo_validation,
-        },
+        executor_config.clone(),
     );
+    #[cfg(feature = "consensus-parameters-canary")]
+    let executor = if let Some(parameters) = &config.consensus_parameters_canary {
+        tracing::info!("The consensus parameters canary mode is enabled");
+        // The candidate parameters act as the upgrade activated at the next block,
+        // so upgrades of the chain scheduled after it still supersede them.
+        let mut consensus_parameters_upgrades =
+            executor_config.consensus_parameters_upgrades.clone();
+        consensus_parameters_upgrades.push(ConsensusParametersUpgrade {
+            height: last_height.succ().unwrap_or(last_height),
+            consensus_parameters: parameters.clone(),
+        });
+        executor.with_canary(fuel_core_executor::Config {
+            consensus_parameters_upgrades,
+            // The canary verifies predicates on its own.
+            predicate_cache: None,
+            ..executor_config
+        })?
+    } else {
+        executor
+    };
 
     let verifier = VerifierAdapter::new(config, database.on_chain().clone());
 
+    let health = HealthRegistry::new();
+    let mut importer_health = health.register("importer");
+    if let Some(timeout) = config.health_stall_timeout {
+        importer_health = importer_health.with_stall_timeout(timeout);
+    }
+
     let importer_adapter = BlockImporterAdapter::new(
         config.block_importer.clone(),
         database.on_chain().clone(),
         executor.clone(),
         verifier.clone(),
+        shutdown,
+        importer_health,
     );
+    #[cfg(feature = "consensus-parameters-canary")]
+    if let Some(canary) = &executor.canary {
+        canary.follow(importer_adapter.block_importer.subscribe())?;
+    }
 
     #[cfg(feature = "relayer")]
     let relayer_service = if let Some(config) = &config.relayer {
         Some(fuel_core_relayer::new_service(
             database.relayer().clone(),
+            Arc::new(database.on_chain().clone()),
             config.clone(),
+            health.register("relayer"),
         )?)
     } else {
         None
@@ -101,14 +183,10 @@ pub fn init_sub_services(
     };
 
     #[cfg(feature = "p2p")]
-    let mut network = config.p2p.clone().map(|p2p_config| {
-        fuel_core_p2p::service::new_service(
-            config.chain_conf.consensus_parameters.chain_id,
-            p2p_config,
-            database.on_chain().clone(),
-            importer_adapter.clone(),
-        )
-    });
+    let mut p2p_externals = config
+        .p2p
+        .as_ref()
+        .map(fuel_core_p2p::service::build_shared_state);
 
     #[cfg(feature = "p2p")]
     let p2p_adapter = {
@@ -124,7 +202,9 @@ pub fn init_sub_services(
             invalid_transactions: -100.,
         };
         P2PAdapter::new(
-            network.as_ref().map(|network| network.shared.clone()),
+            p2p_externals
+                .as_ref()
+                .map(|(shared_state, _)| shared_state.clone()),
             peer_report_config,
         )
     };
@@ -138,9 +218,26 @@ pub fn init_sub_services(
         importer_adapter.clone(),
         p2p_adapter.clone(),
         last_height,
+        predicate_cache,
+        health.register("txpool"),
     );
     let tx_pool_adapter = TxPoolAdapter::new(txpool.shared.clone());
 
+    #[cfg(feature = "p2p")]
+    let mut network = config.p2p.clone().and_then(|p2p_config| {
+        let (shared_state, request_receiver) = p2p_externals.take()?;
+        Some(fuel_core_p2p::service::new_service(
+            config.chain_conf.consensus_parameters.chain_id,
+            p2p_config,
+            shared_state,
+            request_receiver,
+            database.on_chain().clone(),
+            importer_adapter.clone(),
+            tx_pool_adapter.clone(),
+            health.register("p2p"),
+        ))
+    });
+
     let block_producer = fuel_core_producer::Producer {
         config: config.block_producer.clone(),
         view_provider: database.on_chain().clone(),
@@ -171,44 +268,89 @@ pub fn init_sub_services(
     });
     let poa_adapter = PoAAdapter::new(poa.as_ref().map(|service| service.shared.clone()));
 
+    // The synchronization is useless without the network, so it is absent
+    // instead of waiting for peers that never come.
     #[cfg(feature = "p2p")]
-    let sync = fuel_core_sync::service::new_service(
-        last_height,
-        p2p_adapter.clone(),
-        importer_adapter.clone(),
-        super::adapters::ConsensusAdapter::new(
-            verifier.clone(),
-            config.relayer_consensus_config.clone(),
-            relayer_adapter,
-        ),
-        config.sync,
-    )?;
+    let sync = network
+        .is_some()
+        .then(|| {
+            fuel_core_sync::service::new_service(
+                last_height,
+                p2p_adapter.clone(),
+                importer_adapter.clone(),
+                super::adapters::ConsensusAdapter::new(
+                    verifier.clone(),
+                    config.relayer_consensus_config.clone(),
+                    relayer_adapter,
+                ),
+                config.sync,
+                health.register("sync"),
+            )
+        })
+        .transpose()?;
 
-    // TODO: Figure out on how to move it into `fuel-core-graphql-api`.
-    let schema = crate::schema::dap::init(
-        build_schema(),
-        config.chain_conf.consensus_parameters.clone(),
-        config.debug,
-    )
-    .data(database.on_chain().clone());
+    // The node that doesn't modify its database neither accepts transactions nor imports
+    // blocks, so it serves only queries.
+    let schema = if config.modifies_database() {
+        NodeSchemaBuilder::Full(init_schema(build_schema(), config, &database))
+    } else {
+        NodeSchemaBuilder::ReadOnly(init_schema(
+            build_read_only_schema(),
+            config,
+            &database,
+        ))
+    };
 
     let graphql_worker = fuel_core_graphql_api::worker_service::new_service(
         importer_adapter.clone(),
         database.off_chain().clone(),
     );
 
+    if config.single_node {
+        tracing::info!("Running in the single-node mode without the P2P network");
+    }
+
     let graphql_config = GraphQLConfig {
         addr: config.addr,
+        node_mode: config.node_mode(),
+        tx_finality_depth: config.tx_finality_depth,
         utxo_validation: config.utxo_validation,
         debug: config.debug,
+        tx_pool_dump_dir: config.tx_pool_dump_dir.clone(),
         vm_backtrace: config.vm.backtrace,
-        min_gas_price: config.txpool.min_gas_price,
         max_tx: config.txpool.max_tx,
         max_depth: config.txpool.max_depth,
         chain_name: config.chain_conf.chain_name.clone(),
         consensus_parameters: config.chain_conf.consensus_parameters.clone(),
+        consensus_parameters_upgrades: config
+            .chain_conf
+            .consensus_parameters_upgrades
+            .clone(),
         consensus_key: config.consensus_key.clone(),
+        max_queries_depth: config.graphql_max_depth,
+        max_queries_complexity: config.graphql_max_complexity,
+        rate_limit: config.graphql_rate_limit,
+        api_keys: config.graphql_api_keys.iter().cloned().collect(),
+        max_batch_size: config.graphql_max_batch_size,
+        max_subscriptions_per_client: config.txpool.subscription_limits.max_per_client,
+        node_identity: config.node_identity.clone(),
+    };
+
+    let maintenance_entries = if !config.modifies_database() {
+        if !config.maintenance.is_empty() {
+            tracing::warn!(
+                "The node can't modify the database, the maintenance is disabled"
+            );
+        }
+        vec![]
+    } else {
+        config.maintenance.clone()
     };
+    let maintenance = super::maintenance::new_service(
+        database.clone(),
+        maintenance_entries,
+        config.chain_conf.clone(),
+    );
 
     let graph_ql = fuel_core_graphql_api::api_service::new_service(
         graphql_config,
@@ -221,8 +363,23 @@ pub fn init_sub_services(
         Box::new(p2p_adapter),
         config.query_log_threshold_time,
         config.api_request_timeout,
+        health.clone(),
+        maintenance.shared.clone(),
+        graphql_worker.shared.clone(),
     )?;
 
+    let replication_primary = match (&config.replication, replication_log) {
+        (Some(ReplicationConfig::Primary(replication)), Some(log)) => {
+            Some(super::replication::primary::new_service(
+                replication.addr,
+                replication.secret.clone(),
+                log,
+                database.on_chain().clone(),
+            )?)
+        }
+        _ => None,
+    };
+
     let shared = SharedState {
         poa_adapter,
         txpool: txpool.shared.clone(),
@@ -233,9 +390,45 @@ pub fn init_sub_services(
         graph_ql: graph_ql.shared.clone(),
         database,
         block_importer: importer_adapter,
+        replication_address: replication_primary.as_ref().map(|primary| primary.shared),
         config: config.clone(),
     };
 
+    if config.db_read_only {
+        // The read-only node only serves the GraphQL API. The txpool, the block production,
+        // the synchronization and the relayer are not started, so nothing writes to the database.
+        let services: SubServices = vec![Box::new(graph_ql)];
+        return Ok((services, shared))
+    }
+
+    if let Some(replica) = &config.replica {
+        // The replica only serves the GraphQL API, the primary node does everything else.
+        let replica = super::replica::new_service(
+            shared.database.clone(),
+            replica.catch_up_interval,
+        );
+        let services: SubServices = vec![Box::new(graph_ql), Box::new(replica)];
+        return Ok((services, shared))
+    }
+
+    if let Some(ReplicationConfig::Standby(standby)) = &config.replication {
+        // The standby only serves the GraphQL API and applies changes of the primary node.
+        let standby_health = health.register("standby");
+        let standby = super::replication::standby::new_service(
+            standby.primary.clone(),
+            standby.secret.clone(),
+            shared.database.clone(),
+            standby.reconnect_interval,
+            standby_health.clone(),
+        );
+        standby_health.watch(standby.state_watcher());
+        let services: SubServices =
+            vec![Box::new(graph_ql), Box::new(standby), Box::new(maintenance)];
+        return Ok((services, shared))
+    }
+
+    health.register("txpool").watch(txpool.state_watcher());
+
     #[allow(unused_mut)]
     // `FuelService` starts and shutdowns all sub-services in the `services` order
     let mut services: SubServices = vec![
@@ -250,18 +443,51 @@ pub fn init_sub_services(
 
     #[cfg(feature = "relayer")]
     if let Some(relayer) = relayer_service {
+        health.register("relayer").watch(relayer.state_watcher());
         services.push(Box::new(relayer));
     }
 
     #[cfg(feature = "p2p")]
     {
         if let Some(network) = network.take() {
+            health.register("p2p").watch(network.state_watcher());
             services.push(Box::new(network));
+        }
+        if let Some(sync) = sync {
+            health.register("sync").watch(sync.state_watcher());
             services.push(Box::new(sync));
         }
     }
 
     services.push(Box::new(graphql_worker));
+    services.push(Box::new(maintenance));
+
+    if let Some(replication_primary) = replication_primary {
+        services.push(Box::new(replication_primary));
+    }
+
+    if config.hooks.has_import_hooks() {
+        let hooks = super::hooks::new_service(
+            shared.block_importer.block_importer.subscribe(),
+            config.hooks.clone(),
+        );
+        services.push(Box::new(hooks));
+    }
 
     Ok((services, shared))
 }
+
+// TODO: Figure out on how to move it into `fuel-core-graphql-api`.
+fn init_schema<Q, M, S>(
+    schema: SchemaBuilder<Q, M, S>,
+    config: &Config,
+    database: &CombinedDatabase,
+) -> SchemaBuilder<Q, M, S> {
+    crate::schema::dap::init(
+        schema,
+        config.chain_conf.consensus_parameters.clone(),
+        config.chain_conf.consensus_parameters_upgrades.clone(),
+        config.debug,
+    )
+    .data(database.on_chain().clone())
+}
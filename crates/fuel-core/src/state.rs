@@ -20,10 +20,13 @@ use fuel_core_storage::{
 };
 use std::{
     fmt::Debug,
+    path::Path,
     sync::Arc,
 };
 
+pub mod historical;
 pub mod in_memory;
+pub mod replicated;
 #[cfg(feature = "rocksdb")]
 pub mod rocks_db;
 
@@ -54,6 +57,36 @@ where
     }
 }
 
+impl<Description> From<Arc<replicated::ReplicatedStorage<Description>>>
+    for DataSource<Description>
+where
+    Description: DatabaseDescription,
+{
+    fn from(inner: Arc<replicated::ReplicatedStorage<Description>>) -> Self {
+        Self(inner)
+    }
+}
+
+impl<Description> From<Arc<historical::HistoricalStorage<Description>>>
+    for DataSource<Description>
+where
+    Description: DatabaseDescription,
+{
+    fn from(inner: Arc<historical::HistoricalStorage<Description>>) -> Self {
+        Self(inner)
+    }
+}
+
+impl<Description> From<Arc<historical::HistoricalView<Description>>>
+    for DataSource<Description>
+where
+    Description: DatabaseDescription,
+{
+    fn from(inner: Arc<historical::HistoricalView<Description>>) -> Self {
+        Self(inner)
+    }
+}
+
 impl<Description> From<Arc<MemoryStore<Description>>> for DataSource<Description>
 where
     Description: DatabaseDescription,
@@ -87,4 +120,23 @@ pub trait TransactableStorage:
     IteratorableStore + BatchOperations + Debug + Send + Sync
 {
     fn flush(&self) -> DatabaseResult<()>;
+
+    /// Catches up with the primary instance if the storage is the secondary instance.
+    fn catch_up_with_primary(&self) -> DatabaseResult<()> {
+        Ok(())
+    }
+
+    /// Compacts the whole key range of the storage.
+    fn compact(&self) -> DatabaseResult<()> {
+        Ok(())
+    }
+
+    /// Creates the consistent copy of the storage in the `path` directory.
+    fn checkpoint(&self, path: &Path) -> DatabaseResult<()> {
+        Err(anyhow::anyhow!(
+            "The storage doesn't support checkpoints at {}",
+            path.display()
+        )
+        .into())
+    }
 }
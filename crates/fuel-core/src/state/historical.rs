@@ -0,0 +1,559 @@
+//! The data source that keeps the previous values of keys modified by the latest
+//! blocks. It allows reading the state of the on-chain database at the height of
+//! one of these blocks via the [`HistoricalView`].
+
+use crate::{
+    database::{
+        database_description::DatabaseDescription,
+        Result as DatabaseResult,
+    },
+    state::{
+        BatchOperations,
+        DataSource,
+        IterDirection,
+        TransactableStorage,
+    },
+};
+use fuel_core_services::SharedMutex;
+use fuel_core_storage::{
+    column::Column,
+    iter::{
+        BoxedIter,
+        IntoBoxedIter,
+        IteratorableStore,
+    },
+    kv_store::{
+        KVItem,
+        KeyValueStore,
+        StorageColumn,
+        Value,
+        WriteOperation,
+    },
+    Error as StorageError,
+    Result as StorageResult,
+};
+use fuel_core_types::fuel_types::BlockHeight;
+use itertools::{
+    EitherOrBoth,
+    Itertools,
+};
+use std::{
+    collections::{
+        BTreeMap,
+        HashMap,
+        VecDeque,
+    },
+    fmt::Debug,
+};
+
+/// The column id and the key of the value.
+type Key = (u32, Vec<u8>);
+
+/// The values of keys before the modification by the block.
+/// The `None` value means that the key didn't exist.
+struct BlockUndo {
+    height: BlockHeight,
+    previous: HashMap<Key, Option<Value>>,
+}
+
+struct HistoryEntries {
+    blocks: VecDeque<BlockUndo>,
+    capacity: usize,
+}
+
+impl HistoryEntries {
+    /// Returns `true` if the history has the changes of all blocks after the `height`.
+    fn covers(&self, height: BlockHeight) -> bool {
+        self.blocks
+            .front()
+            .map(|block| block.height <= height.succ().unwrap_or(height))
+            .unwrap_or(false)
+    }
+
+    /// Returns the value of the `key` at the `height` if any later block modified it.
+    fn value_at(&self, height: BlockHeight, key: &Key) -> Option<Option<Value>> {
+        self.blocks
+            .iter()
+            .filter(|block| block.height > height)
+            .find_map(|block| block.previous.get(key).cloned())
+    }
+
+    fn record(
+        &mut self,
+        height: Option<BlockHeight>,
+        previous: HashMap<Key, Option<Value>>,
+    ) {
+        match height {
+            Some(height) => {
+                self.blocks.push_back(BlockUndo { height, previous });
+                while self.blocks.len() > self.capacity {
+                    self.blocks.pop_front();
+                }
+            }
+            // The changes outside of blocks are undone together with the latest block.
+            // Without blocks in the history, the state before them is unreachable anyway.
+            None => {
+                if let Some(block) = self.blocks.back_mut() {
+                    for (key, value) in previous {
+                        block.previous.entry(key).or_insert(value);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The bounded history of the changes of the latest blocks.
+/// The changes of the oldest block are dropped when the history reaches its capacity.
+#[derive(Clone)]
+pub struct StateHistory {
+    entries: SharedMutex<HistoryEntries>,
+}
+
+impl Debug for StateHistory {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("StateHistory").finish()
+    }
+}
+
+impl StateHistory {
+    /// Creates the history keeping the changes of up to `capacity` blocks.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: SharedMutex::new(HistoryEntries {
+                blocks: VecDeque::new(),
+                capacity: capacity.max(1),
+            }),
+        }
+    }
+
+    /// Returns the view of the `inner` data source at the `height`.
+    /// The `inner` data source should be the one wrapped by the [`HistoricalStorage`]
+    /// that records into this history.
+    pub fn view_at<Description>(
+        &self,
+        inner: DataSource<Description>,
+        height: BlockHeight,
+    ) -> StorageResult<HistoricalView<Description>>
+    where
+        Description: DatabaseDescription,
+    {
+        if !self.entries.apply(|entries| entries.covers(height)) {
+            return Err(StorageError::Other(anyhow::anyhow!(
+                "The state at height {height} is not in the history"
+            )))
+        }
+        Ok(HistoricalView {
+            inner,
+            history: self.clone(),
+            height,
+        })
+    }
+}
+
+/// The data source that records the previous values of all modified keys
+/// into the [`StateHistory`].
+#[derive(Debug)]
+pub struct HistoricalStorage<Description>
+where
+    Description: DatabaseDescription,
+{
+    inner: DataSource<Description>,
+    history: StateHistory,
+}
+
+impl<Description> HistoricalStorage<Description>
+where
+    Description: DatabaseDescription,
+{
+    pub fn new(inner: DataSource<Description>, history: StateHistory) -> Self {
+        Self { inner, history }
+    }
+
+    /// Returns the view of the wrapped data source at the `height`.
+    pub fn view_at(
+        &self,
+        height: BlockHeight,
+    ) -> StorageResult<HistoricalView<Description>> {
+        self.history.view_at(self.inner.clone(), height)
+    }
+
+    fn write_and_record(
+        &self,
+        entries: Vec<(Vec<u8>, Description::Column, WriteOperation)>,
+    ) -> StorageResult<()> {
+        // The lock is held during the write so that views never observe
+        // the new values without the recorded previous ones.
+        self.history.entries.apply(|history| {
+            let blocks = Column::FuelBlocks.id();
+            let mut previous = HashMap::new();
+            let mut height = None;
+            for (key, column, operation) in entries.iter() {
+                if column.id() == blocks && matches!(operation, WriteOperation::Insert(_))
+                {
+                    if let Ok(bytes) = <[u8; 4]>::try_from(key.as_slice()) {
+                        let block = BlockHeight::new(u32::from_be_bytes(bytes));
+                        height = height.max(Some(block));
+                    }
+                }
+                let entry_key = (column.id(), key.clone());
+                if !previous.contains_key(&entry_key) {
+                    let value = self.inner.get(key, *column)?;
+                    previous.insert(entry_key, value);
+                }
+            }
+            self.inner.batch_write(&mut entries.into_iter())?;
+            history.record(height, previous);
+            Ok(())
+        })
+    }
+}
+
+impl<Description> KeyValueStore for HistoricalStorage<Description>
+where
+    Description: DatabaseDescription,
+{
+    type Column = Description::Column;
+
+    fn write(
+        &self,
+        key: &[u8],
+        column: Self::Column,
+        buf: &[u8],
+    ) -> StorageResult<usize> {
+        let value = WriteOperation::Insert(std::sync::Arc::new(buf.to_vec()));
+        self.write_and_record(vec![(key.to_vec(), column, value)])?;
+        Ok(buf.len())
+    }
+
+    fn delete(&self, key: &[u8], column: Self::Column) -> StorageResult<()> {
+        self.write_and_record(vec![(key.to_vec(), column, WriteOperation::Remove)])
+    }
+
+    fn exists(&self, key: &[u8], column: Self::Column) -> StorageResult<bool> {
+        self.inner.exists(key, column)
+    }
+
+    fn size_of_value(
+        &self,
+        key: &[u8],
+        column: Self::Column,
+    ) -> StorageResult<Option<usize>> {
+        self.inner.size_of_value(key, column)
+    }
+
+    fn get(&self, key: &[u8], column: Self::Column) -> StorageResult<Option<Value>> {
+        self.inner.get(key, column)
+    }
+
+    fn read(
+        &self,
+        key: &[u8],
+        column: Self::Column,
+        buf: &mut [u8],
+    ) -> StorageResult<Option<usize>> {
+        self.inner.read(key, column, buf)
+    }
+}
+
+impl<Description> IteratorableStore for HistoricalStorage<Description>
+where
+    Description: DatabaseDescription,
+{
+    fn iter_all(
+        &self,
+        column: Self::Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<KVItem> {
+        self.inner.iter_all(column, prefix, start, direction)
+    }
+}
+
+impl<Description> BatchOperations for HistoricalStorage<Description>
+where
+    Description: DatabaseDescription,
+{
+    fn batch_write(
+        &self,
+        entries: &mut dyn Iterator<Item = (Vec<u8>, Self::Column, WriteOperation)>,
+    ) -> StorageResult<()> {
+        self.write_and_record(entries.collect())
+    }
+}
+
+impl<Description> TransactableStorage for HistoricalStorage<Description>
+where
+    Description: DatabaseDescription,
+{
+    fn flush(&self) -> DatabaseResult<()> {
+        self.inner.flush()
+    }
+
+    fn catch_up_with_primary(&self) -> DatabaseResult<()> {
+        self.inner.catch_up_with_primary()
+    }
+
+    fn compact(&self) -> DatabaseResult<()> {
+        self.inner.compact()
+    }
+
+    fn checkpoint(&self, path: &std::path::Path) -> DatabaseResult<()> {
+        self.inner.checkpoint(path)
+    }
+}
+
+/// The read-only view of the data source at the height of one of the blocks
+/// in the [`StateHistory`].
+///
+/// The reads of single values are consistent with the height. The iteration merges
+/// the history with the live data source, so it isn't isolated from blocks
+/// committed during the iteration.
+#[derive(Debug)]
+pub struct HistoricalView<Description>
+where
+    Description: DatabaseDescription,
+{
+    inner: DataSource<Description>,
+    history: StateHistory,
+    height: BlockHeight,
+}
+
+impl<Description> HistoricalView<Description>
+where
+    Description: DatabaseDescription,
+{
+    fn read_only_error(&self) -> StorageError {
+        StorageError::Other(anyhow::anyhow!(
+            "The view of the state at height {} is read-only",
+            self.height
+        ))
+    }
+
+    /// Returns the previous values of keys in the `column` modified after the height
+    /// of the view, in the order of the `direction`.
+    fn overlay(
+        &self,
+        column: Description::Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> StorageResult<Vec<(Vec<u8>, Option<Value>)>> {
+        self.history.entries.apply(|history| {
+            if !history.covers(self.height) {
+                return Err(self.evicted_error())
+            }
+            let mut overlay = BTreeMap::new();
+            let blocks = history
+                .blocks
+                .iter()
+                .filter(|block| block.height > self.height);
+            for block in blocks {
+                for ((id, key), value) in block.previous.iter() {
+                    if *id != column.id() {
+                        continue
+                    }
+                    if let Some(prefix) = prefix {
+                        if !key.starts_with(prefix) {
+                            continue
+                        }
+                    }
+                    if let Some(start) = start {
+                        let before_start = match direction {
+                            IterDirection::Forward => key.as_slice() < start,
+                            IterDirection::Reverse => key.as_slice() > start,
+                        };
+                        if before_start {
+                            continue
+                        }
+                    }
+                    overlay.entry(key.clone()).or_insert_with(|| value.clone());
+                }
+            }
+            let overlay = match direction {
+                IterDirection::Forward => overlay.into_iter().collect(),
+                IterDirection::Reverse => overlay.into_iter().rev().collect(),
+            };
+            Ok(overlay)
+        })
+    }
+
+    fn evicted_error(&self) -> StorageError {
+        StorageError::Other(anyhow::anyhow!(
+            "The state at height {} is evicted from the history",
+            self.height
+        ))
+    }
+}
+
+impl<Description> KeyValueStore for HistoricalView<Description>
+where
+    Description: DatabaseDescription,
+{
+    type Column = Description::Column;
+
+    fn write(&self, _: &[u8], _: Self::Column, _: &[u8]) -> StorageResult<usize> {
+        Err(self.read_only_error())
+    }
+
+    fn delete(&self, _: &[u8], _: Self::Column) -> StorageResult<()> {
+        Err(self.read_only_error())
+    }
+
+    fn get(&self, key: &[u8], column: Self::Column) -> StorageResult<Option<Value>> {
+        // The lock is held during the read so that the block committed
+        // in the meantime is either in the history or not in the data source.
+        self.history.entries.apply(|history| {
+            if !history.covers(self.height) {
+                return Err(self.evicted_error())
+            }
+            match history.value_at(self.height, &(column.id(), key.to_vec())) {
+                Some(value) => Ok(value),
+                None => self.inner.get(key, column),
+            }
+        })
+    }
+}
+
+impl<Description> IteratorableStore for HistoricalView<Description>
+where
+    Description: DatabaseDescription,
+{
+    fn iter_all(
+        &self,
+        column: Self::Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<KVItem> {
+        let overlay = match self.overlay(column, prefix, start, direction) {
+            Ok(overlay) => overlay,
+            Err(err) => return core::iter::once(Err(err)).into_boxed(),
+        };
+        overlay
+            .into_iter()
+            .map(Ok)
+            .merge_join_by(
+                self.inner
+                    .iter_all(column, prefix, start, direction)
+                    .map(|item| item.map(|(key, value)| (key, Some(value)))),
+                move |i, j| {
+                    if let (Ok(i), Ok(j)) = (i, j) {
+                        if IterDirection::Forward == direction {
+                            i.0.cmp(&j.0)
+                        } else {
+                            j.0.cmp(&i.0)
+                        }
+                    } else {
+                        // prioritize errors from db result first
+                        if j.is_err() {
+                            core::cmp::Ordering::Greater
+                        } else {
+                            core::cmp::Ordering::Less
+                        }
+                    }
+                },
+            )
+            .map(|either_both| {
+                match either_both {
+                    // in the case of overlap, choose the value from the history
+                    EitherOrBoth::Both(v, _)
+                    | EitherOrBoth::Left(v)
+                    | EitherOrBoth::Right(v) => v,
+                }
+            })
+            // filter keys which didn't exist at the height of the view
+            .filter_map(|item| match item {
+                Ok((key, Some(value))) => Some(Ok((key, value))),
+                Ok((_, None)) => None,
+                Err(err) => Some(Err(err)),
+            })
+            .into_boxed()
+    }
+}
+
+impl<Description> BatchOperations for HistoricalView<Description> where
+    Description: DatabaseDescription
+{
+}
+
+impl<Description> TransactableStorage for HistoricalView<Description>
+where
+    Description: DatabaseDescription,
+{
+    fn flush(&self) -> DatabaseResult<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        database::database_description::on_chain::OnChain,
+        state::in_memory::memory_store::MemoryStore,
+    };
+    use std::sync::Arc;
+
+    fn insert_block(storage: &HistoricalStorage<OnChain>, height: u32, value: u8) {
+        let entries = vec![
+            (
+                BlockHeight::new(height).to_bytes().to_vec(),
+                Column::FuelBlocks,
+                WriteOperation::Insert(Arc::new(vec![])),
+            ),
+            (
+                vec![1],
+                Column::Metadata,
+                WriteOperation::Insert(Arc::new(vec![value])),
+            ),
+        ];
+        storage.batch_write(&mut entries.into_iter()).unwrap();
+    }
+
+    #[test]
+    fn view_at_returns_values_before_later_blocks() {
+        let history = StateHistory::new(10);
+        let inner: DataSource<OnChain> = Arc::new(MemoryStore::default()).into();
+        let storage = HistoricalStorage::new(inner.clone(), history.clone());
+        insert_block(&storage, 1, 1);
+        insert_block(&storage, 2, 2);
+        storage.delete(&[1], Column::Metadata).unwrap();
+
+        let view = history.view_at(inner.clone(), 1u32.into()).unwrap();
+        assert_eq!(
+            view.get(&[1], Column::Metadata).unwrap(),
+            Some(Arc::new(vec![1]))
+        );
+        let blocks = view
+            .iter_all(Column::FuelBlocks, None, None, IterDirection::Reverse)
+            .map(|item| item.unwrap().0)
+            .collect::<Vec<_>>();
+        assert_eq!(blocks, vec![BlockHeight::new(1).to_bytes().to_vec()]);
+
+        let view = history.view_at(inner.clone(), 0u32.into()).unwrap();
+        assert_eq!(view.get(&[1], Column::Metadata).unwrap(), None);
+        assert!(view.write(&[1], Column::Metadata, &[3]).is_err());
+        assert_eq!(inner.get(&[1], Column::Metadata).unwrap(), None);
+    }
+
+    #[test]
+    fn view_at_fails_for_evicted_heights() {
+        let history = StateHistory::new(2);
+        let inner: DataSource<OnChain> = Arc::new(MemoryStore::default()).into();
+        let storage = HistoricalStorage::new(inner.clone(), history.clone());
+        insert_block(&storage, 1, 1);
+        let view = history.view_at(inner.clone(), 0u32.into()).unwrap();
+        insert_block(&storage, 2, 2);
+        insert_block(&storage, 3, 3);
+
+        assert!(history.view_at(inner.clone(), 0u32.into()).is_err());
+        assert!(view.get(&[1], Column::Metadata).is_err());
+        let view = history.view_at(inner, 1u32.into()).unwrap();
+        assert_eq!(
+            view.get(&[1], Column::Metadata).unwrap(),
+            Some(Arc::new(vec![1]))
+        );
+    }
+}
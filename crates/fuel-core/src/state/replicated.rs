@@ -0,0 +1,455 @@
+//! The data source that records all its changes into the [`ReplicationLog`].
+//! The log is streamed by the primary node to the warm standby nodes.
+
+use crate::{
+    database::{
+        database_description::DatabaseDescription,
+        Result as DatabaseResult,
+    },
+    state::{
+        BatchOperations,
+        DataSource,
+        IterDirection,
+        TransactableStorage,
+    },
+};
+use fuel_core_services::SharedMutex;
+use fuel_core_storage::{
+    column::Column,
+    iter::{
+        BoxedIter,
+        IteratorableStore,
+    },
+    kv_store::{
+        KVItem,
+        KeyValueStore,
+        StorageColumn,
+        Value,
+        WriteOperation,
+    },
+    Result as StorageResult,
+};
+use fuel_core_types::fuel_types::BlockHeight;
+use std::{
+    collections::VecDeque,
+    fmt::Debug,
+    sync::Arc,
+};
+use tokio::sync::watch;
+
+/// The database of the node, the changes of which are replicated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum ReplicatedDatabase {
+    OnChain,
+    OffChain,
+    Relayer,
+}
+
+/// The change of the value under the `key` in the `column`. The `None` value is the removal.
+pub type ReplicatedChange = (u32, Vec<u8>, Option<Vec<u8>>);
+
+/// The changes of the database committed at once.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ReplicatedBatch {
+    /// The database modified by the batch.
+    pub database: ReplicatedDatabase,
+    /// The height of the block inserted by the batch into the on-chain database.
+    pub height: Option<BlockHeight>,
+    /// The changes of the batch.
+    pub changes: Vec<ReplicatedChange>,
+}
+
+struct LogEntries {
+    entries: VecDeque<(u64, Arc<ReplicatedBatch>)>,
+    next_sequence: u64,
+    capacity: usize,
+    /// The height of the database at the creation of the log.
+    initial_height: Option<BlockHeight>,
+    latest_height: Option<BlockHeight>,
+}
+
+/// The bounded log of the changes of databases in the order of their commits.
+/// Each batch has a sequence number. The oldest batches are dropped from the log
+/// when it reaches its capacity.
+#[derive(Clone)]
+pub struct ReplicationLog {
+    entries: SharedMutex<LogEntries>,
+    next_sequence: Arc<watch::Sender<u64>>,
+}
+
+impl Debug for ReplicationLog {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ReplicationLog")
+            .field("next_sequence", &*self.next_sequence.borrow())
+            .finish()
+    }
+}
+
+impl ReplicationLog {
+    /// Creates the log keeping up to `capacity` batches for the database
+    /// with the `latest_height` block.
+    pub fn new(capacity: usize, latest_height: Option<BlockHeight>) -> Self {
+        let (next_sequence, _) = watch::channel(0);
+        Self {
+            entries: SharedMutex::new(LogEntries {
+                entries: VecDeque::new(),
+                next_sequence: 0,
+                capacity: capacity.max(1),
+                initial_height: latest_height,
+                latest_height,
+            }),
+            next_sequence: Arc::new(next_sequence),
+        }
+    }
+
+    /// Appends the batch to the log.
+    pub fn push(&self, batch: ReplicatedBatch) {
+        let next_sequence = self.entries.apply(|log| {
+            if batch.height.is_some() {
+                log.latest_height = batch.height;
+            }
+            log.entries.push_back((log.next_sequence, Arc::new(batch)));
+            while log.entries.len() > log.capacity {
+                log.entries.pop_front();
+            }
+            log.next_sequence = log.next_sequence.saturating_add(1);
+            log.next_sequence
+        });
+        self.next_sequence.send_replace(next_sequence);
+    }
+
+    /// Subscribes to the sequence number of the next batch.
+    pub fn subscribe(&self) -> watch::Receiver<u64> {
+        self.next_sequence.subscribe()
+    }
+
+    /// Returns the sequence number of the first batch that the database with the
+    /// `height` block should apply to catch up with the log. Returns the reason
+    /// if the database can't catch up.
+    pub fn resume_sequence(&self, height: Option<BlockHeight>) -> Result<u64, String> {
+        self.entries.apply(|log| {
+            let first = log
+                .entries
+                .front()
+                .map(|(sequence, _)| *sequence)
+                .unwrap_or(log.next_sequence);
+            if height == log.initial_height && first == 0 {
+                return Ok(0)
+            }
+
+            let Some(height) = height else {
+                return Err("The database is empty, it should be initialized with \
+                    a copy of the database of the primary node"
+                    .to_string())
+            };
+
+            if let Some(latest_height) = log.latest_height {
+                if height > latest_height {
+                    return Err(format!(
+                        "The database at height {height} is ahead of the primary \
+                        node at height {latest_height}, the databases diverged"
+                    ))
+                }
+            }
+
+            let block = log
+                .entries
+                .iter()
+                .rev()
+                .find(|(_, batch)| batch.height == Some(height));
+            match block {
+                Some((sequence, _)) => Ok(sequence.saturating_add(1)),
+                None if log.latest_height == Some(height) => Ok(log.next_sequence),
+                None => Err(format!(
+                    "The database at height {height} is behind the replication log \
+                    of the primary node, it should be initialized with a fresh copy \
+                    of the database of the primary node"
+                )),
+            }
+        })
+    }
+
+    /// Returns batches starting from the `sequence`.
+    /// Returns `None` if the batch with the `sequence` is already dropped.
+    pub fn batches_from(
+        &self,
+        sequence: u64,
+    ) -> Option<Vec<(u64, Arc<ReplicatedBatch>)>> {
+        self.entries.apply(|log| {
+            let first = log
+                .entries
+                .front()
+                .map(|(sequence, _)| *sequence)
+                .unwrap_or(log.next_sequence);
+            if sequence < first {
+                return None
+            }
+            let skip =
+                usize::try_from(sequence.saturating_sub(first)).unwrap_or(usize::MAX);
+            Some(log.entries.iter().skip(skip).cloned().collect())
+        })
+    }
+}
+
+/// The data source that records all changes into the replication log.
+#[derive(Debug)]
+pub struct ReplicatedStorage<Description>
+where
+    Description: DatabaseDescription,
+{
+    inner: DataSource<Description>,
+    database: ReplicatedDatabase,
+    log: ReplicationLog,
+}
+
+impl<Description> ReplicatedStorage<Description>
+where
+    Description: DatabaseDescription,
+{
+    pub fn new(
+        inner: DataSource<Description>,
+        database: ReplicatedDatabase,
+        log: ReplicationLog,
+    ) -> Self {
+        Self {
+            inner,
+            database,
+            log,
+        }
+    }
+
+    fn record(&self, changes: Vec<ReplicatedChange>) {
+        let height = if self.database == ReplicatedDatabase::OnChain {
+            let blocks = Column::FuelBlocks.id();
+            changes
+                .iter()
+                .filter(|(column, _, value)| *column == blocks && value.is_some())
+                .filter_map(|(_, key, _)| <[u8; 4]>::try_from(key.as_slice()).ok())
+                .map(|key| BlockHeight::new(u32::from_be_bytes(key)))
+                .max()
+        } else {
+            None
+        };
+        self.log.push(ReplicatedBatch {
+            database: self.database,
+            height,
+            changes,
+        })
+    }
+}
+
+impl<Description> KeyValueStore for ReplicatedStorage<Description>
+where
+    Description: DatabaseDescription,
+{
+    type Column = Description::Column;
+
+    fn replace(
+        &self,
+        key: &[u8],
+        column: Self::Column,
+        value: Value,
+    ) -> StorageResult<Option<Value>> {
+        let change = (column.id(), key.to_vec(), Some(value.as_ref().clone()));
+        let prev = self.inner.replace(key, column, value)?;
+        self.record(vec![change]);
+        Ok(prev)
+    }
+
+    fn write(
+        &self,
+        key: &[u8],
+        column: Self::Column,
+        buf: &[u8],
+    ) -> StorageResult<usize> {
+        let written = self.inner.write(key, column, buf)?;
+        self.record(vec![(column.id(), key.to_vec(), Some(buf.to_vec()))]);
+        Ok(written)
+    }
+
+    fn take(&self, key: &[u8], column: Self::Column) -> StorageResult<Option<Value>> {
+        let prev = self.inner.take(key, column)?;
+        self.record(vec![(column.id(), key.to_vec(), None)]);
+        Ok(prev)
+    }
+
+    fn delete(&self, key: &[u8], column: Self::Column) -> StorageResult<()> {
+        self.inner.delete(key, column)?;
+        self.record(vec![(column.id(), key.to_vec(), None)]);
+        Ok(())
+    }
+
+    fn exists(&self, key: &[u8], column: Self::Column) -> StorageResult<bool> {
+        self.inner.exists(key, column)
+    }
+
+    fn size_of_value(
+        &self,
+        key: &[u8],
+        column: Self::Column,
+    ) -> StorageResult<Option<usize>> {
+        self.inner.size_of_value(key, column)
+    }
+
+    fn get(&self, key: &[u8], column: Self::Column) -> StorageResult<Option<Value>> {
+        self.inner.get(key, column)
+    }
+
+    fn read(
+        &self,
+        key: &[u8],
+        column: Self::Column,
+        buf: &mut [u8],
+    ) -> StorageResult<Option<usize>> {
+        self.inner.read(key, column, buf)
+    }
+}
+
+impl<Description> IteratorableStore for ReplicatedStorage<Description>
+where
+    Description: DatabaseDescription,
+{
+    fn iter_all(
+        &self,
+        column: Self::Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<KVItem> {
+        self.inner.iter_all(column, prefix, start, direction)
+    }
+}
+
+impl<Description> BatchOperations for ReplicatedStorage<Description>
+where
+    Description: DatabaseDescription,
+{
+    fn batch_write(
+        &self,
+        entries: &mut dyn Iterator<Item = (Vec<u8>, Self::Column, WriteOperation)>,
+    ) -> StorageResult<()> {
+        let entries = entries.collect::<Vec<_>>();
+        let changes = entries
+            .iter()
+            .map(|(key, column, operation)| {
+                let value = match operation {
+                    WriteOperation::Insert(value) => Some(value.as_ref().clone()),
+                    WriteOperation::Remove => None,
+                };
+                (column.id(), key.clone(), value)
+            })
+            .collect();
+        self.inner.batch_write(&mut entries.into_iter())?;
+        self.record(changes);
+        Ok(())
+    }
+}
+
+impl<Description> TransactableStorage for ReplicatedStorage<Description>
+where
+    Description: DatabaseDescription,
+{
+    fn flush(&self) -> DatabaseResult<()> {
+        self.inner.flush()
+    }
+
+    fn catch_up_with_primary(&self) -> DatabaseResult<()> {
+        self.inner.catch_up_with_primary()
+    }
+
+    fn compact(&self) -> DatabaseResult<()> {
+        self.inner.compact()
+    }
+
+    fn checkpoint(&self, path: &std::path::Path) -> DatabaseResult<()> {
+        self.inner.checkpoint(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn block(height: u32) -> ReplicatedBatch {
+        ReplicatedBatch {
+            database: ReplicatedDatabase::OnChain,
+            height: Some(height.into()),
+            changes: vec![],
+        }
+    }
+
+    fn off_chain() -> ReplicatedBatch {
+        ReplicatedBatch {
+            database: ReplicatedDatabase::OffChain,
+            height: None,
+            changes: vec![],
+        }
+    }
+
+    #[test]
+    fn resume_sequence_starts_after_the_block_of_the_standby() {
+        let log = ReplicationLog::new(10, Some(0u32.into()));
+        log.push(block(1));
+        log.push(off_chain());
+        log.push(block(2));
+
+        assert_eq!(log.resume_sequence(Some(1u32.into())), Ok(1));
+        assert_eq!(log.resume_sequence(Some(2u32.into())), Ok(3));
+        assert_eq!(log.resume_sequence(Some(0u32.into())), Ok(0));
+        assert!(log.resume_sequence(Some(3u32.into())).is_err());
+        assert!(log.resume_sequence(None).is_err());
+    }
+
+    #[test]
+    fn resume_sequence_fails_when_the_block_is_dropped_from_the_log() {
+        let log = ReplicationLog::new(2, None);
+        log.push(block(0));
+        assert_eq!(log.resume_sequence(None), Ok(0));
+        log.push(block(1));
+        log.push(block(2));
+
+        assert!(log.resume_sequence(None).is_err());
+        assert!(log.resume_sequence(Some(0u32.into())).is_err());
+        assert_eq!(log.resume_sequence(Some(1u32.into())), Ok(2));
+        assert_eq!(log.batches_from(0), None);
+        assert_eq!(log.batches_from(2).map(|batches| batches.len()), Some(1));
+    }
+
+    #[test]
+    fn batch_write_records_the_height_of_the_block() {
+        use crate::{
+            database::database_description::on_chain::OnChain,
+            state::in_memory::memory_store::MemoryStore,
+        };
+
+        let log = ReplicationLog::new(10, None);
+        let storage = ReplicatedStorage::<OnChain>::new(
+            Arc::new(MemoryStore::<OnChain>::default()).into(),
+            ReplicatedDatabase::OnChain,
+            log.clone(),
+        );
+
+        let height = BlockHeight::new(7);
+        storage
+            .batch_write(&mut core::iter::once((
+                height.to_bytes().to_vec(),
+                Column::FuelBlocks,
+                WriteOperation::Insert(Arc::new(vec![1])),
+            )))
+            .unwrap();
+        storage.delete(&[2], Column::Metadata).unwrap();
+
+        let batches = log.batches_from(0).unwrap();
+        assert_eq!(batches.len(), 2);
+        assert_eq!(batches[0].1.height, Some(height));
+        assert_eq!(batches[1].1.height, None);
+        assert_eq!(
+            batches[1].1.changes,
+            vec![(Column::Metadata.id(), vec![2], None)]
+        );
+        assert_eq!(
+            storage.get(&height.to_bytes(), Column::FuelBlocks).unwrap(),
+            Some(Arc::new(vec![1]))
+        );
+    }
+}
@@ -90,6 +90,15 @@ impl Drop for ShallowTempDir {
     }
 }
 
+/// The statistic of the column family of the database.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct ColumnStatistic {
+    pub column: String,
+    pub estimated_keys: u64,
+    pub sst_files_size: u64,
+    pub memtables_size: u64,
+}
+
 #[derive(Debug)]
 pub struct RocksDb<Description> {
     db: DB,
@@ -117,42 +126,11 @@ where
         capacity: Option<usize>,
     ) -> DatabaseResult<Self> {
         let path = path.as_ref().join(Description::name());
-        let mut block_opts = BlockBasedOptions::default();
-        // See https://github.com/facebook/rocksdb/blob/a1523efcdf2f0e8133b9a9f6e170a0dad49f928f/include/rocksdb/table.h#L246-L271 for details on what the format versions are/do.
-        block_opts.set_format_version(5);
-
-        if let Some(capacity) = capacity {
-            // Set cache size 1/3 of the capacity as recommended by
-            // https://github.com/facebook/rocksdb/wiki/Setup-Options-and-Basic-Tuning#block-cache-size
-            let block_cache_size = capacity / 3;
-            let cache = Cache::new_lru_cache(block_cache_size);
-            block_opts.set_block_cache(&cache);
-            // "index and filter blocks will be stored in block cache, together with all other data blocks."
-            // See: https://github.com/facebook/rocksdb/wiki/Memory-usage-in-RocksDB#indexes-and-filter-blocks
-            block_opts.set_cache_index_and_filter_blocks(true);
-            // Don't evict L0 filter/index blocks from the cache
-            block_opts.set_pin_l0_filter_and_index_blocks_in_cache(true);
-        } else {
-            block_opts.disable_cache();
-        }
-        block_opts.set_bloom_filter(10.0, true);
-
+        let block_opts = Self::block_opts(capacity);
         let cf_descriptors = columns.clone().into_iter().map(|i| {
             ColumnFamilyDescriptor::new(Self::col_name(i), Self::cf_opts(i, &block_opts))
         });
-
-        let mut opts = Options::default();
-        opts.create_if_missing(true);
-        opts.set_compression_type(DBCompressionType::Lz4);
-        if let Some(capacity) = capacity {
-            // Set cache size 1/3 of the capacity. Another 1/3 is
-            // used by block cache and the last 1 / 3 remains for other purposes:
-            //
-            // https://github.com/facebook/rocksdb/wiki/Setup-Options-and-Basic-Tuning#block-cache-size
-            let row_cache_size = capacity / 3;
-            let cache = Cache::new_lru_cache(row_cache_size);
-            opts.set_row_cache(&cache);
-        }
+        let opts = Self::db_opts(capacity);
 
         let db = match DB::open_cf_descriptors(&opts, &path, cf_descriptors) {
             Err(_) => {
@@ -191,6 +169,182 @@ where
         Ok(rocks_db)
     }
 
+    /// Opens the database as a secondary instance of the primary database at the `path`.
+    /// The secondary instance is read-only and follows the primary instance
+    /// by [`TransactableStorage::catch_up_with_primary`].
+    pub fn default_open_secondary<P: AsRef<Path>>(
+        path: P,
+        secondary_path: P,
+        capacity: Option<usize>,
+    ) -> DatabaseResult<Self> {
+        Self::open_secondary(
+            path,
+            secondary_path,
+            enum_iterator::all::<Description::Column>().collect::<Vec<_>>(),
+            capacity,
+        )
+    }
+
+    pub fn open_secondary<P: AsRef<Path>>(
+        path: P,
+        secondary_path: P,
+        columns: Vec<Description::Column>,
+        capacity: Option<usize>,
+    ) -> DatabaseResult<Self> {
+        let path = path.as_ref().join(Description::name());
+        let secondary_path = secondary_path.as_ref().join(Description::name());
+        let block_opts = Self::block_opts(capacity);
+        let cf_descriptors = columns.into_iter().map(|i| {
+            ColumnFamilyDescriptor::new(Self::col_name(i), Self::cf_opts(i, &block_opts))
+        });
+        let mut opts = Self::db_opts(capacity);
+        // The secondary instance requires access to all files of the primary instance.
+        opts.set_max_open_files(-1);
+
+        let db = DB::open_cf_descriptors_as_secondary(
+            &opts,
+            &path,
+            &secondary_path,
+            cf_descriptors,
+        )
+        .map_err(|e| DatabaseError::Other(e.into()))?;
+        let rocks_db = RocksDb {
+            db,
+            _marker: Default::default(),
+        };
+        Ok(rocks_db)
+    }
+
+    /// Opens the existing database at the `path` with all its column families
+    /// in the read-only mode. The database created by an older version of the node
+    /// may lack column families added later, so only existing ones are opened.
+    pub fn default_open_read_only<P: AsRef<Path>>(
+        path: P,
+        capacity: Option<usize>,
+    ) -> DatabaseResult<Self> {
+        let column_families = Self::column_families(path.as_ref())?;
+        let columns = enum_iterator::all::<Description::Column>()
+            .filter(|column| column_families.contains(&Self::col_name(*column)))
+            .collect::<Vec<_>>();
+        Self::open_read_only(path, columns, capacity)
+    }
+
+    /// Opens the existing database at the `path` in the read-only mode. Unlike other modes,
+    /// it doesn't create missing column families and allows opening only the part of them.
+    /// The info log of RocksDB is written to the temporary directory,
+    /// so the directory of the database stays untouched.
+    pub fn open_read_only<P: AsRef<Path>>(
+        path: P,
+        columns: Vec<Description::Column>,
+        capacity: Option<usize>,
+    ) -> DatabaseResult<Self> {
+        let path = path.as_ref().join(Description::name());
+        let block_opts = Self::block_opts(capacity);
+        let cf_descriptors = columns.into_iter().map(|i| {
+            ColumnFamilyDescriptor::new(Self::col_name(i), Self::cf_opts(i, &block_opts))
+        });
+        let mut opts = Self::db_opts(capacity);
+        opts.set_db_log_dir(std::env::temp_dir().join("fuel-core-read-only"));
+
+        let db = DB::open_cf_descriptors_read_only(&opts, &path, cf_descriptors, false)
+            .map_err(|e| DatabaseError::Other(e.into()))?;
+        let rocks_db = RocksDb {
+            db,
+            _marker: Default::default(),
+        };
+        Ok(rocks_db)
+    }
+
+    /// Returns names of column families of the existing database at the `path`.
+    pub fn column_families<P: AsRef<Path>>(path: P) -> DatabaseResult<Vec<String>> {
+        let path = path.as_ref().join(Description::name());
+        DB::list_cf(&Options::default(), path).map_err(|e| DatabaseError::Other(e.into()))
+    }
+
+    /// Returns the name of the column family of the `column`.
+    pub fn column_family_name(column: Description::Column) -> String {
+        Self::col_name(column)
+    }
+
+    fn block_opts(capacity: Option<usize>) -> BlockBasedOptions {
+        let mut block_opts = BlockBasedOptions::default();
+        // See https://github.com/facebook/rocksdb/blob/a1523efcdf2f0e8133b9a9f6e170a0dad49f928f/include/rocksdb/table.h#L246-L271 for details on what the format versions are/do.
+        block_opts.set_format_version(5);
+
+        if let Some(capacity) = capacity {
+            // Set cache size 1/3 of the capacity as recommended by
+            // https://github.com/facebook/rocksdb/wiki/Setup-Options-and-Basic-Tuning#block-cache-size
+            let block_cache_size = capacity / 3;
+            let cache = Cache::new_lru_cache(block_cache_size);
+            block_opts.set_block_cache(&cache);
+            // "index and filter blocks will be stored in block cache, together with all other data blocks."
+            // See: https://github.com/facebook/rocksdb/wiki/Memory-usage-in-RocksDB#indexes-and-filter-blocks
+            block_opts.set_cache_index_and_filter_blocks(true);
+            // Don't evict L0 filter/index blocks from the cache
+            block_opts.set_pin_l0_filter_and_index_blocks_in_cache(true);
+        } else {
+            block_opts.disable_cache();
+        }
+        block_opts.set_bloom_filter(10.0, true);
+        block_opts
+    }
+
+    fn db_opts(capacity: Option<usize>) -> Options {
+        let mut opts = Options::default();
+        opts.create_if_missing(true);
+        opts.set_compression_type(DBCompressionType::Lz4);
+        if let Some(capacity) = capacity {
+            // Set cache size 1/3 of the capacity. Another 1/3 is
+            // used by block cache and the last 1 / 3 remains for other purposes:
+            //
+            // https://github.com/facebook/rocksdb/wiki/Setup-Options-and-Basic-Tuning#block-cache-size
+            let row_cache_size = capacity / 3;
+            let cache = Cache::new_lru_cache(row_cache_size);
+            opts.set_row_cache(&cache);
+        }
+        opts
+    }
+
+    /// Returns the statistic of each column family of the database.
+    pub fn column_statistics(&self) -> DatabaseResult<Vec<ColumnStatistic>> {
+        enum_iterator::all::<Description::Column>()
+            .map(|column| {
+                let cf = self.cf(column);
+                let property = |name: &str| -> DatabaseResult<u64> {
+                    let value = self
+                        .db
+                        .property_int_value_cf(&cf, name)
+                        .map_err(|e| DatabaseError::Other(e.into()))?;
+                    Ok(value.unwrap_or_default())
+                };
+                Ok(ColumnStatistic {
+                    column: column.name().to_string(),
+                    estimated_keys: property("rocksdb.estimate-num-keys")?,
+                    sst_files_size: property("rocksdb.total-sst-files-size")?,
+                    memtables_size: property("rocksdb.size-all-mem-tables")?,
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the number of keys in the `column` estimated by the RocksDB.
+    pub fn estimated_keys(&self, column: Description::Column) -> DatabaseResult<u64> {
+        let value = self
+            .db
+            .property_int_value_cf(&self.cf(column), "rocksdb.estimate-num-keys")
+            .map_err(|e| DatabaseError::Other(e.into()))?;
+        Ok(value.unwrap_or_default())
+    }
+
+    /// Compacts the whole key range of each column family of the database.
+    pub fn compact(&self) {
+        for column in enum_iterator::all::<Description::Column>() {
+            tracing::info!("Compacting the column {}", column.name());
+            self.db
+                .compact_range_cf(&self.cf(column), None::<&[u8]>, None::<&[u8]>);
+        }
+    }
+
     fn cf(&self, column: Description::Column) -> Arc<BoundColumnFamily> {
         self.db
             .cf_handle(&Self::col_name(column))
@@ -499,6 +653,34 @@ where
             .map_err(|e| anyhow::anyhow!("Unable to flush SST files: {}", e))?;
         Ok(())
     }
+
+    fn catch_up_with_primary(&self) -> DatabaseResult<()> {
+        self.db
+            .try_catch_up_with_primary()
+            .map_err(|e| anyhow::anyhow!("Unable to catch up with primary: {}", e))?;
+        Ok(())
+    }
+
+    fn compact(&self) -> DatabaseResult<()> {
+        RocksDb::compact(self);
+        Ok(())
+    }
+
+    fn checkpoint(&self, path: &Path) -> DatabaseResult<()> {
+        // The layout of the checkpoint matches the layout of the database,
+        // so the checkpoint can be opened as the regular database.
+        let path = path.join(Description::name());
+        rocksdb::checkpoint::Checkpoint::new(&self.db)
+            .and_then(|checkpoint| checkpoint.create_checkpoint(&path))
+            .map_err(|e| {
+                anyhow::anyhow!(
+                    "Unable to create the checkpoint at {}: {}",
+                    path.display(),
+                    e
+                )
+            })?;
+        Ok(())
+    }
 }
 
 /// The `None` means overflow, so there is not following prefix.
@@ -538,6 +720,91 @@ mod tests {
         assert_eq!(db.get(&key, Column::Metadata).unwrap().unwrap(), expected)
     }
 
+    #[test]
+    fn secondary_reads_values_after_catch_up_with_primary() {
+        let key = vec![0xA, 0xB, 0xC];
+        let (primary, primary_dir) = create_db();
+        let secondary_dir = TempDir::new().unwrap();
+        let secondary = RocksDb::<OnChain>::default_open_secondary(
+            primary_dir.path(),
+            secondary_dir.path(),
+            None,
+        )
+        .unwrap();
+
+        let expected = Arc::new(vec![1, 2, 3]);
+        primary
+            .put(&key, Column::Metadata, expected.clone())
+            .unwrap();
+        assert_eq!(secondary.get(&key, Column::Metadata).unwrap(), None);
+
+        secondary.catch_up_with_primary().unwrap();
+        assert_eq!(
+            secondary.get(&key, Column::Metadata).unwrap().unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn read_only_instance_reads_values_and_rejects_writes() {
+        let key = vec![0xA, 0xB, 0xC];
+        let (db, tmp) = create_db();
+        let expected = Arc::new(vec![1, 2, 3]);
+        db.put(&key, Column::Metadata, expected.clone()).unwrap();
+        drop(db);
+
+        let read_only =
+            RocksDb::<OnChain>::default_open_read_only(tmp.path(), None).unwrap();
+
+        assert_eq!(
+            read_only.get(&key, Column::Metadata).unwrap().unwrap(),
+            expected
+        );
+        read_only
+            .put(&key, Column::Metadata, Arc::new(vec![4, 5, 6]))
+            .expect_err("The read-only instance should reject writes");
+        assert_eq!(
+            read_only.get(&key, Column::Metadata).unwrap().unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn read_only_instance_opens_only_existing_column_families() {
+        let key = vec![0xA, 0xB, 0xC];
+        let tmp = TempDir::new().unwrap();
+        let db =
+            RocksDb::<OnChain>::open(tmp.path(), vec![Column::Metadata], None).unwrap();
+        let expected = Arc::new(vec![1, 2, 3]);
+        db.put(&key, Column::Metadata, expected.clone()).unwrap();
+        drop(db);
+
+        let read_only =
+            RocksDb::<OnChain>::default_open_read_only(tmp.path(), None).unwrap();
+
+        assert_eq!(
+            read_only.get(&key, Column::Metadata).unwrap().unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn column_statistics_are_reported_for_all_columns() {
+        let (db, _tmp) = create_db();
+        db.put(&[0xA], Column::Metadata, Arc::new(vec![1, 2, 3]))
+            .unwrap();
+        db.compact();
+
+        let statistics = db.column_statistics().unwrap();
+
+        assert_eq!(statistics.len(), enum_iterator::all::<Column>().count());
+        let metadata = statistics
+            .iter()
+            .find(|statistic| statistic.column == Column::Metadata.name())
+            .unwrap();
+        assert_eq!(metadata.estimated_keys, 1);
+    }
+
     #[test]
     fn put_returns_previous_value() {
         let key = vec![0xA, 0xB, 0xC];
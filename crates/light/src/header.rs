@@ -0,0 +1,208 @@
+use crate::Error;
+use fuel_crypto::{
+    fuel_types::{
+        Address,
+        BlockHeight,
+        Bytes32,
+    },
+    Hasher,
+    Message,
+    Signature,
+};
+use tai64::Tai64;
+
+/// The fuel block header with all fields covered by the block id.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BlockHeader {
+    /// The layer 1 height of messages and events included into the block.
+    pub da_height: u64,
+    /// Number of transactions in this block.
+    pub transactions_count: u64,
+    /// Number of message receipts in this block.
+    pub message_receipt_count: u64,
+    /// Merkle root of transactions.
+    pub transactions_root: Bytes32,
+    /// Merkle root of message receipts in this block.
+    pub message_receipt_root: Bytes32,
+    /// Merkle root of all previous block header hashes.
+    pub prev_root: Bytes32,
+    /// Fuel block height.
+    pub height: BlockHeight,
+    /// The block producer time.
+    pub time: Tai64,
+    /// Hash of the application fields of the header.
+    pub application_hash: Bytes32,
+}
+
+impl BlockHeader {
+    /// Calculates the hash of the application fields of the header.
+    pub fn calculate_application_hash(&self) -> Bytes32 {
+        application_hash(
+            self.da_height,
+            self.transactions_count,
+            self.message_receipt_count,
+            &self.transactions_root,
+            &self.message_receipt_root,
+        )
+    }
+
+    /// Calculates the id of the block from the consensus fields of the header.
+    /// The application fields are covered by the `application_hash`.
+    pub fn id(&self) -> Bytes32 {
+        block_id(
+            &self.prev_root,
+            &self.height,
+            &self.time,
+            &self.application_hash,
+        )
+    }
+}
+
+/// Hashes the application fields of the header.
+pub fn application_hash(
+    da_height: u64,
+    transactions_count: u64,
+    message_receipt_count: u64,
+    transactions_root: &Bytes32,
+    message_receipt_root: &Bytes32,
+) -> Bytes32 {
+    // Order matters and is the same as the spec.
+    let mut hasher = Hasher::default();
+    hasher.input(da_height.to_be_bytes());
+    hasher.input(transactions_count.to_be_bytes());
+    hasher.input(message_receipt_count.to_be_bytes());
+    hasher.input(transactions_root.as_ref());
+    hasher.input(message_receipt_root.as_ref());
+    hasher.digest()
+}
+
+/// Hashes the consensus fields of the header. The result is the id of the block.
+pub fn block_id(
+    prev_root: &Bytes32,
+    height: &BlockHeight,
+    time: &Tai64,
+    application_hash: &Bytes32,
+) -> Bytes32 {
+    // Order matters and is the same as the spec.
+    let mut hasher = Hasher::default();
+    hasher.input(prev_root.as_ref());
+    hasher.input(height.to_bytes());
+    hasher.input(time.0.to_be_bytes());
+    hasher.input(application_hash.as_ref());
+    hasher.digest()
+}
+
+/// Verifies that the header is consistent and returns the id of the block.
+pub fn verify_header(header: &BlockHeader) -> Result<Bytes32, Error> {
+    if header.application_hash != header.calculate_application_hash() {
+        return Err(Error::InvalidApplicationHash)
+    }
+    Ok(header.id())
+}
+
+/// Verifies that the block with the `block_id` is signed by the `signing_key`.
+/// The `signing_key` is the address of the PoA block producer.
+pub fn verify_signature(
+    block_id: &Bytes32,
+    signature: &Signature,
+    signing_key: &Address,
+) -> Result<(), Error> {
+    let message = Message::from_bytes_ref(block_id);
+    let public_key = signature
+        .recover(message)
+        .map_err(|_| Error::InvalidSignature)?;
+    let signer: [u8; Address::LEN] = public_key.hash().into();
+
+    if &Address::from(signer) == signing_key {
+        Ok(())
+    } else {
+        Err(Error::UnexpectedSigner)
+    }
+}
+
+/// Verifies the header sealed by the PoA block producer with the `signing_key`
+/// and returns the id of the block.
+pub fn verify_sealed_header(
+    header: &BlockHeader,
+    signature: &Signature,
+    signing_key: &Address,
+) -> Result<Bytes32, Error> {
+    let block_id = verify_header(header)?;
+    verify_signature(&block_id, signature, signing_key)?;
+    Ok(block_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_crypto::SecretKey;
+
+    fn header() -> BlockHeader {
+        let mut header = BlockHeader {
+            da_height: 5,
+            transactions_count: 2,
+            message_receipt_count: 1,
+            transactions_root: [1; 32].into(),
+            message_receipt_root: [2; 32].into(),
+            prev_root: [3; 32].into(),
+            height: 10u32.into(),
+            time: Tai64(100),
+            application_hash: Default::default(),
+        };
+        header.application_hash = header.calculate_application_hash();
+        header
+    }
+
+    fn seal(header: &BlockHeader, secret_key: &SecretKey) -> Signature {
+        Signature::sign(secret_key, Message::from_bytes_ref(&header.id()))
+    }
+
+    fn producer() -> (SecretKey, Address) {
+        let secret_key = SecretKey::try_from([7; 32].as_slice()).unwrap();
+        let address: [u8; Address::LEN] = secret_key.public_key().hash().into();
+        (secret_key, address.into())
+    }
+
+    #[test]
+    fn verify_sealed_header_accepts_header_signed_by_producer() {
+        let (secret_key, signing_key) = producer();
+        let header = header();
+        let signature = seal(&header, &secret_key);
+
+        let block_id = verify_sealed_header(&header, &signature, &signing_key);
+
+        assert_eq!(block_id, Ok(header.id()));
+    }
+
+    #[test]
+    fn verify_sealed_header_rejects_other_signer() {
+        let (secret_key, _) = producer();
+        let header = header();
+        let signature = seal(&header, &secret_key);
+
+        let result = verify_sealed_header(&header, &signature, &[1; 32].into());
+
+        assert_eq!(result, Err(Error::UnexpectedSigner));
+    }
+
+    #[test]
+    fn verify_sealed_header_rejects_modified_header() {
+        let (secret_key, signing_key) = producer();
+        let mut header = header();
+        let signature = seal(&header, &secret_key);
+        header.height = 11u32.into();
+
+        let result = verify_sealed_header(&header, &signature, &signing_key);
+
+        assert_eq!(result, Err(Error::UnexpectedSigner));
+    }
+
+    #[test]
+    fn verify_header_rejects_modified_application_fields() {
+        let mut header = header();
+        header.transactions_count = 3;
+
+        assert_eq!(verify_header(&header), Err(Error::InvalidApplicationHash));
+    }
+}
@@ -0,0 +1,67 @@
+//! The crate `fuel-core-light` contains the verification of the Fuel blockchain data
+//! for light clients. It verifies block headers sealed by the PoA block producer and
+//! Merkle proofs of inclusion without the full node. The crate is `no_std` and can be
+//! used in browsers and bridges compiled to wasm.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![deny(clippy::arithmetic_side_effects)]
+#![deny(clippy::cast_possible_truncation)]
+#![deny(unused_crate_dependencies)]
+#![deny(missing_docs)]
+#![deny(warnings)]
+
+extern crate alloc;
+
+#[doc(no_inline)]
+pub use fuel_crypto;
+#[doc(no_inline)]
+pub use tai64;
+
+mod header;
+mod proof;
+
+pub use header::{
+    application_hash,
+    block_id,
+    verify_header,
+    verify_sealed_header,
+    verify_signature,
+    BlockHeader,
+};
+pub use proof::{
+    verify_block_proof,
+    verify_merkle_proof,
+    verify_message_proof,
+    MerkleProof,
+};
+
+/// The error of the verification.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The application hash of the header doesn't match the application fields.
+    InvalidApplicationHash,
+    /// The signature is malformed, and the public key can't be recovered from it.
+    InvalidSignature,
+    /// The block is signed by someone other than the block producer.
+    UnexpectedSigner,
+    /// The Merkle proof doesn't prove the inclusion of the leaf.
+    InvalidProof,
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Error::InvalidApplicationHash => {
+                write!(f, "The application hash mismatch")
+            }
+            Error::InvalidSignature => write!(f, "Can't recover the public key"),
+            Error::UnexpectedSigner => {
+                write!(f, "The block is not signed by the block producer")
+            }
+            Error::InvalidProof => write!(f, "The Merkle proof is invalid"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
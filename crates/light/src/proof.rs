@@ -0,0 +1,154 @@
+use crate::{
+    BlockHeader,
+    Error,
+};
+use fuel_crypto::fuel_types::{
+    Bytes32,
+    MessageId,
+};
+use fuel_merkle::common::ProofSet;
+
+/// Type containing merkle proof data.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MerkleProof {
+    /// The proof set.
+    pub proof_set: ProofSet,
+    /// The proof index.
+    pub proof_index: u64,
+}
+
+/// Verifies that the `leaf` is included into the binary Merkle tree with
+/// the `root` and `leaf_count` leaves.
+pub fn verify_merkle_proof<T: AsRef<[u8]>>(
+    root: &Bytes32,
+    leaf: &T,
+    proof: &MerkleProof,
+    leaf_count: u64,
+) -> Result<(), Error> {
+    let verified = fuel_merkle::binary::verify(
+        root,
+        leaf,
+        &proof.proof_set,
+        proof.proof_index,
+        leaf_count,
+    );
+
+    if verified {
+        Ok(())
+    } else {
+        Err(Error::InvalidProof)
+    }
+}
+
+/// Verifies that the message with the `message_id` is included into the block
+/// with the `message_block_header`.
+pub fn verify_message_proof(
+    message_id: &MessageId,
+    message_block_header: &BlockHeader,
+    proof: &MerkleProof,
+) -> Result<(), Error> {
+    verify_merkle_proof(
+        &message_block_header.message_receipt_root,
+        message_id,
+        proof,
+        message_block_header.message_receipt_count,
+    )
+}
+
+/// Verifies that the block with the `block_id` is included into the history of blocks
+/// preceding the `commit_block_header`. The history starts at the block with zero height.
+pub fn verify_block_proof(
+    block_id: &Bytes32,
+    commit_block_header: &BlockHeader,
+    proof: &MerkleProof,
+) -> Result<(), Error> {
+    verify_merkle_proof(
+        &commit_block_header.prev_root,
+        block_id,
+        proof,
+        u64::from(*commit_block_header.height),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+    use fuel_merkle::binary::in_memory::MerkleTree;
+
+    fn tree(leaves: &[Bytes32]) -> MerkleTree {
+        let mut tree = MerkleTree::new();
+        for leaf in leaves {
+            tree.push(leaf.as_ref());
+        }
+        tree
+    }
+
+    fn leaves(count: u8) -> Vec<Bytes32> {
+        (0..count).map(|i| [i; 32].into()).collect()
+    }
+
+    #[test]
+    fn verify_merkle_proof_accepts_included_leaves() {
+        let leaves = leaves(5);
+        let tree = tree(&leaves);
+
+        for (index, leaf) in leaves.iter().enumerate() {
+            let (root, proof_set) = tree.prove(index as u64).unwrap();
+            let proof = MerkleProof {
+                proof_set,
+                proof_index: index as u64,
+            };
+
+            assert_eq!(
+                verify_merkle_proof(&root.into(), leaf, &proof, leaves.len() as u64),
+                Ok(())
+            );
+        }
+    }
+
+    #[test]
+    fn verify_merkle_proof_rejects_other_leaf() {
+        let leaves = leaves(5);
+        let (root, proof_set) = tree(&leaves).prove(1).unwrap();
+        let proof = MerkleProof {
+            proof_set,
+            proof_index: 1,
+        };
+
+        let result = verify_merkle_proof(&root.into(), &leaves[2], &proof, 5);
+
+        assert_eq!(result, Err(Error::InvalidProof));
+    }
+
+    #[test]
+    fn verify_block_proof_uses_history_of_commit_block() {
+        let block_ids = leaves(4);
+        let (root, proof_set) = tree(&block_ids).prove(2).unwrap();
+        let commit_block_header = BlockHeader {
+            da_height: 0,
+            transactions_count: 0,
+            message_receipt_count: 0,
+            transactions_root: Default::default(),
+            message_receipt_root: Default::default(),
+            prev_root: root.into(),
+            height: 4u32.into(),
+            time: tai64::Tai64(0),
+            application_hash: Default::default(),
+        };
+        let proof = MerkleProof {
+            proof_set,
+            proof_index: 2,
+        };
+
+        assert_eq!(
+            verify_block_proof(&block_ids[2], &commit_block_header, &proof),
+            Ok(())
+        );
+        assert_eq!(
+            verify_block_proof(&block_ids[3], &commit_block_header, &proof),
+            Err(Error::InvalidProof)
+        );
+    }
+}
@@ -1,6 +1,7 @@
 use crate::timing_buckets;
 use prometheus_client::{
     metrics::{
+        counter::Counter,
         gauge::Gauge,
         histogram::Histogram,
     },
@@ -16,6 +17,8 @@ pub struct ImporterMetrics {
     pub block_height: Gauge,
     pub latest_block_import_timestamp: Gauge<f64, AtomicU64>,
     pub execute_and_commit_duration: Histogram,
+    pub consensus_parameters_canary_divergences: Counter,
+    pub hooks_skipped_blocks: Counter,
 }
 
 impl Default for ImporterMetrics {
@@ -26,6 +29,8 @@ impl Default for ImporterMetrics {
         let latest_block_import_ms = Gauge::default();
         let execute_and_commit_duration =
             Histogram::new(timing_buckets().iter().cloned());
+        let consensus_parameters_canary_divergences = Counter::default();
+        let hooks_skipped_blocks = Counter::default();
 
         registry.register(
             "importer_block_height",
@@ -45,11 +50,25 @@ impl Default for ImporterMetrics {
             execute_and_commit_duration.clone(),
         );
 
+        registry.register(
+            "importer_consensus_parameters_canary_divergences",
+            "The number of imported blocks where the execution with the candidate consensus parameters diverged from the canonical one",
+            consensus_parameters_canary_divergences.clone(),
+        );
+
+        registry.register(
+            "importer_hooks_skipped_blocks",
+            "The number of imported blocks skipped by the lifecycle hooks that lagged behind the import",
+            hooks_skipped_blocks.clone(),
+        );
+
         Self {
             registry,
             block_height: block_height_gauge,
             latest_block_import_timestamp: latest_block_import_ms,
             execute_and_commit_duration,
+            consensus_parameters_canary_divergences,
+            hooks_skipped_blocks,
         }
     }
 }
@@ -12,6 +12,7 @@ pub mod importer;
 pub mod p2p_metrics;
 pub mod response;
 pub mod services;
+pub mod sync_metrics;
 pub mod txpool_metrics;
 
 // recommended bucket defaults for logging response times
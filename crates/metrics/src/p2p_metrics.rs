@@ -1,15 +1,42 @@
+use crate::timing_buckets;
 use once_cell::race::OnceBox;
 use prometheus_client::{
-    metrics::counter::Counter,
+    encoding::EncodeLabelSet,
+    metrics::{
+        counter::Counter,
+        family::Family,
+        gauge::Gauge,
+        histogram::Histogram,
+    },
     registry::Registry,
 };
 use std::sync::OnceLock;
 
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct BandwidthLabel {
+    /// The protocol of the P2P network.
+    pub protocol: String,
+    /// The direction of the traffic: `inbound` or `outbound`.
+    pub direction: String,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct TopicLabel {
+    /// The gossipsub topic.
+    pub topic: String,
+}
+
 pub struct P2PMetrics {
     pub gossip_sub_registry: OnceBox<Registry>,
     // For descriptions of each Counter, see the `new` function where each Counter/Histogram is initialized
     pub peer_metrics: Registry,
     pub unique_peers: Counter,
+    pub inbound_peers: Gauge,
+    pub outbound_peers: Gauge,
+    pub bandwidth: Family<BandwidthLabel, Counter>,
+    pub mesh_peers: Family<TopicLabel, Gauge>,
+    pub block_propagation_latency: Histogram,
+    pub tx_propagation_latency: Histogram,
 }
 
 impl P2PMetrics {
@@ -22,6 +49,12 @@ impl P2PMetrics {
             gossip_sub_registry: OnceBox::new(),
             peer_metrics,
             unique_peers,
+            inbound_peers: Gauge::default(),
+            outbound_peers: Gauge::default(),
+            bandwidth: Family::default(),
+            mesh_peers: Family::default(),
+            block_propagation_latency: Histogram::new(timing_buckets().iter().cloned()),
+            tx_propagation_latency: Histogram::new(timing_buckets().iter().cloned()),
         };
 
         metrics.peer_metrics.register(
@@ -30,6 +63,42 @@ impl P2PMetrics {
             metrics.unique_peers.clone(),
         );
 
+        metrics.peer_metrics.register(
+            "Inbound_Peers",
+            "The number of connected peers that dialed the node",
+            metrics.inbound_peers.clone(),
+        );
+
+        metrics.peer_metrics.register(
+            "Outbound_Peers",
+            "The number of connected peers dialed by the node",
+            metrics.outbound_peers.clone(),
+        );
+
+        metrics.peer_metrics.register(
+            "Bandwidth_Bytes",
+            "The number of payload bytes exchanged with peers by each protocol",
+            metrics.bandwidth.clone(),
+        );
+
+        metrics.peer_metrics.register(
+            "Mesh_Peers",
+            "The number of peers in the gossipsub mesh of each topic",
+            metrics.mesh_peers.clone(),
+        );
+
+        metrics.peer_metrics.register(
+            "Block_Propagation_Latency_Seconds",
+            "The delay between the first announcement of the block height in the network and the import of the block",
+            metrics.block_propagation_latency.clone(),
+        );
+
+        metrics.peer_metrics.register(
+            "Tx_Propagation_Latency_Seconds",
+            "The delay between the receipt of the gossiped transaction and its forwarding to peers",
+            metrics.tx_propagation_latency.clone(),
+        );
+
         metrics
     }
 }
@@ -3,6 +3,7 @@ use crate::{
     importer::importer_metrics,
     p2p_metrics::p2p_metrics,
     services::services_metrics,
+    sync_metrics::sync_metrics,
     txpool_metrics::txpool_metrics,
 };
 use axum::{
@@ -50,6 +51,10 @@ pub fn encode_metrics_response() -> impl IntoResponse {
         return error_body()
     }
 
+    if encode(&mut encoded, &sync_metrics().registry).is_err() {
+        return error_body()
+    }
+
     Response::builder()
         .status(200)
         .body(Body::from(encoded))
@@ -0,0 +1,56 @@
+use prometheus_client::{
+    metrics::{
+        counter::Counter,
+        gauge::Gauge,
+    },
+    registry::Registry,
+};
+use std::sync::OnceLock;
+
+pub struct SyncMetrics {
+    pub registry: Registry,
+    pub in_flight_blocks: Gauge,
+    pub in_flight_bytes: Gauge,
+    pub budget_waits: Counter,
+}
+
+impl Default for SyncMetrics {
+    fn default() -> Self {
+        let mut registry = Registry::default();
+
+        let in_flight_blocks = Gauge::default();
+        let in_flight_bytes = Gauge::default();
+        let budget_waits = Counter::default();
+
+        registry.register(
+            "sync_in_flight_blocks",
+            "The number of blocks being downloaded or waiting for the execution",
+            in_flight_blocks.clone(),
+        );
+
+        registry.register(
+            "sync_in_flight_bytes",
+            "The size of blocks being downloaded or waiting for the execution",
+            in_flight_bytes.clone(),
+        );
+
+        registry.register(
+            "sync_in_flight_budget_waits",
+            "The number of times the download of blocks waited for the in-flight budget",
+            budget_waits.clone(),
+        );
+
+        Self {
+            registry,
+            in_flight_blocks,
+            in_flight_bytes,
+            budget_waits,
+        }
+    }
+}
+
+static SYNC_METRICS: OnceLock<SyncMetrics> = OnceLock::new();
+
+pub fn sync_metrics() -> &'static SyncMetrics {
+    SYNC_METRICS.get_or_init(SyncMetrics::default)
+}
@@ -1,5 +1,11 @@
 use prometheus_client::{
-    metrics::histogram::Histogram,
+    encoding::EncodeLabelSet,
+    metrics::{
+        counter::Counter,
+        family::Family,
+        gauge::Gauge,
+        histogram::Histogram,
+    },
     registry::Registry,
 };
 use std::{
@@ -7,11 +13,38 @@ use std::{
     sync::OnceLock,
 };
 
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct OriginLabel {
+    /// The kind of the origin of transactions: `api`, `p2p` or `injected`.
+    pub origin: String,
+    /// The event of the transaction: `submitted`, `rejected` or `included`.
+    pub event: String,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct OriginIdentityLabel {
+    /// The identity of the origin of transactions with the hashed API key:
+    /// `api:<hash>`, `api:anonymous`, `p2p:<peer id>` or `injected`.
+    pub origin: String,
+    /// The event of the transaction: `submitted`, `rejected` or `included`.
+    pub event: String,
+}
+
 pub struct TxPoolMetrics {
     // Attaches each Metric to the Registry
     pub registry: Registry,
     pub gas_price_histogram: Histogram,
     pub tx_size_histogram: Histogram,
+    pub paused: Gauge,
+    pub rejected_while_paused: Counter,
+    pub flushed_transactions: Counter,
+    pub dumps: Counter,
+    pub predicate_cache_hits: Counter,
+    pub predicate_cache_misses: Counter,
+    pub saved_signature_verifications: Counter,
+    pub transactions_by_origin: Family<OriginLabel, Counter>,
+    pub transactions_by_origin_identity: Family<OriginIdentityLabel, Counter>,
+    pub active_subscriptions: Gauge,
 }
 
 impl Default for TxPoolMetrics {
@@ -30,6 +63,16 @@ impl Default for TxPoolMetrics {
             registry,
             gas_price_histogram,
             tx_size_histogram,
+            paused: Gauge::default(),
+            rejected_while_paused: Counter::default(),
+            flushed_transactions: Counter::default(),
+            dumps: Counter::default(),
+            predicate_cache_hits: Counter::default(),
+            predicate_cache_misses: Counter::default(),
+            saved_signature_verifications: Counter::default(),
+            transactions_by_origin: Family::default(),
+            transactions_by_origin_identity: Family::default(),
+            active_subscriptions: Gauge::default(),
         };
 
         metrics.registry.register(
@@ -44,6 +87,66 @@ impl Default for TxPoolMetrics {
             metrics.tx_size_histogram.clone(),
         );
 
+        metrics.registry.register(
+            "Tx_Pool_Paused",
+            "Set to 1 while the insertion of new transactions into the pool is paused",
+            metrics.paused.clone(),
+        );
+
+        metrics.registry.register(
+            "Tx_Pool_Rejected_While_Paused",
+            "The number of transactions rejected because the pool was paused",
+            metrics.rejected_while_paused.clone(),
+        );
+
+        metrics.registry.register(
+            "Tx_Pool_Flushed_Transactions",
+            "The number of transactions removed from the pool by the flush",
+            metrics.flushed_transactions.clone(),
+        );
+
+        metrics.registry.register(
+            "Tx_Pool_Dumps",
+            "The number of dumps of the pool state",
+            metrics.dumps.clone(),
+        );
+
+        metrics.registry.register(
+            "Tx_Pool_Predicate_Cache_Hits",
+            "The number of transactions whose predicates were not re-verified thanks to the cache",
+            metrics.predicate_cache_hits.clone(),
+        );
+
+        metrics.registry.register(
+            "Tx_Pool_Predicate_Cache_Misses",
+            "The number of transactions with predicates verified by the VM",
+            metrics.predicate_cache_misses.clone(),
+        );
+
+        metrics.registry.register(
+            "Tx_Pool_Saved_Signature_Verifications",
+            "The number of duplicate transactions whose signatures were not re-verified thanks to the cache",
+            metrics.saved_signature_verifications.clone(),
+        );
+
+        metrics.registry.register(
+            "Tx_Pool_Transactions_By_Origin",
+            "The number of submitted, rejected and included transactions by their origin",
+            metrics.transactions_by_origin.clone(),
+        );
+
+        metrics.registry.register(
+            "Tx_Pool_Transactions_By_Origin_Identity",
+            "The number of submitted, rejected and included transactions by the identity of their origin tracked by the pool",
+            metrics.transactions_by_origin_identity.clone(),
+        );
+
+        metrics.registry.register(
+            "Tx_Pool_Active_Subscriptions",
+            "The number of active transaction status subscriptions",
+            metrics.active_subscriptions.clone(),
+        );
+
         metrics
     }
 }
@@ -1,16 +1,16 @@
+use crate::signer::BlockSigner;
 use fuel_core_types::{
-    blockchain::primitives::SecretKeyWrapper,
     fuel_asm::Word,
     fuel_tx::ConsensusParameters,
-    secrecy::Secret,
 };
+use std::sync::Arc;
 use tokio::time::Duration;
 
 #[derive(Debug, Clone)]
 pub struct Config {
     pub trigger: Trigger,
     pub block_gas_limit: Word,
-    pub signing_key: Option<Secret<SecretKeyWrapper>>,
+    pub signer: Option<Arc<dyn BlockSigner>>,
     pub metrics: bool,
     pub consensus_params: ConsensusParameters,
     pub min_connected_reserved_peers: usize,
@@ -22,7 +22,7 @@ impl Default for Config {
         Config {
             trigger: Trigger::default(),
             block_gas_limit: 0,
-            signing_key: None,
+            signer: None,
             metrics: false,
             consensus_params: ConsensusParameters::default(),
             min_connected_reserved_peers: 0,
@@ -42,4 +42,11 @@ pub enum Trigger {
     Never,
     /// A new block is produced periodically. Used to simulate consensus block delay.
     Interval { block_time: Duration },
+    /// A new block is produced when transactions are available, but not earlier than
+    /// `min_block_time` after the previous block. An empty block is produced if no
+    /// blocks were produced during `max_block_time`.
+    Hybrid {
+        min_block_time: Duration,
+        max_block_time: Duration,
+    },
 }
@@ -13,6 +13,7 @@ mod service_test;
 pub mod config;
 pub mod ports;
 pub mod service;
+pub mod signer;
 pub mod verifier;
 
 pub use config::{
@@ -10,6 +10,7 @@ use crate::{
         TransactionPool,
         TransactionsSource,
     },
+    signer::BlockSigner,
     sync::{
         SyncState,
         SyncTask,
@@ -32,26 +33,15 @@ use fuel_core_services::{
 use fuel_core_storage::transactional::StorageTransaction;
 use fuel_core_types::{
     blockchain::{
-        block::Block,
-        consensus::{
-            poa::PoAConsensus,
-            Consensus,
-        },
         header::BlockHeader,
-        primitives::SecretKeyWrapper,
         SealedBlock,
     },
     fuel_asm::Word,
-    fuel_crypto::Signature,
     fuel_tx::{
         Transaction,
         TxId,
     },
     fuel_types::BlockHeight,
-    secrecy::{
-        ExposeSecret,
-        Secret,
-    },
     services::{
         block_importer::ImportResult,
         executor::{
@@ -63,7 +53,7 @@ use fuel_core_types::{
     tai64::Tai64,
 };
 use std::{
-    ops::Deref,
+    sync::Arc,
     time::Duration,
 };
 use tokio::{
@@ -97,6 +87,17 @@ impl SharedState {
             .await?;
         receiver.await?
     }
+
+    /// Replaces the trigger of the block production. The next block is scheduled
+    /// according to the new trigger relatively to the previous block.
+    pub async fn set_trigger(&self, trigger: Trigger) -> anyhow::Result<()> {
+        let (sender, receiver) = oneshot::channel();
+
+        self.request_sender
+            .send(Request::SetTrigger((trigger, sender)))
+            .await?;
+        receiver.await?
+    }
 }
 
 pub enum Mode {
@@ -116,6 +117,8 @@ enum Request {
     /// Manually produces the next blocks with `Tai64` block timestamp.
     /// The block timestamp should be higher than previous one.
     ManualBlocks((ManualProduction, oneshot::Sender<anyhow::Result<()>>)),
+    /// Changes the trigger of the block production.
+    SetTrigger((Trigger, oneshot::Sender<anyhow::Result<()>>)),
 }
 
 impl core::fmt::Debug for Request {
@@ -131,7 +134,7 @@ pub(crate) enum RequestType {
 
 pub struct MainTask<T, B, I> {
     block_gas_limit: Word,
-    signing_key: Option<Secret<SecretKeyWrapper>>,
+    signer: Option<Arc<dyn BlockSigner>>,
     block_producer: B,
     block_importer: I,
     txpool: T,
@@ -170,7 +173,7 @@ where
 
         let Config {
             block_gas_limit,
-            signing_key,
+            signer,
             min_connected_reserved_peers,
             time_until_synced,
             trigger,
@@ -189,7 +192,7 @@ where
 
         Self {
             block_gas_limit,
-            signing_key,
+            signer,
             txpool,
             block_producer,
             block_importer,
@@ -225,7 +228,7 @@ where
     fn next_time(&self, request_type: RequestType) -> anyhow::Result<Tai64> {
         match request_type {
             RequestType::Manual => match self.trigger {
-                Trigger::Never | Trigger::Instant => {
+                Trigger::Never | Trigger::Instant | Trigger::Hybrid { .. } => {
                     let duration = self.last_block_created.elapsed();
                     increase_time(self.last_timestamp, duration)
                 }
@@ -316,10 +319,14 @@ where
         request_type: RequestType,
     ) -> anyhow::Result<()> {
         let last_block_created = Instant::now();
-        // verify signing key is set
-        if self.signing_key.is_none() {
+        // verify signer is set
+        let Some(signer) = self
+            .signer
+            .clone()
+            .filter(|signer| signer.is_available(&height))
+        else {
             return Err(anyhow!("unable to produce blocks without a consensus key"))
-        }
+        };
 
         if self.last_timestamp > block_time {
             return Err(anyhow!("The block timestamp should monotonically increase"))
@@ -331,6 +338,7 @@ where
                 block,
                 skipped_transactions,
                 tx_status,
+                state_changes,
             },
             db_transaction,
         ) = self
@@ -347,10 +355,10 @@ where
             );
             tx_ids_to_remove.push(tx_id);
         }
-        self.txpool.remove_txs(tx_ids_to_remove);
 
-        // Sign the block and seal it
-        let seal = seal_block(&self.signing_key, &block)?;
+        // Sign the block and seal it. The block is dropped if the signer fails,
+        // so the pool keeps its transactions until the block is committed.
+        let seal = signer.seal_block(&block).await?;
         let block = SealedBlock {
             entity: block,
             consensus: seal,
@@ -358,10 +366,14 @@ where
         // Import the sealed block
         self.block_importer
             .commit_result(Uncommitted::new(
-                ImportResult::new_from_local(block, tx_status),
+                ImportResult {
+                    state_changes,
+                    ..ImportResult::new_from_local(block, tx_status)
+                },
                 db_transaction,
             ))
             .await?;
+        self.txpool.remove_txs(tx_ids_to_remove);
 
         // Update last block time
         self.last_height = height;
@@ -385,6 +397,14 @@ where
                     .set_deadline(deadline, OnConflict::Overwrite)
                     .await;
             }
+            // The empty block is produced after `max_block_time` unless new transactions
+            // arrive earlier.
+            (Trigger::Hybrid { max_block_time, .. }, _) => {
+                let deadline = last_block_created.checked_add(max_block_time).expect("It is impossible to overflow except in the case where we don't want to produce a block.");
+                self.timer
+                    .set_deadline(deadline, OnConflict::Overwrite)
+                    .await;
+            }
         }
 
         Ok(())
@@ -400,22 +420,77 @@ where
                 }
                 Ok(())
             }
+            Trigger::Hybrid { min_block_time, .. } => {
+                if self.txpool.pending_number() > 0 {
+                    let deadline = self.last_block_created.checked_add(min_block_time).expect("It is impossible to overflow except in the case where we don't want to produce a block.");
+                    if deadline <= Instant::now() {
+                        self.produce_next_block().await?;
+                    } else {
+                        self.timer.set_deadline(deadline, OnConflict::Min).await;
+                    }
+                }
+                Ok(())
+            }
             Trigger::Never | Trigger::Interval { .. } => Ok(()),
         }
     }
 
     async fn on_timer(&mut self, _at: Instant) -> anyhow::Result<()> {
         match self.trigger {
-            Trigger::Instant | Trigger::Never => {
-                unreachable!("Timer is never set in this mode");
-            }
+            // The timer is never set in these modes, but the event could be emitted
+            // before the trigger was changed.
+            Trigger::Instant | Trigger::Never => Ok(()),
             // In the Interval mode the timer expires only when a new block should be created.
             Trigger::Interval { .. } => {
                 self.produce_next_block().await?;
                 Ok(())
             }
+            Trigger::Hybrid {
+                min_block_time,
+                max_block_time,
+            } => {
+                let elapsed = self.last_block_created.elapsed();
+                let has_transactions = self.txpool.pending_number() > 0;
+                if elapsed >= max_block_time
+                    || (has_transactions && elapsed >= min_block_time)
+                {
+                    self.produce_next_block().await?;
+                } else {
+                    let deadline = self.last_block_created.checked_add(max_block_time).expect("It is impossible to overflow except in the case where we don't want to produce a block.");
+                    self.timer
+                        .set_deadline(deadline, OnConflict::Overwrite)
+                        .await;
+                }
+                Ok(())
+            }
         }
     }
+
+    async fn set_trigger(&mut self, trigger: Trigger) -> anyhow::Result<()> {
+        self.trigger = trigger;
+        self.timer.clear().await;
+        match trigger {
+            Trigger::Never => {}
+            Trigger::Interval { block_time } => {
+                let deadline = self.last_block_created.checked_add(block_time).expect("It is impossible to overflow except in the case where we don't want to produce a block.");
+                self.timer
+                    .set_deadline(deadline, OnConflict::Overwrite)
+                    .await;
+            }
+            Trigger::Hybrid { max_block_time, .. } => {
+                let deadline = self.last_block_created.checked_add(max_block_time).expect("It is impossible to overflow except in the case where we don't want to produce a block.");
+                self.timer
+                    .set_deadline(deadline, OnConflict::Overwrite)
+                    .await;
+                // Transactions that arrived before the change shouldn't wait for the next one.
+                self.on_txpool_event().await?;
+            }
+            Trigger::Instant => {
+                self.on_txpool_event().await?;
+            }
+        }
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait]
@@ -447,6 +522,11 @@ where
                     .set_timeout(block_time, OnConflict::Overwrite)
                     .await;
             }
+            Trigger::Hybrid { max_block_time, .. } => {
+                self.timer
+                    .set_timeout(max_block_time, OnConflict::Overwrite)
+                    .await;
+            }
         };
 
         Ok(self)
@@ -494,6 +574,10 @@ where
                             let result = self.produce_manual_blocks(block).await;
                             let _ = response.send(result);
                         }
+                        Request::SetTrigger((trigger, response)) => {
+                            let result = self.set_trigger(trigger).await;
+                            let _ = response.send(result);
+                        }
                     }
                     should_continue = true;
                 } else {
@@ -553,25 +637,6 @@ where
     ))
 }
 
-fn seal_block(
-    signing_key: &Option<Secret<SecretKeyWrapper>>,
-    block: &Block,
-) -> anyhow::Result<Consensus> {
-    if let Some(key) = signing_key {
-        let block_hash = block.id();
-        let message = block_hash.into_message();
-
-        // The length of the secret is checked
-        let signing_key = key.expose_secret().deref();
-
-        let poa_signature = Signature::sign(signing_key, &message);
-        let seal = Consensus::PoA(PoAConsensus::new(poa_signature));
-        Ok(seal)
-    } else {
-        Err(anyhow!("no PoA signing key configured"))
-    }
-}
-
 fn increase_time(time: Tai64, duration: Duration) -> anyhow::Result<Tai64> {
     let timestamp = time.0;
     let timestamp = timestamp
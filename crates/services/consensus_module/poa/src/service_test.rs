@@ -9,6 +9,10 @@ use crate::{
         MockTransactionPool,
     },
     service::MainTask,
+    signer::{
+        BlockSigner,
+        LocalSigner,
+    },
     Config,
     Service,
     Trigger,
@@ -25,7 +29,6 @@ use fuel_core_storage::{
 use fuel_core_types::{
     blockchain::{
         header::BlockHeader,
-        primitives::SecretKeyWrapper,
         SealedBlock,
     },
     fuel_crypto::SecretKey,
@@ -129,6 +132,7 @@ impl TestContextBuilder {
                             block: Default::default(),
                             skipped_transactions: Default::default(),
                             tx_status: Default::default(),
+                            state_changes: Default::default(),
                         },
                         StorageTransaction::new(EmptyStorage),
                     ))
@@ -287,6 +291,7 @@ async fn remove_skipped_transactions() {
                         })
                         .collect(),
                     tx_status: Default::default(),
+                    state_changes: Default::default(),
                 },
                 StorageTransaction::new(EmptyStorage),
             ))
@@ -327,7 +332,7 @@ async fn remove_skipped_transactions() {
     let config = Config {
         trigger: Trigger::Instant,
         block_gas_limit: 1000000,
-        signing_key: Some(Secret::new(secret_key.into())),
+        signer: Some(Arc::new(LocalSigner::new(Secret::new(secret_key.into())))),
         metrics: false,
         ..Default::default()
     };
@@ -375,7 +380,7 @@ async fn does_not_produce_when_txpool_empty_in_instant_mode() {
     let config = Config {
         trigger: Trigger::Instant,
         block_gas_limit: 1000000,
-        signing_key: Some(Secret::new(secret_key.into())),
+        signer: Some(Arc::new(LocalSigner::new(Secret::new(secret_key.into())))),
         metrics: false,
         ..Default::default()
     };
@@ -395,8 +400,78 @@ async fn does_not_produce_when_txpool_empty_in_instant_mode() {
     task.on_txpool_event().await.unwrap();
 }
 
-fn test_signing_key() -> Secret<SecretKeyWrapper> {
+#[derive(Debug)]
+struct FailingSigner;
+
+#[async_trait::async_trait]
+impl BlockSigner for FailingSigner {
+    fn is_available(&self, _: &BlockHeight) -> bool {
+        true
+    }
+
+    async fn seal_block(
+        &self,
+        _: &fuel_core_types::blockchain::block::Block,
+    ) -> anyhow::Result<fuel_core_types::blockchain::consensus::Consensus> {
+        Err(anyhow::anyhow!("The signing service is unavailable"))
+    }
+}
+
+#[tokio::test]
+async fn keeps_skipped_transactions_if_sealing_fails() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let skipped_tx = make_tx(&mut rng);
+
+    let mut block_producer = MockBlockProducer::default();
+    block_producer
+        .expect_produce_and_execute_block()
+        .times(1)
+        .returning(move |_, _, _, _| {
+            Ok(UncommittedResult::new(
+                ExecutionResult {
+                    block: Default::default(),
+                    skipped_transactions: vec![(
+                        skipped_tx.id(&ChainId::default()),
+                        ExecutorError::OutputAlreadyExists,
+                    )],
+                    tx_status: Default::default(),
+                    state_changes: Default::default(),
+                },
+                StorageTransaction::new(EmptyStorage),
+            ))
+        });
+
+    let mut block_importer = MockBlockImporter::default();
+    block_importer.expect_commit_result().never();
+    block_importer
+        .expect_block_stream()
+        .returning(|| Box::pin(tokio_stream::pending()));
+
+    let mut txpool = MockTransactionPool::no_tx_updates();
+    txpool.expect_remove_txs().never();
+
+    let config = Config {
+        trigger: Trigger::Instant,
+        block_gas_limit: 1000000,
+        signer: Some(Arc::new(FailingSigner)),
+        metrics: false,
+        ..Default::default()
+    };
+
+    let mut task = MainTask::new(
+        &BlockHeader::new_block(BlockHeight::from(1u32), Tai64::now()),
+        config,
+        txpool,
+        block_producer,
+        block_importer,
+        generate_p2p_port(),
+    );
+
+    assert!(task.produce_next_block().await.is_err());
+}
+
+fn test_signer() -> Arc<dyn BlockSigner> {
     let mut rng = StdRng::seed_from_u64(0);
     let secret_key = SecretKey::random(&mut rng);
-    Secret::new(secret_key.into())
+    Arc::new(LocalSigner::new(Secret::new(secret_key.into())))
 }
@@ -54,7 +54,7 @@ async fn can_manually_produce_block(
     ctx_builder.with_config(Config {
         trigger,
         block_gas_limit: 100_000,
-        signing_key: Some(test_signing_key()),
+        signer: Some(test_signer()),
         metrics: false,
         consensus_params: consensus_params.clone(),
         ..Default::default()
@@ -92,6 +92,7 @@ async fn can_manually_produce_block(
                     block,
                     skipped_transactions: Default::default(),
                     tx_status: Default::default(),
+                    state_changes: Default::default(),
                 },
                 StorageTransaction::new(EmptyStorage),
             ))
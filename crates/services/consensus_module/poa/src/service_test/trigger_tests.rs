@@ -8,12 +8,16 @@ async fn clean_startup_shutdown_each_trigger() -> anyhow::Result<()> {
         Trigger::Interval {
             block_time: Duration::new(1, 0),
         },
+        Trigger::Hybrid {
+            min_block_time: Duration::new(1, 0),
+            max_block_time: Duration::new(5, 0),
+        },
     ] {
         let mut ctx_builder = TestContextBuilder::new();
         ctx_builder.with_config(Config {
             trigger,
             block_gas_limit: 100_000,
-            signing_key: Some(test_signing_key()),
+            signer: Some(test_signer()),
             metrics: false,
             ..Default::default()
         });
@@ -34,7 +38,7 @@ async fn never_trigger_never_produces_blocks() {
     ctx_builder.with_config(Config {
         trigger: Trigger::Never,
         block_gas_limit: 100_000,
-        signing_key: Some(test_signing_key()),
+        signer: Some(test_signer()),
         metrics: false,
         consensus_params: consensus_params.clone(),
         ..Default::default()
@@ -121,7 +125,7 @@ async fn instant_trigger_produces_block_instantly() {
     let mut ctx = DefaultContext::new(Config {
         trigger: Trigger::Instant,
         block_gas_limit: 100_000,
-        signing_key: Some(test_signing_key()),
+        signer: Some(test_signer()),
         metrics: false,
         ..Default::default()
     });
@@ -141,7 +145,7 @@ async fn interval_trigger_produces_blocks_periodically() -> anyhow::Result<()> {
             block_time: Duration::new(2, 0),
         },
         block_gas_limit: 100_000,
-        signing_key: Some(test_signing_key()),
+        signer: Some(test_signer()),
         metrics: false,
         ..Default::default()
     });
@@ -207,7 +211,7 @@ async fn interval_trigger_doesnt_react_to_full_txpool() -> anyhow::Result<()> {
             block_time: Duration::new(2, 0),
         },
         block_gas_limit: 100_000,
-        signing_key: Some(test_signing_key()),
+        signer: Some(test_signer()),
         metrics: false,
         ..Default::default()
     });
@@ -244,3 +248,138 @@ async fn interval_trigger_doesnt_react_to_full_txpool() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(start_paused = true)]
+async fn hybrid_trigger_produces_blocks_not_earlier_than_min_block_time(
+) -> anyhow::Result<()> {
+    let mut ctx = DefaultContext::new(Config {
+        trigger: Trigger::Hybrid {
+            min_block_time: Duration::new(2, 0),
+            max_block_time: Duration::new(10, 0),
+        },
+        block_gas_limit: 100_000,
+        signer: Some(test_signer()),
+        metrics: false,
+        ..Default::default()
+    });
+    ctx.status_sender.send_replace(Some(TxId::zeroed()));
+
+    // Make sure blocks are not produced before the min block time has elapsed
+    time::sleep(Duration::new(1, 0)).await;
+    assert!(matches!(
+        ctx.block_import.try_recv(),
+        Err(broadcast::error::TryRecvError::Empty)
+    ));
+
+    // Pass time until the min block time is elapsed
+    time::sleep(Duration::new(2, 0)).await;
+    assert!(ctx.block_import.try_recv().is_ok());
+
+    // Emulate tx status update right after the block
+    ctx.status_sender.send_replace(Some(TxId::zeroed()));
+    time::sleep(Duration::from_millis(1)).await;
+    assert!(matches!(
+        ctx.block_import.try_recv(),
+        Err(broadcast::error::TryRecvError::Empty)
+    ));
+
+    // The next block is produced after the min block time instead of the max block time
+    time::sleep(Duration::new(2, 0)).await;
+    assert!(ctx.block_import.try_recv().is_ok());
+    assert!(matches!(
+        ctx.block_import.try_recv(),
+        Err(broadcast::error::TryRecvError::Empty)
+    ));
+
+    // Stop
+    ctx.test_ctx.service.stop_and_await().await?;
+
+    Ok(())
+}
+
+#[tokio::test(start_paused = true)]
+async fn hybrid_trigger_produces_empty_blocks_after_max_block_time() -> anyhow::Result<()>
+{
+    let mut ctx = DefaultContext::new(Config {
+        trigger: Trigger::Hybrid {
+            min_block_time: Duration::new(1, 0),
+            max_block_time: Duration::new(5, 0),
+        },
+        block_gas_limit: 100_000,
+        signer: Some(test_signer()),
+        metrics: false,
+        ..Default::default()
+    });
+    ctx.txs.lock().unwrap().clear();
+
+    // Make sure blocks are not produced without transactions before the max block time
+    time::sleep(Duration::new(4, 0)).await;
+    assert!(matches!(
+        ctx.block_import.try_recv(),
+        Err(broadcast::error::TryRecvError::Empty)
+    ));
+
+    // Make sure one empty block is produced per max block time
+    for _ in 0..3 {
+        time::sleep(Duration::new(5, 0)).await;
+        assert!(ctx.block_import.try_recv().is_ok());
+        assert!(matches!(
+            ctx.block_import.try_recv(),
+            Err(broadcast::error::TryRecvError::Empty)
+        ));
+    }
+
+    // Stop
+    ctx.test_ctx.service.stop_and_await().await?;
+
+    Ok(())
+}
+
+#[tokio::test(start_paused = true)]
+async fn trigger_can_be_changed_at_runtime() -> anyhow::Result<()> {
+    let mut ctx = DefaultContext::new(Config {
+        trigger: Trigger::Never,
+        block_gas_limit: 100_000,
+        signer: Some(test_signer()),
+        metrics: false,
+        ..Default::default()
+    });
+    let shared = ctx.test_ctx.service.shared.clone();
+
+    // Make sure no blocks are produced in the never mode
+    time::sleep(Duration::new(3, 0)).await;
+    assert!(matches!(
+        ctx.block_import.try_recv(),
+        Err(broadcast::error::TryRecvError::Empty)
+    ));
+
+    // The overdue block of the interval mode is produced right after the change
+    shared
+        .set_trigger(Trigger::Interval {
+            block_time: Duration::new(2, 0),
+        })
+        .await?;
+    time::sleep(Duration::from_millis(1)).await;
+    assert!(ctx.block_import.try_recv().is_ok());
+
+    time::sleep(Duration::new(2, 0)).await;
+    assert!(ctx.block_import.try_recv().is_ok());
+
+    // The pending transactions are included right after the change to the instant mode
+    shared.set_trigger(Trigger::Instant).await?;
+    time::sleep(Duration::from_millis(1)).await;
+    assert!(ctx.block_import.try_recv().is_ok());
+
+    // Make sure blocks are not produced after disabling the production
+    shared.set_trigger(Trigger::Never).await?;
+    time::sleep(Duration::new(10, 0)).await;
+    assert!(matches!(
+        ctx.block_import.try_recv(),
+        Err(broadcast::error::TryRecvError::Empty)
+    ));
+
+    // Stop
+    ctx.test_ctx.service.stop_and_await().await?;
+
+    Ok(())
+}
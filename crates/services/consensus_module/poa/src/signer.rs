@@ -0,0 +1,189 @@
+use anyhow::anyhow;
+use async_trait::async_trait;
+use fuel_core_types::{
+    blockchain::{
+        block::Block,
+        consensus::{
+            poa::PoAConsensus,
+            Consensus,
+        },
+        primitives::SecretKeyWrapper,
+    },
+    fuel_crypto::Signature,
+    fuel_types::BlockHeight,
+    secrecy::{
+        ExposeSecret,
+        Secret,
+    },
+};
+use std::{
+    collections::BTreeMap,
+    fmt::Debug,
+    ops::Deref,
+    sync::Arc,
+};
+
+/// The signer of the produced blocks.
+#[async_trait]
+pub trait BlockSigner: Debug + Send + Sync {
+    /// Returns `true` if the signer is able to seal the block at the `height`.
+    fn is_available(&self, height: &BlockHeight) -> bool;
+
+    /// Signs the block and returns the consensus seal for it.
+    async fn seal_block(&self, block: &Block) -> anyhow::Result<Consensus>;
+}
+
+/// The signer that uses the secret key stored in the memory of the node.
+#[derive(Debug, Clone)]
+pub struct LocalSigner {
+    key: Secret<SecretKeyWrapper>,
+}
+
+impl LocalSigner {
+    pub fn new(key: Secret<SecretKeyWrapper>) -> Self {
+        Self { key }
+    }
+}
+
+#[async_trait]
+impl BlockSigner for LocalSigner {
+    fn is_available(&self, _: &BlockHeight) -> bool {
+        true
+    }
+
+    async fn seal_block(&self, block: &Block) -> anyhow::Result<Consensus> {
+        let block_hash = block.id();
+        let message = block_hash.into_message();
+
+        // The length of the secret is checked
+        let signing_key = self.key.expose_secret().deref();
+
+        let poa_signature = Signature::sign(signing_key, &message);
+        Ok(Consensus::PoA(PoAConsensus::new(poa_signature)))
+    }
+}
+
+/// The signer that switches between signers according to the schedule.
+/// Each signer is used from its activation height until the activation height
+/// of the next signer.
+///
+/// The schedule should match the `PoAKeyRotation` consensus configuration
+/// of the network, otherwise other nodes reject produced blocks.
+#[derive(Debug, Clone, Default)]
+pub struct KeyRotation {
+    schedule: BTreeMap<BlockHeight, Arc<dyn BlockSigner>>,
+}
+
+impl KeyRotation {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Uses the `signer` starting from the `height`.
+    pub fn rotate_at(
+        mut self,
+        height: BlockHeight,
+        signer: Arc<dyn BlockSigner>,
+    ) -> Self {
+        self.schedule.insert(height, signer);
+        self
+    }
+
+    /// Returns the signer for the block at the `height`.
+    pub fn signer(&self, height: &BlockHeight) -> Option<&Arc<dyn BlockSigner>> {
+        self.schedule
+            .range(..=*height)
+            .next_back()
+            .map(|(_, signer)| signer)
+    }
+}
+
+#[async_trait]
+impl BlockSigner for KeyRotation {
+    fn is_available(&self, height: &BlockHeight) -> bool {
+        self.signer(height)
+            .map(|signer| signer.is_available(height))
+            .unwrap_or(false)
+    }
+
+    async fn seal_block(&self, block: &Block) -> anyhow::Result<Consensus> {
+        let height = block.header().height();
+        let signer = self
+            .signer(height)
+            .ok_or_else(|| anyhow!("No signer is scheduled for the height {height}"))?;
+        signer.seal_block(block).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::{
+        blockchain::header::PartialBlockHeader,
+        fuel_crypto::SecretKey,
+        fuel_tx::Input,
+    };
+    use rand::{
+        rngs::StdRng,
+        SeedableRng,
+    };
+
+    fn signer(seed: u64) -> (LocalSigner, SecretKey) {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let secret_key = SecretKey::random(&mut rng);
+        (LocalSigner::new(Secret::new(secret_key.into())), secret_key)
+    }
+
+    fn block_at(height: u32) -> Block {
+        let mut header = PartialBlockHeader::default();
+        header.consensus.height = height.into();
+        Block::new(header, vec![], &[])
+    }
+
+    fn signed_by(consensus: Consensus, block: &Block, key: &SecretKey) -> bool {
+        let Consensus::PoA(poa) = consensus else {
+            return false
+        };
+        let message = block.id().into_message();
+        poa.signature.recover(&message).map_or(false, |k| {
+            Input::owner(&k) == Input::owner(&key.public_key())
+        })
+    }
+
+    #[tokio::test]
+    async fn local_signer_signs_the_block() {
+        let (signer, key) = signer(1);
+        let block = block_at(1);
+
+        let consensus = signer.seal_block(&block).await.unwrap();
+
+        assert!(signed_by(consensus, &block, &key));
+    }
+
+    #[tokio::test]
+    async fn key_rotation_uses_the_signer_for_the_height() {
+        let (first, first_key) = signer(1);
+        let (second, second_key) = signer(2);
+        let rotation = KeyRotation::new()
+            .rotate_at(0u32.into(), Arc::new(first))
+            .rotate_at(10u32.into(), Arc::new(second));
+
+        let before_rotation = block_at(9);
+        let consensus = rotation.seal_block(&before_rotation).await.unwrap();
+        assert!(signed_by(consensus, &before_rotation, &first_key));
+
+        let after_rotation = block_at(10);
+        let consensus = rotation.seal_block(&after_rotation).await.unwrap();
+        assert!(signed_by(consensus, &after_rotation, &second_key));
+    }
+
+    #[tokio::test]
+    async fn key_rotation_is_unavailable_before_the_first_signer() {
+        let (first, _) = signer(1);
+        let rotation = KeyRotation::new().rotate_at(10u32.into(), Arc::new(first));
+
+        assert!(!rotation.is_available(&5u32.into()));
+        assert!(rotation.seal_block(&block_at(5)).await.is_err());
+        assert!(rotation.is_available(&10u32.into()));
+    }
+}
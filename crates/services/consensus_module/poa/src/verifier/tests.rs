@@ -115,3 +115,67 @@ fn test_verify_genesis_block_fields(input: Input) -> anyhow::Result<()> {
     *b.transactions_mut() = txs;
     verify_block_fields(&d, &b)
 }
+
+#[test]
+fn verify_consensus_uses_the_key_scheduled_for_the_height() {
+    use fuel_core_chain_config::SigningKeyRotation;
+    use fuel_core_types::{
+        fuel_crypto::{
+            SecretKey,
+            Signature,
+        },
+        fuel_tx::Input,
+    };
+    use rand::{
+        rngs::StdRng,
+        SeedableRng,
+    };
+
+    let mut rng = StdRng::seed_from_u64(1);
+    let first = SecretKey::random(&mut rng);
+    let second = SecretKey::random(&mut rng);
+    let consensus_config = ConsensusConfig::PoAKeyRotation {
+        signing_keys: vec![
+            SigningKeyRotation {
+                height: 1u32.into(),
+                signing_key: Input::owner(&first.public_key()),
+            },
+            SigningKeyRotation {
+                height: 10u32.into(),
+                signing_key: Input::owner(&second.public_key()),
+            },
+        ],
+    };
+    let header_at = |height: u32| {
+        let mut header = BlockHeader::default();
+        header.set_block_height(height.into());
+        header
+    };
+    let seal = |header: &BlockHeader, key: &SecretKey| {
+        PoAConsensus::new(Signature::sign(key, &header.id().into_message()))
+    };
+
+    let before_rotation = header_at(9);
+    assert!(verify_consensus(
+        &consensus_config,
+        &before_rotation,
+        &seal(&before_rotation, &first)
+    ));
+    assert!(!verify_consensus(
+        &consensus_config,
+        &before_rotation,
+        &seal(&before_rotation, &second)
+    ));
+
+    let after_rotation = header_at(10);
+    assert!(verify_consensus(
+        &consensus_config,
+        &after_rotation,
+        &seal(&after_rotation, &second)
+    ));
+    assert!(!verify_consensus(
+        &consensus_config,
+        &after_rotation,
+        &seal(&after_rotation, &first)
+    ));
+}
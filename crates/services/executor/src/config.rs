@@ -1,16 +1,55 @@
-use fuel_core_types::fuel_tx::{
-    ConsensusParameters,
-    ContractId,
+use crate::ports::PredicateVerificationCache;
+use fuel_core_chain_config::{
+    consensus_parameters_at,
+    ConsensusParametersUpgrade,
 };
+use fuel_core_types::{
+    fuel_tx::{
+        ConsensusParameters,
+        ContractId,
+    },
+    fuel_types::BlockHeight,
+};
+use std::sync::Arc;
 
 #[derive(Clone, Debug, Default)]
 pub struct Config {
     /// Network-wide common parameters used for validating the chain
     pub consensus_parameters: ConsensusParameters,
+    /// The upgrades of the `consensus_parameters` scheduled by the chain configuration.
+    /// The block is executed with the parameters in effect at its height.
+    pub consensus_parameters_upgrades: Vec<ConsensusParametersUpgrade>,
     /// The `ContractId` of the fee recipient.
     pub coinbase_recipient: ContractId,
     /// Print execution backtraces if transaction execution reverts.
     pub backtrace: bool,
     /// Default mode for utxo_validation
     pub utxo_validation_default: bool,
+    /// Transactions with verified predicates. The executor doesn't run predicates
+    /// of cached transactions again when the utxo validation is enabled.
+    pub predicate_cache: Option<Arc<dyn PredicateVerificationCache>>,
+}
+
+impl Config {
+    /// Returns the consensus parameters in effect at the `height`.
+    pub fn consensus_parameters_at(&self, height: BlockHeight) -> &ConsensusParameters {
+        consensus_parameters_at(
+            &self.consensus_parameters,
+            &self.consensus_parameters_upgrades,
+            height,
+        )
+    }
+
+    /// Returns the configuration with the `consensus_parameters` in effect at the `height`.
+    pub fn at_height(self: &Arc<Self>, height: BlockHeight) -> Arc<Self> {
+        let consensus_parameters = self.consensus_parameters_at(height);
+        if consensus_parameters == &self.consensus_parameters {
+            return self.clone()
+        }
+        Arc::new(Self {
+            consensus_parameters: consensus_parameters.clone(),
+            consensus_parameters_upgrades: vec![],
+            ..self.as_ref().clone()
+        })
+    }
 }
@@ -83,6 +83,7 @@ use fuel_core_types::{
         Transaction,
         TxId,
         TxPointer,
+        UniqueIdentifier,
         UtxoId,
     },
     fuel_types::{
@@ -115,6 +116,8 @@ use fuel_core_types::{
     services::{
         block_producer::Components,
         executor::{
+            ContractStateChange,
+            DryRunBundleStep,
             Error as ExecutorError,
             ExecutionKind,
             ExecutionResult,
@@ -132,6 +135,7 @@ use fuel_core_types::{
 use parking_lot::Mutex as ParkingMutex;
 use std::{
     borrow::Cow,
+    collections::BTreeMap,
     sync::Arc,
 };
 use tracing::{
@@ -192,6 +196,7 @@ where
             relayer: self.relayer_view_provider.latest_view(),
             config: self.config.clone(),
             options,
+            skip_invalid_transactions: false,
         };
         executor.execute_and_commit(block)
     }
@@ -211,6 +216,7 @@ where
             relayer: self.relayer_view_provider.latest_view(),
             config: self.config.clone(),
             options,
+            skip_invalid_transactions: false,
         };
         let mut block_transaction = executor.database.transaction();
         executor.execute_block(block_transaction.as_mut(), block)
@@ -228,6 +234,7 @@ where
             relayer: self.relayer_view_provider.latest_view(),
             config: self.config.clone(),
             options: self.config.as_ref().into(),
+            skip_invalid_transactions: false,
         };
         executor.execute_inner(block)
     }
@@ -248,9 +255,33 @@ where
             relayer: self.relayer_view_provider.latest_view(),
             config: self.config.clone(),
             options,
+            skip_invalid_transactions: false,
         };
         executor.dry_run(component)
     }
+
+    /// Executes transactions of the bundle one after another on the same fork of the state.
+    /// Unlike [`Self::dry_run`], an invalid transaction doesn't fail the whole bundle.
+    /// It is skipped without affecting the state, and the next transactions are executed.
+    pub fn dry_run_bundle(
+        &self,
+        component: Components<Vec<Transaction>>,
+        utxo_validation: Option<bool>,
+    ) -> ExecutorResult<Vec<DryRunBundleStep>> {
+        let utxo_validation =
+            utxo_validation.unwrap_or(self.config.utxo_validation_default);
+
+        let options = ExecutionOptions { utxo_validation };
+
+        let executor = ExecutionInstance {
+            database: self.database_view_provider.latest_view(),
+            relayer: self.relayer_view_provider.latest_view(),
+            config: self.config.clone(),
+            options,
+            skip_invalid_transactions: true,
+        };
+        executor.dry_run_bundle(component)
+    }
 }
 
 /// Data that is generated after executing all transactions.
@@ -262,6 +293,7 @@ pub struct ExecutionData {
     found_mint: bool,
     message_ids: Vec<MessageId>,
     tx_status: Vec<TransactionExecutionStatus>,
+    state_changes: BTreeMap<(ContractId, Bytes32), Option<Bytes32>>,
     pub skipped_transactions: Vec<(TxId, ExecutorError)>,
 }
 
@@ -290,6 +322,8 @@ struct ExecutionInstance<R, D> {
     pub database: D,
     pub config: Arc<Config>,
     pub options: ExecutionOptions,
+    /// Skips invalid transactions during the dry run instead of failing the execution.
+    pub skip_invalid_transactions: bool,
 }
 
 impl<R, D> ExecutionInstance<R, D>
@@ -367,6 +401,56 @@ where
         Ok(tx_status)
         // drop `_temporary_db` without committing to avoid altering state.
     }
+
+    pub fn dry_run_bundle(
+        self,
+        component: Components<Vec<Transaction>>,
+    ) -> ExecutorResult<Vec<DryRunBundleStep>> {
+        let chain_id = self.config.consensus_parameters.chain_id;
+        let tx_ids: Vec<_> = component
+            .transactions_source
+            .iter()
+            .map(|tx| tx.id(&chain_id))
+            .collect();
+        let component = Components {
+            header_to_produce: component.header_to_produce,
+            transactions_source: OnceTransactionsSource::new(
+                component.transactions_source,
+            ),
+            gas_limit: component.gas_limit,
+        };
+
+        let (
+            ExecutionResult {
+                skipped_transactions,
+                tx_status,
+                ..
+            },
+            _temporary_db,
+        ) = self
+            .execute_without_commit(ExecutionTypes::DryRun(component))?
+            .into();
+
+        // Executed and skipped transactions preserve the order of the bundle.
+        let mut executed = tx_status.into_iter().peekable();
+        let mut skipped = skipped_transactions.into_iter().peekable();
+        tx_ids
+            .into_iter()
+            .map(|tx_id| {
+                if let Some(status) = executed.next_if(|status| status.id == tx_id) {
+                    Ok(DryRunBundleStep::Executed(status))
+                } else if let Some((id, reason)) = skipped.next_if(|(id, _)| *id == tx_id)
+                {
+                    Ok(DryRunBundleStep::Skipped { id, reason })
+                } else {
+                    Err(ExecutorError::InvalidTransactionOutcome {
+                        transaction_id: tx_id,
+                    })
+                }
+            })
+            .collect()
+        // drop `_temporary_db` without committing to avoid altering state.
+    }
 }
 
 // TODO: Make this module private after moving unit tests from `fuel-core` here.
@@ -417,7 +501,7 @@ where
 {
     #[tracing::instrument(skip_all)]
     fn execute_inner<TxSource>(
-        self,
+        mut self,
         block: ExecutionBlockWithSource<TxSource>,
     ) -> ExecutorResult<UncommittedResult<StorageTransaction<D>>>
     where
@@ -430,6 +514,15 @@ where
         // a partial header.
         let block = block.map_v(PartialFuelBlock::from);
 
+        // The block is executed with the consensus parameters in effect at its height.
+        let block_height = match &block {
+            ExecutionTypes::DryRun(component) | ExecutionTypes::Production(component) => {
+                component.header_to_produce.consensus.height
+            }
+            ExecutionTypes::Validation(block) => block.header.consensus.height,
+        };
+        self.config = self.config.at_height(block_height);
+
         // Create a new storage transaction.
         let mut block_st_transaction = self.database.transaction();
 
@@ -479,6 +572,7 @@ where
             used_gas,
             message_ids,
             tx_status,
+            state_changes,
             skipped_transactions,
             ..
         } = execution_data;
@@ -504,10 +598,19 @@ where
             }
         }
 
+        let state_changes = state_changes
+            .into_iter()
+            .map(|((contract_id, key), value)| ContractStateChange {
+                contract_id,
+                key,
+                value,
+            })
+            .collect();
         let result = ExecutionResult {
             block,
             skipped_transactions,
             tx_status,
+            state_changes,
         };
 
         // Get the complete fuel block.
@@ -531,6 +634,7 @@ where
             found_mint: false,
             message_ids: Vec::new(),
             tx_status: Vec::new(),
+            state_changes: BTreeMap::new(),
             skipped_transactions: Vec::new(),
         };
         let execution_data = &mut data;
@@ -581,6 +685,10 @@ where
                                 execution_data.skipped_transactions.push((tx_id, err));
                                 Ok(())
                             }
+                            ExecutionKind::DryRun if self.skip_invalid_transactions => {
+                                execution_data.skipped_transactions.push((tx_id, err));
+                                Ok(())
+                            }
                             ExecutionKind::DryRun | ExecutionKind::Validation => Err(err),
                         }
                     }
@@ -719,9 +827,19 @@ where
 
         let block_height = *header.height();
         let checked_tx = match tx {
-            MaybeCheckedTransaction::Transaction(tx) => tx
-                .into_checked_basic(block_height, &self.config.consensus_parameters)?
-                .into(),
+            MaybeCheckedTransaction::Transaction(tx) => {
+                let checked_tx = tx.into_checked_basic(
+                    block_height,
+                    &self.config.consensus_parameters,
+                )?;
+                self.options
+                    .utxo_validation
+                    .then_some(self.config.predicate_cache.as_ref())
+                    .flatten()
+                    .and_then(|cache| cache.verified(block_height, &checked_tx))
+                    .unwrap_or(checked_tx)
+                    .into()
+            }
             MaybeCheckedTransaction::CheckedTransaction(checked_tx) => checked_tx,
         };
 
@@ -958,11 +1076,14 @@ where
         let sub_db_view = sub_block_db_commit.as_mut();
 
         // execution vm
-        let vm_db = VmStorage::new(
+        let mut vm_db = VmStorage::new(
             sub_db_view.clone(),
             &header.consensus,
             self.config.coinbase_recipient,
         );
+        if execution_kind != ExecutionKind::DryRun {
+            vm_db = vm_db.record_state_changes();
+        }
 
         let mut vm = Interpreter::with_storage(
             vm_db,
@@ -999,9 +1120,12 @@ where
         )?;
 
         // only commit state changes if execution was a success
-        if !reverted {
+        let state_changes = if !reverted {
             sub_block_db_commit.commit()?;
-        }
+            vm.as_mut().take_state_changes()
+        } else {
+            vec![]
+        };
 
         // update block commitment
         let (used_gas, tx_fee) = self.total_fee_paid(&tx, max_fee, &receipts)?;
@@ -1095,6 +1219,12 @@ where
         execution_data
             .message_ids
             .extend(receipts.iter().filter_map(|r| r.message_id()));
+        // the later write to the same slot overrides the earlier one
+        for change in state_changes {
+            execution_data
+                .state_changes
+                .insert((change.contract_id, change.key), change.value);
+        }
         // queue up status for this tx to be stored once block id is finalized.
         execution_data.tx_status.push(TransactionExecutionStatus {
             id: tx_id,
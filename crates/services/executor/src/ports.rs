@@ -27,9 +27,18 @@ use fuel_core_types::{
         TxId,
         UniqueIdentifier,
     },
-    fuel_types::ChainId,
-    fuel_vm::checked_transaction::CheckedTransaction,
-    services::relayer::Event,
+    fuel_types::{
+        BlockHeight,
+        ChainId,
+    },
+    fuel_vm::checked_transaction::{
+        Checked,
+        CheckedTransaction,
+    },
+    services::{
+        port::PortResult,
+        relayer::Event,
+    },
 };
 
 /// The wrapper around either `Transaction` or `CheckedTransaction`.
@@ -60,12 +69,24 @@ pub trait TransactionsSource {
     fn next(&self, gas_limit: u64) -> Vec<MaybeCheckedTransaction>;
 }
 
+/// The cache of transactions with predicates verified outside of the executor,
+/// for example, by the `TxPool` during the insertion.
+pub trait PredicateVerificationCache: core::fmt::Debug + Send + Sync {
+    /// Returns the transaction equal to the `tx` with predicates verified under
+    /// the consensus parameters in effect at the `height`.
+    fn verified(
+        &self,
+        height: BlockHeight,
+        tx: &Checked<fuel_tx::Transaction>,
+    ) -> Option<Checked<fuel_tx::Transaction>>;
+}
+
 pub trait RelayerPort {
     /// Returns `true` if the relayer is enabled.
     fn enabled(&self) -> bool;
 
     /// Get events from the relayer at a given da height.
-    fn get_events(&self, da_height: &DaBlockHeight) -> anyhow::Result<Vec<Event>>;
+    fn get_events(&self, da_height: &DaBlockHeight) -> PortResult<Vec<Event>>;
 }
 
 // TODO: Remove `Clone` bound
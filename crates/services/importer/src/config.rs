@@ -6,6 +6,10 @@ pub struct Config {
     pub max_block_notify_buffer: usize,
     pub metrics: bool,
     pub chain_id: ChainId,
+    /// The maximum encoded size of a transaction in the imported block.
+    /// It is the largest `max_size` among all scheduled consensus parameters,
+    /// the executor verifies the exact limit for the height of the block.
+    pub max_transaction_size: u64,
 }
 
 impl Config {
@@ -14,6 +18,14 @@ impl Config {
             max_block_notify_buffer: 1 << 10,
             metrics: false,
             chain_id: chain_config.consensus_parameters.chain_id,
+            max_transaction_size: chain_config
+                .consensus_parameters_upgrades
+                .iter()
+                .map(|upgrade| upgrade.consensus_parameters.tx_params.max_size)
+                .fold(
+                    chain_config.consensus_parameters.tx_params.max_size,
+                    u64::max,
+                ),
         }
     }
 }
@@ -25,6 +37,7 @@ impl Default for Config {
             max_block_notify_buffer: 1,
             metrics: false,
             chain_id: ChainId::default(),
+            max_transaction_size: u64::MAX,
         }
     }
 }
@@ -8,6 +8,11 @@ use crate::{
     Config,
 };
 use fuel_core_metrics::importer::importer_metrics;
+use fuel_core_services::{
+    CriticalSection,
+    HealthReporter,
+    ShutdownToken,
+};
 use fuel_core_storage::{
     not_found,
     transactional::StorageTransaction,
@@ -15,14 +20,18 @@ use fuel_core_storage::{
 };
 use fuel_core_types::{
     blockchain::{
+        block::Block,
         consensus::{
             Consensus,
             Sealed,
         },
+        header::generate_txns_root,
         primitives::BlockId,
         SealedBlock,
     },
+    fuel_tx::Bytes32,
     fuel_types::{
+        canonical::Serialize,
         BlockHeight,
         ChainId,
     },
@@ -34,6 +43,7 @@ use fuel_core_types::{
         },
         executor,
         executor::ExecutionResult,
+        port::PortError,
         Uncommitted,
     },
 };
@@ -79,8 +89,20 @@ pub enum Error {
         fmt = "Got another block id after validation of the block. Expected {_0} != Actual {_1}"
     )]
     BlockIdMismatch(BlockId, BlockId),
+    #[display(
+        fmt = "The header declares {_0} transactions, but the block contains {_1}."
+    )]
+    TransactionsCountMismatch(u64, u64),
+    #[display(
+        fmt = "The header declares the transactions root {_0}, but the root of the block transactions is {_1}."
+    )]
+    TransactionsRootMismatch(Bytes32, Bytes32),
+    #[display(
+        fmt = "The transaction at index {_0} has the size {_1}, when the maximum size is {_2}."
+    )]
+    TransactionTooLarge(usize, u64, u64),
     #[display(fmt = "Some of the block fields are not valid: {_0}.")]
-    FailedVerification(anyhow::Error),
+    FailedVerification(PortError),
     #[display(fmt = "The execution of the block failed: {_0}.")]
     FailedExecution(executor::Error),
     #[display(
@@ -91,6 +113,8 @@ pub enum Error {
     ExecuteGenesis,
     #[display(fmt = "The database already contains the data at the height {_0}.")]
     NotUnique(BlockHeight),
+    #[display(fmt = "The node is shutting down, the block is not imported.")]
+    ShuttingDown,
     #[from]
     StorageError(StorageError),
     UnsupportedConsensusVariant(String),
@@ -114,12 +138,19 @@ pub struct Importer<D, E, V> {
     executor: Arc<E>,
     verifier: Arc<V>,
     chain_id: ChainId,
+    max_transaction_size: u64,
     broadcast: broadcast::Sender<SharedImportResult>,
     /// The channel to notify about the end of the processing of the previous block by all listeners.
     /// It is used to await until all receivers of the notification process the `SharedImportResult`
     /// before starting committing a new block.
     prev_block_process_result: Mutex<Option<oneshot::Receiver<()>>>,
     guard: tokio::sync::Semaphore,
+    /// The token of the node shutdown. The commit of the block is a critical section,
+    /// so the shutdown waits until the block is fully committed and notified.
+    shutdown: ShutdownToken,
+    /// The reporter of the health of the importer. The progress is reported
+    /// on each committed block.
+    health: HealthReporter,
 }
 
 impl<D, E, V> Importer<D, E, V> {
@@ -131,9 +162,37 @@ impl<D, E, V> Importer<D, E, V> {
             executor: Arc::new(executor),
             verifier: Arc::new(verifier),
             chain_id: config.chain_id,
+            max_transaction_size: config.max_transaction_size,
             broadcast,
             prev_block_process_result: Default::default(),
             guard: tokio::sync::Semaphore::new(1),
+            shutdown: ShutdownToken::default(),
+            health: HealthReporter::default(),
+        }
+    }
+
+    /// Uses the `shutdown` token to stop importing blocks when the node shuts down.
+    pub fn with_shutdown(mut self, shutdown: ShutdownToken) -> Self {
+        self.shutdown = shutdown;
+        self
+    }
+
+    /// Uses the `health` reporter to report the progress and failures of commits.
+    pub fn with_health(mut self, health: HealthReporter) -> Self {
+        self.health = health;
+        self
+    }
+
+    fn report_health(&self, result: &Result<(), Error>) {
+        match result {
+            Ok(()) => self.health.progress(),
+            // Other errors are caused by the invalid or outdated block,
+            // not by the importer itself.
+            Err(
+                err @ (Error::StorageError(_)
+                | Error::InvalidDatabaseStateAfterExecution(_, _)),
+            ) => self.health.error(err),
+            Err(_) => {}
         }
     }
 
@@ -154,6 +213,10 @@ impl<D, E, V> Importer<D, E, V> {
             }
         }
     }
+
+    pub(crate) fn critical_section(&self) -> Result<CriticalSection, Error> {
+        self.shutdown.critical_section().ok_or(Error::ShuttingDown)
+    }
 }
 
 impl<D, E, V> Importer<D, E, V>
@@ -173,6 +236,11 @@ where
     ///
     /// Only one commit may be in progress at the time. All other calls will fail.
     /// Returns an error if called while another call is in progress.
+    ///
+    /// # Shutdown
+    ///
+    /// The commit is not started after the shutdown is requested. The started commit
+    /// is finished before the shutdown.
     pub async fn commit_result<ExecutorDatabase>(
         &self,
         result: UncommittedResult<StorageTransaction<ExecutorDatabase>>,
@@ -181,6 +249,7 @@ where
         ExecutorDatabase: ports::ExecutorDatabase,
     {
         let _guard = self.lock()?;
+        let _critical_section = self.critical_section()?;
         // It is safe to unwrap the channel because we have the `_guard`.
         let previous_block_result = self
             .prev_block_process_result
@@ -193,7 +262,9 @@ where
             let _ = channel.await;
         }
 
-        self._commit_result(result)
+        let result = self._commit_result(result);
+        self.report_health(&result);
+        result
     }
 
     /// The method commits the result of the block execution and notifies about a new imported block.
@@ -353,6 +424,7 @@ where
         Self::verify_and_execute_block_inner(
             self.executor.clone(),
             self.verifier.clone(),
+            self.max_transaction_size,
             sealed_block,
         )
     }
@@ -360,6 +432,7 @@ where
     fn verify_and_execute_block_inner(
         executor: Arc<E>,
         verifier: Arc<V>,
+        max_transaction_size: u64,
         sealed_block: SealedBlock,
     ) -> Result<UncommittedResult<StorageTransaction<E::Database>>, Error> {
         let consensus = sealed_block.consensus;
@@ -378,12 +451,17 @@ where
             return Err(Error::ExecuteGenesis)
         }
 
+        // The execution of the block is expensive, so the body is
+        // cross-checked against the header before it.
+        verify_transactions(&block, max_transaction_size)?;
+
         // TODO: Pass `block` into `ExecutionBlock::Validation` by ref
         let (
             ExecutionResult {
                 block,
                 skipped_transactions,
                 tx_status,
+                state_changes,
             },
             db_tx,
         ) = executor
@@ -407,7 +485,10 @@ where
             entity: block,
             consensus,
         };
-        let import_result = ImportResult::new_from_network(sealed_block, tx_status);
+        let import_result = ImportResult {
+            state_changes,
+            ..ImportResult::new_from_network(sealed_block, tx_status)
+        };
 
         Ok(Uncommitted::new(import_result, db_tx))
     }
@@ -421,24 +502,36 @@ where
 {
     /// The method validates the `Block` fields and commits the `SealedBlock`.
     /// It is a combination of the [`Importer::verify_and_execute_block`] and [`Importer::commit_result`].
+    ///
+    /// If the shutdown is requested during the execution, the result of the execution
+    /// is discarded, and the block is not committed.
     pub async fn execute_and_commit(
         &self,
         sealed_block: SealedBlock,
     ) -> Result<(), Error> {
         let _guard = self.lock()?;
+        if self.shutdown.is_cancelled() {
+            return Err(Error::ShuttingDown)
+        }
 
         let executor = self.executor.clone();
         let verifier = self.verifier.clone();
-        let (result, execute_time) = tokio_rayon::spawn_fifo(|| {
+        let max_transaction_size = self.max_transaction_size;
+        let (result, execute_time) = tokio_rayon::spawn_fifo(move || {
             let start = Instant::now();
-            let result =
-                Self::verify_and_execute_block_inner(executor, verifier, sealed_block);
+            let result = Self::verify_and_execute_block_inner(
+                executor,
+                verifier,
+                max_transaction_size,
+                sealed_block,
+            );
             let execute_time = start.elapsed().as_secs_f64();
             (result, execute_time)
         })
         .await;
 
         let result = result?;
+        let _critical_section = self.critical_section()?;
 
         // It is safe to unwrap the channel because we have the `_guard`.
         let previous_block_result = self
@@ -454,6 +547,7 @@ where
 
         let start = Instant::now();
         let commit_result = self._commit_result(result);
+        self.report_health(&commit_result);
         let commit_time = start.elapsed().as_secs_f64();
         let time = execute_time + commit_time;
         importer_metrics().execute_and_commit_duration.observe(time);
@@ -462,6 +556,42 @@ where
     }
 }
 
+/// Verifies that the transactions of the block match the count and the root
+/// declared by the header, and that each of them fits the `max_transaction_size`.
+fn verify_transactions(block: &Block, max_transaction_size: u64) -> Result<(), Error> {
+    let header = block.header().application();
+    let transactions = block.transactions();
+
+    let count = u64::try_from(transactions.len()).unwrap_or(u64::MAX);
+    if count != header.transactions_count {
+        return Err(Error::TransactionsCountMismatch(
+            header.transactions_count,
+            count,
+        ))
+    }
+
+    for (index, tx) in transactions.iter().enumerate() {
+        let size = u64::try_from(tx.size()).unwrap_or(u64::MAX);
+        if size > max_transaction_size {
+            return Err(Error::TransactionTooLarge(
+                index,
+                size,
+                max_transaction_size,
+            ))
+        }
+    }
+
+    let root = generate_txns_root(transactions);
+    if root != header.transactions_root {
+        return Err(Error::TransactionsRootMismatch(
+            header.transactions_root,
+            root,
+        ))
+    }
+
+    Ok(())
+}
+
 trait ShouldBeUnique {
     fn should_be_unique(&self, height: &BlockHeight) -> Result<(), Error>;
 }
@@ -6,9 +6,14 @@ use crate::{
         MockBlockVerifier,
         MockExecutor,
     },
+    Config,
     Importer,
 };
 use anyhow::anyhow;
+use fuel_core_services::{
+    HealthRegistry,
+    ShutdownManager,
+};
 use fuel_core_storage::{
     transactional::{
         StorageTransaction,
@@ -21,10 +26,16 @@ use fuel_core_types::{
     blockchain::{
         block::Block,
         consensus::Consensus,
+        header::generate_txns_root,
         SealedBlock,
     },
-    fuel_tx::TxId,
+    fuel_tx::{
+        Bytes32,
+        Transaction,
+        TxId,
+    },
     fuel_types::{
+        canonical::Serialize,
         BlockHeight,
         ChainId,
     },
@@ -38,9 +49,14 @@ use fuel_core_types::{
             ExecutionResult,
             Result as ExecutorResult,
         },
+        port::{
+            PortError,
+            PortResult,
+        },
         Uncommitted,
     },
 };
+use std::time::Duration;
 use test_case::test_case;
 use tokio::sync::{
     broadcast::error::TryRecvError,
@@ -97,9 +113,20 @@ fn genesis(height: u32) -> SealedBlock {
 }
 
 fn poa_block(height: u32) -> SealedBlock {
+    poa_block_with_transactions(height, vec![])
+}
+
+fn poa_block_with_transactions(
+    height: u32,
+    transactions: Vec<Transaction>,
+) -> SealedBlock {
     let mut block = Block::default();
-    block.header_mut().set_block_height(height.into());
-    block.header_mut().recalculate_metadata();
+    let header = block.header_mut();
+    header.set_block_height(height.into());
+    header.set_transaction_root(generate_txns_root(&transactions));
+    header.set_transactions_count(transactions.len() as u64);
+    header.recalculate_metadata();
+    *block.transactions_mut() = transactions;
 
     SealedBlock {
         entity: block,
@@ -107,6 +134,23 @@ fn poa_block(height: u32) -> SealedBlock {
     }
 }
 
+fn poa_block_with_wrong_count(height: u32) -> SealedBlock {
+    let mut block = poa_block_with_transactions(height, vec![]);
+    block
+        .entity
+        .transactions_mut()
+        .push(Transaction::default_test_tx());
+    block
+}
+
+fn poa_block_with_wrong_root(height: u32) -> SealedBlock {
+    let mut block = poa_block_with_transactions(height, vec![]);
+    let header = block.entity.header_mut();
+    header.set_transaction_root(Bytes32::zeroed());
+    header.recalculate_metadata();
+    block
+}
+
 fn underlying_db<R>(result: R) -> impl Fn() -> MockDatabase
 where
     R: Fn() -> StorageResult<Option<u32>> + Send + Clone + 'static,
@@ -186,6 +230,7 @@ where
                     block: mock_result.block.entity,
                     skipped_transactions,
                     tx_status: vec![],
+                    state_changes: vec![],
                 },
                 StorageTransaction::new(database),
             ))
@@ -194,8 +239,8 @@ where
     executor
 }
 
-fn verification_failure<T>() -> anyhow::Result<T> {
-    Err(anyhow!("Not verified"))
+fn verification_failure<T>() -> PortResult<T> {
+    Err(PortError::invalid_input(anyhow!("Not verified")))
 }
 
 fn verification_failure_error() -> Error {
@@ -204,7 +249,7 @@ fn verification_failure_error() -> Error {
 
 fn verifier<R>(result: R) -> MockBlockVerifier
 where
-    R: Fn() -> anyhow::Result<()> + Send + 'static,
+    R: Fn() -> PortResult<()> + Send + 'static,
 {
     let mut verifier = MockBlockVerifier::default();
     verifier
@@ -438,6 +483,82 @@ async fn execute_and_commit_fail_when_locked() {
     );
 }
 
+#[tokio::test]
+async fn commit_result_fail_after_shutdown() {
+    let shutdown = ShutdownManager::new();
+    let importer = Importer::new(Default::default(), MockDatabase::default(), (), ())
+        .with_shutdown(shutdown.token());
+    let uncommitted_result = UncommittedResult::new(
+        ImportResult::default(),
+        StorageTransaction::new(MockDatabase::default()),
+    );
+
+    shutdown.cancel();
+    assert_eq!(
+        importer.commit_result(uncommitted_result).await,
+        Err(Error::ShuttingDown)
+    );
+}
+
+#[tokio::test]
+async fn execute_and_commit_fail_after_shutdown() {
+    let shutdown = ShutdownManager::new();
+    let importer = Importer::new(
+        Default::default(),
+        MockDatabase::default(),
+        MockExecutor::default(),
+        MockBlockVerifier::default(),
+    )
+    .with_shutdown(shutdown.token());
+
+    shutdown.cancel();
+    assert_eq!(
+        importer.execute_and_commit(Default::default()).await,
+        Err(Error::ShuttingDown)
+    );
+}
+
+#[tokio::test]
+async fn shutdown_waits_for_the_commit_in_progress() {
+    let shutdown = ShutdownManager::new();
+    let importer = Importer::new(Default::default(), MockDatabase::default(), (), ())
+        .with_shutdown(shutdown.token());
+
+    let critical_section = importer.critical_section().unwrap();
+    assert!(!shutdown.shutdown(Duration::from_millis(100)).await);
+
+    drop(critical_section);
+    assert!(shutdown.shutdown(Duration::from_millis(100)).await);
+}
+
+#[tokio::test]
+async fn commit_result_reports_health() {
+    let health = HealthRegistry::new();
+    let importer =
+        Importer::new(Default::default(), underlying_db(ok(Some(0)))(), (), ())
+            .with_health(health.register("importer"));
+
+    // The storage failure is the error of the importer.
+    let uncommitted_result = UncommittedResult::new(
+        ImportResult::new_from_local(poa_block(1), vec![]),
+        StorageTransaction::new(executor_db(storage_failure, ok(true), 0)()),
+    );
+    let _ = importer.commit_result(uncommitted_result).await;
+    let report = health.report();
+    assert!(report.components[0].1.error.is_some());
+    assert!(!report.is_ready());
+
+    // The committed block is the progress.
+    let uncommitted_result = UncommittedResult::new(
+        ImportResult::new_from_local(poa_block(1), vec![]),
+        StorageTransaction::new(executor_db(ok(Some(0)), ok(true), 1)()),
+    );
+    importer.commit_result(uncommitted_result).await.unwrap();
+    let report = health.report();
+    assert!(report.components[0].1.last_progress.is_some());
+    assert!(report.is_ready());
+}
+
 #[test]
 fn one_lock_at_the_same_time() {
     let importer = Importer::new(
@@ -490,6 +611,16 @@ fn one_lock_at_the_same_time() {
     => Err(verification_failure_error());
     "commit fails if verification fails"
 )]
+#[test_case(
+    poa_block_with_wrong_count(113), execution_failure, ok(()), 0
+    => Err(Error::TransactionsCountMismatch(0, 1));
+    "commit fails before execution if the transactions count mismatches the header"
+)]
+#[test_case(
+    poa_block_with_wrong_root(113), execution_failure, ok(()), 0
+    => Err(Error::TransactionsRootMismatch(Bytes32::zeroed(), generate_txns_root(&[])));
+    "commit fails before execution if the transactions root mismatches the header"
+)]
 #[tokio::test]
 async fn execute_and_commit_and_verify_and_execute_block_poa<V, P>(
     sealed_block: SealedBlock,
@@ -499,7 +630,7 @@ async fn execute_and_commit_and_verify_and_execute_block_poa<V, P>(
 ) -> Result<(), Error>
 where
     P: Fn() -> ExecutorResult<MockExecutionResult> + Send + Clone + 'static,
-    V: Fn() -> anyhow::Result<()> + Send + Clone + 'static,
+    V: Fn() -> PortResult<()> + Send + Clone + 'static,
 {
     // `execute_and_commit` and `verify_and_execute_block` should have the same
     // validation rules(-> test cases) during verification.
@@ -534,7 +665,7 @@ fn verify_and_execute_assert<P, V>(
 ) -> Result<(), Error>
 where
     P: Fn() -> ExecutorResult<MockExecutionResult> + Send + 'static,
-    V: Fn() -> anyhow::Result<()> + Send + 'static,
+    V: Fn() -> PortResult<()> + Send + 'static,
 {
     let importer = Importer::new(
         Default::default(),
@@ -546,6 +677,28 @@ where
     importer.verify_and_execute_block(sealed_block).map(|_| ())
 }
 
+#[test]
+fn verify_and_execute_rejects_too_large_transaction_before_execution() {
+    let tx = Transaction::default_test_tx();
+    let size = tx.size() as u64;
+    let config = Config {
+        max_transaction_size: size - 1,
+        ..Default::default()
+    };
+    let importer = Importer::new(
+        config,
+        MockDatabase::default(),
+        executor(execution_failure, MockDatabase::default()),
+        verifier(ok(())),
+    );
+
+    let result = importer
+        .verify_and_execute_block(poa_block_with_transactions(13, vec![tx]))
+        .map(|_| ());
+
+    assert_eq!(result, Err(Error::TransactionTooLarge(0, size, size - 1)));
+}
+
 #[test]
 fn verify_and_execute_allowed_when_locked() {
     let importer = Importer::new(
@@ -12,9 +12,12 @@ use fuel_core_types::{
         BlockHeight,
         ChainId,
     },
-    services::executor::{
-        Result as ExecutorResult,
-        UncommittedResult,
+    services::{
+        executor::{
+            Result as ExecutorResult,
+            UncommittedResult,
+        },
+        port::PortResult,
     },
 };
 
@@ -60,9 +63,6 @@ pub trait BlockVerifier: Send + Sync {
     /// the corresponding height.
     ///
     /// Return an error if the verification failed, otherwise `Ok(())`.
-    fn verify_block_fields(
-        &self,
-        consensus: &Consensus,
-        block: &Block,
-    ) -> anyhow::Result<()>;
+    fn verify_block_fields(&self, consensus: &Consensus, block: &Block)
+        -> PortResult<()>;
 }
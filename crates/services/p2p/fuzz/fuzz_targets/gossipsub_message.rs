@@ -0,0 +1,32 @@
+#![no_main]
+
+use fuel_core_p2p::{
+    codecs::{
+        postcard::PostcardCodec,
+        GossipsubCodec,
+    },
+    gossipsub::messages::{
+        GossipTopicTag,
+        GossipsubBroadcastRequest,
+        GossipsubMessage,
+    },
+};
+use libfuzzer_sys::fuzz_target;
+use std::sync::Arc;
+
+/// The default max block size of the node.
+const MAX_BLOCK_SIZE: usize = 50 * 1024 * 1024;
+
+fuzz_target!(|data: &[u8]| {
+    let codec = PostcardCodec::new(MAX_BLOCK_SIZE);
+    if let Ok(GossipsubMessage::NewTx(tx)) = codec.decode(data, GossipTopicTag::NewTx) {
+        // The decoded message survives the round trip.
+        let encoded = codec
+            .encode(GossipsubBroadcastRequest::NewTx(Arc::new(tx.clone())))
+            .expect("Decoded transaction should be encodable");
+        let GossipsubMessage::NewTx(decoded) = codec
+            .decode(&encoded, GossipTopicTag::NewTx)
+            .expect("Encoded transaction should be decodable");
+        assert_eq!(decoded, tx);
+    }
+});
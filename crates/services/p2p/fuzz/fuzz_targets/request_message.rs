@@ -0,0 +1,36 @@
+#![no_main]
+
+use fuel_core_p2p::codecs::{
+    postcard::PostcardCodec,
+    NetworkCodec,
+};
+use libfuzzer_sys::fuzz_target;
+use libp2p::request_response::Codec;
+
+/// The default max block size of the node.
+const MAX_BLOCK_SIZE: usize = 50 * 1024 * 1024;
+
+fuzz_target!(|data: &[u8]| {
+    let mut codec = PostcardCodec::new(MAX_BLOCK_SIZE);
+    let protocol = codec.get_req_res_protocol();
+    let mut socket = futures::io::Cursor::new(data);
+
+    let decoded =
+        futures::executor::block_on(codec.read_request(&protocol, &mut socket));
+    assert_eq!(decoded.is_ok(), codec.decode_request(data).is_ok());
+
+    if let Ok(request) = decoded {
+        // The decoded request survives the round trip.
+        let mut encoded = futures::io::Cursor::new(vec![]);
+        futures::executor::block_on(codec.write_request(
+            &protocol,
+            &mut encoded,
+            request.clone(),
+        ))
+        .expect("Decoded request should be encodable");
+        let decoded = codec
+            .decode_request(&encoded.into_inner())
+            .expect("Encoded request should be decodable");
+        assert_eq!(decoded, request);
+    }
+});
@@ -0,0 +1,35 @@
+#![no_main]
+
+use fuel_core_p2p::codecs::{
+    postcard::PostcardCodec,
+    NetworkCodec,
+};
+use libfuzzer_sys::fuzz_target;
+use libp2p::request_response::Codec;
+
+/// The small max size of the response to cover the size validation.
+const MAX_RESPONSE_SIZE: usize = 64 * 1024;
+
+fuzz_target!(|data: &[u8]| {
+    let mut codec = PostcardCodec::new(MAX_RESPONSE_SIZE);
+    let protocol = codec.get_req_res_protocol();
+    let mut socket = futures::io::Cursor::new(data);
+
+    let decoded =
+        futures::executor::block_on(codec.read_response(&protocol, &mut socket));
+    assert_eq!(decoded.is_ok(), codec.decode_response(data).is_ok());
+
+    if let Ok(response) = decoded {
+        // The decoded response survives the round trip.
+        let mut encoded = futures::io::Cursor::new(vec![]);
+        futures::executor::block_on(codec.write_response(
+            &protocol,
+            &mut encoded,
+            response,
+        ))
+        .expect("Decoded response should be encodable");
+        codec
+            .decode_response(&encoded.into_inner())
+            .expect("Encoded response should be decodable");
+    }
+});
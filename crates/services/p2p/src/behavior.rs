@@ -12,8 +12,8 @@ use crate::{
     heartbeat,
     peer_report,
     request_response::messages::{
+        NetworkResponse,
         RequestMessage,
-        ResponseMessage,
     },
 };
 use fuel_core_types::fuel_types::BlockHeight;
@@ -24,6 +24,7 @@ use libp2p::{
         MessageAcceptance,
         MessageId,
         PublishError,
+        TopicHash,
     },
     identify,
     request_response::{
@@ -45,6 +46,13 @@ pub struct FuelBehaviour {
     /// The Behaviour to manage connections to blocked peers.
     blocked_peer: allow_block_list::Behaviour<allow_block_list::BlockedPeers>,
 
+    /// RequestResponse protocol
+    ///
+    /// The behaviours are polled in the order of declaration, so the requests and
+    /// responses of blocks are handled before the pending gossip. Otherwise, the flood
+    /// of gossiped transactions delays the synchronization of blocks.
+    request_response: request_response::Behaviour<PostcardCodec>,
+
     /// Message propagation for p2p
     gossipsub: gossipsub::Behaviour,
 
@@ -59,9 +67,6 @@ pub struct FuelBehaviour {
 
     /// Node discovery
     discovery: discovery::Behaviour,
-
-    /// RequestResponse protocol
-    request_response: request_response::Behaviour<PostcardCodec>,
 }
 
 impl FuelBehaviour {
@@ -165,9 +170,9 @@ impl FuelBehaviour {
 
     pub fn send_response_msg(
         &mut self,
-        channel: ResponseChannel<ResponseMessage>,
-        message: ResponseMessage,
-    ) -> Result<(), ResponseMessage> {
+        channel: ResponseChannel<NetworkResponse>,
+        message: NetworkResponse,
+    ) -> Result<(), NetworkResponse> {
         self.request_response.send_response(channel, message)
     }
 
@@ -205,6 +210,11 @@ impl FuelBehaviour {
         self.heartbeat.update_block_height(block_height);
     }
 
+    /// Returns peers in the gossipsub mesh of the topic.
+    pub fn mesh_peers(&self, topic: &TopicHash) -> impl Iterator<Item = &PeerId> {
+        self.gossipsub.mesh_peers(topic)
+    }
+
     #[cfg(test)]
     pub fn get_peer_score(&self, peer_id: &PeerId) -> Option<f64> {
         self.gossipsub.peer_score(peer_id)
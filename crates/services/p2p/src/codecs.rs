@@ -7,8 +7,8 @@ use crate::{
         GossipsubMessage,
     },
     request_response::messages::{
+        NetworkResponse,
         RequestMessage,
-        ResponseMessage,
     },
 };
 use libp2p::request_response;
@@ -34,7 +34,7 @@ pub trait NetworkCodec:
     GossipsubCodec<
         RequestMessage = GossipsubBroadcastRequest,
         ResponseMessage = GossipsubMessage,
-    > + request_response::Codec<Request = RequestMessage, Response = ResponseMessage>
+    > + request_response::Codec<Request = RequestMessage, Response = NetworkResponse>
     + Clone
     + Send
     + 'static
@@ -9,12 +9,19 @@ use crate::{
         GossipsubMessage,
     },
     request_response::messages::{
+        NetworkResponse,
         RequestMessage,
         ResponseMessage,
+        MAX_REQUEST_SIZE,
         REQUEST_RESPONSE_PROTOCOL_ID,
     },
+    telemetry::{
+        Direction,
+        TelemetrySender,
+    },
 };
 use async_trait::async_trait;
+use fuel_core_types::services::p2p::NetworkProtocol;
 use futures::{
     AsyncRead,
     AsyncReadExt,
@@ -29,9 +36,38 @@ use std::io;
 
 /// Helper method for decoding data
 /// Reusable across `RequestResponseCodec` and `GossipsubCodec`
+/// The data is rejected if it has trailing bytes after the decoded message.
 fn deserialize<'a, R: Deserialize<'a>>(encoded_data: &'a [u8]) -> Result<R, io::Error> {
-    postcard::from_bytes(encoded_data)
-        .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))
+    let (decoded, remainder) = postcard::take_from_bytes(encoded_data)
+        .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
+    if !remainder.is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{} trailing bytes after the message", remainder.len()),
+        ))
+    }
+    Ok(decoded)
+}
+
+/// Reads the data from the socket until the end of the stream.
+/// The data is rejected if it is bigger than `max_size`.
+async fn read_limited<T>(socket: &mut T, max_size: usize) -> io::Result<Vec<u8>>
+where
+    T: AsyncRead + Unpin + Send,
+{
+    let mut data = Vec::new();
+    // Read one byte more than allowed to detect oversized messages.
+    let limit = u64::try_from(max_size)
+        .unwrap_or(u64::MAX)
+        .saturating_add(1);
+    socket.take(limit).read_to_end(&mut data).await?;
+    if data.len() > max_size {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("The message exceeds the maximum size of {max_size} bytes"),
+        ))
+    }
+    Ok(data)
 }
 
 fn serialize<D: Serialize>(data: &D) -> Result<Vec<u8>, io::Error> {
@@ -45,6 +81,8 @@ pub struct PostcardCodec {
     /// Necessary in order to avoid DoS attacks
     /// Currently the size mostly depends on the max size of the Block
     max_response_size: usize,
+    /// Accounts the bytes of requests and responses.
+    telemetry: TelemetrySender,
 }
 
 impl PostcardCodec {
@@ -56,8 +94,57 @@ impl PostcardCodec {
 
         Self {
             max_response_size: max_block_size,
+            telemetry: TelemetrySender::default(),
         }
     }
+
+    /// Reports the bandwidth of the request-response protocol to the `telemetry`.
+    pub fn with_telemetry(mut self, telemetry: TelemetrySender) -> Self {
+        self.telemetry = telemetry;
+        self
+    }
+
+    /// The telemetry of the codec.
+    pub fn telemetry(&self) -> &TelemetrySender {
+        &self.telemetry
+    }
+
+    fn record_bandwidth(&self, direction: Direction, bytes: usize) {
+        self.telemetry
+            .bandwidth(NetworkProtocol::RequestResponse, direction, bytes)
+    }
+
+    /// Decodes the request received from the peer.
+    pub fn decode_request(&self, encoded_data: &[u8]) -> io::Result<RequestMessage> {
+        if encoded_data.len() > MAX_REQUEST_SIZE {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "The request exceeds the maximum size of {MAX_REQUEST_SIZE} bytes"
+                ),
+            ))
+        }
+        deserialize(encoded_data)
+    }
+
+    /// Decodes the response received from the peer.
+    pub fn decode_response(&self, encoded_data: &[u8]) -> io::Result<ResponseMessage> {
+        if encoded_data.len() > self.max_response_size {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "The response exceeds the maximum size of {} bytes",
+                    self.max_response_size
+                ),
+            ))
+        }
+        deserialize(encoded_data)
+    }
+
+    /// Encodes the response for the peer.
+    pub fn encode_response(&self, response: &ResponseMessage) -> io::Result<Vec<u8>> {
+        serialize(response)
+    }
 }
 
 /// Since Postcard does not support async reads or writes out of the box
@@ -71,7 +158,7 @@ impl PostcardCodec {
 impl request_response::Codec for PostcardCodec {
     type Protocol = MessageExchangePostcardProtocol;
     type Request = RequestMessage;
-    type Response = ResponseMessage;
+    type Response = NetworkResponse;
 
     async fn read_request<T>(
         &mut self,
@@ -81,12 +168,9 @@ impl request_response::Codec for PostcardCodec {
     where
         T: AsyncRead + Unpin + Send,
     {
-        let mut response = Vec::new();
-        socket
-            .take(self.max_response_size as u64)
-            .read_to_end(&mut response)
-            .await?;
-        deserialize(&response)
+        let request = read_limited(socket, MAX_REQUEST_SIZE).await?;
+        self.record_bandwidth(Direction::Inbound, request.len());
+        self.decode_request(&request)
     }
 
     async fn read_response<T>(
@@ -97,13 +181,10 @@ impl request_response::Codec for PostcardCodec {
     where
         T: AsyncRead + Unpin + Send,
     {
-        let mut response = Vec::new();
-        socket
-            .take(self.max_response_size as u64)
-            .read_to_end(&mut response)
-            .await?;
-
-        deserialize(&response)
+        let response = read_limited(socket, self.max_response_size).await?;
+        self.record_bandwidth(Direction::Inbound, response.len());
+        self.decode_response(&response)
+            .map(NetworkResponse::Message)
     }
 
     async fn write_request<T>(
@@ -117,6 +198,7 @@ impl request_response::Codec for PostcardCodec {
     {
         let encoded_data = serialize(&req)?;
         socket.write_all(&encoded_data).await?;
+        self.record_bandwidth(Direction::Outbound, encoded_data.len());
         Ok(())
     }
 
@@ -129,8 +211,12 @@ impl request_response::Codec for PostcardCodec {
     where
         T: futures::AsyncWrite + Unpin + Send,
     {
-        let encoded_data = serialize(&res)?;
+        let encoded_data = match res {
+            NetworkResponse::Message(message) => serialize(&message)?,
+            NetworkResponse::Encoded(encoded_data) => encoded_data,
+        };
         socket.write_all(&encoded_data).await?;
+        self.record_bandwidth(Direction::Outbound, encoded_data.len());
         Ok(())
     }
 }
@@ -177,8 +263,13 @@ impl AsRef<str> for MessageExchangePostcardProtocol {
 
 #[cfg(test)]
 mod tests {
+    #![allow(non_snake_case)]
     use super::*;
-    use crate::request_response::messages::MAX_REQUEST_SIZE;
+    use crate::request_response::messages::MAX_POOLED_TRANSACTIONS_PER_REQUEST;
+    use fuel_core_types::{
+        fuel_tx::Transaction,
+        services::p2p::ShortTxId,
+    };
 
     #[test]
     fn test_request_size_fits() {
@@ -186,4 +277,91 @@ mod tests {
         let m = RequestMessage::Transactions(arbitrary_range);
         assert!(postcard::to_stdvec(&m).unwrap().len() <= MAX_REQUEST_SIZE);
     }
+
+    #[test]
+    fn test_max_pooled_transactions_request_size_fits() {
+        let tx_ids = vec![
+            ShortTxId([u8::MAX; ShortTxId::LEN]);
+            MAX_POOLED_TRANSACTIONS_PER_REQUEST
+        ];
+        let m = RequestMessage::PooledTransactions(tx_ids);
+        assert!(postcard::to_stdvec(&m).unwrap().len() <= MAX_REQUEST_SIZE);
+    }
+
+    #[test]
+    fn decode_request__rejects_trailing_bytes() {
+        let codec = PostcardCodec::new(1024);
+        let mut encoded = serialize(&RequestMessage::TxPoolSummary).unwrap();
+        assert_eq!(
+            codec.decode_request(&encoded).unwrap(),
+            RequestMessage::TxPoolSummary
+        );
+
+        encoded.push(0);
+
+        assert!(codec.decode_request(&encoded).is_err());
+    }
+
+    #[test]
+    fn decode_request__rejects_oversized_requests() {
+        let codec = PostcardCodec::new(1024);
+        let tx_ids = vec![ShortTxId::default(); MAX_REQUEST_SIZE];
+        let encoded = serialize(&RequestMessage::PooledTransactions(tx_ids)).unwrap();
+
+        assert!(codec.decode_request(&encoded).is_err());
+    }
+
+    #[test]
+    fn decode_response__rejects_oversized_responses() {
+        let codec = PostcardCodec::new(16);
+        let txs = vec![Transaction::default_test_tx()];
+        let encoded = serialize(&ResponseMessage::PooledTransactions(Some(txs))).unwrap();
+
+        assert!(codec.decode_response(&encoded).is_err());
+    }
+
+    #[test]
+    fn decode_gossip__rejects_trailing_bytes() {
+        let codec = PostcardCodec::new(1024);
+        let mut encoded = codec
+            .encode(GossipsubBroadcastRequest::NewTx(
+                Transaction::default_test_tx().into(),
+            ))
+            .unwrap();
+        assert!(codec.decode(&encoded, GossipTopicTag::NewTx).is_ok());
+
+        encoded.push(0);
+
+        assert!(codec.decode(&encoded, GossipTopicTag::NewTx).is_err());
+    }
+
+    #[test]
+    fn decode__handles_truncated_messages() {
+        let codec = PostcardCodec::new(1024 * 1024);
+        let encoded = codec
+            .encode(GossipsubBroadcastRequest::NewTx(
+                Transaction::default_test_tx().into(),
+            ))
+            .unwrap();
+
+        for len in 0..encoded.len() {
+            let truncated = &encoded[..len];
+            assert!(codec.decode(truncated, GossipTopicTag::NewTx).is_err());
+            let _ = codec.decode_request(truncated);
+            let _ = codec.decode_response(truncated);
+        }
+    }
+
+    #[tokio::test]
+    async fn read_request__rejects_oversized_frames() {
+        let mut codec = PostcardCodec::new(1024);
+        let protocol = codec.get_req_res_protocol();
+        let mut socket = futures::io::Cursor::new(vec![0u8; MAX_REQUEST_SIZE + 1]);
+
+        let result =
+            request_response::Codec::read_request(&mut codec, &protocol, &mut socket)
+                .await;
+
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::InvalidData);
+    }
 }
@@ -2,6 +2,7 @@ use crate::{
     gossipsub::config::default_gossipsub_config,
     heartbeat,
     peer_manager::ConnectionState,
+    request_accounting::PeerRequestLimits,
     TryPeerId,
 };
 use fuel_core_types::blockchain::consensus::Genesis;
@@ -45,11 +46,18 @@ use self::{
     fuel_authenticated::FuelAuthenticated,
     fuel_upgrade::Checksum,
     guarded_node::GuardedNode,
+    proxy::ProxyTransport,
 };
 mod connection_tracker;
 mod fuel_authenticated;
 pub(crate) mod fuel_upgrade;
 mod guarded_node;
+mod proxy;
+
+pub use proxy::{
+    Proxy,
+    ProxyProtocol,
+};
 
 const REQ_RES_TIMEOUT: Duration = Duration::from_secs(20);
 
@@ -136,9 +144,17 @@ pub struct Config<State = Initialized> {
     /// Max time since a given peer has sent a heartbeat before getting reputation penalty
     pub heartbeat_max_time_since_last: Duration,
 
+    /// The ceilings of the request-response usage by a single peer.
+    /// The usage isn't limited if it is `None`.
+    pub peer_request_limits: Option<PeerRequestLimits>,
+
     /// Enables prometheus metrics for this fuel-service
     pub metrics: bool,
 
+    /// The proxy used to dial peers. Domain names of peers are resolved by the proxy,
+    /// and websocket connections are tunneled through it as well.
+    pub proxy: Option<Proxy>,
+
     /// It is the state of the config initialization. Everyone can create an instance of the `Self`
     /// with the `NotInitialized` state. But it can be set into the `Initialized` state only with
     /// the `init` method.
@@ -184,7 +200,9 @@ impl Config<NotInitialized> {
             heartbeat_check_interval: self.heartbeat_check_interval,
             heartbeat_max_avg_interval: self.heartbeat_max_time_since_last,
             heartbeat_max_time_since_last: self.heartbeat_max_time_since_last,
+            peer_request_limits: self.peer_request_limits,
             metrics: self.metrics,
+            proxy: self.proxy,
             state: Initialized(()),
         })
     }
@@ -232,7 +250,9 @@ impl Config<NotInitialized> {
             heartbeat_max_time_since_last: Duration::from_secs(40),
             info_interval: Some(Duration::from_secs(3)),
             identify_interval: Some(Duration::from_secs(5)),
+            peer_request_limits: None,
             metrics: false,
+            proxy: None,
             state: NotInitialized,
         }
     }
@@ -262,7 +282,12 @@ pub(crate) fn build_transport_function(
     let transport_function = move |keypair: &Keypair| {
         let transport = {
             let generate_tcp_transport = || {
-                tokio::Transport::new(tcp::Config::new().port_reuse(true).nodelay(true))
+                ProxyTransport::new(
+                    p2p_config.proxy.clone(),
+                    tokio::Transport::new(
+                        tcp::Config::new().port_reuse(true).nodelay(true),
+                    ),
+                )
             };
 
             let tcp = generate_tcp_transport();
@@ -270,7 +295,14 @@ pub(crate) fn build_transport_function(
             let ws_tcp = libp2p::websocket::WsConfig::new(generate_tcp_transport())
                 .or_transport(tcp);
 
-            libp2p::dns::tokio::Transport::system(ws_tcp).unwrap()
+            if p2p_config.proxy.is_some() {
+                // The proxy resolves domain names, so they don't leak to the local resolver.
+                ws_tcp.boxed()
+            } else {
+                libp2p::dns::tokio::Transport::system(ws_tcp)
+                    .unwrap()
+                    .boxed()
+            }
         }
         .upgrade(libp2p::core::upgrade::Version::V1Lazy);
 
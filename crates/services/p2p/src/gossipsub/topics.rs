@@ -29,6 +29,11 @@ impl GossipsubTopics {
         }
     }
 
+    /// Returns names and hashes of all topics.
+    pub fn topics(&self) -> [(&'static str, &TopicHash); 1] {
+        [(NEW_TX_GOSSIP_TOPIC, &self.new_tx_topic.0)]
+    }
+
     /// Given a TopicHash it will return a matching GossipTopicTag
     pub fn get_gossipsub_tag(
         &self,
@@ -11,8 +11,10 @@ pub mod p2p_service;
 pub mod peer_manager;
 pub mod peer_report;
 pub mod ports;
+pub mod request_accounting;
 pub mod request_response;
 pub mod service;
+pub mod telemetry;
 
 pub use gossipsub::config as gossipsub_config;
 pub use heartbeat::Config;
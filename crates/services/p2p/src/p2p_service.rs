@@ -25,6 +25,7 @@ use crate::{
     },
     peer_report::PeerReportEvent,
     request_response::messages::{
+        NetworkResponse,
         RequestError,
         RequestMessage,
         ResponseError,
@@ -32,12 +33,22 @@ use crate::{
         ResponseSendError,
         ResponseSender,
     },
+    telemetry::{
+        Direction,
+        TelemetryEvent,
+        TelemetrySender,
+    },
     TryPeerId,
 };
 use fuel_core_metrics::p2p_metrics::p2p_metrics;
 use fuel_core_types::{
     fuel_types::BlockHeight,
-    services::p2p::peer_reputation::AppScore,
+    services::p2p::{
+        peer_reputation::AppScore,
+        NetworkProtocol,
+        PeerId as FuelPeerId,
+        TopicMesh,
+    },
 };
 use futures::prelude::*;
 use libp2p::{
@@ -67,7 +78,10 @@ use std::{
     collections::HashMap,
     time::Duration,
 };
-use tokio::sync::broadcast;
+use tokio::{
+    sync::broadcast,
+    time::Instant,
+};
 use tracing::{
     debug,
     warn,
@@ -109,7 +123,7 @@ pub struct FuelP2PService {
     /// Whenever we're done processing the request, it's removed from this table,
     /// and the channel is used to send the result to libp2p, which will forward it
     /// to the peer that requested it.
-    inbound_requests_table: HashMap<InboundRequestId, ResponseChannel<ResponseMessage>>,
+    inbound_requests_table: HashMap<InboundRequestId, ResponseChannel<NetworkResponse>>,
 
     /// NetworkCodec used as `<GossipsubCodec>` for encoding and decoding of Gossipsub messages    
     network_codec: PostcardCodec,
@@ -122,6 +136,9 @@ pub struct FuelP2PService {
 
     /// Holds peers' information, and manages existing connections
     peer_manager: PeerManager,
+
+    /// Reports the telemetry of the network
+    telemetry: TelemetrySender,
 }
 
 #[derive(Debug)]
@@ -151,6 +168,7 @@ pub enum FuelP2PEvent {
         message: FuelGossipsubMessage,
     },
     InboundRequestMessage {
+        peer_id: PeerId,
         request_id: InboundRequestId,
         request_message: RequestMessage,
     },
@@ -170,6 +188,8 @@ impl FuelP2PService {
     ) -> Self {
         let gossipsub_data =
             GossipsubData::with_topics(GossipsubTopics::new(&config.network_name));
+        // The service reports its telemetry to the same task as the codec.
+        let telemetry = codec.telemetry().clone();
         let network_metadata = NetworkMetadata { gossipsub_data };
 
         // configure and build P2P Service
@@ -221,6 +241,7 @@ impl FuelP2PService {
                 connection_state,
                 config.max_peers_connected as usize,
             ),
+            telemetry,
         }
     }
 
@@ -286,10 +307,19 @@ impl FuelP2PService {
             .get_gossipsub_topic(&message);
 
         match self.network_codec.encode(message) {
-            Ok(encoded_data) => self
-                .swarm
-                .behaviour_mut()
-                .publish_message(topic, encoded_data),
+            Ok(encoded_data) => {
+                let size = encoded_data.len();
+                let message_id = self
+                    .swarm
+                    .behaviour_mut()
+                    .publish_message(topic, encoded_data)?;
+                self.telemetry.bandwidth(
+                    NetworkProtocol::Gossipsub,
+                    Direction::Outbound,
+                    size,
+                );
+                Ok(message_id)
+            }
             Err(e) => Err(PublishError::TransformFailed(e)),
         }
     }
@@ -328,31 +358,42 @@ impl FuelP2PService {
         Ok(request_id)
     }
 
-    /// Sends ResponseMessage to a peer that requested the data
+    /// Sends ResponseMessage to a peer that requested the data.
+    /// Returns the size of the encoded response.
     pub fn send_response_msg(
         &mut self,
         request_id: InboundRequestId,
         message: ResponseMessage,
-    ) -> Result<(), ResponseSendError> {
+    ) -> Result<usize, ResponseSendError> {
         let Some(channel) = self.inbound_requests_table.remove(&request_id) else {
             debug!("ResponseChannel for {:?} does not exist!", request_id);
             return Err(ResponseSendError::ResponseChannelDoesNotExist);
         };
 
+        let Ok(encoded) = self.network_codec.encode_response(&message) else {
+            debug!("Failed to encode ResponseMessage for {:?}", request_id);
+            return Err(ResponseSendError::ConversionToIntermediateFailed);
+        };
+        let size = encoded.len();
+
         if self
             .swarm
             .behaviour_mut()
-            .send_response_msg(channel, message)
+            .send_response_msg(channel, NetworkResponse::Encoded(encoded))
             .is_err()
         {
             debug!("Failed to send ResponseMessage for {:?}", request_id);
             return Err(ResponseSendError::SendingResponseFailed);
         }
 
-        Ok(())
+        Ok(size)
     }
 
     pub fn update_block_height(&mut self, block_height: BlockHeight) {
+        self.telemetry.send(TelemetryEvent::LocalBlockHeight {
+            height: block_height,
+            at: Instant::now(),
+        });
         self.swarm.behaviour_mut().update_block_height(block_height)
     }
 
@@ -365,6 +406,12 @@ impl FuelP2PService {
         propagation_source: PeerId,
         mut acceptance: MessageAcceptance,
     ) {
+        self.telemetry.send(TelemetryEvent::TransactionValidated {
+            message_id: msg_id.clone(),
+            forwarded: matches!(acceptance, MessageAcceptance::Accept),
+            at: Instant::now(),
+        });
+
         // Even invalid transactions shouldn't affect reserved peer reputation.
         if let MessageAcceptance::Reject = acceptance {
             if self.peer_manager.is_reserved(&propagation_source) {
@@ -438,6 +485,37 @@ impl FuelP2PService {
                 );
                 None
             }
+            SwarmEvent::ConnectionEstablished {
+                peer_id,
+                endpoint,
+                num_established,
+                ..
+            } => {
+                // The direction of the peer is defined by its first connection.
+                if num_established.get() == 1 {
+                    let direction = if endpoint.is_dialer() {
+                        Direction::Outbound
+                    } else {
+                        Direction::Inbound
+                    };
+                    self.telemetry.send(TelemetryEvent::ConnectionEstablished {
+                        peer_id,
+                        direction,
+                    });
+                }
+                None
+            }
+            SwarmEvent::ConnectionClosed {
+                peer_id,
+                num_established,
+                ..
+            } => {
+                if num_established == 0 {
+                    self.telemetry
+                        .send(TelemetryEvent::ConnectionClosed { peer_id });
+                }
+                None
+            }
             _ => None,
         }
     }
@@ -472,6 +550,11 @@ impl FuelP2PService {
             message_id,
         } = event
         {
+            self.telemetry.bandwidth(
+                NetworkProtocol::Gossipsub,
+                Direction::Inbound,
+                message.data.len(),
+            );
             if let Some(correct_topic) = self
                 .network_metadata
                 .gossipsub_data
@@ -480,6 +563,10 @@ impl FuelP2PService {
             {
                 match self.network_codec.decode(&message.data, correct_topic) {
                     Ok(decoded_message) => {
+                        self.telemetry.send(TelemetryEvent::TransactionReceived {
+                            message_id: message_id.clone(),
+                            at: Instant::now(),
+                        });
                         return Some(FuelP2PEvent::GossipsubMessage {
                             peer_id: propagation_source,
                             message_id,
@@ -510,7 +597,8 @@ impl FuelP2PService {
     ) -> Option<FuelP2PEvent> {
         match event {
             PeerReportEvent::PerformDecay => {
-                self.peer_manager.batch_update_score_with_decay()
+                self.peer_manager.batch_update_score_with_decay();
+                self.report_mesh();
             }
             PeerReportEvent::CheckReservedNodesHealth => {
                 let disconnected_peers: Vec<_> = self
@@ -548,9 +636,30 @@ impl FuelP2PService {
         None
     }
 
+    /// Reports the gossipsub mesh of each topic to the telemetry.
+    fn report_mesh(&self) {
+        let mesh = self
+            .network_metadata
+            .gossipsub_data
+            .topics
+            .topics()
+            .into_iter()
+            .map(|(topic, hash)| TopicMesh {
+                topic: topic.to_string(),
+                peers: self
+                    .swarm
+                    .behaviour()
+                    .mesh_peers(hash)
+                    .map(|peer_id| FuelPeerId::from(peer_id.to_bytes()))
+                    .collect(),
+            })
+            .collect();
+        self.telemetry.send(TelemetryEvent::Mesh(mesh));
+    }
+
     fn handle_request_response_event(
         &mut self,
-        event: request_response::Event<RequestMessage, ResponseMessage>,
+        event: request_response::Event<RequestMessage, NetworkResponse>,
     ) -> Option<FuelP2PEvent> {
         match event {
             request_response::Event::Message { peer, message } => match message {
@@ -562,6 +671,7 @@ impl FuelP2PService {
                     self.inbound_requests_table.insert(request_id, channel);
 
                     return Some(FuelP2PEvent::InboundRequestMessage {
+                        peer_id: peer,
                         request_id,
                         request_message: request,
                     });
@@ -576,6 +686,22 @@ impl FuelP2PService {
                         return None;
                     };
 
+                    let response = match response {
+                        NetworkResponse::Message(response) => response,
+                        NetworkResponse::Encoded(encoded) => {
+                            match self.network_codec.decode_response(&encoded) {
+                                Ok(response) => response,
+                                Err(e) => {
+                                    warn!(
+                                        "Failed to decode response for {:?}: {e}",
+                                        request_id
+                                    );
+                                    return None;
+                                }
+                            }
+                        }
+                    };
+
                     let send_ok = match channel {
                         ResponseSender::SealedHeaders(c) => match response {
                             ResponseMessage::SealedHeaders(v) => {
@@ -601,6 +727,30 @@ impl FuelP2PService {
                                 c.send((peer, Err(ResponseError::TypeMismatch))).is_ok()
                             }
                         },
+                        ResponseSender::TxPoolSummary(c) => match response {
+                            ResponseMessage::TxPoolSummary(v) => {
+                                c.send((peer, Ok(v))).is_ok()
+                            }
+                            _ => {
+                                warn!(
+                                    "Invalid response type received for request {:?}",
+                                    request_id
+                                );
+                                c.send((peer, Err(ResponseError::TypeMismatch))).is_ok()
+                            }
+                        },
+                        ResponseSender::PooledTransactions(c) => match response {
+                            ResponseMessage::PooledTransactions(v) => {
+                                c.send((peer, Ok(v))).is_ok()
+                            }
+                            _ => {
+                                warn!(
+                                    "Invalid response type received for request {:?}",
+                                    request_id
+                                );
+                                c.send((peer, Err(ResponseError::TypeMismatch))).is_ok()
+                            }
+                        },
                     };
 
                     if !send_ok {
@@ -633,6 +783,12 @@ impl FuelP2PService {
                         ResponseSender::Transactions(c) => {
                             let _ = c.send((peer, Err(ResponseError::P2P(error))));
                         }
+                        ResponseSender::TxPoolSummary(c) => {
+                            let _ = c.send((peer, Err(ResponseError::P2P(error))));
+                        }
+                        ResponseSender::PooledTransactions(c) => {
+                            let _ = c.send((peer, Err(ResponseError::P2P(error))));
+                        }
                     };
                 }
             }
@@ -690,6 +846,10 @@ impl FuelP2PService {
         } = event;
         self.peer_manager
             .handle_peer_info_updated(&peer_id, latest_block_height);
+        self.telemetry.send(TelemetryEvent::PeerBlockHeight {
+            height: latest_block_height,
+            at: Instant::now(),
+        });
 
         Some(FuelP2PEvent::PeerInfoUpdated {
             peer_id,
@@ -727,6 +887,7 @@ mod tests {
             ResponseSender,
         },
         service::to_message_acceptance,
+        telemetry,
     };
     use fuel_core_types::{
         blockchain::{
@@ -743,6 +904,7 @@ mod tests {
         },
         services::p2p::{
             GossipsubMessageAcceptance,
+            ShortTxId,
             Transactions,
         },
     };
@@ -1189,6 +1351,45 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    #[instrument]
+    async fn telemetry_reports_directions_of_connections() {
+        use fuel_core_services::Service;
+
+        // Node A
+        let mut p2p_config =
+            Config::default_initialized("telemetry_reports_directions_of_connections");
+        p2p_config.keypair = Keypair::generate_secp256k1();
+        let (network_info, mut network_info_a) =
+            watch::channel(telemetry::empty_network_info());
+        let (telemetry, telemetry_task) =
+            telemetry::new_service(Arc::new(network_info), false);
+        telemetry_task.start_and_await().await.unwrap();
+        let (sender, _) = broadcast::channel(1);
+        let codec =
+            PostcardCodec::new(p2p_config.max_block_size).with_telemetry(telemetry);
+        let mut node_a = FuelP2PService::new(sender, p2p_config.clone(), codec);
+        node_a.start().await.unwrap();
+
+        // Node B dials Node A
+        p2p_config.bootstrap_nodes = node_a.multiaddrs();
+        let mut node_b = build_service_from_config(p2p_config).await;
+
+        loop {
+            tokio::select! {
+                _ = node_a.next_event() => {},
+                _ = node_b.next_event() => {},
+                _ = network_info_a.changed() => {
+                    let info = network_info_a.borrow().clone();
+                    if info.inbound_peers == 1 {
+                        assert_eq!(info.outbound_peers, 0);
+                        break
+                    }
+                }
+            }
+        }
+    }
+
     // Simulates 2 p2p nodes that connect to each other and consequently exchange Peer Info
     // On successful connection, node B updates its latest BlockHeight
     // and shares it with Peer A via Heartbeat protocol
@@ -1572,6 +1773,40 @@ mod tests {
                                             }
                                         });
                                     }
+                                    RequestMessage::TxPoolSummary => {
+                                        let (tx_orchestrator, rx_orchestrator) = oneshot::channel();
+                                        assert!(node_a.send_request_msg(None, request_msg.clone(), ResponseSender::TxPoolSummary(tx_orchestrator)).is_ok());
+                                        let tx_test_end = tx_test_end.clone();
+
+                                        tokio::spawn(async move {
+                                            let response_message = rx_orchestrator.await;
+
+                                            if let Ok((_, Ok(Some(summary)))) = response_message {
+                                                let check = summary == vec![ShortTxId([1; ShortTxId::LEN])];
+                                                let _ = tx_test_end.send(check).await;
+                                            } else {
+                                                tracing::error!("Orchestrator failed to receive a message: {:?}", response_message);
+                                                let _ = tx_test_end.send(false).await;
+                                            }
+                                        });
+                                    }
+                                    RequestMessage::PooledTransactions(tx_ids) => {
+                                        let (tx_orchestrator, rx_orchestrator) = oneshot::channel();
+                                        assert!(node_a.send_request_msg(None, request_msg.clone(), ResponseSender::PooledTransactions(tx_orchestrator)).is_ok());
+                                        let tx_test_end = tx_test_end.clone();
+
+                                        tokio::spawn(async move {
+                                            let response_message = rx_orchestrator.await;
+
+                                            if let Ok((_, Ok(Some(transactions)))) = response_message {
+                                                let check = transactions.len() == tx_ids.len();
+                                                let _ = tx_test_end.send(check).await;
+                                            } else {
+                                                tracing::error!("Orchestrator failed to receive a message: {:?}", response_message);
+                                                let _ = tx_test_end.send(false).await;
+                                            }
+                                        });
+                                    }
                                 }
                             }
                         }
@@ -1581,7 +1816,7 @@ mod tests {
                 },
                 node_b_event = node_b.next_event() => {
                     // 2. Node B receives the RequestMessage from Node A initiated by the NetworkOrchestrator
-                    if let Some(FuelP2PEvent::InboundRequestMessage{ request_id, request_message: received_request_message }) = &node_b_event {
+                    if let Some(FuelP2PEvent::InboundRequestMessage{ request_id, request_message: received_request_message, .. }) = &node_b_event {
                         match received_request_message {
                             RequestMessage::SealedHeaders(range) => {
                                 let sealed_headers: Vec<_> = arbitrary_headers_for_range(range.clone());
@@ -1593,6 +1828,14 @@ mod tests {
                                 let transactions = vec![Transactions(txs)];
                                 let _ = node_b.send_response_msg(*request_id, ResponseMessage::Transactions(Some(transactions)));
                             }
+                            RequestMessage::TxPoolSummary => {
+                                let summary = vec![ShortTxId([1; ShortTxId::LEN])];
+                                let _ = node_b.send_response_msg(*request_id, ResponseMessage::TxPoolSummary(Some(summary)));
+                            }
+                            RequestMessage::PooledTransactions(tx_ids) => {
+                                let txs = tx_ids.iter().map(|_| Transaction::default_test_tx()).collect();
+                                let _ = node_b.send_response_msg(*request_id, ResponseMessage::PooledTransactions(Some(txs)));
+                            }
                         }
                     }
 
@@ -1609,6 +1852,22 @@ mod tests {
         request_response_works_with(RequestMessage::Transactions(arbitrary_range)).await
     }
 
+    #[tokio::test]
+    #[instrument]
+    async fn request_response_works_with_tx_pool_summary() {
+        request_response_works_with(RequestMessage::TxPoolSummary).await
+    }
+
+    #[tokio::test]
+    #[instrument]
+    async fn request_response_works_with_pooled_transactions() {
+        let tx_ids = vec![
+            ShortTxId([1; ShortTxId::LEN]),
+            ShortTxId([2; ShortTxId::LEN]),
+        ];
+        request_response_works_with(RequestMessage::PooledTransactions(tx_ids)).await
+    }
+
     #[tokio::test]
     #[instrument]
     async fn request_response_works_with_sealed_headers_range_inclusive() {
@@ -1682,7 +1941,7 @@ mod tests {
                 },
                 node_b_event = node_b.next_event() => {
                     // 2. Node B receives the RequestMessage from Node A initiated by the NetworkOrchestrator
-                    if let Some(FuelP2PEvent::InboundRequestMessage{ request_id, request_message: _ }) = &node_b_event {
+                    if let Some(FuelP2PEvent::InboundRequestMessage{ request_id, .. }) = &node_b_event {
                         let sealed_headers: Vec<_> = arbitrary_headers_for_range(1..3);
                         let _ = node_b.send_response_msg(*request_id, ResponseMessage::SealedHeaders(Some(sealed_headers)));
                     }
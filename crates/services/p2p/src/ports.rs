@@ -5,6 +5,10 @@ use fuel_core_types::{
         consensus::Genesis,
         SealedBlockHeader,
     },
+    fuel_tx::{
+        Transaction,
+        TxId,
+    },
     fuel_types::BlockHeight,
     services::p2p::Transactions,
 };
@@ -24,6 +28,14 @@ pub trait P2pDb: Send + Sync {
     fn get_genesis(&self) -> StorageResult<Genesis>;
 }
 
+pub trait TxPool: Send + Sync {
+    /// Returns ids of at most `max_txs` transactions from the pool.
+    fn tx_ids(&self, max_txs: usize) -> Vec<TxId>;
+
+    /// Returns transactions from the pool. Transactions that are not in the pool are skipped.
+    fn transactions(&self, tx_ids: &[TxId]) -> Vec<Transaction>;
+}
+
 pub trait BlockHeightImporter: Send + Sync {
     /// Creates a stream of next block heights
     fn next_block_height(&self) -> BoxStream<BlockHeight>;
@@ -0,0 +1,222 @@
+//! The accounting of the request-response usage by each peer.
+//!
+//! The node tracks the number of blocks and bytes served to each peer within
+//! a fixed time window. When the peer reaches any ceiling, its requests are answered
+//! with empty responses until the window ends. It prevents a single peer mass-downloading
+//! the history from degrading the service to other peers.
+
+use libp2p::PeerId;
+use std::{
+    collections::HashMap,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+/// The ceilings of the usage of the request-response protocol by a single peer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PeerRequestLimits {
+    /// The duration of the window within which the usage is accounted.
+    pub window: Duration,
+    /// The maximum number of blocks served to the peer within the window.
+    /// Headers and transactions of the same block are accounted separately.
+    pub max_blocks: u64,
+    /// The maximum number of bytes served to the peer within the window.
+    pub max_bytes: u64,
+}
+
+/// The usage of the request-response protocol by the peer within the current window.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PeerUsage {
+    /// The number of served blocks.
+    pub blocks: u64,
+    /// The number of served bytes.
+    pub bytes: u64,
+}
+
+struct Window {
+    started_at: Instant,
+    usage: PeerUsage,
+}
+
+/// Tracks the usage of each peer. Doesn't limit anything without the limits.
+pub(crate) struct RequestAccounting {
+    limits: Option<PeerRequestLimits>,
+    peers: HashMap<PeerId, Window>,
+}
+
+impl RequestAccounting {
+    pub(crate) fn new(limits: Option<PeerRequestLimits>) -> Self {
+        Self {
+            limits,
+            peers: HashMap::new(),
+        }
+    }
+
+    /// Accounts `blocks` for the request of the peer. Returns `false` without
+    /// accounting if serving the request would exceed the limits of the peer.
+    pub(crate) fn try_serve(&mut self, peer_id: &PeerId, blocks: u64) -> bool {
+        self.try_serve_at(peer_id, blocks, Instant::now())
+    }
+
+    /// Accounts `bytes` served to the peer.
+    pub(crate) fn record_bytes(&mut self, peer_id: &PeerId, bytes: u64) {
+        self.record_bytes_at(peer_id, bytes, Instant::now())
+    }
+
+    /// Drops the usage of peers whose window is over.
+    pub(crate) fn prune(&mut self) {
+        self.prune_at(Instant::now())
+    }
+
+    fn try_serve_at(&mut self, peer_id: &PeerId, blocks: u64, now: Instant) -> bool {
+        let Some(limits) = self.limits else {
+            return true
+        };
+        let usage = &mut self.window(peer_id, &limits, now).usage;
+        if usage.bytes >= limits.max_bytes
+            || usage.blocks.saturating_add(blocks) > limits.max_blocks
+        {
+            return false
+        }
+        usage.blocks = usage.blocks.saturating_add(blocks);
+        true
+    }
+
+    fn record_bytes_at(&mut self, peer_id: &PeerId, bytes: u64, now: Instant) {
+        let Some(limits) = self.limits else { return };
+        let usage = &mut self.window(peer_id, &limits, now).usage;
+        usage.bytes = usage.bytes.saturating_add(bytes);
+    }
+
+    fn prune_at(&mut self, now: Instant) {
+        let Some(limits) = self.limits else { return };
+        self.peers.retain(|_, window| {
+            now.saturating_duration_since(window.started_at) < limits.window
+        });
+    }
+
+    fn window(
+        &mut self,
+        peer_id: &PeerId,
+        limits: &PeerRequestLimits,
+        now: Instant,
+    ) -> &mut Window {
+        let window = self.peers.entry(*peer_id).or_insert_with(|| Window {
+            started_at: now,
+            usage: PeerUsage::default(),
+        });
+        if now.saturating_duration_since(window.started_at) >= limits.window {
+            window.started_at = now;
+            window.usage = PeerUsage::default();
+        }
+        window
+    }
+
+    #[cfg(test)]
+    fn usage(&self, peer_id: &PeerId) -> PeerUsage {
+        self.peers
+            .get(peer_id)
+            .map(|window| window.usage)
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    use super::*;
+
+    fn accounting(max_blocks: u64, max_bytes: u64) -> RequestAccounting {
+        RequestAccounting::new(Some(PeerRequestLimits {
+            window: Duration::from_secs(60),
+            max_blocks,
+            max_bytes,
+        }))
+    }
+
+    #[test]
+    fn try_serve__rejects_requests_above_max_blocks() {
+        let mut accounting = accounting(10, u64::MAX);
+        let peer_id = PeerId::random();
+        let now = Instant::now();
+
+        assert!(accounting.try_serve_at(&peer_id, 6, now));
+        assert!(!accounting.try_serve_at(&peer_id, 5, now));
+        assert!(accounting.try_serve_at(&peer_id, 4, now));
+        assert!(!accounting.try_serve_at(&peer_id, 1, now));
+
+        assert_eq!(
+            accounting.usage(&peer_id),
+            PeerUsage {
+                blocks: 10,
+                bytes: 0
+            }
+        );
+    }
+
+    #[test]
+    fn try_serve__rejects_requests_after_max_bytes() {
+        let mut accounting = accounting(u64::MAX, 1000);
+        let peer_id = PeerId::random();
+        let now = Instant::now();
+
+        assert!(accounting.try_serve_at(&peer_id, 1, now));
+        accounting.record_bytes_at(&peer_id, 999, now);
+        assert!(accounting.try_serve_at(&peer_id, 1, now));
+        accounting.record_bytes_at(&peer_id, 1, now);
+
+        assert!(!accounting.try_serve_at(&peer_id, 0, now));
+    }
+
+    #[test]
+    fn try_serve__resets_usage_in_next_window() {
+        let mut accounting = accounting(1, u64::MAX);
+        let peer_id = PeerId::random();
+        let now = Instant::now();
+        assert!(accounting.try_serve_at(&peer_id, 1, now));
+        assert!(!accounting.try_serve_at(&peer_id, 1, now));
+
+        let later = now + Duration::from_secs(60);
+
+        assert!(accounting.try_serve_at(&peer_id, 1, later));
+    }
+
+    #[test]
+    fn try_serve__limits_peers_independently() {
+        let mut accounting = accounting(1, u64::MAX);
+        let peer_id = PeerId::random();
+        let other_peer_id = PeerId::random();
+        let now = Instant::now();
+        assert!(accounting.try_serve_at(&peer_id, 1, now));
+
+        assert!(!accounting.try_serve_at(&peer_id, 1, now));
+        assert!(accounting.try_serve_at(&other_peer_id, 1, now));
+    }
+
+    #[test]
+    fn try_serve__allows_everything_without_limits() {
+        let mut accounting = RequestAccounting::new(None);
+        let peer_id = PeerId::random();
+
+        accounting.record_bytes(&peer_id, u64::MAX);
+
+        assert!(accounting.try_serve(&peer_id, u64::MAX));
+        assert_eq!(accounting.usage(&peer_id), PeerUsage::default());
+    }
+
+    #[test]
+    fn prune__drops_expired_windows() {
+        let mut accounting = accounting(1, u64::MAX);
+        let peer_id = PeerId::random();
+        let now = Instant::now();
+        assert!(accounting.try_serve_at(&peer_id, 1, now));
+
+        accounting.prune_at(now + Duration::from_secs(30));
+        assert_eq!(accounting.peers.len(), 1);
+        accounting.prune_at(now + Duration::from_secs(60));
+
+        assert!(accounting.peers.is_empty());
+    }
+}
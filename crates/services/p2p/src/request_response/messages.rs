@@ -1,6 +1,10 @@
 use fuel_core_types::{
     blockchain::SealedBlockHeader,
-    services::p2p::Transactions,
+    fuel_tx::Transaction,
+    services::p2p::{
+        ShortTxId,
+        Transactions,
+    },
 };
 use libp2p::{
     request_response::OutboundFailure,
@@ -14,22 +18,47 @@ use std::ops::Range;
 use thiserror::Error;
 use tokio::sync::oneshot;
 
-pub(crate) const REQUEST_RESPONSE_PROTOCOL_ID: &str = "/fuel/req_res/0.0.1";
+/// The version is bumped on each change of the encoding of the messages, so peers with
+/// incompatible codecs don't negotiate the protocol.
+pub(crate) const REQUEST_RESPONSE_PROTOCOL_ID: &str = "/fuel/req_res/0.0.2";
 
-/// Max Size in Bytes of the Request Message
-#[cfg(test)]
-pub(crate) const MAX_REQUEST_SIZE: usize = core::mem::size_of::<RequestMessage>();
+/// Max number of transactions in the summary of the pool sent to the peer.
+pub const MAX_TX_POOL_SUMMARY_SIZE: usize = 16384;
+
+/// Max number of transactions requested from the pool of the peer in one request.
+pub const MAX_POOLED_TRANSACTIONS_PER_REQUEST: usize = 64;
+
+/// Max Size in Bytes of the encoded Request Message.
+/// It fits the request of [`MAX_POOLED_TRANSACTIONS_PER_REQUEST`] transactions.
+pub const MAX_REQUEST_SIZE: usize = 1024;
 
 #[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Clone)]
 pub enum RequestMessage {
     SealedHeaders(Range<u32>),
     Transactions(Range<u32>),
+    /// Requests short ids of all transactions in the pool of the peer.
+    TxPoolSummary,
+    /// Requests transactions from the pool of the peer by their short ids.
+    PooledTransactions(Vec<ShortTxId>),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ResponseMessage {
     SealedHeaders(Option<Vec<SealedBlockHeader>>),
     Transactions(Option<Vec<Transactions>>),
+    TxPoolSummary(Option<Vec<ShortTxId>>),
+    PooledTransactions(Option<Vec<Transaction>>),
+}
+
+/// The response of the request-response protocol on the wire.
+/// The node encodes outbound responses in advance to know their size,
+/// and the codec writes them to the peer as is.
+#[derive(Debug, Clone)]
+pub enum NetworkResponse {
+    /// The response decoded from the peer.
+    Message(ResponseMessage),
+    /// The encoded response for the peer.
+    Encoded(Vec<u8>),
 }
 
 pub type OnResponse<T> = oneshot::Sender<(PeerId, Result<T, ResponseError>)>;
@@ -38,6 +67,8 @@ pub type OnResponse<T> = oneshot::Sender<(PeerId, Result<T, ResponseError>)>;
 pub enum ResponseSender {
     SealedHeaders(OnResponse<Option<Vec<SealedBlockHeader>>>),
     Transactions(OnResponse<Option<Vec<Transactions>>>),
+    TxPoolSummary(OnResponse<Option<Vec<ShortTxId>>>),
+    PooledTransactions(OnResponse<Option<Vec<Transaction>>>),
 }
 
 #[derive(Debug, Error)]
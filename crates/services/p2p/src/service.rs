@@ -16,19 +16,26 @@ use crate::{
     ports::{
         BlockHeightImporter,
         P2pDb,
+        TxPool,
     },
+    request_accounting::RequestAccounting,
     request_response::messages::{
         OnResponse,
         RequestMessage,
         ResponseMessage,
         ResponseSender,
+        MAX_POOLED_TRANSACTIONS_PER_REQUEST,
+        MAX_TX_POOL_SUMMARY_SIZE,
     },
+    telemetry,
 };
 use anyhow::anyhow;
 use fuel_core_services::{
     stream::BoxStream,
+    HealthReporter,
     RunnableService,
     RunnableTask,
+    Service as _,
     ServiceRunner,
     StateWatcher,
 };
@@ -43,18 +50,26 @@ use fuel_core_types::{
         BlockHeight,
         ChainId,
     },
-    services::p2p::{
-        peer_reputation::{
-            AppScore,
-            PeerReport,
+    services::{
+        p2p::{
+            peer_reputation::{
+                AppScore,
+                PeerReport,
+            },
+            BlockHeightHeartbeatData,
+            GossipData,
+            GossipsubMessageAcceptance,
+            GossipsubMessageInfo,
+            NetworkInfo,
+            PeerId as FuelPeerId,
+            ShortTxId,
+            TransactionGossipData,
+            Transactions,
+        },
+        port::{
+            PortError,
+            PortResult,
         },
-        BlockHeightHeartbeatData,
-        GossipData,
-        GossipsubMessageAcceptance,
-        GossipsubMessageInfo,
-        PeerId as FuelPeerId,
-        TransactionGossipData,
-        Transactions,
     },
 };
 use futures::{
@@ -67,6 +82,7 @@ use libp2p::{
     PeerId,
 };
 use std::{
+    collections::HashMap,
     fmt::Debug,
     ops::Range,
     sync::Arc,
@@ -76,6 +92,7 @@ use tokio::{
         broadcast,
         mpsc,
         oneshot,
+        watch,
     },
     time::{
         Duration,
@@ -84,9 +101,9 @@ use tokio::{
 };
 use tracing::warn;
 
-pub type Service<V> = ServiceRunner<UninitializedTask<V, SharedState>>;
+pub type Service<V, T> = ServiceRunner<UninitializedTask<V, SharedState, T>>;
 
-enum TaskRequest {
+pub enum TaskRequest {
     // Broadcast requests to p2p network
     BroadcastTransaction(Arc<Transaction>),
     // Request to get one-off data from p2p network
@@ -104,6 +121,15 @@ enum TaskRequest {
         from_peer: PeerId,
         channel: OnResponse<Option<Vec<Transactions>>>,
     },
+    GetTxPoolSummary {
+        from_peer: PeerId,
+        channel: OnResponse<Option<Vec<ShortTxId>>>,
+    },
+    GetPooledTransactions {
+        from_peer: PeerId,
+        tx_ids: Vec<ShortTxId>,
+        channel: OnResponse<Option<Vec<Transaction>>>,
+    },
     // Responds back to the p2p network
     RespondWithGossipsubMessageReport((GossipsubMessageInfo, GossipsubMessageAcceptance)),
     RespondWithPeerReport {
@@ -113,6 +139,28 @@ enum TaskRequest {
     },
 }
 
+/// Receives requests of the [`Task`]. The requests related to blocks are received
+/// via a separate queue with the strict priority over other requests, so the flood of
+/// transactions doesn't delay the synchronization of blocks. The inbound requests
+/// and responses of blocks are prioritized over gossip by the `FuelBehaviour`.
+pub struct TaskRequestReceiver {
+    /// Requests of sealed headers and transactions of blocks.
+    block_requests: mpsc::Receiver<TaskRequest>,
+    /// All other requests, like broadcasting and reporting of transactions.
+    requests: mpsc::Receiver<TaskRequest>,
+}
+
+impl TaskRequestReceiver {
+    /// Returns the next request. The pending block request is always returned first.
+    pub async fn recv(&mut self) -> Option<TaskRequest> {
+        tokio::select! {
+            biased;
+            Some(request) = self.block_requests.recv() => Some(request),
+            request = self.requests.recv() => request,
+        }
+    }
+}
+
 impl Debug for TaskRequest {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -128,6 +176,12 @@ impl Debug for TaskRequest {
             TaskRequest::GetTransactions { .. } => {
                 write!(f, "TaskRequest::GetTransactions")
             }
+            TaskRequest::GetTxPoolSummary { .. } => {
+                write!(f, "TaskRequest::GetTxPoolSummary")
+            }
+            TaskRequest::GetPooledTransactions { .. } => {
+                write!(f, "TaskRequest::GetPooledTransactions")
+            }
             TaskRequest::RespondWithGossipsubMessageReport(_) => {
                 write!(f, "TaskRequest::RespondWithGossipsubMessageReport")
             }
@@ -170,7 +224,7 @@ pub trait TaskP2PService: Send {
         &mut self,
         request_id: InboundRequestId,
         message: ResponseMessage,
-    ) -> anyhow::Result<()>;
+    ) -> anyhow::Result<usize>;
 
     fn report_message(
         &mut self,
@@ -227,9 +281,8 @@ impl TaskP2PService for FuelP2PService {
         &mut self,
         request_id: InboundRequestId,
         message: ResponseMessage,
-    ) -> anyhow::Result<()> {
-        self.send_response_msg(request_id, message)?;
-        Ok(())
+    ) -> anyhow::Result<usize> {
+        Ok(self.send_response_msg(request_id, message)?)
     }
 
     fn report_message(
@@ -271,6 +324,8 @@ pub trait Broadcast: Send {
     ) -> anyhow::Result<()>;
 
     fn tx_broadcast(&self, transaction: TransactionGossipData) -> anyhow::Result<()>;
+
+    fn new_peer_broadcast(&self, peer_id: FuelPeerId) -> anyhow::Result<()>;
 }
 
 impl Broadcast for SharedState {
@@ -295,28 +350,36 @@ impl Broadcast for SharedState {
         self.tx_broadcast.send(transaction)?;
         Ok(())
     }
+
+    fn new_peer_broadcast(&self, peer_id: FuelPeerId) -> anyhow::Result<()> {
+        self.new_peers_broadcast.send(peer_id)?;
+        Ok(())
+    }
 }
 
 /// Uninitialized task for the p2p that can be upgraded later into [`Task`].
-pub struct UninitializedTask<V, B> {
+pub struct UninitializedTask<V, B, T> {
     chain_id: ChainId,
     view_provider: V,
+    tx_pool: T,
     next_block_height: BoxStream<BlockHeight>,
     /// Receive internal Task Requests
-    request_receiver: mpsc::Receiver<TaskRequest>,
+    request_receiver: TaskRequestReceiver,
     broadcast: B,
     config: Config<NotInitialized>,
+    health: HealthReporter,
 }
 
 /// Orchestrates various p2p-related events between the inner `P2pService`
 /// and the top level `NetworkService`.
-pub struct Task<P, V, B> {
+pub struct Task<P, V, B, T> {
     chain_id: ChainId,
     p2p_service: P,
     view_provider: V,
+    tx_pool: T,
     next_block_height: BoxStream<BlockHeight>,
     /// Receive internal Task Requests
-    request_receiver: mpsc::Receiver<TaskRequest>,
+    request_receiver: TaskRequestReceiver,
     broadcast: B,
     max_headers_per_request: u32,
     // milliseconds wait time between peer heartbeat reputation checks
@@ -325,6 +388,11 @@ pub struct Task<P, V, B> {
     heartbeat_max_time_since_last: Duration,
     next_check_time: Instant,
     heartbeat_peer_reputation_config: HeartbeatPeerReputationConfig,
+    /// The usage of the request-response protocol by peers.
+    request_accounting: RequestAccounting,
+    /// Aggregates the telemetry of the network.
+    telemetry_task_handle: ServiceRunner<telemetry::Task>,
+    health: HealthReporter,
 }
 
 #[derive(Clone)]
@@ -333,43 +401,72 @@ pub struct HeartbeatPeerReputationConfig {
     low_heartbeat_frequency_penalty: AppScore,
 }
 
-impl<V> UninitializedTask<V, SharedState> {
+/// Creates the shared state of the p2p service and the receiver of its requests.
+/// The shared state is available before the creation of the service, so services
+/// used by the p2p, like the transaction pool, can depend on it.
+pub fn build_shared_state(
+    config: &Config<NotInitialized>,
+) -> (SharedState, TaskRequestReceiver) {
+    let (request_sender, requests) = mpsc::channel(1024 * 10);
+    let (block_request_sender, block_requests) = mpsc::channel(1024);
+    let (tx_broadcast, _) = broadcast::channel(1024 * 10);
+    let (block_height_broadcast, _) = broadcast::channel(1024 * 10);
+    let (new_peers_broadcast, _) = broadcast::channel(1024);
+    let (network_info, _) = watch::channel(telemetry::empty_network_info());
+
+    let (reserved_peers_broadcast, _) = broadcast::channel::<usize>(
+        config
+            .reserved_nodes
+            .len()
+            .saturating_mul(2)
+            .saturating_add(1),
+    );
+
+    (
+        SharedState {
+            request_sender,
+            block_request_sender,
+            tx_broadcast,
+            reserved_peers_broadcast,
+            block_height_broadcast,
+            new_peers_broadcast,
+            network_info: Arc::new(network_info),
+        },
+        TaskRequestReceiver {
+            block_requests,
+            requests,
+        },
+    )
+}
+
+impl<V, T> UninitializedTask<V, SharedState, T> {
+    #[allow(clippy::too_many_arguments)]
     pub fn new<B: BlockHeightImporter>(
         chain_id: ChainId,
         config: Config<NotInitialized>,
+        shared_state: SharedState,
+        request_receiver: TaskRequestReceiver,
         view_provider: V,
         block_importer: B,
+        tx_pool: T,
+        health: HealthReporter,
     ) -> Self {
-        let (request_sender, request_receiver) = mpsc::channel(1024 * 10);
-        let (tx_broadcast, _) = broadcast::channel(1024 * 10);
-        let (block_height_broadcast, _) = broadcast::channel(1024 * 10);
-
-        let (reserved_peers_broadcast, _) = broadcast::channel::<usize>(
-            config
-                .reserved_nodes
-                .len()
-                .saturating_mul(2)
-                .saturating_add(1),
-        );
         let next_block_height = block_importer.next_block_height();
 
         Self {
             chain_id,
             view_provider,
+            tx_pool,
             next_block_height,
             request_receiver,
-            broadcast: SharedState {
-                request_sender,
-                tx_broadcast,
-                reserved_peers_broadcast,
-                block_height_broadcast,
-            },
+            broadcast: shared_state,
             config,
+            health,
         }
     }
 }
 
-impl<P: TaskP2PService, V, B: Broadcast> Task<P, V, B> {
+impl<P: TaskP2PService, V, B: Broadcast, T> Task<P, V, B, T> {
     fn peer_heartbeat_reputation_checks(&self) -> anyhow::Result<()> {
         for (peer_id, peer_info) in self.p2p_service.get_all_peer_info() {
             if peer_info.heartbeat_data.duration_since_last_heartbeat()
@@ -410,6 +507,82 @@ impl<P: TaskP2PService, V, B: Broadcast> Task<P, V, B> {
             .report_peer(peer_id, app_score, reporting_service)?;
         Ok(())
     }
+
+    /// Sends the response to the request of the peer and accounts its size.
+    fn send_response(
+        &mut self,
+        peer_id: &PeerId,
+        request_id: InboundRequestId,
+        message: ResponseMessage,
+    ) -> anyhow::Result<()> {
+        let size = self.p2p_service.send_response_msg(request_id, message)?;
+        self.request_accounting
+            .record_bytes(peer_id, u64::try_from(size).unwrap_or(u64::MAX));
+        Ok(())
+    }
+}
+
+/// Returns the number of blocks requested by the message.
+fn requested_blocks(request: &RequestMessage) -> u64 {
+    match request {
+        RequestMessage::SealedHeaders(range) | RequestMessage::Transactions(range) => {
+            u64::from(range.end.saturating_sub(range.start))
+        }
+        RequestMessage::TxPoolSummary | RequestMessage::PooledTransactions(_) => 0,
+    }
+}
+
+/// Returns `true` if the request asks for more blocks than the node serves at once.
+fn exceeds_max_range(request: &RequestMessage, max_len: u32) -> bool {
+    requested_blocks(request) > u64::from(max_len)
+}
+
+/// Returns the empty response to the throttled or invalid request.
+fn empty_response(request: &RequestMessage) -> ResponseMessage {
+    match request {
+        RequestMessage::SealedHeaders(_) => ResponseMessage::SealedHeaders(None),
+        RequestMessage::Transactions(_) => ResponseMessage::Transactions(None),
+        RequestMessage::TxPoolSummary => ResponseMessage::TxPoolSummary(None),
+        RequestMessage::PooledTransactions(_) => {
+            ResponseMessage::PooledTransactions(None)
+        }
+    }
+}
+
+/// Returns short ids of transactions in the pool.
+fn tx_pool_summary<T: TxPool>(tx_pool: &T) -> Vec<ShortTxId> {
+    tx_pool
+        .tx_ids(MAX_TX_POOL_SUMMARY_SIZE)
+        .iter()
+        .map(ShortTxId::from)
+        .collect()
+}
+
+/// Returns transactions of the pool with the short ids. Returns `None`
+/// if the number of requested transactions exceeds the limit.
+fn pooled_transactions<T: TxPool>(
+    tx_pool: &T,
+    short_ids: &[ShortTxId],
+) -> Option<Vec<Transaction>> {
+    if short_ids.len() > MAX_POOLED_TRANSACTIONS_PER_REQUEST {
+        tracing::error!(
+            "Requested too many pooled transactions. Requested length: {:?}, Max length: {:?}",
+            short_ids.len(),
+            MAX_POOLED_TRANSACTIONS_PER_REQUEST
+        );
+        return None
+    }
+
+    let tx_ids: HashMap<_, _> = tx_pool
+        .tx_ids(MAX_TX_POOL_SUMMARY_SIZE)
+        .into_iter()
+        .map(|tx_id| (ShortTxId::from(&tx_id), tx_id))
+        .collect();
+    let requested: Vec<_> = short_ids
+        .iter()
+        .filter_map(|short_id| tx_ids.get(short_id).copied())
+        .collect();
+    Some(tx_pool.transactions(&requested))
 }
 
 fn convert_peer_id(peer_id: &PeerId) -> anyhow::Result<FuelPeerId> {
@@ -418,15 +591,16 @@ fn convert_peer_id(peer_id: &PeerId) -> anyhow::Result<FuelPeerId> {
 }
 
 #[async_trait::async_trait]
-impl<V> RunnableService for UninitializedTask<V, SharedState>
+impl<V, T> RunnableService for UninitializedTask<V, SharedState, T>
 where
     V: AtomicView + 'static,
     V::View: P2pDb,
+    T: TxPool + 'static,
 {
     const NAME: &'static str = "P2P";
 
     type SharedData = SharedState;
-    type Task = Task<FuelP2PService, V, SharedState>;
+    type Task = Task<FuelP2PService, V, SharedState, T>;
     type TaskParams = ();
 
     fn shared_data(&self) -> Self::SharedData {
@@ -441,10 +615,12 @@ where
         let Self {
             chain_id,
             view_provider,
+            tx_pool,
             next_block_height,
             request_receiver,
             broadcast,
             config,
+            health,
         } = self;
 
         let view = view_provider.latest_view();
@@ -456,6 +632,8 @@ where
             heartbeat_check_interval,
             heartbeat_max_avg_interval,
             heartbeat_max_time_since_last,
+            peer_request_limits,
+            metrics,
             ..
         } = config;
 
@@ -466,10 +644,14 @@ where
             low_heartbeat_frequency_penalty: -5.,
         };
 
+        let (telemetry, telemetry_task_handle) =
+            telemetry::new_service(broadcast.network_info.clone(), metrics);
+        telemetry_task_handle.start_and_await().await?;
+
         let mut p2p_service = FuelP2PService::new(
             broadcast.reserved_peers_broadcast.clone(),
             config,
-            PostcardCodec::new(max_block_size),
+            PostcardCodec::new(max_block_size).with_telemetry(telemetry),
         );
         p2p_service.start().await?;
 
@@ -482,6 +664,7 @@ where
             chain_id,
             p2p_service,
             view_provider,
+            tx_pool,
             request_receiver,
             next_block_height,
             broadcast,
@@ -491,6 +674,9 @@ where
             heartbeat_max_time_since_last,
             next_check_time,
             heartbeat_peer_reputation_config,
+            request_accounting: RequestAccounting::new(peer_request_limits),
+            telemetry_task_handle,
+            health,
         };
         Ok(task)
     }
@@ -498,12 +684,13 @@ where
 
 // TODO: Add tests https://github.com/FuelLabs/fuel-core/issues/1275
 #[async_trait::async_trait]
-impl<P, V, B> RunnableTask for Task<P, V, B>
+impl<P, V, B, T> RunnableTask for Task<P, V, B, T>
 where
     P: TaskP2PService + 'static,
     V: AtomicView + 'static,
     V::View: P2pDb,
     B: Broadcast + 'static,
+    T: TxPool + 'static,
 {
     async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
         tracing::debug!("P2P task is running");
@@ -548,6 +735,16 @@ where
                         let request_msg = RequestMessage::Transactions(block_height_range);
                         self.p2p_service.send_request_msg(Some(from_peer), request_msg, channel).expect("We always a peer here, so send has a target");
                     }
+                    Some(TaskRequest::GetTxPoolSummary { from_peer, channel }) => {
+                        let channel = ResponseSender::TxPoolSummary(channel);
+                        let request_msg = RequestMessage::TxPoolSummary;
+                        self.p2p_service.send_request_msg(Some(from_peer), request_msg, channel).expect("We always have a peer here, so send has a target");
+                    }
+                    Some(TaskRequest::GetPooledTransactions { from_peer, tx_ids, channel }) => {
+                        let channel = ResponseSender::PooledTransactions(channel);
+                        let request_msg = RequestMessage::PooledTransactions(tx_ids);
+                        self.p2p_service.send_request_msg(Some(from_peer), request_msg, channel).expect("We always have a peer here, so send has a target");
+                    }
                     Some(TaskRequest::RespondWithGossipsubMessageReport((message, acceptance))) => {
                         // report_message(&mut self.p2p_service, message, acceptance);
                         self.p2p_service.report_message(message, acceptance)?;
@@ -569,7 +766,12 @@ where
             }
             p2p_event = self.p2p_service.next_event() => {
                 should_continue = true;
+                self.health.progress();
                 match p2p_event {
+                    Some(FuelP2PEvent::PeerConnected(peer_id)) => {
+                        let peer_id: Vec<u8> = peer_id.into();
+                        let _ = self.broadcast.new_peer_broadcast(peer_id.into());
+                    }
                     Some(FuelP2PEvent::PeerInfoUpdated { peer_id, block_height }) => {
                         let peer_id: Vec<u8> = peer_id.into();
                         let block_height_data = BlockHeightHeartbeatData {
@@ -589,44 +791,55 @@ where
                             },
                         }
                     },
-                    Some(FuelP2PEvent::InboundRequestMessage { request_message, request_id }) => {
-                        match request_message {
-                            RequestMessage::Transactions(range) => {
-                                let view = self.view_provider.latest_view();
-                                match view.get_transactions(range.clone()) {
-                                    Ok(response) => {
-                                        let _ = self.p2p_service.send_response_msg(request_id, ResponseMessage::Transactions(response));
-                                    },
-                                    Err(e) => {
-                                        tracing::error!("Failed to get transactions for range {:?}: {:?}", range, e);
-                                        let response = None;
-                                        let _ = self.p2p_service.send_response_msg(request_id, ResponseMessage::Transactions(response));
-                                        return Err(e.into())
+                    Some(FuelP2PEvent::InboundRequestMessage { peer_id, request_message, request_id }) => {
+                        if exceeds_max_range(&request_message, self.max_headers_per_request) {
+                            tracing::error!("Requested range is too big. Request: {:?}, Max length: {:?}", request_message, self.max_headers_per_request);
+                            // TODO: Return helpful error message to requester. https://github.com/FuelLabs/fuel-core/issues/1311
+                            let _ = self.send_response(&peer_id, request_id, empty_response(&request_message));
+                        } else if !self.request_accounting.try_serve(&peer_id, requested_blocks(&request_message)) {
+                            tracing::debug!("Peer {:?} exceeded its request limits, the request is throttled", peer_id);
+                            let _ = self.send_response(&peer_id, request_id, empty_response(&request_message));
+                        } else {
+                            match request_message {
+                                RequestMessage::Transactions(range) => {
+                                    let view = self.view_provider.latest_view();
+                                    match view.get_transactions(range.clone()) {
+                                        Ok(response) => {
+                                            let _ = self.send_response(&peer_id, request_id, ResponseMessage::Transactions(response));
+                                        },
+                                        Err(e) => {
+                                            tracing::error!("Failed to get transactions for range {:?}: {:?}", range, e);
+                                            let response = None;
+                                            let _ = self.send_response(&peer_id, request_id, ResponseMessage::Transactions(response));
+                                            self.health.error(&e);
+                                            return Err(e.into())
+                                        }
                                     }
                                 }
-                            }
-                            RequestMessage::SealedHeaders(range) => {
-                                let max_len = self.max_headers_per_request.try_into().expect("u32 should always fit into usize");
-                                if range.len() > max_len {
-                                    tracing::error!("Requested range of sealed headers is too big. Requested length: {:?}, Max length: {:?}", range.len(), max_len);
-                                    // TODO: Return helpful error message to requester. https://github.com/FuelLabs/fuel-core/issues/1311
-                                    let response = None;
-                                    let _ = self.p2p_service.send_response_msg(request_id, ResponseMessage::SealedHeaders(response));
-                                } else {
+                                RequestMessage::TxPoolSummary => {
+                                    let summary = tx_pool_summary(&self.tx_pool);
+                                    let _ = self.send_response(&peer_id, request_id, ResponseMessage::TxPoolSummary(Some(summary)));
+                                }
+                                RequestMessage::PooledTransactions(short_ids) => {
+                                    let response = pooled_transactions(&self.tx_pool, &short_ids);
+                                    let _ = self.send_response(&peer_id, request_id, ResponseMessage::PooledTransactions(response));
+                                }
+                                RequestMessage::SealedHeaders(range) => {
                                     let view = self.view_provider.latest_view();
                                     match view.get_sealed_headers(range.clone()) {
                                         Ok(headers) => {
                                             let response = Some(headers);
-                                            let _ = self.p2p_service.send_response_msg(request_id, ResponseMessage::SealedHeaders(response));
+                                            let _ = self.send_response(&peer_id, request_id, ResponseMessage::SealedHeaders(response));
                                         },
                                         Err(e) => {
                                             tracing::error!("Failed to get sealed headers for range {:?}: {:?}", range, &e);
                                             let response = None;
-                                            let _ = self.p2p_service.send_response_msg(request_id, ResponseMessage::SealedHeaders(response));
+                                            let _ = self.send_response(&peer_id, request_id, ResponseMessage::SealedHeaders(response));
+                                            self.health.error(&e);
                                             return Err(e.into())
                                         }
                                     }
-                                };
+                                }
                             }
                         }
                     },
@@ -635,6 +848,7 @@ where
             },
             _  = tokio::time::sleep_until(self.next_check_time) => {
                 should_continue = true;
+                self.request_accounting.prune();
                 let res = self.peer_heartbeat_reputation_checks();
                 match res {
                     Ok(_) => tracing::debug!("Peer heartbeat reputation checks completed"),
@@ -666,6 +880,7 @@ where
         // https://github.com/libp2p/specs/blob/master/ROADMAP.md#%EF%B8%8F-polite-peering
         // Dropping of the `FuelP2PService` will close all connections.
 
+        self.telemetry_task_handle.stop_and_await().await?;
         Ok(())
     }
 }
@@ -678,8 +893,14 @@ pub struct SharedState {
     reserved_peers_broadcast: broadcast::Sender<usize>,
     /// Used for communicating with the `Task`.
     request_sender: mpsc::Sender<TaskRequest>,
+    /// Used for requests related to blocks, which have priority over other requests.
+    block_request_sender: mpsc::Sender<TaskRequest>,
     /// Sender of p2p blopck height data
     block_height_broadcast: broadcast::Sender<BlockHeightHeartbeatData>,
+    /// Sender of newly connected peers.
+    new_peers_broadcast: broadcast::Sender<FuelPeerId>,
+    /// The telemetry of the network published by the telemetry task.
+    network_info: Arc<watch::Sender<NetworkInfo>>,
 }
 
 impl SharedState {
@@ -687,37 +908,38 @@ impl SharedState {
         &self,
         message_info: GossipsubMessageInfo,
         acceptance: GossipsubMessageAcceptance,
-    ) -> anyhow::Result<()> {
+    ) -> PortResult<()> {
         self.request_sender
             .try_send(TaskRequest::RespondWithGossipsubMessageReport((
                 message_info,
                 acceptance,
-            )))?;
-        Ok(())
+            )))
+            .map_err(try_send_error)
     }
 
     pub async fn get_sealed_block_headers(
         &self,
         block_height_range: Range<u32>,
-    ) -> anyhow::Result<(Vec<u8>, Option<Vec<SealedBlockHeader>>)> {
+    ) -> PortResult<(Vec<u8>, Option<Vec<SealedBlockHeader>>)> {
         let (sender, receiver) = oneshot::channel();
 
         if block_height_range.is_empty() {
-            return Err(anyhow!(
+            return Err(PortError::invalid_input(anyhow!(
                 "Cannot retrieve headers for an empty range of block heights"
-            ))
+            )))
         }
 
-        self.request_sender
+        self.block_request_sender
             .send(TaskRequest::GetSealedHeaders {
                 block_height_range,
                 channel: sender,
             })
-            .await?;
+            .await
+            .map_err(send_error)?;
 
-        let (peer_id, response) = receiver.await.map_err(|e| anyhow!("{e}"))?;
+        let (peer_id, response) = receiver.await.map_err(recv_error)?;
 
-        let data = response.map_err(|e| anyhow!("Invalid response from peer {e:?}"))?;
+        let data = response.map_err(response_error)?;
         Ok((peer_id.to_bytes(), data))
     }
 
@@ -725,7 +947,7 @@ impl SharedState {
         &self,
         peer_id: Vec<u8>,
         range: Range<u32>,
-    ) -> anyhow::Result<Option<Vec<Transactions>>> {
+    ) -> PortResult<Option<Vec<Transactions>>> {
         let (sender, receiver) = oneshot::channel();
         let from_peer = PeerId::from_bytes(&peer_id).expect("Valid PeerId");
 
@@ -734,26 +956,77 @@ impl SharedState {
             from_peer,
             channel: sender,
         };
-        self.request_sender.send(request).await?;
+        self.block_request_sender
+            .send(request)
+            .await
+            .map_err(send_error)?;
 
-        let (response_from_peer, response) =
-            receiver.await.map_err(|e| anyhow!("{e}"))?;
+        let (response_from_peer, response) = receiver.await.map_err(recv_error)?;
         assert_eq!(
             peer_id,
             response_from_peer.to_bytes(),
             "Bug: response from non-requested peer"
         );
 
-        response.map_err(|e| anyhow!("Invalid response from peer {e:?}"))
+        response.map_err(response_error)
     }
 
-    pub fn broadcast_transaction(
+    /// Requests short ids of transactions in the pool of the peer.
+    pub async fn get_tx_pool_summary_from_peer(
         &self,
-        transaction: Arc<Transaction>,
-    ) -> anyhow::Result<()> {
+        peer_id: FuelPeerId,
+    ) -> PortResult<Option<Vec<ShortTxId>>> {
+        let (sender, receiver) = oneshot::channel();
+        let from_peer =
+            PeerId::from_bytes(peer_id.as_ref()).map_err(PortError::invalid_input)?;
+
+        let request = TaskRequest::GetTxPoolSummary {
+            from_peer,
+            channel: sender,
+        };
         self.request_sender
-            .try_send(TaskRequest::BroadcastTransaction(transaction))?;
-        Ok(())
+            .send(request)
+            .await
+            .map_err(send_error)?;
+
+        let (_, response) = receiver.await.map_err(recv_error)?;
+        response.map_err(response_error)
+    }
+
+    /// Requests transactions from the pool of the peer by their short ids.
+    pub async fn get_pooled_transactions_from_peer(
+        &self,
+        peer_id: FuelPeerId,
+        tx_ids: Vec<ShortTxId>,
+    ) -> PortResult<Option<Vec<Transaction>>> {
+        if tx_ids.len() > MAX_POOLED_TRANSACTIONS_PER_REQUEST {
+            return Err(PortError::invalid_input(anyhow!(
+                "Cannot request more than {MAX_POOLED_TRANSACTIONS_PER_REQUEST} transactions at once"
+            )))
+        }
+
+        let (sender, receiver) = oneshot::channel();
+        let from_peer =
+            PeerId::from_bytes(peer_id.as_ref()).map_err(PortError::invalid_input)?;
+
+        let request = TaskRequest::GetPooledTransactions {
+            from_peer,
+            tx_ids,
+            channel: sender,
+        };
+        self.request_sender
+            .send(request)
+            .await
+            .map_err(send_error)?;
+
+        let (_, response) = receiver.await.map_err(recv_error)?;
+        response.map_err(response_error)
+    }
+
+    pub fn broadcast_transaction(&self, transaction: Arc<Transaction>) -> PortResult<()> {
+        self.request_sender
+            .try_send(TaskRequest::BroadcastTransaction(transaction))
+            .map_err(try_send_error)
     }
 
     pub async fn get_peer_ids(&self) -> anyhow::Result<Vec<PeerId>> {
@@ -776,6 +1049,11 @@ impl SharedState {
         receiver.await.map_err(|e| anyhow!("{}", e))
     }
 
+    /// Returns the telemetry of the network collected since the start of the node.
+    pub fn network_info(&self) -> NetworkInfo {
+        self.network_info.borrow().clone()
+    }
+
     pub fn subscribe_tx(&self) -> broadcast::Receiver<TransactionGossipData> {
         self.tx_broadcast.subscribe()
     }
@@ -790,12 +1068,17 @@ impl SharedState {
         self.reserved_peers_broadcast.subscribe()
     }
 
+    /// Subscribes to peers connected to the node.
+    pub fn subscribe_new_peers(&self) -> broadcast::Receiver<FuelPeerId> {
+        self.new_peers_broadcast.subscribe()
+    }
+
     pub fn report_peer<T: PeerReport>(
         &self,
         peer_id: FuelPeerId,
         peer_report: T,
         reporting_service: &'static str,
-    ) -> anyhow::Result<()> {
+    ) -> PortResult<()> {
         match Vec::from(peer_id).try_into() {
             Ok(peer_id) => {
                 let score = peer_report.get_score_from_report();
@@ -805,31 +1088,73 @@ impl SharedState {
                         peer_id,
                         score,
                         reporting_service,
-                    })?;
-
-                Ok(())
+                    })
+                    .map_err(try_send_error)
             }
             Err(e) => {
                 warn!(target: "fuel-p2p", "Failed to read PeerId from {e:?}");
-                Err(anyhow::anyhow!("Failed to read PeerId from {e:?}"))
+                Err(PortError::invalid_input(anyhow!(
+                    "Failed to read PeerId from {e:?}"
+                )))
             }
         }
     }
 }
 
-pub fn new_service<V, B>(
+/// The full queue of requests is the temporary backpressure,
+/// but the closed queue means that the task is stopped.
+fn try_send_error<T>(error: mpsc::error::TrySendError<T>) -> PortError {
+    match error {
+        mpsc::error::TrySendError::Full(_) => {
+            PortError::transient(anyhow!("The queue of the P2P service is full"))
+        }
+        mpsc::error::TrySendError::Closed(_) => {
+            PortError::fatal(anyhow!("The P2P service is stopped"))
+        }
+    }
+}
+
+fn send_error<T>(_: mpsc::error::SendError<T>) -> PortError {
+    PortError::fatal(anyhow!("The P2P service is stopped"))
+}
+
+/// The task drops the response channel when the request to the peer fails.
+fn recv_error(error: oneshot::error::RecvError) -> PortError {
+    PortError::transient(anyhow!("The request to the peer failed: {error}"))
+}
+
+/// Another peer may respond correctly to the same request.
+fn response_error<E: Debug>(error: E) -> PortError {
+    PortError::transient(anyhow!("Invalid response from peer {error:?}"))
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn new_service<V, B, T>(
     chain_id: ChainId,
     p2p_config: Config<NotInitialized>,
+    shared_state: SharedState,
+    request_receiver: TaskRequestReceiver,
     view_provider: V,
     block_importer: B,
-) -> Service<V>
+    tx_pool: T,
+    health: HealthReporter,
+) -> Service<V, T>
 where
     V: AtomicView + 'static,
     V::View: P2pDb,
     B: BlockHeightImporter,
+    T: TxPool + 'static,
 {
-    let task =
-        UninitializedTask::new(chain_id, p2p_config, view_provider, block_importer);
+    let task = UninitializedTask::new(
+        chain_id,
+        p2p_config,
+        shared_state,
+        request_receiver,
+        view_provider,
+        block_importer,
+        tx_pool,
+        health,
+    );
     Service::new(task)
 }
 
@@ -879,6 +1204,10 @@ pub mod tests {
     use fuel_core_storage::Result as StorageResult;
     use fuel_core_types::{
         blockchain::consensus::Genesis,
+        fuel_tx::{
+            TransactionBuilder,
+            TxId,
+        },
         fuel_types::BlockHeight,
     };
     use futures::FutureExt;
@@ -928,6 +1257,29 @@ pub mod tests {
         }
     }
 
+    #[derive(Clone, Debug, Default)]
+    struct FakeTxPool {
+        transactions: Vec<Transaction>,
+    }
+
+    impl TxPool for FakeTxPool {
+        fn tx_ids(&self, max_txs: usize) -> Vec<TxId> {
+            self.transactions
+                .iter()
+                .take(max_txs)
+                .map(|tx| tx.id(&ChainId::default()))
+                .collect()
+        }
+
+        fn transactions(&self, tx_ids: &[TxId]) -> Vec<Transaction> {
+            self.transactions
+                .iter()
+                .filter(|tx| tx_ids.contains(&tx.id(&ChainId::default())))
+                .cloned()
+                .collect()
+        }
+    }
+
     #[derive(Clone, Debug)]
     struct FakeBlockImporter;
 
@@ -940,8 +1292,17 @@ pub mod tests {
     #[tokio::test]
     async fn start_and_stop_awaits_works() {
         let p2p_config = Config::<NotInitialized>::default("start_stop_works");
-        let service =
-            new_service(ChainId::default(), p2p_config, FakeDb, FakeBlockImporter);
+        let (shared_state, request_receiver) = build_shared_state(&p2p_config);
+        let service = new_service(
+            ChainId::default(),
+            p2p_config,
+            shared_state,
+            request_receiver,
+            FakeDb,
+            FakeBlockImporter,
+            FakeTxPool::default(),
+            Default::default(),
+        );
 
         // Node with p2p service started
         assert!(service.start_and_await().await.unwrap().started());
@@ -949,6 +1310,36 @@ pub mod tests {
         assert!(service.stop_and_await().await.unwrap().stopped());
     }
 
+    #[tokio::test]
+    async fn block_requests_are_received_before_queued_transactions() {
+        // given
+        let p2p_config = Config::<NotInitialized>::default("block_requests_priority");
+        let (shared_state, mut request_receiver) = build_shared_state(&p2p_config);
+        for _ in 0..10 {
+            shared_state
+                .broadcast_transaction(Arc::new(Transaction::default_test_tx()))
+                .unwrap();
+        }
+        let block_request = {
+            let shared_state = shared_state.clone();
+            tokio::spawn(async move { shared_state.get_sealed_block_headers(0..1).await })
+        };
+        while shared_state.block_request_sender.capacity()
+            == shared_state.block_request_sender.max_capacity()
+        {
+            tokio::task::yield_now().await;
+        }
+
+        // when
+        let first = request_receiver.recv().await.unwrap();
+        let second = request_receiver.recv().await.unwrap();
+
+        // then
+        assert!(matches!(first, TaskRequest::GetSealedHeaders { .. }));
+        assert!(matches!(second, TaskRequest::BroadcastTransaction(_)));
+        block_request.abort();
+    }
+
     struct FakeP2PService {
         peer_info: Vec<(PeerId, PeerInfo)>,
     }
@@ -990,7 +1381,7 @@ pub mod tests {
             &mut self,
             _request_id: InboundRequestId,
             _message: ResponseMessage,
-        ) -> anyhow::Result<()> {
+        ) -> anyhow::Result<usize> {
             todo!()
         }
 
@@ -1089,6 +1480,70 @@ pub mod tests {
         ) -> anyhow::Result<()> {
             todo!()
         }
+
+        fn new_peer_broadcast(&self, _peer_id: FuelPeerId) -> anyhow::Result<()> {
+            todo!()
+        }
+    }
+
+    fn transactions(count: u64) -> Vec<Transaction> {
+        (0..count)
+            .map(|i| {
+                TransactionBuilder::script(vec![], i.to_be_bytes().to_vec())
+                    .finalize_as_transaction()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn tx_pool_summary__contains_short_ids_of_pooled_transactions() {
+        let tx_pool = FakeTxPool {
+            transactions: transactions(3),
+        };
+
+        let summary = tx_pool_summary(&tx_pool);
+
+        let expected: Vec<_> = tx_pool
+            .transactions
+            .iter()
+            .map(|tx| ShortTxId::from(&tx.id(&ChainId::default())))
+            .collect();
+        assert_eq!(summary, expected);
+    }
+
+    #[test]
+    fn pooled_transactions__returns_known_transactions() {
+        let tx_pool = FakeTxPool {
+            transactions: transactions(3),
+        };
+        let requested = ShortTxId::from(&tx_pool.transactions[1].id(&ChainId::default()));
+        let unknown = ShortTxId([0; ShortTxId::LEN]);
+
+        let response = pooled_transactions(&tx_pool, &[requested, unknown]);
+
+        assert_eq!(response, Some(vec![tx_pool.transactions[1].clone()]));
+    }
+
+    #[test]
+    fn pooled_transactions__rejects_too_big_requests() {
+        let tx_pool = FakeTxPool::default();
+        let short_ids =
+            vec![ShortTxId::default(); MAX_POOLED_TRANSACTIONS_PER_REQUEST + 1];
+
+        let response = pooled_transactions(&tx_pool, &short_ids);
+
+        assert_eq!(response, None);
+    }
+
+    #[test]
+    fn exceeds_max_range__rejects_ranges_above_the_limit() {
+        assert!(!exceeds_max_range(
+            &RequestMessage::SealedHeaders(0..10),
+            10
+        ));
+        assert!(exceeds_max_range(&RequestMessage::SealedHeaders(0..11), 10));
+        assert!(exceeds_max_range(&RequestMessage::Transactions(5..16), 10));
+        assert!(!exceeds_max_range(&RequestMessage::TxPoolSummary, 0));
     }
 
     #[tokio::test]
@@ -1115,7 +1570,8 @@ pub mod tests {
         };
         let peer_info = vec![(peer_id, peer_info)];
         let p2p_service = FakeP2PService { peer_info };
-        let (_request_sender, request_receiver) = mpsc::channel(100);
+        let (_shared_state, request_receiver) =
+            build_shared_state(&Config::<NotInitialized>::default("test"));
 
         let (report_sender, mut report_receiver) = mpsc::channel(100);
         let broadcast = FakeBroadcast {
@@ -1137,6 +1593,7 @@ pub mod tests {
             chain_id: Default::default(),
             p2p_service,
             view_provider: FakeDB,
+            tx_pool: FakeTxPool::default(),
             next_block_height: FakeBlockImporter.next_block_height(),
             request_receiver,
             broadcast,
@@ -1146,6 +1603,13 @@ pub mod tests {
             heartbeat_max_time_since_last,
             next_check_time: Instant::now(),
             heartbeat_peer_reputation_config: heartbeat_peer_reputation_config.clone(),
+            request_accounting: RequestAccounting::new(None),
+            telemetry_task_handle: telemetry::new_service(
+                Arc::new(watch::channel(NetworkInfo::default()).0),
+                false,
+            )
+            .1,
+            health: Default::default(),
         };
         let (watch_sender, watch_receiver) = tokio::sync::watch::channel(State::Started);
         let mut watcher = StateWatcher::from(watch_receiver);
@@ -1196,7 +1660,8 @@ pub mod tests {
         };
         let peer_info = vec![(peer_id, peer_info)];
         let p2p_service = FakeP2PService { peer_info };
-        let (_request_sender, request_receiver) = mpsc::channel(100);
+        let (_shared_state, request_receiver) =
+            build_shared_state(&Config::<NotInitialized>::default("test"));
 
         let (report_sender, mut report_receiver) = mpsc::channel(100);
         let broadcast = FakeBroadcast {
@@ -1218,6 +1683,7 @@ pub mod tests {
             chain_id: Default::default(),
             p2p_service,
             view_provider: FakeDB,
+            tx_pool: FakeTxPool::default(),
             next_block_height: FakeBlockImporter.next_block_height(),
             request_receiver,
             broadcast,
@@ -1227,6 +1693,13 @@ pub mod tests {
             heartbeat_max_time_since_last,
             next_check_time: Instant::now(),
             heartbeat_peer_reputation_config: heartbeat_peer_reputation_config.clone(),
+            request_accounting: RequestAccounting::new(None),
+            telemetry_task_handle: telemetry::new_service(
+                Arc::new(watch::channel(NetworkInfo::default()).0),
+                false,
+            )
+            .1,
+            health: Default::default(),
         };
         let (watch_sender, watch_receiver) = tokio::sync::watch::channel(State::Started);
         let mut watcher = StateWatcher::from(watch_receiver);
@@ -0,0 +1,545 @@
+//! The telemetry of the P2P network.
+//!
+//! The P2P service reports connections, exchanged bytes, the gossipsub mesh and
+//! block heights to the telemetry task through the [`TelemetrySender`]. The task
+//! aggregates them into the [`NetworkInfo`] available from the shared state of
+//! the P2P service and updates the metrics. The reporting never blocks the P2P
+//! service: events are dropped while the telemetry task is behind.
+
+use fuel_core_metrics::{
+    p2p_metrics::{
+        p2p_metrics,
+        BandwidthLabel,
+        TopicLabel,
+    },
+    timing_buckets,
+};
+use fuel_core_services::{
+    EmptyShared,
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+};
+use fuel_core_types::{
+    fuel_types::BlockHeight,
+    services::p2p::{
+        LatencyHistogram,
+        NetworkInfo,
+        NetworkProtocol,
+        ProtocolBandwidth,
+        TopicMesh,
+    },
+};
+use libp2p::{
+    gossipsub::MessageId,
+    PeerId,
+};
+use std::{
+    collections::{
+        BTreeMap,
+        HashMap,
+    },
+    sync::Arc,
+};
+use tokio::{
+    sync::{
+        mpsc,
+        watch,
+    },
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+/// The capacity of the channel of telemetry events.
+const EVENTS_CAPACITY: usize = 1024 * 10;
+/// The maximum number of gossiped transactions awaiting the validation.
+const MAX_PENDING_TRANSACTIONS: usize = 1024 * 10;
+/// The maximum number of announced block heights awaiting the import.
+const MAX_ANNOUNCED_HEIGHTS: usize = 1024;
+/// Transactions not validated within this time are forgotten.
+const PENDING_TRANSACTION_TTL: Duration = Duration::from_secs(60);
+
+/// The direction of the connection or the traffic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Direction {
+    Inbound,
+    Outbound,
+}
+
+impl Direction {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Direction::Inbound => "inbound",
+            Direction::Outbound => "outbound",
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) enum TelemetryEvent {
+    ConnectionEstablished {
+        peer_id: PeerId,
+        direction: Direction,
+    },
+    ConnectionClosed {
+        peer_id: PeerId,
+    },
+    Bandwidth {
+        protocol: NetworkProtocol,
+        direction: Direction,
+        bytes: u64,
+    },
+    Mesh(Vec<TopicMesh>),
+    TransactionReceived {
+        message_id: MessageId,
+        at: Instant,
+    },
+    TransactionValidated {
+        message_id: MessageId,
+        /// `true` if the transaction is accepted and forwarded to peers.
+        forwarded: bool,
+        at: Instant,
+    },
+    PeerBlockHeight {
+        height: BlockHeight,
+        at: Instant,
+    },
+    LocalBlockHeight {
+        height: BlockHeight,
+        at: Instant,
+    },
+}
+
+/// Reports telemetry events to the telemetry task. The default sender drops all events.
+#[derive(Debug, Clone, Default)]
+pub struct TelemetrySender(Option<mpsc::Sender<TelemetryEvent>>);
+
+impl TelemetrySender {
+    pub(crate) fn send(&self, event: TelemetryEvent) {
+        if let Some(sender) = &self.0 {
+            let _ = sender.try_send(event);
+        }
+    }
+
+    pub(crate) fn bandwidth(
+        &self,
+        protocol: NetworkProtocol,
+        direction: Direction,
+        bytes: usize,
+    ) {
+        self.send(TelemetryEvent::Bandwidth {
+            protocol,
+            direction,
+            bytes: u64::try_from(bytes).unwrap_or(u64::MAX),
+        })
+    }
+}
+
+pub struct Task {
+    events: mpsc::Receiver<TelemetryEvent>,
+    network_info: Arc<watch::Sender<NetworkInfo>>,
+    metrics: bool,
+    connections: HashMap<PeerId, Direction>,
+    pending_transactions: HashMap<MessageId, Instant>,
+    announced_heights: BTreeMap<BlockHeight, Instant>,
+    local_height: Option<BlockHeight>,
+}
+
+#[async_trait::async_trait]
+impl RunnableService for Task {
+    const NAME: &'static str = "P2PTelemetry";
+    type SharedData = EmptyShared;
+    type Task = Self;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {
+        EmptyShared
+    }
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        Ok(self)
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableTask for Task {
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        tokio::select! {
+            biased;
+            _ = watcher.while_started() => Ok(false),
+            event = self.events.recv() => match event {
+                Some(event) => {
+                    self.process(event);
+                    Ok(true)
+                }
+                None => Ok(false),
+            },
+        }
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+impl Task {
+    fn process(&mut self, event: TelemetryEvent) {
+        match event {
+            TelemetryEvent::ConnectionEstablished { peer_id, direction } => {
+                self.connections.insert(peer_id, direction);
+                self.update_connections();
+            }
+            TelemetryEvent::ConnectionClosed { peer_id } => {
+                self.connections.remove(&peer_id);
+                self.update_connections();
+            }
+            TelemetryEvent::Bandwidth {
+                protocol,
+                direction,
+                bytes,
+            } => {
+                self.network_info.send_modify(|info| {
+                    let position = info
+                        .bandwidth
+                        .iter()
+                        .position(|bandwidth| bandwidth.protocol == protocol)
+                        .unwrap_or_else(|| {
+                            info.bandwidth.push(ProtocolBandwidth {
+                                protocol,
+                                inbound_bytes: 0,
+                                outbound_bytes: 0,
+                            });
+                            info.bandwidth.len().saturating_sub(1)
+                        });
+                    let bandwidth = &mut info.bandwidth[position];
+                    let total = match direction {
+                        Direction::Inbound => &mut bandwidth.inbound_bytes,
+                        Direction::Outbound => &mut bandwidth.outbound_bytes,
+                    };
+                    *total = total.saturating_add(bytes);
+                });
+                if self.metrics {
+                    p2p_metrics()
+                        .bandwidth
+                        .get_or_create(&BandwidthLabel {
+                            protocol: protocol_name(protocol).to_string(),
+                            direction: direction.as_str().to_string(),
+                        })
+                        .inc_by(bytes);
+                }
+            }
+            TelemetryEvent::Mesh(mesh) => {
+                if self.metrics {
+                    for topic in &mesh {
+                        p2p_metrics()
+                            .mesh_peers
+                            .get_or_create(&TopicLabel {
+                                topic: topic.topic.clone(),
+                            })
+                            .set(i64::try_from(topic.peers.len()).unwrap_or(i64::MAX));
+                    }
+                }
+                self.network_info.send_modify(|info| info.mesh = mesh);
+                self.prune_pending_transactions(Instant::now());
+            }
+            TelemetryEvent::TransactionReceived { message_id, at } => {
+                if self.pending_transactions.len() < MAX_PENDING_TRANSACTIONS {
+                    self.pending_transactions.insert(message_id, at);
+                }
+            }
+            TelemetryEvent::TransactionValidated {
+                message_id,
+                forwarded,
+                at,
+            } => {
+                let received_at = self.pending_transactions.remove(&message_id);
+                // Rejected and ignored transactions aren't propagated further.
+                if let Some(received_at) = received_at.filter(|_| forwarded) {
+                    let latency = at.saturating_duration_since(received_at);
+                    self.network_info
+                        .send_modify(|info| info.tx_propagation.observe(latency));
+                    if self.metrics {
+                        p2p_metrics()
+                            .tx_propagation_latency
+                            .observe(latency.as_secs_f64());
+                    }
+                }
+            }
+            TelemetryEvent::PeerBlockHeight { height, at } => {
+                let is_new = self.local_height.map_or(true, |local| height > local);
+                if is_new && self.announced_heights.len() < MAX_ANNOUNCED_HEIGHTS {
+                    self.announced_heights.entry(height).or_insert(at);
+                }
+            }
+            TelemetryEvent::LocalBlockHeight { height, at } => {
+                self.local_height = Some(height);
+                // Peers announce only their latest height, so the height is known to
+                // the network since the first announcement of it or any later height.
+                let announced_at = self
+                    .announced_heights
+                    .range(height..)
+                    .map(|(_, announced_at)| *announced_at)
+                    .min();
+                self.announced_heights = self
+                    .announced_heights
+                    .split_off(&height.succ().unwrap_or(height));
+                if let Some(announced_at) = announced_at {
+                    let latency = at.saturating_duration_since(announced_at);
+                    self.network_info
+                        .send_modify(|info| info.block_propagation.observe(latency));
+                    if self.metrics {
+                        p2p_metrics()
+                            .block_propagation_latency
+                            .observe(latency.as_secs_f64());
+                    }
+                }
+            }
+        }
+    }
+
+    fn update_connections(&self) {
+        let inbound = self
+            .connections
+            .values()
+            .filter(|direction| **direction == Direction::Inbound)
+            .count();
+        let outbound = self.connections.len().saturating_sub(inbound);
+        self.network_info.send_modify(|info| {
+            info.inbound_peers = u32::try_from(inbound).unwrap_or(u32::MAX);
+            info.outbound_peers = u32::try_from(outbound).unwrap_or(u32::MAX);
+        });
+        if self.metrics {
+            p2p_metrics()
+                .inbound_peers
+                .set(i64::try_from(inbound).unwrap_or(i64::MAX));
+            p2p_metrics()
+                .outbound_peers
+                .set(i64::try_from(outbound).unwrap_or(i64::MAX));
+        }
+    }
+
+    fn prune_pending_transactions(&mut self, now: Instant) {
+        self.pending_transactions.retain(|_, received_at| {
+            now.saturating_duration_since(*received_at) < PENDING_TRANSACTION_TTL
+        });
+    }
+}
+
+fn protocol_name(protocol: NetworkProtocol) -> &'static str {
+    match protocol {
+        NetworkProtocol::Gossipsub => "gossipsub",
+        NetworkProtocol::RequestResponse => "request_response",
+    }
+}
+
+/// Returns the network info without any telemetry collected.
+pub(crate) fn empty_network_info() -> NetworkInfo {
+    let histogram = || {
+        LatencyHistogram::with_bounds(
+            timing_buckets()
+                .iter()
+                .map(|seconds| Duration::from_secs_f64(*seconds)),
+        )
+    };
+    NetworkInfo {
+        block_propagation: histogram(),
+        tx_propagation: histogram(),
+        ..Default::default()
+    }
+}
+
+/// Creates the telemetry task publishing the telemetry to `network_info` and
+/// the sender of its events.
+pub fn new_service(
+    network_info: Arc<watch::Sender<NetworkInfo>>,
+    metrics: bool,
+) -> (TelemetrySender, ServiceRunner<Task>) {
+    let (sender, events) = mpsc::channel(EVENTS_CAPACITY);
+    let task = Task {
+        events,
+        network_info,
+        metrics,
+        connections: HashMap::new(),
+        pending_transactions: HashMap::new(),
+        announced_heights: BTreeMap::new(),
+        local_height: None,
+    };
+    (TelemetrySender(Some(sender)), ServiceRunner::new(task))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn task() -> (Task, watch::Receiver<NetworkInfo>) {
+        let (network_info, receiver) = watch::channel(empty_network_info());
+        let (_, events) = mpsc::channel(1);
+        let task = Task {
+            events,
+            network_info: Arc::new(network_info),
+            metrics: false,
+            connections: HashMap::new(),
+            pending_transactions: HashMap::new(),
+            announced_heights: BTreeMap::new(),
+            local_height: None,
+        };
+        (task, receiver)
+    }
+
+    #[test]
+    fn connections_are_counted_by_direction() {
+        let (mut task, info) = task();
+        let inbound = PeerId::random();
+        let outbound = PeerId::random();
+
+        task.process(TelemetryEvent::ConnectionEstablished {
+            peer_id: inbound,
+            direction: Direction::Inbound,
+        });
+        task.process(TelemetryEvent::ConnectionEstablished {
+            peer_id: outbound,
+            direction: Direction::Outbound,
+        });
+        assert_eq!(info.borrow().inbound_peers, 1);
+        assert_eq!(info.borrow().outbound_peers, 1);
+
+        task.process(TelemetryEvent::ConnectionClosed { peer_id: inbound });
+        assert_eq!(info.borrow().inbound_peers, 0);
+        assert_eq!(info.borrow().outbound_peers, 1);
+    }
+
+    #[test]
+    fn bandwidth_is_accumulated_by_protocol() {
+        let (mut task, info) = task();
+
+        for (protocol, direction, bytes) in [
+            (NetworkProtocol::Gossipsub, Direction::Inbound, 10),
+            (NetworkProtocol::Gossipsub, Direction::Inbound, 5),
+            (NetworkProtocol::Gossipsub, Direction::Outbound, 7),
+            (NetworkProtocol::RequestResponse, Direction::Outbound, 100),
+        ] {
+            task.process(TelemetryEvent::Bandwidth {
+                protocol,
+                direction,
+                bytes,
+            });
+        }
+
+        assert_eq!(
+            info.borrow().bandwidth,
+            vec![
+                ProtocolBandwidth {
+                    protocol: NetworkProtocol::Gossipsub,
+                    inbound_bytes: 15,
+                    outbound_bytes: 7,
+                },
+                ProtocolBandwidth {
+                    protocol: NetworkProtocol::RequestResponse,
+                    inbound_bytes: 0,
+                    outbound_bytes: 100,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn tx_propagation_latency_is_observed_on_forwarding() {
+        let (mut task, info) = task();
+        let message_id = MessageId::new(&[1]);
+        let received_at = Instant::now();
+
+        task.process(TelemetryEvent::TransactionReceived {
+            message_id: message_id.clone(),
+            at: received_at,
+        });
+        task.process(TelemetryEvent::TransactionValidated {
+            message_id: message_id.clone(),
+            forwarded: true,
+            at: received_at + Duration::from_millis(20),
+        });
+        // The second report of the same message is ignored.
+        task.process(TelemetryEvent::TransactionValidated {
+            message_id,
+            forwarded: true,
+            at: received_at + Duration::from_secs(20),
+        });
+
+        let histogram = info.borrow().tx_propagation.clone();
+        assert_eq!(histogram.count, 1);
+        assert_eq!(histogram.sum, Duration::from_millis(20));
+        let below = |bound: Duration| {
+            histogram
+                .buckets
+                .iter()
+                .find(|bucket| bucket.upper_bound == bound)
+                .unwrap()
+                .count
+        };
+        assert_eq!(below(Duration::from_millis(10)), 0);
+        assert_eq!(below(Duration::from_millis(25)), 1);
+    }
+
+    #[test]
+    fn tx_propagation_latency_ignores_not_forwarded_transactions() {
+        let (mut task, info) = task();
+        let message_id = MessageId::new(&[1]);
+        let received_at = Instant::now();
+
+        task.process(TelemetryEvent::TransactionReceived {
+            message_id: message_id.clone(),
+            at: received_at,
+        });
+        task.process(TelemetryEvent::TransactionValidated {
+            message_id: message_id.clone(),
+            forwarded: false,
+            at: received_at + Duration::from_millis(20),
+        });
+
+        assert_eq!(info.borrow().tx_propagation.count, 0);
+        assert!(!task.pending_transactions.contains_key(&message_id));
+    }
+
+    #[test]
+    fn block_propagation_latency_is_measured_from_first_announcement() {
+        let (mut task, info) = task();
+        let now = Instant::now();
+        task.process(TelemetryEvent::LocalBlockHeight {
+            height: 1u32.into(),
+            at: now,
+        });
+        // The height announced before the import of the local block.
+        task.process(TelemetryEvent::PeerBlockHeight {
+            height: 3u32.into(),
+            at: now,
+        });
+        task.process(TelemetryEvent::PeerBlockHeight {
+            height: 2u32.into(),
+            at: now + Duration::from_millis(50),
+        });
+        task.process(TelemetryEvent::LocalBlockHeight {
+            height: 2u32.into(),
+            at: now + Duration::from_millis(100),
+        });
+        task.process(TelemetryEvent::LocalBlockHeight {
+            height: 3u32.into(),
+            at: now + Duration::from_millis(300),
+        });
+        // The block produced by the node itself isn't observed.
+        task.process(TelemetryEvent::LocalBlockHeight {
+            height: 4u32.into(),
+            at: now + Duration::from_millis(400),
+        });
+
+        let histogram = info.borrow().block_propagation.clone();
+        assert_eq!(histogram.count, 2);
+        assert_eq!(histogram.sum, Duration::from_millis(400));
+        assert!(task.announced_heights.is_empty());
+    }
+}
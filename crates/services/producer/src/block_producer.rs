@@ -29,6 +29,7 @@ use fuel_core_types::{
     services::{
         block_producer::Components,
         executor::{
+            DryRunBundleStep,
             TransactionExecutionStatus,
             UncommittedResult,
         },
@@ -187,24 +188,7 @@ where
         height: Option<BlockHeight>,
         utxo_validation: Option<bool>,
     ) -> anyhow::Result<Vec<TransactionExecutionStatus>> {
-        let height = height.unwrap_or_else(|| {
-            self.view_provider
-                .latest_height()
-                .succ()
-                .expect("It is impossible to overflow the current block height")
-        });
-
-        // The dry run execution should use the state of the blockchain based on the
-        // last available block, not on the upcoming one. It means that we need to
-        // use the same configuration as the last block -> the same DA height.
-        // It is deterministic from the result perspective, plus it is more performant
-        // because we don't need to wait for the relayer to sync.
-        let header = self._new_header(height, Tai64::now())?;
-        let component = Components {
-            header_to_produce: header,
-            transactions_source: transactions.clone(),
-            gas_limit: u64::MAX,
-        };
+        let component = self.dry_run_component(transactions.clone(), height)?;
 
         let executor = self.executor.clone();
 
@@ -228,6 +212,53 @@ where
             Ok(tx_statuses)
         }
     }
+
+    /// Simulates the bundle of transactions executed one after another on the same fork
+    /// of the state. Each transaction sees the changes of the previous ones, so the bundle
+    /// can simulate multistep interactions. Doesn't alter any state.
+    pub async fn dry_run_bundle(
+        &self,
+        transactions: Vec<Transaction>,
+        height: Option<BlockHeight>,
+        utxo_validation: Option<bool>,
+    ) -> anyhow::Result<Vec<DryRunBundleStep>> {
+        let component = self.dry_run_component(transactions, height)?;
+        let executor = self.executor.clone();
+
+        // use the blocking threadpool for dry_run to avoid clogging up the main async runtime
+        let steps =
+            tokio_rayon::spawn_fifo(move || -> anyhow::Result<Vec<DryRunBundleStep>> {
+                Ok(executor.dry_run_bundle(component, utxo_validation)?)
+            })
+            .await?;
+
+        Ok(steps)
+    }
+
+    fn dry_run_component(
+        &self,
+        transactions: Vec<Transaction>,
+        height: Option<BlockHeight>,
+    ) -> anyhow::Result<Components<Vec<Transaction>>> {
+        let height = height.unwrap_or_else(|| {
+            self.view_provider
+                .latest_height()
+                .succ()
+                .expect("It is impossible to overflow the current block height")
+        });
+
+        // The dry run execution should use the state of the blockchain based on the
+        // last available block, not on the upcoming one. It means that we need to
+        // use the same configuration as the last block -> the same DA height.
+        // It is deterministic from the result perspective, plus it is more performant
+        // because we don't need to wait for the relayer to sync.
+        let header = self._new_header(height, Tai64::now())?;
+        Ok(Components {
+            header_to_produce: header,
+            transactions_source: transactions,
+            gas_limit: u64::MAX,
+        })
+    }
 }
 
 impl<ViewProvider, TxPool, Executor> Producer<ViewProvider, TxPool, Executor>
@@ -16,6 +16,7 @@ use fuel_core_types::{
     services::{
         block_producer::Components,
         executor::{
+            DryRunBundleStep,
             Result as ExecutorResult,
             TransactionExecutionStatus,
             UncommittedResult,
@@ -76,4 +77,13 @@ pub trait DryRunner: Send + Sync {
         block: Components<Vec<Transaction>>,
         utxo_validation: Option<bool>,
     ) -> ExecutorResult<Vec<TransactionExecutionStatus>>;
+
+    /// Executes transactions of the bundle one after another without committing them
+    /// to the database. Each transaction sees the changes of the previous ones.
+    /// Invalid transactions are skipped and reported instead of failing the whole bundle.
+    fn dry_run_bundle(
+        &self,
+        block: Components<Vec<Transaction>>,
+        utxo_validation: Option<bool>,
+    ) -> ExecutorResult<Vec<DryRunBundleStep>>;
 }
@@ -21,9 +21,16 @@ pub struct Config {
     pub da_deploy_height: DaBlockHeight,
     /// Uri address to ethereum client.
     pub relayer: Option<url::Url>,
+    /// The proxy of requests to the ethereum client. Supports `http://`, `https://`,
+    /// `socks5://` and `socks5h://` proxies.
+    pub proxy: Option<url::Url>,
     // TODO: Create `EthAddress` into `fuel_core_types`.
     /// Ethereum contract address.
     pub eth_v2_listening_contracts: Vec<H160>,
+    /// The number of da blocks that must be built on top of a block before the relayer
+    /// considers its events final. If not set, the relayer relies on the `finalized`
+    /// block tag of the da node.
+    pub da_finality_depth: Option<u64>,
     /// Number of pages or blocks containing logs that
     /// should be downloaded in a single call to the da layer
     pub log_page_size: u64,
@@ -55,10 +62,12 @@ impl Default for Config {
         Self {
             da_deploy_height: DaBlockHeight::from(Self::DEFAULT_DA_DEPLOY_HEIGHT),
             relayer: None,
+            proxy: None,
             eth_v2_listening_contracts: vec![H160::from_str(
                 "0x03E4538018285e1c03CCce2F92C9538c87606911",
             )
             .unwrap()],
+            da_finality_depth: None,
             log_page_size: Self::DEFAULT_LOG_PAGE_SIZE,
             sync_minimum_duration: Self::DEFAULT_SYNC_MINIMUM_DURATION,
             syncing_call_frequency: Self::DEFAULT_SYNCING_CALL_FREQ,
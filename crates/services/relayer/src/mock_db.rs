@@ -1,6 +1,12 @@
 #![allow(missing_docs)]
 
-use crate::ports::RelayerDb;
+use crate::{
+    ports::{
+        FuelBlockDb,
+        RelayerDb,
+    },
+    storage::MAX_SYNC_CHECKPOINTS,
+};
 use fuel_core_storage::{
     not_found,
     Result as StorageResult,
@@ -9,7 +15,10 @@ use fuel_core_types::{
     blockchain::primitives::DaBlockHeight,
     entities::message::Message,
     fuel_types::Nonce,
-    services::relayer::Event,
+    services::relayer::{
+        Event,
+        SyncCheckpoint,
+    },
 };
 use std::{
     collections::{
@@ -26,6 +35,8 @@ use std::{
 pub struct Data {
     pub messages: BTreeMap<DaBlockHeight, HashMap<Nonce, Message>>,
     pub finalized_da_height: Option<DaBlockHeight>,
+    pub checkpoints: Vec<SyncCheckpoint>,
+    pub latest_block_da_height: Option<DaBlockHeight>,
 }
 
 // TODO: Maybe remove `Arc<Mutex<>>`
@@ -87,4 +98,41 @@ impl RelayerDb for MockDb {
             .finalized_da_height
             .ok_or(not_found!("FinalizedDaHeight for test"))
     }
+
+    fn insert_checkpoint(&mut self, checkpoint: &SyncCheckpoint) -> StorageResult<()> {
+        let mut lock = self.data.lock().unwrap();
+        lock.checkpoints.retain(|c| c.height < checkpoint.height);
+        lock.checkpoints.push(*checkpoint);
+        let outdated = lock.checkpoints.len().saturating_sub(MAX_SYNC_CHECKPOINTS);
+        lock.checkpoints.drain(..outdated);
+        Ok(())
+    }
+
+    fn get_checkpoints(&self) -> StorageResult<Vec<SyncCheckpoint>> {
+        Ok(self.data.lock().unwrap().checkpoints.clone())
+    }
+
+    fn rollback_to(
+        &mut self,
+        height: &DaBlockHeight,
+        finalized: &DaBlockHeight,
+    ) -> StorageResult<()> {
+        if height < finalized {
+            return Err(anyhow::anyhow!(
+                "Can't roll back to {height} below the finalized da height {finalized}"
+            )
+            .into())
+        }
+        let mut lock = self.data.lock().unwrap();
+        lock.messages.retain(|h, _| h <= height);
+        lock.checkpoints.retain(|c| c.height <= *height);
+        lock.finalized_da_height = Some(*height);
+        Ok(())
+    }
+}
+
+impl FuelBlockDb for MockDb {
+    fn latest_block_da_height(&self) -> StorageResult<Option<DaBlockHeight>> {
+        Ok(self.data.lock().unwrap().latest_block_da_height)
+    }
 }
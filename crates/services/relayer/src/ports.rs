@@ -4,7 +4,10 @@ use async_trait::async_trait;
 use fuel_core_storage::Result as StorageResult;
 use fuel_core_types::{
     blockchain::primitives::DaBlockHeight,
-    services::relayer::Event,
+    services::relayer::{
+        Event,
+        SyncCheckpoint,
+    },
 };
 
 #[cfg(test)]
@@ -13,7 +16,7 @@ mod tests;
 /// Manages state related to supported external chains.
 #[async_trait]
 pub trait RelayerDb: Send + Sync {
-    /// Add bridge events to database. Events are not revertible.
+    /// Add bridge events to database. Events are revertible only with [`Self::rollback_to`].
     /// Must only set a new da height if it is greater than the current.
     fn insert_events(
         &mut self,
@@ -31,4 +34,29 @@ pub trait RelayerDb: Send + Sync {
     /// Get finalized da height that represent last block from da layer that got finalized.
     /// Panics if height is not set as of initialization of database.
     fn get_finalized_da_height(&self) -> StorageResult<DaBlockHeight>;
+
+    /// Records the checkpoint of the synced da block. Checkpoints at the same or greater
+    /// height are replaced, and only the latest checkpoints are kept.
+    fn insert_checkpoint(&mut self, checkpoint: &SyncCheckpoint) -> StorageResult<()>;
+
+    /// Get checkpoints of the synced da blocks ordered from the oldest to the newest.
+    fn get_checkpoints(&self) -> StorageResult<Vec<SyncCheckpoint>>;
+
+    /// Removes events and checkpoints above the `height` and sets the finalized da height to it.
+    /// It is used to revert blocks that were reorganized on the da layer.
+    ///
+    /// Returns an error if the `height` is below the `finalized` height of the da layer,
+    /// because blocks may already reference events at or below it.
+    fn rollback_to(
+        &mut self,
+        height: &DaBlockHeight,
+        finalized: &DaBlockHeight,
+    ) -> StorageResult<()>;
+}
+
+/// Provides access to the Fuel blocks that include the events of the relayer.
+pub trait FuelBlockDb: Send + Sync {
+    /// Get the da height recorded in the header of the latest Fuel block.
+    /// Returns `None` if there are no blocks yet.
+    fn latest_block_da_height(&self) -> StorageResult<Option<DaBlockHeight>>;
 }
@@ -2,7 +2,10 @@
 
 use crate::{
     log::EthEventLog,
-    ports::RelayerDb,
+    ports::{
+        FuelBlockDb,
+        RelayerDb,
+    },
     service::state::EthLocal,
     Config,
 };
@@ -22,6 +25,7 @@ use ethers_providers::{
     ProviderError,
 };
 use fuel_core_services::{
+    HealthReporter,
     RunnableService,
     RunnableTask,
     ServiceRunner,
@@ -35,6 +39,7 @@ use futures::StreamExt;
 use std::{
     convert::TryInto,
     ops::Deref,
+    sync::Arc,
 };
 use synced::update_synced;
 use tokio::sync::watch;
@@ -44,6 +49,7 @@ use self::{
     run::RelayerData,
 };
 
+mod checkpoints;
 mod get_logs;
 mod run;
 mod state;
@@ -76,8 +82,12 @@ pub struct NotInitializedTask<P, D> {
     eth_node: P,
     /// The fuel database.
     database: D,
+    /// The Fuel blocks that include the events of the relayer.
+    fuel_blocks: Arc<dyn FuelBlockDb>,
     /// Configuration settings.
     config: Config,
+    /// The reporter of the health of the relayer.
+    health: HealthReporter,
 }
 
 /// The actual relayer background task that syncs with the DA layer.
@@ -88,22 +98,34 @@ pub struct Task<P, D> {
     eth_node: P,
     /// The fuel database.
     database: D,
+    /// The Fuel blocks that include the events of the relayer.
+    fuel_blocks: Arc<dyn FuelBlockDb>,
     /// Configuration settings.
     config: Config,
     /// The watcher used to track the state of the service. If the service stops,
     /// the task will stop synchronization.
     shutdown: StateWatcher,
+    /// The reporter of the health of the relayer.
+    health: HealthReporter,
 }
 
 impl<P, D> NotInitializedTask<P, D> {
     /// Create a new relayer task.
-    fn new(eth_node: P, database: D, config: Config) -> Self {
+    fn new(
+        eth_node: P,
+        database: D,
+        fuel_blocks: Arc<dyn FuelBlockDb>,
+        config: Config,
+        health: HealthReporter,
+    ) -> Self {
         let (synced, _) = watch::channel(None);
         Self {
             synced,
             eth_node,
             database,
+            fuel_blocks,
             config,
+            health,
         }
     }
 }
@@ -156,6 +178,40 @@ where
         write_logs(&mut self.database, logs).await
     }
 
+    async fn revert_reorged_blocks(&mut self) -> anyhow::Result<()> {
+        let mut shutdown = self.shutdown.clone();
+        tokio::select! {
+            biased;
+            _ = shutdown.while_started() => {
+                Err(anyhow::anyhow!("The relayer got a stop signal"))
+            },
+            result = checkpoints::revert_reorged_blocks(
+                &self.eth_node,
+                &mut self.database,
+                self.fuel_blocks.as_ref(),
+                self.config.da_finality_depth,
+            ) => {
+                result
+            }
+        }
+    }
+
+    async fn save_checkpoint(&mut self) -> anyhow::Result<()> {
+        let mut shutdown = self.shutdown.clone();
+        tokio::select! {
+            biased;
+            _ = shutdown.while_started() => {
+                Err(anyhow::anyhow!("The relayer got a stop signal"))
+            },
+            result = checkpoints::save_checkpoint(
+                &self.eth_node,
+                &mut self.database,
+            ) => {
+                result
+            }
+        }
+    }
+
     fn update_synced(&self, state: &state::EthState) {
         update_synced(&self.synced, state)
     }
@@ -192,14 +248,18 @@ where
             synced,
             eth_node,
             database,
+            fuel_blocks,
             config,
+            health,
         } = self;
         let mut task = Task {
             synced,
             eth_node,
             database,
+            fuel_blocks,
             config,
             shutdown,
+            health,
         };
         task.set_deploy_height();
 
@@ -219,9 +279,15 @@ where
 
         let result = run::run(self).await;
 
-        if self.shutdown.borrow_and_update().started()
-            && (result.is_err() | self.synced.borrow().is_some())
-        {
+        let started = self.shutdown.borrow_and_update().started();
+        if started {
+            match &result {
+                Ok(_) => self.health.progress(),
+                Err(e) => self.health.error(e),
+            }
+        }
+
+        if started && (result.is_err() | self.synced.borrow().is_some()) {
             // Sleep the loop so the da node is not spammed.
             tokio::time::sleep(
                 self.config
@@ -296,17 +362,48 @@ where
             _ = shutdown.while_started() => {
                 Err(anyhow::anyhow!("The relayer got a stop signal"))
             },
-            block = self.eth_node.get_block(ethers_core::types::BlockNumber::Finalized) => {
-                let block_number = block.map_err(anyhow::Error::msg)?
-                    .and_then(|block| block.number)
-                    .ok_or(anyhow::anyhow!("Block pending"))?
-                    .as_u64();
-                Ok(block_number)
+            block_number = finalized_height(
+                &self.eth_node,
+                self.config.da_finality_depth,
+            ) => {
+                block_number
             }
         }
     }
 }
 
+/// Get the height of the most recently finalized block on the Ethereum node.
+/// If the `finality_depth` is set, the block is final when `finality_depth` blocks
+/// are built on top of it. Otherwise, the `finalized` block tag is used.
+async fn finalized_height<P>(
+    eth_node: &P,
+    finality_depth: Option<u64>,
+) -> anyhow::Result<u64>
+where
+    P: Middleware<Error = ProviderError>,
+{
+    match finality_depth {
+        Some(depth) => {
+            let latest = eth_node
+                .get_block_number()
+                .await
+                .map_err(anyhow::Error::msg)?
+                .as_u64();
+            Ok(latest.saturating_sub(depth))
+        }
+        None => {
+            let block_number = eth_node
+                .get_block(ethers_core::types::BlockNumber::Finalized)
+                .await
+                .map_err(anyhow::Error::msg)?
+                .and_then(|block| block.number)
+                .ok_or(anyhow::anyhow!("Block pending"))?
+                .as_u64();
+            Ok(block_number)
+        }
+    }
+}
+
 #[async_trait]
 impl<P, D> EthLocal for Task<P, D>
 where
@@ -319,7 +416,12 @@ where
 }
 
 /// Creates an instance of runnable relayer service.
-pub fn new_service<D>(database: D, config: Config) -> anyhow::Result<Service<D>>
+pub fn new_service<D>(
+    database: D,
+    fuel_blocks: Arc<dyn FuelBlockDb>,
+    config: Config,
+    health: HealthReporter,
+) -> anyhow::Result<Service<D>>
 where
     D: RelayerDb + Clone + 'static,
 {
@@ -329,9 +431,23 @@ where
         )
     })?;
     // TODO: Does this handle https?
-    let http = Http::new(url);
+    let http = match &config.proxy {
+        Some(proxy) => {
+            let client = reqwest::Client::builder()
+                .proxy(reqwest::Proxy::all(proxy.clone())?)
+                .build()?;
+            Http::new_with_client(url, client)
+        }
+        None => Http::new(url),
+    };
     let eth_node = Provider::new(http);
-    Ok(new_service_internal(eth_node, database, config))
+    Ok(new_service_internal(
+        eth_node,
+        database,
+        fuel_blocks,
+        config,
+        health,
+    ))
 }
 
 #[cfg(any(test, feature = "test-helpers"))]
@@ -339,25 +455,34 @@ where
 pub fn new_service_test<P, D>(
     eth_node: P,
     database: D,
+    fuel_blocks: Arc<dyn FuelBlockDb>,
     config: Config,
 ) -> CustomizableService<P, D>
 where
     P: Middleware<Error = ProviderError> + 'static,
     D: RelayerDb + Clone + 'static,
 {
-    new_service_internal(eth_node, database, config)
+    new_service_internal(
+        eth_node,
+        database,
+        fuel_blocks,
+        config,
+        HealthReporter::default(),
+    )
 }
 
 fn new_service_internal<P, D>(
     eth_node: P,
     database: D,
+    fuel_blocks: Arc<dyn FuelBlockDb>,
     config: Config,
+    health: HealthReporter,
 ) -> CustomizableService<P, D>
 where
     P: Middleware<Error = ProviderError> + 'static,
     D: RelayerDb + Clone + 'static,
 {
-    let task = NotInitializedTask::new(eth_node, database, config);
+    let task = NotInitializedTask::new(eth_node, database, fuel_blocks, config, health);
 
     CustomizableService::new(task)
 }
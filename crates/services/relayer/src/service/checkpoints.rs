@@ -0,0 +1,100 @@
+//! # Checkpoints
+//! Tracks the synced DA blocks to detect and revert reorganizations of the DA layer.
+
+use super::*;
+use ethers_core::types::BlockNumber;
+use fuel_core_types::{
+    fuel_types::Bytes32,
+    services::relayer::SyncCheckpoint,
+};
+
+/// Get the hash of the DA block at the `height`, if the block exists.
+pub(crate) async fn block_hash<P>(
+    eth_node: &P,
+    height: u64,
+) -> anyhow::Result<Option<Bytes32>>
+where
+    P: Middleware<Error = ProviderError>,
+{
+    let block = eth_node
+        .get_block(BlockNumber::Number(height.into()))
+        .await
+        .map_err(anyhow::Error::msg)?;
+    Ok(block
+        .and_then(|block| block.hash)
+        .map(|hash| Bytes32::from(hash.0)))
+}
+
+/// Reverts the synced blocks that are not a part of the canonical DA chain anymore.
+/// The state of the relayer is rolled back to the newest checkpoint that is still canonical.
+///
+/// Returns an error if the DA layer reorganized blocks below the oldest checkpoint,
+/// if the newest canonical checkpoint is below the finalized DA block
+/// according to the `finality_depth`, or if it is below the DA height
+/// of the latest Fuel block. Nothing is rolled back in these cases.
+pub(crate) async fn revert_reorged_blocks<P, D>(
+    eth_node: &P,
+    database: &mut D,
+    fuel_blocks: &dyn FuelBlockDb,
+    finality_depth: Option<u64>,
+) -> anyhow::Result<()>
+where
+    P: Middleware<Error = ProviderError>,
+    D: RelayerDb,
+{
+    let checkpoints = database.get_checkpoints()?;
+    let (Some(oldest), Some(newest)) = (checkpoints.first(), checkpoints.last()) else {
+        return Ok(())
+    };
+
+    for checkpoint in checkpoints.iter().rev() {
+        let hash = block_hash(eth_node, checkpoint.height.as_u64()).await?;
+        if hash == Some(checkpoint.hash) {
+            if checkpoint != newest {
+                let included = fuel_blocks.latest_block_da_height()?;
+                if let Some(included) = included.filter(|h| checkpoint.height < *h) {
+                    return Err(anyhow::anyhow!(
+                        "The DA layer reorganized blocks after {}, \
+                        but the latest Fuel block already includes events up to {}",
+                        checkpoint.height,
+                        included
+                    ))
+                }
+                tracing::warn!(
+                    "The DA layer reorganized blocks after {}, reverting the relayer to it",
+                    checkpoint.height
+                );
+                let finalized = finalized_height(eth_node, finality_depth).await?;
+                database.rollback_to(&checkpoint.height, &finalized.into())?;
+            }
+            return Ok(())
+        }
+    }
+
+    Err(anyhow::anyhow!(
+        "The DA layer reorganized blocks below the oldest known checkpoint at {}",
+        oldest.height
+    ))
+}
+
+/// Records the checkpoint of the latest synced DA block.
+pub(crate) async fn save_checkpoint<P, D>(
+    eth_node: &P,
+    database: &mut D,
+) -> anyhow::Result<()>
+where
+    P: Middleware<Error = ProviderError>,
+    D: RelayerDb,
+{
+    let height = database.get_finalized_da_height()?;
+    let newest = database.get_checkpoints()?.last().map(|c| c.height);
+    if newest == Some(height) {
+        return Ok(())
+    }
+
+    let hash = block_hash(eth_node, height.as_u64())
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("The DA block at {} is not found", height))?;
+    database.insert_checkpoint(&SyncCheckpoint { height, hash })?;
+    Ok(())
+}
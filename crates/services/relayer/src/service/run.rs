@@ -27,6 +27,12 @@ pub trait RelayerData: EthRemote + EthLocal {
         eth_sync_gap: &state::EthSyncGap,
     ) -> anyhow::Result<()>;
 
+    /// Revert the synced blocks that were reorganized on the DA layer.
+    async fn revert_reorged_blocks(&mut self) -> anyhow::Result<()>;
+
+    /// Record the checkpoint of the latest synced DA block.
+    async fn save_checkpoint(&mut self) -> anyhow::Result<()>;
+
     /// Update the synced state.
     fn update_synced(&self, state: &EthState);
 }
@@ -39,6 +45,9 @@ where
     // Await the eth node to sync.
     relayer.wait_if_eth_syncing().await?;
 
+    // Revert the synced blocks if the DA layer reorganized them.
+    relayer.revert_reorged_blocks().await?;
+
     // Build the DA layer state.
     let state = state::build_eth(relayer).await?;
 
@@ -46,6 +55,9 @@ where
     if let Some(eth_sync_gap) = state.needs_to_sync_eth() {
         // Download events and write them to the database.
         relayer.download_logs(&eth_sync_gap).await?;
+
+        // Record the synced block to detect future reorganizations from it.
+        relayer.save_checkpoint().await?;
     }
 
     // Update the synced state.
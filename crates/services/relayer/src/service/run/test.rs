@@ -7,6 +7,8 @@ async fn can_set_da_height() {
     let mut relayer = MockRelayerData::default();
     relayer.expect_wait_if_eth_syncing().returning(|| Ok(()));
     relayer.expect_update_synced().return_const(());
+    relayer.expect_revert_reorged_blocks().returning(|| Ok(()));
+    relayer.expect_save_checkpoint().returning(|| Ok(()));
     relayer.expect_download_logs().returning(|_| Ok(()));
     test_data_source(
         &mut relayer,
@@ -23,6 +25,8 @@ async fn logs_are_downloaded_and_written() {
     let mut relayer = MockRelayerData::default();
     relayer.expect_wait_if_eth_syncing().returning(|| Ok(()));
     relayer.expect_update_synced().return_const(());
+    relayer.expect_revert_reorged_blocks().returning(|| Ok(()));
+    relayer.expect_save_checkpoint().returning(|| Ok(()));
     relayer
         .expect_download_logs()
         .withf(|gap| gap.oldest() == 0 && gap.latest() == 200)
@@ -37,6 +41,55 @@ async fn logs_are_downloaded_and_written() {
     run(&mut relayer).await.unwrap();
 }
 
+#[tokio::test]
+async fn reorged_blocks_are_reverted_before_sync() {
+    let mut relayer = MockRelayerData::default();
+    let mut seq = mockall::Sequence::new();
+    relayer.expect_wait_if_eth_syncing().returning(|| Ok(()));
+    relayer.expect_update_synced().return_const(());
+    relayer
+        .expect_revert_reorged_blocks()
+        .once()
+        .in_sequence(&mut seq)
+        .returning(|| Ok(()));
+    relayer
+        .expect_download_logs()
+        .once()
+        .in_sequence(&mut seq)
+        .returning(|_| Ok(()));
+    relayer
+        .expect_save_checkpoint()
+        .once()
+        .in_sequence(&mut seq)
+        .returning(|| Ok(()));
+    test_data_source(
+        &mut relayer,
+        TestDataSource {
+            eth_remote_finalized: 200,
+            eth_local_finalized: Some(100),
+        },
+    );
+    run(&mut relayer).await.unwrap();
+}
+
+#[tokio::test]
+async fn checkpoint_is_not_saved_if_synced() {
+    let mut relayer = MockRelayerData::default();
+    relayer.expect_wait_if_eth_syncing().returning(|| Ok(()));
+    relayer.expect_update_synced().return_const(());
+    relayer.expect_revert_reorged_blocks().returning(|| Ok(()));
+    relayer.expect_download_logs().never();
+    relayer.expect_save_checkpoint().never();
+    test_data_source(
+        &mut relayer,
+        TestDataSource {
+            eth_remote_finalized: 200,
+            eth_local_finalized: Some(200),
+        },
+    );
+    run(&mut relayer).await.unwrap();
+}
+
 mockall::mock! {
     RelayerData {}
 
@@ -58,6 +111,10 @@ mockall::mock! {
             eth_sync_gap: &state::EthSyncGap,
         ) -> anyhow::Result<()>;
 
+        async fn revert_reorged_blocks(&mut self) -> anyhow::Result<()>;
+
+        async fn save_checkpoint(&mut self) -> anyhow::Result<()>;
+
         fn update_synced(&self, state: &EthState);
     }
 }
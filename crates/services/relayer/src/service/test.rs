@@ -1,4 +1,11 @@
+#![allow(non_snake_case)]
+
 use crate::test_helpers::middleware::MockMiddleware;
+use ethers_core::types::H256;
+use fuel_core_types::services::relayer::{
+    Event,
+    SyncCheckpoint,
+};
 use futures::TryStreamExt;
 
 use super::*;
@@ -53,7 +60,13 @@ async fn deploy_height_does_not_override() {
         ..Default::default()
     };
     let eth_node = MockMiddleware::default();
-    let relayer = NotInitializedTask::new(eth_node, mock_db.clone(), config);
+    let relayer = NotInitializedTask::new(
+        eth_node,
+        mock_db.clone(),
+        Arc::new(mock_db.clone()),
+        config,
+        Default::default(),
+    );
     let _ = relayer.into_task(&Default::default(), ()).await;
 
     assert_eq!(*mock_db.get_finalized_da_height().unwrap(), 50);
@@ -70,8 +83,261 @@ async fn deploy_height_does_override() {
         ..Default::default()
     };
     let eth_node = MockMiddleware::default();
-    let relayer = NotInitializedTask::new(eth_node, mock_db.clone(), config);
+    let relayer = NotInitializedTask::new(
+        eth_node,
+        mock_db.clone(),
+        Arc::new(mock_db.clone()),
+        config,
+        Default::default(),
+    );
     let _ = relayer.into_task(&Default::default(), ()).await;
 
     assert_eq!(*mock_db.get_finalized_da_height().unwrap(), 52);
 }
+
+#[tokio::test]
+async fn finalized_height_respects_finality_depth() {
+    let eth_node = MockMiddleware::default();
+    eth_node.update_data(|data| data.best_block.number = Some(100.into()));
+
+    let finalized = finalized_height(&eth_node, Some(10)).await.unwrap();
+
+    assert_eq!(finalized, 90);
+}
+
+#[tokio::test]
+async fn finalized_height_uses_finalized_tag_without_finality_depth() {
+    let eth_node = MockMiddleware::default();
+    eth_node.update_data(|data| data.best_block.number = Some(100.into()));
+
+    let finalized = finalized_height(&eth_node, None).await.unwrap();
+
+    assert_eq!(finalized, 100);
+}
+
+fn message_at(da_height: u64, nonce: u64) -> Event {
+    let mut message = Message::default();
+    message.set_da_height(da_height.into());
+    message.set_nonce(nonce.into());
+    message.into()
+}
+
+fn checkpoint_at(height: u64, hash: u8) -> SyncCheckpoint {
+    SyncCheckpoint {
+        height: height.into(),
+        hash: [hash; 32].into(),
+    }
+}
+
+fn canonical_hashes(eth_node: &MockMiddleware, hashes: &[(u64, u8)]) {
+    eth_node.update_data(|data| {
+        for (height, hash) in hashes {
+            data.block_hashes.insert(*height, H256::from([*hash; 32]));
+        }
+    });
+}
+
+/// Reverts the reorged blocks of the `mock_db` that also provides the Fuel blocks.
+async fn revert_reorged_blocks(
+    eth_node: &MockMiddleware,
+    mock_db: &crate::mock_db::MockDb,
+    finality_depth: Option<u64>,
+) -> anyhow::Result<()> {
+    checkpoints::revert_reorged_blocks(
+        eth_node,
+        &mut mock_db.clone(),
+        mock_db,
+        finality_depth,
+    )
+    .await
+}
+
+#[tokio::test]
+async fn revert_reorged_blocks__reverts_to_the_newest_canonical_checkpoint() {
+    // Given
+    let mut mock_db = crate::mock_db::MockDb::default();
+    mock_db
+        .insert_events(&10u64.into(), &[message_at(10, 0)])
+        .unwrap();
+    mock_db.insert_checkpoint(&checkpoint_at(10, 1)).unwrap();
+    mock_db
+        .insert_events(&20u64.into(), &[message_at(20, 1)])
+        .unwrap();
+    mock_db.insert_checkpoint(&checkpoint_at(20, 2)).unwrap();
+    let eth_node = MockMiddleware::default();
+    eth_node.update_data(|data| data.best_block.number = Some(10.into()));
+    canonical_hashes(&eth_node, &[(10, 1), (20, 3)]);
+
+    // When
+    revert_reorged_blocks(&eth_node, &mock_db, None)
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(*mock_db.get_finalized_da_height().unwrap(), 10);
+    assert_eq!(
+        mock_db.get_checkpoints().unwrap(),
+        vec![checkpoint_at(10, 1)]
+    );
+    assert!(mock_db.get_message(&0u64.into()).is_some());
+    assert!(mock_db.get_message(&1u64.into()).is_none());
+}
+
+#[tokio::test]
+async fn revert_reorged_blocks__fails_to_revert_below_finalized_block() {
+    // Given
+    let mut mock_db = crate::mock_db::MockDb::default();
+    mock_db
+        .insert_events(&10u64.into(), &[message_at(10, 0)])
+        .unwrap();
+    mock_db.insert_checkpoint(&checkpoint_at(10, 1)).unwrap();
+    mock_db
+        .insert_events(&20u64.into(), &[message_at(20, 1)])
+        .unwrap();
+    mock_db.insert_checkpoint(&checkpoint_at(20, 2)).unwrap();
+    let eth_node = MockMiddleware::default();
+    eth_node.update_data(|data| data.best_block.number = Some(15.into()));
+    canonical_hashes(&eth_node, &[(10, 1), (20, 3)]);
+
+    // When
+    let result = revert_reorged_blocks(&eth_node, &mock_db, None).await;
+
+    // Then
+    assert!(result.is_err());
+    assert_eq!(*mock_db.get_finalized_da_height().unwrap(), 20);
+    assert!(mock_db.get_message(&1u64.into()).is_some());
+}
+
+#[tokio::test]
+async fn revert_reorged_blocks__does_nothing_without_reorg() {
+    // Given
+    let mut mock_db = crate::mock_db::MockDb::default();
+    mock_db
+        .insert_events(&20u64.into(), &[message_at(20, 1)])
+        .unwrap();
+    mock_db.insert_checkpoint(&checkpoint_at(20, 2)).unwrap();
+    let eth_node = MockMiddleware::default();
+    canonical_hashes(&eth_node, &[(20, 2)]);
+
+    // When
+    revert_reorged_blocks(&eth_node, &mock_db, None)
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(*mock_db.get_finalized_da_height().unwrap(), 20);
+    assert!(mock_db.get_message(&1u64.into()).is_some());
+}
+
+#[tokio::test]
+async fn revert_reorged_blocks__fails_if_reorg_is_deeper_than_checkpoints() {
+    // Given
+    let mut mock_db = crate::mock_db::MockDb::default();
+    mock_db.insert_checkpoint(&checkpoint_at(10, 1)).unwrap();
+    mock_db.insert_checkpoint(&checkpoint_at(20, 2)).unwrap();
+    let eth_node = MockMiddleware::default();
+    canonical_hashes(&eth_node, &[(10, 3), (20, 4)]);
+
+    // When
+    let result = revert_reorged_blocks(&eth_node, &mock_db, None).await;
+
+    // Then
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn revert_reorged_blocks__uses_finality_depth_for_the_finalized_block() {
+    // Given
+    let mut mock_db = crate::mock_db::MockDb::default();
+    mock_db
+        .insert_events(&27u64.into(), &[message_at(27, 0)])
+        .unwrap();
+    mock_db.insert_checkpoint(&checkpoint_at(27, 1)).unwrap();
+    mock_db
+        .insert_events(&29u64.into(), &[message_at(29, 1)])
+        .unwrap();
+    mock_db.insert_checkpoint(&checkpoint_at(29, 2)).unwrap();
+    let eth_node = MockMiddleware::default();
+    // The depth-based finalized block is 25, while the `finalized` tag is 30.
+    eth_node.update_data(|data| data.best_block.number = Some(30.into()));
+    canonical_hashes(&eth_node, &[(27, 1), (29, 3)]);
+    let tag_result = revert_reorged_blocks(&eth_node, &mock_db, None).await;
+
+    // When
+    let depth_result = revert_reorged_blocks(&eth_node, &mock_db, Some(5)).await;
+
+    // Then
+    assert!(tag_result.is_err());
+    assert!(depth_result.is_ok());
+    assert_eq!(*mock_db.get_finalized_da_height().unwrap(), 27);
+    assert!(mock_db.get_message(&0u64.into()).is_some());
+    assert!(mock_db.get_message(&1u64.into()).is_none());
+}
+
+#[tokio::test]
+async fn revert_reorged_blocks__fails_to_revert_below_the_latest_fuel_block() {
+    // Given
+    let mut mock_db = crate::mock_db::MockDb::default();
+    mock_db
+        .insert_events(&10u64.into(), &[message_at(10, 0)])
+        .unwrap();
+    mock_db.insert_checkpoint(&checkpoint_at(10, 1)).unwrap();
+    mock_db
+        .insert_events(&20u64.into(), &[message_at(20, 1)])
+        .unwrap();
+    mock_db.insert_checkpoint(&checkpoint_at(20, 2)).unwrap();
+    mock_db.data.lock().unwrap().latest_block_da_height = Some(20u64.into());
+    let eth_node = MockMiddleware::default();
+    eth_node.update_data(|data| data.best_block.number = Some(10.into()));
+    canonical_hashes(&eth_node, &[(10, 1), (20, 3)]);
+
+    // When
+    let result = revert_reorged_blocks(&eth_node, &mock_db, None).await;
+
+    // Then
+    assert!(result.is_err());
+    assert_eq!(*mock_db.get_finalized_da_height().unwrap(), 20);
+    assert!(mock_db.get_message(&1u64.into()).is_some());
+}
+
+#[tokio::test]
+async fn save_checkpoint__records_the_hash_of_the_synced_block() {
+    // Given
+    let mut mock_db = crate::mock_db::MockDb::default();
+    mock_db
+        .set_finalized_da_height_to_at_least(&30u64.into())
+        .unwrap();
+    let eth_node = MockMiddleware::default();
+    canonical_hashes(&eth_node, &[(30, 5)]);
+
+    // When
+    checkpoints::save_checkpoint(&eth_node, &mut mock_db)
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(
+        mock_db.get_checkpoints().unwrap(),
+        vec![checkpoint_at(30, 5)]
+    );
+}
+
+#[test]
+fn insert_checkpoint__keeps_only_the_latest_checkpoints() {
+    // Given
+    let mut mock_db = crate::mock_db::MockDb::default();
+    let checkpoints = (crate::storage::MAX_SYNC_CHECKPOINTS as u64).saturating_add(1);
+
+    // When
+    for height in 1..=checkpoints {
+        mock_db
+            .insert_checkpoint(&checkpoint_at(height, 1))
+            .unwrap();
+    }
+
+    // Then
+    let stored = mock_db.get_checkpoints().unwrap();
+    assert_eq!(stored.len(), crate::storage::MAX_SYNC_CHECKPOINTS);
+    assert_eq!(stored.first().unwrap().height, 2u64.into());
+    assert_eq!(stored.last().unwrap().height, checkpoints.into());
+}
@@ -19,7 +19,10 @@ use fuel_core_storage::{
 };
 use fuel_core_types::{
     blockchain::primitives::DaBlockHeight,
-    services::relayer::Event,
+    services::relayer::{
+        Event,
+        SyncCheckpoint,
+    },
 };
 
 /// GraphQL database tables column ids to the corresponding [`fuel_core_storage::Mappable`] table.
@@ -42,6 +45,8 @@ pub enum Column {
     History = 1,
     /// The column that tracks the da height of the relayer.
     RelayerHeight = 2,
+    /// The column that tracks the checkpoints of the synced da blocks.
+    Checkpoints = 3,
 }
 
 impl Column {
@@ -108,13 +113,38 @@ impl TableWithBlueprint for EventsHistory {
     }
 }
 
+/// The maximum number of checkpoints stored by the relayer. The relayer can
+/// roll back its state only to the oldest stored checkpoint.
+pub const MAX_SYNC_CHECKPOINTS: usize = 64;
+
+/// The table contains checkpoints of the synced DA blocks, ordered from the oldest to the newest.
+pub struct SyncCheckpoints;
+
+impl Mappable for SyncCheckpoints {
+    type Key = Self::OwnedKey;
+    type OwnedKey = ();
+    type Value = [SyncCheckpoint];
+    type OwnedValue = Vec<SyncCheckpoint>;
+}
+
+impl TableWithBlueprint for SyncCheckpoints {
+    type Blueprint = Plain<Postcard, Postcard>;
+    type Column = Column;
+
+    fn column() -> Column {
+        Column::Checkpoints
+    }
+}
+
 impl<T, Storage> RelayerDb for T
 where
     T: Send + Sync,
     T: Transactional<Storage = Storage>,
-    T: StorageMutate<DaHeightTable, Error = StorageError>,
+    T: StorageMutate<DaHeightTable, Error = StorageError>
+        + StorageMutate<SyncCheckpoints, Error = StorageError>,
     Storage: StorageMutate<EventsHistory, Error = StorageError>
-        + StorageMutate<DaHeightTable, Error = StorageError>,
+        + StorageMutate<DaHeightTable, Error = StorageError>
+        + StorageMutate<SyncCheckpoints, Error = StorageError>,
 {
     fn insert_events(
         &mut self,
@@ -164,6 +194,66 @@ where
             .get(&METADATA_KEY)?
             .unwrap_or_default())
     }
+
+    fn insert_checkpoint(&mut self, checkpoint: &SyncCheckpoint) -> StorageResult<()> {
+        let mut checkpoints = self.get_checkpoints()?;
+        checkpoints.retain(|c| c.height < checkpoint.height);
+        checkpoints.push(*checkpoint);
+        let outdated = checkpoints.len().saturating_sub(MAX_SYNC_CHECKPOINTS);
+        checkpoints.drain(..outdated);
+        self.storage::<SyncCheckpoints>()
+            .insert(&METADATA_KEY, &checkpoints)?;
+        Ok(())
+    }
+
+    fn get_checkpoints(&self) -> StorageResult<Vec<SyncCheckpoint>> {
+        Ok(StorageAsRef::storage::<SyncCheckpoints>(&self)
+            .get(&METADATA_KEY)?
+            .map(|cow| cow.into_owned())
+            .unwrap_or_default())
+    }
+
+    fn rollback_to(
+        &mut self,
+        height: &DaBlockHeight,
+        finalized: &DaBlockHeight,
+    ) -> StorageResult<()> {
+        if height < finalized {
+            return Err(anyhow::anyhow!(
+                "Can't roll back to {height} below the finalized da height {finalized}"
+            )
+            .into())
+        }
+
+        // A transaction is required to ensure that events, checkpoints and the height
+        // are reverted atomically.
+        let mut db_tx = self.transaction();
+        let db = db_tx.as_mut();
+
+        let current = (&db)
+            .storage::<DaHeightTable>()
+            .get(&METADATA_KEY)?
+            .map(|cow| cow.as_u64())
+            .unwrap_or_default();
+        let mut reverted = height.as_u64().saturating_add(1);
+        while reverted <= current {
+            db.storage::<EventsHistory>().remove(&reverted.into())?;
+            reverted = reverted.saturating_add(1);
+        }
+
+        let mut checkpoints = (&db)
+            .storage::<SyncCheckpoints>()
+            .get(&METADATA_KEY)?
+            .map(|cow| cow.into_owned())
+            .unwrap_or_default();
+        checkpoints.retain(|c| c.height <= *height);
+        db.storage::<SyncCheckpoints>()
+            .insert(&METADATA_KEY, &checkpoints)?;
+        db.storage::<DaHeightTable>()
+            .insert(&METADATA_KEY, height)?;
+        db_tx.commit()?;
+        Ok(())
+    }
 }
 
 fn grow_monotonically<Storage>(
@@ -205,4 +295,13 @@ mod tests {
         <EventsHistory as Mappable>::Key::default(),
         vec![Event::Message(Default::default())]
     );
+
+    fuel_core_storage::basic_storage_tests!(
+        SyncCheckpoints,
+        <SyncCheckpoints as Mappable>::Key::default(),
+        vec![SyncCheckpoint {
+            height: Default::default(),
+            hash: Default::default(),
+        }]
+    );
 }
@@ -2,6 +2,7 @@ use async_trait::async_trait;
 use ethers_core::types::{
     Block,
     BlockId,
+    BlockNumber,
     Filter,
     Log,
     SyncingStatus,
@@ -23,6 +24,7 @@ use serde::{
     Serialize,
 };
 use std::{
+    collections::HashMap,
     fmt,
     fmt::Debug,
     str::FromStr,
@@ -53,6 +55,8 @@ pub struct MockData {
     pub best_block: Block<TxHash>,
     pub logs_batch: Vec<Vec<Log>>,
     pub logs_batch_index: usize,
+    /// Overrides the hash of the `best_block` when it is requested by the number.
+    pub block_hashes: HashMap<u64, H256>,
 }
 
 impl MockMiddleware {
@@ -143,6 +147,7 @@ impl Default for MockData {
             is_syncing: SyncingStatus::IsFalse,
             logs_batch: Vec::new(),
             logs_batch_index: 0,
+            block_hashes: HashMap::new(),
         }
     }
 }
@@ -298,7 +303,16 @@ impl Middleware for MockMiddleware {
         tokio::task::yield_now().await;
         let block_id = block_hash_or_number.into();
         self.before_event(TriggerType::GetBlock(block_id));
-        let r = Ok(Some(self.update_data(|data| data.best_block.clone())));
+        let r = Ok(Some(self.update_data(|data| {
+            let mut block = data.best_block.clone();
+            if let BlockId::Number(BlockNumber::Number(number)) = block_id {
+                if let Some(hash) = data.block_hashes.get(&number.as_u64()) {
+                    block.number = Some(number);
+                    block.hash = Some(*hash);
+                }
+            }
+            block
+        })));
         self.after_event(TriggerType::GetBlock(block_id));
         r
     }
@@ -17,6 +17,7 @@ use fuel_core_relayer::{
     Config,
 };
 use fuel_core_services::Service;
+use std::sync::Arc;
 
 fuel_core_trace::enable_tracing!();
 
@@ -27,7 +28,12 @@ async fn can_set_da_height() {
     // Setup the eth node with a block high enough that there
     // will be some finalized blocks.
     eth_node.update_data(|data| data.best_block.number = Some(100.into()));
-    let relayer = new_service_test(eth_node, mock_db.clone(), Default::default());
+    let relayer = new_service_test(
+        eth_node,
+        mock_db.clone(),
+        Arc::new(mock_db.clone()),
+        Default::default(),
+    );
     relayer.start_and_await().await.unwrap();
 
     relayer.shared.await_synced().await.unwrap();
@@ -45,7 +51,12 @@ async fn stop_service_at_the_begin() {
     // Setup the eth node with a block high enough that there
     // will be some finalized blocks.
     eth_node.update_data(|data| data.best_block.number = Some(100.into()));
-    let relayer = new_service_test(eth_node, mock_db.clone(), Default::default());
+    let relayer = new_service_test(
+        eth_node,
+        mock_db.clone(),
+        Arc::new(mock_db.clone()),
+        Default::default(),
+    );
     relayer.start_and_await().await.unwrap();
     relayer.stop();
 
@@ -66,7 +77,8 @@ async fn stop_service_at_the_middle() {
         log_page_size: 5,
         ..Default::default()
     };
-    let relayer = new_service_test(eth_node, mock_db.clone(), config);
+    let relayer =
+        new_service_test(eth_node, mock_db.clone(), Arc::new(mock_db.clone()), config);
     relayer.start_and_await().await.unwrap();
 
     // Skip the initial requests to start the synchronization.
@@ -114,7 +126,8 @@ async fn can_get_messages() {
     // Setup the eth node with a block high enough that there
     // will be some finalized blocks.
     eth_node.update_data(|data| data.best_block.number = Some(100.into()));
-    let relayer = new_service_test(eth_node, mock_db.clone(), config);
+    let relayer =
+        new_service_test(eth_node, mock_db.clone(), Arc::new(mock_db.clone()), config);
     relayer.start_and_await().await.unwrap();
 
     relayer.shared.await_synced().await.unwrap();
@@ -155,7 +168,8 @@ async fn deploy_height_is_set() {
             }
         }
     });
-    let relayer = new_service_test(eth_node, mock_db.clone(), config);
+    let relayer =
+        new_service_test(eth_node, mock_db.clone(), Arc::new(mock_db.clone()), config);
     relayer.start_and_await().await.unwrap();
     relayer.shared.await_synced().await.unwrap();
     rx.await.unwrap();
@@ -0,0 +1,296 @@
+//! The module collects the health of services.
+//!
+//! Each service reports its health via the [`HealthReporter`] registered in the
+//! [`HealthRegistry`]. The service is alive while its [`State`](crate::State) is started.
+//! The service reports the progress of its work and the errors it faced, so the
+//! [`HealthReport`] distinguishes the service that is up but stalled from the healthy one.
+
+use crate::{
+    SharedMutex,
+    StateWatcher,
+};
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    time::{
+        Duration,
+        SystemTime,
+    },
+};
+
+#[derive(Default)]
+struct Component {
+    state: Option<StateWatcher>,
+    registered_at: Option<SystemTime>,
+    last_progress: Option<SystemTime>,
+    error: Option<String>,
+    stall_timeout: Option<Duration>,
+}
+
+type Components = SharedMutex<BTreeMap<&'static str, Component>>;
+
+/// The registry of the health of services.
+#[derive(Clone)]
+pub struct HealthRegistry {
+    components: Components,
+}
+
+impl Default for HealthRegistry {
+    fn default() -> Self {
+        Self {
+            components: SharedMutex::new(BTreeMap::new()),
+        }
+    }
+}
+
+impl core::fmt::Debug for HealthRegistry {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("HealthRegistry")
+            .field("components", &self.report().components)
+            .finish()
+    }
+}
+
+impl HealthRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the service with the `name` and returns the reporter of its health.
+    /// The service registered twice shares the health.
+    pub fn register(&self, name: &'static str) -> HealthReporter {
+        self.components.apply(|components| {
+            components.entry(name).or_insert_with(|| Component {
+                registered_at: Some(SystemTime::now()),
+                ..Default::default()
+            });
+        });
+        HealthReporter {
+            name,
+            components: Some(self.components.clone()),
+        }
+    }
+
+    /// Returns the current health of all registered services.
+    pub fn report(&self) -> HealthReport {
+        let now = SystemTime::now();
+        let components = self.components.apply(|components| {
+            components
+                .iter()
+                .map(|(name, component)| {
+                    let alive = component
+                        .state
+                        .as_ref()
+                        .map(|state| state.borrow().started())
+                        .unwrap_or(true);
+                    let since = component.last_progress.or(component.registered_at);
+                    let stalled = match (component.stall_timeout, since) {
+                        (Some(timeout), Some(since)) => now
+                            .duration_since(since)
+                            .map(|elapsed| elapsed > timeout)
+                            .unwrap_or(false),
+                        _ => false,
+                    };
+                    let health = ComponentHealth {
+                        alive,
+                        last_progress: component.last_progress,
+                        error: component.error.clone(),
+                        stalled,
+                    };
+                    (*name, health)
+                })
+                .collect()
+        });
+        HealthReport { components }
+    }
+}
+
+/// The reporter of the health of the service. The default reporter
+/// is not registered anywhere and ignores all reports.
+#[derive(Clone, Default)]
+pub struct HealthReporter {
+    name: &'static str,
+    components: Option<Components>,
+}
+
+impl core::fmt::Debug for HealthReporter {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("HealthReporter")
+            .field("name", &self.name)
+            .finish()
+    }
+}
+
+impl HealthReporter {
+    /// The service is considered stalled if it doesn't report the progress
+    /// within the `timeout`.
+    pub fn with_stall_timeout(self, timeout: Duration) -> Self {
+        self.update(|component| component.stall_timeout = Some(timeout));
+        self
+    }
+
+    /// Uses the `state` of the service to determine whether it is alive.
+    /// The component without the state, like the block importer, is always alive.
+    pub fn watch(&self, state: StateWatcher) {
+        self.update(|component| component.state = Some(state));
+    }
+
+    /// Reports the progress of the service. It clears the last reported error.
+    pub fn progress(&self) {
+        self.update(|component| {
+            component.last_progress = Some(SystemTime::now());
+            component.error = None;
+        });
+    }
+
+    /// Reports the error faced by the service. The error is kept until the next progress.
+    pub fn error(&self, error: impl Display) {
+        let error = error.to_string();
+        self.update(|component| component.error = Some(error));
+    }
+
+    fn update(&self, f: impl FnOnce(&mut Component)) {
+        if let Some(components) = &self.components {
+            components.apply(|components| {
+                if let Some(component) = components.get_mut(self.name) {
+                    f(component)
+                }
+            })
+        }
+    }
+}
+
+/// The health of the service.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComponentHealth {
+    /// The service is started and running.
+    pub alive: bool,
+    /// The time of the last reported progress.
+    pub last_progress: Option<SystemTime>,
+    /// The last error faced by the service since the last progress.
+    pub error: Option<String>,
+    /// The service didn't report the progress within its stall timeout.
+    pub stalled: bool,
+}
+
+impl ComponentHealth {
+    /// The service is alive, doesn't have errors, and is not stalled.
+    pub fn is_ready(&self) -> bool {
+        self.alive && self.error.is_none() && !self.stalled
+    }
+}
+
+/// The health of all registered services.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HealthReport {
+    /// The health of services ordered by their names.
+    pub components: Vec<(&'static str, ComponentHealth)>,
+}
+
+impl HealthReport {
+    /// All services are alive.
+    pub fn is_live(&self) -> bool {
+        self.components.iter().all(|(_, health)| health.alive)
+    }
+
+    /// All services are ready.
+    pub fn is_ready(&self) -> bool {
+        self.components.iter().all(|(_, health)| health.is_ready())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::State;
+    use tokio::sync::watch;
+
+    fn started() -> (watch::Sender<State>, StateWatcher) {
+        let (sender, receiver) = watch::channel(State::Started);
+        (sender, receiver.into())
+    }
+
+    #[test]
+    fn report_is_ready_when_all_services_are_alive_without_errors() {
+        let registry = HealthRegistry::new();
+        let (_txpool_state, txpool_watcher) = started();
+        let (_importer_state, importer_watcher) = started();
+        registry.register("txpool").watch(txpool_watcher);
+        let importer = registry.register("importer");
+        importer.watch(importer_watcher);
+        importer.progress();
+
+        let report = registry.report();
+
+        assert!(report.is_live());
+        assert!(report.is_ready());
+        assert_eq!(report.components.len(), 2);
+        assert_eq!(report.components[0].0, "importer");
+        assert!(report.components[0].1.last_progress.is_some());
+    }
+
+    #[test]
+    fn report_is_not_live_when_service_is_stopped() {
+        let registry = HealthRegistry::new();
+        let (state, watcher) = started();
+        registry.register("p2p").watch(watcher);
+
+        state
+            .send(State::StoppedWithError("panic".to_string()))
+            .unwrap();
+
+        let report = registry.report();
+        assert!(!report.is_live());
+        assert!(!report.is_ready());
+    }
+
+    #[test]
+    fn error_makes_service_not_ready_until_progress() {
+        let registry = HealthRegistry::new();
+        let (_state, watcher) = started();
+        let relayer = registry.register("relayer");
+        relayer.watch(watcher);
+
+        relayer.error("Failed to download logs");
+
+        let report = registry.report();
+        assert!(report.is_live());
+        assert!(!report.is_ready());
+        assert_eq!(
+            report.components[0].1.error.as_deref(),
+            Some("Failed to download logs")
+        );
+
+        relayer.progress();
+        assert!(registry.report().is_ready());
+    }
+
+    #[test]
+    fn service_without_progress_within_stall_timeout_is_stalled() {
+        let registry = HealthRegistry::new();
+        let (_state, watcher) = started();
+        let importer = registry
+            .register("importer")
+            .with_stall_timeout(Duration::from_millis(10));
+        importer.watch(watcher);
+        assert!(registry.report().is_ready());
+
+        std::thread::sleep(Duration::from_millis(20));
+
+        let report = registry.report();
+        assert!(report.is_live());
+        assert!(report.components[0].1.stalled);
+        assert!(!report.is_ready());
+        importer.progress();
+        assert!(registry.report().is_ready());
+    }
+
+    #[test]
+    fn default_reporter_ignores_reports() {
+        let reporter = HealthReporter::default();
+
+        reporter.progress();
+        reporter.error("error");
+    }
+}
@@ -5,7 +5,9 @@
 #![deny(missing_docs)]
 #![deny(warnings)]
 
+mod health;
 mod service;
+mod shutdown;
 mod state;
 
 /// Re-exports for streaming utilities
@@ -39,6 +41,12 @@ pub mod stream {
     impl<S> IntoBoxStream for S where S: Stream + Send + Sync + 'static {}
 }
 
+pub use health::{
+    ComponentHealth,
+    HealthRegistry,
+    HealthReport,
+    HealthReporter,
+};
 pub use service::{
     EmptyShared,
     RunnableService,
@@ -48,6 +56,11 @@ pub use service::{
     Shared,
     SharedMutex,
 };
+pub use shutdown::{
+    CriticalSection,
+    ShutdownManager,
+    ShutdownToken,
+};
 pub use state::{
     State,
     StateWatcher,
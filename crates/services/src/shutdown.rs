@@ -0,0 +1,186 @@
+//! The module coordinates the graceful shutdown of services.
+//!
+//! The [`ShutdownManager`] owns the cancellation signal shared by all [`ShutdownToken`]s.
+//! The token allows to stop starting new work once the shutdown is requested
+//! and to mark the work that must not be interrupted as a [`CriticalSection`].
+//! The manager waits for all critical sections to finish before the node stops
+//! sub-services and drops databases.
+//!
+//! For now, only the block importer uses the token to guard the commit of the block.
+//! Other services are stopped without waiting for their writes.
+
+use crate::service::Shared;
+use std::time::Duration;
+use tokio::sync::watch;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct ShutdownState {
+    cancelled: bool,
+    critical_sections: usize,
+}
+
+fn new_state() -> Shared<watch::Sender<ShutdownState>> {
+    let (sender, _) = watch::channel(ShutdownState::default());
+    Shared::new(sender)
+}
+
+/// The manager of the graceful shutdown.
+#[derive(Debug)]
+pub struct ShutdownManager {
+    state: Shared<watch::Sender<ShutdownState>>,
+}
+
+impl Default for ShutdownManager {
+    fn default() -> Self {
+        Self { state: new_state() }
+    }
+}
+
+impl ShutdownManager {
+    /// Creates a new manager without requested shutdown.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the token to listen for the shutdown.
+    pub fn token(&self) -> ShutdownToken {
+        ShutdownToken {
+            state: self.state.clone(),
+        }
+    }
+
+    /// Requests the shutdown. After this call, no new critical section can be entered.
+    pub fn cancel(&self) {
+        self.state.send_if_modified(|state| {
+            let modified = !state.cancelled;
+            state.cancelled = true;
+            modified
+        });
+    }
+
+    /// Requests the shutdown and waits until all entered critical sections are finished.
+    /// Returns `false` if critical sections are still in progress after the `timeout`.
+    pub async fn shutdown(&self, timeout: Duration) -> bool {
+        self.cancel();
+        let mut receiver = self.state.subscribe();
+        let drained = async move {
+            // The sender is held by `self`, so the channel can't be closed.
+            let _ = receiver
+                .wait_for(|state| state.critical_sections == 0)
+                .await;
+        };
+        tokio::time::timeout(timeout, drained).await.is_ok()
+    }
+}
+
+/// The token to listen for the shutdown requested by the [`ShutdownManager`].
+/// The default token belongs to the manager that never requests the shutdown.
+#[derive(Debug, Clone)]
+pub struct ShutdownToken {
+    state: Shared<watch::Sender<ShutdownState>>,
+}
+
+impl Default for ShutdownToken {
+    fn default() -> Self {
+        Self { state: new_state() }
+    }
+}
+
+impl ShutdownToken {
+    /// Returns `true` if the shutdown was requested.
+    pub fn is_cancelled(&self) -> bool {
+        self.state.borrow().cancelled
+    }
+
+    /// Waits until the shutdown is requested.
+    pub async fn cancelled(&self) {
+        let mut receiver = self.state.subscribe();
+        // The sender is held by `self`, so the channel can't be closed.
+        let _ = receiver.wait_for(|state| state.cancelled).await;
+    }
+
+    /// Enters the critical section that the shutdown waits for.
+    /// Returns `None` if the shutdown was already requested, so the work should not be started.
+    pub fn critical_section(&self) -> Option<CriticalSection> {
+        let entered = self.state.send_if_modified(|state| {
+            if state.cancelled {
+                false
+            } else {
+                state.critical_sections = state.critical_sections.saturating_add(1);
+                true
+            }
+        });
+        entered.then(|| CriticalSection {
+            state: self.state.clone(),
+        })
+    }
+}
+
+/// The guard of the critical section. The section ends when the guard is dropped.
+#[derive(Debug)]
+pub struct CriticalSection {
+    state: Shared<watch::Sender<ShutdownState>>,
+}
+
+impl Drop for CriticalSection {
+    fn drop(&mut self) {
+        self.state.send_modify(|state| {
+            state.critical_sections = state.critical_sections.saturating_sub(1);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn critical_section_is_not_entered_after_cancel() {
+        let manager = ShutdownManager::new();
+        let token = manager.token();
+        assert!(token.critical_section().is_some());
+
+        manager.cancel();
+
+        assert!(token.is_cancelled());
+        assert!(token.critical_section().is_none());
+    }
+
+    #[tokio::test]
+    async fn shutdown_waits_for_critical_sections() {
+        let manager = ShutdownManager::new();
+        let token = manager.token();
+        let section = token.critical_section().unwrap();
+
+        let release = tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            drop(section);
+        });
+
+        assert!(manager.shutdown(Duration::from_secs(10)).await);
+        release.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn shutdown_times_out_if_critical_section_is_not_finished() {
+        let manager = ShutdownManager::new();
+        let token = manager.token();
+        let _section = token.critical_section().unwrap();
+
+        assert!(!manager.shutdown(Duration::from_millis(100)).await);
+    }
+
+    #[tokio::test]
+    async fn cancelled_resolves_after_cancel() {
+        let manager = ShutdownManager::new();
+        let token = manager.token();
+        let listener = tokio::spawn(async move { token.cancelled().await });
+
+        manager.cancel();
+
+        tokio::time::timeout(Duration::from_secs(10), listener)
+            .await
+            .expect("Should be cancelled")
+            .unwrap();
+    }
+}
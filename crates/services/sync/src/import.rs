@@ -3,6 +3,7 @@
 //! importing blocks from the network into the local blockchain.
 
 use fuel_core_services::{
+    HealthReporter,
     SharedMutex,
     StateWatcher,
 };
@@ -13,7 +14,10 @@ use fuel_core_types::{
         SealedBlock,
         SealedBlockHeader,
     },
-    fuel_types::BlockHeight,
+    fuel_types::{
+        canonical::Serialize,
+        BlockHeight,
+    },
     services::p2p::{
         PeerId,
         SourcePeer,
@@ -37,6 +41,10 @@ use tokio::sync::Notify;
 use tracing::Instrument;
 
 use crate::{
+    import::budget::{
+        InFlightBudget,
+        Reservation,
+    },
     ports::{
         BlockImporterPort,
         ConsensusPort,
@@ -47,6 +55,8 @@ use crate::{
     tracing_helpers::TraceErr,
 };
 
+mod budget;
+
 #[cfg(any(test, feature = "benchmarking"))]
 /// Accessories for testing the sync. Available only when compiling under test
 /// or benchmarking.
@@ -65,6 +75,9 @@ pub struct Config {
     pub block_stream_buffer_size: usize,
     /// The maximum number of headers to request in a single batch.
     pub header_batch_size: usize,
+    /// The maximum number of bytes of blocks downloaded or being downloaded and waiting
+    /// for the execution. A new batch of headers is requested only below the limit.
+    pub max_in_flight_bytes: usize,
 }
 
 impl Default for Config {
@@ -72,6 +85,7 @@ impl Default for Config {
         Self {
             block_stream_buffer_size: 10,
             header_batch_size: 100,
+            max_in_flight_bytes: 64 * 1024 * 1024,
         }
     }
 }
@@ -91,6 +105,8 @@ pub struct Import<P, E, C> {
     executor: Arc<E>,
     /// Consensus port.
     consensus: Arc<C>,
+    /// The reporter of the health of the import.
+    health: HealthReporter,
 }
 
 impl<P, E, C> Import<P, E, C> {
@@ -111,9 +127,16 @@ impl<P, E, C> Import<P, E, C> {
             p2p,
             executor,
             consensus,
+            health: HealthReporter::default(),
         }
     }
 
+    /// Reports the progress of the import and its failures to the `health` reporter.
+    pub fn with_health(mut self, health: HealthReporter) -> Self {
+        self.health = health;
+        self
+    }
+
     /// Signal other asynchronous tasks that an import event has occurred.
     pub fn notify_one(&self) {
         self.notify.notify_one()
@@ -125,6 +148,8 @@ struct Batch<T> {
     peer: PeerId,
     range: Range<u32>,
     results: Vec<T>,
+    /// The part of the in-flight budget used by `results`.
+    reservation: Option<Reservation>,
 }
 
 impl<T> Batch<T> {
@@ -133,9 +158,15 @@ impl<T> Batch<T> {
             peer,
             range,
             results,
+            reservation: None,
         }
     }
 
+    pub fn with_reservation(mut self, reservation: Reservation) -> Self {
+        self.reservation = Some(reservation);
+        self
+    }
+
     pub fn is_err(&self) -> bool {
         self.results.len() < self.range.len()
     }
@@ -167,6 +198,10 @@ where
             // Get the size of the range.
             let range_len = range.size_hint().0;
 
+            if count > 0 {
+                self.health.progress();
+            }
+
             // If we did not process the entire range, mark the failed heights as failed.
             if count < range_len {
                 let count = u32::try_from(count)
@@ -174,10 +209,12 @@ where
                 let incomplete_range = range.start().saturating_add(count)..=*range.end();
                 self.state
                     .apply(|s| s.failed_to_process(incomplete_range.clone()));
-                Err(anyhow::anyhow!(
+                let error = anyhow::anyhow!(
                     "Failed to import range of blocks: {:?}",
                     incomplete_range
-                ))?;
+                );
+                self.health.error(&error);
+                Err(error)?;
             }
         }
         Ok(())
@@ -208,8 +245,14 @@ where
         let (shutdown_guard, mut shutdown_guard_recv) =
             tokio::sync::mpsc::channel::<()>(1);
 
-        let block_stream =
-            get_block_stream(range.clone(), params, p2p.clone(), consensus.clone());
+        let budget = InFlightBudget::new(params.max_in_flight_bytes);
+        let block_stream = get_block_stream(
+            range.clone(),
+            params,
+            p2p.clone(),
+            consensus.clone(),
+            budget,
+        );
         let result = block_stream
             .map(move |stream_block_batch| {
                 let shutdown_guard = shutdown_guard.clone();
@@ -249,6 +292,7 @@ where
                         peer,
                         range,
                         results,
+                        reservation,
                     } = batch;
 
                     let mut done = vec![];
@@ -267,6 +311,8 @@ where
                             },
                         };
                     }
+                    // The blocks are committed, so they don't use the budget anymore.
+                    drop(reservation);
 
                     let batch = Batch::new(peer.clone(), range, done);
 
@@ -303,8 +349,10 @@ fn get_block_stream<
     params: &Config,
     p2p: Arc<P>,
     consensus: Arc<C>,
+    budget: Arc<InFlightBudget>,
 ) -> impl Stream<Item = impl Future<Output = SealedBlockBatch>> + '_ {
-    let header_stream = get_header_batch_stream(range.clone(), params, p2p.clone());
+    let header_stream =
+        get_header_batch_stream(range.clone(), params, p2p.clone(), budget);
     header_stream
         .map({
             let consensus = consensus.clone();
@@ -314,6 +362,7 @@ fn get_block_stream<
                     peer,
                     range,
                     results,
+                    reservation,
                 } = header_batch;
                 let checked_headers = results
                     .into_iter()
@@ -321,29 +370,29 @@ fn get_block_stream<
                         check_sealed_header(header, peer.clone(), &p2p, &consensus)
                     })
                     .collect::<Vec<_>>();
-                Batch::new(peer, range, checked_headers)
+                Batch {
+                    peer,
+                    range,
+                    results: checked_headers,
+                    reservation,
+                }
             }
         })
         .map(move |headers| {
             let consensus = consensus.clone();
             let p2p = p2p.clone();
             async move {
-                let Batch {
-                    peer,
-                    range,
-                    results,
-                } = headers;
-                if results.is_empty() {
-                    SealedBlockBatch::new(peer, range, vec![])
+                if headers.results.is_empty() {
+                    SealedBlockBatch::new(headers.peer, headers.range, vec![])
                 } else {
                     await_da_height(
-                        results
+                        headers
+                            .results
                             .last()
                             .expect("We checked headers are not empty above"),
                         &consensus,
                     )
                     .await;
-                    let headers = SealedHeaderBatch::new(peer, range, results);
                     get_blocks(&p2p, headers).await
                 }
             }
@@ -352,10 +401,12 @@ fn get_block_stream<
         })
 }
 
+/// The download of headers waits until the `budget` has capacity for new blocks.
 fn get_header_batch_stream<P: PeerToPeerPort + Send + Sync + 'static>(
     range: RangeInclusive<u32>,
     params: &Config,
     p2p: Arc<P>,
+    budget: Arc<InFlightBudget>,
 ) -> impl Stream<Item = SealedHeaderBatch> {
     let Config {
         header_batch_size, ..
@@ -363,7 +414,15 @@ fn get_header_batch_stream<P: PeerToPeerPort + Send + Sync + 'static>(
     let ranges = range_chunks(range, *header_batch_size);
     futures::stream::iter(ranges).then(move |range| {
         let p2p = p2p.clone();
-        async move { get_headers_batch(range, &p2p).await }
+        let budget = budget.clone();
+        async move {
+            let blocks = range.len();
+            let bytes = blocks.saturating_mul(core::mem::size_of::<SealedBlockHeader>());
+            let reservation = budget.acquire(blocks, bytes).await;
+            get_headers_batch(range, &p2p)
+                .await
+                .with_reservation(reservation)
+        }
     })
 }
 
@@ -461,6 +520,8 @@ where
         .trace_err("Failed to get transactions");
     match res {
         Ok(Some(transactions)) => Some(transactions),
+        // The request failed on our side, so the peer is not at fault.
+        Err(e) if !e.is_retryable() => None,
         _ => {
             report_peer(p2p, peer_id.clone(), PeerReportReason::MissingTransactions);
             None
@@ -510,7 +571,8 @@ where
         .trace_err(&format!("Failed to report peer {:?}", peer_id));
 }
 
-/// Get blocks correlating to the headers from a specific peer
+/// Get blocks correlating to the headers from a specific peer.
+/// The downloaded transactions extend the budget reserved for the headers.
 #[tracing::instrument(skip(p2p, headers))]
 async fn get_blocks<P>(p2p: &Arc<P>, headers: SealedHeaderBatch) -> SealedBlockBatch
 where
@@ -520,12 +582,26 @@ where
         results: headers,
         peer,
         range,
+        mut reservation,
     } = headers;
     let Some(transaction_data) = get_transactions(peer.clone(), range.clone(), p2p).await
     else {
         return Batch::new(peer, range, vec![])
     };
 
+    let bytes = headers.iter().zip(transaction_data.iter()).fold(
+        0usize,
+        |bytes, (_, transactions)| {
+            transactions
+                .0
+                .iter()
+                .fold(bytes, |bytes, tx| bytes.saturating_add(tx.size()))
+        },
+    );
+    if let Some(reservation) = &mut reservation {
+        reservation.add_bytes(bytes);
+    }
+
     let iter = headers.into_iter().zip(transaction_data.into_iter());
     let mut blocks = vec![];
     for (block_header, transactions) in iter {
@@ -545,7 +621,12 @@ where
             break
         }
     }
-    Batch::new(peer, range, blocks)
+    Batch {
+        peer,
+        range,
+        results: blocks,
+        reservation,
+    }
 }
 
 #[tracing::instrument(
@@ -24,6 +24,7 @@ struct Input {
     Config{
         block_stream_buffer_size: 1,
         header_batch_size: 1,
+        max_in_flight_bytes: usize::MAX,
     }
     => Count::default() ; "Empty sanity test"
 )]
@@ -36,6 +37,7 @@ struct Input {
     Config{
         block_stream_buffer_size: 1,
         header_batch_size: 1,
+        max_in_flight_bytes: usize::MAX,
     }
     => is less_or_equal_than Count{ headers: 1, consensus: 1, transactions: 1, executes: 1, blocks: 1 }
     ; "Single with slow headers"
@@ -49,6 +51,7 @@ struct Input {
     Config{
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_in_flight_bytes: usize::MAX,
     }
     => is less_or_equal_than Count{ headers: 10, consensus: 10, transactions: 10, executes: 1, blocks: 21 }
     ; "100 headers with max 10 with slow headers"
@@ -62,6 +65,7 @@ struct Input {
     Config{
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_in_flight_bytes: usize::MAX,
     }
     => is less_or_equal_than Count{ headers: 10, consensus: 10, transactions: 10, executes: 1, blocks: 21 }
     ; "100 headers with max 10 with slow transactions"
@@ -75,6 +79,7 @@ struct Input {
     Config{
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_in_flight_bytes: usize::MAX,
     }
     => is less_or_equal_than Count{ headers: 10, consensus: 10, transactions: 10, executes: 1, blocks: 21 }
     ; "50 headers with max 10 with slow executes"
@@ -88,12 +93,40 @@ struct Input {
     Config{
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_in_flight_bytes: usize::MAX,
     }
     => is less_or_equal_than Count{ headers: 10, consensus: 10, transactions: 10, executes: 1, blocks: 21 }
     ; "50 headers with max 10 size and max 10 requests"
 )]
 #[tokio::test(flavor = "multi_thread")]
 async fn test_back_pressure(input: Input, state: State, params: Config) -> Count {
+    run_import(input, state, params).await
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn in_flight_budget_smaller_than_a_block_caps_the_import_at_one_batch() {
+    // Given
+    let input = Input {
+        executes: Duration::from_millis(10),
+        ..Default::default()
+    };
+    let params = Config {
+        block_stream_buffer_size: 10,
+        header_batch_size: 10,
+        max_in_flight_bytes: 1,
+    };
+
+    // When
+    let max = run_import(input, State::new(None, 50), params).await;
+
+    // Then
+    assert_eq!(max.headers, 1);
+    assert_eq!(max.transactions, 10);
+    assert_eq!(max.blocks, 10);
+    assert_eq!(max.executes, 1);
+}
+
+async fn run_import(input: Input, state: State, params: Config) -> Count {
     let counts = SharedCounts::new(Default::default());
     let state = SharedMutex::new(state);
 
@@ -112,6 +145,7 @@ async fn test_back_pressure(input: Input, state: State, params: Config) -> Count
         p2p,
         executor,
         consensus,
+        health: Default::default(),
     };
 
     import.notify.notify_one();
@@ -0,0 +1,176 @@
+//! The budget of blocks downloaded from peers but not committed yet.
+
+use fuel_core_metrics::sync_metrics::sync_metrics;
+use std::sync::Arc;
+use tokio::sync::watch;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct InFlight {
+    blocks: usize,
+    bytes: usize,
+}
+
+/// Limits the size of blocks downloaded or being downloaded but not committed yet.
+/// The budget is reserved for the headers before their download and extended by
+/// the transactions after their download, so a new batch is requested only while
+/// the size of in-flight blocks is below the limit.
+#[derive(Debug)]
+pub(crate) struct InFlightBudget {
+    max_bytes: usize,
+    in_flight: watch::Sender<InFlight>,
+}
+
+impl InFlightBudget {
+    pub(crate) fn new(max_bytes: usize) -> Arc<Self> {
+        Arc::new(Self {
+            max_bytes,
+            in_flight: watch::Sender::new(InFlight::default()),
+        })
+    }
+
+    /// Waits until the size of in-flight blocks is below the limit and reserves
+    /// the budget for `blocks` of `bytes` until the returned reservation is dropped.
+    /// It doesn't wait if there are no in-flight blocks, so a batch larger than
+    /// the limit is still imported.
+    pub(crate) async fn acquire(
+        self: &Arc<Self>,
+        blocks: usize,
+        bytes: usize,
+    ) -> Reservation {
+        let mut receiver = self.in_flight.subscribe();
+        let mut waited = false;
+        loop {
+            // The check and the reservation are done under the same lock,
+            // so concurrent batches can't exceed the limit together.
+            let reserved = self.in_flight.send_if_modified(|in_flight| {
+                if in_flight.blocks != 0 && in_flight.bytes >= self.max_bytes {
+                    return false
+                }
+                in_flight.blocks = in_flight.blocks.saturating_add(blocks);
+                in_flight.bytes = in_flight.bytes.saturating_add(bytes);
+                record_metrics(in_flight);
+                true
+            });
+            if reserved {
+                return Reservation {
+                    budget: self.clone(),
+                    blocks,
+                    bytes,
+                }
+            }
+            if !waited {
+                sync_metrics().budget_waits.inc();
+                waited = true;
+            }
+            // The sender lives as long as `self`, so the wait can't fail.
+            let _ = receiver.changed().await;
+        }
+    }
+
+    fn update(&self, f: impl FnOnce(&mut InFlight)) {
+        self.in_flight.send_modify(|in_flight| {
+            f(in_flight);
+            record_metrics(in_flight);
+        });
+    }
+
+    #[cfg(test)]
+    fn in_flight(&self) -> InFlight {
+        *self.in_flight.borrow()
+    }
+}
+
+fn record_metrics(in_flight: &InFlight) {
+    let metrics = sync_metrics();
+    metrics
+        .in_flight_blocks
+        .set(i64::try_from(in_flight.blocks).unwrap_or(i64::MAX));
+    metrics
+        .in_flight_bytes
+        .set(i64::try_from(in_flight.bytes).unwrap_or(i64::MAX));
+}
+
+/// The part of the [`InFlightBudget`] used by in-flight blocks.
+#[derive(Debug)]
+pub(crate) struct Reservation {
+    budget: Arc<InFlightBudget>,
+    blocks: usize,
+    bytes: usize,
+}
+
+impl Reservation {
+    /// Extends the reservation by the `bytes` downloaded for the reserved blocks.
+    pub(crate) fn add_bytes(&mut self, bytes: usize) {
+        self.bytes = self.bytes.saturating_add(bytes);
+        self.budget.update(|in_flight| {
+            in_flight.bytes = in_flight.bytes.saturating_add(bytes);
+        });
+    }
+}
+
+impl Drop for Reservation {
+    fn drop(&mut self) {
+        let (blocks, bytes) = (self.blocks, self.bytes);
+        self.budget.update(|in_flight| {
+            in_flight.blocks = in_flight.blocks.saturating_sub(blocks);
+            in_flight.bytes = in_flight.bytes.saturating_sub(bytes);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn waits_for_capacity_until_reservation_is_dropped() {
+        let budget = InFlightBudget::new(100);
+        let reservation = budget.acquire(1, 100).await;
+
+        let acquire =
+            tokio::time::timeout(Duration::from_millis(10), budget.acquire(1, 1)).await;
+        assert!(acquire.is_err());
+
+        drop(reservation);
+        let reservation = budget.acquire(1, 1).await;
+        assert_eq!(
+            budget.in_flight(),
+            InFlight {
+                blocks: 1,
+                bytes: 1
+            }
+        );
+        drop(reservation);
+        assert_eq!(budget.in_flight(), InFlight::default());
+    }
+
+    #[tokio::test]
+    async fn batch_larger_than_budget_is_allowed_alone() {
+        let budget = InFlightBudget::new(1);
+
+        let _reservation = budget.acquire(10, 1000).await;
+
+        assert_eq!(
+            budget.in_flight(),
+            InFlight {
+                blocks: 10,
+                bytes: 1000
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn added_bytes_are_released_with_the_reservation() {
+        let budget = InFlightBudget::new(100);
+        let mut reservation = budget.acquire(1, 10).await;
+
+        reservation.add_bytes(90);
+        let acquire =
+            tokio::time::timeout(Duration::from_millis(10), budget.acquire(1, 1)).await;
+        assert!(acquire.is_err());
+
+        drop(reservation);
+        assert_eq!(budget.in_flight(), InFlight::default());
+    }
+}
@@ -14,10 +14,13 @@ use fuel_core_services::stream::BoxStream;
 use fuel_core_types::{
     blockchain::SealedBlockHeader,
     fuel_types::BlockHeight,
-    services::p2p::{
-        PeerId,
-        SourcePeer,
-        Transactions,
+    services::{
+        p2p::{
+            PeerId,
+            SourcePeer,
+            Transactions,
+        },
+        port::PortResult,
     },
 };
 use std::{
@@ -40,7 +43,7 @@ impl PeerToPeerPort for PressurePeerToPeer {
     async fn get_sealed_block_headers(
         &self,
         block_height_range: Range<u32>,
-    ) -> anyhow::Result<SourcePeer<Option<Vec<SealedBlockHeader>>>> {
+    ) -> PortResult<SourcePeer<Option<Vec<SealedBlockHeader>>>> {
         self.counts.apply(|c| c.inc_headers());
         tokio::time::sleep(self.durations[0]).await;
         self.counts.apply(|c| c.dec_headers());
@@ -53,7 +56,7 @@ impl PeerToPeerPort for PressurePeerToPeer {
     async fn get_transactions(
         &self,
         block_ids: SourcePeer<Range<u32>>,
-    ) -> anyhow::Result<Option<Vec<Transactions>>> {
+    ) -> PortResult<Option<Vec<Transactions>>> {
         let transactions_count = block_ids.data.len();
         self.counts
             .apply(|c| c.add_transactions(transactions_count));
@@ -63,11 +66,7 @@ impl PeerToPeerPort for PressurePeerToPeer {
         self.p2p.get_transactions(block_ids).await
     }
 
-    fn report_peer(
-        &self,
-        _peer: PeerId,
-        _report: PeerReportReason,
-    ) -> anyhow::Result<()> {
+    fn report_peer(&self, _peer: PeerId, _report: PeerReportReason) -> PortResult<()> {
         Ok(())
     }
 }
@@ -13,7 +13,10 @@ use crate::{
         PeerReportReason,
     },
 };
-use fuel_core_types::services::p2p::Transactions;
+use fuel_core_types::services::{
+    p2p::Transactions,
+    port::PortError,
+};
 
 use super::*;
 
@@ -53,6 +56,7 @@ async fn test_import_0_to_5() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_in_flight_bytes: usize::MAX,
     };
     let mocks = Mocks {
         consensus_port,
@@ -99,6 +103,7 @@ async fn test_import_3_to_5() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_in_flight_bytes: usize::MAX,
     };
     let mocks = Mocks {
         consensus_port,
@@ -165,6 +170,7 @@ async fn test_import_0_to_499() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size,
+        max_in_flight_bytes: usize::MAX,
     };
     let mocks = Mocks {
         consensus_port,
@@ -217,6 +223,7 @@ async fn import__signature_fails_on_header_5_only() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_in_flight_bytes: usize::MAX,
     };
 
     // when
@@ -265,6 +272,7 @@ async fn import__signature_fails_on_header_4_only() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_in_flight_bytes: usize::MAX,
     };
 
     // when
@@ -296,6 +304,7 @@ async fn import__header_not_found() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_in_flight_bytes: usize::MAX,
     };
 
     // when
@@ -327,6 +336,7 @@ async fn import__header_response_incomplete() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_in_flight_bytes: usize::MAX,
     };
 
     // when
@@ -366,6 +376,7 @@ async fn import__header_5_not_found() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_in_flight_bytes: usize::MAX,
     };
 
     // when
@@ -398,6 +409,7 @@ async fn import__header_4_not_found() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_in_flight_bytes: usize::MAX,
     };
 
     // when
@@ -442,6 +454,7 @@ async fn import__transactions_not_found() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_in_flight_bytes: usize::MAX,
     };
 
     // when
@@ -496,6 +509,7 @@ async fn import__transactions_not_found_for_header_4() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_in_flight_bytes: usize::MAX,
     };
 
     // when
@@ -541,6 +555,7 @@ async fn import__transactions_not_found_for_header_5() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_in_flight_bytes: usize::MAX,
     };
 
     // when
@@ -556,7 +571,7 @@ async fn import__p2p_error() {
     let mut p2p = MockPeerToPeerPort::default();
     p2p.expect_get_sealed_block_headers()
         .times(1)
-        .returning(|_| Err(anyhow::anyhow!("Some network error")));
+        .returning(|_| Err(PortError::transient(anyhow::anyhow!("Some network error"))));
     p2p.expect_get_transactions().times(0);
 
     let state = State::new(3, 5).into();
@@ -568,6 +583,7 @@ async fn import__p2p_error() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_in_flight_bytes: usize::MAX,
     };
 
     // when
@@ -601,7 +617,7 @@ async fn import__p2p_error_on_4_transactions() {
         });
     p2p.expect_get_transactions()
         .times(1)
-        .returning(|_| Err(anyhow::anyhow!("Some network error")));
+        .returning(|_| Err(PortError::transient(anyhow::anyhow!("Some network error"))));
 
     let state = State::new(3, 5).into();
     let mocks = Mocks {
@@ -612,6 +628,7 @@ async fn import__p2p_error_on_4_transactions() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_in_flight_bytes: usize::MAX,
     };
 
     // when
@@ -621,6 +638,44 @@ async fn import__p2p_error_on_4_transactions() {
     assert_eq!((State::new(3, None), false), res);
 }
 
+#[tokio::test]
+async fn get_transactions__reports_peer_on_transient_error() {
+    // given
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_get_transactions()
+        .times(1)
+        .returning(|_| Err(PortError::transient(anyhow::anyhow!("Timeout"))));
+    p2p.expect_report_peer()
+        .times(1)
+        .with(
+            mockall::predicate::always(),
+            mockall::predicate::eq(PeerReportReason::MissingTransactions),
+        )
+        .returning(|_, _| Ok(()));
+
+    // when
+    let res = get_transactions(random_peer(), 0..1, &Arc::new(p2p)).await;
+
+    // then
+    assert!(res.is_none());
+}
+
+#[tokio::test]
+async fn get_transactions__does_not_report_peer_on_fatal_error() {
+    // given
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_get_transactions()
+        .times(1)
+        .returning(|_| Err(PortError::fatal(anyhow::anyhow!("P2P is stopped"))));
+    p2p.expect_report_peer().times(0);
+
+    // when
+    let res = get_transactions(random_peer(), 0..1, &Arc::new(p2p)).await;
+
+    // then
+    assert!(res.is_none());
+}
+
 #[tokio::test]
 async fn import__consensus_error_on_4() {
     // given
@@ -660,6 +715,7 @@ async fn import__consensus_error_on_4() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_in_flight_bytes: usize::MAX,
     };
 
     // when
@@ -714,6 +770,7 @@ async fn import__consensus_error_on_5() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_in_flight_bytes: usize::MAX,
     };
 
     // when
@@ -774,6 +831,7 @@ async fn import__execution_error_on_header_4() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_in_flight_bytes: usize::MAX,
     };
 
     // when
@@ -834,6 +892,7 @@ async fn import__execution_error_on_header_5() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_in_flight_bytes: usize::MAX,
     };
 
     // when
@@ -862,6 +921,7 @@ async fn signature_always_fails() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_in_flight_bytes: usize::MAX,
     };
 
     // when
@@ -914,6 +974,7 @@ async fn import__can_work_in_two_loops() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_in_flight_bytes: usize::MAX,
     };
 
     // when
@@ -948,6 +1009,7 @@ async fn test_import_inner(
         p2p,
         executor,
         consensus,
+        health: Default::default(),
     };
     let (_tx, shutdown) = tokio::sync::watch::channel(fuel_core_services::State::Started);
     let mut watcher = shutdown.into();
@@ -1101,6 +1163,7 @@ impl PeerReportTestBuilder {
         let params = Config {
             block_stream_buffer_size: 10,
             header_batch_size: 10,
+            max_in_flight_bytes: usize::MAX,
         };
 
         let import = Import {
@@ -1110,6 +1173,7 @@ impl PeerReportTestBuilder {
             p2p,
             executor,
             consensus,
+            health: Default::default(),
         };
         let (_tx, shutdown) =
             tokio::sync::watch::channel(fuel_core_services::State::Started);
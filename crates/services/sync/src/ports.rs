@@ -8,10 +8,13 @@ use fuel_core_types::{
         SealedBlockHeader,
     },
     fuel_types::BlockHeight,
-    services::p2p::{
-        PeerId,
-        SourcePeer,
-        Transactions,
+    services::{
+        p2p::{
+            PeerId,
+            SourcePeer,
+            Transactions,
+        },
+        port::PortResult,
     },
 };
 use std::ops::Range;
@@ -45,17 +48,17 @@ pub trait PeerToPeerPort {
     async fn get_sealed_block_headers(
         &self,
         block_height_range: Range<u32>,
-    ) -> anyhow::Result<SourcePeer<Option<Vec<SealedBlockHeader>>>>;
+    ) -> PortResult<SourcePeer<Option<Vec<SealedBlockHeader>>>>;
 
     /// Request transactions from the network for the given block
     /// and source peer.
     async fn get_transactions(
         &self,
         block_ids: SourcePeer<Range<u32>>,
-    ) -> anyhow::Result<Option<Vec<Transactions>>>;
+    ) -> PortResult<Option<Vec<Transactions>>>;
 
     /// Report a peer for some reason to modify their reputation.
-    fn report_peer(&self, peer: PeerId, report: PeerReportReason) -> anyhow::Result<()>;
+    fn report_peer(&self, peer: PeerId, report: PeerReportReason) -> PortResult<()>;
 }
 
 #[cfg_attr(any(test, feature = "benchmarking"), mockall::automock)]
@@ -21,6 +21,7 @@ use fuel_core_services::{
         BoxStream,
         IntoBoxStream,
     },
+    HealthReporter,
     RunnableService,
     RunnableTask,
     Service,
@@ -42,6 +43,7 @@ pub fn new_service<P, E, C>(
     executor: E,
     consensus: C,
     params: Config,
+    health: HealthReporter,
 ) -> anyhow::Result<ServiceRunner<SyncTask<P, E, C>>>
 where
     P: ports::PeerToPeerPort + Send + Sync + 'static,
@@ -59,6 +61,7 @@ where
         p2p,
         executor,
         consensus,
+        health,
     )?))
 }
 
@@ -82,6 +85,7 @@ where
     E: BlockImporterPort + Send + Sync + 'static,
     C: ConsensusPort + Send + Sync + 'static,
 {
+    #[allow(clippy::too_many_arguments)]
     fn new(
         height_stream: BoxStream<BlockHeight>,
         committed_height_stream: BoxStream<BlockHeight>,
@@ -90,6 +94,7 @@ where
         p2p: P,
         executor: E,
         consensus: C,
+        health: HealthReporter,
     ) -> anyhow::Result<Self> {
         let notify = Arc::new(Notify::new());
         let state = SharedMutex::new(state);
@@ -102,7 +107,8 @@ where
             state.clone(),
             notify.clone(),
         );
-        let import = Import::new(state, notify, params, p2p, executor, consensus);
+        let import = Import::new(state, notify, params, p2p, executor, consensus)
+            .with_health(health);
         let import_task_handle = ServiceRunner::new(ImportTask(import));
         Ok(Self {
             sync_heights,
@@ -66,8 +66,17 @@ async fn test_new_service() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_in_flight_bytes: usize::MAX,
     };
-    let s = new_service(4u32.into(), p2p, importer, consensus, params).unwrap();
+    let s = new_service(
+        4u32.into(),
+        p2p,
+        importer,
+        consensus,
+        params,
+        Default::default(),
+    )
+    .unwrap();
 
     assert_eq!(
         s.start_and_await().await.unwrap(),
@@ -0,0 +1,76 @@
+//! Types of the maintenance operations on the transaction pool.
+
+use crate::types::{
+    ContractId,
+    PoolTransaction,
+    TxId,
+    Word,
+};
+use fuel_core_types::{
+    fuel_tx::{
+        Address,
+        Transaction,
+    },
+    fuel_types::BlockHeight,
+};
+
+/// The target of the audit log entries of maintenance operations.
+pub const AUDIT_TARGET: &str = "txpool_audit";
+
+/// Selects transactions removed by the flush of the pool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlushFilter {
+    /// All transactions.
+    All,
+    /// Transactions that spend coins or messages of the owner.
+    Owner(Address),
+    /// Transactions that use the contract as an input or create it.
+    Contract(ContractId),
+}
+
+impl FlushFilter {
+    /// Returns `true` if the transaction should be removed.
+    pub fn matches(&self, tx: &PoolTransaction) -> bool {
+        match self {
+            FlushFilter::All => true,
+            FlushFilter::Owner(owner) => tx
+                .inputs()
+                .iter()
+                .any(|input| input.input_owner() == Some(owner)),
+            FlushFilter::Contract(contract_id) => {
+                tx.inputs()
+                    .iter()
+                    .any(|input| input.contract_id() == Some(contract_id))
+                    || tx
+                        .outputs()
+                        .iter()
+                        .any(|output| output.contract_id() == Some(contract_id))
+            }
+        }
+    }
+}
+
+/// The state of the pool written by the dump for post-mortem analysis.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct TxPoolDump {
+    /// The height of the latest block known to the pool.
+    pub height: BlockHeight,
+    /// Whether the insertion into the pool was paused.
+    pub paused: bool,
+    /// Transactions of the pool sorted from the highest gas price to the lowest.
+    pub transactions: Vec<DumpedTransaction>,
+}
+
+/// The transaction of the pool in the [`TxPoolDump`].
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct DumpedTransaction {
+    pub id: TxId,
+    pub gas_price: Word,
+    pub max_gas: Word,
+    /// The time of the submission in seconds since the `UNIX_EPOCH`.
+    pub submitted_time: u64,
+    /// The origin of the transaction as `TxOrigin::label`, so API keys
+    /// of clients are not written into the dump.
+    pub origin: String,
+    pub transaction: Transaction,
+}
@@ -1,5 +1,13 @@
+use crate::ports::GasPriceOracle;
 use fuel_core_chain_config::ChainConfig;
-use std::time::Duration;
+use fuel_core_types::fuel_types::{
+    BlockHeight,
+    Word,
+};
+use std::{
+    sync::Arc,
+    time::Duration,
+};
 
 #[derive(Debug, Clone)]
 pub struct Config {
@@ -9,6 +17,8 @@ pub struct Config {
     pub max_depth: usize,
     /// The minimum allowed gas price
     pub min_gas_price: u64,
+    /// The oracle that sets the minimum gas price per block instead of the `min_gas_price`.
+    pub gas_price_oracle: Option<Arc<dyn GasPriceOracle>>,
     /// Flag to disable utxo existence and signature checks
     pub utxo_validation: bool,
     /// chain config
@@ -19,6 +29,14 @@ pub struct Config {
     pub transaction_ttl: Duration,
     /// The number of allowed active transaction status subscriptions.
     pub number_of_active_subscription: usize,
+    /// The limits of transaction status subscriptions of a single client.
+    pub subscription_limits: SubscriptionLimits,
+    /// The maximum total size in bytes of transactions in the predicate verification cache.
+    pub predicate_cache_size: usize,
+    /// The maximum total size in bytes of transactions in the signature verification cache.
+    pub signature_cache_size: usize,
+    /// For how long transactions with verified signatures are cached.
+    pub signature_cache_ttl: Duration,
 }
 
 impl Default for Config {
@@ -31,15 +49,27 @@ impl Default for Config {
         // 5 minute TTL
         let transaction_ttl = Duration::from_secs(60 * 5);
         let number_of_active_subscription = max_tx;
+        let subscription_limits = SubscriptionLimits::default();
+        // 64 MiB
+        let predicate_cache_size = 64 * 1024 * 1024;
+        // 16 MiB
+        let signature_cache_size = 16 * 1024 * 1024;
+        // Duplicates of the transaction arrive shortly after the original.
+        let signature_cache_ttl = Duration::from_secs(30);
         Self::new(
             max_tx,
             max_depth,
             ChainConfig::default(),
             min_gas_price,
+            None,
             utxo_validation,
             metrics,
             transaction_ttl,
             number_of_active_subscription,
+            subscription_limits,
+            predicate_cache_size,
+            signature_cache_size,
+            signature_cache_ttl,
         )
     }
 }
@@ -51,10 +81,15 @@ impl Config {
         max_depth: usize,
         chain_config: ChainConfig,
         min_gas_price: u64,
+        gas_price_oracle: Option<Arc<dyn GasPriceOracle>>,
         utxo_validation: bool,
         metrics: bool,
         transaction_ttl: Duration,
         number_of_active_subscription: usize,
+        subscription_limits: SubscriptionLimits,
+        predicate_cache_size: usize,
+        signature_cache_size: usize,
+        signature_cache_ttl: Duration,
     ) -> Self {
         // # Dev-note: If you add a new field, be sure that this field is propagated correctly
         //  in all places where `new` is used.
@@ -62,11 +97,46 @@ impl Config {
             max_tx,
             max_depth,
             min_gas_price,
+            gas_price_oracle,
             utxo_validation,
             chain_config,
             metrics,
             transaction_ttl,
             number_of_active_subscription,
+            subscription_limits,
+            predicate_cache_size,
+            signature_cache_size,
+            signature_cache_ttl,
+        }
+    }
+
+    /// Returns the minimum gas price of transactions included into the block at the `height`.
+    pub fn min_gas_price_at(&self, height: BlockHeight) -> Word {
+        match &self.gas_price_oracle {
+            Some(oracle) => oracle.min_gas_price(height),
+            None => self.min_gas_price,
+        }
+    }
+}
+
+/// The limits of transaction status subscriptions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SubscriptionLimits {
+    /// The maximum number of active subscriptions of a single client. Subscriptions
+    /// without a known client share the limit. When the client reaches the limit,
+    /// its least recently updated subscription is dropped in favor of the new one.
+    pub max_per_client: usize,
+    /// Subscriptions that haven't received any update during this period are dropped.
+    pub idle_timeout: Duration,
+}
+
+impl Default for SubscriptionLimits {
+    fn default() -> Self {
+        Self {
+            max_per_client: 64,
+            // Transactions leave the pool within its TTL, so subscriptions to
+            // known transactions receive an update earlier.
+            idle_timeout: Duration::from_secs(60 * 5),
         }
     }
 }
@@ -0,0 +1,298 @@
+//! The gas price oracle that follows the fullness of blocks, similar to EIP-1559.
+//! The minimum gas price grows after blocks that use more gas than the target
+//! and falls after blocks that use less, but never below the floor.
+//!
+//! The price at the height is derived from the last
+//! [`BlockFullnessConfig::history_blocks`] blocks starting from the floor, so nodes
+//! agree on it regardless of when they were started. The fullness of the block is
+//! the maximum gas of its transactions, the same block space the producer accounts
+//! against the block gas limit, so `Create` transactions and predicates count too.
+
+use crate::ports::GasPriceOracle;
+use fuel_core_types::{
+    fuel_tx::{
+        ConsensusParameters,
+        Transaction,
+        TransactionFee,
+    },
+    fuel_types::{
+        BlockHeight,
+        Word,
+    },
+};
+use parking_lot::Mutex;
+use std::collections::BTreeMap;
+
+/// The configuration of the [`BlockFullnessGasPrice`] oracle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockFullnessConfig {
+    /// The lowest minimum gas price. The oracle starts from it.
+    pub floor: Word,
+    /// The gas used by the block, in percents of the block gas limit,
+    /// at which the price stays the same.
+    pub target_fullness_percent: u8,
+    /// The maximum change of the price after one block, in percents.
+    pub max_change_percent: u8,
+    /// The number of the latest blocks the price is derived from.
+    pub history_blocks: u32,
+}
+
+impl Default for BlockFullnessConfig {
+    fn default() -> Self {
+        Self {
+            floor: 0,
+            target_fullness_percent: 50,
+            // Close to the 12.5% maximum change of the base fee in EIP-1559.
+            max_change_percent: 12,
+            history_blocks: 64,
+        }
+    }
+}
+
+/// Returns the gas of the block: the sum of the maximum gas of its transactions
+/// under the `params` of the block. `Mint` transactions don't use the block space.
+pub fn block_gas(transactions: &[Transaction], params: &ConsensusParameters) -> Word {
+    let gas_costs = params.gas_costs();
+    let fee_params = params.fee_params();
+    transactions
+        .iter()
+        .filter_map(|tx| match tx {
+            Transaction::Script(script) => {
+                TransactionFee::checked_from_tx(gas_costs, fee_params, script)
+            }
+            Transaction::Create(create) => {
+                TransactionFee::checked_from_tx(gas_costs, fee_params, create)
+            }
+            Transaction::Mint(_) => None,
+        })
+        .fold(0u64, |sum, fee| sum.saturating_add(fee.max_gas()))
+}
+
+/// Adjusts the minimum gas price after each block in proportion to
+/// the deviation of the gas of the block from the target.
+#[derive(Debug)]
+pub struct BlockFullnessGasPrice {
+    config: BlockFullnessConfig,
+    block_gas_limit: Word,
+    /// The gas of the latest blocks by their heights.
+    blocks_gas: Mutex<BTreeMap<BlockHeight, Word>>,
+}
+
+impl BlockFullnessGasPrice {
+    pub fn new(config: BlockFullnessConfig, block_gas_limit: Word) -> Self {
+        Self {
+            config,
+            block_gas_limit,
+            blocks_gas: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    /// Returns the price for the block following the block with `gas_used`
+    /// when the current block has the `price`.
+    fn next_price(&self, price: Word, gas_used: Word) -> Word {
+        let target = u128::from(self.block_gas_limit)
+            .saturating_mul(u128::from(self.config.target_fullness_percent))
+            .checked_div(100)
+            .unwrap_or_default();
+        if target == 0 {
+            return price
+        }
+        let gas_used = u128::from(gas_used.min(self.block_gas_limit));
+        let deviation = gas_used.abs_diff(target).min(target);
+        let change = u128::from(price)
+            .saturating_mul(u128::from(self.config.max_change_percent))
+            .saturating_mul(deviation)
+            .checked_div(target.saturating_mul(100))
+            .unwrap_or_default();
+        let change = Word::try_from(change).unwrap_or(Word::MAX);
+
+        if gas_used > target {
+            // The price starting from zero should be able to grow.
+            price.saturating_add(change.max(1))
+        } else {
+            price.saturating_sub(change).max(self.config.floor)
+        }
+    }
+}
+
+impl GasPriceOracle for BlockFullnessGasPrice {
+    fn min_gas_price(&self, block_height: BlockHeight) -> Word {
+        let start = u32::from(block_height).saturating_sub(self.config.history_blocks);
+        // The blocks unknown to the oracle don't change the price.
+        self.blocks_gas
+            .lock()
+            .range(BlockHeight::from(start)..block_height)
+            .fold(self.config.floor, |price, (_, gas)| {
+                self.next_price(price, *gas)
+            })
+    }
+
+    fn history_blocks(&self) -> u32 {
+        self.config.history_blocks
+    }
+
+    fn on_block(&self, block_height: BlockHeight, block_gas: Word) {
+        let mut blocks_gas = self.blocks_gas.lock();
+        blocks_gas.insert(block_height, block_gas);
+        // Keeps the blocks required for the price at the next height.
+        let next_height = block_height.succ().unwrap_or(block_height);
+        let oldest = u32::from(next_height).saturating_sub(self.config.history_blocks);
+        *blocks_gas = blocks_gas.split_off(&BlockHeight::from(oldest));
+    }
+}
+
+#[allow(clippy::arithmetic_side_effects)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::fuel_tx::{
+        Finalizable,
+        Script,
+        TransactionBuilder,
+    };
+
+    const BLOCK_GAS_LIMIT: Word = 1_000_000;
+
+    fn oracle(floor: Word) -> BlockFullnessGasPrice {
+        BlockFullnessGasPrice::new(
+            BlockFullnessConfig {
+                floor,
+                target_fullness_percent: 50,
+                max_change_percent: 10,
+                history_blocks: 4,
+            },
+            BLOCK_GAS_LIMIT,
+        )
+    }
+
+    /// Imports the next block with the `gas` and returns the price
+    /// for the block after it.
+    fn import_block(oracle: &BlockFullnessGasPrice, gas: Word) -> Word {
+        let height = oracle
+            .blocks_gas
+            .lock()
+            .keys()
+            .next_back()
+            .map(|height| u32::from(*height) + 1)
+            .unwrap_or_default();
+        oracle.on_block(height.into(), gas);
+        oracle.min_gas_price((height + 1).into())
+    }
+
+    #[test]
+    fn full_blocks_raise_the_price_by_the_max_change() {
+        let oracle = oracle(1000);
+
+        assert_eq!(import_block(&oracle, BLOCK_GAS_LIMIT), 1100);
+        assert_eq!(import_block(&oracle, BLOCK_GAS_LIMIT), 1210);
+    }
+
+    #[test]
+    fn blocks_at_the_target_keep_the_price() {
+        let oracle = oracle(1000);
+        import_block(&oracle, BLOCK_GAS_LIMIT);
+
+        assert_eq!(import_block(&oracle, BLOCK_GAS_LIMIT / 2), 1100);
+    }
+
+    #[test]
+    fn empty_blocks_lower_the_price_down_to_the_floor() {
+        let oracle = oracle(1000);
+        import_block(&oracle, BLOCK_GAS_LIMIT);
+        import_block(&oracle, BLOCK_GAS_LIMIT);
+
+        assert_eq!(import_block(&oracle, 0), 1089);
+        assert_eq!(import_block(&oracle, 0), 1000);
+        assert_eq!(import_block(&oracle, 0), 1000);
+    }
+
+    #[test]
+    fn price_grows_from_zero_floor() {
+        let oracle = oracle(0);
+
+        assert_eq!(import_block(&oracle, BLOCK_GAS_LIMIT), 1);
+    }
+
+    #[test]
+    fn price_depends_on_the_block_height() {
+        // Given
+        let oracle = oracle(1000);
+        oracle.on_block(1.into(), BLOCK_GAS_LIMIT);
+        oracle.on_block(2.into(), BLOCK_GAS_LIMIT);
+
+        // When
+        let prices: Vec<_> = (1..=3)
+            .map(|height| oracle.min_gas_price(height.into()))
+            .collect();
+
+        // Then
+        assert_eq!(prices, vec![1000, 1100, 1210]);
+    }
+
+    #[test]
+    fn price_is_derived_only_from_the_history_blocks() {
+        // Given
+        let oracle = oracle(1000);
+        for height in 1..=10u32 {
+            oracle.on_block(height.into(), BLOCK_GAS_LIMIT);
+        }
+
+        // When
+        let price = oracle.min_gas_price(11.into());
+
+        // Then
+        assert_eq!(price, 1464);
+        assert_eq!(oracle.blocks_gas.lock().len(), 4);
+    }
+
+    #[test]
+    fn restarted_oracle_agrees_on_the_price_after_the_replay_of_history() {
+        // Given
+        let running = oracle(1000);
+        for height in 1..=10u32 {
+            running.on_block(height.into(), height as Word * 100_000);
+        }
+
+        // When
+        let restarted = oracle(1000);
+        let history = restarted.history_blocks();
+        for height in (10 - history + 1)..=10u32 {
+            restarted.on_block(height.into(), height as Word * 100_000);
+        }
+
+        // Then
+        assert_eq!(
+            restarted.min_gas_price(11.into()),
+            running.min_gas_price(11.into())
+        );
+    }
+
+    #[test]
+    fn block_gas_counts_max_gas_of_scripts_and_creates() {
+        // Given
+        let params = ConsensusParameters::default();
+        let script: Script = TransactionBuilder::script(vec![], vec![])
+            .script_gas_limit(1000)
+            .finalize();
+        let create =
+            TransactionBuilder::create(Default::default(), Default::default(), vec![])
+                .finalize_as_transaction();
+        let mint = Transaction::mint(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        );
+        let script_gas = block_gas(&[script.clone().into()], &params);
+        let create_gas = block_gas(&[create.clone()], &params);
+
+        // When
+        let gas = block_gas(&[script.into(), create, mint.into()], &params);
+
+        // Then
+        assert!(script_gas >= 1000);
+        assert!(create_gas > 0);
+        assert_eq!(gas, script_gas + create_gas);
+    }
+}
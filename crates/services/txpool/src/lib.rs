@@ -7,6 +7,7 @@ use fuel_core_types::{
     services::txpool::{
         ArcPoolTx,
         TransactionStatus,
+        TxOrigin,
     },
     tai64::Tai64,
 };
@@ -15,10 +16,15 @@ use std::{
     time::Duration,
 };
 
+pub mod admin;
 pub mod config;
 mod containers;
+pub mod gas_price;
+pub mod origin;
 pub mod ports;
+pub mod predicate_cache;
 pub mod service;
+pub mod signature_cache;
 mod transaction_selector;
 pub mod txpool;
 pub mod types;
@@ -38,6 +44,8 @@ pub use txpool::TxPool;
 
 #[cfg(any(test, feature = "test-helpers"))]
 pub(crate) mod test_helpers;
+#[cfg(any(test, feature = "test-utils"))]
+pub mod test_utils;
 
 #[cfg(test)]
 fuel_core_trace::enable_tracing!();
@@ -48,11 +56,12 @@ pub struct TxInfo {
     tx: ArcPoolTx,
     submitted_time: Duration,
     creation_instant: tokio::time::Instant,
+    origin: TxOrigin,
 }
 
 #[allow(missing_docs)]
 impl TxInfo {
-    pub fn new(tx: ArcPoolTx) -> Self {
+    pub fn new(tx: ArcPoolTx, origin: TxOrigin) -> Self {
         let since_epoch = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
             .expect("Now is bellow of the `UNIX_EPOCH`");
@@ -61,6 +70,7 @@ impl TxInfo {
             tx,
             submitted_time: since_epoch,
             creation_instant: tokio::time::Instant::now(),
+            origin,
         }
     }
 
@@ -75,6 +85,10 @@ impl TxInfo {
     pub fn created(&self) -> tokio::time::Instant {
         self.creation_instant
     }
+
+    pub fn origin(&self) -> &TxOrigin {
+        &self.origin
+    }
 }
 
 impl Deref for TxInfo {
@@ -88,6 +102,7 @@ impl From<TxInfo> for TransactionStatus {
     fn from(tx_info: TxInfo) -> Self {
         Self::Submitted {
             time: Tai64::from_unix(tx_info.submitted_time.as_secs() as i64),
+            origin: tx_info.origin,
         }
     }
 }
@@ -14,6 +14,7 @@ use fuel_core_types::{
     fuel_tx::{
         Contract,
         ContractId,
+        Transaction,
         UtxoId,
     },
     fuel_types::{
@@ -38,6 +39,7 @@ pub struct Data {
     pub contracts: HashMap<ContractId, Contract>,
     pub messages: HashMap<Nonce, Message>,
     pub spent_messages: HashSet<Nonce>,
+    pub blocks: HashMap<BlockHeight, Vec<Transaction>>,
 }
 
 #[derive(Clone, Default)]
@@ -65,6 +67,14 @@ impl MockDb {
     pub fn spend_message(&self, id: Nonce) {
         self.data.lock().unwrap().spent_messages.insert(id);
     }
+
+    pub fn insert_block(&self, height: BlockHeight, transactions: Vec<Transaction>) {
+        self.data
+            .lock()
+            .unwrap()
+            .blocks
+            .insert(height, transactions);
+    }
 }
 
 impl TxPoolDb for MockDb {
@@ -94,6 +104,13 @@ impl TxPoolDb for MockDb {
     fn is_message_spent(&self, id: &Nonce) -> StorageResult<bool> {
         Ok(self.data.lock().unwrap().spent_messages.contains(id))
     }
+
+    fn block_transactions(
+        &self,
+        height: &BlockHeight,
+    ) -> StorageResult<Option<Vec<Transaction>>> {
+        Ok(self.data.lock().unwrap().blocks.get(height).cloned())
+    }
 }
 
 pub struct MockDBProvider(pub MockDb);
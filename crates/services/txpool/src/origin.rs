@@ -0,0 +1,207 @@
+//! The statistics of transactions by their origin. They allow attributing
+//! spam waves to API keys and peers, and measuring which share of included
+//! transactions was received via the API of the node or via the gossip.
+
+use fuel_core_metrics::txpool_metrics::{
+    txpool_metrics,
+    OriginIdentityLabel,
+    OriginLabel,
+};
+use fuel_core_types::services::txpool::TxOrigin;
+use std::collections::HashMap;
+
+/// The maximum number of origins tracked by the [`OriginStatistics`].
+pub const MAX_TRACKED_ORIGINS: usize = 1024;
+
+/// The number of transactions received from the origin.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct OriginCounters {
+    /// Transactions inserted into the pool.
+    pub submitted: u64,
+    /// Transactions rejected by the pool.
+    pub rejected: u64,
+    /// Transactions included into blocks.
+    pub included: u64,
+}
+
+impl OriginCounters {
+    fn activity(&self) -> u64 {
+        self.submitted.saturating_add(self.rejected)
+    }
+}
+
+/// Counts transactions of each origin since the start of the pool.
+/// Only [`MAX_TRACKED_ORIGINS`] origins are tracked, and the least active origin
+/// is dropped to track a new one. The metrics are recorded per kind of the origin and
+/// per identity of tracked origins. The identity is the [`TxOrigin::label`],
+/// so API keys don't leak into labels, and series of dropped origins are removed.
+#[derive(Debug, Clone)]
+pub struct OriginStatistics {
+    origins: HashMap<TxOrigin, OriginCounters>,
+    metrics: bool,
+}
+
+impl OriginStatistics {
+    pub fn new(metrics: bool) -> Self {
+        Self {
+            origins: HashMap::new(),
+            metrics,
+        }
+    }
+
+    /// Records the transaction inserted into the pool.
+    pub fn record_submitted(&mut self, origin: &TxOrigin) {
+        let counters = self.counters(origin);
+        counters.submitted = counters.submitted.saturating_add(1);
+        self.record_metric(origin, "submitted", 1);
+    }
+
+    /// Records `count` transactions rejected by the pool.
+    pub fn record_rejected(&mut self, origin: &TxOrigin, count: u64) {
+        if count == 0 {
+            return
+        }
+        let counters = self.counters(origin);
+        counters.rejected = counters.rejected.saturating_add(count);
+        self.record_metric(origin, "rejected", count);
+    }
+
+    /// Records the transaction included into the block.
+    pub fn record_included(&mut self, origin: &TxOrigin) {
+        let counters = self.counters(origin);
+        counters.included = counters.included.saturating_add(1);
+        self.record_metric(origin, "included", 1);
+    }
+
+    pub fn get(&self, origin: &TxOrigin) -> Option<OriginCounters> {
+        self.origins.get(origin).copied()
+    }
+
+    /// Returns the tracked origins sorted from the most active to the least active.
+    pub fn sorted(&self) -> Vec<(TxOrigin, OriginCounters)> {
+        let mut origins: Vec<_> = self
+            .origins
+            .iter()
+            .map(|(origin, counters)| (origin.clone(), *counters))
+            .collect();
+        origins.sort_by_key(|(_, counters)| core::cmp::Reverse(counters.activity()));
+        origins
+    }
+
+    fn counters(&mut self, origin: &TxOrigin) -> &mut OriginCounters {
+        if !self.origins.contains_key(origin) && self.origins.len() >= MAX_TRACKED_ORIGINS
+        {
+            let least_active = self
+                .origins
+                .iter()
+                .min_by_key(|(_, counters)| counters.activity())
+                .map(|(origin, _)| origin.clone());
+            if let Some(least_active) = least_active {
+                self.origins.remove(&least_active);
+                self.remove_identity_metrics(&least_active);
+            }
+        }
+        self.origins.entry(origin.clone()).or_default()
+    }
+
+    fn record_metric(&self, origin: &TxOrigin, event: &str, count: u64) {
+        if self.metrics {
+            txpool_metrics()
+                .transactions_by_origin
+                .get_or_create(&OriginLabel {
+                    origin: origin.kind().to_string(),
+                    event: event.to_string(),
+                })
+                .inc_by(count);
+            txpool_metrics()
+                .transactions_by_origin_identity
+                .get_or_create(&OriginIdentityLabel {
+                    origin: origin.label(),
+                    event: event.to_string(),
+                })
+                .inc_by(count);
+        }
+    }
+
+    fn remove_identity_metrics(&self, origin: &TxOrigin) {
+        if self.metrics {
+            let label = origin.label();
+            for event in ["submitted", "rejected", "included"] {
+                txpool_metrics().transactions_by_origin_identity.remove(
+                    &OriginIdentityLabel {
+                        origin: label.clone(),
+                        event: event.to_string(),
+                    },
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn api(key: usize) -> TxOrigin {
+        TxOrigin::Api {
+            api_key: Some(key.to_string()),
+        }
+    }
+
+    #[test]
+    fn events_are_counted_per_origin() {
+        let mut statistics = OriginStatistics::new(false);
+
+        statistics.record_submitted(&api(1));
+        statistics.record_submitted(&api(1));
+        statistics.record_rejected(&api(1), 3);
+        statistics.record_included(&api(1));
+        statistics.record_submitted(&TxOrigin::Injected);
+
+        assert_eq!(
+            statistics.get(&api(1)),
+            Some(OriginCounters {
+                submitted: 2,
+                rejected: 3,
+                included: 1,
+            })
+        );
+        assert_eq!(
+            statistics.get(&TxOrigin::Injected),
+            Some(OriginCounters {
+                submitted: 1,
+                rejected: 0,
+                included: 0,
+            })
+        );
+        assert_eq!(statistics.sorted()[0].0, api(1));
+    }
+
+    #[test]
+    fn label_does_not_reveal_api_key() {
+        let label = api(12345).label();
+
+        assert!(label.starts_with("api:"));
+        assert!(!label.contains("12345"));
+        assert_eq!(label, api(12345).label());
+        assert_ne!(label, api(54321).label());
+        assert_eq!(TxOrigin::Api { api_key: None }.label(), "api:anonymous");
+    }
+
+    #[test]
+    fn least_active_origin_is_dropped_when_limit_is_reached() {
+        let mut statistics = OriginStatistics::new(false);
+        for key in 0..MAX_TRACKED_ORIGINS {
+            statistics.record_submitted(&api(key));
+            statistics.record_submitted(&api(key));
+        }
+        statistics.record_rejected(&api(0), 1);
+        statistics.record_included(&api(1));
+
+        statistics.record_submitted(&TxOrigin::Injected);
+
+        assert_eq!(statistics.sorted().len(), MAX_TRACKED_ORIGINS);
+        assert!(statistics.get(&TxOrigin::Injected).is_some());
+        assert!(statistics.get(&api(0)).is_some());
+    }
+}
@@ -10,8 +10,10 @@ use fuel_core_types::{
         UtxoId,
     },
     fuel_types::{
+        BlockHeight,
         ContractId,
         Nonce,
+        Word,
     },
     services::{
         block_importer::SharedImportResult,
@@ -19,16 +21,23 @@ use fuel_core_types::{
             GossipsubMessageAcceptance,
             GossipsubMessageInfo,
             NetworkData,
+            PeerId,
+            ShortTxId,
         },
+        port::PortResult,
     },
 };
-use std::sync::Arc;
+use std::{
+    fmt::Debug,
+    sync::Arc,
+};
 
+#[async_trait::async_trait]
 pub trait PeerToPeer: Send + Sync {
     type GossipedTransaction: NetworkData<Transaction>;
 
     // Gossip broadcast a transaction inserted via API.
-    fn broadcast_transaction(&self, transaction: Arc<Transaction>) -> anyhow::Result<()>;
+    fn broadcast_transaction(&self, transaction: Arc<Transaction>) -> PortResult<()>;
 
     /// Creates a stream of next transactions gossiped from the network.
     fn gossiped_transaction_events(&self) -> BoxStream<Self::GossipedTransaction>;
@@ -38,7 +47,23 @@ pub trait PeerToPeer: Send + Sync {
         &self,
         message_info: GossipsubMessageInfo,
         validity: GossipsubMessageAcceptance,
-    ) -> anyhow::Result<()>;
+    ) -> PortResult<()>;
+
+    /// Creates a stream of peers connected to the node.
+    fn new_peers_events(&self) -> BoxStream<PeerId>;
+
+    /// Requests short ids of transactions in the pool of the peer.
+    async fn request_tx_pool_summary(
+        &self,
+        peer_id: PeerId,
+    ) -> PortResult<Option<Vec<ShortTxId>>>;
+
+    /// Requests transactions from the pool of the peer by their short ids.
+    async fn request_pooled_transactions(
+        &self,
+        peer_id: PeerId,
+        tx_ids: Vec<ShortTxId>,
+    ) -> PortResult<Option<Vec<Transaction>>>;
 }
 
 pub trait BlockImporter: Send + Sync {
@@ -46,6 +71,25 @@ pub trait BlockImporter: Send + Sync {
     fn block_events(&self) -> BoxStream<SharedImportResult>;
 }
 
+/// The source of the minimum gas price that replaces the static
+/// [`Config::min_gas_price`](crate::Config::min_gas_price). The price can follow
+/// the fullness of blocks or an external feed.
+pub trait GasPriceOracle: Debug + Send + Sync {
+    /// Returns the minimum gas price of transactions accepted into the pool
+    /// and included into the block at the `block_height`.
+    fn min_gas_price(&self, block_height: BlockHeight) -> Word;
+
+    /// Returns the number of the latest blocks the price depends on. The pool
+    /// replays them via [`GasPriceOracle::on_block`] on start.
+    fn history_blocks(&self) -> u32 {
+        0
+    }
+
+    /// Notifies the oracle about the block with the `block_gas`, the maximum gas
+    /// of its transactions, before the pool accepts transactions for the next block.
+    fn on_block(&self, _block_height: BlockHeight, _block_gas: Word) {}
+}
+
 pub trait TxPoolDb: Send + Sync {
     fn utxo(&self, utxo_id: &UtxoId) -> StorageResult<Option<CompressedCoin>>;
 
@@ -54,4 +98,10 @@ pub trait TxPoolDb: Send + Sync {
     fn message(&self, message_id: &Nonce) -> StorageResult<Option<Message>>;
 
     fn is_message_spent(&self, message_id: &Nonce) -> StorageResult<bool>;
+
+    /// Returns the transactions of the block at the `height`, if the block exists.
+    fn block_transactions(
+        &self,
+        height: &BlockHeight,
+    ) -> StorageResult<Option<Vec<Transaction>>>;
 }
@@ -0,0 +1,277 @@
+//! The cache of transactions with verified predicates. The same transaction can be
+//! submitted several times, for example, it can be gossiped by several peers or
+//! resubmitted by the user. The cache allows skipping the repeated execution of its
+//! predicates by the VM.
+
+use fuel_core_types::{
+    fuel_tx::{
+        field::Inputs,
+        Transaction,
+        TxId,
+    },
+    fuel_types::{
+        canonical::Serialize,
+        BlockHeight,
+    },
+    fuel_vm::checked_transaction::Checked,
+};
+use parking_lot::Mutex as ParkingMutex;
+use std::{
+    collections::{
+        HashMap,
+        VecDeque,
+    },
+    sync::Arc,
+};
+
+/// The [`PredicateCache`] shared by the `TxPool` with the executor, so predicates
+/// verified by the `TxPool` are not executed again during the block execution.
+pub type SharedPredicateCache = Arc<ParkingMutex<PredicateCache>>;
+
+/// The cache stores transactions with verified signatures and predicates.
+/// It is bounded by the total serialized size of cached transactions,
+/// and the oldest transactions are evicted first. The predicates depend on the gas costs
+/// and limits of the consensus parameters, so all transactions are evicted
+/// when the version of the consensus parameters changes.
+#[derive(Debug)]
+pub struct PredicateCache {
+    max_size: usize,
+    size: usize,
+    /// The version of the consensus parameters used to verify cached transactions.
+    version: Option<BlockHeight>,
+    transactions: HashMap<TxId, CachedTransaction>,
+    /// The ids of cached transactions, from the oldest to the newest.
+    order: VecDeque<TxId>,
+}
+
+#[derive(Debug)]
+struct CachedTransaction {
+    tx: Checked<Transaction>,
+    size: usize,
+}
+
+impl PredicateCache {
+    pub fn new(max_size: usize) -> Self {
+        Self {
+            max_size,
+            size: 0,
+            version: None,
+            transactions: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    /// Creates the empty cache that can be shared between services.
+    pub fn shared(max_size: usize) -> SharedPredicateCache {
+        Arc::new(ParkingMutex::new(Self::new(max_size)))
+    }
+
+    /// Returns the verified transaction equal to the `tx`, if it was verified under
+    /// the `version` of the consensus parameters.
+    pub fn get(
+        &mut self,
+        version: Option<BlockHeight>,
+        tx: &Checked<Transaction>,
+    ) -> Option<Checked<Transaction>> {
+        self.invalidate_if_upgraded(version);
+        self.verified(version, tx)
+    }
+
+    /// Returns the verified transaction equal to the `tx`, if it was verified under
+    /// the `version` of the consensus parameters. Unlike [`Self::get`], it doesn't
+    /// invalidate the cache on the version mismatch, so the executor can look up
+    /// transactions of past blocks without evicting transactions verified by the `TxPool`.
+    pub fn verified(
+        &self,
+        version: Option<BlockHeight>,
+        tx: &Checked<Transaction>,
+    ) -> Option<Checked<Transaction>> {
+        if self.version != version {
+            return None
+        }
+        let cached = self.transactions.get(&tx.id())?;
+        // The id doesn't commit to witnesses, but predicates can read them.
+        (cached.tx.transaction() == tx.transaction()).then(|| cached.tx.clone())
+    }
+
+    /// Caches the transaction verified under the `version` of the consensus parameters.
+    /// Transactions without predicates aren't cached.
+    pub fn insert(&mut self, version: Option<BlockHeight>, tx: &Checked<Transaction>) {
+        self.invalidate_if_upgraded(version);
+        if !has_predicates(tx.transaction()) {
+            return
+        }
+        let size = tx.transaction().size();
+        if size > self.max_size {
+            return
+        }
+
+        let id = tx.id();
+        self.remove(&id);
+        while self.size.saturating_add(size) > self.max_size {
+            let Some(oldest) = self.order.pop_front() else {
+                break
+            };
+            if let Some(evicted) = self.transactions.remove(&oldest) {
+                self.size = self.size.saturating_sub(evicted.size);
+            }
+        }
+
+        self.size = self.size.saturating_add(size);
+        self.order.push_back(id);
+        self.transactions.insert(
+            id,
+            CachedTransaction {
+                tx: tx.clone(),
+                size,
+            },
+        );
+    }
+
+    fn remove(&mut self, id: &TxId) {
+        if let Some(removed) = self.transactions.remove(id) {
+            self.size = self.size.saturating_sub(removed.size);
+            self.order.retain(|cached| cached != id);
+        }
+    }
+
+    fn invalidate_if_upgraded(&mut self, version: Option<BlockHeight>) {
+        if self.version != version {
+            self.version = version;
+            self.size = 0;
+            self.transactions.clear();
+            self.order.clear();
+        }
+    }
+}
+
+/// Returns `true` if any input of the transaction is owned by the predicate.
+pub fn has_predicates(tx: &Transaction) -> bool {
+    let inputs = match tx {
+        Transaction::Script(script) => script.inputs(),
+        Transaction::Create(create) => create.inputs(),
+        Transaction::Mint(_) => return false,
+    };
+    inputs.iter().any(|input| input.predicate().is_some())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::{
+        fuel_asm::op,
+        fuel_tx::{
+            field::Witnesses,
+            AssetId,
+            ConsensusParameters,
+            Input,
+            TransactionBuilder,
+            UtxoId,
+            Witness,
+        },
+        fuel_vm::checked_transaction::IntoChecked,
+    };
+
+    fn predicate_tx(seed: u8) -> Checked<Transaction> {
+        let predicate = vec![op::ret(1)].into_iter().collect::<Vec<u8>>();
+        let owner = Input::predicate_owner(&predicate);
+        TransactionBuilder::script(vec![], vec![])
+            .add_input(Input::coin_predicate(
+                UtxoId::new([seed; 32].into(), 0),
+                owner,
+                10,
+                AssetId::BASE,
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                predicate,
+                vec![],
+            ))
+            .finalize_as_transaction()
+            .into_checked_basic(Default::default(), &ConsensusParameters::standard())
+            .unwrap()
+    }
+
+    #[test]
+    fn cached_transaction_is_returned_for_the_same_version() {
+        let mut cache = PredicateCache::new(usize::MAX);
+        let tx = predicate_tx(1);
+
+        cache.insert(None, &tx);
+
+        assert_eq!(cache.get(None, &tx), Some(tx));
+    }
+
+    #[test]
+    fn transaction_with_different_witnesses_is_not_returned() {
+        let mut cache = PredicateCache::new(usize::MAX);
+        let tx = predicate_tx(1);
+        cache.insert(None, &tx);
+
+        let (mut other, _) = tx.into();
+        if let Transaction::Script(script) = &mut other {
+            script.witnesses_mut().push(Witness::from(vec![1]));
+        }
+        let other = other
+            .into_checked_basic(Default::default(), &ConsensusParameters::standard())
+            .unwrap();
+
+        assert_eq!(cache.get(None, &other), None);
+    }
+
+    #[test]
+    fn upgrade_of_consensus_parameters_invalidates_cache() {
+        let mut cache = PredicateCache::new(usize::MAX);
+        let tx = predicate_tx(1);
+        cache.insert(None, &tx);
+
+        assert_eq!(cache.get(Some(10.into()), &tx), None);
+        assert_eq!(cache.get(None, &tx), None);
+    }
+
+    #[test]
+    fn lookup_of_other_version_does_not_invalidate_cache() {
+        let mut cache = PredicateCache::new(usize::MAX);
+        let tx = predicate_tx(1);
+        cache.insert(None, &tx);
+
+        assert_eq!(cache.verified(Some(10.into()), &tx), None);
+        assert_eq!(cache.verified(None, &tx), Some(tx));
+    }
+
+    #[test]
+    fn oldest_transactions_are_evicted_when_size_is_exceeded() {
+        let first = predicate_tx(1);
+        let second = predicate_tx(2);
+        let mut cache = PredicateCache::new(first.transaction().size());
+
+        cache.insert(None, &first);
+        cache.insert(None, &second);
+
+        assert_eq!(cache.get(None, &first), None);
+        assert_eq!(cache.get(None, &second), Some(second));
+    }
+
+    #[test]
+    fn transactions_without_predicates_are_not_cached() {
+        let mut cache = PredicateCache::new(usize::MAX);
+        let tx = TransactionBuilder::script(vec![], vec![])
+            .add_input(Input::coin_signed(
+                Default::default(),
+                Default::default(),
+                10,
+                AssetId::BASE,
+                Default::default(),
+                0,
+                Default::default(),
+            ))
+            .add_witness(Witness::default())
+            .finalize_as_transaction()
+            .into_checked_basic(Default::default(), &ConsensusParameters::standard())
+            .unwrap();
+
+        cache.insert(None, &tx);
+
+        assert_eq!(cache.get(None, &tx), None);
+    }
+}
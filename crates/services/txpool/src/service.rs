@@ -1,9 +1,20 @@
 use crate::{
+    admin::{
+        DumpedTransaction,
+        FlushFilter,
+        TxPoolDump,
+        AUDIT_TARGET,
+    },
+    config::SubscriptionLimits,
+    gas_price::block_gas,
+    origin::OriginCounters,
     ports::{
         BlockImporter,
         PeerToPeer,
         TxPoolDb,
     },
+    predicate_cache::SharedPredicateCache,
+    signature_cache::SignatureCache,
     transaction_selector::select_transactions,
     txpool::{
         check_single_tx,
@@ -17,6 +28,7 @@ use crate::{
 
 use fuel_core_services::{
     stream::BoxStream,
+    HealthReporter,
     RunnableService,
     RunnableTask,
     ServiceRunner,
@@ -32,12 +44,15 @@ use fuel_core_types::{
     fuel_types::{
         BlockHeight,
         Bytes32,
+        Word,
     },
     services::{
         p2p::{
             GossipData,
             GossipsubMessageAcceptance,
             GossipsubMessageInfo,
+            PeerId,
+            ShortTxId,
             TransactionGossipData,
         },
         txpool::{
@@ -45,17 +60,34 @@ use fuel_core_types::{
             Error,
             InsertionResult,
             TransactionStatus,
+            TxOrigin,
         },
     },
     tai64::Tai64,
 };
 
-use anyhow::anyhow;
+use anyhow::{
+    anyhow,
+    Context,
+};
+use fuel_core_metrics::txpool_metrics::txpool_metrics;
 use fuel_core_storage::transactional::AtomicView;
 use fuel_core_types::services::block_importer::SharedImportResult;
+use futures::{
+    future::BoxFuture,
+    stream::FuturesUnordered,
+};
 use parking_lot::Mutex as ParkingMutex;
 use std::{
-    sync::Arc,
+    collections::HashSet,
+    path::Path,
+    sync::{
+        atomic::{
+            AtomicBool,
+            Ordering,
+        },
+        Arc,
+    },
     time::Duration,
 };
 use tokio::{
@@ -65,6 +97,7 @@ use tokio::{
 use tokio_stream::StreamExt;
 use update_sender::UpdateSender;
 
+pub use self::update_sender::Subscriber;
 use self::update_sender::{
     MpscChannel,
     TxStatusStream,
@@ -81,9 +114,16 @@ pub struct TxStatusChange {
 }
 
 impl TxStatusChange {
-    pub fn new(capacity: usize, ttl: Duration) -> Self {
+    pub fn new(
+        capacity: usize,
+        ttl: Duration,
+        limits: SubscriptionLimits,
+        metrics: bool,
+    ) -> Self {
         let (new_tx_notification_sender, _) = broadcast::channel(capacity);
-        let update_sender = UpdateSender::new(capacity, ttl);
+        let update_sender = UpdateSender::new(capacity, ttl)
+            .with_limits(limits)
+            .with_metrics(metrics);
         Self {
             new_tx_notification_sender,
             update_sender,
@@ -100,12 +140,15 @@ impl TxStatusChange {
         self.update_sender.send(TxUpdate::new(id, message.into()));
     }
 
-    pub fn send_submitted(&self, id: Bytes32, time: Tai64) {
-        tracing::info!("Transaction {id} successfully submitted to the tx pool");
+    pub fn send_submitted(&self, id: Bytes32, time: Tai64, origin: TxOrigin) {
+        tracing::info!(
+            "Transaction {id} from {} successfully submitted to the tx pool",
+            origin.kind()
+        );
         let _ = self.new_tx_notification_sender.send(id);
         self.update_sender.send(TxUpdate::new(
             id,
-            TxStatusMessage::Status(TransactionStatus::Submitted { time }),
+            TxStatusMessage::Status(TransactionStatus::Submitted { time, origin }),
         ));
     }
 
@@ -126,6 +169,12 @@ pub struct SharedState<P2P, ViewProvider> {
     p2p: Arc<P2P>,
     consensus_params: ConsensusParameters,
     current_height: Arc<ParkingMutex<BlockHeight>>,
+    /// The insertion of new transactions is paused for maintenance.
+    paused: Arc<AtomicBool>,
+    /// The transactions with verified predicates.
+    predicate_cache: SharedPredicateCache,
+    /// The recently received transactions with verified signatures.
+    signature_cache: Arc<ParkingMutex<SignatureCache>>,
     config: Config,
 }
 
@@ -137,6 +186,9 @@ impl<P2P, ViewProvider> Clone for SharedState<P2P, ViewProvider> {
             p2p: self.p2p.clone(),
             consensus_params: self.consensus_params.clone(),
             current_height: self.current_height.clone(),
+            paused: self.paused.clone(),
+            predicate_cache: self.predicate_cache.clone(),
+            signature_cache: self.signature_cache.clone(),
             config: self.config.clone(),
         }
     }
@@ -145,15 +197,19 @@ impl<P2P, ViewProvider> Clone for SharedState<P2P, ViewProvider> {
 pub struct Task<P2P, ViewProvider> {
     gossiped_tx_stream: BoxStream<TransactionGossipData>,
     committed_block_stream: BoxStream<SharedImportResult>,
+    new_peers_stream: BoxStream<PeerId>,
+    /// Pulls of transactions from pools of newly connected peers.
+    pulls: FuturesUnordered<BoxFuture<'static, ()>>,
     shared: SharedState<P2P, ViewProvider>,
     ttl_timer: tokio::time::Interval,
+    health: HealthReporter,
 }
 
 #[async_trait::async_trait]
 impl<P2P, ViewProvider, View> RunnableService for Task<P2P, ViewProvider>
 where
-    P2P: PeerToPeer<GossipedTransaction = TransactionGossipData>,
-    ViewProvider: AtomicView<View = View>,
+    P2P: PeerToPeer<GossipedTransaction = TransactionGossipData> + 'static,
+    ViewProvider: AtomicView<View = View> + 'static,
     View: TxPoolDb,
 {
     const NAME: &'static str = "TxPool";
@@ -172,6 +228,7 @@ where
         _: Self::TaskParams,
     ) -> anyhow::Result<Self::Task> {
         self.ttl_timer.reset();
+        self.shared.replay_gas_price_history()?;
         Ok(self)
     }
 }
@@ -179,8 +236,8 @@ where
 #[async_trait::async_trait]
 impl<P2P, ViewProvider, View> RunnableTask for Task<P2P, ViewProvider>
 where
-    P2P: PeerToPeer<GossipedTransaction = TransactionGossipData>,
-    ViewProvider: AtomicView<View = View>,
+    P2P: PeerToPeer<GossipedTransaction = TransactionGossipData> + 'static,
+    ViewProvider: AtomicView<View = View> + 'static,
     View: TxPoolDb,
 {
     async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
@@ -211,6 +268,17 @@ where
                     let block = &result
                         .sealed_block
                         .entity;
+                    // The oracle updates the price before the pool accepts
+                    // transactions for the next block.
+                    if let Some(oracle) = &self.shared.config.gas_price_oracle {
+                        let params = self
+                            .shared
+                            .config
+                            .chain_config
+                            .consensus_parameters_at(new_height);
+                        let gas = block_gas(block.transactions(), params);
+                        oracle.on_block(new_height, gas);
+                    }
                     {
                         let mut lock = self.shared.txpool.lock();
                         lock.block_update(
@@ -220,6 +288,30 @@ where
                         );
                         *self.shared.current_height.lock() = new_height;
                     }
+                    self.health.progress();
+                    should_continue = true;
+                } else {
+                    should_continue = false;
+                }
+            }
+
+            Some(_) = self.pulls.next(), if !self.pulls.is_empty() => {
+                should_continue = true;
+            }
+
+            new_peer = self.new_peers_stream.next() => {
+                if let Some(peer_id) = new_peer {
+                    let shared = self.shared.clone();
+                    self.pulls.push(Box::pin(async move {
+                        match shared.pull_transactions_from_peer(peer_id.clone()).await {
+                            Ok(inserted) => tracing::debug!(
+                                "Pulled {inserted} transactions from the pool of the peer {peer_id}"
+                            ),
+                            Err(e) => tracing::warn!(
+                                "Failed to pull transactions from the pool of the peer {peer_id}: {e}"
+                            ),
+                        }
+                    }));
                     should_continue = true;
                 } else {
                     should_continue = false;
@@ -228,11 +320,31 @@ where
 
             new_transaction = self.gossiped_tx_stream.next() => {
                 if let Some(GossipData { data: Some(tx), message_id, peer_id }) = new_transaction {
+                    let origin = TxOrigin::Peer(peer_id.clone());
+                    if self.shared.reject_if_paused(&origin, 1) {
+                        // The transaction is not invalid, so the peer is not punished.
+                        let message_info = GossipsubMessageInfo {
+                            message_id,
+                            peer_id,
+                        };
+                        let _ = self.shared.p2p.notify_gossip_transaction_validity(
+                            message_info,
+                            GossipsubMessageAcceptance::Ignore,
+                        );
+                        return Ok(true)
+                    }
+
                     let id = tx.id(&self.shared.consensus_params.chain_id);
                     let current_height = *self.shared.current_height.lock();
 
                     // verify tx
-                    let checked_tx = check_single_tx(tx, current_height, &self.shared.config).await;
+                    let checked_tx = check_single_tx(
+                        tx,
+                        current_height,
+                        &self.shared.config,
+                        &self.shared.predicate_cache,
+                        &self.shared.signature_cache,
+                    ).await;
 
                     let acceptance = match checked_tx {
                         Ok(tx) => {
@@ -243,7 +355,8 @@ where
                                 .in_scope(|| {
                                     self.shared.txpool.lock().insert(
                                         &self.shared.tx_status_sender,
-                                        txs
+                                        txs,
+                                        &origin,
                                     )
                                 });
 
@@ -258,6 +371,7 @@ where
                             }
                         }
                         Err(_) => {
+                            self.shared.txpool.lock().record_rejected(&origin, 1);
                             GossipsubMessageAcceptance::Reject
                         }
                     };
@@ -310,6 +424,16 @@ impl<P2P, ViewProvider> SharedState<P2P, ViewProvider> {
         self.txpool.lock().find(&ids)
     }
 
+    /// Returns ids of at most `max_txs` transactions with the highest gas price.
+    pub fn tx_ids(&self, max_txs: usize) -> Vec<TxId> {
+        self.txpool
+            .lock()
+            .sorted_includable()
+            .take(max_txs)
+            .map(|tx| tx.id())
+            .collect()
+    }
+
     pub fn find_one(&self, id: TxId) -> Option<TxInfo> {
         self.txpool.lock().find_one(&id)
     }
@@ -318,9 +442,23 @@ impl<P2P, ViewProvider> SharedState<P2P, ViewProvider> {
         self.txpool.lock().find_dependent(&ids)
     }
 
-    pub fn select_transactions(&self, max_gas: u64) -> Vec<ArcPoolTx> {
+    /// Returns the minimum gas price of transactions accepted for the next block.
+    pub fn min_gas_price(&self) -> Word {
+        let current_height = *self.current_height.lock();
+        let next_height = current_height.succ().unwrap_or(current_height);
+        self.config.min_gas_price_at(next_height)
+    }
+
+    /// Selects transactions for the block at the `block_height`. Transactions priced below
+    /// the minimum gas price of the block stay in the pool for the following blocks.
+    pub fn select_transactions(
+        &self,
+        block_height: BlockHeight,
+        max_gas: u64,
+    ) -> Vec<ArcPoolTx> {
+        let min_gas_price = self.config.min_gas_price_at(block_height);
         let mut guard = self.txpool.lock();
-        let txs = guard.includable();
+        let txs = guard.includable().filter(|tx| tx.price() >= min_gas_price);
         let sorted_txs = select_transactions(txs, max_gas);
 
         for tx in sorted_txs.iter() {
@@ -338,11 +476,135 @@ impl<P2P, ViewProvider> SharedState<P2P, ViewProvider> {
     }
 
     pub fn tx_update_subscribe(&self, tx_id: Bytes32) -> anyhow::Result<TxStatusStream> {
+        self.tx_update_subscribe_as(tx_id, None)
+    }
+
+    /// Subscribes to the status updates of the transaction on behalf of the `subscriber`.
+    /// The number of subscriptions of the subscriber is limited by the
+    /// [`SubscriptionLimits::max_per_client`].
+    pub fn tx_update_subscribe_as(
+        &self,
+        tx_id: Bytes32,
+        subscriber: Option<Subscriber>,
+    ) -> anyhow::Result<TxStatusStream> {
         self.tx_status_sender
             .update_sender
-            .try_subscribe::<MpscChannel>(tx_id)
+            .try_subscribe_as::<MpscChannel>(tx_id, subscriber)
             .ok_or(anyhow!("Maximum number of subscriptions reached"))
     }
+
+    /// Pauses the insertion of new transactions from the API and the gossip.
+    /// Transactions in the pool are still available for block production.
+    pub fn pause(&self) {
+        if !self.paused.swap(true, Ordering::SeqCst) {
+            tracing::warn!(target: AUDIT_TARGET, "The insertion into the TxPool is paused");
+            if self.config.metrics {
+                txpool_metrics().paused.set(1);
+            }
+        }
+    }
+
+    /// Resumes the insertion of new transactions.
+    pub fn resume(&self) {
+        if self.paused.swap(false, Ordering::SeqCst) {
+            tracing::warn!(target: AUDIT_TARGET, "The insertion into the TxPool is resumed");
+            if self.config.metrics {
+                txpool_metrics().paused.set(0);
+            }
+        }
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::SeqCst)
+    }
+
+    /// Returns `true` and records the rejection of `count` transactions
+    /// from the `origin` if the insertion is paused.
+    fn reject_if_paused(&self, origin: &TxOrigin, count: usize) -> bool {
+        let paused = self.is_paused();
+        if paused {
+            self.txpool.lock().record_rejected(origin, count);
+            if self.config.metrics {
+                txpool_metrics().rejected_while_paused.inc_by(count as u64);
+            }
+        }
+        paused
+    }
+
+    /// Returns the number of transactions from each origin tracked by the pool,
+    /// sorted from the most active origin to the least active.
+    pub fn origin_statistics(&self) -> Vec<(TxOrigin, OriginCounters)> {
+        self.txpool.lock().origin_statistics().sorted()
+    }
+
+    /// Removes transactions selected by the `filter` with their dependent transactions.
+    /// Subscribers are notified that transactions are squeezed out.
+    pub fn flush(&self, filter: FlushFilter) -> Vec<ArcPoolTx> {
+        let removed = self.txpool.lock().flush(&self.tx_status_sender, &filter);
+        tracing::warn!(
+            target: AUDIT_TARGET,
+            "Flushed {} transactions from the TxPool with the filter {:?}",
+            removed.len(),
+            filter
+        );
+        if self.config.metrics {
+            txpool_metrics()
+                .flushed_transactions
+                .inc_by(removed.len() as u64);
+        }
+        removed
+    }
+
+    /// Returns the current state of the pool.
+    pub fn snapshot(&self) -> TxPoolDump {
+        let transactions = {
+            let txpool = self.txpool.lock();
+            txpool
+                .sorted_includable()
+                .filter_map(|tx| txpool.txs().get(&tx.id()).cloned())
+                .map(|info| DumpedTransaction {
+                    id: info.id(),
+                    gas_price: info.price(),
+                    max_gas: info.max_gas(),
+                    submitted_time: info.submitted_time().as_secs(),
+                    origin: info.origin().label(),
+                    transaction: Transaction::from(info.tx().as_ref()),
+                })
+                .collect()
+        };
+
+        TxPoolDump {
+            height: *self.current_height.lock(),
+            paused: self.is_paused(),
+            transactions,
+        }
+    }
+
+    /// Writes the current state of the pool as JSON to the new file at `path`.
+    /// Fails if the file already exists. Returns the number of dumped transactions.
+    pub fn dump(&self, path: &Path) -> anyhow::Result<usize> {
+        let dump = self.snapshot();
+        let file = std::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(path)
+            .with_context(|| {
+                format!("Failed to create the dump file {}", path.display())
+            })?;
+        serde_json::to_writer_pretty(std::io::BufWriter::new(file), &dump)
+            .context("Failed to write the dump of the TxPool")?;
+
+        tracing::warn!(
+            target: AUDIT_TARGET,
+            "Dumped {} transactions of the TxPool to {}",
+            dump.transactions.len(),
+            path.display()
+        );
+        if self.config.metrics {
+            txpool_metrics().dumps.inc();
+        }
+        Ok(dump.transactions.len())
+    }
 }
 
 impl<P2P, ViewProvider, View> SharedState<P2P, ViewProvider>
@@ -351,15 +613,108 @@ where
     ViewProvider: AtomicView<View = View>,
     View: TxPoolDb,
 {
+    /// Feeds the gas price oracle with the latest blocks the price depends on,
+    /// so the price doesn't depend on when the node was started.
+    fn replay_gas_price_history(&self) -> anyhow::Result<()> {
+        let Some(oracle) = &self.config.gas_price_oracle else {
+            return Ok(())
+        };
+        let current_height = u32::from(*self.current_height.lock());
+        let first_height = current_height
+            .saturating_add(1)
+            .saturating_sub(oracle.history_blocks());
+        let view = self.txpool.lock().database().latest_view();
+        for height in first_height..=current_height {
+            let height = BlockHeight::from(height);
+            if let Some(transactions) = view.block_transactions(&height)? {
+                let params = self.config.chain_config.consensus_parameters_at(height);
+                oracle.on_block(height, block_gas(&transactions, params));
+            }
+        }
+        Ok(())
+    }
+
     #[tracing::instrument(name = "insert_submitted_txn", skip_all)]
     pub async fn insert(
         &self,
         txs: Vec<Arc<Transaction>>,
+        origin: TxOrigin,
+    ) -> Vec<anyhow::Result<InsertionResult>> {
+        self.insert_transactions(txs, origin, true).await
+    }
+
+    /// Pulls transactions that are missing in the pool from the pool of the peer.
+    /// The transactions are not broadcast, because the peer already has them
+    /// and gossips them to other peers. Returns the number of inserted transactions.
+    pub async fn pull_transactions_from_peer(
+        &self,
+        peer_id: PeerId,
+    ) -> anyhow::Result<usize> {
+        if self.is_paused() {
+            return Ok(0)
+        }
+
+        let Some(summary) = self.p2p.request_tx_pool_summary(peer_id.clone()).await?
+        else {
+            return Ok(0)
+        };
+
+        let missing: Vec<_> = {
+            let txpool = self.txpool.lock();
+            let mut known: HashSet<_> =
+                txpool.txs().keys().map(ShortTxId::from).collect();
+            summary
+                .into_iter()
+                .filter(|short_id| known.insert(*short_id))
+                .collect()
+        };
+        if missing.is_empty() {
+            return Ok(0)
+        }
+
+        let Some(txs) = self
+            .p2p
+            .request_pooled_transactions(peer_id.clone(), missing.clone())
+            .await?
+        else {
+            return Ok(0)
+        };
+        // The peer can't send more transactions than was requested.
+        let txs = txs.into_iter().take(missing.len()).map(Arc::new).collect();
+
+        let inserted = self
+            .insert_transactions(txs, TxOrigin::Peer(peer_id), false)
+            .await
+            .into_iter()
+            .filter(Result::is_ok)
+            .count();
+        Ok(inserted)
+    }
+
+    async fn insert_transactions(
+        &self,
+        txs: Vec<Arc<Transaction>>,
+        origin: TxOrigin,
+        broadcast: bool,
     ) -> Vec<anyhow::Result<InsertionResult>> {
+        if self.reject_if_paused(&origin, txs.len()) {
+            return txs
+                .iter()
+                .map(|_| Err(Error::NotInsertedPoolPaused.into()))
+                .collect()
+        }
+
         // verify txs
         let current_height = *self.current_height.lock();
 
-        let checked_txs = check_transactions(&txs, current_height, &self.config).await;
+        let checked_txs = check_transactions(
+            &txs,
+            current_height,
+            &self.config,
+            &self.predicate_cache,
+            &self.signature_cache,
+        )
+        .await;
 
         let mut valid_txs = vec![];
 
@@ -375,11 +730,16 @@ where
             .collect();
 
         // insert txs
-        let insertion = { self.txpool.lock().insert(&self.tx_status_sender, valid_txs) };
+        let insertion = {
+            let mut txpool = self.txpool.lock();
+            let rejected = checked_txs.iter().filter(|result| result.is_some()).count();
+            txpool.record_rejected(&origin, rejected);
+            txpool.insert(&self.tx_status_sender, valid_txs, &origin)
+        };
 
         for (ret, tx) in insertion.iter().zip(txs.into_iter()) {
             match ret {
-                Ok(_) => {
+                Ok(_) if broadcast => {
                     let result = self.p2p.broadcast_transaction(tx.clone());
                     if let Err(e) = result {
                         // It can be only in the case of p2p being down or requests overloading it.
@@ -389,7 +749,7 @@ where
                         );
                     }
                 }
-                Err(_) => {}
+                _ => {}
             }
         }
 
@@ -441,6 +801,8 @@ pub fn new_service<P2P, Importer, ViewProvider>(
     importer: Importer,
     p2p: P2P,
     current_height: BlockHeight,
+    predicate_cache: SharedPredicateCache,
+    health: HealthReporter,
 ) -> Service<P2P, ViewProvider>
 where
     Importer: BlockImporter,
@@ -450,6 +812,7 @@ where
 {
     let p2p = Arc::new(p2p);
     let gossiped_tx_stream = p2p.gossiped_transaction_events();
+    let new_peers_stream = p2p.new_peers_events();
     let committed_block_stream = importer.block_events();
     let mut ttl_timer = tokio::time::interval(config.transaction_ttl);
     ttl_timer.set_missed_tick_behavior(MissedTickBehavior::Skip);
@@ -459,6 +822,8 @@ where
     let task = Task {
         gossiped_tx_stream,
         committed_block_stream,
+        new_peers_stream,
+        pulls: FuturesUnordered::new(),
         shared: SharedState {
             tx_status_sender: TxStatusChange::new(
                 number_of_active_subscription,
@@ -467,14 +832,23 @@ where
                 // We allow the subscriber to receive the event produced by TxPool's TTL.
                 // But we still want to drop subscribers after `2 * TxPool_TTL`.
                 config.transaction_ttl.saturating_mul(2),
+                config.subscription_limits,
+                config.metrics,
             ),
             txpool,
             p2p,
             consensus_params,
             current_height: Arc::new(ParkingMutex::new(current_height)),
+            paused: Arc::new(AtomicBool::new(false)),
+            predicate_cache,
+            signature_cache: Arc::new(ParkingMutex::new(SignatureCache::new(
+                config.signature_cache_size,
+                config.signature_cache_ttl,
+            ))),
             config,
         },
         ttl_timer,
+        health,
     };
 
     Service::new(task)
@@ -2,6 +2,7 @@ use super::*;
 use crate::{
     mock_db::MockDBProvider,
     ports::BlockImporter,
+    predicate_cache::PredicateCache,
     MockDb,
 };
 use fuel_core_services::{
@@ -25,6 +26,7 @@ use fuel_core_types::{
     services::{
         block_importer::ImportResult,
         p2p::GossipsubMessageAcceptance,
+        port::PortResult,
     },
 };
 use std::cell::RefCell;
@@ -67,10 +69,11 @@ impl TestContext {
 mockall::mock! {
     pub P2P {}
 
+    #[async_trait::async_trait]
     impl PeerToPeer for P2P {
         type GossipedTransaction = GossipedTransaction;
 
-        fn broadcast_transaction(&self, transaction: Arc<Transaction>) -> anyhow::Result<()>;
+        fn broadcast_transaction(&self, transaction: Arc<Transaction>) -> PortResult<()>;
 
         fn gossiped_transaction_events(&self) -> BoxStream<GossipedTransaction>;
 
@@ -78,7 +81,20 @@ mockall::mock! {
             &self,
             message_info: GossipsubMessageInfo,
             validity: GossipsubMessageAcceptance,
-        ) -> anyhow::Result<()>;
+        ) -> PortResult<()>;
+
+        fn new_peers_events(&self) -> BoxStream<PeerId>;
+
+        async fn request_tx_pool_summary(
+            &self,
+            peer_id: PeerId,
+        ) -> PortResult<Option<Vec<ShortTxId>>>;
+
+        async fn request_pooled_transactions(
+            &self,
+            peer_id: PeerId,
+            tx_ids: Vec<ShortTxId>,
+        ) -> PortResult<Option<Vec<Transaction>>>;
     }
 }
 
@@ -99,6 +115,37 @@ impl MockP2P {
         });
         p2p.expect_broadcast_transaction()
             .returning(move |_| Ok(()));
+        p2p.expect_new_peers_events()
+            .returning(|| Box::pin(fuel_core_services::stream::pending()));
+        p2p
+    }
+
+    /// Creates the P2P with one connected peer that has `txs` in its pool.
+    pub fn new_with_peer_pool(txs: Vec<Transaction>) -> Self {
+        let mut p2p = MockP2P::default();
+        p2p.expect_gossiped_transaction_events()
+            .returning(|| Box::pin(fuel_core_services::stream::pending()));
+        p2p.expect_new_peers_events().returning(|| {
+            let peer = futures::stream::iter([PeerId::from(vec![1])]);
+            Box::pin(peer.chain(fuel_core_services::stream::pending()))
+        });
+        let summary: Vec<_> = txs
+            .iter()
+            .map(|tx| ShortTxId::from(&tx.id(&Default::default())))
+            .collect();
+        p2p.expect_request_tx_pool_summary()
+            .returning(move |_| Ok(Some(summary.clone())));
+        p2p.expect_request_pooled_transactions()
+            .returning(move |_, tx_ids| {
+                let txs = txs
+                    .iter()
+                    .filter(|tx| {
+                        tx_ids.contains(&ShortTxId::from(&tx.id(&Default::default())))
+                    })
+                    .cloned()
+                    .collect();
+                Ok(Some(txs))
+            });
         p2p
     }
 }
@@ -139,6 +186,7 @@ pub struct TestContextBuilder {
     rng: StdRng,
     p2p: Option<MockP2P>,
     importer: Option<MockImporter>,
+    current_height: BlockHeight,
 }
 
 impl Default for TestContextBuilder {
@@ -155,6 +203,7 @@ impl TestContextBuilder {
             rng: StdRng::seed_from_u64(10),
             p2p: None,
             importer: None,
+            current_height: Default::default(),
         }
     }
 
@@ -171,6 +220,15 @@ impl TestContextBuilder {
         self.p2p = Some(p2p)
     }
 
+    pub fn with_current_height(&mut self, height: BlockHeight) {
+        self.current_height = height
+    }
+
+    /// Stores the block with the `transactions` at the `height` in the database.
+    pub fn with_block(&mut self, height: BlockHeight, transactions: Vec<Transaction>) {
+        self.mock_db.insert_block(height, transactions)
+    }
+
     pub fn setup_script_tx(&mut self, gas_price: Word) -> Transaction {
         let (_, gas_coin) = self.setup_coin();
         TransactionBuilder::script(vec![], vec![])
@@ -187,6 +245,7 @@ impl TestContextBuilder {
     pub fn build(self) -> TestContext {
         let rng = RefCell::new(self.rng);
         let config = self.config.unwrap_or_default();
+        let predicate_cache = PredicateCache::shared(config.predicate_cache_size);
         let mock_db = self.mock_db;
 
         let p2p = self.p2p.unwrap_or_else(|| MockP2P::new_with_txs(vec![]));
@@ -199,6 +258,8 @@ impl TestContextBuilder {
             MockDBProvider(mock_db.clone()),
             importer,
             p2p,
+            self.current_height,
+            predicate_cache,
             Default::default(),
         );
 
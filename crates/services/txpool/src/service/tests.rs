@@ -5,7 +5,10 @@ use crate::service::test_helpers::{
 };
 use fuel_core_services::Service as ServiceTrait;
 use fuel_core_types::{
-    fuel_tx::UniqueIdentifier,
+    fuel_tx::{
+        field::Inputs,
+        UniqueIdentifier,
+    },
     fuel_types::ChainId,
 };
 use std::time::Duration;
@@ -33,7 +36,10 @@ async fn test_find() {
 
     let service = ctx.service();
 
-    let out = service.shared.insert(vec![tx1.clone(), tx2.clone()]).await;
+    let out = service
+        .shared
+        .insert(vec![tx1.clone(), tx2.clone()], TxOrigin::Injected)
+        .await;
 
     assert_eq!(out.len(), 2, "Should be len 2:{out:?}");
     assert!(out[0].is_ok(), "Tx1 should be OK, got err:{out:?}");
@@ -71,7 +77,10 @@ async fn test_prune_transactions() {
 
     let out = service
         .shared
-        .insert(vec![tx1.clone(), tx2.clone(), tx3.clone()])
+        .insert(
+            vec![tx1.clone(), tx2.clone(), tx3.clone()],
+            TxOrigin::Injected,
+        )
         .await;
 
     // Check that we have all transactions after insertion.
@@ -126,13 +135,19 @@ async fn test_prune_transactions_the_oldest() {
     let service = ctx.service();
 
     // insert tx1 at time `0`
-    let out = service.shared.insert(vec![tx1.clone()]).await;
+    let out = service
+        .shared
+        .insert(vec![tx1.clone()], TxOrigin::Injected)
+        .await;
     assert!(out[0].is_ok(), "Tx1 should be OK, got err:{out:?}");
 
     // sleep for `4` seconds
     tokio::time::sleep(Duration::from_secs(4)).await;
     // insert tx2 at time `4`
-    let out = service.shared.insert(vec![tx2.clone()]).await;
+    let out = service
+        .shared
+        .insert(vec![tx2.clone()], TxOrigin::Injected)
+        .await;
     assert!(out[0].is_ok(), "Tx2 should be OK, got err:{out:?}");
 
     // check that tx1 and tx2 are still there at time `4`
@@ -146,14 +161,20 @@ async fn test_prune_transactions_the_oldest() {
     // sleep for another `4` seconds
     tokio::time::sleep(Duration::from_secs(4)).await;
     // insert tx3 at time `8`
-    let out = service.shared.insert(vec![tx3.clone()]).await;
+    let out = service
+        .shared
+        .insert(vec![tx3.clone()], TxOrigin::Injected)
+        .await;
     assert!(out[0].is_ok(), "Tx3 should be OK, got err:{out:?}");
 
     // sleep for `3` seconds
     tokio::time::sleep(Duration::from_secs(3)).await;
 
     // insert tx4 at time `11`
-    let out = service.shared.insert(vec![tx4.clone()]).await;
+    let out = service
+        .shared
+        .insert(vec![tx4.clone()], TxOrigin::Injected)
+        .await;
     assert!(out[0].is_ok(), "Tx4 should be OK, got err:{out:?}");
 
     // time is now `11`, tx1 and tx2 should be pruned
@@ -204,7 +225,10 @@ async fn simple_insert_removal_subscription() {
         .tx_update_subscribe(tx2.cached_id().unwrap())
         .unwrap();
 
-    let out = service.shared.insert(vec![tx1.clone(), tx2.clone()]).await;
+    let out = service
+        .shared
+        .insert(vec![tx1.clone(), tx2.clone()], TxOrigin::Injected)
+        .await;
 
     if out[0].is_ok() {
         assert_eq!(
@@ -267,3 +291,225 @@ async fn simple_insert_removal_subscription() {
 
     service.stop_and_await().await.unwrap();
 }
+
+#[tokio::test]
+async fn insert_is_rejected_while_paused() {
+    let ctx = TestContextBuilder::new().build_and_start().await;
+    let tx1 = Arc::new(ctx.setup_script_tx(10));
+    let tx2 = Arc::new(ctx.setup_script_tx(20));
+    let service = ctx.service();
+
+    service.shared.pause();
+    assert!(service.shared.is_paused());
+    let out = service
+        .shared
+        .insert(vec![tx1.clone()], TxOrigin::Injected)
+        .await;
+    assert_eq!(
+        out[0].as_ref().unwrap_err().downcast_ref::<TxPoolError>(),
+        Some(&TxPoolError::NotInsertedPoolPaused)
+    );
+    assert_eq!(service.shared.pending_number(), 0);
+
+    service.shared.resume();
+    let out = service
+        .shared
+        .insert(vec![tx2.clone()], TxOrigin::Injected)
+        .await;
+    assert!(out[0].is_ok(), "Tx2 should be OK, got err:{out:?}");
+
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn flush_removes_transactions_of_the_owner() {
+    let ctx = TestContextBuilder::new().build_and_start().await;
+    let tx1 = Arc::new(ctx.setup_script_tx(10));
+    let tx2 = Arc::new(ctx.setup_script_tx(20));
+    let service = ctx.service();
+    let owner = *tx1.as_script().unwrap().inputs()[0].input_owner().unwrap();
+
+    let out = service
+        .shared
+        .insert(vec![tx1.clone(), tx2.clone()], TxOrigin::Injected)
+        .await;
+    assert!(out.iter().all(|result| result.is_ok()), "{out:?}");
+    let mut tx1_updates = service
+        .shared
+        .tx_update_subscribe(tx1.cached_id().unwrap())
+        .unwrap();
+
+    let removed = service.shared.flush(FlushFilter::Owner(owner));
+
+    assert_eq!(removed.len(), 1);
+    assert_eq!(removed[0].id(), tx1.cached_id().unwrap());
+    assert_eq!(
+        tx1_updates.next().await.unwrap(),
+        TxStatusMessage::Status(TransactionStatus::SqueezedOut {
+            reason: TxPoolError::Flushed.to_string()
+        })
+    );
+    assert_eq!(service.shared.pending_number(), 1);
+
+    let removed = service.shared.flush(FlushFilter::All);
+    assert_eq!(removed.len(), 1);
+    assert_eq!(service.shared.pending_number(), 0);
+
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn dump_writes_transactions_of_the_pool() {
+    let ctx = TestContextBuilder::new().build_and_start().await;
+    let tx1 = Arc::new(ctx.setup_script_tx(10));
+    let tx2 = Arc::new(ctx.setup_script_tx(20));
+    let service = ctx.service();
+    let out = service
+        .shared
+        .insert(vec![tx1.clone(), tx2.clone()], TxOrigin::Injected)
+        .await;
+    assert!(out.iter().all(|result| result.is_ok()), "{out:?}");
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("txpool.json");
+
+    let dumped = service.shared.dump(&path).unwrap();
+
+    assert_eq!(dumped, 2);
+    let dump: TxPoolDump =
+        serde_json::from_reader(std::fs::File::open(&path).unwrap()).unwrap();
+    assert_eq!(dump, service.shared.snapshot());
+    assert!(!dump.paused);
+    // Sorted from the highest gas price to the lowest.
+    assert_eq!(dump.transactions[0].transaction, *tx2);
+    assert_eq!(dump.transactions[1].transaction, *tx1);
+
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn dump_does_not_overwrite_existing_file() {
+    // Given
+    let ctx = TestContextBuilder::new().build_and_start().await;
+    let service = ctx.service();
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("txpool.json");
+    std::fs::write(&path, b"existing").unwrap();
+
+    // When
+    let result = service.shared.dump(&path);
+
+    // Then
+    assert!(result.is_err());
+    assert_eq!(std::fs::read(&path).unwrap(), b"existing");
+
+    service.stop_and_await().await.unwrap();
+}
+
+/// The oracle that requires the `price` starting from the `height`.
+#[derive(Debug)]
+struct PriceFromHeight {
+    height: BlockHeight,
+    price: Word,
+}
+
+impl crate::ports::GasPriceOracle for PriceFromHeight {
+    fn min_gas_price(&self, block_height: BlockHeight) -> Word {
+        if block_height >= self.height {
+            self.price
+        } else {
+            0
+        }
+    }
+}
+
+fn config_with_oracle(height: u32, price: Word) -> Config {
+    Config {
+        gas_price_oracle: Some(Arc::new(PriceFromHeight {
+            height: height.into(),
+            price,
+        })),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn insert_rejects_transactions_below_the_oracle_price() {
+    let ctx = TestContextBuilder::new()
+        .with_config(config_with_oracle(1, 15))
+        .build_and_start()
+        .await;
+    let tx1 = Arc::new(ctx.setup_script_tx(10));
+    let tx2 = Arc::new(ctx.setup_script_tx(20));
+    let service = ctx.service();
+
+    assert_eq!(service.shared.min_gas_price(), 15);
+    let out = service
+        .shared
+        .insert(vec![tx1.clone(), tx2.clone()], TxOrigin::Injected)
+        .await;
+
+    assert_eq!(
+        out[0].as_ref().unwrap_err().downcast_ref::<TxPoolError>(),
+        Some(&TxPoolError::NotInsertedGasPriceTooLow)
+    );
+    assert!(out[1].is_ok(), "Tx2 should be OK, got err:{out:?}");
+
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn transactions_below_the_block_price_stay_in_the_pool() {
+    let ctx = TestContextBuilder::new()
+        .with_config(config_with_oracle(5, 15))
+        .build_and_start()
+        .await;
+    let tx1 = Arc::new(ctx.setup_script_tx(10));
+    let tx2 = Arc::new(ctx.setup_script_tx(20));
+    let service = ctx.service();
+    let out = service
+        .shared
+        .insert(vec![tx1.clone(), tx2.clone()], TxOrigin::Injected)
+        .await;
+    assert!(out.iter().all(|result| result.is_ok()), "{out:?}");
+
+    let selected = service.shared.select_transactions(5u32.into(), u64::MAX);
+
+    assert_eq!(selected.len(), 1);
+    assert_eq!(selected[0].id(), tx2.id(&Default::default()));
+    assert_eq!(service.shared.pending_number(), 1);
+
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn block_fullness_oracle_replays_the_history_blocks_on_start() {
+    // Given
+    let oracle = crate::gas_price::BlockFullnessGasPrice::new(
+        crate::gas_price::BlockFullnessConfig {
+            floor: 10,
+            history_blocks: 2,
+            ..Default::default()
+        },
+        // Any transaction fills the block above the target.
+        2,
+    );
+    let mut builder = TestContextBuilder::new().with_config(Config {
+        gas_price_oracle: Some(Arc::new(oracle)),
+        ..Default::default()
+    });
+    for height in 1..=3u32 {
+        let tx = builder.setup_script_tx(10);
+        builder.with_block(height.into(), vec![tx]);
+    }
+    builder.with_current_height(3.into());
+
+    // When
+    let ctx = builder.build_and_start().await;
+    let service = ctx.service();
+
+    // Then
+    // Only the blocks 2 and 3 raise the price: 10 -> 11 -> 12.
+    assert_eq!(service.shared.min_gas_price(), 12);
+
+    service.stop_and_await().await.unwrap();
+}
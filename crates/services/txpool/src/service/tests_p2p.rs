@@ -6,6 +6,7 @@ use crate::service::test_helpers::{
 use fuel_core_services::Service;
 use fuel_core_types::fuel_tx::{
     Transaction,
+    TransactionBuilder,
     UniqueIdentifier,
 };
 use std::{
@@ -68,7 +69,10 @@ async fn insert_from_local_broadcasts_to_p2p() {
         .tx_update_subscribe(tx1.cached_id().unwrap())
         .unwrap();
 
-    let out = service.shared.insert(vec![Arc::new(tx1.clone())]).await;
+    let out = service
+        .shared
+        .insert(vec![Arc::new(tx1.clone())], TxOrigin::Injected)
+        .await;
 
     if out[0].is_ok() {
         // we are sure that included tx are already broadcasted.
@@ -133,3 +137,126 @@ async fn test_insert_from_p2p_does_not_broadcast_to_p2p() {
         "expected a timeout because no broadcast should have occurred"
     )
 }
+
+#[tokio::test]
+async fn gossiped_tx_is_ignored_while_paused() {
+    let mut ctx_builder = TestContextBuilder::new();
+    let tx1 = ctx_builder.setup_script_tx(10);
+    let mut p2p = MockP2P::new_with_txs(vec![tx1.clone()]);
+    let (send, mut receive) = broadcast::channel::<GossipsubMessageAcceptance>(1);
+    p2p.expect_notify_gossip_transaction_validity()
+        .returning(move |_, acceptance| {
+            send.send(acceptance).unwrap();
+            Ok(())
+        });
+    ctx_builder.with_p2p(p2p);
+
+    let ctx = ctx_builder.build();
+    let service = ctx.service();
+    service.shared.pause();
+    service.start_and_await().await.unwrap();
+
+    let acceptance = tokio::time::timeout(Duration::from_secs(5), receive.recv())
+        .await
+        .expect("The gossiped tx should be processed")
+        .unwrap();
+    assert_eq!(acceptance, GossipsubMessageAcceptance::Ignore);
+    let out = service.shared.find(vec![tx1.id(&Default::default())]);
+    assert!(out[0].is_none(), "The tx should not be inserted");
+}
+
+#[tokio::test]
+async fn missing_transactions_are_pulled_from_new_peer() {
+    let mut ctx_builder = TestContextBuilder::new();
+    let known = ctx_builder.setup_script_tx(10);
+    let missing = ctx_builder.setup_script_tx(20);
+    let mut p2p = MockP2P::new_with_peer_pool(vec![known.clone(), missing.clone()]);
+    // Only the transaction submitted via API is broadcast, pulled transactions are not.
+    let submitted = known.clone();
+    p2p.expect_broadcast_transaction()
+        .withf(move |tx: &Arc<Transaction>| **tx == submitted)
+        .times(1)
+        .returning(|_| Ok(()));
+    ctx_builder.with_p2p(p2p);
+
+    let ctx = ctx_builder.build();
+    let service = ctx.service();
+    let result = service
+        .shared
+        .insert(vec![Arc::new(known.clone())], TxOrigin::Injected)
+        .await;
+    assert!(result[0].is_ok());
+    let mut receiver = service
+        .shared
+        .tx_update_subscribe(missing.id(&Default::default()))
+        .unwrap();
+
+    service.start_and_await().await.unwrap();
+
+    let res = tokio::time::timeout(Duration::from_secs(5), receiver.next())
+        .await
+        .expect("The missing tx should be pulled");
+    assert!(matches!(
+        res,
+        Some(TxStatusMessage::Status(TransactionStatus::Submitted { .. }))
+    ));
+    assert_eq!(service.shared.pending_number(), 2);
+}
+
+#[tokio::test(start_paused = true)]
+async fn transactions_are_counted_by_their_origin() {
+    use crate::{
+        origin::OriginCounters,
+        test_utils::TestHarnessBuilder,
+    };
+
+    let mut harness = TestHarnessBuilder::new().build_and_start().await;
+    let submitted = harness.setup_script_tx(10);
+    let (_, coin) = harness.setup_coin();
+    let gossiped = |gas_price| {
+        TransactionBuilder::script(vec![], vec![])
+            .gas_price(gas_price)
+            .script_gas_limit(1000)
+            .add_input(coin.clone())
+            .finalize_as_transaction()
+    };
+    let mut events = harness
+        .shared()
+        .tx_update_subscribe(submitted.id(&Default::default()))
+        .unwrap();
+
+    assert!(harness.submit(vec![submitted.clone()]).await[0].is_ok());
+    harness.gossip(gossiped(10)).await;
+    // The double spend of the gossiped transaction is rejected.
+    harness.gossip(gossiped(5)).await;
+    harness.commit_block(vec![submitted, gossiped(10)]).await;
+
+    let api = TxOrigin::Api { api_key: None };
+    let peer = TxOrigin::Peer(vec![0].into());
+    let event = events.next().await;
+    assert!(matches!(
+        event,
+        Some(TxStatusMessage::Status(TransactionStatus::Submitted { origin, .. })) if origin == api
+    ));
+    assert_eq!(
+        harness.shared().origin_statistics(),
+        vec![
+            (
+                peer,
+                OriginCounters {
+                    submitted: 1,
+                    rejected: 1,
+                    included: 1,
+                }
+            ),
+            (
+                api,
+                OriginCounters {
+                    submitted: 1,
+                    rejected: 0,
+                    included: 1,
+                }
+            ),
+        ]
+    );
+}
@@ -2,6 +2,10 @@ use std::{
     collections::HashMap,
     future::Future,
     pin::Pin,
+    task::{
+        Context,
+        Poll,
+    },
     time::Duration,
 };
 
@@ -46,6 +50,10 @@ pub struct UpdateSender {
     permits: GetPermit,
     /// TTL for senders
     ttl: Duration,
+    /// Limits of senders of a single subscriber.
+    limits: SubscriptionLimits,
+    /// Report the number of active senders to metrics.
+    metrics: bool,
 }
 
 /// Error returned when a transaction status update cannot be sent.
@@ -75,6 +83,9 @@ pub type TxStatusStream = Pin<Box<dyn Stream<Item = TxStatusMessage> + Send + Sy
 /// Gives permits to subscribe once they are available.
 type GetPermit = Arc<dyn PermitsDebug + Send + Sync>;
 
+/// The identity of the client that subscribed to transaction status updates.
+pub type Subscriber = String;
+
 /// A sender that is subscribed to transaction status updates
 /// for a specific transaction hash.
 struct Sender<P = OwnedSemaphorePermit, Tx = mpsc::Sender<TxStatusMessage>> {
@@ -86,6 +97,10 @@ struct Sender<P = OwnedSemaphorePermit, Tx = mpsc::Sender<TxStatusMessage>> {
     tx: Tx,
     /// time that this sender was created
     created: Instant,
+    /// The client owning this sender, if known.
+    subscriber: Option<Subscriber>,
+    /// time of the last update sent to this sender
+    last_activity: Instant,
 }
 
 /// A trait for sending transaction status updates.
@@ -218,26 +233,47 @@ impl UpdateSender {
             senders: Default::default(),
             permits: Arc::new(Semaphore::new(capacity)),
             ttl,
+            limits: SubscriptionLimits {
+                max_per_client: usize::MAX,
+                idle_timeout: Duration::MAX,
+            },
+            metrics: false,
         }
     }
 
+    /// Set the limits of senders of a single subscriber.
+    pub fn with_limits(mut self, limits: SubscriptionLimits) -> UpdateSender {
+        self.limits = limits;
+        self
+    }
+
+    /// Enable reporting of the number of active senders to metrics.
+    pub fn with_metrics(mut self, metrics: bool) -> UpdateSender {
+        self.metrics = metrics;
+        self
+    }
+
     /// Try to subscribe for updates, returns a TxStatusStream if successful
     pub fn try_subscribe<C>(&self, tx_id: Bytes32) -> Option<TxStatusStream>
     where
         C: CreateChannel,
     {
-        // Remove closed senders from the list
-        remove_closed_and_expired(&mut self.senders.lock(), self.ttl);
-
-        // Try to acquire a permit from the semaphore
-        let permit = Arc::clone(&self.permits).try_acquire()?;
-
-        // Call subscribe_inner with the acquired permit
-        Some(self.subscribe_inner::<C>(tx_id, permit))
+        self.try_subscribe_as::<C>(tx_id, None)
     }
 
-    /// Subscribe to updates with the given transaction id and a permit.
-    fn subscribe_inner<C>(&self, tx_id: Bytes32, permit: Permit) -> TxStatusStream
+    /// Try to subscribe for updates on behalf of the `subscriber`,
+    /// returns a TxStatusStream if successful. Subscriptions without
+    /// the `subscriber` share the limit of the anonymous subscriber.
+    ///
+    /// If the subscriber reached its limit of senders, its least recently
+    /// updated sender is dropped to free the place for the new one.
+    /// The dropped sender receives the [`TxStatusMessage::FailedStatus`]
+    /// before its stream ends.
+    pub fn try_subscribe_as<C>(
+        &self,
+        tx_id: Bytes32,
+        subscriber: Option<Subscriber>,
+    ) -> Option<TxStatusStream>
     where
         C: CreateChannel,
     {
@@ -245,10 +281,44 @@ impl UpdateSender {
         let mut senders = self.senders.lock();
 
         // Remove closed senders from the list
-        remove_closed_and_expired(&mut senders, self.ttl);
+        remove_closed_and_expired(&mut senders, self.ttl, self.limits.idle_timeout);
+
+        // Evict the least recently updated sender of the subscriber
+        // if the subscriber is at its limit.
+        if count_of(&senders, &subscriber) >= self.limits.max_per_client
+            && !evict_least_recent(&mut senders, &subscriber)
+        {
+            self.report(&senders);
+            return None
+        }
+
+        // Try to acquire a permit from the semaphore
+        let Some(permit) = Arc::clone(&self.permits).try_acquire() else {
+            self.report(&senders);
+            return None
+        };
 
         // Call the subscribe function with the tx_id, senders, and permit
-        subscribe::<_, C>(tx_id, &mut (*senders), permit)
+        let stream = subscribe::<_, C>(tx_id, &mut senders, permit, subscriber);
+        self.report(&senders);
+        Some(Box::pin(ReleaseOnDrop {
+            stream: Some(stream),
+            tx_id,
+            update_sender: self.clone(),
+        }))
+    }
+
+    /// Removes the senders of the `tx_id` with dropped receivers,
+    /// freeing their permits.
+    fn release_dropped(&self, tx_id: &Bytes32) {
+        let mut senders = self.senders.lock();
+        if let Some(list) = senders.get_mut(tx_id) {
+            list.retain(|sender| !sender.tx.is_closed());
+            if list.is_empty() {
+                senders.remove(tx_id);
+            }
+        }
+        self.report(&senders);
     }
 
     /// Send updates to all subscribed senders.
@@ -257,7 +327,7 @@ impl UpdateSender {
         let mut senders = self.senders.lock();
 
         // Remove closed senders from the list
-        remove_closed_and_expired(&mut senders, self.ttl);
+        remove_closed_and_expired(&mut senders, self.ttl, self.limits.idle_timeout);
 
         // Initialize a flag to check if there are no senders
         // left for a given tx_id.
@@ -265,8 +335,11 @@ impl UpdateSender {
 
         if let Some(senders) = senders.get_mut(update.tx_id()) {
             // Retain only senders that are able to receive the update.
-            senders
-                .retain_mut(|sender| sender.try_send(update.clone().into_msg()).is_ok());
+            let now = Instant::now();
+            senders.retain_mut(|sender| {
+                sender.last_activity = now;
+                sender.try_send(update.clone().into_msg()).is_ok()
+            });
 
             // Check if the list of senders for the tx_id is empty.
             empty = senders.is_empty();
@@ -276,6 +349,17 @@ impl UpdateSender {
         if empty {
             senders.remove(update.tx_id());
         }
+        self.report(&senders);
+    }
+
+    /// Report the number of active senders to metrics.
+    fn report<P, Tx>(&self, senders: &SenderMap<P, Tx>) {
+        if self.metrics {
+            let active = senders.values().map(Vec::len).sum::<usize>();
+            txpool_metrics()
+                .active_subscriptions
+                .set(i64::try_from(active).unwrap_or(i64::MAX));
+        }
     }
 }
 
@@ -284,6 +368,7 @@ fn subscribe<P, C>(
     tx_id: Bytes32,                 // transaction ID
     senders: &mut SenderMap<P, Tx>, // mutable senders map reference
     permit: P,                      // permit of type P
+    subscriber: Option<Subscriber>, // owner of the sender
 ) -> TxStatusStream
 where
     C: CreateChannel,
@@ -293,30 +378,113 @@ where
 
     // Insert a new vec into the senders map if not exists,
     // and then push the sender to the vec.
+    let now = Instant::now();
     senders.entry(tx_id).or_default().push(Sender {
         _permit: permit,
         stream: TxUpdateStream::new(),
         tx,
-        created: Instant::now(),
+        created: now,
+        subscriber,
+        last_activity: now,
     });
 
     // Return the receiver part of the channel
     rx
 }
 
-// Remove closed senders from the senders map
-fn remove_closed_and_expired<P, Tx>(senders: &mut SenderMap<P, Tx>, ttl: Duration)
-where
+// Remove closed, expired and idle senders from the senders map
+fn remove_closed_and_expired<P, Tx>(
+    senders: &mut SenderMap<P, Tx>,
+    ttl: Duration,
+    idle_timeout: Duration,
+) where
     Tx: SendStatus,
 {
     // Iterate over the senders map, retaining only the senders that are not closed
     senders.retain(|_, senders| {
-        senders.retain(|sender| !sender.is_closed() && sender.created.elapsed() < ttl);
+        senders.retain(|sender| {
+            !sender.is_closed()
+                && sender.created.elapsed() < ttl
+                && sender.last_activity.elapsed() < idle_timeout
+        });
         // Continue retaining if the senders list is not empty
         !senders.is_empty()
     });
 }
 
+// Count the senders of the subscriber
+fn count_of<P, Tx>(senders: &SenderMap<P, Tx>, subscriber: &Option<Subscriber>) -> usize {
+    senders
+        .values()
+        .flatten()
+        .filter(|sender| &sender.subscriber == subscriber)
+        .count()
+}
+
+// Remove the least recently updated sender of the subscriber,
+// notifying it about the failure. Returns `true` if a sender was removed.
+fn evict_least_recent<P, Tx>(
+    senders: &mut SenderMap<P, Tx>,
+    subscriber: &Option<Subscriber>,
+) -> bool
+where
+    Tx: SendStatus,
+{
+    let least_recent = senders
+        .iter()
+        .flat_map(|(tx_id, senders)| {
+            senders
+                .iter()
+                .enumerate()
+                .filter(|(_, sender)| &sender.subscriber == subscriber)
+                .map(move |(i, sender)| (sender.last_activity, *tx_id, i))
+        })
+        .min_by_key(|(last_activity, _, _)| *last_activity);
+
+    let Some((_, tx_id, i)) = least_recent else {
+        return false
+    };
+    if let Some(list) = senders.get_mut(&tx_id) {
+        // Dropping the sender closes the channel and frees the permit.
+        let mut sender = list.remove(i);
+        let _ = sender.tx.try_send(TxStatusMessage::FailedStatus);
+        if list.is_empty() {
+            senders.remove(&tx_id);
+        }
+    }
+    true
+}
+
+/// The stream of the subscriber that removes its sender when dropped,
+/// so the permit and the metrics are updated without waiting for the next update.
+struct ReleaseOnDrop {
+    stream: Option<TxStatusStream>,
+    tx_id: Bytes32,
+    update_sender: UpdateSender,
+}
+
+impl Stream for ReleaseOnDrop {
+    type Item = TxStatusMessage;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        match self.stream.as_mut() {
+            Some(stream) => stream.as_mut().poll_next(cx),
+            None => Poll::Ready(None),
+        }
+    }
+}
+
+impl Drop for ReleaseOnDrop {
+    fn drop(&mut self) {
+        // Drop the receiver first to close the channel of the sender.
+        drop(self.stream.take());
+        self.update_sender.release_dropped(&self.tx_id);
+    }
+}
+
 impl<T> SendStatus for Box<T>
 where
     T: SendStatus + ?Sized,
@@ -340,6 +508,8 @@ impl Clone for UpdateSender {
             senders: self.senders.clone(),
             permits: self.permits.clone(),
             ttl: self.ttl,
+            limits: self.limits,
+            metrics: self.metrics,
         }
     }
 }
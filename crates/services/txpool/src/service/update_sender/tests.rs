@@ -11,9 +11,11 @@ use super::{
     tx_status_stream::State,
     *,
 };
+use fuel_core_types::services::txpool::TxOrigin;
 use proptest::prelude::*;
 
 mod test_e2e;
+mod test_limits;
 mod test_permits;
 mod test_sending;
 mod test_subscribe;
@@ -35,8 +37,8 @@ fn validate_tx_update_stream_state(state: State, transition: StateTransitions) -
     match (state, transition) {
         (
             Empty,
-            AddMsg(TxStatusMessage::Status(TransactionStatus::Submitted { time })),
-        ) => Initial(TransactionStatus::Submitted { time }),
+            AddMsg(TxStatusMessage::Status(status @ TransactionStatus::Submitted { .. })),
+        ) => Initial(status),
         // If not Submitted, it's an early success.
         (Empty, AddMsg(TxStatusMessage::Status(s))) => EarlySuccess(s),
         (Empty, AddMsg(TxStatusMessage::FailedStatus)) => Failed,
@@ -50,7 +50,13 @@ fn test_update_sender_reg() {
             }),
         ),
         Recv(0),
-        Send(0, Status(Submitted { time: Tai64(0) })),
+        Send(
+            0,
+            Status(Submitted {
+                time: Tai64(0),
+                origin: TxOrigin::Injected,
+            }),
+        ),
         Recv(0),
     ];
     test_update_sender_inner(ops);
@@ -148,7 +154,14 @@ fn test_update_sender_inner(ops: Vec<Op>) {
                 }
                 // Model
                 if i < model_receivers.len() {
-                    model_receivers.remove(i);
+                    let (id, sender_id, _) = model_receivers.remove(i);
+                    // Dropping the receiver removes its sender.
+                    if let Some(senders) = model.get_mut(&id) {
+                        senders.remove(&sender_id);
+                        if senders.is_empty() {
+                            model.remove(&id);
+                        }
+                    }
                 }
             }
         }
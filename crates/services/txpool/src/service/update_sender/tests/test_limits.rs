@@ -0,0 +1,127 @@
+use super::*;
+use crate::config::SubscriptionLimits;
+use tokio_stream::StreamExt;
+
+fn submitted(id: u8) -> TxUpdate {
+    TxUpdate {
+        tx_id: Bytes32::from([id; 32]),
+        message: TxStatusMessage::Status(TransactionStatus::Submitted {
+            time: Tai64(0),
+            origin: TxOrigin::Injected,
+        }),
+    }
+}
+
+#[tokio::test]
+async fn subscriber_at_limit_drops_least_recently_updated_subscription() {
+    let update =
+        UpdateSender::new(10, Duration::from_secs(100)).with_limits(SubscriptionLimits {
+            max_per_client: 2,
+            idle_timeout: Duration::from_secs(100),
+        });
+    let client = Some("client".to_string());
+
+    let mut first = update
+        .try_subscribe_as::<MpscChannel>(Bytes32::from([1; 32]), client.clone())
+        .unwrap();
+    let mut second = update
+        .try_subscribe_as::<MpscChannel>(Bytes32::from([2; 32]), client.clone())
+        .unwrap();
+    let _other = update
+        .try_subscribe_as::<MpscChannel>(Bytes32::from([3; 32]), Some("other".into()))
+        .unwrap();
+    update.send(submitted(1));
+
+    let _third = update
+        .try_subscribe_as::<MpscChannel>(Bytes32::from([4; 32]), client)
+        .unwrap();
+
+    // The first subscription was updated more recently, so the second one is dropped.
+    assert!(first.next().await.is_some());
+    assert!(matches!(
+        second.next().await,
+        Some(TxStatusMessage::FailedStatus)
+    ));
+    assert!(second.next().await.is_none());
+    assert_eq!(update.senders.lock().values().flatten().count(), 3);
+}
+
+#[tokio::test]
+async fn subscriber_without_limit_is_rejected() {
+    let update =
+        UpdateSender::new(10, Duration::from_secs(100)).with_limits(SubscriptionLimits {
+            max_per_client: 0,
+            idle_timeout: Duration::from_secs(100),
+        });
+
+    let result =
+        update.try_subscribe_as::<MpscChannel>(Bytes32::zeroed(), Some("client".into()));
+
+    assert!(result.is_none());
+    assert!(update
+        .try_subscribe::<MpscChannel>(Bytes32::zeroed())
+        .is_none());
+}
+
+#[tokio::test]
+async fn anonymous_subscriptions_share_the_limit() {
+    let update =
+        UpdateSender::new(10, Duration::from_secs(100)).with_limits(SubscriptionLimits {
+            max_per_client: 1,
+            idle_timeout: Duration::from_secs(100),
+        });
+
+    let mut first = update
+        .try_subscribe::<MpscChannel>(Bytes32::zeroed())
+        .unwrap();
+    let _second = update
+        .try_subscribe::<MpscChannel>(Bytes32::zeroed())
+        .unwrap();
+
+    assert!(matches!(
+        first.next().await,
+        Some(TxStatusMessage::FailedStatus)
+    ));
+    assert_eq!(update.senders.lock().values().flatten().count(), 1);
+}
+
+#[tokio::test]
+async fn dropped_subscription_frees_its_permit() {
+    let update = UpdateSender::new(1, Duration::from_secs(100));
+    let subscription = update
+        .try_subscribe::<MpscChannel>(Bytes32::zeroed())
+        .unwrap();
+    assert!(update
+        .try_subscribe::<MpscChannel>(Bytes32::zeroed())
+        .is_none());
+
+    drop(subscription);
+
+    assert!(update.senders.lock().is_empty());
+    assert!(update
+        .try_subscribe::<MpscChannel>(Bytes32::zeroed())
+        .is_some());
+}
+
+#[tokio::test(start_paused = true)]
+async fn idle_subscriptions_are_dropped() {
+    let update =
+        UpdateSender::new(1, Duration::from_secs(100)).with_limits(SubscriptionLimits {
+            max_per_client: 10,
+            idle_timeout: Duration::from_secs(10),
+        });
+    let mut idle = update
+        .try_subscribe_as::<MpscChannel>(Bytes32::zeroed(), Some("client".into()))
+        .unwrap();
+    assert!(update
+        .try_subscribe::<MpscChannel>(Bytes32::zeroed())
+        .is_none());
+
+    tokio::time::advance(Duration::from_secs(11)).await;
+
+    // The idle subscription frees its permit for the new one.
+    assert!(update
+        .try_subscribe::<MpscChannel>(Bytes32::zeroed())
+        .is_some());
+    assert!(idle.next().await.is_none());
+}
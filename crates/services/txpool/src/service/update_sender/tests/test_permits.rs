@@ -1,6 +1,9 @@
-use crate::service::update_sender::tests::utils::{
-    box_senders,
-    MockCreateChannel,
+use crate::{
+    config::SubscriptionLimits,
+    service::update_sender::tests::utils::{
+        box_senders,
+        MockCreateChannel,
+    },
 };
 
 use super::{
@@ -69,6 +72,8 @@ fn test_try_subscribe_inner(senders: HashMap<Bytes32, Vec<Sender<(), MockSendSta
                         stream: v.stream,
                         tx: v.tx,
                         created: Instant::now(),
+                        subscriber: None,
+                        last_activity: Instant::now(),
                     })
                     .collect(),
             )
@@ -96,6 +101,11 @@ fn test_try_subscribe_inner(senders: HashMap<Bytes32, Vec<Sender<(), MockSendSta
         senders: Arc::new(Mutex::new(box_senders(senders))),
         permits: Arc::new(permits),
         ttl: Duration::from_secs(100),
+        limits: SubscriptionLimits {
+            max_per_client: usize::MAX,
+            idle_timeout: Duration::MAX,
+        },
+        metrics: false,
     };
 
     // Test the try_subscribe function on the UpdateSender
@@ -71,10 +71,19 @@ fn test_send_reg() {
             2,
             &[
                 SenderData::closed(Success(
-                    TransactionStatus::Submitted { time: Tai64(0) },
-                    TransactionStatus::Submitted { time: Tai64(0) },
+                    TransactionStatus::Submitted {
+                        time: Tai64(0),
+                        origin: TxOrigin::Injected,
+                    },
+                    TransactionStatus::Submitted {
+                        time: Tai64(0),
+                        origin: TxOrigin::Injected,
+                    },
                 )),
-                SenderData::ok(Initial(TransactionStatus::Submitted { time: Tai64(0) })),
+                SenderData::ok(Initial(TransactionStatus::Submitted {
+                    time: Tai64(0),
+                    origin: TxOrigin::Injected,
+                })),
             ],
         )]),
     );
@@ -118,6 +127,8 @@ fn test_send_inner(
         senders: Arc::new(Mutex::new(box_senders(senders))),
         permits: Arc::new(()),
         ttl: Duration::from_secs(5),
+        limits: Default::default(),
+        metrics: false,
     };
     update.send(msg.clone());
 
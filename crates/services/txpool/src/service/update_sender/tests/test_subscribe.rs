@@ -27,6 +27,7 @@ fn test_subscriber(input: Input) {
         Bytes32::from([tx_id; 32]),
         &mut senders,
         Box::new(()),
+        None,
     );
     let len_after = senders.values().map(|v| v.len()).sum::<usize>();
     assert_eq!(len_before + 1, len_after);
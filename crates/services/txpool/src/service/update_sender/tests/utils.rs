@@ -2,7 +2,10 @@ use super::*;
 
 pub fn transaction_status_strategy() -> impl Strategy<Value = TransactionStatus> {
     prop_oneof![
-        Just(TransactionStatus::Submitted { time: Tai64(0) }),
+        Just(TransactionStatus::Submitted {
+            time: Tai64(0),
+            origin: TxOrigin::Injected,
+        }),
         Just(TransactionStatus::Success {
             block_id: Default::default(),
             time: Tai64(0),
@@ -87,6 +90,8 @@ pub(super) fn senders_strategy(
             _permit: (),
             tx,
             created: Instant::now(),
+            subscriber: None,
+            last_activity: Instant::now(),
         }
     });
     prop::collection::hash_map(
@@ -169,6 +174,8 @@ pub(super) fn construct_senders(
                 _permit: (),
                 tx,
                 created: Instant::now(),
+                subscriber: None,
+                last_activity: Instant::now(),
             });
         }
         senders.insert(Bytes32::from([*i; 32]), v);
@@ -215,6 +222,8 @@ pub(super) fn box_senders<
                         stream: s.stream,
                         tx: Tx::from(Box::new(s.tx)),
                         created: s.created,
+                        subscriber: s.subscriber,
+                        last_activity: s.last_activity,
                     })
                     .collect::<Vec<_>>(),
             )
@@ -39,8 +39,8 @@ impl TxUpdateStream {
         let state = std::mem::replace(&mut self.state, State::Empty);
         self.state = match state {
             State::Empty => match msg {
-                TxStatusMessage::Status(TransactionStatus::Submitted { time }) => {
-                    State::Initial(TransactionStatus::Submitted { time })
+                TxStatusMessage::Status(status @ TransactionStatus::Submitted { .. }) => {
+                    State::Initial(status)
                 }
                 TxStatusMessage::Status(s) => State::EarlySuccess(s),
                 TxStatusMessage::FailedStatus => State::Failed,
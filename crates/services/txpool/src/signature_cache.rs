@@ -0,0 +1,272 @@
+//! The short-lived cache of transactions with verified signatures. The same transaction
+//! often arrives several times within a short period: the user retries the submission
+//! via the API, and several peers gossip it. The cache allows skipping the repeated
+//! recovery of signatures of such duplicates.
+
+use fuel_core_types::{
+    fuel_tx::{
+        Transaction,
+        TxId,
+    },
+    fuel_types::canonical::Serialize,
+    fuel_vm::checked_transaction::{
+        Checked,
+        Checks,
+    },
+};
+use std::{
+    collections::{
+        HashMap,
+        VecDeque,
+    },
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+/// The cache stores transactions with verified signatures for the `ttl` period.
+/// It is bounded by the total serialized size of cached transactions,
+/// and the oldest transactions are evicted first.
+#[derive(Debug)]
+pub struct SignatureCache {
+    max_size: usize,
+    size: usize,
+    ttl: Duration,
+    transactions: HashMap<TxId, CachedTransaction>,
+    /// The ids of cached transactions, from the oldest to the newest.
+    order: VecDeque<TxId>,
+}
+
+#[derive(Debug)]
+struct CachedTransaction {
+    tx: Checked<Transaction>,
+    size: usize,
+    verified_at: Instant,
+}
+
+impl SignatureCache {
+    pub fn new(max_size: usize, ttl: Duration) -> Self {
+        Self {
+            max_size,
+            size: 0,
+            ttl,
+            transactions: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    /// Returns the transaction with verified signatures equal to the `tx`.
+    pub fn get(&mut self, tx: &Checked<Transaction>) -> Option<Checked<Transaction>> {
+        self.get_at(tx, Instant::now())
+    }
+
+    /// Caches the transaction with verified signatures.
+    pub fn insert(&mut self, tx: &Checked<Transaction>) {
+        self.insert_at(tx, Instant::now())
+    }
+
+    fn get_at(
+        &mut self,
+        tx: &Checked<Transaction>,
+        now: Instant,
+    ) -> Option<Checked<Transaction>> {
+        self.remove_expired(now);
+        let cached = self.transactions.get(&tx.id())?;
+        // The id doesn't commit to witnesses, so the signatures of the transaction
+        // with the same id can differ. The metadata depends on the consensus parameters
+        // used by the basic checks, so it should match too.
+        (cached.tx.transaction() == tx.transaction()
+            && cached.tx.metadata() == tx.metadata())
+        .then(|| cached.tx.clone())
+    }
+
+    fn insert_at(&mut self, tx: &Checked<Transaction>, now: Instant) {
+        if !tx.checks().contains(Checks::Signatures) {
+            return
+        }
+        let size = tx.transaction().size();
+        if size > self.max_size {
+            return
+        }
+        self.remove_expired(now);
+
+        let id = tx.id();
+        if let Some(removed) = self.transactions.remove(&id) {
+            self.size = self.size.saturating_sub(removed.size);
+            self.order.retain(|cached| cached != &id);
+        }
+        while self.size.saturating_add(size) > self.max_size {
+            let Some(oldest) = self.order.pop_front() else {
+                break
+            };
+            self.remove_entry(&oldest);
+        }
+
+        self.size = self.size.saturating_add(size);
+        self.order.push_back(id);
+        self.transactions.insert(
+            id,
+            CachedTransaction {
+                tx: tx.clone(),
+                size,
+                verified_at: now,
+            },
+        );
+    }
+
+    fn remove_entry(&mut self, id: &TxId) {
+        if let Some(removed) = self.transactions.remove(id) {
+            self.size = self.size.saturating_sub(removed.size);
+        }
+    }
+
+    fn remove_expired(&mut self, now: Instant) {
+        while let Some(oldest) = self.order.front() {
+            let expired = self
+                .transactions
+                .get(oldest)
+                .map(|cached| {
+                    now.saturating_duration_since(cached.verified_at) >= self.ttl
+                })
+                .unwrap_or(true);
+            if !expired {
+                break
+            }
+            if let Some(oldest) = self.order.pop_front() {
+                self.remove_entry(&oldest);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::{
+        fuel_crypto::{
+            rand::{
+                rngs::StdRng,
+                SeedableRng,
+            },
+            SecretKey,
+        },
+        fuel_tx::{
+            field::Witnesses,
+            AssetId,
+            ConsensusParameters,
+            TransactionBuilder,
+            Witness,
+        },
+        fuel_vm::checked_transaction::IntoChecked,
+    };
+
+    const TTL: Duration = Duration::from_secs(10);
+
+    fn after(now: Instant, secs: u64) -> Instant {
+        now.checked_add(Duration::from_secs(secs)).unwrap()
+    }
+
+    fn signed_tx(seed: u64) -> Checked<Transaction> {
+        let rng = &mut StdRng::seed_from_u64(seed);
+        let params = ConsensusParameters::standard();
+        TransactionBuilder::script(vec![], vec![])
+            .add_unsigned_coin_input(
+                SecretKey::random(rng),
+                Default::default(),
+                10,
+                AssetId::BASE,
+                Default::default(),
+                Default::default(),
+            )
+            .finalize_as_transaction()
+            .into_checked_basic(Default::default(), &params)
+            .unwrap()
+            .check_signatures(&params.chain_id)
+            .unwrap()
+    }
+
+    #[test]
+    fn cached_transaction_is_returned_within_ttl() {
+        let mut cache = SignatureCache::new(usize::MAX, TTL);
+        let tx = signed_tx(1);
+        let now = Instant::now();
+
+        cache.insert_at(&tx, now);
+
+        assert_eq!(cache.get_at(&tx, after(now, 5)), Some(tx));
+    }
+
+    #[test]
+    fn cached_transaction_expires_after_ttl() {
+        let mut cache = SignatureCache::new(usize::MAX, TTL);
+        let tx = signed_tx(1);
+        let now = Instant::now();
+
+        cache.insert_at(&tx, now);
+
+        assert_eq!(cache.get_at(&tx, after(now, TTL.as_secs())), None);
+        assert!(cache.transactions.is_empty());
+        assert!(cache.order.is_empty());
+        assert_eq!(cache.size, 0);
+    }
+
+    #[test]
+    fn transaction_with_different_witnesses_is_not_returned() {
+        let mut cache = SignatureCache::new(usize::MAX, TTL);
+        let tx = signed_tx(1);
+        let now = Instant::now();
+        cache.insert_at(&tx, now);
+
+        let (mut other, _) = tx.into();
+        if let Transaction::Script(script) = &mut other {
+            script.witnesses_mut()[0] = Witness::from(vec![1; 64]);
+        }
+        let other = other
+            .into_checked_basic(Default::default(), &ConsensusParameters::standard())
+            .unwrap();
+
+        assert_eq!(cache.get_at(&other, now), None);
+    }
+
+    #[test]
+    fn oldest_transactions_are_evicted_when_size_is_exceeded() {
+        let first = signed_tx(1);
+        let second = signed_tx(2);
+        let mut cache = SignatureCache::new(first.transaction().size(), TTL);
+        let now = Instant::now();
+
+        cache.insert_at(&first, now);
+        cache.insert_at(&second, now);
+
+        assert_eq!(cache.get_at(&first, now), None);
+        assert_eq!(cache.get_at(&second, now), Some(second));
+    }
+
+    #[test]
+    fn transaction_larger_than_cache_is_not_cached() {
+        let tx = signed_tx(1);
+        let mut cache =
+            SignatureCache::new(tx.transaction().size().saturating_sub(1), TTL);
+        let now = Instant::now();
+
+        cache.insert_at(&tx, now);
+
+        assert_eq!(cache.get_at(&tx, now), None);
+        assert_eq!(cache.size, 0);
+    }
+
+    #[test]
+    fn transactions_without_verified_signatures_are_not_cached() {
+        let mut cache = SignatureCache::new(usize::MAX, TTL);
+        let (tx, _) = signed_tx(1).into();
+        let tx = tx
+            .into_checked_basic(Default::default(), &ConsensusParameters::standard())
+            .unwrap();
+        let now = Instant::now();
+
+        cache.insert_at(&tx, now);
+
+        assert_eq!(cache.get_at(&tx, now), None);
+    }
+}
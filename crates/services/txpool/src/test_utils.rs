@@ -0,0 +1,751 @@
+//! The deterministic harness driving the real TxPool service. It allows downstream
+//! crates and fuzzers to script events of the network and the block importer,
+//! control the clock, and check invariants of the pool after each event.
+//!
+//! The harness relies on the paused clock of the Tokio runtime, so tests should
+//! run on the current thread runtime with `#[tokio::test(start_paused = true)]`.
+//! The clock only moves by [`TestHarness::advance`] and the scheduled offsets of events.
+
+use crate::{
+    admin::TxPoolDump,
+    mock_db::MockDBProvider,
+    new_service,
+    ports::{
+        BlockImporter,
+        PeerToPeer,
+    },
+    predicate_cache::PredicateCache,
+    service::SharedState,
+    Config,
+    MockDb,
+    Service,
+};
+use fuel_core_services::{
+    stream::BoxStream,
+    Service as ServiceTrait,
+};
+use fuel_core_types::{
+    blockchain::{
+        block::Block,
+        header::PartialBlockHeader,
+        SealedBlock,
+    },
+    entities::coins::coin::Coin,
+    fuel_crypto::rand::{
+        rngs::StdRng,
+        SeedableRng,
+    },
+    fuel_tx::{
+        field::Inputs,
+        Input,
+        Transaction,
+        TransactionBuilder,
+        TxId,
+        UniqueIdentifier,
+        UtxoId,
+        Word,
+    },
+    fuel_types::{
+        BlockHeight,
+        Nonce,
+    },
+    services::{
+        block_importer::{
+            ImportResult,
+            SharedImportResult,
+        },
+        executor::{
+            TransactionExecutionResult,
+            TransactionExecutionStatus,
+        },
+        p2p::{
+            GossipData,
+            GossipsubMessageAcceptance,
+            GossipsubMessageInfo,
+            PeerId,
+            ShortTxId,
+            TransactionGossipData,
+        },
+        port::PortResult,
+        txpool::{
+            InsertionResult,
+            TxOrigin,
+        },
+    },
+};
+use parking_lot::Mutex;
+use std::{
+    collections::HashMap,
+    future::Future,
+    sync::Arc,
+    time::Duration,
+};
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::UnboundedReceiverStream;
+
+/// The time after which the harness gives up waiting for the service to process an event.
+const PROCESSING_TIMEOUT: Duration = Duration::from_secs(10);
+
+type Receiver<T> = Mutex<Option<mpsc::UnboundedReceiver<T>>>;
+
+fn take_stream<T: Send + Sync + 'static>(receiver: &Receiver<T>) -> BoxStream<T> {
+    match receiver.lock().take() {
+        Some(receiver) => Box::pin(UnboundedReceiverStream::new(receiver)),
+        None => Box::pin(fuel_core_services::stream::pending()),
+    }
+}
+
+struct ScriptedP2PInner {
+    gossip_sender: mpsc::UnboundedSender<TransactionGossipData>,
+    gossip_receiver: Receiver<TransactionGossipData>,
+    peers_sender: mpsc::UnboundedSender<PeerId>,
+    peers_receiver: Receiver<PeerId>,
+    peer_pools: Mutex<HashMap<PeerId, Vec<Transaction>>>,
+    broadcasts: Mutex<Vec<Arc<Transaction>>>,
+    reports: Mutex<Vec<(GossipsubMessageInfo, GossipsubMessageAcceptance)>>,
+}
+
+/// The P2P network controlled by the test. It records broadcast transactions
+/// and validity reports of gossiped transactions.
+#[derive(Clone)]
+pub struct ScriptedP2P {
+    inner: Arc<ScriptedP2PInner>,
+}
+
+impl Default for ScriptedP2P {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ScriptedP2P {
+    pub fn new() -> Self {
+        let (gossip_sender, gossip_receiver) = mpsc::unbounded_channel();
+        let (peers_sender, peers_receiver) = mpsc::unbounded_channel();
+        Self {
+            inner: Arc::new(ScriptedP2PInner {
+                gossip_sender,
+                gossip_receiver: Mutex::new(Some(gossip_receiver)),
+                peers_sender,
+                peers_receiver: Mutex::new(Some(peers_receiver)),
+                peer_pools: Default::default(),
+                broadcasts: Default::default(),
+                reports: Default::default(),
+            }),
+        }
+    }
+
+    /// Gossips the `tx` from the `peer_id` with the `message_id`.
+    pub fn gossip(&self, tx: Transaction, peer_id: PeerId, message_id: Vec<u8>) {
+        let gossip = GossipData {
+            data: Some(tx),
+            peer_id,
+            message_id,
+        };
+        let _ = self.inner.gossip_sender.send(gossip);
+    }
+
+    /// Connects the peer with the `pool` of transactions to the node.
+    pub fn connect_peer(&self, peer_id: PeerId, pool: Vec<Transaction>) {
+        self.inner.peer_pools.lock().insert(peer_id.clone(), pool);
+        let _ = self.inner.peers_sender.send(peer_id);
+    }
+
+    /// Transactions broadcast by the service in the order of broadcasting.
+    pub fn broadcasts(&self) -> Vec<Arc<Transaction>> {
+        self.inner.broadcasts.lock().clone()
+    }
+
+    /// Validity reports of gossiped transactions in the order of reporting.
+    pub fn reports(&self) -> Vec<(GossipsubMessageInfo, GossipsubMessageAcceptance)> {
+        self.inner.reports.lock().clone()
+    }
+
+    fn report_for(&self, message_id: &[u8]) -> Option<GossipsubMessageAcceptance> {
+        self.inner
+            .reports
+            .lock()
+            .iter()
+            .find(|(info, _)| info.message_id == message_id)
+            .map(|(_, acceptance)| *acceptance)
+    }
+}
+
+#[async_trait::async_trait]
+impl PeerToPeer for ScriptedP2P {
+    type GossipedTransaction = TransactionGossipData;
+
+    fn broadcast_transaction(&self, transaction: Arc<Transaction>) -> PortResult<()> {
+        self.inner.broadcasts.lock().push(transaction);
+        Ok(())
+    }
+
+    fn gossiped_transaction_events(&self) -> BoxStream<Self::GossipedTransaction> {
+        take_stream(&self.inner.gossip_receiver)
+    }
+
+    fn notify_gossip_transaction_validity(
+        &self,
+        message_info: GossipsubMessageInfo,
+        validity: GossipsubMessageAcceptance,
+    ) -> PortResult<()> {
+        self.inner.reports.lock().push((message_info, validity));
+        Ok(())
+    }
+
+    fn new_peers_events(&self) -> BoxStream<PeerId> {
+        take_stream(&self.inner.peers_receiver)
+    }
+
+    async fn request_tx_pool_summary(
+        &self,
+        peer_id: PeerId,
+    ) -> PortResult<Option<Vec<ShortTxId>>> {
+        let pools = self.inner.peer_pools.lock();
+        Ok(pools.get(&peer_id).map(|pool| {
+            pool.iter()
+                .map(|tx| ShortTxId::from(&tx.id(&Default::default())))
+                .collect()
+        }))
+    }
+
+    async fn request_pooled_transactions(
+        &self,
+        peer_id: PeerId,
+        tx_ids: Vec<ShortTxId>,
+    ) -> PortResult<Option<Vec<Transaction>>> {
+        let pools = self.inner.peer_pools.lock();
+        Ok(pools.get(&peer_id).map(|pool| {
+            pool.iter()
+                .filter(|tx| {
+                    tx_ids.contains(&ShortTxId::from(&tx.id(&Default::default())))
+                })
+                .cloned()
+                .collect()
+        }))
+    }
+}
+
+/// The block importer controlled by the test.
+#[derive(Clone)]
+pub struct ScriptedImporter {
+    sender: mpsc::UnboundedSender<SharedImportResult>,
+    receiver: Arc<Receiver<SharedImportResult>>,
+}
+
+impl Default for ScriptedImporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ScriptedImporter {
+    pub fn new() -> Self {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        Self {
+            sender,
+            receiver: Arc::new(Mutex::new(Some(receiver))),
+        }
+    }
+
+    /// Notifies the service about the imported block.
+    pub fn import(&self, result: SharedImportResult) {
+        let _ = self.sender.send(result);
+    }
+}
+
+impl BlockImporter for ScriptedImporter {
+    fn block_events(&self) -> BoxStream<SharedImportResult> {
+        take_stream(&self.receiver)
+    }
+}
+
+/// The event applied to the service by the [`Schedule`].
+#[derive(Debug, Clone)]
+pub enum Event {
+    /// The peer gossips the transaction.
+    Gossip(Transaction),
+    /// The user submits transactions via the API.
+    Submit(Vec<Transaction>),
+    /// The importer commits the next block with transactions.
+    /// Inputs of transactions are spent in the database.
+    CommitBlock(Vec<Transaction>),
+}
+
+/// Events ordered by their offsets from the start of the harness.
+/// Events with the same offset are applied in the order of scheduling.
+#[derive(Debug, Clone, Default)]
+pub struct Schedule {
+    events: Vec<(Duration, Event)>,
+}
+
+impl Schedule {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Schedules the `event` at the `offset` from the start of the harness.
+    pub fn at(mut self, offset: Duration, event: Event) -> Self {
+        self.events.push((offset, event));
+        self
+    }
+
+    pub fn events(&self) -> &[(Duration, Event)] {
+        &self.events
+    }
+}
+
+/// The input spent by transactions of the pool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SpentInput {
+    Coin(UtxoId),
+    Message(Nonce),
+}
+
+/// The violated invariant of the pool.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InvariantViolation {
+    /// The pool has more transactions than the `Config::max_tx`.
+    TooManyTransactions { count: usize, max: usize },
+    /// Two transactions of the pool spend the same input.
+    DoubleSpend {
+        input: SpentInput,
+        first: TxId,
+        second: TxId,
+    },
+    /// The transaction of the pool has the gas price below the `Config::min_gas_price`.
+    GasPriceBelowMinimum { id: TxId, gas_price: Word },
+    /// The number of pending transactions doesn't match the number of sorted transactions.
+    InconsistentIndexes { pending: usize, sorted: usize },
+}
+
+/// Checks invariants of the pool represented by the `dump` with `pending` transactions.
+pub fn check_invariants(
+    config: &Config,
+    dump: &TxPoolDump,
+    pending: usize,
+) -> Result<(), InvariantViolation> {
+    let sorted = dump.transactions.len();
+    if pending != sorted {
+        return Err(InvariantViolation::InconsistentIndexes { pending, sorted })
+    }
+    if sorted > config.max_tx {
+        return Err(InvariantViolation::TooManyTransactions {
+            count: sorted,
+            max: config.max_tx,
+        })
+    }
+
+    let mut spent = HashMap::new();
+    for dumped in &dump.transactions {
+        if dumped.gas_price < config.min_gas_price {
+            return Err(InvariantViolation::GasPriceBelowMinimum {
+                id: dumped.id,
+                gas_price: dumped.gas_price,
+            })
+        }
+
+        let inputs = match &dumped.transaction {
+            Transaction::Script(tx) => tx.inputs().as_slice(),
+            Transaction::Create(tx) => tx.inputs().as_slice(),
+            Transaction::Mint(_) => &[],
+        };
+        for input in inputs {
+            let input = match (input.utxo_id(), input.nonce()) {
+                (Some(utxo_id), _) if !input.is_contract() => SpentInput::Coin(*utxo_id),
+                (_, Some(nonce)) => SpentInput::Message(*nonce),
+                _ => continue,
+            };
+            if let Some(first) = spent.insert(input, dumped.id) {
+                return Err(InvariantViolation::DoubleSpend {
+                    input,
+                    first,
+                    second: dumped.id,
+                })
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Builds the [`TestHarness`].
+pub struct TestHarnessBuilder {
+    config: Config,
+    seed: u64,
+}
+
+impl Default for TestHarnessBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TestHarnessBuilder {
+    pub fn new() -> Self {
+        Self {
+            config: Config::default(),
+            seed: 0,
+        }
+    }
+
+    pub fn with_config(mut self, config: Config) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// The seed of the generator of coins and transactions.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    pub async fn build_and_start(self) -> TestHarness {
+        let mock_db = MockDb::default();
+        let p2p = ScriptedP2P::new();
+        let importer = ScriptedImporter::new();
+        let service = new_service(
+            self.config.clone(),
+            MockDBProvider(mock_db.clone()),
+            importer.clone(),
+            p2p.clone(),
+            Default::default(),
+            PredicateCache::shared(self.config.predicate_cache_size),
+            Default::default(),
+        );
+        service
+            .start_and_await()
+            .await
+            .expect("The TxPool service should start");
+
+        TestHarness {
+            service,
+            config: self.config,
+            mock_db,
+            p2p,
+            importer,
+            rng: StdRng::seed_from_u64(self.seed),
+            start: tokio::time::Instant::now(),
+            height: BlockHeight::default(),
+            next_message: 0,
+        }
+    }
+}
+
+/// The running TxPool service with scripted dependencies.
+pub struct TestHarness {
+    service: Service<ScriptedP2P, MockDBProvider>,
+    config: Config,
+    mock_db: MockDb,
+    p2p: ScriptedP2P,
+    importer: ScriptedImporter,
+    rng: StdRng,
+    start: tokio::time::Instant,
+    height: BlockHeight,
+    next_message: u64,
+}
+
+impl TestHarness {
+    pub fn service(&self) -> &Service<ScriptedP2P, MockDBProvider> {
+        &self.service
+    }
+
+    pub fn shared(&self) -> &SharedState<ScriptedP2P, MockDBProvider> {
+        &self.service.shared
+    }
+
+    pub fn database(&self) -> &MockDb {
+        &self.mock_db
+    }
+
+    pub fn p2p(&self) -> &ScriptedP2P {
+        &self.p2p
+    }
+
+    /// The height of the latest committed block.
+    pub fn height(&self) -> BlockHeight {
+        self.height
+    }
+
+    /// The time elapsed on the clock of the harness since the start.
+    pub fn elapsed(&self) -> Duration {
+        self.start.elapsed()
+    }
+
+    /// Adds the new coin to the database and returns the input spending it.
+    pub fn setup_coin(&mut self) -> (Coin, Input) {
+        crate::test_helpers::setup_coin(&mut self.rng, Some(&self.mock_db))
+    }
+
+    /// Creates the script transaction spending the new coin.
+    pub fn setup_script_tx(&mut self, gas_price: Word) -> Transaction {
+        let (_, gas_coin) = self.setup_coin();
+        TransactionBuilder::script(vec![], vec![])
+            .gas_price(gas_price)
+            .script_gas_limit(1000)
+            .add_input(gas_coin)
+            .finalize_as_transaction()
+    }
+
+    /// Moves the clock forward and lets the service process expired timers.
+    pub async fn advance(&self, duration: Duration) {
+        tokio::time::advance(duration).await;
+        settle().await;
+    }
+
+    /// Gossips the transaction and waits for the service to report its validity.
+    pub async fn gossip(&mut self, tx: Transaction) -> GossipsubMessageAcceptance {
+        let message_id = self.next_message.to_be_bytes().to_vec();
+        self.next_message = self.next_message.saturating_add(1);
+        self.p2p
+            .gossip(tx, PeerId::from(vec![0]), message_id.clone());
+        wait_until(|| self.p2p.report_for(&message_id)).await
+    }
+
+    /// Submits transactions via the API of the service.
+    pub async fn submit(
+        &self,
+        txs: Vec<Transaction>,
+    ) -> Vec<anyhow::Result<InsertionResult>> {
+        let txs = txs.into_iter().map(Arc::new).collect();
+        busy_wait(
+            self.service
+                .shared
+                .insert(txs, TxOrigin::Api { api_key: None }),
+        )
+        .await
+    }
+
+    /// Commits the next block with transactions, spends their inputs in the database,
+    /// and waits for the service to process the block.
+    pub async fn commit_block(&mut self, txs: Vec<Transaction>) {
+        let height = self.height.succ().expect("The block height overflowed");
+        self.height = height;
+
+        let mut tx_status = vec![];
+        for tx in &txs {
+            self.spend_inputs(tx);
+            tx_status.push(TransactionExecutionStatus {
+                id: tx.id(&self.config.chain_config.consensus_parameters.chain_id),
+                result: TransactionExecutionResult::Success { result: None },
+                receipts: vec![],
+            });
+        }
+
+        let mut header = PartialBlockHeader::default();
+        header.consensus.height = height;
+        let block = Block::new(header, txs, &[]);
+        let sealed_block = SealedBlock {
+            entity: block,
+            consensus: Default::default(),
+        };
+        self.importer.import(Arc::new(ImportResult::new_from_local(
+            sealed_block,
+            tx_status,
+        )));
+        let shared = &self.service.shared;
+        wait_until(|| (shared.snapshot().height == height).then_some(())).await
+    }
+
+    /// Applies the `schedule` and checks invariants of the pool after each event.
+    pub async fn run(&mut self, schedule: Schedule) -> Result<(), InvariantViolation> {
+        let mut events = schedule.events;
+        events.sort_by_key(|(offset, _)| *offset);
+        for (offset, event) in events {
+            let elapsed = self.elapsed();
+            if offset > elapsed {
+                self.advance(offset.saturating_sub(elapsed)).await;
+            }
+            match event {
+                Event::Gossip(tx) => {
+                    self.gossip(tx).await;
+                }
+                Event::Submit(txs) => {
+                    self.submit(txs).await;
+                }
+                Event::CommitBlock(txs) => self.commit_block(txs).await,
+            }
+            self.check_invariants()?;
+        }
+        Ok(())
+    }
+
+    /// Checks invariants of the current pool.
+    pub fn check_invariants(&self) -> Result<(), InvariantViolation> {
+        let shared = &self.service.shared;
+        check_invariants(&self.config, &shared.snapshot(), shared.pending_number())
+    }
+
+    fn spend_inputs(&self, tx: &Transaction) {
+        let inputs = match tx {
+            Transaction::Script(tx) => tx.inputs().as_slice(),
+            Transaction::Create(tx) => tx.inputs().as_slice(),
+            Transaction::Mint(_) => &[],
+        };
+        let mut data = self.mock_db.data.lock().expect("The lock is poisoned");
+        for input in inputs {
+            if input.is_contract() {
+                continue
+            }
+            if let Some(utxo_id) = input.utxo_id() {
+                data.coins.remove(utxo_id);
+            }
+            if let Some(nonce) = input.nonce() {
+                data.spent_messages.insert(*nonce);
+            }
+        }
+    }
+}
+
+/// Yields to the service until the `condition` returns the value. The harness doesn't
+/// sleep, so the paused clock is not auto-advanced while the service processes the event.
+async fn wait_until<T>(mut condition: impl FnMut() -> Option<T>) -> T {
+    let started = std::time::Instant::now();
+    loop {
+        if let Some(value) = condition() {
+            return value
+        }
+        assert!(
+            started.elapsed() < PROCESSING_TIMEOUT,
+            "The TxPool service didn't process the event within {PROCESSING_TIMEOUT:?}"
+        );
+        tokio::task::yield_now().await;
+    }
+}
+
+/// Polls the `future` to the completion without letting the runtime idle.
+async fn busy_wait<F: Future>(future: F) -> F::Output {
+    let mut future = std::pin::pin!(future);
+    let waker = futures::task::noop_waker();
+    wait_until(|| {
+        match future
+            .as_mut()
+            .poll(&mut std::task::Context::from_waker(&waker))
+        {
+            std::task::Poll::Ready(output) => Some(output),
+            std::task::Poll::Pending => None,
+        }
+    })
+    .await
+}
+
+/// Lets the service process the events that are ready.
+async fn settle() {
+    for _ in 0..100 {
+        tokio::task::yield_now().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::admin::DumpedTransaction;
+
+    #[tokio::test(start_paused = true)]
+    async fn gossiped_double_spend_is_rejected() {
+        let mut harness = TestHarnessBuilder::new().build_and_start().await;
+        let (_, coin) = harness.setup_coin();
+        let tx = |gas_price| {
+            TransactionBuilder::script(vec![], vec![])
+                .gas_price(gas_price)
+                .script_gas_limit(1000)
+                .add_input(coin.clone())
+                .finalize_as_transaction()
+        };
+
+        let accepted = harness.gossip(tx(10)).await;
+        let rejected = harness.gossip(tx(5)).await;
+
+        assert_eq!(accepted, GossipsubMessageAcceptance::Accept);
+        assert_eq!(rejected, GossipsubMessageAcceptance::Reject);
+        assert_eq!(harness.shared().pending_number(), 1);
+        assert_eq!(harness.check_invariants(), Ok(()));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn schedule_controls_the_clock_and_commits_blocks() {
+        let ttl = Duration::from_secs(10);
+        let config = Config {
+            transaction_ttl: ttl,
+            ..Default::default()
+        };
+        let mut harness = TestHarnessBuilder::new()
+            .with_config(config)
+            .build_and_start()
+            .await;
+        let committed = harness.setup_script_tx(10);
+        let expired = harness.setup_script_tx(20);
+        let committed_id = committed.id(&Default::default());
+
+        let schedule = Schedule::new()
+            .at(Duration::ZERO, Event::Submit(vec![committed.clone()]))
+            .at(Duration::from_secs(1), Event::Gossip(expired))
+            .at(
+                Duration::from_secs(2),
+                Event::CommitBlock(vec![committed.clone()]),
+            );
+        harness.run(schedule).await.unwrap();
+
+        assert_eq!(harness.height(), 1u32.into());
+        assert_eq!(harness.elapsed(), Duration::from_secs(2));
+        assert!(harness.shared().find_one(committed_id).is_none());
+        assert_eq!(harness.shared().pending_number(), 1);
+        assert_eq!(harness.p2p().broadcasts().len(), 1);
+
+        harness.advance(ttl.saturating_mul(2)).await;
+        assert_eq!(harness.shared().pending_number(), 0);
+
+        // The committed transaction spent its coin.
+        let replay = harness.gossip(committed).await;
+        assert_eq!(replay, GossipsubMessageAcceptance::Reject);
+    }
+
+    #[test]
+    fn check_invariants_detects_double_spend_and_size_limit() {
+        let (_, coin) =
+            crate::test_helpers::setup_coin(&mut StdRng::seed_from_u64(1), None);
+        let dumped = |gas_price| {
+            let transaction = TransactionBuilder::script(vec![gas_price], vec![])
+                .gas_price(Word::from(gas_price))
+                .add_input(coin.clone())
+                .finalize_as_transaction();
+            DumpedTransaction {
+                id: transaction.id(&Default::default()),
+                gas_price: Word::from(gas_price),
+                max_gas: 0,
+                submitted_time: 0,
+                origin: TxOrigin::Injected.label(),
+                transaction,
+            }
+        };
+        let dump = TxPoolDump {
+            height: Default::default(),
+            paused: false,
+            transactions: vec![dumped(2), dumped(1)],
+        };
+
+        let result = check_invariants(&Config::default(), &dump, 2);
+        assert!(matches!(
+            result,
+            Err(InvariantViolation::DoubleSpend {
+                input: SpentInput::Coin(_),
+                ..
+            })
+        ));
+
+        let config = Config {
+            max_tx: 1,
+            ..Default::default()
+        };
+        assert_eq!(
+            check_invariants(&config, &dump, 2),
+            Err(InvariantViolation::TooManyTransactions { count: 2, max: 1 })
+        );
+        assert_eq!(
+            check_invariants(&config, &dump, 1),
+            Err(InvariantViolation::InconsistentIndexes {
+                pending: 1,
+                sorted: 2
+            })
+        );
+    }
+}
@@ -1,11 +1,18 @@
 use crate::{
+    admin::FlushFilter,
     containers::{
         dependency::Dependency,
         price_sort::PriceSort,
         time_sort::TimeSort,
     },
+    origin::OriginStatistics,
     ports::TxPoolDb,
+    predicate_cache::{
+        has_predicates,
+        PredicateCache,
+    },
     service::TxStatusChange,
+    signature_cache::SignatureCache,
     types::*,
     Config,
     Error,
@@ -31,6 +38,7 @@ use fuel_core_types::{
     services::txpool::{
         ArcPoolTx,
         InsertionResult,
+        TxOrigin,
     },
     tai64::Tai64,
 };
@@ -46,6 +54,7 @@ use fuel_core_types::{
         txpool::from_executor_to_status,
     },
 };
+use parking_lot::Mutex as ParkingMutex;
 use std::{
     cmp::Reverse,
     collections::HashMap,
@@ -62,11 +71,16 @@ pub struct TxPool<ViewProvider> {
     by_dependency: Dependency,
     config: Config,
     database: ViewProvider,
+    origin_statistics: OriginStatistics,
+    /// The origins of transactions removed from the pool for the inclusion into
+    /// the next block. They are accounted when the block is imported.
+    committed_origins: HashMap<TxId, TxOrigin>,
 }
 
 impl<ViewProvider> TxPool<ViewProvider> {
     pub fn new(config: Config, database: ViewProvider) -> Self {
         let max_depth = config.max_depth;
+        let origin_statistics = OriginStatistics::new(config.metrics);
 
         Self {
             by_hash: HashMap::new(),
@@ -75,6 +89,8 @@ impl<ViewProvider> TxPool<ViewProvider> {
             by_dependency: Dependency::new(max_depth, config.utxo_validation),
             config,
             database,
+            origin_statistics,
+            committed_origins: HashMap::new(),
         }
     }
 
@@ -87,10 +103,24 @@ impl<ViewProvider> TxPool<ViewProvider> {
         &self.by_hash
     }
 
+    pub fn database(&self) -> &ViewProvider {
+        &self.database
+    }
+
     pub fn dependency(&self) -> &Dependency {
         &self.by_dependency
     }
 
+    pub fn origin_statistics(&self) -> &OriginStatistics {
+        &self.origin_statistics
+    }
+
+    /// Records `count` transactions from the `origin` rejected before the insertion.
+    pub fn record_rejected(&mut self, origin: &TxOrigin, count: usize) {
+        self.origin_statistics
+            .record_rejected(origin, u64::try_from(count).unwrap_or(u64::MAX));
+    }
+
     /// Return all sorted transactions that are includable in next block.
     pub fn sorted_includable(&self) -> impl Iterator<Item = ArcPoolTx> + '_ {
         self.by_gas_price
@@ -135,6 +165,9 @@ impl<ViewProvider> TxPool<ViewProvider> {
     //  This method is used during `select_transactions`, so we need to handle the case
     //  when transaction was skipped during block execution(`ExecutionResult.skipped_transaction`).
     pub fn remove_committed_tx(&mut self, tx_id: &TxId) -> Vec<ArcPoolTx> {
+        if let Some(info) = self.by_hash.get(tx_id) {
+            self.committed_origins.insert(*tx_id, info.origin().clone());
+        }
         self.remove_by_tx_id(tx_id)
     }
 
@@ -206,7 +239,12 @@ impl<ViewProvider> TxPool<ViewProvider> {
                 TxStatusMessage::Status(status),
             );
             self.remove_committed_tx(&tx_id);
+            if let Some(origin) = self.committed_origins.remove(&tx_id) {
+                self.origin_statistics.record_included(&origin);
+            }
         }
+        // Transactions skipped during the block production are not included.
+        self.committed_origins.clear();
     }
 
     /// remove transaction from pool needed on user demand. Low priority
@@ -229,6 +267,30 @@ impl<ViewProvider> TxPool<ViewProvider> {
         removed
     }
 
+    /// Removes all transactions selected by the `filter` with their dependent transactions.
+    pub fn flush(
+        &mut self,
+        tx_status_sender: &TxStatusChange,
+        filter: &FlushFilter,
+    ) -> Vec<ArcPoolTx> {
+        let tx_ids: Vec<_> = self
+            .by_hash
+            .values()
+            .filter(|info| filter.matches(info.tx()))
+            .map(|info| info.id())
+            .collect();
+
+        let mut removed = Vec::new();
+        for tx_id in tx_ids {
+            // The transaction could be already removed as a dependent transaction.
+            for tx in self.remove_by_tx_id(&tx_id) {
+                tx_status_sender.send_squeezed_out(tx.id(), Error::Flushed);
+                removed.push(tx);
+            }
+        }
+        removed
+    }
+
     /// Remove all old transactions from the pool.
     pub fn prune_old_txs(&mut self) -> Vec<ArcPoolTx> {
         let Some(deadline) =
@@ -265,7 +327,7 @@ where
         tx: Checked<Transaction>,
     ) -> anyhow::Result<InsertionResult> {
         let view = self.database.latest_view();
-        self.insert_inner(tx, &view)
+        self.insert_inner(tx, &TxOrigin::Injected, &view)
     }
 
     #[tracing::instrument(level = "info", skip_all, fields(tx_id = %tx.id()), ret, err)]
@@ -273,6 +335,7 @@ where
     fn insert_inner(
         &mut self,
         tx: Checked<Transaction>,
+        origin: &TxOrigin,
         view: &View,
     ) -> anyhow::Result<InsertionResult> {
         let tx: CheckedTransaction = tx.into();
@@ -323,7 +386,7 @@ where
         }
         // check and insert dependency
         let rem = self.by_dependency.insert(&self.by_hash, view, &tx)?;
-        let info = TxInfo::new(tx.clone());
+        let info = TxInfo::new(tx.clone(), origin.clone());
         let submitted_time = info.submitted_time();
         self.by_gas_price.insert(&info);
         self.by_time.insert(&info);
@@ -361,6 +424,7 @@ where
         &mut self,
         tx_status_sender: &TxStatusChange,
         txs: Vec<Checked<Transaction>>,
+        origin: &TxOrigin,
     ) -> Vec<anyhow::Result<InsertionResult>> {
         // Check if that data is okay (witness match input/output, and if recovered signatures ara valid).
         // should be done before transaction comes to txpool, or before it enters RwLocked region.
@@ -368,7 +432,12 @@ where
         let view = self.database.latest_view();
 
         for tx in txs.into_iter() {
-            res.push(self.insert_inner(tx, &view));
+            let result = self.insert_inner(tx, origin, &view);
+            match &result {
+                Ok(_) => self.origin_statistics.record_submitted(origin),
+                Err(_) => self.origin_statistics.record_rejected(origin, 1),
+            }
+            res.push(result);
         }
 
         // announce to subscribers
@@ -387,6 +456,7 @@ where
                     tx_status_sender.send_submitted(
                         inserted.id(),
                         Tai64::from_unix(submitted_time.as_secs() as i64),
+                        origin.clone(),
                     );
                 }
                 Err(_) => {
@@ -402,12 +472,22 @@ pub async fn check_transactions(
     txs: &[Arc<Transaction>],
     current_height: BlockHeight,
     config: &Config,
+    predicate_cache: &ParkingMutex<PredicateCache>,
+    signature_cache: &ParkingMutex<SignatureCache>,
 ) -> Vec<anyhow::Result<Checked<Transaction>>> {
     let mut checked_txs = Vec::with_capacity(txs.len());
 
     for tx in txs.iter() {
-        checked_txs
-            .push(check_single_tx(tx.deref().clone(), current_height, config).await);
+        checked_txs.push(
+            check_single_tx(
+                tx.deref().clone(),
+                current_height,
+                config,
+                predicate_cache,
+                signature_cache,
+            )
+            .await,
+        );
     }
 
     checked_txs
@@ -417,22 +497,54 @@ pub async fn check_single_tx(
     tx: Transaction,
     current_height: BlockHeight,
     config: &Config,
+    predicate_cache: &ParkingMutex<PredicateCache>,
+    signature_cache: &ParkingMutex<SignatureCache>,
 ) -> anyhow::Result<Checked<Transaction>> {
     if tx.is_mint() {
         return Err(Error::NotSupportedTransactionType.into())
     }
 
-    verify_tx_min_gas_price(&tx, config)?;
+    // The transaction is included in the next block, so it should be valid
+    // under the consensus parameters in effect at the next height.
+    let next_height = current_height.succ().unwrap_or(current_height);
 
-    let tx: Checked<Transaction> = if config.utxo_validation {
-        let consensus_params = &config.chain_config.consensus_parameters;
+    verify_tx_min_gas_price(&tx, next_height, config)?;
+    let consensus_params = config.chain_config.consensus_parameters_at(next_height);
 
+    let tx: Checked<Transaction> = if config.utxo_validation {
         let tx = tx
             .into_checked_basic(current_height, consensus_params)
-            .map_err(|e| anyhow::anyhow!("{e:?}"))?
-            .check_signatures(&consensus_params.chain_id)
             .map_err(|e| anyhow::anyhow!("{e:?}"))?;
 
+        // The basic checks depend on the current height, so they are repeated
+        // even if the transaction is cached.
+        let version = config
+            .chain_config
+            .consensus_parameters_version_at(next_height);
+        if let Some(verified) = predicate_cache.lock().get(version, &tx) {
+            if config.metrics {
+                txpool_metrics().predicate_cache_hits.inc();
+            }
+            return Ok(verified)
+        }
+
+        let cached = signature_cache.lock().get(&tx);
+        let tx = if let Some(verified) = cached {
+            if config.metrics {
+                txpool_metrics().saved_signature_verifications.inc();
+            }
+            verified
+        } else {
+            let tx = tx
+                .check_signatures(&consensus_params.chain_id)
+                .map_err(|e| anyhow::anyhow!("{e:?}"))?;
+            signature_cache.lock().insert(&tx);
+            tx
+        };
+
+        if config.metrics && has_predicates(tx.transaction()) {
+            txpool_metrics().predicate_cache_misses.inc();
+        }
         let tx = tx
             .check_predicates_async::<TokioWithRayon>(&CheckPredicateParams::from(
                 consensus_params,
@@ -441,17 +553,22 @@ pub async fn check_single_tx(
             .map_err(|e| anyhow::anyhow!("{e:?}"))?;
 
         debug_assert!(tx.checks().contains(Checks::all()));
+        predicate_cache.lock().insert(version, &tx);
 
         tx
     } else {
-        tx.into_checked_basic(current_height, &config.chain_config.consensus_parameters)
+        tx.into_checked_basic(current_height, consensus_params)
             .map_err(|e| anyhow::anyhow!("{e:?}"))?
     };
 
     Ok(tx)
 }
 
-fn verify_tx_min_gas_price(tx: &Transaction, config: &Config) -> Result<(), Error> {
+fn verify_tx_min_gas_price(
+    tx: &Transaction,
+    block_height: BlockHeight,
+    config: &Config,
+) -> Result<(), Error> {
     let price = match tx {
         Transaction::Script(script) => script.price(),
         Transaction::Create(create) => create.price(),
@@ -463,7 +580,7 @@ fn verify_tx_min_gas_price(tx: &Transaction, config: &Config) -> Result<(), Erro
         // price if there is no minimum gas price
         txpool_metrics().gas_price_histogram.observe(price as f64);
     }
-    if price < config.min_gas_price {
+    if price < config.min_gas_price_at(block_height) {
         return Err(Error::NotInsertedGasPriceTooLow)
     }
     Ok(())
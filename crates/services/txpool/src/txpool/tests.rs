@@ -1,4 +1,6 @@
 use crate::{
+    predicate_cache::PredicateCache,
+    signature_cache::SignatureCache,
     test_helpers::{
         IntoEstimated,
         TextContext,
@@ -24,6 +26,8 @@ use fuel_core_types::{
         Address,
         AssetId,
         Contract,
+        GasCosts,
+        GasCostsValues,
         Input,
         Output,
         Transaction,
@@ -34,6 +38,7 @@ use fuel_core_types::{
     fuel_types::ChainId,
     fuel_vm::checked_transaction::Checked,
 };
+use parking_lot::Mutex as ParkingMutex;
 use std::{
     cmp::Reverse,
     collections::HashMap,
@@ -44,8 +49,19 @@ use super::check_single_tx;
 
 const GAS_LIMIT: Word = 1000;
 
+fn predicate_cache(config: &Config) -> ParkingMutex<PredicateCache> {
+    ParkingMutex::new(PredicateCache::new(config.predicate_cache_size))
+}
+
+fn signature_cache(config: &Config) -> ParkingMutex<SignatureCache> {
+    ParkingMutex::new(SignatureCache::new(
+        config.signature_cache_size,
+        config.signature_cache_ttl,
+    ))
+}
+
 async fn check_unwrap_tx(tx: Transaction, config: &Config) -> Checked<Transaction> {
-    check_single_tx(tx, Default::default(), config)
+    check_tx(tx, config)
         .await
         .expect("Transaction should be checked")
 }
@@ -54,7 +70,14 @@ async fn check_tx(
     tx: Transaction,
     config: &Config,
 ) -> anyhow::Result<Checked<Transaction>> {
-    check_single_tx(tx, Default::default(), config).await
+    check_single_tx(
+        tx,
+        Default::default(),
+        config,
+        &predicate_cache(config),
+        &signature_cache(config),
+    )
+    .await
 }
 
 #[tokio::test]
@@ -1062,3 +1085,96 @@ async fn predicate_that_returns_false_is_invalid() {
         "unexpected error: {err}",
     )
 }
+
+#[tokio::test]
+async fn predicates_of_cached_transaction_are_not_verified_again() {
+    let mut context = TextContext::default();
+    let coin = context
+        .custom_predicate(
+            AssetId::BASE,
+            TEST_COIN_AMOUNT,
+            vec![op::ret(RegId::ONE)].into_iter().collect(),
+            None,
+        )
+        .into_default_estimated();
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(coin)
+        .finalize_as_transaction();
+    let config = Config::default();
+    let cache = predicate_cache(&config);
+    let signature_cache = signature_cache(&config);
+
+    check_single_tx(
+        tx.clone(),
+        Default::default(),
+        &config,
+        &cache,
+        &signature_cache,
+    )
+    .await
+    .expect("The predicate is valid");
+
+    // The estimated gas of the predicate doesn't match the increased cost of the `RET`,
+    // but the predicate verified with the same version of parameters isn't executed again.
+    let mut expensive_config = config.clone();
+    expensive_config.chain_config.consensus_parameters.gas_costs =
+        GasCosts::new(GasCostsValues {
+            ret: 1000,
+            ..GasCostsValues::default()
+        });
+    check_single_tx(
+        tx.clone(),
+        Default::default(),
+        &expensive_config,
+        &cache,
+        &signature_cache,
+    )
+    .await
+    .expect("The predicate is cached");
+    check_single_tx(
+        tx,
+        Default::default(),
+        &expensive_config,
+        &predicate_cache(&expensive_config),
+        &signature_cache,
+    )
+    .await
+    .expect_err("The predicate is verified without the cache");
+}
+
+#[tokio::test]
+async fn tx_is_checked_with_consensus_parameters_of_next_block() {
+    let mut context = TextContext::default();
+    let (_, gas_coin) = context.setup_coin();
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+
+    // The upgrade at the height `10` makes the gas limit of the transaction too high.
+    let mut config = Config::default();
+    let mut consensus_parameters = config.chain_config.consensus_parameters.clone();
+    consensus_parameters.tx_params.max_gas_per_tx = 1;
+    config.chain_config.consensus_parameters_upgrades =
+        vec![fuel_core_chain_config::ConsensusParametersUpgrade {
+            height: 10u32.into(),
+            consensus_parameters,
+        }];
+
+    let predicate_cache = predicate_cache(&config);
+    let signature_cache = signature_cache(&config);
+
+    check_single_tx(
+        tx.clone(),
+        8u32.into(),
+        &config,
+        &predicate_cache,
+        &signature_cache,
+    )
+    .await
+    .expect("The upgrade isn't active in the next block");
+    check_single_tx(tx, 9u32.into(), &config, &predicate_cache, &signature_cache)
+        .await
+        .expect_err("The upgrade is active in the next block");
+}
@@ -7,6 +7,7 @@ use crate::codec::{
     Decode,
     Encode,
 };
+use core::mem::size_of;
 use fuel_core_types::{
     blockchain::primitives::DaBlockHeight,
     fuel_tx::{
@@ -83,6 +84,18 @@ pub fn utxo_id_to_bytes(utxo_id: &UtxoId) -> [u8; TxId::LEN + 1] {
     default
 }
 
+/// Converts the compact id of the owner and the `UtxoId` into an array of bytes.
+/// The big-endian id goes first, so keys of one owner share the short prefix.
+pub fn owner_id_utxo_id_to_bytes(
+    owner_id: u64,
+    utxo_id: &UtxoId,
+) -> [u8; size_of::<u64>() + TxId::LEN + 1] {
+    let mut default = [0; size_of::<u64>() + TxId::LEN + 1];
+    default[0..size_of::<u64>()].copy_from_slice(&owner_id.to_be_bytes());
+    default[size_of::<u64>()..].copy_from_slice(utxo_id_to_bytes(utxo_id).as_ref());
+    default
+}
+
 impl Encode<UtxoId> for Primitive<{ TxId::LEN + 1 }> {
     type Encoder<'a> = [u8; TxId::LEN + 1];
 
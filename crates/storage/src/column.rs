@@ -72,6 +72,14 @@ pub enum Column {
     OwnedCoins = 20,
     /// The column of the table that stores `true` if `owner` owns `Message` with `message_id`
     OwnedMessageIds = 21,
+    /// The column of the table that maps the `owner` to its compact id used by `OwnedCoins`
+    OwnerIds = 22,
+    /// The column of the table that stores the last assigned compact id of the owner
+    LastOwnerId = 23,
+    /// The column of the table that stores the total amount of coins per `owner` and `asset_id`
+    CoinBalances = 24,
+    /// The column of the table that marks the `CoinBalances` table as inconsistent with coins
+    CoinBalancesDirty = 25,
 }
 
 impl Column {
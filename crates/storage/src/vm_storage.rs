@@ -40,6 +40,7 @@ use fuel_core_types::{
         Word,
     },
     fuel_vm::InterpreterStorage,
+    services::executor::ContractStateChange,
     tai64::Tai64,
 };
 use itertools::Itertools;
@@ -53,6 +54,7 @@ pub struct VmStorage<D> {
     current_timestamp: Tai64,
     coinbase: ContractId,
     database: D,
+    state_changes: Option<Vec<ContractStateChange>>,
 }
 
 /// The trait around the `U256` type allows increasing the key by one.
@@ -79,6 +81,7 @@ impl<D: Default> Default for VmStorage<D> {
             current_timestamp: Tai64::now(),
             coinbase: Default::default(),
             database: D::default(),
+            state_changes: None,
         }
     }
 }
@@ -95,6 +98,37 @@ impl<D> VmStorage<D> {
             current_timestamp: header.time,
             coinbase,
             database,
+            state_changes: None,
+        }
+    }
+
+    /// Enables the recording of writes to the contract storage slots.
+    pub fn record_state_changes(mut self) -> Self {
+        self.state_changes = Some(vec![]);
+        self
+    }
+
+    /// Takes the recorded writes to the contract storage slots in the order of execution.
+    /// Returns nothing if the recording is not enabled.
+    pub fn take_state_changes(&mut self) -> Vec<ContractStateChange> {
+        self.state_changes
+            .as_mut()
+            .map(core::mem::take)
+            .unwrap_or_default()
+    }
+
+    fn record_state_change(
+        &mut self,
+        contract_id: &ContractId,
+        key: &Bytes32,
+        value: Option<Bytes32>,
+    ) {
+        if let Some(state_changes) = &mut self.state_changes {
+            state_changes.push(ContractStateChange {
+                contract_id: *contract_id,
+                key: *key,
+                value,
+            });
         }
     }
 
@@ -233,12 +267,38 @@ where
         self.storage_contract_insert(id, contract)?;
         self.storage_contract_root_insert(id, salt, root)?;
 
+        for slot in slots {
+            self.record_state_change(id, slot.key(), Some(*slot.value()));
+        }
         self.database.init_contract_state(
             id,
             slots.iter().map(|slot| (*slot.key(), *slot.value())),
         )
     }
 
+    fn merkle_contract_state_insert(
+        &mut self,
+        contract: &ContractId,
+        key: &Bytes32,
+        value: &Bytes32,
+    ) -> Result<Option<Bytes32>, Self::DataError> {
+        self.record_state_change(contract, key, Some(*value));
+        self.database
+            .storage::<ContractsState>()
+            .insert(&(contract, key).into(), value)
+    }
+
+    fn merkle_contract_state_remove(
+        &mut self,
+        contract: &ContractId,
+        key: &Bytes32,
+    ) -> Result<Option<Bytes32>, Self::DataError> {
+        self.record_state_change(contract, key, None);
+        self.database
+            .storage::<ContractsState>()
+            .remove(&(contract, key).into())
+    }
+
     fn merkle_contract_state_range(
         &self,
         contract_id: &ContractId,
@@ -277,6 +337,7 @@ where
         for value in values {
             current_key.to_big_endian(key_bytes.as_mut());
 
+            self.record_state_change(contract_id, &key_bytes, Some(*value));
             let option = self
                 .database
                 .storage::<ContractsState>()
@@ -308,6 +369,7 @@ where
         for _ in 0..range {
             current_key.to_big_endian(key_bytes.as_mut());
 
+            self.record_state_change(contract_id, &key_bytes, None);
             let option = self
                 .database
                 .storage::<ContractsState>()
@@ -11,7 +11,10 @@ use fuel_core::{
     },
 };
 use fuel_core_client::client::FuelClient;
-use fuel_core_poa::Trigger;
+use fuel_core_poa::{
+    service::Mode,
+    Trigger,
+};
 use fuel_core_types::{
     fuel_asm::op,
     fuel_tx::{
@@ -45,12 +48,25 @@ pub struct TestContext {
 
 impl TestContext {
     pub async fn new(seed: u64) -> Self {
-        let rng = StdRng::seed_from_u64(seed);
-        let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+        Self::start(Config::local_node(), StdRng::seed_from_u64(seed)).await
+    }
+
+    /// Starts the node with the `config` and connects the client to its GraphQL API.
+    pub async fn start(config: Config, rng: StdRng) -> Self {
+        let srv = FuelService::new_node(config).await.unwrap();
         let client = FuelClient::from(srv.bound_address);
         Self { srv, rng, client }
     }
 
+    /// Produces the `number_of_blocks` blocks regardless of the block production trigger.
+    pub async fn produce_blocks(&self, number_of_blocks: u32) -> anyhow::Result<()> {
+        self.srv
+            .shared
+            .poa_adapter
+            .manually_produce_blocks(None, Mode::Blocks { number_of_blocks })
+            .await
+    }
+
     pub async fn transfer(
         &mut self,
         from: Address,
@@ -180,8 +196,9 @@ impl TestSetupBuilder {
         self
     }
 
-    // setup chainspec and spin up a fuel-node
-    pub async fn finalize(&mut self) -> TestContext {
+    /// Returns the configuration of the node with the genesis state of the builder.
+    /// It can be adjusted before starting the node by [`TestContext::start`].
+    pub fn config(&self) -> Config {
         let mut chain_config = ChainConfig {
             initial_state: Some(StateConfig {
                 coins: Some(self.initial_coins.clone()),
@@ -193,7 +210,7 @@ impl TestSetupBuilder {
         };
         chain_config.consensus_parameters.tx_params.max_gas_per_tx = self.gas_limit;
         chain_config.block_gas_limit = self.gas_limit;
-        let config = Config {
+        Config {
             utxo_validation: self.utxo_validation,
             txpool: fuel_core_txpool::Config {
                 chain_config: chain_config.clone(),
@@ -203,17 +220,13 @@ impl TestSetupBuilder {
             chain_conf: chain_config,
             block_production: self.trigger,
             ..Config::local_node()
-        };
-
-        let srv = FuelService::new_node(config).await.unwrap();
-        let client = FuelClient::from(srv.bound_address);
-
-        TestContext {
-            srv,
-            rng: self.rng.clone(),
-            client,
         }
     }
+
+    // setup chainspec and spin up a fuel-node
+    pub async fn finalize(&mut self) -> TestContext {
+        TestContext::start(self.config(), self.rng.clone()).await
+    }
 }
 
 impl Default for TestSetupBuilder {
@@ -0,0 +1,17 @@
+//! The harness for black-box integration tests of the Fuel node.
+//!
+//! It runs the full node in-process and connects the GraphQL client to it,
+//! so tests drive and observe the node only via its public API:
+//!
+//! - [`builder::TestSetupBuilder`] configures the genesis state and starts the node.
+//! - [`builder::TestContext`] holds the running node with its client and
+//!   produces blocks on demand.
+//! - [`relayer`] mocks the Ethereum node followed by the relayer (the `relayer` feature).
+//! - [`p2p`] creates networks of producer and validator nodes (the `p2p` feature).
+
+pub mod builder;
+#[cfg(feature = "relayer")]
+pub mod relayer;
+
+#[cfg(feature = "p2p")]
+pub use fuel_core::p2p_test_helpers as p2p;
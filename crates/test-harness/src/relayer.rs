@@ -0,0 +1,227 @@
+//! The mock of the Ethereum node followed by the relayer.
+//!
+//! The mock serves the JSON-RPC methods used by the relayer from the [`MockMiddleware`],
+//! so tests control the Ethereum state by updating the middleware.
+
+use ethers_core::types::{
+    BlockNumber,
+    Filter,
+    Log,
+    SyncingStatus,
+    U256,
+};
+use ethers_providers::Middleware;
+use fuel_core_relayer::{
+    test_helpers::{
+        middleware::MockMiddleware,
+        EvtToLog,
+    },
+    H160,
+};
+use fuel_core_types::fuel_types::Nonce;
+use hyper::{
+    service::{
+        make_service_fn,
+        service_fn,
+    },
+    Body,
+    Request,
+    Response,
+    Server,
+};
+use serde_json::{
+    json,
+    Map,
+    Value,
+};
+use std::{
+    convert::Infallible,
+    net::{
+        Ipv4Addr,
+        SocketAddr,
+    },
+    sync::Arc,
+};
+use tokio::sync::oneshot::Sender;
+
+/// Creates the log of the message sent to Fuel by the bridge contract
+/// at the `contract_address`.
+pub fn make_message_event(
+    nonce: Nonce,
+    block_number: u64,
+    contract_address: H160,
+    sender: Option<[u8; 32]>,
+    recipient: Option<[u8; 32]>,
+    amount: Option<u64>,
+    data: Option<Vec<u8>>,
+) -> Log {
+    let message = fuel_core_relayer::bridge::MessageSentFilter {
+        nonce: U256::from_big_endian(nonce.as_ref()),
+        sender: sender.unwrap_or_default(),
+        recipient: recipient.unwrap_or_default(),
+        amount: amount.unwrap_or_default(),
+        data: data.map(Into::into).unwrap_or_default(),
+    };
+    let mut log = message.into_log();
+    log.address = contract_address;
+    log.block_number = Some(block_number.into());
+    log
+}
+
+/// Serves the `eth_node` via JSON-RPC on a random local port until the shutdown.
+pub async fn spawn_eth_node(eth_node: Arc<MockMiddleware>) -> EthNodeHandle {
+    // Construct our SocketAddr to listen on...
+    let addr = SocketAddr::from((Ipv4Addr::LOCALHOST, 0));
+
+    // And a MakeService to handle each connection...
+    let make_service = make_service_fn(move |_conn| {
+        let eth_node = eth_node.clone();
+        async move {
+            Ok::<_, Infallible>(service_fn({
+                let eth_node = eth_node.clone();
+                move |req| handle(eth_node.clone(), req)
+            }))
+        }
+    });
+
+    // Then bind and serve...
+    let server = Server::bind(&addr).serve(make_service);
+    let addr = server.local_addr();
+
+    let (shutdown, rx) = tokio::sync::oneshot::channel();
+
+    tokio::spawn(async move {
+        let graceful = server.with_graceful_shutdown(async {
+            rx.await.ok();
+        });
+        // And run forever...
+        if let Err(e) = graceful.await {
+            tracing::error!("The mock Ethereum node failed: {e}");
+        }
+    });
+    EthNodeHandle {
+        shutdown,
+        address: addr,
+    }
+}
+
+pub struct EthNodeHandle {
+    /// Stops the server.
+    pub shutdown: Sender<()>,
+    /// The address to use as the relayer URL, `http://{address}`.
+    pub address: SocketAddr,
+}
+
+/// The standard JSON-RPC error codes.
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+const INTERNAL_ERROR: i64 = -32603;
+
+/// The error returned to the relayer in the JSON-RPC response.
+#[derive(Debug)]
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+impl RpcError {
+    fn new(code: i64, message: impl ToString) -> Self {
+        Self {
+            code,
+            message: message.to_string(),
+        }
+    }
+
+    fn invalid_params(error: serde_json::Error) -> Self {
+        Self::new(INVALID_PARAMS, error)
+    }
+
+    fn internal(error: impl ToString) -> Self {
+        Self::new(INTERNAL_ERROR, error)
+    }
+}
+
+async fn handle(
+    mock: Arc<MockMiddleware>,
+    req: Request<Body>,
+) -> Result<Response<Body>, Infallible> {
+    let (id, result) = match read_request(req).await {
+        Ok(request) => {
+            let id = request.get("id").cloned().unwrap_or_default();
+            (id, call(&mock, request).await)
+        }
+        Err(err) => (Value::Null, Err(err)),
+    };
+
+    let response = match result {
+        Ok(result) => json!({ "id": id, "jsonrpc": "2.0", "result": result }),
+        Err(err) => {
+            tracing::warn!(
+                "The mock Ethereum node failed the request {id}: {}",
+                err.message
+            );
+            json!({ "id": id, "jsonrpc": "2.0", "error": {
+                "code": err.code,
+                "message": err.message,
+            } })
+        }
+    };
+
+    Ok(Response::new(Body::from(response.to_string())))
+}
+
+async fn read_request(req: Request<Body>) -> Result<Map<String, Value>, RpcError> {
+    let body = hyper::body::to_bytes(req)
+        .await
+        .map_err(|e| RpcError::new(INVALID_REQUEST, e))?;
+    match serde_json::from_slice(body.as_ref()) {
+        Ok(Value::Object(request)) => Ok(request),
+        Ok(_) => Err(RpcError::new(
+            INVALID_REQUEST,
+            "The request is not a JSON object",
+        )),
+        Err(e) => Err(RpcError::new(PARSE_ERROR, e)),
+    }
+}
+
+async fn call(
+    mock: &MockMiddleware,
+    mut request: Map<String, Value>,
+) -> Result<Value, RpcError> {
+    let Some(Value::String(method)) = request.remove("method") else {
+        return Err(RpcError::new(INVALID_REQUEST, "The request has no method"))
+    };
+    let params = request.remove("params").unwrap_or_else(|| json!([]));
+
+    match method.as_str() {
+        "eth_getBlockByNumber" => {
+            let (number, _full_transactions): (BlockNumber, bool) =
+                serde_json::from_value(params).map_err(RpcError::invalid_params)?;
+            let block = mock.get_block(number).await.map_err(RpcError::internal)?;
+            serde_json::to_value(block).map_err(RpcError::internal)
+        }
+        "eth_syncing" => {
+            let status = mock.syncing().await.map_err(RpcError::internal)?;
+            match status {
+                SyncingStatus::IsFalse => Ok(json!(false)),
+                SyncingStatus::IsSyncing(status) => Ok(json!({
+                    "starting_block": status.starting_block,
+                    "current_block": status.current_block,
+                    "highest_block": status.highest_block,
+                })),
+            }
+        }
+        "eth_getLogs" => {
+            let (filter,): (Filter,) =
+                serde_json::from_value(params).map_err(RpcError::invalid_params)?;
+            let logs = mock.get_logs(&filter).await.map_err(RpcError::internal)?;
+            serde_json::to_value(logs).map_err(RpcError::internal)
+        }
+        _ => Err(RpcError::new(
+            METHOD_NOT_FOUND,
+            format!("The method `{method}` is not supported by the mock"),
+        )),
+    }
+}
@@ -129,6 +129,11 @@ impl BlockHeader {
         self.application_mut().generated.transactions_root = root;
     }
 
+    /// Set the transactions count for the header
+    pub fn set_transactions_count(&mut self, count: u64) {
+        self.application_mut().generated.transactions_count = count;
+    }
+
     /// Set the DA height for the header
     pub fn set_da_height(&mut self, da_height: DaBlockHeight) {
         self.application_mut().da_height = da_height;
@@ -389,7 +394,8 @@ impl PartialBlockHeader {
     }
 }
 
-fn generate_txns_root(transactions: &[Transaction]) -> Bytes32 {
+/// Generates the merkle root of the transactions of the block.
+pub fn generate_txns_root(transactions: &[Transaction]) -> Bytes32 {
     let transaction_ids = transactions.iter().map(|tx| tx.to_bytes());
     // Generate the transaction merkle root.
     let mut transaction_tree =
@@ -403,27 +409,42 @@ fn generate_txns_root(transactions: &[Transaction]) -> Bytes32 {
 impl ApplicationHeader<GeneratedApplicationFields> {
     /// Hash the application header.
     pub fn hash(&self) -> Bytes32 {
-        // Order matters and is the same as the spec.
-        let mut hasher = crate::fuel_crypto::Hasher::default();
-        hasher.input(&self.da_height.to_bytes()[..]);
-        hasher.input(self.transactions_count.to_be_bytes());
-        hasher.input(self.message_receipt_count.to_be_bytes());
-        hasher.input(self.transactions_root.as_ref());
-        hasher.input(self.message_receipt_root.as_ref());
-        hasher.digest()
+        fuel_core_light::application_hash(
+            *self.da_height,
+            self.transactions_count,
+            self.message_receipt_count,
+            &self.transactions_root,
+            &self.message_receipt_root,
+        )
     }
 }
 
 impl ConsensusHeader<GeneratedConsensusFields> {
     /// Hash the consensus header.
     pub fn hash(&self) -> BlockId {
-        // Order matters and is the same as the spec.
-        let mut hasher = crate::fuel_crypto::Hasher::default();
-        hasher.input(self.prev_root.as_ref());
-        hasher.input(&self.height.to_bytes()[..]);
-        hasher.input(self.time.0.to_be_bytes());
-        hasher.input(self.application_hash.as_ref());
-        BlockId::from(hasher.digest())
+        fuel_core_light::block_id(
+            &self.prev_root,
+            &self.height,
+            &self.time,
+            &self.application_hash,
+        )
+        .into()
+    }
+}
+
+impl From<&BlockHeader> for fuel_core_light::BlockHeader {
+    fn from(header: &BlockHeader) -> Self {
+        Self {
+            da_height: *header.da_height,
+            transactions_count: header.transactions_count,
+            message_receipt_count: header.message_receipt_count,
+            transactions_root: header.transactions_root,
+            message_receipt_root: header.message_receipt_root,
+            prev_root: *header.prev_root(),
+            height: *header.height(),
+            time: header.time(),
+            application_hash: *header.application_hash(),
+        }
     }
 }
 
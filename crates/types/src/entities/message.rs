@@ -5,7 +5,6 @@ use crate::{
         header::BlockHeader,
         primitives::DaBlockHeight,
     },
-    fuel_merkle::common::ProofSet,
     fuel_tx::{
         input::message::{
             compute_message_id,
@@ -24,6 +23,9 @@ use crate::{
     },
 };
 
+#[doc(no_inline)]
+pub use fuel_core_light::MerkleProof;
+
 /// Message sent from DA layer to fuel by relayer bridge.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -225,15 +227,6 @@ impl Message {
     }
 }
 
-/// Type containing merkle proof data.
-#[derive(Default, Debug, Clone, PartialEq, Eq)]
-pub struct MerkleProof {
-    /// The proof set.
-    pub proof_set: ProofSet,
-    /// The proof index.
-    pub proof_index: u64,
-}
-
 /// Proves to da layer that this message was included in a Fuel block.
 pub struct MessageProof {
     /// Proof that message is contained within the provided block header.
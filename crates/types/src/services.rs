@@ -4,12 +4,12 @@ pub mod block_importer;
 pub mod block_producer;
 pub mod executor;
 pub mod graphql_api;
+pub mod node_identity;
 pub mod p2p;
+pub mod port;
 pub mod relayer;
 pub mod txpool;
 
-// TODO: Define a one common error for all services like
-
 /// The uncommitted `Result` of some action with database transaction.
 /// The user should commit the result by itself.
 #[derive(Debug)]
@@ -6,7 +6,10 @@ use crate::{
         SealedBlock,
     },
     services::{
-        executor::TransactionExecutionStatus,
+        executor::{
+            ContractStateChange,
+            TransactionExecutionStatus,
+        },
         Uncommitted,
     },
 };
@@ -28,6 +31,8 @@ pub struct ImportResult {
     pub sealed_block: SealedBlock,
     /// The status of the transactions execution included into the block.
     pub tx_status: Vec<TransactionExecutionStatus>,
+    /// The final values of the contract storage slots written by the block.
+    pub state_changes: Vec<ContractStateChange>,
     /// The source producer of the block.
     pub source: Source,
 }
@@ -59,6 +64,7 @@ impl ImportResult {
         Self {
             sealed_block,
             tx_status,
+            state_changes: vec![],
             source: Source::Local,
         }
     }
@@ -71,6 +77,7 @@ impl ImportResult {
         Self {
             sealed_block,
             tx_status,
+            state_changes: vec![],
             source: Source::Network,
         }
     }
@@ -45,10 +45,23 @@ pub struct ExecutionResult {
     pub skipped_transactions: Vec<(TxId, Error)>,
     /// The status of the transactions execution included into the block.
     pub tx_status: Vec<TransactionExecutionStatus>,
+    /// The final values of the contract storage slots written by the block.
+    pub state_changes: Vec<ContractStateChange>,
+}
+
+/// The write to the contract storage slot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContractStateChange {
+    /// The id of the contract.
+    pub contract_id: ContractId,
+    /// The key of the storage slot.
+    pub key: Bytes32,
+    /// The value after the write. `None` if the slot is removed.
+    pub value: Option<Bytes32>,
 }
 
 /// The status of a transaction after it is executed.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TransactionExecutionStatus {
     /// The id of the transaction.
     pub id: Bytes32,
@@ -58,8 +71,22 @@ pub struct TransactionExecutionStatus {
     pub receipts: Vec<Receipt>,
 }
 
+/// The result of one transaction of the dry run bundle.
+#[derive(Debug)]
+pub enum DryRunBundleStep {
+    /// The transaction is executed, and its changes are visible to the next transactions.
+    Executed(TransactionExecutionStatus),
+    /// The transaction is invalid and skipped without affecting the state.
+    Skipped {
+        /// The id of the transaction.
+        id: TxId,
+        /// The reason why the transaction was skipped.
+        reason: Error,
+    },
+}
+
 /// The result of transaction execution.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TransactionExecutionResult {
     /// Transaction was successfully executed.
     Success {
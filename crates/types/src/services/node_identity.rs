@@ -0,0 +1,149 @@
+//! Types related to the identity document of the node.
+
+use crate::fuel_crypto::{
+    Message,
+    PublicKey,
+    SecretKey,
+    Signature,
+};
+
+/// The domain of the signed message. It prevents reusing the signature
+/// of the identity for other messages signed by the same key.
+const DOMAIN: &[u8] = b"FUEL_NODE_IDENTITY";
+
+/// The path of the signed identity of the node in the API.
+pub const NODE_IDENTITY_PATH: &str = "/.well-known/fuel-node-identity";
+
+/// The maximum difference in seconds between the time of the issue of the identity
+/// and the time of its verification. Older identities are rejected as replayed.
+pub const MAX_NODE_IDENTITY_AGE: u64 = 300;
+
+/// The information about the operator of the node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NodeIdentity {
+    /// The name of the operator of the node.
+    pub operator: String,
+    /// The contact of the operator, like an email or a website.
+    pub contact: String,
+    /// The name of the network served by the node.
+    pub network: String,
+    /// The public key of the node. The identity is signed by the corresponding secret key.
+    pub node_key: PublicKey,
+    /// The URL at which the identity is served.
+    pub url: String,
+    /// The time of the issue of the identity, as the Unix timestamp in seconds.
+    pub issued_at: u64,
+}
+
+impl NodeIdentity {
+    /// Returns the message signed by the node key.
+    pub fn message(&self) -> Message {
+        let mut bytes = DOMAIN.to_vec();
+        for field in [&self.operator, &self.contact, &self.network, &self.url] {
+            bytes.extend((field.len() as u64).to_be_bytes());
+            bytes.extend(field.as_bytes());
+        }
+        bytes.extend(self.node_key.as_ref());
+        bytes.extend(self.issued_at.to_be_bytes());
+        Message::new(bytes)
+    }
+
+    /// Signs the identity with the `secret_key`.
+    /// The node key of the identity is replaced with the public key of the secret.
+    pub fn sign(mut self, secret_key: &SecretKey) -> SignedNodeIdentity {
+        self.node_key = secret_key.public_key();
+        let signature = Signature::sign(secret_key, &self.message());
+        SignedNodeIdentity {
+            identity: self,
+            signature,
+        }
+    }
+}
+
+/// The identity of the node signed by the node key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SignedNodeIdentity {
+    /// The identity of the node.
+    pub identity: NodeIdentity,
+    /// The signature of the identity by the node key.
+    pub signature: Signature,
+}
+
+impl SignedNodeIdentity {
+    /// Verifies that the identity is signed by its node key.
+    pub fn verify(&self) -> Result<(), NodeIdentityError> {
+        self.signature
+            .verify(&self.identity.node_key, &self.identity.message())
+            .map_err(|_| NodeIdentityError::InvalidSignature)
+    }
+
+    /// Verifies the signature of the identity, that the node key, the network
+    /// and the URL are the expected ones, and that the identity was issued
+    /// within [`MAX_NODE_IDENTITY_AGE`] seconds of `now` (the Unix timestamp in seconds).
+    pub fn verify_expected(
+        &self,
+        node_key: &PublicKey,
+        network: &str,
+        url: &str,
+        now: u64,
+    ) -> Result<(), NodeIdentityError> {
+        self.verify()?;
+        if &self.identity.node_key != node_key {
+            return Err(NodeIdentityError::UnexpectedNodeKey)
+        }
+        if self.identity.network != network {
+            return Err(NodeIdentityError::UnexpectedNetwork {
+                expected: network.to_string(),
+                actual: self.identity.network.clone(),
+            })
+        }
+        if self.identity.url != url {
+            return Err(NodeIdentityError::UnexpectedUrl {
+                expected: url.to_string(),
+                actual: self.identity.url.clone(),
+            })
+        }
+        if self.identity.issued_at.abs_diff(now) > MAX_NODE_IDENTITY_AGE {
+            return Err(NodeIdentityError::Stale {
+                issued_at: self.identity.issued_at,
+            })
+        }
+        Ok(())
+    }
+}
+
+/// The error of the verification of the identity of the node.
+#[derive(thiserror::Error, Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum NodeIdentityError {
+    /// The identity is not signed by its node key.
+    #[error("The identity is not signed by its node key")]
+    InvalidSignature,
+    /// The node key differs from the expected one.
+    #[error("The node key differs from the expected one")]
+    UnexpectedNodeKey,
+    /// The network differs from the expected one.
+    #[error("The network {actual} differs from the expected {expected}")]
+    UnexpectedNetwork {
+        /// The expected network.
+        expected: String,
+        /// The network of the identity.
+        actual: String,
+    },
+    /// The URL differs from the expected one.
+    #[error("The URL {actual} differs from the expected {expected}")]
+    UnexpectedUrl {
+        /// The expected URL.
+        expected: String,
+        /// The URL of the identity.
+        actual: String,
+    },
+    /// The identity wasn't issued recently, and may be replayed.
+    #[error("The identity issued at {issued_at} is stale")]
+    Stale {
+        /// The time of the issue of the identity.
+        issued_at: u64,
+    },
+}
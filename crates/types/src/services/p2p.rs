@@ -1,7 +1,10 @@
 //! Contains types related to P2P data
 
 use crate::{
-    fuel_tx::Transaction,
+    fuel_tx::{
+        Transaction,
+        TxId,
+    },
     fuel_types::BlockHeight,
 };
 use std::{
@@ -12,7 +15,10 @@ use std::{
         Formatter,
     },
     str::FromStr,
-    time::SystemTime,
+    time::{
+        Duration,
+        SystemTime,
+    },
 };
 
 /// Contains types and logic for Peer Reputation
@@ -120,6 +126,26 @@ pub struct BlockHeightHeartbeatData {
     pub block_height: BlockHeight,
 }
 
+/// The compact identifier of the transaction used to exchange summaries of pools
+/// between peers. It is the prefix of the [`TxId`], so it is not unique, and
+/// the collision only means that the transaction is not pulled from the peer.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ShortTxId(pub [u8; Self::LEN]);
+
+impl ShortTxId {
+    /// The size of the short id in bytes.
+    pub const LEN: usize = 8;
+}
+
+impl From<&TxId> for ShortTxId {
+    fn from(tx_id: &TxId) -> Self {
+        let mut short_id = [0u8; Self::LEN];
+        short_id.copy_from_slice(&tx_id.as_ref()[..Self::LEN]);
+        Self(short_id)
+    }
+}
+
 /// Opaque peer identifier.
 #[derive(Default, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -190,3 +216,103 @@ pub struct HeartbeatData {
     /// The instant representing when the latest heartbeat was received.
     pub last_heartbeat: SystemTime,
 }
+
+/// The telemetry of the P2P network collected by the node.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct NetworkInfo {
+    /// The number of connected peers that dialed the node.
+    pub inbound_peers: u32,
+    /// The number of connected peers dialed by the node.
+    pub outbound_peers: u32,
+    /// The number of payload bytes exchanged with peers by each protocol.
+    pub bandwidth: Vec<ProtocolBandwidth>,
+    /// The peers in the gossipsub mesh of each subscribed topic.
+    pub mesh: Vec<TopicMesh>,
+    /// The delays between the first announcement of the block height
+    /// in the network and the import of the block by the node.
+    pub block_propagation: LatencyHistogram,
+    /// The delays between the receipt of the gossiped transaction and
+    /// its forwarding to peers. Rejected and ignored transactions aren't accounted.
+    pub tx_propagation: LatencyHistogram,
+}
+
+/// The protocol of the P2P network.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NetworkProtocol {
+    /// The gossiping of new transactions.
+    Gossipsub,
+    /// The requests of blocks and transactions from peers.
+    RequestResponse,
+}
+
+/// The number of bytes exchanged with peers by the protocol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProtocolBandwidth {
+    /// The protocol.
+    pub protocol: NetworkProtocol,
+    /// The number of bytes received from peers.
+    pub inbound_bytes: u64,
+    /// The number of bytes sent to peers.
+    pub outbound_bytes: u64,
+}
+
+/// The gossipsub mesh of the topic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TopicMesh {
+    /// The name of the topic.
+    pub topic: String,
+    /// The peers in the mesh of the topic.
+    pub peers: Vec<PeerId>,
+}
+
+/// The histogram of latencies with cumulative buckets.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct LatencyHistogram {
+    /// The number of observed latencies.
+    pub count: u64,
+    /// The sum of observed latencies.
+    pub sum: Duration,
+    /// The buckets in ascending order of their upper bounds.
+    pub buckets: Vec<LatencyBucket>,
+}
+
+/// The bucket of the latency histogram.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LatencyBucket {
+    /// The inclusive upper bound of latencies in the bucket.
+    pub upper_bound: Duration,
+    /// The number of observed latencies less than or equal to the upper bound.
+    pub count: u64,
+}
+
+impl LatencyHistogram {
+    /// Creates an empty histogram with buckets of `upper_bounds`.
+    pub fn with_bounds(upper_bounds: impl IntoIterator<Item = Duration>) -> Self {
+        let mut buckets: Vec<_> = upper_bounds
+            .into_iter()
+            .map(|upper_bound| LatencyBucket {
+                upper_bound,
+                count: 0,
+            })
+            .collect();
+        buckets.sort_by_key(|bucket| bucket.upper_bound);
+        Self {
+            count: 0,
+            sum: Duration::ZERO,
+            buckets,
+        }
+    }
+
+    /// Adds the `latency` to the histogram.
+    pub fn observe(&mut self, latency: Duration) {
+        self.count = self.count.saturating_add(1);
+        self.sum = self.sum.saturating_add(latency);
+        for bucket in self
+            .buckets
+            .iter_mut()
+            .filter(|bucket| latency <= bucket.upper_bound)
+        {
+            bucket.count = bucket.count.saturating_add(1);
+        }
+    }
+}
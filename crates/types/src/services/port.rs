@@ -0,0 +1,89 @@
+//! The common error of ports between services. Each error has a category, so the caller
+//! can decide whether to retry the call without knowing the implementation of the port.
+
+/// The error returned by ports of services.
+#[derive(thiserror::Error, Debug)]
+pub enum PortError {
+    /// The failure is temporary, and the same call may succeed later.
+    /// For example, the peer didn't respond, or the queue of the service is full.
+    #[error("Transient failure: {0:#}")]
+    Transient(anyhow::Error),
+    /// The input of the call is invalid. The same call fails again.
+    #[error("Invalid input: {0:#}")]
+    InvalidInput(anyhow::Error),
+    /// The service behind the port can't process calls anymore,
+    /// for example, because it was stopped.
+    #[error("Fatal failure: {0:#}")]
+    Fatal(anyhow::Error),
+}
+
+impl PortError {
+    /// Creates the [`PortError::Transient`] error.
+    pub fn transient<E>(error: E) -> Self
+    where
+        E: Into<anyhow::Error>,
+    {
+        Self::Transient(error.into())
+    }
+
+    /// Creates the [`PortError::InvalidInput`] error.
+    pub fn invalid_input<E>(error: E) -> Self
+    where
+        E: Into<anyhow::Error>,
+    {
+        Self::InvalidInput(error.into())
+    }
+
+    /// Creates the [`PortError::Fatal`] error.
+    pub fn fatal<E>(error: E) -> Self
+    where
+        E: Into<anyhow::Error>,
+    {
+        Self::Fatal(error.into())
+    }
+
+    /// Returns `true` if the same call may succeed later.
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, Self::Transient(_))
+    }
+
+    /// Returns the underlying error.
+    pub fn into_inner(self) -> anyhow::Error {
+        match self {
+            Self::Transient(error) | Self::InvalidInput(error) | Self::Fatal(error) => {
+                error
+            }
+        }
+    }
+}
+
+/// The result of the call to the port.
+pub type PortResult<T> = Result<T, PortError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_transient_errors_are_retryable() {
+        assert!(PortError::transient(anyhow::anyhow!("timeout")).is_retryable());
+        assert!(!PortError::invalid_input(anyhow::anyhow!("empty range")).is_retryable());
+        assert!(!PortError::fatal(anyhow::anyhow!("stopped")).is_retryable());
+    }
+
+    #[test]
+    fn error_is_displayed_with_its_category() {
+        let error = PortError::fatal(anyhow::anyhow!("The service is stopped"));
+        assert_eq!(error.to_string(), "Fatal failure: The service is stopped");
+    }
+
+    #[test]
+    fn category_is_preserved_through_anyhow() {
+        let error: anyhow::Error =
+            PortError::transient(anyhow::anyhow!("timeout")).into();
+        assert!(matches!(
+            error.downcast_ref::<PortError>(),
+            Some(PortError::Transient(_))
+        ));
+    }
+}
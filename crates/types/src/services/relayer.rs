@@ -3,6 +3,7 @@
 use crate::{
     blockchain::primitives::DaBlockHeight,
     entities::message::Message,
+    fuel_types::Bytes32,
 };
 
 /// The event that may come from the relayer.
@@ -27,3 +28,14 @@ impl From<Message> for Event {
         Event::Message(message)
     }
 }
+
+/// The DA block that was synced by the relayer. The relayer uses checkpoints
+/// to detect reorganizations of the DA layer.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SyncCheckpoint {
+    /// The height of the synced DA block.
+    pub height: DaBlockHeight,
+    /// The hash of the synced DA block.
+    pub hash: Bytes32,
+}
@@ -6,6 +6,7 @@ use crate::{
         primitives::BlockId,
     },
     fuel_asm::Word,
+    fuel_crypto::Hasher,
     fuel_tx::{
         field::{
             Inputs,
@@ -30,7 +31,10 @@ use crate::{
         checked_transaction::Checked,
         ProgramState,
     },
-    services::executor::TransactionExecutionResult,
+    services::{
+        executor::TransactionExecutionResult,
+        p2p::PeerId,
+    },
 };
 use fuel_vm_private::checked_transaction::CheckedTransaction;
 use std::{
@@ -167,6 +171,55 @@ pub struct InsertionResult {
     pub removed: Vec<ArcPoolTx>,
 }
 
+/// The origin of the transaction in the tx pool.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TxOrigin {
+    /// Submitted through the API of the node.
+    Api {
+        /// The API key of the client, if it was provided.
+        api_key: Option<String>,
+    },
+    /// Received from the peer by the gossip or the pull of its pool.
+    Peer(PeerId),
+    /// Inserted by the node itself, bypassing the API and the network.
+    Injected,
+}
+
+impl TxOrigin {
+    /// The kind of the origin without the identity of the sender:
+    /// `api`, `p2p` or `injected`.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            TxOrigin::Api { .. } => "api",
+            TxOrigin::Peer(_) => "p2p",
+            TxOrigin::Injected => "injected",
+        }
+    }
+
+    /// The identity of the origin that is safe to expose in metrics and dumps:
+    /// `api:<hash>`, `api:anonymous`, `p2p:<peer id>` or `injected`.
+    /// The API key is the secret of the client, so only the prefix of its hash is used.
+    pub fn label(&self) -> String {
+        match self {
+            TxOrigin::Api {
+                api_key: Some(api_key),
+            } => {
+                let hash = Hasher::hash(api_key.as_bytes());
+                let prefix: String = hash
+                    .iter()
+                    .take(8)
+                    .map(|byte| format!("{byte:02x}"))
+                    .collect();
+                format!("api:{prefix}")
+            }
+            TxOrigin::Api { api_key: None } => "api:anonymous".to_string(),
+            TxOrigin::Peer(peer_id) => format!("p2p:{peer_id}"),
+            TxOrigin::Injected => "injected".to_string(),
+        }
+    }
+}
+
 /// The status of the transaction during its life from the tx pool until the block.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -175,6 +228,8 @@ pub enum TransactionStatus {
     Submitted {
         /// Timestamp of submission into the txpool
         time: Tai64,
+        /// The origin of the transaction
+        origin: TxOrigin,
     },
     /// Transaction was successfully included in a block
     Success {
@@ -241,6 +296,8 @@ pub enum Error {
     NotInsertedLimitHit,
     #[error("Transaction is not inserted. The gas price is too low.")]
     NotInsertedGasPriceTooLow,
+    #[error("Transaction is not inserted. The insertion into the pool is paused.")]
+    NotInsertedPoolPaused,
     #[error(
         "Transaction is not inserted. More priced tx {0:#x} already spend this UTXO output: {1:#x}"
     )]
@@ -296,6 +353,8 @@ pub enum Error {
     // small todo for now it can pass but in future we should include better messages
     #[error("Transaction removed.")]
     Removed,
+    #[error("Transaction removed because the pool was flushed.")]
+    Flushed,
     #[error("Transaction expired because it exceeded the configured time to live `tx-pool-ttl`.")]
     TTLReason,
     #[error("Transaction squeezed out because {0}")]
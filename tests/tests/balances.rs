@@ -239,3 +239,56 @@ async fn first_5_balances() {
         assert_eq!(balances[i].amount, 300);
     }
 }
+
+#[tokio::test]
+async fn balances_reports_overflow_of_amount() {
+    let owner = Address::default();
+    let asset_id = AssetId::BASE;
+
+    // The coin and the message together don't fit into `u64`.
+    let mut config = Config::local_node();
+    config.chain_conf.initial_state = Some(StateConfig {
+        height: None,
+        contracts: None,
+        coins: Some(vec![CoinConfig {
+            tx_id: None,
+            output_index: None,
+            tx_pointer_block_height: None,
+            tx_pointer_tx_idx: None,
+            maturity: None,
+            owner,
+            amount: u64::MAX,
+            asset_id,
+        }]),
+        messages: Some(vec![MessageConfig {
+            sender: owner,
+            recipient: owner,
+            nonce: 0u64.into(),
+            amount: 1,
+            data: vec![],
+            da_height: DaBlockHeight::from(1usize),
+        }]),
+    });
+
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let error = client
+        .balances(
+            &owner,
+            PaginationRequest {
+                cursor: None,
+                results: 5,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .expect_err("The balance overflows `u64`");
+    assert!(error.to_string().contains("the amount overflowed"));
+
+    let error = client
+        .balance(&owner, Some(&asset_id))
+        .await
+        .expect_err("The balance overflows `u64`");
+    assert!(error.to_string().contains("the amount overflowed"));
+}
@@ -1,3 +1,7 @@
+use crate::helpers::{
+    TestContext,
+    TestSetupBuilder,
+};
 use fuel_core::{
     database::Database,
     service::{
@@ -170,6 +174,22 @@ async fn produce_block_negative() {
     );
 }
 
+#[tokio::test]
+async fn harness_produces_blocks_without_debug_endpoint() {
+    let mut test_builder = TestSetupBuilder::new(2322);
+    test_builder.trigger = Trigger::Never;
+    let config = Config {
+        debug: false,
+        ..test_builder.config()
+    };
+    let ctx = TestContext::start(config, test_builder.rng.clone()).await;
+
+    ctx.produce_blocks(3).await.unwrap();
+
+    let chain_info = ctx.client.chain_info().await.unwrap();
+    assert_eq!(chain_info.latest_block.header.height, 3);
+}
+
 #[tokio::test]
 async fn produce_block_custom_time() {
     let db = Database::default();
@@ -1,8 +1,16 @@
-use fuel_core::service::{
-    Config,
-    FuelService,
+use fuel_core::{
+    chain_config::ConsensusParametersUpgrade,
+    service::{
+        Config,
+        FuelService,
+    },
 };
 use fuel_core_client::client::FuelClient;
+use fuel_core_types::fuel_tx::{
+    ConsensusParameters,
+    TransactionBuilder,
+    TxParameters,
+};
 
 #[tokio::test]
 async fn chain_info() {
@@ -24,3 +32,100 @@ async fn chain_info() {
         chain_info.consensus_parameters.gas_costs
     );
 }
+
+#[tokio::test]
+async fn consensus_parameters_by_height() {
+    let mut node_config = Config::local_node();
+    let upgraded = ConsensusParameters {
+        tx_params: TxParameters {
+            max_inputs: 1,
+            ..TxParameters::DEFAULT
+        },
+        ..node_config.chain_conf.consensus_parameters.clone()
+    };
+    node_config.chain_conf.consensus_parameters_upgrades =
+        vec![ConsensusParametersUpgrade {
+            height: 10u32.into(),
+            consensus_parameters: upgraded.clone(),
+        }];
+    let srv = FuelService::new_node(node_config.clone()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let genesis = client.consensus_parameters(9u32.into()).await.unwrap();
+    assert_eq!(node_config.chain_conf.consensus_parameters, genesis);
+
+    let after_upgrade = client.consensus_parameters(10u32.into()).await.unwrap();
+    assert_eq!(upgraded, after_upgrade);
+}
+
+#[tokio::test]
+async fn chain_info_returns_consensus_parameters_of_latest_block() {
+    let mut node_config = Config::local_node();
+    let upgraded = ConsensusParameters {
+        tx_params: TxParameters {
+            max_inputs: 1,
+            ..TxParameters::DEFAULT
+        },
+        ..node_config.chain_conf.consensus_parameters.clone()
+    };
+    node_config.chain_conf.consensus_parameters_upgrades =
+        vec![ConsensusParametersUpgrade {
+            height: 2u32.into(),
+            consensus_parameters: upgraded.clone(),
+        }];
+    let srv = FuelService::new_node(node_config.clone()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    client.produce_blocks(1, None).await.unwrap();
+    let chain_info = client.chain_info().await.unwrap();
+    assert_eq!(
+        node_config.chain_conf.consensus_parameters,
+        chain_info.consensus_parameters
+    );
+
+    client.produce_blocks(1, None).await.unwrap();
+    let chain_info = client.chain_info().await.unwrap();
+    assert_eq!(upgraded, chain_info.consensus_parameters);
+}
+
+#[tokio::test]
+async fn node_enforces_consensus_parameters_reported_by_height() {
+    let mut node_config = Config::local_node();
+    let upgraded = ConsensusParameters {
+        tx_params: TxParameters {
+            max_inputs: 1,
+            ..TxParameters::DEFAULT
+        },
+        ..node_config.chain_conf.consensus_parameters.clone()
+    };
+    node_config.chain_conf.consensus_parameters_upgrades =
+        vec![ConsensusParametersUpgrade {
+            height: 2u32.into(),
+            consensus_parameters: upgraded.clone(),
+        }];
+    let srv = FuelService::new_node(node_config.clone()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let tx_with_two_inputs = || {
+        TransactionBuilder::script(vec![], vec![])
+            .script_gas_limit(10_000)
+            .add_random_fee_input()
+            .add_random_fee_input()
+            .finalize_as_transaction()
+    };
+
+    // The first block uses the genesis parameters.
+    let genesis = client.consensus_parameters(1u32.into()).await.unwrap();
+    assert_eq!(node_config.chain_conf.consensus_parameters, genesis);
+    client
+        .submit_and_await_commit(&tx_with_two_inputs())
+        .await
+        .expect("Two inputs are allowed by the genesis parameters");
+
+    // The second block uses the upgraded parameters.
+    let after_upgrade = client.consensus_parameters(2u32.into()).await.unwrap();
+    assert_eq!(upgraded, after_upgrade);
+    client
+        .submit(&tx_with_two_inputs())
+        .await
+        .expect_err("Only one input is allowed by the upgraded parameters");
+}
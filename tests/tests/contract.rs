@@ -296,3 +296,61 @@ async fn can_get_message_proof() {
     assert_eq!(log[1].rb().unwrap(), 1);
     assert_eq!(logd.data().unwrap(), db_data);
 }
+
+#[tokio::test]
+async fn contract_state_diff_returns_slots_written_by_block() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Deploy the contract with the initial storage slots.
+    let bytecode: Witness = vec![op::ret(RegId::ONE)]
+        .into_iter()
+        .collect::<Vec<u8>>()
+        .into();
+    let salt = Salt::zeroed();
+    let slots = vec![
+        StorageSlot::new(key(1), key(10)),
+        StorageSlot::new(key(2), key(20)),
+    ];
+    let contract = Contract::from(bytecode.as_ref());
+    let root = contract.root();
+    let state_root = Contract::initial_state_root(slots.iter());
+    let id = contract.id(&salt, &root, &state_root);
+    let contract_deploy = TransactionBuilder::create(bytecode, salt, slots)
+        .add_random_fee_input()
+        .add_output(Output::contract_created(id, state_root))
+        .finalize_as_transaction();
+    let status = client
+        .submit_and_await_commit(&contract_deploy)
+        .await
+        .unwrap();
+    assert!(matches!(status, TransactionStatus::Success { .. }));
+    let height = client
+        .chain_info()
+        .await
+        .unwrap()
+        .latest_block
+        .header
+        .height;
+
+    let changes = client
+        .contract_state_diff(&id, 0u32.into(), height.into())
+        .await
+        .unwrap();
+
+    assert_eq!(
+        changes
+            .into_iter()
+            .map(|change| (change.block_height, change.key, change.value))
+            .collect::<Vec<_>>(),
+        vec![
+            (height, key(1), Some(key(10))),
+            (height, key(2), Some(key(20))),
+        ]
+    );
+    let changes = client
+        .contract_state_diff(&id, 0u32.into(), 0u32.into())
+        .await
+        .unwrap();
+    assert!(changes.is_empty());
+}
@@ -4,8 +4,21 @@ use fuel_core::service::{
 };
 use fuel_core_client::client::FuelClient;
 use fuel_core_types::{
-    fuel_tx::Transaction,
-    fuel_types::ContractId,
+    fuel_asm::{
+        op,
+        RegId,
+    },
+    fuel_tx::{
+        Receipt,
+        Transaction,
+        TransactionBuilder,
+        UniqueIdentifier,
+    },
+    fuel_types::{
+        ChainId,
+        ContractId,
+    },
+    fuel_vm::consts::VM_REGISTER_COUNT,
 };
 
 /// Tests that debugger doesn't produce any errors with a running local node,
@@ -44,3 +57,131 @@ async fn debugger_integration() {
     let result = client.end_session(session_id).await.unwrap();
     assert!(result);
 }
+
+#[tokio::test]
+async fn debugger_steps_over_breakpoints() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let session = client.start_session().await.unwrap();
+    let session_id = session.as_str();
+
+    let registers = client.registers(session_id).await.unwrap();
+    assert_eq!(registers.len(), VM_REGISTER_COUNT);
+
+    client
+        .set_breakpoint(session_id, ContractId::zeroed(), 0)
+        .await
+        .unwrap();
+    client
+        .set_breakpoint(session_id, ContractId::zeroed(), 4)
+        .await
+        .unwrap();
+    client
+        .remove_breakpoint(session_id, ContractId::zeroed(), 4)
+        .await
+        .unwrap();
+
+    let tx: Transaction = serde_json::from_str(include_str!("example_tx.json"))
+        .expect("Invalid transaction JSON");
+    let status = client.start_tx(session_id, &tx).await.unwrap();
+    assert!(status.breakpoint.is_some());
+
+    // The step stops at the next instruction without the single-stepping mode.
+    let status = client.step_tx(session_id).await.unwrap();
+    assert!(status.breakpoint.is_some());
+
+    // The removed breakpoint doesn't stop the execution.
+    let status = client.continue_tx(session_id).await.unwrap();
+    assert!(status.breakpoint.is_none());
+
+    let result = client.end_session(session_id).await.unwrap();
+    assert!(result);
+}
+
+#[tokio::test]
+async fn trace_transaction_returns_executed_instructions() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let script = vec![op::addi(0x10, RegId::ZERO, 0xca), op::ret(0x10)]
+        .into_iter()
+        .collect::<Vec<u8>>();
+    let tx = TransactionBuilder::script(script, vec![])
+        .script_gas_limit(1_000_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+    client.submit_and_await_commit(&tx).await.unwrap();
+
+    let trace = client
+        .trace_transaction(&tx.id(&ChainId::default()), None)
+        .await
+        .unwrap();
+
+    assert!(!trace.truncated);
+    let opcodes: Vec<_> = trace
+        .steps
+        .iter()
+        .map(|step| step.opcode.clone().unwrap())
+        .collect();
+    assert_eq!(opcodes, vec!["ADDI", "RET"]);
+    // The register is set by the first instruction.
+    assert_eq!(trace.steps[0].registers[0x10].0, 0);
+    assert_eq!(trace.steps[1].registers[0x10].0, 0xca);
+    assert!(trace
+        .receipts()
+        .any(|receipt| matches!(receipt, Receipt::Return { val: 0xca, .. })));
+}
+
+#[tokio::test]
+async fn trace_transaction_is_truncated_to_max_steps() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let script = vec![op::noop(), op::noop(), op::noop(), op::ret(RegId::ONE)]
+        .into_iter()
+        .collect::<Vec<u8>>();
+    let tx = TransactionBuilder::script(script, vec![])
+        .script_gas_limit(1_000_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+    client.submit_and_await_commit(&tx).await.unwrap();
+
+    let trace = client
+        .trace_transaction(&tx.id(&ChainId::default()), Some(2))
+        .await
+        .unwrap();
+
+    assert!(trace.truncated);
+    assert_eq!(trace.steps.len(), 2);
+}
+
+#[tokio::test]
+async fn trace_transaction_fails_when_the_state_is_evicted_from_the_history() {
+    let config = Config {
+        state_history_blocks: 1,
+        ..Config::local_node()
+    };
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let script = vec![op::ret(RegId::ONE)].into_iter().collect::<Vec<u8>>();
+    let mut txs = vec![];
+    for _ in 0..3 {
+        let tx = TransactionBuilder::script(script.clone(), vec![])
+            .script_gas_limit(1_000_000)
+            .add_random_fee_input()
+            .finalize_as_transaction();
+        client.submit_and_await_commit(&tx).await.unwrap();
+        txs.push(tx);
+    }
+
+    // The state before the block of the oldest transaction isn't kept anymore.
+    let result = client
+        .trace_transaction(&txs[0].id(&ChainId::default()), None)
+        .await;
+    assert!(result.is_err());
+
+    let trace = client
+        .trace_transaction(&txs[2].id(&ChainId::default()), None)
+        .await
+        .unwrap();
+    assert_eq!(trace.steps.len(), 1);
+}
@@ -0,0 +1,137 @@
+use fuel_core::service::{
+    config::RateLimitConfig,
+    Config,
+    FuelService,
+};
+use fuel_core_client::client::FuelClient;
+use reqwest::StatusCode;
+use serde_json::{
+    json,
+    Value,
+};
+
+async fn post_query(srv: &FuelService, query: &str) -> (StatusCode, Value) {
+    let response = reqwest::Client::new()
+        .post(format!("http://{}/graphql", srv.bound_address))
+        .json(&json!({ "query": query }))
+        .send()
+        .await
+        .unwrap();
+    let status = response.status();
+    (status, response.json().await.unwrap())
+}
+
+#[tokio::test]
+async fn too_deep_query_is_rejected() {
+    let mut config = Config::local_node();
+    config.graphql_max_depth = 3;
+    let srv = FuelService::new_node(config).await.unwrap();
+
+    let (_, shallow) = post_query(&srv, "{ chain { latestBlock { id } } }").await;
+    assert!(shallow.get("errors").is_none(), "{shallow}");
+
+    let (_, deep) =
+        post_query(&srv, "{ chain { latestBlock { header { height } } } }").await;
+    assert_eq!(deep["errors"][0]["message"], "Query is nested too deep.");
+}
+
+#[tokio::test]
+async fn too_complex_query_is_rejected() {
+    let mut config = Config::local_node();
+    config.graphql_max_complexity = 2;
+    let srv = FuelService::new_node(config).await.unwrap();
+
+    let (_, response) =
+        post_query(&srv, "{ chain { name daHeight latestBlock { id } } }").await;
+
+    assert_eq!(response["errors"][0]["message"], "Query is too complex.");
+}
+
+#[tokio::test]
+async fn requests_above_rate_limit_are_throttled() {
+    let mut config = Config::local_node();
+    config.graphql_rate_limit = Some(RateLimitConfig {
+        requests_per_second: 1.try_into().unwrap(),
+        burst: 2.try_into().unwrap(),
+    });
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    client.chain_info().await.unwrap();
+    client.chain_info().await.unwrap();
+    let (status, response) = post_query(&srv, "{ chain { name } }").await;
+
+    assert_eq!(status, StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(response["errors"][0]["extensions"]["code"], "THROTTLED");
+    assert_eq!(response["errors"][0]["extensions"]["retryAfter"], 1);
+}
+
+async fn post_batch(srv: &FuelService, queries: &[&str]) -> (StatusCode, Value) {
+    let batch: Vec<_> = queries
+        .iter()
+        .map(|query| json!({ "query": query }))
+        .collect();
+    let response = reqwest::Client::new()
+        .post(format!("http://{}/graphql", srv.bound_address))
+        .json(&batch)
+        .send()
+        .await
+        .unwrap();
+    let status = response.status();
+    (status, response.json().await.unwrap())
+}
+
+#[tokio::test]
+async fn batched_queries_are_executed_in_order() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+
+    let (status, response) = post_batch(
+        &srv,
+        &["{ chain { name } }", "{ nodeInfo { utxoValidation } }"],
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    let responses = response.as_array().expect("The batch response is an array");
+    assert_eq!(responses.len(), 2);
+    assert!(
+        responses[0]["data"]["chain"]["name"].is_string(),
+        "{response}"
+    );
+    assert!(
+        responses[1]["data"]["nodeInfo"]["utxoValidation"].is_boolean(),
+        "{response}"
+    );
+}
+
+#[tokio::test]
+async fn too_large_batch_is_rejected() {
+    let mut config = Config::local_node();
+    config.graphql_max_batch_size = 2;
+    let srv = FuelService::new_node(config).await.unwrap();
+
+    let (status, response) = post_batch(&srv, &["{ chain { name } }"; 3]).await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(
+        response["errors"][0]["message"],
+        "The batch has 3 operations, but at most 2 are allowed"
+    );
+}
+
+#[tokio::test]
+async fn each_batched_query_is_counted_by_rate_limit() {
+    let mut config = Config::local_node();
+    config.graphql_rate_limit = Some(RateLimitConfig {
+        requests_per_second: 1.try_into().unwrap(),
+        burst: 3.try_into().unwrap(),
+    });
+    let srv = FuelService::new_node(config).await.unwrap();
+
+    let (status, _) = post_batch(&srv, &["{ chain { name } }"; 2]).await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, response) = post_batch(&srv, &["{ chain { name } }"; 2]).await;
+
+    assert_eq!(status, StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(response["errors"][0]["extensions"]["code"], "THROTTLED");
+}
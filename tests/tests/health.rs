@@ -6,6 +6,10 @@ use fuel_core::{
     },
 };
 use fuel_core_client::client::FuelClient;
+use std::{
+    net::SocketAddr,
+    time::Duration,
+};
 
 #[tokio::test]
 async fn health() {
@@ -18,6 +22,86 @@ async fn health() {
     assert!(health);
 }
 
+async fn get_health(
+    address: SocketAddr,
+    path: &str,
+) -> (reqwest::StatusCode, serde_json::Value) {
+    let response = reqwest::get(format!("http://{address}{path}"))
+        .await
+        .unwrap();
+    let status = response.status();
+    let body = response.text().await.unwrap();
+    (status, serde_json::from_str(&body).unwrap())
+}
+
+#[tokio::test]
+async fn readiness_reports_status_of_services() {
+    let srv = FuelService::from_database(Database::default(), Config::local_node())
+        .await
+        .unwrap();
+
+    let (status, body) = get_health(srv.bound_address, "/v1/readiness").await;
+
+    assert_eq!(status, reqwest::StatusCode::OK);
+    assert_eq!(body["up"], true);
+    assert_eq!(body["services"]["txpool"]["alive"], true);
+    assert_eq!(body["services"]["importer"]["ready"], true);
+    assert!(body["services"]["importer"]["error"].is_null());
+}
+
+#[tokio::test]
+async fn readiness_fails_if_node_does_not_import_blocks_within_stall_timeout() {
+    let mut config = Config::local_node();
+    config.health_stall_timeout = Some(Duration::from_millis(100));
+    let srv = FuelService::from_database(Database::default(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    // The node is alive, but stalled.
+    let (status, _) = get_health(srv.bound_address, "/v1/health").await;
+    assert_eq!(status, reqwest::StatusCode::OK);
+    let (status, body) = get_health(srv.bound_address, "/v1/readiness").await;
+    assert_eq!(status, reqwest::StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(body["services"]["importer"]["stalled"], true);
+
+    // The import of the block is the progress.
+    client.produce_blocks(1, None).await.unwrap();
+    let (status, body) = get_health(srv.bound_address, "/v1/readiness").await;
+    assert_eq!(status, reqwest::StatusCode::OK);
+    assert!(body["services"]["importer"]["last_progress"].is_u64());
+}
+
+#[tokio::test]
+async fn maintenance_reports_scheduled_tasks() {
+    let mut config = Config::local_node();
+    config.maintenance = vec!["0 3 * * * backup /var/backups/fuel".parse().unwrap()];
+    config.graphql_api_keys = vec!["operator".to_string()];
+    let srv = FuelService::from_database(Database::default(), config)
+        .await
+        .unwrap();
+    let url = format!("http://{}/v1/maintenance", srv.bound_address);
+
+    let response = reqwest::get(&url).await.unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::UNAUTHORIZED);
+
+    let response = reqwest::Client::new()
+        .get(&url)
+        .header("x-api-key", "operator")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+    let body: serde_json::Value = response.json().await.unwrap();
+    let task = &body["tasks"][0];
+    // The paths of the node are not revealed.
+    assert_eq!(task["entry"], "0 3 * * * backup");
+    assert_eq!(task["running"], false);
+    assert!(task["next_run"].is_u64());
+    assert!(task["last_started"].is_null());
+}
+
 #[cfg(feature = "default")]
 #[tokio::test]
 async fn can_restart_node() {
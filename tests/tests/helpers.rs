@@ -1,4 +1,4 @@
-pub use test_helpers::builder::{
+pub use fuel_core_test_harness::builder::{
     TestContext,
     TestSetupBuilder,
 };
@@ -11,14 +11,17 @@ mod dap;
 mod debugger;
 mod deployment;
 mod fee_collection_contract;
+mod graphql_limits;
 mod health;
 mod helpers;
 mod messages;
 mod metrics;
 mod node_info;
 mod poa;
+mod read_only;
 #[cfg(feature = "relayer")]
 mod relayer;
+mod replication;
 mod snapshot;
 #[cfg(feature = "p2p")]
 mod sync;
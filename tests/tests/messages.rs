@@ -566,6 +566,27 @@ async fn can_get_message_proof() {
                 &block_proof_set,
                 blocks_count as u64,
             ));
+
+            // 5. Verify the same proofs with the light client.
+            let message_block_header =
+                fuel_core_light::BlockHeader::from(&result.message_block_header);
+            let commit_block_header =
+                fuel_core_light::BlockHeader::from(&result.commit_block_header);
+            let message_block_id =
+                fuel_core_light::verify_header(&message_block_header).unwrap();
+            fuel_core_light::verify_header(&commit_block_header).unwrap();
+            fuel_core_light::verify_message_proof(
+                &generated_message_id,
+                &message_block_header,
+                &(&result.message_proof).into(),
+            )
+            .unwrap();
+            fuel_core_light::verify_block_proof(
+                &message_block_id,
+                &commit_block_header,
+                &(&result.block_proof).into(),
+            )
+            .unwrap();
         }
     }
 }
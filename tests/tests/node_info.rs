@@ -1,11 +1,26 @@
-use fuel_core::service::{
-    Config,
-    FuelService,
+use fuel_core::{
+    chain_config::default_consensus_dev_key,
+    service::{
+        Config,
+        FuelService,
+        NodeIdentityConfig,
+    },
 };
 use fuel_core_client::client::{
-    types::NodeInfo,
+    types::{
+        NodeInfo,
+        NodeMode,
+    },
     FuelClient,
 };
+use fuel_core_types::{
+    fuel_vm::SecretKey,
+    secrecy::Secret,
+};
+use rand::{
+    rngs::StdRng,
+    SeedableRng,
+};
 
 #[tokio::test]
 async fn node_info() {
@@ -29,6 +44,79 @@ async fn node_info() {
     assert_eq!(max_tx, node_config.txpool.max_tx as u64);
 }
 
+#[tokio::test]
+async fn node_identity_is_signed_by_node_key() {
+    let key = default_consensus_dev_key();
+    let mut node_config = Config::local_node();
+    node_config.node_identity = Some(NodeIdentityConfig {
+        operator: "Fuel Labs".to_string(),
+        contact: "ops@fuel.network".to_string(),
+        public_url: None,
+        key: Secret::new(key.into()),
+    });
+    let srv = FuelService::new_node(node_config.clone()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let identity = client
+        .verified_node_identity(&key.public_key(), &node_config.chain_conf.chain_name)
+        .await
+        .unwrap();
+
+    assert_eq!(identity.operator, "Fuel Labs");
+    assert_eq!(identity.contact, "ops@fuel.network");
+}
+
+#[tokio::test]
+async fn node_identity_fails_verification_with_unexpected_key() {
+    let key = default_consensus_dev_key();
+    let mut node_config = Config::local_node();
+    node_config.node_identity = Some(NodeIdentityConfig {
+        operator: "Fuel Labs".to_string(),
+        contact: "ops@fuel.network".to_string(),
+        public_url: None,
+        key: Secret::new(key.into()),
+    });
+    let srv = FuelService::new_node(node_config.clone()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let other_key = SecretKey::random(&mut StdRng::seed_from_u64(2322)).public_key();
+
+    let result = client
+        .verified_node_identity(&other_key, &node_config.chain_conf.chain_name)
+        .await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn node_identity_fails_verification_for_another_url() {
+    let key = default_consensus_dev_key();
+    let mut node_config = Config::local_node();
+    node_config.node_identity = Some(NodeIdentityConfig {
+        operator: "Fuel Labs".to_string(),
+        contact: "ops@fuel.network".to_string(),
+        public_url: Some("https://node.example.com".to_string()),
+        key: Secret::new(key.into()),
+    });
+    let srv = FuelService::new_node(node_config.clone()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let result = client
+        .verified_node_identity(&key.public_key(), &node_config.chain_conf.chain_name)
+        .await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn node_identity_is_not_served_by_default() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let identity = client.node_identity().await.unwrap();
+
+    assert_eq!(identity, None);
+}
+
 #[cfg(feature = "p2p")]
 #[tokio::test(flavor = "multi_thread")]
 async fn test_peer_info() {
@@ -39,14 +127,7 @@ async fn test_peer_info() {
         ProducerSetup,
         ValidatorSetup,
     };
-    use fuel_core_types::{
-        fuel_tx::Input,
-        fuel_vm::SecretKey,
-    };
-    use rand::{
-        rngs::StdRng,
-        SeedableRng,
-    };
+    use fuel_core_types::fuel_tx::Input;
     use std::time::Duration;
 
     let mut rng = StdRng::seed_from_u64(line!() as u64);
@@ -105,3 +186,89 @@ async fn test_peer_info() {
         .unwrap();
     assert!(time_since_heartbeat < Duration::from_secs(10));
 }
+
+#[tokio::test]
+async fn node_info_reports_single_node_mode() {
+    let mut node_config = Config::local_node();
+    node_config.single_node = true;
+    #[cfg(feature = "p2p")]
+    {
+        node_config.p2p = None;
+    }
+    let srv = FuelService::new_node(node_config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let node_info = client.node_info().await.unwrap();
+
+    assert_eq!(node_info.node_mode, NodeMode::SingleNode);
+}
+
+#[tokio::test]
+async fn network_info_is_none_without_p2p() {
+    let mut node_config = Config::local_node();
+    #[cfg(feature = "p2p")]
+    {
+        node_config.p2p = None;
+    }
+    let srv = FuelService::new_node(node_config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let network_info = client.network_info().await.unwrap();
+
+    assert_eq!(network_info, None);
+}
+
+#[cfg(feature = "p2p")]
+#[tokio::test(flavor = "multi_thread")]
+async fn network_info_reports_telemetry_of_connected_peers() {
+    use fuel_core::p2p_test_helpers::{
+        make_nodes,
+        BootstrapSetup,
+        Nodes,
+        ProducerSetup,
+        ValidatorSetup,
+    };
+    use fuel_core_types::{
+        fuel_tx::Input,
+        services::p2p::NetworkProtocol,
+    };
+
+    let mut rng = StdRng::seed_from_u64(line!() as u64);
+
+    let secret = SecretKey::random(&mut rng);
+    let pub_key = Input::owner(&secret.public_key());
+    let Nodes {
+        mut producers,
+        mut validators,
+        bootstrap_nodes: _dont_drop,
+    } = make_nodes(
+        [Some(BootstrapSetup::new(pub_key))],
+        [Some(
+            ProducerSetup::new(secret).with_txs(1).with_name("Alice"),
+        )],
+        [Some(ValidatorSetup::new(pub_key).with_name("Bob"))],
+        None,
+    )
+    .await;
+
+    let producer = producers.pop().unwrap();
+    let mut validator = validators.pop().unwrap();
+    let expected = producer.insert_txs().await;
+    validator.consistency_10s(&expected).await;
+
+    let client = FuelClient::from(validator.node.bound_address);
+    let network_info = client.network_info().await.unwrap().unwrap();
+
+    assert_eq!(network_info.inbound_peers + network_info.outbound_peers, 2);
+    let request_response = network_info
+        .bandwidth
+        .iter()
+        .find(|bandwidth| bandwidth.protocol == NetworkProtocol::RequestResponse)
+        .expect("The validator should request blocks from the producer");
+    assert!(request_response.inbound_bytes > 0);
+    assert!(request_response.outbound_bytes > 0);
+    assert!(network_info
+        .mesh
+        .iter()
+        .any(|mesh| mesh.topic.starts_with("new_tx")));
+}
@@ -0,0 +1,39 @@
+use fuel_core::service::{
+    Config,
+    DbType,
+    FuelService,
+    ServiceTrait,
+};
+use fuel_core_client::client::FuelClient;
+use tempfile::TempDir;
+
+#[tokio::test]
+async fn read_only_node_serves_only_queries() {
+    let tmp_dir = TempDir::new().unwrap();
+    let mut config = Config::local_node();
+    config.database_type = DbType::RocksDb;
+    config.database_path = tmp_dir.path().to_path_buf();
+    let node = FuelService::new_node(config.clone()).await.unwrap();
+    let client = FuelClient::from(node.bound_address);
+    client.produce_blocks(1, None).await.unwrap();
+    node.stop_and_await().await.unwrap();
+
+    // Given
+    config.db_read_only = true;
+    let read_only = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(read_only.bound_address);
+
+    // When
+    let block = client.block_by_height(1).await;
+    let produced = client.produce_blocks(1, None).await;
+    let subscription = reqwest::Client::new()
+        .post(format!("http://{}/graphql-sub", read_only.bound_address))
+        .send()
+        .await
+        .unwrap();
+
+    // Then
+    assert!(block.unwrap().is_some());
+    assert!(produced.is_err());
+    assert_eq!(subscription.status(), reqwest::StatusCode::NOT_FOUND);
+}
@@ -1,11 +1,3 @@
-use ethers::{
-    providers::Middleware,
-    types::{
-        Log,
-        SyncingStatus,
-        U256,
-    },
-};
 use fuel_core::{
     database::Database,
     relayer,
@@ -24,49 +16,37 @@ use fuel_core_client::client::{
     FuelClient,
 };
 use fuel_core_poa::service::Mode;
-use fuel_core_relayer::{
-    test_helpers::{
-        middleware::MockMiddleware,
-        EvtToLog,
-        LogTestHelper,
-    },
-    H160,
+use fuel_core_relayer::test_helpers::{
+    middleware::MockMiddleware,
+    LogTestHelper,
 };
 use fuel_core_storage::{
     tables::Messages,
     StorageAsRef,
 };
+use fuel_core_test_harness::relayer::{
+    make_message_event,
+    spawn_eth_node,
+};
 use fuel_core_types::{
     fuel_asm::*,
     fuel_crypto::*,
     fuel_tx::*,
     fuel_types::Nonce,
 };
-use hyper::{
-    service::{
-        make_service_fn,
-        service_fn,
-    },
-    Body,
-    Request,
-    Response,
-    Server,
-};
 use rand::{
     prelude::StdRng,
     Rng,
     SeedableRng,
 };
-use serde_json::json;
+use serde_json::{
+    json,
+    Value,
+};
 use std::{
-    convert::Infallible,
-    net::{
-        Ipv4Addr,
-        SocketAddr,
-    },
+    net::SocketAddr,
     sync::Arc,
 };
-use tokio::sync::oneshot::Sender;
 
 #[tokio::test(flavor = "multi_thread")]
 async fn relayer_can_download_logs() {
@@ -251,112 +231,67 @@ async fn messages_are_spendable_after_relayer_is_synced() {
     eth_node_handle.shutdown.send(()).unwrap();
 }
 
-fn make_message_event(
-    nonce: Nonce,
-    block_number: u64,
-    contract_address: H160,
-    sender: Option<[u8; 32]>,
-    recipient: Option<[u8; 32]>,
-    amount: Option<u64>,
-    data: Option<Vec<u8>>,
-) -> Log {
-    let message = fuel_core_relayer::bridge::MessageSentFilter {
-        nonce: U256::from_big_endian(nonce.as_ref()),
-        sender: sender.unwrap_or_default(),
-        recipient: recipient.unwrap_or_default(),
-        amount: amount.unwrap_or_default(),
-        data: data.map(Into::into).unwrap_or_default(),
-    };
-    let mut log = message.into_log();
-    log.address = contract_address;
-    log.block_number = Some(block_number.into());
-    log
+async fn eth_rpc(address: SocketAddr, request: Value) -> Value {
+    reqwest::Client::new()
+        .post(format!("http://{address}"))
+        .json(&request)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap()
 }
 
-async fn spawn_eth_node(eth_node: Arc<MockMiddleware>) -> EthNodeHandle {
-    // Construct our SocketAddr to listen on...
-    let addr = SocketAddr::from((Ipv4Addr::LOCALHOST, 0));
-
-    // And a MakeService to handle each connection...
-    let make_service = make_service_fn(move |_conn| {
-        let eth_node = eth_node.clone();
-        async move {
-            Ok::<_, Infallible>(service_fn({
-                let eth_node = eth_node.clone();
-                move |req| handle(eth_node.clone(), req)
-            }))
-        }
+#[tokio::test]
+async fn mock_eth_node_serves_blocks_by_number_from_params() {
+    // Given
+    let eth_node = MockMiddleware::default();
+    eth_node.update_data(|data| {
+        data.best_block.number = Some(200.into());
+        data.block_hashes.insert(5, Default::default());
     });
+    let eth_node_handle = spawn_eth_node(Arc::new(eth_node)).await;
 
-    // Then bind and serve...
-    let server = Server::bind(&addr).serve(make_service);
-    let addr = server.local_addr();
-
-    let (shutdown, rx) = tokio::sync::oneshot::channel();
-
-    tokio::spawn(async move {
-        let graceful = server.with_graceful_shutdown(async {
-            rx.await.ok();
-        });
-        // And run forever...
-        if let Err(e) = graceful.await {
-            eprintln!("server error: {e}");
-        }
-    });
-    EthNodeHandle {
-        shutdown,
-        address: addr,
-    }
-}
+    // When
+    let response = eth_rpc(
+        eth_node_handle.address,
+        json!({
+            "id": 7,
+            "jsonrpc": "2.0",
+            "method": "eth_getBlockByNumber",
+            "params": ["0x5", false],
+        }),
+    )
+    .await;
 
-pub(crate) struct EthNodeHandle {
-    pub(crate) shutdown: Sender<()>,
-    pub(crate) address: SocketAddr,
+    // Then
+    assert_eq!(response["id"], 7);
+    assert_eq!(response["result"]["number"], "0x5", "{response}");
+    eth_node_handle.shutdown.send(()).unwrap();
 }
 
-async fn handle(
-    mock: Arc<MockMiddleware>,
-    req: Request<Body>,
-) -> Result<Response<Body>, Infallible> {
-    let body = hyper::body::to_bytes(req).await.unwrap();
-
-    let v: serde_json::Value = serde_json::from_slice(body.as_ref()).unwrap();
-    let mut o = match v {
-        serde_json::Value::Object(o) => o,
-        _ => unreachable!(),
-    };
-    let id = o.get("id").unwrap().as_u64().unwrap();
-    let method = o.get("method").unwrap().as_str().unwrap();
-    let r = match method {
-        "eth_getBlockByNumber" => {
-            let r = mock.get_block(id).await.unwrap().unwrap();
-            json!({ "id": id, "jsonrpc": "2.0", "result": r })
-        }
-        "eth_syncing" => {
-            let r = mock.syncing().await.unwrap();
-            match r {
-                SyncingStatus::IsFalse => {
-                    json!({ "id": id, "jsonrpc": "2.0", "result": false })
-                }
-                SyncingStatus::IsSyncing(status) => {
-                    json!({ "id": id, "jsonrpc": "2.0", "result": {
-                        "starting_block": status.starting_block, 
-                        "current_block": status.current_block,
-                        "highest_block": status.highest_block,
-                    } })
-                }
-            }
-        }
-        "eth_getLogs" => {
-            let params = o.remove("params").unwrap();
-            let params: Vec<_> = serde_json::from_value(params).unwrap();
-            let r = mock.get_logs(&params[0]).await.unwrap();
-            json!({ "id": id, "jsonrpc": "2.0", "result": r })
-        }
-        _ => unreachable!("Mock handler for method not defined"),
-    };
+#[tokio::test]
+async fn mock_eth_node_returns_errors_for_invalid_requests() {
+    // Given
+    let eth_node_handle = spawn_eth_node(Arc::new(MockMiddleware::default())).await;
 
-    let r = serde_json::to_vec(&r).unwrap();
+    // When
+    let unknown_method = eth_rpc(
+        eth_node_handle.address,
+        json!({ "id": 1, "jsonrpc": "2.0", "method": "eth_chainId", "params": [] }),
+    )
+    .await;
+    let invalid_params = eth_rpc(
+        eth_node_handle.address,
+        json!({ "id": 2, "jsonrpc": "2.0", "method": "eth_getLogs", "params": [] }),
+    )
+    .await;
 
-    Ok(Response::new(Body::from(r)))
+    // Then
+    assert_eq!(unknown_method["id"], 1);
+    assert_eq!(unknown_method["error"]["code"], -32601, "{unknown_method}");
+    assert_eq!(invalid_params["id"], 2);
+    assert_eq!(invalid_params["error"]["code"], -32602, "{invalid_params}");
+    eth_node_handle.shutdown.send(()).unwrap();
 }
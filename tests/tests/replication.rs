@@ -0,0 +1,94 @@
+use fuel_core::service::{
+    Config,
+    FuelService,
+    PrimaryReplicationConfig,
+    ReplicationConfig,
+    StandbyReplicationConfig,
+};
+use fuel_core_client::client::FuelClient;
+use fuel_core_poa::Trigger;
+use std::{
+    net::SocketAddr,
+    time::Duration,
+};
+
+fn standby_config(primary: SocketAddr, secret: &str) -> Config {
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    config.replication = Some(ReplicationConfig::Standby(StandbyReplicationConfig {
+        primary: primary.to_string(),
+        secret: secret.to_string().into(),
+        reconnect_interval: Duration::from_millis(100),
+    }));
+    config
+}
+
+async fn start_primary() -> FuelService {
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    config.replication = Some(ReplicationConfig::Primary(PrimaryReplicationConfig {
+        addr: "127.0.0.1:0".parse().unwrap(),
+        secret: "secret".to_string().into(),
+        log_capacity: 100,
+    }));
+    FuelService::new_node(config).await.unwrap()
+}
+
+async fn wait_for_height(client: &FuelClient, height: u32) -> u32 {
+    let mut latest = 0;
+    for _ in 0..50 {
+        latest = client
+            .chain_info()
+            .await
+            .unwrap()
+            .latest_block
+            .header
+            .height;
+        if latest == height {
+            break
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    latest
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn standby_follows_blocks_of_primary() {
+    let primary = start_primary().await;
+    let primary_client = FuelClient::from(primary.bound_address);
+    primary_client.produce_blocks(3, None).await.unwrap();
+
+    let replication_address = primary.shared.replication_address.unwrap();
+    let standby = FuelService::new_node(standby_config(replication_address, "secret"))
+        .await
+        .unwrap();
+    let standby_client = FuelClient::from(standby.bound_address);
+    primary_client.produce_blocks(2, None).await.unwrap();
+
+    assert_eq!(wait_for_height(&standby_client, 5).await, 5);
+    assert_eq!(
+        standby_client.block_by_height(5).await.unwrap().unwrap().id,
+        primary_client.block_by_height(5).await.unwrap().unwrap().id
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn standby_with_wrong_secret_is_rejected() {
+    let primary = start_primary().await;
+    let primary_client = FuelClient::from(primary.bound_address);
+    primary_client.produce_blocks(1, None).await.unwrap();
+
+    let replication_address = primary.shared.replication_address.unwrap();
+    let standby = FuelService::new_node(standby_config(replication_address, "wrong"))
+        .await
+        .unwrap();
+    tokio::time::sleep(Duration::from_millis(500)).await;
+
+    let latest_block = standby
+        .shared
+        .database
+        .on_chain()
+        .get_current_block()
+        .unwrap();
+    assert!(latest_block.is_none());
+}
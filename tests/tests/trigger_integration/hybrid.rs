@@ -0,0 +1,139 @@
+use fuel_core::{
+    database::Database,
+    service::{
+        Config,
+        FuelService,
+    },
+};
+use fuel_core_client::client::{
+    types::BlockProductionTrigger,
+    FuelClient,
+};
+use fuel_core_poa::Trigger;
+use fuel_core_types::{
+    fuel_asm::op,
+    fuel_crypto::SecretKey,
+    fuel_tx::TransactionBuilder,
+    secrecy::Secret,
+};
+use rand::{
+    rngs::StdRng,
+    SeedableRng,
+};
+use std::time::Duration;
+
+async fn latest_height(client: &FuelClient) -> u32 {
+    client
+        .chain_info()
+        .await
+        .expect("chain info request failed")
+        .latest_block
+        .header
+        .height
+}
+
+#[tokio::test(start_paused = true)]
+async fn poa_hybrid_produces_empty_blocks_at_max_block_time() {
+    let rounds = 5;
+    let max_block_time_seconds = 5;
+
+    let mut rng = StdRng::seed_from_u64(10);
+    let db = Database::default();
+    let mut config = Config::local_node();
+    config.consensus_key = Some(Secret::new(SecretKey::random(&mut rng).into()));
+    config.block_production = Trigger::Hybrid {
+        min_block_time: Duration::from_secs(1),
+        max_block_time: Duration::from_secs(max_block_time_seconds),
+    };
+    let srv = FuelService::from_database(db.clone(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let time_start = tokio::time::Instant::now();
+    let height_start = latest_height(&client).await;
+    while latest_height(&client).await < height_start + rounds {
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    let time_end = tokio::time::Instant::now();
+
+    // Empty blocks are produced only after the max block time
+    assert!(
+        time_end.duration_since(time_start)
+            >= Duration::from_secs(max_block_time_seconds * u64::from(rounds))
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn poa_hybrid_produces_block_with_transactions_after_min_block_time() {
+    let mut rng = StdRng::seed_from_u64(10);
+    let db = Database::default();
+    let mut config = Config::local_node();
+    config.consensus_key = Some(Secret::new(SecretKey::random(&mut rng).into()));
+    config.block_production = Trigger::Hybrid {
+        min_block_time: Duration::from_secs(2),
+        max_block_time: Duration::from_secs(60),
+    };
+    let srv = FuelService::from_database(db.clone(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let time_start = tokio::time::Instant::now();
+    let tx = TransactionBuilder::script([op::ret(0)].into_iter().collect(), vec![])
+        .add_random_fee_input()
+        .finalize_as_transaction();
+    client.submit_and_await_commit(&tx).await.unwrap();
+    let elapsed = tokio::time::Instant::now().duration_since(time_start);
+
+    // The block is produced without waiting for the max block time
+    assert!(elapsed < Duration::from_secs(60));
+}
+
+#[tokio::test(start_paused = true)]
+async fn block_production_trigger_can_be_changed_through_graphql() {
+    let mut rng = StdRng::seed_from_u64(10);
+    let db = Database::default();
+    let mut config = Config::local_node();
+    config.consensus_key = Some(Secret::new(SecretKey::random(&mut rng).into()));
+    config.block_production = Trigger::Never;
+    let srv = FuelService::from_database(db.clone(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    tokio::time::sleep(Duration::from_secs(10)).await;
+    assert_eq!(latest_height(&client).await, 0);
+
+    client
+        .set_block_production(BlockProductionTrigger::Interval {
+            block_time: Duration::from_secs(2),
+        })
+        .await
+        .unwrap();
+    tokio::time::sleep(Duration::from_secs(5)).await;
+    assert!(latest_height(&client).await >= 1);
+
+    client
+        .set_block_production(BlockProductionTrigger::Never)
+        .await
+        .unwrap();
+    let height = latest_height(&client).await;
+    tokio::time::sleep(Duration::from_secs(10)).await;
+    assert_eq!(latest_height(&client).await, height);
+}
+
+#[tokio::test]
+async fn invalid_hybrid_trigger_is_rejected() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let result = client
+        .set_block_production(BlockProductionTrigger::Hybrid {
+            min_block_time: Duration::from_secs(10),
+            max_block_time: Duration::from_secs(1),
+        })
+        .await;
+
+    assert!(result.is_err());
+}
@@ -11,7 +11,12 @@ use fuel_core_client::client::{
         PageDirection,
         PaginationRequest,
     },
-    types::TransactionStatus,
+    types::{
+        ConfirmationStatus,
+        DryRunBundleStep,
+        TransactionConfirmation,
+        TransactionStatus,
+    },
     FuelClient,
 };
 use fuel_core_poa::service::Mode;
@@ -137,6 +142,69 @@ async fn dry_run_create() {
     assert_eq!(err.kind(), NotFound);
 }
 
+#[tokio::test]
+async fn dry_run_bundle_skips_invalid_transactions() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let salt: Salt = rng.gen();
+    let contract = Contract::from(vec![]);
+    let root = contract.root();
+    let state_root = Contract::default_state_root();
+    let contract_id = contract.id(&salt, &root, &state_root);
+
+    let create = TransactionBuilder::create(vec![].into(), salt, vec![])
+        .add_random_fee_input()
+        .add_output(Output::contract_created(contract_id, state_root))
+        .finalize_as_transaction();
+    let call_contract = |contract_id: ContractId| {
+        TransactionBuilder::script(vec![], vec![])
+            .script_gas_limit(1_000_000)
+            .add_random_fee_input()
+            .add_input(Input::contract(
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                contract_id,
+            ))
+            .add_output(Output::contract(1, Default::default(), Default::default()))
+            .finalize_as_transaction()
+    };
+    // The contract doesn't exist, so the transaction is skipped.
+    let invalid = call_contract(rng.gen());
+    // The contract is created by the first transaction of the bundle.
+    let dependent = call_contract(contract_id);
+
+    let steps = client
+        .dry_run_bundle(&[create.clone(), invalid.clone(), dependent.clone()], None)
+        .await
+        .unwrap();
+
+    let chain_id = ChainId::default();
+    assert_eq!(steps.len(), 3);
+    assert!(matches!(
+        &steps[0],
+        DryRunBundleStep::Executed(status) if status.id == create.id(&chain_id)
+    ));
+    assert!(matches!(
+        &steps[1],
+        DryRunBundleStep::Skipped { id, .. } if *id == invalid.id(&chain_id)
+    ));
+    assert!(matches!(
+        &steps[2],
+        DryRunBundleStep::Executed(status) if status.id == dependent.id(&chain_id)
+    ));
+
+    // ensure the txs aren't available in the blockchain history
+    let err = client
+        .transaction_status(&create.id(&chain_id))
+        .await
+        .unwrap_err();
+    assert_eq!(err.kind(), NotFound);
+}
+
 #[tokio::test]
 async fn submit() {
     let srv = FuelService::new_node(Config::local_node()).await.unwrap();
@@ -175,6 +243,49 @@ async fn submit() {
     assert_eq!(tx.id(&ChainId::default()), ret_tx.id(&ChainId::default()));
 }
 
+#[tokio::test]
+async fn transaction_confirmation_follows_depth_of_block() {
+    let mut config = Config::local_node();
+    config.tx_finality_depth = Some(2);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let tx = TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+        .script_gas_limit(1_000_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+    let tx_id = tx.id(&ChainId::default());
+    client.submit_and_await_commit(&tx).await.unwrap();
+
+    let confirmation = |status, depth| Some(TransactionConfirmation { status, depth });
+    assert_eq!(
+        client.transaction_confirmation(&tx_id).await.unwrap(),
+        confirmation(ConfirmationStatus::Included, 0)
+    );
+    client.produce_blocks(1, None).await.unwrap();
+    assert_eq!(
+        client.transaction_confirmation(&tx_id).await.unwrap(),
+        confirmation(ConfirmationStatus::Confirmed, 1)
+    );
+    client.produce_blocks(1, None).await.unwrap();
+    assert_eq!(
+        client.transaction_confirmation(&tx_id).await.unwrap(),
+        confirmation(ConfirmationStatus::Finalized, 2)
+    );
+}
+
+#[tokio::test]
+async fn transaction_confirmation_of_unknown_transaction_is_none() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let confirmation = client
+        .transaction_confirmation(&TxId::default())
+        .await
+        .unwrap();
+    assert_eq!(confirmation, None);
+}
+
 #[ignore]
 #[tokio::test]
 async fn transaction_status_submitted() {
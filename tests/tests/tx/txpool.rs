@@ -4,11 +4,18 @@ use crate::helpers::{
     TestContext,
     TestSetupBuilder,
 };
+use fuel_core::service::{
+    Config,
+    FuelService,
+};
+use fuel_core_client::client::FuelClient;
+use fuel_core_poa::Trigger;
 use fuel_core_types::{
     fuel_asm::*,
     fuel_crypto::*,
     fuel_tx,
     fuel_tx::*,
+    services::txpool::TxOrigin,
 };
 use itertools::Itertools;
 use rand::{
@@ -65,7 +72,7 @@ async fn txs_max_script_gas_limit() {
         .into_iter()
         .map(|script| Arc::new(fuel_tx::Transaction::from(script)))
         .collect::<Vec<_>>();
-    srv.shared.txpool.insert(txs).await;
+    srv.shared.txpool.insert(txs, TxOrigin::Injected).await;
 
     tokio::time::sleep(Duration::from_secs(1)).await;
 
@@ -75,3 +82,59 @@ async fn txs_max_script_gas_limit() {
         transactions.len() + 1 // coinbase
     )
 }
+
+#[tokio::test]
+async fn txpool_can_be_paused_flushed_and_dumped_via_api() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    config.tx_pool_dump_dir = Some(dir.path().to_path_buf());
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let tx = || {
+        TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+            .script_gas_limit(10_000)
+            .add_random_fee_input()
+            .finalize_as_transaction()
+    };
+
+    // The paused pool rejects new transactions.
+    client.pause_tx_pool().await.unwrap();
+    assert!(client.submit(&tx()).await.is_err());
+
+    client.resume_tx_pool().await.unwrap();
+    client.submit(&tx()).await.unwrap();
+    client.submit(&tx()).await.unwrap();
+
+    let dumped = client.dump_tx_pool("before_flush.json").await.unwrap();
+    assert_eq!(dumped, 2);
+    assert!(dir.path().join("before_flush.json").exists());
+
+    let flushed = client.flush_tx_pool(None, None).await.unwrap();
+    assert_eq!(flushed, 2);
+    let dumped = client.dump_tx_pool("after_flush.json").await.unwrap();
+    assert_eq!(dumped, 0);
+
+    // The existing dumps are never overwritten.
+    assert!(client.dump_tx_pool("before_flush.json").await.is_err());
+}
+
+#[tokio::test]
+async fn dump_tx_pool_rejects_paths_outside_of_the_dump_dir() {
+    // Given
+    let dir = tempfile::TempDir::new().unwrap();
+    let mut config = Config::local_node();
+    config.tx_pool_dump_dir = Some(dir.path().join("dumps"));
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let absolute = dir.path().join("txpool.json");
+
+    // When
+    let absolute_result = client.dump_tx_pool(absolute.to_str().unwrap()).await;
+    let parent_result = client.dump_tx_pool("../txpool.json").await;
+
+    // Then
+    assert!(absolute_result.is_err());
+    assert!(parent_result.is_err());
+    assert!(!absolute.exists());
+}